@@ -10,6 +10,7 @@ use crate::extensions::ExtensionManager;
 use crate::fees::{FeeConfig, FeeManager};
 use crate::markets::MarketStateManager;
 use crate::resolution::MarketResolutionManager;
+use crate::storage::DataKey;
 use alloc::string::ToString;
 
 /// Admin management system for Predictify Hybrid contract
@@ -40,6 +41,22 @@ pub enum AdminRole {
     ReadOnlyAdmin,
 }
 
+/// Operational roles that can be delegated by the primary admin.
+///
+/// The primary admin implicitly holds every role.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Role {
+    /// May resolve markets manually
+    Resolver,
+    /// May collect and withdraw platform fees
+    FeeManager,
+    /// May pause and unpause the contract
+    Pauser,
+    /// May rule on result disputes and their appeals
+    Arbitrator,
+}
+
 /// Admin permission enumeration
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[contracttype]
@@ -83,6 +100,28 @@ pub struct AdminAction {
     pub error_message: Option<String>,
 }
 
+/// What an admin log entry acted on
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AdminLogTarget {
+    /// A contract-wide setting or the contract code itself
+    Contract,
+    /// A single market
+    Market(Symbol),
+    /// An account or token contract
+    Account(Address),
+}
+
+/// Entry of the bounded on-chain admin log read with `get_admin_log`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AdminLogEntry {
+    pub who: Address,
+    pub action: Symbol,
+    pub target: AdminLogTarget,
+    pub timestamp: u64,
+}
+
 /// Admin role assignment
 #[derive(Clone, Debug)]
 #[contracttype]
@@ -180,7 +219,7 @@ impl AdminInitializer {
     ///
     /// This function returns specific errors:
     /// - `Error::InvalidAddress` - Admin address is invalid or zero
-    /// - `Error::AlreadyInitialized` - Contract has already been initialized
+    /// - `Error::InvalidState` - Contract has already been initialized
     /// - `Error::StorageError` - Failed to store admin data
     /// - Role assignment errors from AdminRoleManager
     ///
@@ -233,8 +272,8 @@ impl AdminInitializer {
 
         // Store admin in persistent storage
         env.storage()
-            .persistent()
-            .set(&Symbol::new(env, "Admin"), admin);
+            .instance()
+            .set(&DataKey::Admin, admin);
 
         // Set default admin role
         AdminRoleManager::assign_role(env, admin, AdminRole::SuperAdmin, admin)?;
@@ -362,7 +401,7 @@ impl AdminInitializer {
     ///
     /// This function returns specific validation errors:
     /// - `Error::InvalidAddress` - Admin address is invalid, zero, or malformed
-    /// - `Error::AlreadyInitialized` - Contract has already been initialized
+    /// - `Error::InvalidState` - Contract has already been initialized
     /// - Address format validation errors
     ///
     /// # Example
@@ -578,8 +617,8 @@ impl AdminAccessControl {
         // Validate admin exists
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
 
         if admin != &stored_admin {
@@ -593,6 +632,7 @@ impl AdminAccessControl {
 // ===== CONTRACT PAUSE AND ADMIN TRANSFER =====
 
 const CONTRACT_PAUSED_KEY: &str = "ContractPaused";
+const PENDING_ADMIN_KEY: &str = "PendingAdmin";
 
 /// Contract-level pause and primary admin transfer.
 pub struct ContractPauseManager;
@@ -606,17 +646,10 @@ impl ContractPauseManager {
             .unwrap_or(false)
     }
 
-    /// Pause contract operations. Caller must be the current primary admin.
+    /// Pause contract operations. Caller must hold the `Pauser` role.
     pub fn pause(env: &Env, admin: &Address) -> Result<(), Error> {
         admin.require_auth();
-        let stored: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
-            .ok_or(Error::AdminNotSet)?;
-        if admin != &stored {
-            return Err(Error::Unauthorized);
-        }
+        RoleManager::require_role(env, Role::Pauser, admin)?;
         env.storage()
             .persistent()
             .set(&Symbol::new(env, CONTRACT_PAUSED_KEY), &true);
@@ -624,17 +657,10 @@ impl ContractPauseManager {
         Ok(())
     }
 
-    /// Unpause contract operations. Caller must be the current primary admin.
+    /// Unpause contract operations. Caller must hold the `Pauser` role.
     pub fn unpause(env: &Env, admin: &Address) -> Result<(), Error> {
         admin.require_auth();
-        let stored: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
-            .ok_or(Error::AdminNotSet)?;
-        if admin != &stored {
-            return Err(Error::Unauthorized);
-        }
+        RoleManager::require_role(env, Role::Pauser, admin)?;
         env.storage()
             .persistent()
             .set(&Symbol::new(env, CONTRACT_PAUSED_KEY), &false);
@@ -642,22 +668,30 @@ impl ContractPauseManager {
         Ok(())
     }
 
-    /// Require that the contract is not paused; return Error::InvalidState otherwise.
+    /// Require that the contract is not paused; return Error::ContractPaused otherwise.
     pub fn require_not_paused(env: &Env) -> Result<(), Error> {
         if Self::is_contract_paused(env) {
-            return Err(Error::InvalidState);
+            return Err(Error::ContractPaused);
         }
         Ok(())
     }
 
-    /// Transfer the primary admin role to a new address. Caller must be the current primary admin.
-    /// New admin must not be the zero/invalid address.
-    pub fn transfer_admin(env: &Env, current_admin: &Address, new_admin: &Address) -> Result<(), Error> {
+    /// Returns the address proposed as the next primary admin, if any.
+    pub fn get_pending_admin(env: &Env) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, PENDING_ADMIN_KEY))
+    }
+
+    /// Propose a new primary admin. Caller must be the current primary admin.
+    /// The current admin keeps all privileges until `accept_admin` is called by
+    /// the proposed address; a newer proposal replaces any pending one.
+    pub fn propose_admin(env: &Env, current_admin: &Address, new_admin: &Address) -> Result<(), Error> {
         current_admin.require_auth();
         let stored: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
         if current_admin != &stored {
             return Err(Error::Unauthorized);
@@ -668,8 +702,223 @@ impl ContractPauseManager {
         AdminValidator::validate_admin_address(env, new_admin)?;
         env.storage()
             .persistent()
-            .set(&Symbol::new(env, "Admin"), new_admin);
-        EventEmitter::emit_admin_transferred(env, current_admin, new_admin);
+            .set(&Symbol::new(env, PENDING_ADMIN_KEY), new_admin);
+        EventEmitter::emit_admin_proposed(env, current_admin, new_admin);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal. Caller must be the proposed address.
+    pub fn accept_admin(env: &Env, new_admin: &Address) -> Result<(), Error> {
+        new_admin.require_auth();
+        let pending = Self::get_pending_admin(env).ok_or(Error::ConfigNotFound)?;
+        if new_admin != &pending {
+            return Err(Error::Unauthorized);
+        }
+        let previous: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, new_admin);
+        Self::move_role_assignments(env, &previous, new_admin);
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, PENDING_ADMIN_KEY));
+        EventEmitter::emit_admin_transferred(env, &previous, new_admin);
+        Ok(())
+    }
+
+    /// Hand the primary admin's role assignment and multi-admin entry to
+    /// `new_admin`, so role-checked entry points accept the new admin and no
+    /// longer accept the previous one.
+    fn move_role_assignments(env: &Env, previous: &Address, new_admin: &Address) {
+        let now = env.ledger().timestamp();
+        let storage = env.storage().persistent();
+
+        let role_key = Symbol::new(env, "admin_role");
+        if let Some(mut assignment) = storage.get::<_, AdminRoleAssignment>(&role_key) {
+            if &assignment.admin == previous {
+                assignment.admin = new_admin.clone();
+                assignment.assigned_by = previous.clone();
+                assignment.assigned_at = now;
+                storage.set(&role_key, &assignment);
+            }
+        }
+
+        let previous_key = AdminManager::get_admin_key(env, previous);
+        if let Some(mut assignment) = storage.get::<_, AdminRoleAssignment>(&previous_key) {
+            let new_key = AdminManager::get_admin_key(env, new_admin);
+            if storage.has(&new_key) {
+                // The new admin already held an entry; the two collapse into one
+                let count_key = Symbol::new(env, "AdminCount");
+                let count: u32 = storage.get(&count_key).unwrap_or(0);
+                storage.set(&count_key, &count.saturating_sub(1));
+            }
+            assignment.admin = new_admin.clone();
+            assignment.assigned_by = previous.clone();
+            assignment.assigned_at = now;
+            storage.set(&new_key, &assignment);
+            storage.remove(&previous_key);
+        }
+    }
+
+    /// Withdraw the pending admin proposal. Caller must be the current primary admin.
+    pub fn cancel_admin_proposal(env: &Env, current_admin: &Address) -> Result<(), Error> {
+        current_admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if current_admin != &stored {
+            return Err(Error::Unauthorized);
+        }
+        let pending = Self::get_pending_admin(env).ok_or(Error::ConfigNotFound)?;
+        env.storage()
+            .persistent()
+            .remove(&Symbol::new(env, PENDING_ADMIN_KEY));
+        EventEmitter::emit_admin_proposal_cancelled(env, current_admin, &pending);
+        Ok(())
+    }
+}
+
+// ===== COMPLIANCE BLACKLIST =====
+
+/// Addresses blocked from betting, claiming, moving positions and receiving refunds.
+pub struct ComplianceManager;
+
+impl ComplianceManager {
+    /// Returns true if `address` is blocked.
+    pub fn is_blocked(env: &Env, address: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Blacklisted(address.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Require that `address` is not blocked; return Error::AddressBlocked otherwise.
+    pub fn require_not_blocked(env: &Env, address: &Address) -> Result<(), Error> {
+        if Self::is_blocked(env, address) {
+            return Err(Error::AddressBlocked);
+        }
+        Ok(())
+    }
+
+    /// Block or unblock an address. Caller must be the primary admin.
+    pub fn set_blacklist(env: &Env, admin: &Address, address: &Address, blocked: bool) -> Result<(), Error> {
+        admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != &stored {
+            return Err(Error::Unauthorized);
+        }
+        let key = DataKey::Blacklisted(address.clone());
+        if blocked {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        EventEmitter::emit_address_blacklisted(env, address, blocked, admin);
+        Ok(())
+    }
+}
+
+// ===== OPERATIONAL ROLES =====
+
+const ROLES_KEY: &str = "Roles";
+
+/// Role grants for delegated operations, stored as a map of address to roles.
+pub struct RoleManager;
+
+impl RoleManager {
+    /// Returns true if `addr` holds `role`. The primary admin holds every role.
+    pub fn has_role(env: &Env, role: Role, addr: &Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if admin.as_ref() == Some(addr) {
+            return true;
+        }
+        Self::has_granted_role(env, role, addr)
+    }
+
+    /// True if `addr` was explicitly granted `role`; the implicit admin holding does not count.
+    pub fn has_granted_role(env: &Env, role: Role, addr: &Address) -> bool {
+        Self::get_roles(env)
+            .get(addr.clone())
+            .is_some_and(|roles| roles.contains(role))
+    }
+
+    /// True if any address has been granted `role`.
+    pub fn is_role_granted(env: &Env, role: Role) -> bool {
+        Self::get_roles(env).values().iter().any(|roles| roles.contains(role))
+    }
+
+    /// Return `Error::Unauthorized` unless `addr` holds `role`.
+    pub fn require_role(env: &Env, role: Role, addr: &Address) -> Result<(), Error> {
+        if !Self::has_role(env, role, addr) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Grant `role` to `addr`. Caller must be the primary admin; granting a held role is a no-op.
+    pub fn grant_role(env: &Env, admin: &Address, role: Role, addr: &Address) -> Result<(), Error> {
+        Self::require_primary_admin(env, admin)?;
+        let mut roles = Self::get_roles(env);
+        let mut held = roles.get(addr.clone()).unwrap_or(Vec::new(env));
+        if !held.contains(role) {
+            held.push_back(role);
+            roles.set(addr.clone(), held);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(env, ROLES_KEY), &roles);
+        }
+        EventEmitter::emit_role_granted(env, &role, addr, admin);
+        Ok(())
+    }
+
+    /// Revoke `role` from `addr`. Caller must be the primary admin; revoking an absent role is a no-op.
+    pub fn revoke_role(env: &Env, admin: &Address, role: Role, addr: &Address) -> Result<(), Error> {
+        Self::require_primary_admin(env, admin)?;
+        let mut roles = Self::get_roles(env);
+        if let Some(mut held) = roles.get(addr.clone()) {
+            if let Some(index) = held.first_index_of(role) {
+                held.remove(index);
+                if held.is_empty() {
+                    roles.remove(addr.clone());
+                } else {
+                    roles.set(addr.clone(), held);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&Symbol::new(env, ROLES_KEY), &roles);
+            }
+        }
+        EventEmitter::emit_role_revoked(env, &role, addr, admin);
+        Ok(())
+    }
+
+    fn get_roles(env: &Env) -> Map<Address, Vec<Role>> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, ROLES_KEY))
+            .unwrap_or(Map::new(env))
+    }
+
+    fn require_primary_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != &stored {
+            return Err(Error::Unauthorized);
+        }
         Ok(())
     }
 }
@@ -1545,10 +1794,9 @@ impl AdminManager {
     // ===== Helper Methods =====
 
     /// Generate a proper admin storage key using the correct environment
-    fn get_admin_key(env: &Env, admin: &Address) -> Symbol {
-        // Create a unique key based on admin address
-        let key_str = alloc::format!("MultiAdmin_{:?}", admin.to_string());
-        Symbol::new(env, &key_str)
+    fn get_admin_key(env: &Env, admin: &Address) -> (Symbol, Address) {
+        // Keyed by address; a formatted address does not fit in a Symbol
+        (Symbol::new(env, "MultiAdmin"), admin.clone())
     }
 
     /// Check if an address is the original admin from single-admin system
@@ -1561,7 +1809,7 @@ impl AdminManager {
 
     /// Get the original admin address from single-admin system
     pub fn get_original_admin(env: &Env) -> Option<Address> {
-        env.storage().persistent().get(&Symbol::new(env, "Admin"))
+        env.storage().instance().get(&DataKey::Admin)
     }
 
     /// Check if an address is any type of admin (original or multi-admin)
@@ -2637,7 +2885,7 @@ impl AdminValidator {
     /// - Consider it a potential security incident
     /// - Provide clear error messages to legitimate callers
     pub fn validate_contract_not_initialized(env: &Env) -> Result<(), Error> {
-        let admin_exists = env.storage().persistent().has(&Symbol::new(env, "Admin"));
+        let admin_exists = env.storage().instance().has(&DataKey::Admin);
 
         if admin_exists {
             return Err(Error::InvalidState);
@@ -2973,6 +3221,61 @@ impl AdminActionLogger {
         Ok(())
     }
 
+    /// Appends an entry to the on-chain admin log.
+    ///
+    /// The log is a ring of `ADMIN_LOG_CAPACITY` slots, each its own persistent
+    /// entry, so a write touches one slot plus the instance counter; once full,
+    /// the oldest entry is overwritten.
+    pub fn record(env: &Env, who: &Address, action: Symbol, target: AdminLogTarget) {
+        let count = Self::get_log_count(env);
+        let slot = (count % crate::config::ADMIN_LOG_CAPACITY as u64) as u32;
+        let entry = AdminLogEntry {
+            who: who.clone(),
+            action,
+            target,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&(soroban_sdk::symbol_short!("adm_log"), slot), &entry);
+        env.storage().instance().set(
+            &Symbol::new(env, crate::config::ADMIN_LOG_COUNT_STORAGE_KEY),
+            &(count + 1),
+        );
+    }
+
+    /// Total number of entries ever written to the admin log, including those
+    /// since overwritten.
+    pub fn get_log_count(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::ADMIN_LOG_COUNT_STORAGE_KEY))
+            .unwrap_or(0)
+    }
+
+    /// Returns up to `limit` retained admin log entries, oldest first, starting
+    /// `start` entries after the oldest one still retained.
+    pub fn get_log(env: &Env, start: u32, limit: u32) -> Vec<AdminLogEntry> {
+        let capacity = crate::config::ADMIN_LOG_CAPACITY;
+        let count = Self::get_log_count(env);
+        let retained = count.min(capacity as u64) as u32;
+        let oldest = count - retained as u64;
+        let end = start.saturating_add(limit.min(capacity)).min(retained);
+
+        let mut entries = Vec::new(env);
+        for offset in start..end {
+            let slot = ((oldest + offset as u64) % capacity as u64) as u32;
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get(&(soroban_sdk::symbol_short!("adm_log"), slot))
+            {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
     /// Retrieves a list of all administrative actions from the audit trail.
     ///
     /// This function provides access to the complete history of administrative
@@ -3579,8 +3882,8 @@ mod tests {
             // Verify admin is stored
             let stored_admin: Address = env
                 .storage()
-                .persistent()
-                .get(&Symbol::new(&env, "Admin"))
+                .instance()
+                .get(&DataKey::Admin)
                 .unwrap();
             assert_eq!(stored_admin, admin);
         });
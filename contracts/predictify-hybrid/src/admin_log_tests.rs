@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+//! On-chain admin log tests.
+//!
+//! Admin actions are written to a ring of `ADMIN_LOG_CAPACITY` slots; once it
+//! is full the oldest entries are overwritten and `get_admin_log` pages through
+//! the retained ones oldest first.
+
+use crate::admin::{AdminLogTarget, Role};
+use crate::config::ADMIN_LOG_CAPACITY;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::CancelReason;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, String};
+
+#[test]
+fn test_admin_actions_are_logged_with_target() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // `setup` already set the staking token
+    let log = client.get_admin_log(&0, &10);
+    assert_eq!(log.len(), 1);
+    let entry = log.get(0).unwrap();
+    assert_eq!(entry.who, test.admin);
+    assert_eq!(entry.action, symbol_short!("set_token"));
+    assert_eq!(
+        entry.target,
+        AdminLogTarget::Account(test.token_test.token_id.clone())
+    );
+
+    let resolver = Address::generate(&test.env);
+    client.grant_role(&test.admin, &Role::Resolver, &resolver);
+    client.set_fee_bps(&test.admin, &150);
+
+    let resolved = test.create_test_market();
+    let end_time = client.get_market(&resolved).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &resolved, &String::from_str(&test.env, "yes"));
+
+    let cancelled = test.create_test_market();
+    client.cancel_event(&test.admin, &cancelled, &CancelReason::Administrative, &None);
+
+    let log = client.get_admin_log(&1, &10);
+    assert_eq!(log.len(), 4);
+    assert_eq!(log.get(0).unwrap().action, symbol_short!("grant_rl"));
+    assert_eq!(log.get(0).unwrap().target, AdminLogTarget::Account(resolver));
+    assert_eq!(log.get(1).unwrap().action, symbol_short!("fee_bps"));
+    assert_eq!(log.get(1).unwrap().target, AdminLogTarget::Contract);
+    assert_eq!(log.get(2).unwrap().action, symbol_short!("resolve"));
+    assert_eq!(log.get(2).unwrap().target, AdminLogTarget::Market(resolved));
+    assert_eq!(log.get(2).unwrap().timestamp, end_time + 1);
+    assert_eq!(log.get(3).unwrap().action, symbol_short!("cancel"));
+    assert_eq!(log.get(3).unwrap().target, AdminLogTarget::Market(cancelled));
+    assert_eq!(client.get_admin_log_count(), 5);
+}
+
+#[test]
+fn test_failed_admin_action_is_not_logged() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outsider = Address::generate(&test.env);
+
+    assert_eq!(
+        client.try_set_fee_bps(&outsider, &150),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_fee_bps(&test.admin, &5_000),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert_eq!(client.get_admin_log_count(), 1);
+}
+
+#[test]
+fn test_admin_log_wraps_and_reads_back_in_order() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let start_time = test.env.ledger().timestamp();
+
+    // One entry per second, 50 past capacity on top of the `set_token` entry
+    let writes = ADMIN_LOG_CAPACITY + 50;
+    for i in 1..=writes {
+        test.env
+            .ledger()
+            .with_mut(|li| li.timestamp = start_time + i as u64);
+        client.set_keeper_incentive_bps(&test.admin, &(i % 100));
+    }
+    assert_eq!(client.get_admin_log_count(), writes as u64 + 1);
+
+    // The oldest 51 entries, including `set_token`, were overwritten
+    let mut expected = start_time + 51;
+    let mut start = 0;
+    loop {
+        let page = client.get_admin_log(&start, &30);
+        if page.is_empty() {
+            break;
+        }
+        for entry in page.iter() {
+            assert_eq!(entry.action, symbol_short!("keep_bps"));
+            assert_eq!(entry.timestamp, expected);
+            expected += 1;
+        }
+        start += page.len();
+    }
+    assert_eq!(start, ADMIN_LOG_CAPACITY);
+    assert_eq!(expected, start_time + writes as u64 + 1);
+}
+
+#[test]
+fn test_admin_log_page_bounds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    for bps in 0..5 {
+        client.set_keeper_incentive_bps(&test.admin, &bps);
+    }
+
+    assert_eq!(client.get_admin_log(&0, &u32::MAX).len(), 6);
+    assert_eq!(client.get_admin_log(&4, &10).len(), 2);
+    assert_eq!(client.get_admin_log(&6, &10).len(), 0);
+    assert_eq!(client.get_admin_log(&u32::MAX, &u32::MAX).len(), 0);
+    assert_eq!(client.get_admin_log(&0, &0).len(), 0);
+}
@@ -0,0 +1,106 @@
+//! Checked arithmetic for token amounts.
+//!
+//! Token amounts are `i128` and must never go negative or wrap. Every pool,
+//! stake and payout update goes through these helpers so a bad amount is
+//! rejected instead of silently corrupting accounting.
+//!
+//! The contract error enum is at the 50-variant spec limit, so the two
+//! failure kinds map onto existing codes:
+//! - a negative (or, where required, zero) amount is `Error::InvalidInput`
+//! - a result that does not fit in `i128` is `Error::ThresholdTooHigh`
+
+use crate::errors::Error;
+
+/// Error for an amount that is negative or otherwise not a valid quantity.
+pub const INVALID_AMOUNT: Error = Error::InvalidInput;
+
+/// Error for a sum or product that does not fit in `i128`.
+pub const OVERFLOW: Error = Error::ThresholdTooHigh;
+
+/// Reject negative amounts; zero is allowed.
+pub fn require_non_negative(amount: i128) -> Result<i128, Error> {
+    if amount < 0 {
+        return Err(INVALID_AMOUNT);
+    }
+    Ok(amount)
+}
+
+/// Reject zero and negative amounts.
+pub fn require_positive(amount: i128) -> Result<i128, Error> {
+    if amount <= 0 {
+        return Err(INVALID_AMOUNT);
+    }
+    Ok(amount)
+}
+
+/// `a + b` for non-negative amounts.
+pub fn checked_add(a: i128, b: i128) -> Result<i128, Error> {
+    require_non_negative(a)?;
+    require_non_negative(b)?;
+    a.checked_add(b).ok_or(OVERFLOW)
+}
+
+/// `a - b` for non-negative amounts; taking out more than `a` holds is invalid.
+pub fn checked_sub(a: i128, b: i128) -> Result<i128, Error> {
+    require_non_negative(a)?;
+    require_non_negative(b)?;
+    if b > a {
+        return Err(INVALID_AMOUNT);
+    }
+    Ok(a - b)
+}
+
+/// `a * b / denominator`, rounded down, for non-negative amounts.
+///
+/// The product is taken at 256 bits, so large pools (up to `i128::MAX`) are
+/// scaled exactly; only a quotient that itself exceeds `i128::MAX` fails.
+pub fn mul_div(a: i128, b: i128, denominator: i128) -> Result<i128, Error> {
+    require_non_negative(a)?;
+    require_non_negative(b)?;
+    require_positive(denominator)?;
+    if let Some(product) = a.checked_mul(b) {
+        return Ok(product / denominator);
+    }
+
+    let (high, low) = wide_mul(a as u128, b as u128);
+    let quotient = wide_div(high, low, denominator as u128).ok_or(OVERFLOW)?;
+    i128::try_from(quotient).map_err(|_| OVERFLOW)
+}
+
+/// Full 256-bit product of two `u128` values as `(high, low)` words.
+fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (middle << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Divide the 256-bit value `(high, low)` by `divisor` (below 2^127), or `None`
+/// if the quotient needs more than 128 bits.
+fn wide_div(high: u128, low: u128, divisor: u128) -> Option<u128> {
+    if high >= divisor {
+        return None;
+    }
+    // Shift-subtract long division; the remainder stays below `divisor`, so
+    // doubling it cannot overflow while `divisor < 2^127`.
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if remainder >= divisor {
+            remainder -= divisor;
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
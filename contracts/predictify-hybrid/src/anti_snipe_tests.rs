@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+//! Anti-sniping tests.
+//!
+//! A large bet close to the betting deadline pushes the deadline back, up to
+//! the market's maximum total extension.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::AntiSnipeConfig;
+use crate::PredictifyHybridClient;
+use soroban_sdk::{String, Symbol};
+
+const WINDOW: u64 = 600;
+const EXTENSION: u64 = 300;
+const THRESHOLD: i128 = 100_000_000;
+
+fn create_guarded_market(test: &PredictifyTest, max_extension_seconds: u64) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    client.set_anti_snipe(
+        &test.admin,
+        &market_id,
+        &Some(AntiSnipeConfig {
+            window_seconds: WINDOW,
+            extension_seconds: EXTENSION,
+            threshold_amount: THRESHOLD,
+            max_extension_seconds,
+        }),
+    );
+    market_id
+}
+
+fn bet(test: &PredictifyTest, market_id: &Symbol, amount: i128) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        market_id,
+        &String::from_str(&test.env, "yes"),
+        &amount,
+        &None,
+    );
+}
+
+#[test]
+fn test_large_late_bet_extends_deadline() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_guarded_market(&test, 3 * EXTENSION);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+
+    test.set_time(end_time - 60);
+    bet(&test, &market_id, THRESHOLD);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.end_time, end_time + EXTENSION);
+    assert_eq!(client.get_anti_snipe_extension(&market_id), EXTENSION);
+
+    // Bets keep flowing past the original deadline
+    test.set_time(end_time + 10);
+    bet(&test, &market_id, 10_000_000);
+}
+
+#[test]
+fn test_extensions_stack_up_to_cap() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_guarded_market(&test, 2 * EXTENSION + 100);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+
+    test.set_time(end_time - 1);
+    bet(&test, &market_id, THRESHOLD);
+    test.set_time(end_time + EXTENSION - 1);
+    bet(&test, &market_id, THRESHOLD);
+    test.set_time(end_time + 2 * EXTENSION - 1);
+    bet(&test, &market_id, THRESHOLD);
+
+    // The third extension is cut to what is left of the cap
+    let capped_end = end_time + 2 * EXTENSION + 100;
+    assert_eq!(client.get_market(&market_id).unwrap().end_time, capped_end);
+    assert_eq!(
+        client.get_anti_snipe_extension(&market_id),
+        2 * EXTENSION + 100
+    );
+
+    test.set_time(capped_end - 1);
+    bet(&test, &market_id, THRESHOLD);
+    assert_eq!(client.get_market(&market_id).unwrap().end_time, capped_end);
+
+    test.set_time(capped_end);
+    let user = test.create_funded_user();
+    assert_eq!(
+        client.try_place_bet(
+            &user,
+            &market_id,
+            &String::from_str(&test.env, "yes"),
+            &THRESHOLD,
+            &None,
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+#[test]
+fn test_small_or_early_bets_do_not_extend() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_guarded_market(&test, 3 * EXTENSION);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+
+    // Large, but outside the window
+    test.set_time(end_time - WINDOW - 1);
+    bet(&test, &market_id, THRESHOLD);
+    // Inside the window, but below the threshold
+    test.set_time(end_time - 60);
+    bet(&test, &market_id, THRESHOLD - 1);
+
+    assert_eq!(client.get_market(&market_id).unwrap().end_time, end_time);
+    assert_eq!(client.get_anti_snipe_extension(&market_id), 0);
+}
+
+#[test]
+fn test_set_anti_snipe_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+
+    let config = AntiSnipeConfig {
+        window_seconds: WINDOW,
+        extension_seconds: EXTENSION,
+        threshold_amount: THRESHOLD,
+        max_extension_seconds: EXTENSION - 1,
+    };
+    assert_eq!(
+        client.try_set_anti_snipe(&test.admin, &market_id, &Some(config.clone())),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    bet(&test, &market_id, THRESHOLD);
+    let config = AntiSnipeConfig {
+        max_extension_seconds: EXTENSION,
+        ..config
+    };
+    assert_eq!(
+        client.try_set_anti_snipe(&test.admin, &market_id, &Some(config)),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+    assert_eq!(client.get_anti_snipe(&market_id), None);
+}
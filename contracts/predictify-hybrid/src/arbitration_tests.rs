@@ -0,0 +1,180 @@
+#![cfg(test)]
+
+//! Arbitration tests.
+//!
+//! Result disputes are settled by `Arbitrator` role holders once one is
+//! granted, by the admin otherwise, or by a per-market arbitration contract
+//! through `arbitrate_by_contract`.
+
+use crate::admin::Role;
+use crate::disputes::DisputeStatus;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Symbol};
+
+const STAKE: i128 = 100_0000000;
+const DISPUTE_STAKE: i128 = 10_000_000;
+
+/// Minimal arbitration contract exposing `arbitrate(market_id) -> String`.
+#[contract]
+pub struct MockArbitrationContract;
+
+#[contractimpl]
+impl MockArbitrationContract {
+    pub fn set_ruling(env: Env, outcome: String) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "ruling"), &outcome);
+    }
+
+    pub fn arbitrate(env: Env, _market_id: Symbol) -> String {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "ruling"))
+            .unwrap()
+    }
+}
+
+/// A market resolved to "yes" with an open dispute proposing "no".
+/// Returns (market_id, disputer).
+fn disputed_market(test: &PredictifyTest) -> (Symbol, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let backer = test.create_funded_user();
+    let disputer = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &backer,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    client.place_bet(
+        &disputer,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.dispute_result(
+        &disputer,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &DISPUTE_STAKE,
+    );
+
+    (market_id, disputer)
+}
+
+#[test]
+fn test_arbitrator_settles_disputes_exclusively() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _) = disputed_market(&test);
+    let no = String::from_str(&test.env, "no");
+
+    let arbitrator = Address::generate(&test.env);
+    client.grant_role(&test.admin, &Role::Arbitrator, &arbitrator);
+
+    // With an arbitrator in place the admin can no longer settle
+    assert_eq!(
+        client.try_arbitrate(&test.admin, &market_id, &no),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&test.admin, &market_id, &no, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_arbitrate(
+            &arbitrator,
+            &market_id,
+            &String::from_str(&test.env, "maybe")
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+
+    client.arbitrate(&arbitrator, &market_id, &no);
+    assert_eq!(
+        client.get_result_dispute(&market_id).unwrap().status,
+        DisputeStatus::Resolved
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, no])
+    );
+}
+
+#[test]
+fn test_admin_arbitrates_when_no_arbitrator_is_set() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _) = disputed_market(&test);
+    let yes = String::from_str(&test.env, "yes");
+
+    assert_eq!(
+        client.try_arbitrate(&test.user, &market_id, &yes),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.arbitrate(&test.admin, &market_id, &yes);
+
+    assert_eq!(
+        client.get_result_dispute(&market_id).unwrap().status,
+        DisputeStatus::Rejected
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, yes])
+    );
+}
+
+#[test]
+fn test_arbitration_contract_settles_dispute() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _) = disputed_market(&test);
+    let no = String::from_str(&test.env, "no");
+
+    assert_eq!(
+        client.try_arbitrate_by_contract(&market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let arbiter = test.env.register(MockArbitrationContract, ());
+    MockArbitrationContractClient::new(&test.env, &arbiter).set_ruling(&no);
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_set_arbitration_contract(&outsider, &market_id, &Some(arbiter.clone())),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_arbitration_contract(&test.admin, &market_id, &Some(arbiter.clone()));
+    assert_eq!(client.get_arbitration_contract(&market_id), Some(arbiter));
+
+    // Settlement is delegated: the admin cannot rule directly
+    assert_eq!(
+        client.try_arbitrate(&test.admin, &market_id, &no),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(client.arbitrate_by_contract(&market_id), no);
+    assert_eq!(
+        client.get_result_dispute(&market_id).unwrap().status,
+        DisputeStatus::Resolved
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, no])
+    );
+    assert_eq!(
+        client.try_arbitrate_by_contract(&market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+}
@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use crate::admin::ComplianceManager;
 use crate::errors::Error;
 use crate::events::EventEmitter;
 use crate::markets::MarketUtils;
@@ -51,7 +52,7 @@ impl BalanceManager {
         // but standard Soroban token interface uses transfer(from, to, amount) where 'from' must auth.
         // Since we called user.require_auth(), we can try to transfer.
         // Note: The token contract will check if 'user' signed the tx.
-        token_client.transfer(&user, &env.current_contract_address(), &amount);
+        MarketUtils::receive_tokens(env, &token_client, &user, amount)?;
 
         // Update balance
         let balance = BalanceStorage::add_balance(env, &user, &asset, amount)?;
@@ -86,6 +87,7 @@ impl BalanceManager {
         amount: i128,
     ) -> Result<Balance, Error> {
         user.require_auth();
+        ComplianceManager::require_not_blocked(env, &user)?;
 
         // Validate amount
         InputValidator::validate_balance_amount(&amount).map_err(|_| Error::InvalidInput)?;
@@ -869,6 +869,9 @@ impl BatchTesting {
                 feed_id: String::from_str(env, "BTC"),
                 threshold: 100_000_00, // $100,000
                 comparison: String::from_str(env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         }
     }
@@ -5,7 +5,7 @@
 mod batch_operations_tests {
     use crate::admin::AdminRoleManager;
     use crate::batch_operations::*;
-    use crate::types::OracleProvider;
+    use crate::types::{OracleProvider, OracleSigner};
     use soroban_sdk::{testutils::Address, vec, Env, String, Symbol, Vec};
 
     #[test]
@@ -418,6 +418,9 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         };
 
@@ -439,6 +442,9 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         };
 
@@ -456,6 +462,9 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         };
 
@@ -477,6 +486,9 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         };
 
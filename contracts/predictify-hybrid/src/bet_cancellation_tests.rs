@@ -16,7 +16,10 @@
 #![cfg(test)]
 
 use crate::bets::{BetManager, BetStorage};
-use crate::types::{BetStatus, Market, MarketState, OracleConfig, OracleProvider};
+use crate::storage::DataKey;
+use crate::types::{
+    BetStatus, CancelReason, Market, MarketState, OracleConfig, OracleProvider, OracleSigner,
+};
 use crate::{Error, PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
@@ -55,8 +58,8 @@ impl BetCancellationTestSetup {
 
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         let stellar_client = StellarAssetClient::new(&env, &token_id);
@@ -97,6 +100,9 @@ impl BetCancellationTestSetup {
             feed_id: String::from_str(env, "test_feed"),
             threshold: 100_000_000,
             comparison: String::from_str(env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         };
 
         client.create_market(
@@ -120,6 +126,7 @@ impl BetCancellationTestSetup {
             &self.market_id,
             &String::from_str(&self.env, outcome),
             &amount,
+            &None,
         );
     }
 
@@ -216,7 +223,6 @@ fn test_cancel_bet_updates_outcome_totals() {
 // ===== DEADLINE VALIDATION TESTS =====
 
 #[test]
-#[should_panic(expected = "Error(Contract, #102)")]
 fn test_cancel_bet_after_deadline_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -233,11 +239,10 @@ fn test_cancel_bet_after_deadline_fails() {
     });
 
     // Attempt to cancel - should fail with MarketClosed error
-    client.cancel_bet(&setup.user, &setup.market_id);
+    assert_eq!(client.try_cancel_bet(&setup.user, &setup.market_id), Err(Ok(Error::MarketClosed)));
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #102)")]
 fn test_cancel_bet_exactly_at_deadline_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -254,7 +259,7 @@ fn test_cancel_bet_exactly_at_deadline_fails() {
     });
 
     // Attempt to cancel - should fail
-    client.cancel_bet(&setup.user, &setup.market_id);
+    assert_eq!(client.try_cancel_bet(&setup.user, &setup.market_id), Err(Ok(Error::MarketClosed)));
 }
 
 #[test]
@@ -283,17 +288,18 @@ fn test_cancel_bet_one_second_before_deadline_succeeds() {
 // ===== AUTHORIZATION TESTS =====
 
 #[test]
-#[should_panic(expected = "Error(Contract, #105)")]
 fn test_cancel_bet_no_bet_placed_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
 
     // Attempt to cancel without placing bet - should fail with NothingToClaim
-    client.cancel_bet(&setup.user, &setup.market_id);
+    assert_eq!(
+        client.try_cancel_bet(&setup.user, &setup.market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #105)")]
 fn test_cancel_bet_different_user_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -304,13 +310,15 @@ fn test_cancel_bet_different_user_fails() {
     setup.place_bet(&setup.user, "yes", bet_amount);
 
     // User2 attempts to cancel user1's bet - should fail
-    client.cancel_bet(&setup.user2, &setup.market_id);
+    assert_eq!(
+        client.try_cancel_bet(&setup.user2, &setup.market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
 }
 
 // ===== BET STATUS VALIDATION TESTS =====
 
 #[test]
-#[should_panic(expected = "Error(Contract, #400)")]
 fn test_cancel_already_cancelled_bet_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -322,11 +330,10 @@ fn test_cancel_already_cancelled_bet_fails() {
     client.cancel_bet(&setup.user, &setup.market_id);
 
     // Attempt to cancel again - should fail with InvalidBetStatus
-    client.cancel_bet(&setup.user, &setup.market_id);
+    assert_eq!(client.try_cancel_bet(&setup.user, &setup.market_id), Err(Ok(Error::InvalidState)));
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #400)")]
 fn test_cancel_refunded_bet_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -336,11 +343,12 @@ fn test_cancel_refunded_bet_fails() {
     // Place bet
     setup.place_bet(&setup.user, "yes", bet_amount);
 
-    // Admin cancels event (refunds all bets)
-    client.cancel_event(&setup.admin, &setup.market_id, &None);
+    // Admin cancels event and refunds all bets
+    client.cancel_event(&setup.admin, &setup.market_id, &CancelReason::Administrative, &None);
+    client.process_refunds(&setup.market_id, &0, &10);
 
     // Attempt to cancel refunded bet - should fail
-    client.cancel_bet(&setup.user, &setup.market_id);
+    assert_eq!(client.try_cancel_bet(&setup.user, &setup.market_id), Err(Ok(Error::InvalidState)));
 }
 
 // ===== MULTIPLE BETS TESTS =====
@@ -458,7 +466,6 @@ fn test_cancel_bet_immediately_after_placement() {
 // ===== MARKET STATE VALIDATION =====
 
 #[test]
-#[should_panic(expected = "Error(Contract, #105)")]
 fn test_cancel_bet_nonexistent_market_fails() {
     let setup = BetCancellationTestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -467,7 +474,7 @@ fn test_cancel_bet_nonexistent_market_fails() {
     
     // Attempt to cancel bet on non-existent market
     // Should fail with NothingToClaim since there's no bet
-    client.cancel_bet(&setup.user, &fake_market);
+    assert_eq!(client.try_cancel_bet(&setup.user, &fake_market), Err(Ok(Error::NothingToClaim)));
 }
 
 // ===== INTEGRATION TESTS =====
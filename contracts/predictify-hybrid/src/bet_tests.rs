@@ -7,7 +7,7 @@
 //! - **Happy Path Tests**: Successful bet placement scenarios
 //! - **Validation Tests**: Input validation and error handling
 //! - **Edge Case Tests**: Boundary conditions and special scenarios
-//! - **Security Tests**: Double betting prevention and authentication
+//! - **Security Tests**: Position merging, conflicting outcomes and authentication
 //! - **Integration Tests**: Full bet lifecycle testing
 //! - **Bet Limits Tests**: Comprehensive validation of minimum and maximum bet limits
 //!
@@ -16,9 +16,14 @@
 #![cfg(test)]
 
 use crate::bets::{BetManager, BetStorage, BetValidator, MAX_BET_AMOUNT, MIN_BET_AMOUNT};
-use crate::types::{Bet, BetStats, BetStatus, Market, MarketState, OracleConfig, OracleProvider};
-use crate::{Error, PredictifyHybrid, PredictifyHybridClient};
+use crate::storage::DataKey;
+use crate::types::{
+    Bet, BetStats, BetStatus, Market, MarketState, OracleConfig, OracleProvider, OracleSigner, ReflectorAsset,
+};
+use crate::events::BetPlacedEvent;
+use crate::{Error, PredictifyHybrid, PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
 use soroban_sdk::{
+    symbol_short,
     testutils::{Address as _, Ledger, LedgerInfo},
     token::StellarAssetClient,
     vec, Address, Env, Map, String, Symbol, Vec,
@@ -61,8 +66,8 @@ impl BetTestSetup {
         // Set token for staking in contract storage
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         // Fund users with tokens
@@ -115,6 +120,9 @@ impl BetTestSetup {
                 feed_id: String::from_str(env, "BTC/USD"),
                 threshold: 100_000_00000000, // $100,000
                 comparison: String::from_str(env, "gte"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             &None,
             &86400u64,
@@ -132,8 +140,13 @@ impl BetTestSetup {
     /// Advance time past market end
     fn advance_past_market_end(&self) {
         let market = self.client().get_market(&self.market_id).unwrap();
+        self.set_time(market.end_time + 1);
+    }
+
+    /// Set the ledger timestamp
+    fn set_time(&self, timestamp: u64) {
         self.env.ledger().set(LedgerInfo {
-            timestamp: market.end_time + 1,
+            timestamp,
             protocol_version: 22,
             sequence_number: self.env.ledger().sequence(),
             network_id: Default::default(),
@@ -168,6 +181,7 @@ fn test_place_bet_exactly_minimum() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &MIN_BET_AMOUNT,
+        &None,
     );
 
     assert_eq!(bet.amount, MIN_BET_AMOUNT);
@@ -190,6 +204,7 @@ fn test_place_bet_exactly_maximum() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &MAX_BET_AMOUNT,
+        &None,
     );
 
     assert_eq!(bet.amount, MAX_BET_AMOUNT);
@@ -216,6 +231,7 @@ fn test_place_bet_below_minimum_one_stroop() {
     // Test validator directly - MIN_BET_AMOUNT - 1 should be rejected
     let result = BetValidator::validate_bet_amount(MIN_BET_AMOUNT - 1);
     assert!(result.is_err());
+    assert_eq!(result, Err(Error::BetBelowMinimum));
 
     // Verify error code
     assert_eq!(Error::InsufficientStake as i128, 107);
@@ -226,6 +242,7 @@ fn test_place_bet_below_minimum_half() {
     // Test validator directly - half of minimum should be rejected
     let result = BetValidator::validate_bet_amount(MIN_BET_AMOUNT / 2);
     assert!(result.is_err());
+    assert_eq!(result, Err(Error::BetBelowMinimum));
 
     // Verify error code
     assert_eq!(Error::InsufficientStake as i128, 107);
@@ -239,6 +256,7 @@ fn test_place_bet_zero_amount() {
 
     // Verify error code
     assert_eq!(Error::InsufficientStake as i128, 107);
+    assert_eq!(result, Err(Error::InvalidAmount));
 }
 
 #[test]
@@ -249,6 +267,7 @@ fn test_place_bet_negative_amount() {
 
     // Verify error code
     assert_eq!(Error::InsufficientStake as i128, 107);
+    assert_eq!(result, Err(Error::InvalidAmount));
 }
 
 #[test]
@@ -364,6 +383,7 @@ fn test_place_bet_minimum_with_sufficient_balance() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &MIN_BET_AMOUNT,
+        &None,
     );
 
     assert_eq!(bet.amount, MIN_BET_AMOUNT);
@@ -389,6 +409,7 @@ fn test_place_bet_maximum_with_sufficient_balance() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &MAX_BET_AMOUNT,
+        &None,
     );
 
     assert_eq!(bet.amount, MAX_BET_AMOUNT);
@@ -400,6 +421,26 @@ fn test_place_bet_maximum_with_sufficient_balance() {
     assert_eq!(retrieved_bet.unwrap().amount, MAX_BET_AMOUNT);
 }
 
+#[test]
+fn test_place_bet_insufficient_balance() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let balance = token_client.balance(&setup.user);
+
+    // The shortfall is caught before the token transfer is attempted
+    let result = client.try_place_bet(
+        &setup.user,
+        &setup.market_id,
+        &String::from_str(&setup.env, "yes"),
+        &(balance + 1),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(token_client.balance(&setup.user), balance);
+    assert!(client.get_bet(&setup.market_id, &setup.user).is_none());
+}
+
 #[test]
 fn test_place_bet_below_minimum_rejects_with_error() {
     // Verify error code for below minimum
@@ -408,6 +449,7 @@ fn test_place_bet_below_minimum_rejects_with_error() {
     // Verify validator returns correct error
     let result = BetValidator::validate_bet_amount(MIN_BET_AMOUNT - 1);
     assert!(result.is_err());
+    assert_eq!(result, Err(Error::BetBelowMinimum));
 }
 
 #[test]
@@ -435,6 +477,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &MIN_BET_AMOUNT,
+        &None,
     );
     assert_eq!(bet1.amount, MIN_BET_AMOUNT);
     assert_eq!(bet1.status, BetStatus::Active);
@@ -447,6 +490,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &market2,
         &String::from_str(&setup.env, "yes"),
         &(MIN_BET_AMOUNT + 1_000_000),
+        &None,
     );
     assert_eq!(bet2.amount, MIN_BET_AMOUNT + 1_000_000);
     assert_eq!(bet2.status, BetStatus::Active);
@@ -479,6 +523,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &market3,
         &String::from_str(&setup.env, "yes"),
         &10_000_000,
+        &None,
     );
     assert_eq!(bet3.amount, 10_000_000);
 
@@ -490,6 +535,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &market4,
         &String::from_str(&setup.env, "yes"),
         &50_000_000,
+        &None,
     );
     assert_eq!(bet4.amount, 50_000_000);
 
@@ -501,6 +547,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &market5,
         &String::from_str(&setup.env, "yes"),
         &100_000_000,
+        &None,
     );
     assert_eq!(bet5.amount, 100_000_000);
 
@@ -512,6 +559,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &market6,
         &String::from_str(&setup.env, "yes"),
         &(MAX_BET_AMOUNT - 1_000_000),
+        &None,
     );
     assert_eq!(bet6.amount, MAX_BET_AMOUNT - 1_000_000);
 
@@ -523,6 +571,7 @@ fn test_place_bet_valid_amounts_in_range() {
         &market7,
         &String::from_str(&setup.env, "yes"),
         &MAX_BET_AMOUNT,
+        &None,
     );
     assert_eq!(bet7.amount, MAX_BET_AMOUNT);
     assert_eq!(bet7.status, BetStatus::Active);
@@ -538,6 +587,7 @@ fn test_bet_below_minimum_returns_insufficient_stake() {
     // Verify validator rejects below minimum
     let result = BetValidator::validate_bet_amount(MIN_BET_AMOUNT - 1);
     assert!(result.is_err());
+    assert_eq!(result, Err(Error::BetBelowMinimum));
 }
 
 #[test]
@@ -596,6 +646,7 @@ fn test_multiple_bets_at_different_limits() {
         &market1,
         &String::from_str(&setup.env, "yes"),
         &MIN_BET_AMOUNT,
+        &None,
     );
     assert_eq!(bet1.amount, MIN_BET_AMOUNT);
 
@@ -605,6 +656,7 @@ fn test_multiple_bets_at_different_limits() {
         &market2,
         &String::from_str(&setup.env, "yes"),
         &50_000_000,
+        &None,
     );
     assert_eq!(bet2.amount, 50_000_000);
 
@@ -614,6 +666,7 @@ fn test_multiple_bets_at_different_limits() {
         &market3,
         &String::from_str(&setup.env, "yes"),
         &500_000_000,
+        &None,
     );
     assert_eq!(bet3.amount, 500_000_000);
 
@@ -623,6 +676,7 @@ fn test_multiple_bets_at_different_limits() {
         &market4,
         &String::from_str(&setup.env, "yes"),
         &MAX_BET_AMOUNT,
+        &None,
     );
     assert_eq!(bet4.amount, MAX_BET_AMOUNT);
 }
@@ -662,6 +716,7 @@ fn test_bet_limit_validation_in_place_bet_flow() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &MIN_BET_AMOUNT,
+        &None,
     );
     assert_eq!(bet_min.amount, MIN_BET_AMOUNT);
 
@@ -679,6 +734,7 @@ fn test_bet_limit_validation_in_place_bet_flow() {
         &market2,
         &String::from_str(&setup.env, "yes"),
         &MAX_BET_AMOUNT,
+        &None,
     );
     assert_eq!(bet_max.amount, MAX_BET_AMOUNT);
 }
@@ -715,6 +771,672 @@ fn test_bet_amount_edge_cases_comprehensive() {
     assert!(BetValidator::validate_bet_amount(1_000_000_000).is_ok());
 }
 
+// ===== MINIMUM BET TESTS =====
+
+#[test]
+fn test_place_bet_below_global_min_bet() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    assert_eq!(client.get_min_bet(), MIN_BET_AMOUNT);
+
+    let min_bet = 5 * MIN_BET_AMOUNT;
+    assert_eq!(
+        client.try_set_min_bet(&setup.user, &min_bet),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_min_bet(&setup.admin, &min_bet);
+    assert_eq!(client.get_min_bet(), min_bet);
+    assert_eq!(
+        client.get_effective_bet_limits(&setup.market_id).max_bet,
+        MAX_BET_AMOUNT
+    );
+
+    let yes = String::from_str(&setup.env, "yes");
+    assert_eq!(
+        client.try_place_bet(&setup.user, &setup.market_id, &yes, &(min_bet - 1), &None),
+        Err(Ok(Error::BetBelowMinimum))
+    );
+    let bet = client.place_bet(&setup.user, &setup.market_id, &yes, &min_bet, &None);
+    assert_eq!(bet.amount, min_bet);
+}
+
+#[test]
+fn test_place_bet_below_market_min_bet_override() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let global_min = 2 * MIN_BET_AMOUNT;
+    let market_min = 10 * MIN_BET_AMOUNT;
+    client.set_min_bet(&setup.admin, &global_min);
+    client.set_event_bet_limits(&setup.admin, &setup.market_id, &market_min, &MAX_BET_AMOUNT);
+
+    // Above the global minimum but below the market's own minimum
+    let yes = String::from_str(&setup.env, "yes");
+    assert_eq!(
+        client.try_place_bet(&setup.user, &setup.market_id, &yes, &global_min, &None),
+        Err(Ok(Error::BetBelowMinimum))
+    );
+    client.place_bet(&setup.user, &setup.market_id, &yes, &market_min, &None);
+
+    // Other markets keep the global minimum
+    let market2 =
+        BetTestSetup::create_test_market_static(&setup.env, &setup.contract_id, &setup.admin);
+    let bet = client.place_bet(&setup.user2, &market2, &yes, &global_min, &None);
+    assert_eq!(bet.amount, global_min);
+}
+
+#[test]
+fn test_place_bet_non_positive_amount_is_invalid_amount() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    for amount in [0i128, -1, -MIN_BET_AMOUNT] {
+        assert_eq!(
+            client.try_place_bet(&setup.user, &setup.market_id, &yes, &amount, &None),
+            Err(Ok(Error::InvalidAmount))
+        );
+    }
+    assert_eq!(client.get_market(&setup.market_id).unwrap().position_count(), 0);
+}
+
+// ===== POSITION MERGING TESTS =====
+
+#[test]
+fn test_repeat_bet_same_outcome_merges_position() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+    let balance_before = token_client.balance(&setup.user);
+
+    let first = client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+    let merged = client.place_bet(&setup.user, &setup.market_id, &yes, &5_0000000, &None);
+
+    assert_eq!(merged.amount, 15_0000000);
+    assert_eq!(merged.timestamp, first.timestamp);
+    assert_eq!(
+        client.get_bet(&setup.market_id, &setup.user).unwrap().amount,
+        15_0000000
+    );
+    assert_eq!(token_client.balance(&setup.user), balance_before - 15_0000000);
+
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert_eq!(market.outcome_pool(&yes), 15_0000000);
+    assert_eq!(market.position_count(), 1);
+    assert_eq!(market.total_staked, 15_0000000);
+
+    // One position, one bettor
+    let stats = client.get_market_bet_stats(&setup.market_id);
+    assert_eq!(stats.total_bets, 1);
+    assert_eq!(stats.unique_bettors, 1);
+    assert_eq!(stats.total_amount_locked, 15_0000000);
+    assert_eq!(stats.outcome_totals.get(yes), Some(15_0000000));
+
+    let event = setup.env.as_contract(&setup.contract_id, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .get::<Symbol, BetPlacedEvent>(&symbol_short!("bet_plc"))
+            .unwrap()
+    });
+    assert_eq!(event.amount, 5_0000000);
+    assert_eq!(event.total_stake, 15_0000000);
+}
+
+#[test]
+fn test_repeat_bet_different_outcome_rejected() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    client.place_bet(
+        &setup.user,
+        &setup.market_id,
+        &String::from_str(&setup.env, "yes"),
+        &10_0000000,
+        &None,
+    );
+
+    assert_eq!(
+        client.try_place_bet(
+            &setup.user,
+            &setup.market_id,
+            &String::from_str(&setup.env, "no"),
+            &10_0000000,
+            &None
+        ),
+        Err(Ok(Error::ConflictingOutcome))
+    );
+    let bet = client.get_bet(&setup.market_id, &setup.user).unwrap();
+    assert_eq!(bet.outcome, String::from_str(&setup.env, "yes"));
+    assert_eq!(bet.amount, 10_0000000);
+}
+
+#[test]
+fn test_repeat_bet_merged_position_respects_max_bet() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    client.set_event_bet_limits(&setup.admin, &setup.market_id, &MIN_BET_AMOUNT, &20_0000000);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &15_0000000, &None);
+    assert_eq!(
+        client.try_place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None),
+        Err(Ok(Error::InvalidInput))
+    );
+    let bet = client.place_bet(&setup.user, &setup.market_id, &yes, &5_0000000, &None);
+    assert_eq!(bet.amount, 20_0000000);
+}
+
+// ===== PER-USER EXPOSURE CAP TESTS =====
+
+#[test]
+fn test_max_bet_per_user_exact_boundary() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    client.set_max_bet_per_user(&setup.admin, &setup.market_id, &Some(20_0000000));
+    assert_eq!(
+        client.get_remaining_bet_allowance(&setup.user, &setup.market_id),
+        20_0000000
+    );
+
+    // Merged positions count toward the cap
+    client.place_bet(&setup.user, &setup.market_id, &yes, &15_0000000, &None);
+    assert_eq!(
+        client.get_remaining_bet_allowance(&setup.user, &setup.market_id),
+        5_0000000
+    );
+    assert_eq!(
+        client.try_place_bet(&setup.user, &setup.market_id, &yes, &(5_0000000 + 1), &None),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+    let bet = client.place_bet(&setup.user, &setup.market_id, &yes, &5_0000000, &None);
+    assert_eq!(bet.amount, 20_0000000);
+    assert_eq!(
+        client.get_remaining_bet_allowance(&setup.user, &setup.market_id),
+        0
+    );
+    assert_eq!(
+        client.try_place_bet(&setup.user, &setup.market_id, &yes, &MIN_BET_AMOUNT, &None),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+
+    // The cap is per user; batched entries for one market add up
+    assert_eq!(
+        client.get_remaining_bet_allowance(&setup.user2, &setup.market_id),
+        20_0000000
+    );
+    let batch = vec![
+        &setup.env,
+        (setup.market_id.clone(), yes.clone(), 10_0000000),
+        (setup.market_id.clone(), yes.clone(), 10_0000000 + 1),
+    ];
+    assert_eq!(
+        client.try_place_bets(&setup.user2, &batch),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+    assert!(client.get_bet(&setup.market_id, &setup.user2).is_none());
+}
+
+#[test]
+fn test_max_bet_per_user_default_and_override() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    assert_eq!(client.get_max_bet_per_user(&setup.market_id), None);
+
+    client.set_default_max_bet_per_user(&setup.admin, &Some(10_0000000));
+    assert_eq!(
+        client.get_max_bet_per_user(&setup.market_id),
+        Some(10_0000000)
+    );
+
+    // A market's own cap takes precedence over the default
+    assert_eq!(
+        client.try_set_max_bet_per_user(&setup.user, &setup.market_id, &Some(30_0000000)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_max_bet_per_user(
+            &setup.admin,
+            &setup.market_id,
+            &Some(MAX_BET_AMOUNT + 1)
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_max_bet_per_user(&setup.admin, &setup.market_id, &Some(30_0000000));
+    assert_eq!(
+        client.get_max_bet_per_user(&setup.market_id),
+        Some(30_0000000)
+    );
+    client.place_bet(&setup.user, &setup.market_id, &yes, &30_0000000, &None);
+    assert_eq!(
+        client.try_set_max_bet_per_user(&setup.admin, &setup.market_id, &None),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+
+    // Other markets fall back to the default
+    let other =
+        BetTestSetup::create_test_market_static(&setup.env, &setup.contract_id, &setup.admin);
+    assert_eq!(
+        client.try_place_bet(&setup.user, &other, &yes, &(10_0000000 + 1), &None),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+    client.place_bet(&setup.user, &other, &yes, &10_0000000, &None);
+
+    client.set_default_max_bet_per_user(&setup.admin, &None);
+    assert_eq!(client.get_max_bet_per_user(&other), None);
+}
+
+// ===== POOL CAP TESTS =====
+
+#[test]
+fn test_max_pool_size_fills_exactly_to_cap() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+    assert_eq!(
+        client.get_market_stats(&setup.market_id).remaining_capacity,
+        None
+    );
+    client.set_max_pool_size(&setup.admin, &setup.market_id, &Some(30_0000000));
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &20_0000000, &None);
+    assert_eq!(
+        client.get_market_stats(&setup.market_id).remaining_capacity,
+        Some(10_0000000)
+    );
+
+    // One stroop over is rejected whole, not partially filled
+    assert_eq!(
+        client.try_place_bet(&setup.user2, &setup.market_id, &no, &(10_0000000 + 1), &None),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+    assert!(client.get_bet(&setup.market_id, &setup.user2).is_none());
+
+    client.place_bet(&setup.user2, &setup.market_id, &no, &10_0000000, &None);
+    let stats = client.get_market_stats(&setup.market_id);
+    assert_eq!(stats.total_staked, 30_0000000);
+    assert_eq!(stats.remaining_capacity, Some(0));
+    assert_eq!(
+        client.try_place_bet(&setup.user, &setup.market_id, &yes, &MIN_BET_AMOUNT, &None),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+}
+
+#[test]
+fn test_max_pool_size_validation() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+
+    assert_eq!(
+        client.try_set_max_pool_size(&setup.user, &setup.market_id, &Some(30_0000000)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_max_pool_size(&setup.admin, &setup.market_id, &Some(0)),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // The cap may not sit below the minimum pool size
+    client.set_global_min_pool_size(&setup.admin, &40_0000000);
+    assert_eq!(
+        client.try_set_max_pool_size(&setup.admin, &setup.market_id, &Some(40_0000000 - 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_max_pool_size(&setup.admin, &setup.market_id, &Some(40_0000000));
+    assert_eq!(
+        client.get_market(&setup.market_id).unwrap().max_pool_size,
+        Some(40_0000000)
+    );
+
+    client.place_bet(
+        &setup.user,
+        &setup.market_id,
+        &String::from_str(&setup.env, "yes"),
+        &10_0000000,
+        &None,
+    );
+    assert_eq!(
+        client.try_set_max_pool_size(&setup.admin, &setup.market_id, &None),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+}
+
+#[test]
+fn test_distribute_payouts_uses_merged_stake() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    // user holds 2x user2's winning stake, built from two bets
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+    client.place_bet(&setup.user2, &setup.market_id, &yes, &10_0000000, &None);
+    client.place_bet(
+        &setup.admin,
+        &setup.market_id,
+        &String::from_str(&setup.env, "no"),
+        &30_0000000,
+        &None,
+    );
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    let market = client.get_market(&setup.market_id).unwrap();
+    setup.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: setup.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let (paid, _) = client.distribute_payouts(&setup.market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+
+    // Markets without a token credit winnings to the internal balance
+    let user_payout = client.get_balance(&setup.user, &ReflectorAsset::Stellar).amount;
+    let user2_payout = client.get_balance(&setup.user2, &ReflectorAsset::Stellar).amount;
+    assert!(user2_payout > 0);
+    assert_eq!(user_payout, 2 * user2_payout);
+    assert_eq!(paid, user_payout + user2_payout);
+}
+
+// ===== WITHDRAWAL TESTS =====
+
+#[test]
+fn test_withdraw_bet_refunds_stake_minus_penalty() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+    let yes = String::from_str(&setup.env, "yes");
+    assert_eq!(client.get_early_exit_penalty(), 100);
+
+    let user_before = token_client.balance(&setup.user);
+    client.place_bet(&setup.user, &setup.market_id, &yes, &100_0000000, &None);
+    assert_eq!(token_client.balance(&setup.contract_id), 100_0000000);
+
+    // 1% of 100 XLM stays in the contract as vault fees
+    let refunded = client.withdraw_bet(&setup.user, &setup.market_id);
+    assert_eq!(refunded, 99_0000000);
+    assert_eq!(token_client.balance(&setup.user), user_before - 1_0000000);
+    assert_eq!(token_client.balance(&setup.contract_id), 1_0000000);
+    assert_eq!(client.get_fee_vault_balance(&None), 1_0000000);
+
+    let bet = client.get_bet(&setup.market_id, &setup.user).unwrap();
+    assert_eq!(bet.status, BetStatus::Cancelled);
+    let market = client.get_market(&setup.market_id).unwrap();
+    assert_eq!(market.position_count(), 0);
+    assert_eq!(market.total_staked, 0);
+    let stats = client.get_market_bet_stats(&setup.market_id);
+    assert_eq!(stats.total_bets, 0);
+    assert_eq!(stats.total_amount_locked, 0);
+
+    // A withdrawn position cannot be withdrawn again
+    assert_eq!(
+        client.try_withdraw_bet(&setup.user, &setup.market_id),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_withdrawn_bet_is_not_paid_out() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+    client.place_bet(&setup.user2, &setup.market_id, &yes, &10_0000000, &None);
+    client.place_bet(
+        &setup.admin,
+        &setup.market_id,
+        &String::from_str(&setup.env, "no"),
+        &10_0000000,
+        &None,
+    );
+    client.withdraw_bet(&setup.user, &setup.market_id);
+
+    setup.advance_past_market_end();
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    let market = client.get_market(&setup.market_id).unwrap();
+    setup.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: setup.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let (paid, _) = client.distribute_payouts(&setup.market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(
+        client.get_balance(&setup.user2, &ReflectorAsset::Stellar).amount,
+        paid
+    );
+    assert_eq!(
+        client.get_balance(&setup.user, &ReflectorAsset::Stellar).amount,
+        0
+    );
+    assert!(!client.has_claimed(&setup.market_id, &setup.user));
+    assert_eq!(
+        client.get_bet(&setup.market_id, &setup.user).unwrap().status,
+        BetStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_withdraw_bet_after_deadline_or_resolution_fails() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+
+    assert_eq!(
+        client.try_withdraw_bet(&setup.user, &setup.market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+    setup.advance_past_market_end();
+    assert_eq!(
+        client.try_withdraw_bet(&setup.user, &setup.market_id),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    client.resolve_market_manual(&setup.admin, &setup.market_id, &yes);
+    assert_eq!(
+        client.try_withdraw_bet(&setup.user, &setup.market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_set_early_exit_penalty() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let token_client = soroban_sdk::token::Client::new(&setup.env, &setup.token_id);
+
+    assert_eq!(
+        client.try_set_early_exit_penalty(&setup.user, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_early_exit_penalty(&setup.admin, &10_001),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // 5% penalty, recorded in the admin log
+    client.set_early_exit_penalty(&setup.admin, &500);
+    assert_eq!(client.get_early_exit_penalty(), 500);
+    let last = (client.get_admin_log_count() - 1) as u32;
+    let entry = client.get_admin_log(&last, &1).get(0).unwrap();
+    assert_eq!(entry.action, soroban_sdk::symbol_short!("exit_pen"));
+    let user_before = token_client.balance(&setup.user);
+    client.place_bet(
+        &setup.user,
+        &setup.market_id,
+        &String::from_str(&setup.env, "yes"),
+        &20_0000000,
+        &None,
+    );
+    assert_eq!(client.withdraw_bet(&setup.user, &setup.market_id), 19_0000000);
+    assert_eq!(token_client.balance(&setup.user), user_before - 1_0000000);
+    assert_eq!(client.get_fee_vault_balance(&None), 1_0000000);
+}
+
+// ===== BET DEADLINE TESTS =====
+
+/// Create a yes/no market lasting `duration_days` that stops taking bets
+/// `deadline_mins` minutes before it ends.
+fn try_create_market_with_bet_deadline(
+    setup: &BetTestSetup,
+    duration_days: u32,
+    deadline_mins: Option<u32>,
+) -> Result<Symbol, Error> {
+    setup
+        .client()
+        .try_create_market(
+            &setup.admin,
+            &String::from_str(&setup.env, "Will ETH flip BTC this month?"),
+            &vec![
+                &setup.env,
+                String::from_str(&setup.env, "yes"),
+                String::from_str(&setup.env, "no"),
+            ],
+            &duration_days,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&setup.env),
+                feed_id: String::from_str(&setup.env, "ETH/BTC"),
+                threshold: 1,
+                comparison: String::from_str(&setup.env, "gte"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &86400u64,
+            &None,
+            &deadline_mins,
+            &None,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_bets_close_at_bet_deadline() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let market_id = try_create_market_with_bet_deadline(&setup, 30, Some(30)).unwrap();
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    assert_eq!(client.get_market(&market_id).unwrap().bet_deadline, end_time - 30 * 60);
+
+    // 31 minutes before the end: still open
+    setup.set_time(end_time - 31 * 60);
+    client.place_bet(&setup.user, &market_id, &yes, &10_0000000, &None);
+
+    // 29 minutes before the end: inside the 30-minute deadline window
+    setup.set_time(end_time - 29 * 60);
+    assert_eq!(
+        client.try_place_bet(&setup.user2, &market_id, &yes, &10_0000000, &None),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+#[test]
+fn test_bets_without_deadline_close_at_end_time() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let end_time = client.get_market(&setup.market_id).unwrap().end_time;
+
+    setup.set_time(end_time - 1);
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+
+    setup.set_time(end_time);
+    assert_eq!(
+        client.try_place_bet(&setup.user2, &setup.market_id, &yes, &10_0000000, &None),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+#[test]
+fn test_bet_deadline_must_be_shorter_than_market_duration() {
+    let setup = BetTestSetup::new();
+    let one_day_mins = 24 * 60;
+
+    assert!(try_create_market_with_bet_deadline(&setup, 1, Some(one_day_mins - 1)).is_ok());
+    assert_eq!(
+        try_create_market_with_bet_deadline(&setup, 1, Some(one_day_mins)),
+        Err(Error::InvalidDuration)
+    );
+    assert_eq!(
+        try_create_market_with_bet_deadline(&setup, 1, Some(30 * one_day_mins)),
+        Err(Error::InvalidDuration)
+    );
+    assert_eq!(
+        try_create_market_with_bet_deadline(&setup, 1, Some(0)),
+        Err(Error::InvalidDuration)
+    );
+}
+
+// ===== MARKET STATS TESTS =====
+
+#[test]
+fn test_market_stats_track_bets_on_both_outcomes() {
+    let setup = BetTestSetup::new();
+    let client = setup.client();
+    let yes = String::from_str(&setup.env, "yes");
+    let no = String::from_str(&setup.env, "no");
+
+    let stats = client.get_market_stats(&setup.market_id);
+    assert_eq!(stats.total_staked, 0);
+    assert_eq!(stats.outcome_pools.get(yes.clone()), Some(0));
+    assert_eq!(stats.outcome_pools.get(no.clone()), Some(0));
+    assert_eq!(stats.payout_multipliers.get(yes.clone()), Some(0));
+    assert_eq!(stats.total_bets, 0);
+
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+    client.place_bet(&setup.user2, &setup.market_id, &no, &30_0000000, &None);
+    // A top-up adds to the pool without adding a bettor or a bet
+    client.place_bet(&setup.user, &setup.market_id, &yes, &10_0000000, &None);
+
+    // Pool 50 XLM: yes 20, no 30, 2% fee
+    let stats = client.get_market_stats(&setup.market_id);
+    assert_eq!(stats.total_staked, 50_0000000);
+    assert_eq!(stats.outcome_pools.get(yes.clone()), Some(20_0000000));
+    assert_eq!(stats.outcome_pools.get(no.clone()), Some(30_0000000));
+    assert_eq!(stats.unique_bettors, 2);
+    assert_eq!(stats.total_bets, 2);
+    assert_eq!(stats.payout_multipliers.get(yes.clone()), Some(245));
+    assert_eq!(stats.payout_multipliers.get(no.clone()), Some(163));
+
+    let user3 = Address::generate(&setup.env);
+    StellarAssetClient::new(&setup.env, &setup.token_id).mint(&user3, &100_0000000);
+    client.place_bet(&user3, &setup.market_id, &no, &50_0000000, &None);
+
+    // Pool 100 XLM: yes 20, no 80
+    let stats = client.get_market_stats(&setup.market_id);
+    assert_eq!(stats.total_staked, 100_0000000);
+    assert_eq!(stats.outcome_pools.get(no.clone()), Some(80_0000000));
+    assert_eq!(stats.unique_bettors, 3);
+    assert_eq!(stats.total_bets, 3);
+    assert_eq!(stats.payout_multipliers.get(yes), Some(490));
+    assert_eq!(stats.payout_multipliers.get(no), Some(122));
+
+    assert_eq!(
+        client.try_get_market_stats(&Symbol::new(&setup.env, "missing")),
+        Err(Ok(Error::MarketNotFound))
+    );
+}
+
 // ===== CONFIGURABLE LIMITS TESTS =====
 // NOTE: These tests require set_global_bet_limits and set_event_bet_limits
 // functions to be implemented in the contract. If these functions don't exist,
@@ -744,6 +1466,7 @@ fn test_set_global_bet_limits_and_place_bet_exactly_min_max() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &min,
+        &None,
     );
     assert_eq!(bet_min.amount, min);
 
@@ -756,6 +1479,7 @@ fn test_set_global_bet_limits_and_place_bet_exactly_min_max() {
         &setup.market_id,
         &String::from_str(&setup.env, "no"),
         &max,
+        &None,
     );
     assert_eq!(bet_max.amount, max);
 }
@@ -777,6 +1501,7 @@ fn test_place_bet_below_configured_min_rejects() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &(min - 1),
+        &None,
     );
 }
 
@@ -797,6 +1522,7 @@ fn test_place_bet_above_configured_max_rejects() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &(max + 1),
+        &None,
     );
 }
 
@@ -821,6 +1547,7 @@ fn test_set_event_bet_limits_overrides_global() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &event_min,
+        &None,
     );
     assert_eq!(bet.amount, event_min);
 }
@@ -842,6 +1569,7 @@ fn test_place_bet_below_event_min_rejects() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &(event_min - 1),
+        &None,
     );
 }
 
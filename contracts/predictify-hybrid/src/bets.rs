@@ -8,7 +8,7 @@
 //! - **Bet Placement**: Users can place bets on active markets
 //! - **Fund Locking**: User funds are locked in the contract until resolution
 //! - **Bet Tracking**: Tracks bet amount and selected outcome per user
-//! - **Double Betting Prevention**: Prevents users from betting twice on the same market
+//! - **Position Merging**: Repeat bets on the same outcome add to one position per user
 //! - **Validation**: Comprehensive validation for market state, outcomes, and balances
 //! - **Event Emission**: Emits bet placement events for transparency
 //!
@@ -21,29 +21,58 @@
 
 use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
 
+use crate::admin::ComplianceManager;
+use crate::amounts;
+use crate::config::ConfigManager;
+use crate::credits::CreditManager;
 use crate::errors::Error;
+use crate::escrow::EscrowManager;
 use crate::events::EventEmitter;
-use crate::markets::{MarketStateManager, MarketUtils, MarketValidator};
+use crate::extensions::AntiSnipeManager;
+use crate::markets::{MarketAccessManager, MarketStateManager, MarketUtils, MarketValidator};
 use crate::reentrancy_guard::ReentrancyGuard;
-use crate::types::{Bet, BetLimits, BetStats, BetStatus, Market, MarketState};
+use crate::statistics::StatisticsManager;
+use crate::storage::{DataKey, TtlManager};
+use crate::types::{Bet, BetLimits, BetStats, BetStatus, Market, MarketState, MarketStats};
 use crate::validation;
 
 // ===== CONSTANTS =====
 
-/// Minimum bet amount (0.1 XLM = 1,000,000 stroops). Absolute floor for any configured limit.
+/// Minimum bet amount (0.1 XLM = 1,000,000 stroops). Absolute floor for any configured limit,
+/// which like this constant is written at `DEFAULT_TOKEN_DECIMALS`.
 pub const MIN_BET_AMOUNT: i128 = 1_000_000;
 
-/// Maximum bet amount (10,000 XLM = 100,000,000,000 stroops). Absolute ceiling for any configured limit.
+/// Maximum bet amount (10,000 XLM = 100,000,000,000 stroops). Absolute ceiling for any configured limit,
+/// which like this constant is written at `DEFAULT_TOKEN_DECIMALS`.
 pub const MAX_BET_AMOUNT: i128 = 100_000_000_000;
 
+/// Default early-exit penalty for `withdraw_bet`, in basis points of the stake (1%).
+pub const DEFAULT_EARLY_EXIT_PENALTY_BPS: u32 = 100;
+
+/// Default fee on `cash_out`, in basis points of the cash-out value (2%).
+pub const DEFAULT_CASH_OUT_FEE_BPS: u32 = 200;
+
+/// `cash_out` closes this long before the betting deadline (1 hour), so positions
+/// cannot be cashed out once the outcome may already be known.
+pub const CASH_OUT_CUTOFF_SECONDS: u64 = 60 * 60;
+
 /// Storage key for global bet limits.
 const GLOBAL_BET_LIMITS_KEY: &str = "bet_limits_global";
 /// Storage key for per-event bet limits map (Symbol -> BetLimits).
 const PER_EVENT_BET_LIMITS_KEY: &str = "bet_limits_evt";
+/// Storage key for the early-exit penalty (basis points).
+const EARLY_EXIT_PENALTY_KEY: &str = "exit_penalty";
+/// Storage key for the cash-out fee (basis points).
+const CASH_OUT_FEE_KEY: &str = "cash_out_fee";
+/// Storage key for the global per-user exposure cap.
+const GLOBAL_USER_CAP_KEY: &str = "user_cap_global";
+/// Storage key for per-event per-user exposure caps (Symbol -> i128).
+const PER_EVENT_USER_CAP_KEY: &str = "user_cap_evt";
 
 // ===== STORAGE KEY TYPES =====
 
-/// Storage key for user bets on a specific market
+/// Legacy storage key for user bets, replaced by `DataKey::Bet`.
+/// Only read by `migrate_storage` for instances deployed before the switch.
 #[contracttype]
 #[derive(Clone)]
 pub struct BetKey {
@@ -58,7 +87,8 @@ pub struct MarketBetsKey {
     pub market_id: Symbol,
 }
 
-/// Storage key for market bet registry
+/// Storage key of the bettor list kept as a single vector before bettors
+/// were listed under `DataKey::Bettor`; only read by `migrate_storage`
 #[contracttype]
 #[derive(Clone)]
 pub struct BetRegistryKey {
@@ -67,8 +97,13 @@ pub struct BetRegistryKey {
 }
 
 // ===== BET LIMITS STORAGE =====
+//
+// Limits and caps are stored as configured, at `DEFAULT_TOKEN_DECIMALS`, and
+// converted to base units of the staking token when read, so they keep their
+// whole-token value across a switch to a token with other decimals.
 
-/// Get effective bet limits for a market: per-event if set, else global, else default constants.
+/// Get effective bet limits for a market, in base units of the staking token:
+/// per-event if set, else global, else default constants.
 pub fn get_effective_bet_limits(env: &Env, market_id: &Symbol) -> BetLimits {
     let key_evt = Symbol::new(env, PER_EVENT_BET_LIMITS_KEY);
     let per_event: soroban_sdk::Map<Symbol, BetLimits> = env
@@ -77,8 +112,19 @@ pub fn get_effective_bet_limits(env: &Env, market_id: &Symbol) -> BetLimits {
         .get(&key_evt)
         .unwrap_or(soroban_sdk::Map::new(env));
     if let Some(limits) = per_event.get(market_id.clone()) {
-        return limits;
+        return limits_in_token_units(env, &limits);
     }
+    get_global_bet_limits(env)
+}
+
+/// Get global bet limits in base units of the staking token, or the default
+/// constants when none are configured.
+pub fn get_global_bet_limits(env: &Env) -> BetLimits {
+    limits_in_token_units(env, &get_configured_global_bet_limits(env))
+}
+
+/// Get global bet limits as configured, at `DEFAULT_TOKEN_DECIMALS`.
+pub fn get_configured_global_bet_limits(env: &Env) -> BetLimits {
     let key_global = Symbol::new(env, GLOBAL_BET_LIMITS_KEY);
     env.storage()
         .persistent()
@@ -89,7 +135,14 @@ pub fn get_effective_bet_limits(env: &Env, market_id: &Symbol) -> BetLimits {
         })
 }
 
-/// Set global bet limits (admin only; validation of bounds done by caller).
+fn limits_in_token_units(env: &Env, limits: &BetLimits) -> BetLimits {
+    BetLimits {
+        min_bet: ConfigManager::to_token_units(env, limits.min_bet),
+        max_bet: ConfigManager::to_token_units(env, limits.max_bet),
+    }
+}
+
+/// Set global bet limits at `DEFAULT_TOKEN_DECIMALS` (admin only; checked by caller).
 pub fn set_global_bet_limits(env: &Env, limits: &BetLimits) -> Result<(), Error> {
     validate_limits_bounds(limits)?;
     let key = Symbol::new(env, GLOBAL_BET_LIMITS_KEY);
@@ -97,7 +150,7 @@ pub fn set_global_bet_limits(env: &Env, limits: &BetLimits) -> Result<(), Error>
     Ok(())
 }
 
-/// Set per-event bet limits (admin only; validation of bounds done by caller).
+/// Set per-event bet limits at `DEFAULT_TOKEN_DECIMALS` (admin only; checked by caller).
 pub fn set_event_bet_limits(
     env: &Env,
     market_id: &Symbol,
@@ -115,6 +168,117 @@ pub fn set_event_bet_limits(
     Ok(())
 }
 
+// ===== PER-USER EXPOSURE CAP STORAGE =====
+
+/// Get the most a single user may hold on a market, in base units of the
+/// staking token: per-event cap if set, else the global default, else `None`
+/// (only `max_bet` applies).
+pub fn get_effective_max_bet_per_user(env: &Env, market_id: &Symbol) -> Option<i128> {
+    let per_event: Map<Symbol, i128> = env
+        .storage()
+        .persistent()
+        .get(&Symbol::new(env, PER_EVENT_USER_CAP_KEY))
+        .unwrap_or(Map::new(env));
+    per_event
+        .get(market_id.clone())
+        .or_else(|| {
+            env.storage()
+                .persistent()
+                .get(&Symbol::new(env, GLOBAL_USER_CAP_KEY))
+        })
+        .map(|cap| ConfigManager::to_token_units(env, cap))
+}
+
+/// Set or clear the global per-user exposure cap at `DEFAULT_TOKEN_DECIMALS`
+/// (admin only; checked by caller).
+pub fn set_global_max_bet_per_user(env: &Env, cap: Option<i128>) -> Result<(), Error> {
+    let key = Symbol::new(env, GLOBAL_USER_CAP_KEY);
+    match cap {
+        Some(cap) => {
+            validate_user_cap(cap)?;
+            env.storage().persistent().set(&key, &cap);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+    Ok(())
+}
+
+/// Set or clear a market's per-user exposure cap at `DEFAULT_TOKEN_DECIMALS`
+/// (authorization checked by caller).
+pub fn set_event_max_bet_per_user(
+    env: &Env,
+    market_id: &Symbol,
+    cap: Option<i128>,
+) -> Result<(), Error> {
+    let key = Symbol::new(env, PER_EVENT_USER_CAP_KEY);
+    let mut per_event: Map<Symbol, i128> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Map::new(env));
+    match cap {
+        Some(cap) => {
+            validate_user_cap(cap)?;
+            per_event.set(market_id.clone(), cap);
+        }
+        None => {
+            per_event.remove(market_id.clone());
+        }
+    }
+    env.storage().persistent().set(&key, &per_event);
+    Ok(())
+}
+
+/// A cap must lie within the absolute bet bounds.
+fn validate_user_cap(cap: i128) -> Result<(), Error> {
+    if !(MIN_BET_AMOUNT..=MAX_BET_AMOUNT).contains(&cap) {
+        return Err(Error::InvalidInput);
+    }
+    Ok(())
+}
+
+// ===== EARLY EXIT PENALTY STORAGE =====
+
+/// Get the early-exit penalty charged by `withdraw_bet`, in basis points.
+pub fn get_early_exit_penalty_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&Symbol::new(env, EARLY_EXIT_PENALTY_KEY))
+        .unwrap_or(DEFAULT_EARLY_EXIT_PENALTY_BPS)
+}
+
+/// Set the early-exit penalty (admin only; checked by caller). Must be at most 10_000 bps.
+pub fn set_early_exit_penalty_bps(env: &Env, penalty_bps: u32) -> Result<(), Error> {
+    if penalty_bps > 10_000 {
+        return Err(Error::InvalidInput);
+    }
+    env.storage()
+        .persistent()
+        .set(&Symbol::new(env, EARLY_EXIT_PENALTY_KEY), &penalty_bps);
+    Ok(())
+}
+
+// ===== CASH-OUT FEE STORAGE =====
+
+/// Get the fee charged by `cash_out`, in basis points.
+pub fn get_cash_out_fee_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&Symbol::new(env, CASH_OUT_FEE_KEY))
+        .unwrap_or(DEFAULT_CASH_OUT_FEE_BPS)
+}
+
+/// Set the cash-out fee (admin only; checked by caller). Must be at most 10_000 bps.
+pub fn set_cash_out_fee_bps(env: &Env, fee_bps: u32) -> Result<(), Error> {
+    if fee_bps > 10_000 {
+        return Err(Error::InvalidInput);
+    }
+    env.storage()
+        .persistent()
+        .set(&Symbol::new(env, CASH_OUT_FEE_KEY), &fee_bps);
+    Ok(())
+}
+
 /// Validate that min <= max and both are within absolute bounds.
 fn validate_limits_bounds(limits: &BetLimits) -> Result<(), Error> {
     if limits.min_bet > limits.max_bet {
@@ -205,28 +369,36 @@ impl BetManager {
     ///
     /// # Returns
     ///
-    /// Returns `Ok(Bet)` on success with the created bet details,
-    /// or `Err(Error)` if validation fails.
+    /// Returns `Ok(Bet)` on success with the user's position on the market,
+    /// or `Err(Error)` if validation fails. If the user already holds an active
+    /// position on the same outcome, `amount` is added to it and the merged bet is
+    /// returned.
     ///
     /// # Errors
     ///
+    /// - `Error::AddressBlocked` - User is blocked
+    /// - `Error::Unauthorized` - The market is private and the user is not on its allowlist
     /// - `Error::MarketNotFound` - Market does not exist
     /// - `Error::MarketClosed` - Market has ended or is not active
     /// - `Error::MarketResolved` - Market has already been resolved
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
-    /// - `Error::InsufficientStake` - Bet amount below minimum
+    /// - `Error::ConflictingOutcome` - User already holds a position on a different outcome
+    /// - `Error::InvalidInput` - Merged position would exceed the maximum bet
+    /// - `Error::ThresholdTooHigh` - Position would exceed the per-user exposure cap,
+    ///   the pool would exceed the market's `max_pool_size`, or a total would overflow
+    /// - `Error::BetBelowMinimum` - Bet amount below minimum
+    /// - `Error::InvalidAmount` - Bet amount is zero or negative
     /// - `Error::InvalidOutcome` - Selected outcome not valid for this market
     /// - `Error::InsufficientBalance` - User doesn't have enough funds
-    /// - `Error::Unauthorized` - User not on allowlist for private event
+    /// - `Error::Unauthorized` - User not on the allowlist of a private market
     ///
     /// # Security
     ///
     /// - Requires user authentication via `require_auth()`
     /// - Validates market state before accepting bet
-    /// - Validates user has not already bet on this market
+    /// - Rejects bets on a different outcome than the user's existing position
     /// - Validates user has sufficient balance
     /// - Locks funds atomically with bet creation
-    /// - Enforces allowlist for private events
+    /// - Enforces the allowlist of private markets
     ///
     /// # Example
     ///
@@ -245,9 +417,42 @@ impl BetManager {
         market_id: Symbol,
         outcome: String,
         amount: i128,
+    ) -> Result<Bet, Error> {
+        Self::place_bet_funded(env, user, market_id, outcome, amount, false)
+    }
+
+    /// `place_bet` paid with the user's free-bet credit instead of tokens.
+    ///
+    /// Only markets on the global staking token accept credit. The stake joins
+    /// the pools normally, but its credited principal is returned to the fee
+    /// vault when the position is settled (see `CreditManager::settle`).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InsufficientBalance` - Credit is missing, expired or too small
+    /// - `Error::InvalidState` - Market is denominated in its own token
+    /// - Any error from `place_bet`
+    pub fn place_bet_with_credit(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> Result<Bet, Error> {
+        Self::place_bet_funded(env, user, market_id, outcome, amount, true)
+    }
+
+    fn place_bet_funded(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+        use_credit: bool,
     ) -> Result<Bet, Error> {
         // Require authentication from the user
         user.require_auth();
+        ComplianceManager::require_not_blocked(env, &user)?;
 
         // Note: Event visibility checking is disabled to avoid deserialization issues
         // when markets and events share the same ID space. Events should use a different
@@ -256,45 +461,76 @@ impl BetManager {
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
         BetValidator::validate_market_for_betting(env, &market)?;
+        MarketAccessManager::require_allowed(env, &market_id, &user)?;
 
         // Validate bet parameters (uses configurable min/max limits per event or global)
         BetValidator::validate_bet_parameters(env, &market_id, &outcome, &market.outcomes, amount)?;
 
-        // Check if user has already bet on this market
-        if Self::has_user_bet(env, &market_id, &user) {
-            return Err(Error::AlreadyBet);
-        }
+        // A repeat bet on the same outcome tops up the existing position
+        let existing = Self::get_mergeable_position(env, &market_id, &user, &outcome, amount)?;
+        let new_position = existing.is_none();
+        BetValidator::validate_pool_capacity(&market, amount)?;
 
-        // Lock funds (transfer from user to contract)
-        BetUtils::lock_funds(env, &user, amount)?;
+        // Lock funds (transfer from user to contract), or spend credit backed by
+        // the admin's deposit
+        if use_credit {
+            if market.token.is_some() {
+                return Err(Error::InvalidState);
+            }
+            CreditManager::spend(env, &market_id, &user, amount)?;
+        } else {
+            BetUtils::lock_funds(env, &market, &user, amount)?;
+        }
 
-        // Create bet
-        let bet = Bet::new(
-            env,
-            user.clone(),
-            market_id.clone(),
-            outcome.clone(),
-            amount,
-        );
+        // Create bet or merge into the existing one
+        let bet = match existing {
+            Some(mut bet) => {
+                bet.amount = amounts::checked_add(bet.amount, amount)?;
+                bet
+            }
+            None => Bet::new(
+                env,
+                user.clone(),
+                market_id.clone(),
+                outcome.clone(),
+                amount,
+            ),
+        };
 
         // Store bet
         BetStorage::store_bet(env, &bet)?;
 
         // Update market betting stats
-        Self::update_market_bet_stats(env, &market_id, &outcome, amount)?;
+        Self::update_market_bet_stats(env, &market_id, &outcome, amount, new_position)?;
 
         // Update market's total staked (for payout pool calculation)
-        market.total_staked += amount;
+        market.total_staked = amounts::checked_add(market.total_staked, amount)?;
+        StatisticsManager::record_stake_escrowed(env, amount);
+        EscrowManager::credit(env, &market_id, amount)?;
+        if new_position {
+            StatisticsManager::record_market_joined(env, &user, &market_id);
+        }
 
-        // Also update votes and stakes for backward compatibility with payout distribution
-        // This allows distribute_payouts to work with both bets and votes
-        market.votes.set(user.clone(), outcome.clone());
-        market.stakes.set(user.clone(), amount);
+        // Keep the outcome totals payouts are computed from in step
+        if new_position {
+            market.add_position(&outcome, amount);
+        } else {
+            market.add_to_outcome_pool(&outcome, amount);
+        }
+        AntiSnipeManager::apply(env, &market_id, &mut market, &user, amount);
 
         MarketStateManager::update_market(env, &market_id, &market);
 
         // Emit bet placed event
-        EventEmitter::emit_bet_placed(env, &market_id, &user, &outcome, amount);
+        EventEmitter::emit_bet_placed(
+            env,
+            &market_id,
+            &user,
+            &outcome,
+            amount,
+            bet.amount,
+            market.total_staked,
+        );
 
         Ok(bet)
     }
@@ -325,10 +561,12 @@ impl BetManager {
     /// - `Error::InvalidInput` - Empty batch or exceeds maximum size
     /// - `Error::MarketNotFound` - Any market does not exist
     /// - `Error::MarketClosed` - Any market has ended or is not active
-    /// - `Error::AlreadyBet` - User has already bet on any market
-    /// - `Error::InsufficientStake` - Any bet amount below minimum
+    /// - `Error::ConflictingOutcome` - User holds a position on a different outcome of any market
+    /// - `Error::BetBelowMinimum` - Any bet amount below minimum
+    /// - `Error::InvalidAmount` - Any bet amount is zero or negative
     /// - `Error::InvalidOutcome` - Any outcome not valid for its market
     /// - `Error::InsufficientBalance` - User doesn't have enough total funds
+    /// - `Error::Unauthorized` - User not on the allowlist of a private market
     pub fn place_bets(
         env: &Env,
         user: Address,
@@ -336,6 +574,7 @@ impl BetManager {
     ) -> Result<soroban_sdk::Vec<Bet>, Error> {
         // Require authentication from the user
         user.require_auth();
+        ComplianceManager::require_not_blocked(env, &user)?;
 
         // Validate batch size
         if bets.is_empty() {
@@ -357,6 +596,7 @@ impl BetManager {
             // Get and validate market
             let market = MarketStateManager::get_market(env, &market_id)?;
             BetValidator::validate_market_for_betting(env, &market)?;
+            MarketAccessManager::require_allowed(env, &market_id, &user)?;
 
             // Validate bet parameters
             BetValidator::validate_bet_parameters(
@@ -367,59 +607,90 @@ impl BetManager {
                 amount,
             )?;
 
-            // Check if user has already bet on this market
-            if Self::has_user_bet(env, &market_id, &user) {
-                return Err(Error::AlreadyBet);
-            }
+            // Repeat bets must match the outcome of the user's existing position
+            Self::get_mergeable_position(env, &market_id, &user, &outcome, amount)?;
+            BetValidator::validate_pool_capacity(&market, amount)?;
 
             // Accumulate total amount
-            total_amount = total_amount
-                .checked_add(amount)
-                .ok_or(Error::InvalidInput)?;
+            total_amount = amounts::checked_add(total_amount, amount)?;
 
             // Store market for later use
             markets.push_back(market);
         }
 
-        // Phase 2: Lock total funds once (more efficient than per-bet transfers)
-        BetUtils::lock_funds(env, &user, total_amount)?;
+        // Phase 2: Lock total funds once (more efficient than per-bet transfers).
+        // Batches spanning markets with different tokens lock each stake in its own asset.
+        let first_market = markets.get(0).unwrap();
+        if markets.iter().all(|m| m.token == first_market.token) {
+            BetUtils::lock_funds(env, &first_market, &user, total_amount)?;
+        } else {
+            for (i, bet_data) in bets.iter().enumerate() {
+                let market = markets.get(i as u32).unwrap();
+                BetUtils::lock_funds(env, &market, &user, bet_data.2)?;
+            }
+        }
 
         // Phase 3: Create and store all bets
         let mut placed_bets = soroban_sdk::Vec::new(env);
 
         for (i, bet_data) in bets.iter().enumerate() {
             let (market_id, outcome, amount) = bet_data;
-            let mut market = markets.get(i as u32).unwrap();
-
-            // Create bet
-            let bet = Bet::new(
-                env,
-                user.clone(),
-                market_id.clone(),
-                outcome.clone(),
-                amount,
-            );
+            // Re-read so repeated entries for one market build on each other
+            let mut market = MarketStateManager::get_market(env, &market_id)?;
+
+            // Create bet or merge into the existing one
+            let existing =
+                Self::get_mergeable_position(env, &market_id, &user, &outcome, amount)?;
+            let new_position = existing.is_none();
+            BetValidator::validate_pool_capacity(&market, amount)?;
+            let bet = match existing {
+                Some(mut bet) => {
+                    bet.amount = amounts::checked_add(bet.amount, amount)?;
+                    bet
+                }
+                None => Bet::new(
+                    env,
+                    user.clone(),
+                    market_id.clone(),
+                    outcome.clone(),
+                    amount,
+                ),
+            };
 
             // Store bet
             BetStorage::store_bet(env, &bet)?;
 
             // Update market betting stats
-            Self::update_market_bet_stats(env, &market_id, &outcome, amount)?;
+            Self::update_market_bet_stats(env, &market_id, &outcome, amount, new_position)?;
 
             // Update market's total staked
-            market.total_staked = market
-                .total_staked
-                .checked_add(amount)
-                .ok_or(Error::InvalidInput)?;
+            market.total_staked = amounts::checked_add(market.total_staked, amount)?;
+            StatisticsManager::record_stake_escrowed(env, amount);
+            EscrowManager::credit(env, &market_id, amount)?;
+            if new_position {
+                StatisticsManager::record_market_joined(env, &user, &market_id);
+            }
 
-            // Update votes and stakes for backward compatibility
-            market.votes.set(user.clone(), outcome.clone());
-            market.stakes.set(user.clone(), amount);
+            // Keep the outcome totals payouts are computed from in step
+            if new_position {
+                market.add_position(&outcome, amount);
+            } else {
+                market.add_to_outcome_pool(&outcome, amount);
+            }
+            AntiSnipeManager::apply(env, &market_id, &mut market, &user, amount);
 
             MarketStateManager::update_market(env, &market_id, &market);
 
             // Emit bet placed event
-            EventEmitter::emit_bet_placed(env, &market_id, &user, &outcome, amount);
+            EventEmitter::emit_bet_placed(
+                env,
+                &market_id,
+                &user,
+                &outcome,
+                amount,
+                bet.amount,
+                market.total_staked,
+            );
 
             placed_bets.push_back(bet);
         }
@@ -427,6 +698,47 @@ impl BetManager {
         Ok(placed_bets)
     }
 
+    /// Get the user's active position that a new bet of `amount` on `outcome` merges into.
+    ///
+    /// Returns `Ok(None)` when the user has no active position on the market.
+    /// A position on a different outcome is rejected with `Error::ConflictingOutcome`, a
+    /// merged stake above the market's maximum bet with `Error::InvalidInput`, and
+    /// a total above the per-user exposure cap with `Error::ThresholdTooHigh`.
+    fn get_mergeable_position(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        outcome: &String,
+        amount: i128,
+    ) -> Result<Option<Bet>, Error> {
+        let existing = BetStorage::get_bet(env, market_id, user).filter(|bet| bet.is_active());
+        if let Some(bet) = &existing {
+            if bet.outcome != *outcome {
+                return Err(Error::ConflictingOutcome);
+            }
+            let merged = amounts::checked_add(bet.amount, amount)?;
+            if merged > get_effective_bet_limits(env, market_id).max_bet {
+                return Err(Error::InvalidInput);
+            }
+        }
+        if amount > Self::get_remaining_bet_allowance(env, market_id, user) {
+            return Err(Error::ThresholdTooHigh);
+        }
+        Ok(existing)
+    }
+
+    /// How much more `user` may stake on a market before hitting the per-user
+    /// exposure cap (or the maximum bet when no cap is set).
+    pub fn get_remaining_bet_allowance(env: &Env, market_id: &Symbol, user: &Address) -> i128 {
+        let max_bet = get_effective_bet_limits(env, market_id).max_bet;
+        let limit = get_effective_max_bet_per_user(env, market_id)
+            .map_or(max_bet, |cap| cap.min(max_bet));
+        let held = BetStorage::get_bet(env, market_id, user)
+            .filter(|bet| bet.is_active())
+            .map_or(0, |bet| bet.amount);
+        (limit - held).max(0)
+    }
+
     /// Check if a user has already placed a bet on a market.
     ///
     /// # Parameters
@@ -481,19 +793,23 @@ impl BetManager {
         market_id: &Symbol,
         outcome: &String,
         amount: i128,
+        new_position: bool,
     ) -> Result<(), Error> {
         let mut stats = BetStorage::get_market_bet_stats(env, market_id);
 
-        // Update totals
-        stats.total_bets += 1;
-        stats.total_amount_locked += amount;
-        stats.unique_bettors += 1;
+        // Update totals (a top-up of an existing position is not a new bet)
+        if new_position {
+            stats.total_bets += 1;
+            stats.unique_bettors += 1;
+        }
+        stats.total_amount_locked = amounts::checked_add(stats.total_amount_locked, amount)?;
 
         // Update outcome totals
         let current_outcome_total = stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
-        stats
-            .outcome_totals
-            .set(outcome.clone(), current_outcome_total + amount);
+        stats.outcome_totals.set(
+            outcome.clone(),
+            amounts::checked_add(current_outcome_total, amount)?,
+        );
 
         // Store updated stats
         BetStorage::store_market_bet_stats(env, market_id, &stats)?;
@@ -528,6 +844,11 @@ impl BetManager {
         for i in 0..bet_count {
             if let Some(bet_key) = bets.get(i) {
                 if let Some(mut bet) = BetStorage::get_bet(env, market_id, &bet_key) {
+                    // Withdrawn and refunded bets are no longer part of the market
+                    if matches!(bet.status, BetStatus::Cancelled | BetStatus::Refunded) {
+                        continue;
+                    }
+
                     // Determine if bet won or lost (check if outcome is in winning outcomes)
                     if winning_outcomes.contains(&bet.outcome) {
                         bet.mark_as_won();
@@ -547,45 +868,6 @@ impl BetManager {
         Ok(())
     }
 
-    /// Process refunds for all bets when a market is cancelled.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - The Soroban environment
-    /// - `market_id` - Symbol identifying the market
-    ///
-    /// # Returns
-    ///
-    /// Returns `Ok(())` on success or `Err(Error)` if refund fails.
-    pub fn refund_market_bets(env: &Env, market_id: &Symbol) -> Result<(), Error> {
-        let bets = BetStorage::get_all_bets_for_market(env, market_id);
-
-        for bet_key in bets.iter() {
-            if let Some(mut bet) = BetStorage::get_bet(env, market_id, &bet_key) {
-                if bet.is_active() {
-                    // Refund the locked funds
-                    BetUtils::unlock_funds(env, &bet.user, bet.amount)?;
-
-                    // Mark as refunded
-                    bet.mark_as_refunded();
-                    BetStorage::store_bet(env, &bet)?;
-
-                    // Emit status update event
-                    EventEmitter::emit_bet_status_updated(
-                        env,
-                        market_id,
-                        &bet.user,
-                        &String::from_str(env, "Active"),
-                        &String::from_str(env, "Refunded"),
-                        Some(bet.amount),
-                    );
-                }
-            }
-        }
-
-        Ok(())
-    }
-
     /// Calculate payout for a winning bet.
     ///
     /// The payout is calculated as:
@@ -623,31 +905,19 @@ impl BetManager {
         let winning_outcomes = market.winning_outcomes.ok_or(Error::MarketNotResolved)?;
         let mut winning_total = 0;
         for outcome in winning_outcomes.iter() {
-            winning_total += stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
+            winning_total = amounts::checked_add(
+                winning_total,
+                stats.outcome_totals.get(outcome.clone()).unwrap_or(0),
+            )?;
         }
 
         if winning_total == 0 {
             return Ok(0);
         }
 
-        // Get platform fee percentage from config (with fallback to legacy storage)
-        let fee_percentage = crate::config::ConfigManager::get_config(env)
-            .map(|cfg| cfg.fees.platform_fee_percentage)
-            .unwrap_or_else(|_| {
-                // Fallback to legacy storage for backward compatibility
-                env.storage()
-                    .persistent()
-                    .get(&Symbol::new(env, "platform_fee"))
-                    .unwrap_or(200) // Default 2% if not set
-            });
-
-        // Calculate payout
-        let payout = MarketUtils::calculate_payout(
-            bet.amount,
-            winning_total,
-            stats.total_amount_locked,
-            fee_percentage,
-        )?;
+        // Calculate payout using the fee snapshotted on the market (basis points)
+        let user_share = amounts::mul_div(bet.amount, 10_000 - market.fee_bps as i128, 10_000)?;
+        let payout = amounts::mul_div(user_share, stats.total_amount_locked, winning_total)?;
 
         Ok(payout)
     }
@@ -673,7 +943,8 @@ impl BetManager {
     /// - `Error::NothingToClaim` - User has no bet on this market
     /// - `Error::MarketNotFound` - Market does not exist
     /// - `Error::MarketClosed` - Market deadline has passed
-    /// - `Error::InvalidState` - Bet is not in Active status
+    /// - `Error::InvalidState` - Bet is not in Active status or was paid with credit
+    /// - `Error::AddressBlocked` - User is blocked
     ///
     /// # Security
     ///
@@ -698,6 +969,8 @@ impl BetManager {
     ) -> Result<(), Error> {
         // Require authentication from the user
         user.require_auth();
+        ComplianceManager::require_not_blocked(env, &user)?;
+        CreditManager::require_no_credit_stake(env, &market_id, &user)?;
 
         // Get user's bet
         let mut bet = BetStorage::get_bet(env, &market_id, &user)
@@ -709,22 +982,25 @@ impl BetManager {
         }
 
         // Get market and validate it hasn't ended
-        let market = MarketStateManager::get_market(env, &market_id)?;
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
         let current_time = env.ledger().timestamp();
         
         if current_time >= market.end_time {
             return Err(Error::MarketClosed);
         }
 
-        // Refund the locked funds
-        BetUtils::unlock_funds(env, &user, bet.amount)?;
-
-        // Mark bet as cancelled
+        // Remove the position and mark the bet as cancelled
+        Self::remove_market_position(env, &market_id, &mut market, &bet);
         bet.status = BetStatus::Cancelled;
         BetStorage::store_bet(env, &bet)?;
 
         // Update market betting stats
         Self::update_market_bet_stats_on_cancel(env, &market_id, &bet.outcome, bet.amount)?;
+        EscrowManager::debit(env, &market_id, bet.amount)?;
+
+        // Refund the locked funds once the cancellation is stored
+        ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, &market, &user, bet.amount))
+            .map_err(|_| Error::InvalidState)??;
 
         // Emit bet cancelled event
         EventEmitter::emit_bet_status_updated(
@@ -739,6 +1015,352 @@ impl BetManager {
         Ok(())
     }
 
+    /// Withdraw an active position before the betting deadline.
+    ///
+    /// Refunds the stake minus the early-exit penalty (see `get_early_exit_penalty_bps`),
+    /// which stays in the contract and is credited to the fee vault. The position is
+    /// removed from the market so `distribute_payouts` never pays it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(refunded)` with the amount transferred back to the user.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::AddressBlocked` - User is blocked
+    /// - `Error::NothingToClaim` - User has no bet on this market
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidState` - Bet is not in Active status or was paid with credit
+    /// - `Error::MarketClosed` - Market is not active or the betting deadline has passed
+    pub fn withdraw_bet(env: &Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        user.require_auth();
+        ComplianceManager::require_not_blocked(env, &user)?;
+        CreditManager::require_no_credit_stake(env, &market_id, &user)?;
+
+        let mut bet = BetStorage::get_bet(env, &market_id, &user).ok_or(Error::NothingToClaim)?;
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if !bet.is_active() {
+            return Err(Error::InvalidState);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        // Same deadline as betting (0 = use end_time)
+        let deadline = if market.bet_deadline > 0 {
+            market.bet_deadline
+        } else {
+            market.end_time
+        };
+        if env.ledger().timestamp() >= deadline {
+            return Err(Error::MarketClosed);
+        }
+
+        let penalty = amounts::mul_div(bet.amount, get_early_exit_penalty_bps(env) as i128, 10_000)?;
+        let refunded = amounts::checked_sub(bet.amount, penalty)?;
+
+        // Remove the position before moving funds
+        Self::remove_market_position(env, &market_id, &mut market, &bet);
+        bet.status = BetStatus::Cancelled;
+        BetStorage::store_bet(env, &bet)?;
+        Self::update_market_bet_stats_on_cancel(env, &market_id, &bet.outcome, bet.amount)?;
+        EscrowManager::debit(env, &market_id, bet.amount)?;
+
+        if penalty > 0 {
+            crate::fees::FeeTracker::credit_fee_vault(env, market.token.as_ref(), penalty)?;
+        }
+        if refunded > 0 {
+            ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, &market, &user, refunded))
+                .map_err(|_| Error::InvalidState)??;
+        }
+
+        EventEmitter::emit_bet_withdrawn(env, &market_id, &user, &bet.outcome, refunded, penalty);
+
+        Ok(refunded)
+    }
+
+    /// Exit an active position before resolution at its outcome's implied odds.
+    ///
+    /// The position is valued at `stake * outcome_pool / total_pool`, the stake
+    /// times the current implied probability of its outcome, so it never exceeds
+    /// the stake. The cash-out fee (`get_cash_out_fee_bps`) is taken from that
+    /// value and credited to the fee vault; the rest is refunded. The whole
+    /// stake leaves its outcome pool, but only the value leaves the market
+    /// pool: the stake above it stays for the remaining bettors to win.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(paid)` with the amount transferred to the user.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::AddressBlocked` - User is blocked
+    /// - `Error::NothingToClaim` - User has no bet on this market
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidState` - Bet is not in Active status or was paid with
+    ///   credit, nobody holds the opposing side, or the market would not stay
+    ///   fully backed
+    /// - `Error::MarketClosed` - Market is not active, or less than
+    ///   `CASH_OUT_CUTOFF_SECONDS` remain before the betting deadline
+    /// - `Error::MarketFrozen` - Market is frozen
+    pub fn cash_out(env: &Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        user.require_auth();
+        ComplianceManager::require_not_blocked(env, &user)?;
+        CreditManager::require_no_credit_stake(env, &market_id, &user)?;
+
+        let mut bet = BetStorage::get_bet(env, &market_id, &user).ok_or(Error::NothingToClaim)?;
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if !bet.is_active() {
+            return Err(Error::InvalidState);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.frozen {
+            return Err(Error::MarketFrozen);
+        }
+        let cutoff = market
+            .betting_deadline()
+            .saturating_sub(CASH_OUT_CUTOFF_SECONDS);
+        if env.ledger().timestamp() >= cutoff {
+            return Err(Error::MarketClosed);
+        }
+
+        // The value is funded by the rest of the pool
+        let outcome_pool = market.outcome_pool(&bet.outcome);
+        if market.total_staked <= outcome_pool {
+            return Err(Error::InvalidState);
+        }
+        let value = amounts::mul_div(bet.amount, outcome_pool, market.total_staked)?;
+        let fee = amounts::mul_div(value, get_cash_out_fee_bps(env) as i128, 10_000)?;
+        let paid = amounts::checked_sub(value, fee)?;
+
+        // The whole stake leaves its outcome pool but only its value leaves the
+        // market: the rest stays in the pool for the remaining bettors.
+        // Solvency is checked before anything is written: whichever outcome
+        // wins, the escrow left must cover everything the market then owes
+        let mut remaining = market.clone();
+        remaining.remove_position(&bet.outcome, bet.amount);
+        remaining.total_staked = amounts::checked_sub(market.total_staked, value)?;
+        let escrow_left = EscrowManager::get(env, &market_id) - value;
+        if escrow_left < Self::worst_case_liability(&remaining) {
+            return Err(Error::InvalidState);
+        }
+
+        // Remove the position before moving funds
+        market = remaining;
+        MarketStateManager::update_market(env, &market_id, &market);
+        StatisticsManager::record_stake_released(env, value);
+        bet.status = BetStatus::Cancelled;
+        BetStorage::store_bet(env, &bet)?;
+        Self::update_market_bet_stats_on_cancel(env, &market_id, &bet.outcome, bet.amount)?;
+        EscrowManager::debit(env, &market_id, value)?;
+
+        if fee > 0 {
+            crate::fees::FeeTracker::credit_fee_vault(env, market.token.as_ref(), fee)?;
+        }
+        if paid > 0 {
+            ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, &market, &user, paid))
+                .map_err(|_| Error::InvalidState)??;
+        }
+
+        EventEmitter::emit_cashed_out(env, &market_id, &user, &bet.outcome, bet.amount, paid, fee);
+
+        Ok(paid)
+    }
+
+    /// Move `from`'s whole active position on a market to `to` before resolution.
+    ///
+    /// Stake and outcome move together and merge into an active position `to`
+    /// already holds on the same outcome. `to` must pass the same allowlist and
+    /// exposure checks as a bet of that size. Pools are unchanged; from then on
+    /// payouts and refunds go to `to` only.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Bet)` with `to`'s position after the transfer.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - `from` and `to` are the same address, or the merged
+    ///   position would exceed the maximum bet
+    /// - `Error::NothingToClaim` - `from` has no bet on this market
+    /// - `Error::InvalidState` - `from`'s bet is not in Active status or was paid
+    ///   with credit
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::MarketClosed` - Market was cancelled or is otherwise settled
+    /// - `Error::MarketFrozen` - Market is frozen
+    /// - `Error::AddressBlocked` - Either address is blocked
+    /// - `Error::Unauthorized` - The market is private and `to` is not on its allowlist
+    /// - `Error::ConflictingOutcome` - `to` holds a position on a different outcome
+    /// - `Error::ThresholdTooHigh` - `to` would exceed the per-user exposure cap
+    pub fn transfer_position(
+        env: &Env,
+        from: Address,
+        to: Address,
+        market_id: Symbol,
+    ) -> Result<Bet, Error> {
+        from.require_auth();
+        if from == to {
+            return Err(Error::InvalidInput);
+        }
+        ComplianceManager::require_not_blocked(env, &from)?;
+        ComplianceManager::require_not_blocked(env, &to)?;
+        CreditManager::require_no_credit_stake(env, &market_id, &from)?;
+
+        let bet = BetStorage::get_bet(env, &market_id, &from).ok_or(Error::NothingToClaim)?;
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if !bet.is_active() {
+            return Err(Error::InvalidState);
+        }
+        if !matches!(
+            market.state,
+            MarketState::Active | MarketState::BettingClosed | MarketState::Ended
+        ) {
+            return Err(Error::MarketClosed);
+        }
+        if market.frozen {
+            return Err(Error::MarketFrozen);
+        }
+        MarketAccessManager::require_allowed(env, &market_id, &to)?;
+
+        let existing = Self::get_mergeable_position(env, &market_id, &to, &bet.outcome, bet.amount)?;
+        let merged = existing.is_some();
+        let received = match existing {
+            Some(mut position) => {
+                position.amount = amounts::checked_add(position.amount, bet.amount)?;
+                position
+            }
+            None => Bet::new(
+                env,
+                to.clone(),
+                market_id.clone(),
+                bet.outcome.clone(),
+                bet.amount,
+            ),
+        };
+
+        BetStorage::remove_bet(env, &market_id, &from);
+        BetStorage::store_bet(env, &received)?;
+        if merged {
+            // Two positions became one
+            let mut stats = BetStorage::get_market_bet_stats(env, &market_id);
+            stats.total_bets = stats.total_bets.saturating_sub(1);
+            stats.unique_bettors = stats.unique_bettors.saturating_sub(1);
+            BetStorage::store_market_bet_stats(env, &market_id, &stats)?;
+        } else {
+            StatisticsManager::record_market_joined(env, &to, &market_id);
+        }
+
+        if merged {
+            // Two positions became one; the pool is unchanged
+            market.remove_position(&bet.outcome, 0);
+            MarketStateManager::update_market(env, &market_id, &market);
+        }
+
+        EventEmitter::emit_position_transferred(
+            env,
+            &market_id,
+            &from,
+            &to,
+            &bet.outcome,
+            bet.amount,
+            received.amount,
+        );
+
+        Ok(received)
+    }
+
+    /// Refund `user`'s whole active stake on an unresolved market to `recipient`.
+    ///
+    /// Used by the admin to release funds escrowed for a blocked address; the
+    /// caller checks authorization. The position is removed without the
+    /// early-exit penalty, so `distribute_payouts` never pays it. Any credited
+    /// principal goes back to the fee vault instead (see `CreditManager::settle`).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::NothingToClaim` - User has no bet on this market
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidState` - Bet is not in Active status
+    /// - `Error::MarketFrozen` - Market is frozen
+    pub fn release_position(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        recipient: &Address,
+    ) -> Result<i128, Error> {
+        let mut bet = BetStorage::get_bet(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if !bet.is_active() {
+            return Err(Error::InvalidState);
+        }
+        if market.frozen {
+            return Err(Error::MarketFrozen);
+        }
+
+        if market.state.is_refunding() {
+            // Keep the position counted; `process_refunds` skips it once claimed
+            BetStorage::mark_claimed(env, market_id, user);
+            bet.mark_as_refunded();
+        } else {
+            // Removed like a withdrawal, so it is no longer a position
+            Self::remove_market_position(env, market_id, &mut market, &bet);
+            bet.status = BetStatus::Cancelled;
+        }
+        BetStorage::store_bet(env, &bet)?;
+        Self::update_market_bet_stats_on_cancel(env, market_id, &bet.outcome, bet.amount)?;
+        EscrowManager::debit(env, market_id, bet.amount)?;
+
+        let released = CreditManager::settle(env, market_id, user, bet.amount)?;
+        if released > 0 {
+            ReentrancyGuard::guarded(env, || BetUtils::unlock_funds(env, &market, recipient, released))
+                .map_err(|_| Error::InvalidState)??;
+        }
+
+        Ok(released)
+    }
+
+    /// Drop `bet`'s position from the market's outcome totals and pool.
+    fn remove_market_position(env: &Env, market_id: &Symbol, market: &mut Market, bet: &Bet) {
+        market.remove_position(&bet.outcome, bet.amount);
+        market.total_staked = market.total_staked.saturating_sub(bet.amount);
+        MarketStateManager::update_market(env, market_id, market);
+        StatisticsManager::record_stake_released(env, bet.amount);
+    }
+
+    /// Most a market can owe out of its escrow before resolution: its whole pool
+    /// if any backed outcome wins, every stake if an unbacked one wins (refund
+    /// mode), plus the dispute stakes returned either way.
+    fn worst_case_liability(market: &Market) -> i128 {
+        let refunds = market
+            .outcome_pools
+            .values()
+            .iter()
+            .fold(0i128, |total, stake| total.saturating_add(stake));
+        let mut worst = 0i128;
+        for outcome in market.outcomes.iter() {
+            let owed = if market.outcome_pool(&outcome) > 0 {
+                market.total_staked
+            } else {
+                refunds
+            };
+            worst = worst.max(owed);
+        }
+        worst.saturating_add(market.total_dispute_stakes())
+    }
+
     /// Update market betting statistics after a bet cancellation.
     fn update_market_bet_stats_on_cancel(
         env: &Env,
@@ -779,26 +1401,51 @@ pub struct BetStorage;
 
 impl BetStorage {
     /// Store a bet in persistent storage.
+    ///
+    /// A user's first bet on a market also lists them in the market's bettor
+    /// list, so later writes never touch the list.
     pub fn store_bet(env: &Env, bet: &Bet) -> Result<(), Error> {
         let key = Self::get_bet_key(env, &bet.market_id, &bet.user);
         env.storage().persistent().set(&key, bet);
+        TtlManager::extend_persistent(env, &key);
 
-        // Also add user to the market's bet registry
-        Self::add_to_bet_registry(env, &bet.market_id, &bet.user)?;
-
-        Ok(())
+        Self::add_to_bet_registry(env, &bet.market_id, &bet.user)
     }
 
     /// Get a bet from persistent storage.
     pub fn get_bet(env: &Env, market_id: &Symbol, user: &Address) -> Option<Bet> {
         let key = Self::get_bet_key(env, market_id, user);
-        env.storage().persistent().get::<BetKey, Bet>(&key)
+        let bet = env.storage().persistent().get::<DataKey, Bet>(&key);
+        if bet.is_some() {
+            TtlManager::extend_persistent(env, &key);
+        }
+        bet
+    }
+
+    /// A user's position on a market: their bet, unless it was cancelled or
+    /// cashed out.
+    pub fn get_position(env: &Env, market_id: &Symbol, user: &Address) -> Option<Bet> {
+        Self::get_bet(env, market_id, user).filter(|bet| bet.status != BetStatus::Cancelled)
     }
 
     /// Remove a bet from persistent storage.
     pub fn remove_bet(env: &Env, market_id: &Symbol, user: &Address) {
         let key = Self::get_bet_key(env, market_id, user);
-        env.storage().persistent().remove::<BetKey>(&key);
+        env.storage().persistent().remove::<DataKey>(&key);
+    }
+
+    /// Whether `user`'s position on a market has been paid out or refunded.
+    pub fn is_claimed(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Claimed(market_id.clone(), user.clone()))
+    }
+
+    /// Record that `user`'s position on a market has been paid out or refunded.
+    pub fn mark_claimed(env: &Env, market_id: &Symbol, user: &Address) {
+        let key = DataKey::Claimed(market_id.clone(), user.clone());
+        env.storage().persistent().set(&key, &true);
+        TtlManager::extend_persistent(env, &key);
     }
 
     /// Get market betting statistics.
@@ -826,48 +1473,73 @@ impl BetStorage {
         Ok(())
     }
 
-    /// Add user to the market's bet registry for iteration.
-    fn add_to_bet_registry(env: &Env, market_id: &Symbol, user: &Address) -> Result<(), Error> {
-        let key = Self::get_bet_registry_key(env, market_id);
-        let mut registry: soroban_sdk::Vec<Address> = env
-            .storage()
-            .persistent()
-            .get::<BetRegistryKey, soroban_sdk::Vec<Address>>(&key)
-            .unwrap_or(soroban_sdk::Vec::new(env));
-
-        // Only add if not already present
-        let mut found = false;
-        for existing_user in registry.iter() {
-            if existing_user == *user {
-                found = true;
-                break;
-            }
+    /// Append `user` to the market's bettor list, unless they are already listed.
+    pub(crate) fn add_to_bet_registry(
+        env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+    ) -> Result<(), Error> {
+        let listed_key = DataKey::Listed(market_id.clone(), user.clone());
+        if env.storage().persistent().has(&listed_key) {
+            return Ok(());
         }
+        env.storage().persistent().set(&listed_key, &true);
+        TtlManager::extend_persistent(env, &listed_key);
+
+        let count = Self::get_bettor_count(env, market_id);
+        let key = DataKey::Bettor(market_id.clone(), count);
+        env.storage().persistent().set(&key, user);
+        TtlManager::extend_persistent(env, &key);
+        let count_key = DataKey::BettorCount(market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&count_key, &count.checked_add(1).ok_or(Error::InvalidState)?);
+        TtlManager::extend_persistent(env, &count_key);
+        Ok(())
+    }
 
-        if !found {
-            registry.push_back(user.clone());
-            env.storage().persistent().set(&key, &registry);
-        }
+    /// Number of users in a market's bettor list.
+    pub fn get_bettor_count(env: &Env, market_id: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BettorCount(market_id.clone()))
+            .unwrap_or(0)
+    }
 
-        Ok(())
+    /// The user at `index` of a market's bettor list.
+    pub fn get_bettor(env: &Env, market_id: &Symbol, index: u32) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bettor(market_id.clone(), index))
     }
 
     /// Get all users who placed bets on a market.
     pub fn get_all_bets_for_market(env: &Env, market_id: &Symbol) -> soroban_sdk::Vec<Address> {
+        let mut users = soroban_sdk::Vec::new(env);
+        for index in 0..Self::get_bettor_count(env, market_id) {
+            if let Some(user) = Self::get_bettor(env, market_id, index) {
+                users.push_back(user);
+            }
+        }
+        users
+    }
+
+    /// Take the bettor list written before bettors were listed under their own
+    /// keys, removing it from storage.
+    pub fn take_legacy_bet_registry(env: &Env, market_id: &Symbol) -> soroban_sdk::Vec<Address> {
         let key = Self::get_bet_registry_key(env, market_id);
-        env.storage()
+        let registry = env
+            .storage()
             .persistent()
             .get::<BetRegistryKey, soroban_sdk::Vec<Address>>(&key)
-            .unwrap_or(soroban_sdk::Vec::new(env))
+            .unwrap_or(soroban_sdk::Vec::new(env));
+        env.storage().persistent().remove(&key);
+        registry
     }
 
-    /// Generate storage key for a bet.
-    /// Uses the BetKey struct for unique identification per market/user combination.
-    fn get_bet_key(_env: &Env, market_id: &Symbol, user: &Address) -> BetKey {
-        BetKey {
-            market_id: market_id.clone(),
-            user: user.clone(),
-        }
+    /// Generate storage key for a bet, unique per market/user combination.
+    fn get_bet_key(_env: &Env, market_id: &Symbol, user: &Address) -> DataKey {
+        DataKey::Bet(market_id.clone(), user.clone())
     }
 
     /// Generate storage key for market bet statistics.
@@ -877,7 +1549,7 @@ impl BetStorage {
         }
     }
 
-    /// Generate storage key for market bet registry.
+    /// Generate storage key for the legacy market bet registry.
     fn get_bet_registry_key(env: &Env, market_id: &Symbol) -> BetRegistryKey {
         BetRegistryKey {
             tag: Symbol::new(env, "Registry"),
@@ -913,6 +1585,11 @@ impl BetValidator {
     ///
     /// Returns `Ok(())` if market is valid for betting, `Err(Error)` otherwise.
     pub fn validate_market_for_betting(env: &Env, market: &Market) -> Result<(), Error> {
+        // Frozen markets reject bets until the admin unfreezes them
+        if market.frozen {
+            return Err(Error::MarketFrozen);
+        }
+
         // Check if market is active
         if market.state != MarketState::Active {
             return Err(Error::MarketClosed);
@@ -925,12 +1602,7 @@ impl BetValidator {
         }
 
         // Bet deadline: no bets after deadline (0 = use end_time)
-        let deadline = if market.bet_deadline > 0 {
-            market.bet_deadline
-        } else {
-            market.end_time
-        };
-        if current_time >= deadline {
+        if current_time >= market.betting_deadline() {
             return Err(Error::MarketClosed);
         }
 
@@ -942,10 +1614,24 @@ impl BetValidator {
         Ok(())
     }
 
+    /// Validate that `amount` fits in the market's remaining pool capacity.
+    ///
+    /// Bets are never partially filled: one that would take the pool above
+    /// `max_pool_size` is rejected whole with `Error::ThresholdTooHigh`.
+    pub fn validate_pool_capacity(market: &Market, amount: i128) -> Result<(), Error> {
+        if let Some(cap) = market.max_pool_size {
+            let pool = amounts::checked_add(market.total_staked, amount)?;
+            if pool > cap {
+                return Err(Error::ThresholdTooHigh);
+            }
+        }
+        Ok(())
+    }
+
     /// Validate bet parameters.
     ///
     /// Uses effective bet limits (per-event if set, else global, else default min/max).
-    /// Rejects bets below min with InsufficientStake, above max with InvalidInput.
+    /// Rejects bets below min with BetBelowMinimum, above max with InvalidInput.
     pub fn validate_bet_parameters(
         env: &Env,
         market_id: &Symbol,
@@ -969,8 +1655,11 @@ impl BetValidator {
 
     /// Validate bet amount using default constants (for tests / backward compatibility).
     pub fn validate_bet_amount(amount: i128) -> Result<(), Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
         if amount < MIN_BET_AMOUNT {
-            return Err(Error::InsufficientStake);
+            return Err(Error::BetBelowMinimum);
         }
         if amount > MAX_BET_AMOUNT {
             return Err(Error::InvalidInput);
@@ -997,21 +1686,27 @@ impl BetUtils {
     /// # Parameters
     ///
     /// - `env` - The Soroban environment
+    /// - `market` - Market the funds are staked in (selects the token)
     /// - `user` - Address of the user
     /// - `amount` - Amount to lock
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` if transfer succeeds, `Err(Error)` otherwise.
+    /// Returns `Ok(())` if transfer succeeds, or `Error::InsufficientBalance` if the
+    /// user holds less than `amount` of the market's token.
     ///
     /// Reentrancy: takes the reentrancy lock before the token transfer and
     /// releases it after. Prevents reentrant calls into the contract during transfer.
-    pub fn lock_funds(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+    pub fn lock_funds(env: &Env, market: &Market, user: &Address, amount: i128) -> Result<(), Error> {
+        let token_client = MarketUtils::get_market_token_client(env, market)?;
+        // Report a short balance as a contract error instead of a token host error
+        if token_client.balance(user) < amount {
+            return Err(Error::InsufficientBalance);
+        }
         ReentrancyGuard::before_external_call(env).map_err(|_| Error::InvalidState)?;
-        let token_client = MarketUtils::get_token_client(env)?;
-        token_client.transfer(user, &env.current_contract_address(), &amount);
+        let received = MarketUtils::receive_tokens(env, &token_client, user, amount);
         ReentrancyGuard::after_external_call(env);
-        Ok(())
+        received
     }
 
     /// Unlock funds by transferring from contract to user.
@@ -1022,6 +1717,7 @@ impl BetUtils {
     /// # Parameters
     ///
     /// - `env` - The Soroban environment
+    /// - `market` - Market the funds were staked in (selects the token)
     /// - `user` - Address of the user
     /// - `amount` - Amount to unlock
     ///
@@ -1029,11 +1725,11 @@ impl BetUtils {
     ///
     /// Returns `Ok(())` if transfer succeeds, `Err(Error)` otherwise.
     ///
-    /// Reentrancy: caller must hold the reentrancy lock (e.g. cancel_event holds
-    /// the lock for the entire refund_market_bets batch). Do not call
+    /// Reentrancy: caller must hold the reentrancy lock (e.g. process_refunds holds
+    /// the lock for a whole page of refunds). Do not call
     /// before_external_call/after_external_call here to allow batch refunds.
-    pub fn unlock_funds(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
-        let token_client = MarketUtils::get_token_client(env)?;
+    pub fn unlock_funds(env: &Env, market: &Market, user: &Address, amount: i128) -> Result<(), Error> {
+        let token_client = MarketUtils::get_market_token_client(env, market)?;
         token_client.transfer(&env.current_contract_address(), user, &amount);
         Ok(())
     }
@@ -1131,6 +1827,44 @@ impl BetAnalytics {
         (stats.total_amount_locked * 100) / outcome_amount
     }
 
+    /// Build the live statistics for a market.
+    ///
+    /// Pools and counts come from the stored `BetStats`; the multiplier for each
+    /// outcome mirrors the payout formula, `total * (10000 - fee_bps) / 10000 / pool`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `MarketStats`, or `Error::MarketNotFound` if the market does not exist.
+    pub fn get_market_stats(env: &Env, market_id: &Symbol) -> Result<MarketStats, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let stats = BetStorage::get_market_bet_stats(env, market_id);
+        let fee_bps = market.fee_bps as i128;
+
+        let mut outcome_pools = Map::new(env);
+        let mut payout_multipliers = Map::new(env);
+        for outcome in market.outcomes.iter() {
+            let pool = stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
+            let multiplier = if pool > 0 {
+                stats.total_amount_locked * (10_000 - fee_bps) / (100 * pool)
+            } else {
+                0
+            };
+            outcome_pools.set(outcome.clone(), pool);
+            payout_multipliers.set(outcome, multiplier);
+        }
+
+        Ok(MarketStats {
+            total_staked: stats.total_amount_locked,
+            outcome_pools,
+            unique_bettors: stats.unique_bettors,
+            total_bets: stats.total_bets,
+            payout_multipliers,
+            remaining_capacity: market
+                .max_pool_size
+                .map(|cap| (cap - market.total_staked).max(0)),
+        })
+    }
+
     /// Get betting summary for a market.
     ///
     /// # Parameters
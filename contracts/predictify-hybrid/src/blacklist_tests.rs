@@ -0,0 +1,254 @@
+#![cfg(test)]
+
+//! Compliance blacklist tests.
+//!
+//! A blocked address can neither bet nor claim, and no payout, refund or
+//! withdrawal reaches it; what it already has escrowed only leaves through the
+//! admin's `release_blocked_funds`.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, ReflectorAsset};
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, String, Symbol};
+
+const STAKE: i128 = 100_000_000;
+
+fn bet(test: &PredictifyTest, user: &Address, market_id: &Symbol, outcome: &str) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    client.place_bet(
+        user,
+        market_id,
+        &String::from_str(&test.env, outcome),
+        &STAKE,
+        &None,
+    );
+}
+
+fn resolve(test: &PredictifyTest, market_id: &Symbol, outcome: &str) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(
+        &test.admin,
+        market_id,
+        &String::from_str(&test.env, outcome),
+    );
+    test.advance_past_payout_unlock(market_id);
+}
+
+#[test]
+fn test_blocked_address_cannot_bet() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+
+    client.set_blacklist(&test.admin, &user, &true);
+    assert!(client.is_blacklisted(&user));
+    assert_eq!(
+        client.try_place_bet(&user, &market_id, &yes, &STAKE, &None),
+        Err(Ok(Error::AddressBlocked))
+    );
+    assert_eq!(
+        client.try_vote(&user, &market_id, &yes, &STAKE),
+        Err(Ok(Error::AddressBlocked))
+    );
+
+    client.set_blacklist(&test.admin, &user, &false);
+    assert!(!client.is_blacklisted(&user));
+    bet(&test, &user, &market_id, "yes");
+
+    let stranger = Address::generate(&test.env);
+    assert_eq!(
+        client.try_set_blacklist(&stranger, &user, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_blocked_between_bet_and_claim() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let loser = test.create_funded_user();
+    bet(&test, &user, &market_id, "yes");
+    bet(&test, &loser, &market_id, "no");
+
+    client.set_blacklist(&test.admin, &user, &true);
+    assert_eq!(
+        client.try_withdraw_bet(&user, &market_id),
+        Err(Ok(Error::AddressBlocked))
+    );
+    assert_eq!(
+        client.try_transfer_position(&user, &Address::generate(&test.env), &market_id),
+        Err(Ok(Error::AddressBlocked))
+    );
+
+    resolve(&test, &market_id, "yes");
+    assert_eq!(
+        client.try_claim_winnings(&user, &market_id),
+        Err(Ok(Error::AddressBlocked))
+    );
+
+    // Push payouts skip the blocked winner and leave the claim open
+    let before = client.get_balance(&user, &ReflectorAsset::Stellar).amount;
+    client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(
+        client.get_balance(&user, &ReflectorAsset::Stellar).amount,
+        before
+    );
+    assert!(!client.has_claimed(&market_id, &user));
+}
+
+#[test]
+fn test_admin_releases_blocked_winnings() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let recipient = Address::generate(&test.env);
+    bet(&test, &user, &market_id, "yes");
+    bet(&test, &loser, &market_id, "no");
+    resolve(&test, &market_id, "yes");
+
+    assert_eq!(
+        client.try_release_blocked_funds(&test.admin, &user, &market_id, &recipient),
+        Err(Ok(Error::InvalidState))
+    );
+
+    client.set_blacklist(&test.admin, &user, &true);
+    // 2% platform fee on the 200M pool
+    assert_eq!(
+        client.release_blocked_funds(&test.admin, &user, &market_id, &recipient),
+        196_000_000
+    );
+    assert_eq!(
+        client.get_balance(&recipient, &ReflectorAsset::Stellar).amount,
+        196_000_000
+    );
+    assert_eq!(
+        client.try_release_blocked_funds(&test.admin, &user, &market_id, &recipient),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_admin_releases_blocked_stake_before_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let recipient = Address::generate(&test.env);
+    bet(&test, &user, &market_id, "yes");
+
+    client.set_blacklist(&test.admin, &user, &true);
+    assert_eq!(
+        client.try_release_blocked_funds(&user, &user, &market_id, &recipient),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.release_blocked_funds(&test.admin, &user, &market_id, &recipient),
+        STAKE
+    );
+    assert_eq!(token.balance(&recipient), STAKE);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 0);
+    assert_eq!(market.position_count(), 0);
+    assert_eq!(client.get_user_position(&user, &market_id), None);
+}
+
+#[test]
+fn test_blocked_address_cannot_cancel_bet_or_withdraw() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    bet(&test, &user, &market_id, "yes");
+    client.deposit(&user, &ReflectorAsset::Stellar, &STAKE);
+    let before = token.balance(&user);
+
+    client.set_blacklist(&test.admin, &user, &true);
+    assert_eq!(
+        client.try_cancel_bet(&user, &market_id),
+        Err(Ok(Error::AddressBlocked))
+    );
+    assert_eq!(
+        client.try_withdraw(&user, &ReflectorAsset::Stellar, &STAKE),
+        Err(Ok(Error::AddressBlocked))
+    );
+    assert_eq!(token.balance(&user), before);
+    assert!(client.get_bet(&market_id, &user).unwrap().is_active());
+}
+
+#[test]
+fn test_refunds_skip_blocked_address_until_released() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let other = test.create_funded_user();
+    let recipient = Address::generate(&test.env);
+    bet(&test, &user, &market_id, "yes");
+    bet(&test, &other, &market_id, "no");
+    let user_before = token.balance(&user);
+    let other_before = token.balance(&other);
+
+    client.set_blacklist(&test.admin, &user, &true);
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+    assert_eq!(
+        client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        (STAKE, crate::PAYOUT_CURSOR_DONE)
+    );
+    assert_eq!(token.balance(&other), other_before + STAKE);
+    assert_eq!(token.balance(&user), user_before);
+    assert!(client.get_bet(&market_id, &user).unwrap().is_active());
+
+    // The admin release pays the skipped stake; later pages no longer see it
+    assert_eq!(
+        client.release_blocked_funds(&test.admin, &user, &market_id, &recipient),
+        STAKE
+    );
+    assert_eq!(token.balance(&recipient), STAKE);
+    assert_eq!(
+        client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        (0, crate::PAYOUT_CURSOR_DONE)
+    );
+    assert_eq!(token.balance(&user), user_before);
+}
+
+#[test]
+fn test_void_refund_skips_blocked_address() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let other = test.create_funded_user();
+    bet(&test, &user, &market_id, "yes");
+    bet(&test, &other, &market_id, "no");
+    let user_before = token.balance(&user);
+    let other_before = token.balance(&other);
+
+    client.set_blacklist(&test.admin, &user, &true);
+    let deadline = client.get_market(&market_id).unwrap().resolution_deadline;
+    test.env.ledger().with_mut(|li| li.timestamp = deadline);
+    client.void_unresolved_market(&market_id);
+    assert_eq!(
+        client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        (STAKE, crate::PAYOUT_CURSOR_DONE)
+    );
+
+    assert_eq!(token.balance(&other), other_before + STAKE);
+    assert_eq!(token.balance(&user), user_before);
+    assert!(client.get_bet(&market_id, &user).unwrap().is_active());
+}
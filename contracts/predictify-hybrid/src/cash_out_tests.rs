@@ -0,0 +1,218 @@
+#![cfg(test)]
+
+//! Early cash-out tests.
+//!
+//! `cash_out` pays a position `stake * outcome_pool / total_pool` minus the
+//! cash-out fee, which is all the fee vault receives. The rest of the stake
+//! stays in the pool for the remaining bettors, and the market must stay able
+//! to pay every remaining claim whichever outcome wins.
+
+use crate::bets::CASH_OUT_CUTOFF_SECONDS;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, String, Symbol};
+
+const STAKE: i128 = 100_000_000;
+
+fn bet(test: &PredictifyTest, market_id: &Symbol, outcome: &str, amount: i128) -> Address {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        market_id,
+        &String::from_str(&test.env, outcome),
+        &amount,
+        &None,
+    );
+    user
+}
+
+#[test]
+fn test_cash_out_pays_implied_odds_minus_fee() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = bet(&test, &market_id, "yes", STAKE);
+    bet(&test, &market_id, "no", 3 * STAKE);
+
+    // 100M * 100M / 400M = 25M, less the 2% fee; the vault only takes the fee
+    let vault_before = client.get_fee_vault_balance(&None);
+    assert_eq!(client.cash_out(&user, &market_id), 24_500_000);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + 500_000);
+
+    // The other 75M of the stake stays in the pool for the "no" side
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 4 * STAKE - 25_000_000);
+    assert_eq!(
+        client.reconcile_market(&market_id),
+        (4 * STAKE - 25_000_000, 4 * STAKE - 25_000_000)
+    );
+    assert_eq!(market.outcome_pool(&String::from_str(&test.env, "yes")), 0);
+    assert_eq!(client.get_user_position(&user, &market_id), None);
+    assert_eq!(
+        client.try_cash_out(&user, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    test.assert_solvent();
+}
+
+#[test]
+fn test_market_stays_solvent_after_cash_outs() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    client.set_cash_out_fee(&test.admin, &500);
+
+    let winner_a = bet(&test, &market_id, "yes", STAKE);
+    let winner_b = bet(&test, &market_id, "yes", 2 * STAKE);
+    let quitter_yes = bet(&test, &market_id, "yes", STAKE);
+    let quitter_no = bet(&test, &market_id, "no", 2 * STAKE);
+    bet(&test, &market_id, "no", STAKE);
+
+    // 100M of 700M at 400/700 is worth 57_142_857, 5% fee 2_857_142; then
+    // 200M of 642_857_143 at 300M is worth 93_333_333, 5% fee 4_666_666
+    let vault_before = client.get_fee_vault_balance(&None);
+    assert_eq!(client.cash_out(&quitter_yes, &market_id), 54_285_715);
+    assert_eq!(client.cash_out(&quitter_no, &market_id), 88_666_667);
+    assert_eq!(
+        client.get_fee_vault_balance(&None),
+        vault_before + 2_857_142 + 4_666_666
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        7 * STAKE - 57_142_857 - 93_333_333
+    );
+    test.assert_solvent();
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+
+    // Winners share the 549_523_810 left, less the 2% platform fee, 1:2
+    let paid_a = client.claim_winnings(&winner_a, &market_id);
+    let paid_b = client.claim_winnings(&winner_b, &market_id);
+    assert_eq!(paid_a, 179_511_111);
+    // Paid last, winner_b also takes the rounding remainder
+    assert_eq!(paid_b, 2 * paid_a + 1);
+    assert_eq!(
+        client.try_claim_winnings(&quitter_yes, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    test.assert_solvent();
+}
+
+#[test]
+fn test_cash_out_closed_near_deadline() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = bet(&test, &market_id, "yes", STAKE);
+    bet(&test, &market_id, "no", STAKE);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time - CASH_OUT_CUTOFF_SECONDS);
+    assert_eq!(
+        client.try_cash_out(&user, &market_id),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time - CASH_OUT_CUTOFF_SECONDS - 1);
+    assert_eq!(client.cash_out(&user, &market_id), 49_000_000);
+}
+
+#[test]
+fn test_cash_out_rejected_when_escrow_cannot_back_the_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = bet(&test, &market_id, "yes", STAKE);
+    bet(&test, &market_id, "no", 3 * STAKE);
+
+    // The 25M cash-out is covered, but the 375M left in the pool would not be
+    test.env.as_contract(&test.contract_id, || {
+        crate::escrow::EscrowManager::debit(&test.env, &market_id, 1).unwrap();
+    });
+    assert_eq!(
+        client.try_cash_out(&user, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 4 * STAKE);
+    assert_eq!(client.get_bet(&market_id, &user).unwrap().amount, STAKE);
+    assert!(client.get_bet(&market_id, &user).unwrap().is_active());
+
+    test.env.as_contract(&test.contract_id, || {
+        crate::escrow::EscrowManager::credit(&test.env, &market_id, 1).unwrap();
+    });
+    assert_eq!(client.cash_out(&user, &market_id), 24_500_000);
+}
+
+#[test]
+fn test_cash_out_rejected_when_remaining_winner_would_be_underpaid() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let quitter = bet(&test, &market_id, "yes", STAKE);
+    let winner = bet(&test, &market_id, "yes", STAKE);
+    bet(&test, &market_id, "no", 2 * STAKE);
+
+    // Escrow 1 short of the pool: after the cash-out, a "yes" win could not be
+    // paid in full, so nothing is written
+    test.env.as_contract(&test.contract_id, || {
+        crate::escrow::EscrowManager::debit(&test.env, &market_id, 1).unwrap();
+    });
+    let vault_before = client.get_fee_vault_balance(&None);
+    assert_eq!(
+        client.try_cash_out(&quitter, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 4 * STAKE);
+    assert_eq!(
+        market.outcome_pool(&String::from_str(&test.env, "yes")),
+        2 * STAKE
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before);
+
+    // Fully backed, the cash-out goes through and the winner gets the whole
+    // remaining pool, including the 50M the quitter left behind, after the
+    // platform fee
+    test.env.as_contract(&test.contract_id, || {
+        crate::escrow::EscrowManager::credit(&test.env, &market_id, 1).unwrap();
+    });
+    client.cash_out(&quitter, &market_id);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+    assert_eq!(
+        client.claim_winnings(&winner, &market_id),
+        (4 * STAKE - STAKE / 2) * 98 / 100
+    );
+    test.assert_solvent();
+}
+
+#[test]
+fn test_cash_out_needs_opposing_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = bet(&test, &market_id, "yes", STAKE);
+    bet(&test, &market_id, "yes", STAKE);
+
+    assert_eq!(
+        client.try_cash_out(&user, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_set_cash_out_fee(&test.admin, &10_001),
+        Err(Ok(Error::InvalidInput))
+    );
+}
@@ -1,5 +1,6 @@
-use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
-use crate::{PredictifyHybrid, PredictifyHybridClient};
+use crate::storage::DataKey;
+use crate::types::{Market, MarketState, OracleConfig, OracleProvider, OracleSigner};
+use crate::{Error, PredictifyHybrid, PredictifyHybridClient};
 use alloc::format;
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
@@ -43,6 +44,9 @@ fn create_test_market(
         feed_id: String::from_str(env, "BTC/USD"),
         threshold: 100,
         comparison: String::from_str(env, "gte"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     client.create_market(
@@ -197,7 +201,6 @@ fn test_query_events_by_tags() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #100)")]
 fn test_security_update_category_unauthorized() {
     let (env, client, admin) = setup_test();
     let market_id = create_test_market(&env, &client, &admin, "Security");
@@ -205,11 +208,13 @@ fn test_security_update_category_unauthorized() {
     let user = Address::generate(&env);
 
     // Try to update category as non-admin
-    client.update_event_category(&user, &market_id, &Some(String::from_str(&env, "Hack")));
+    assert_eq!(
+        client.try_update_event_category(&user, &market_id, &Some(String::from_str(&env, "Hack"))),
+        Err(Ok(Error::Unauthorized))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #100)")]
 fn test_security_update_tags_unauthorized() {
     let (env, client, admin) = setup_test();
     let market_id = create_test_market(&env, &client, &admin, "Security");
@@ -217,10 +222,13 @@ fn test_security_update_tags_unauthorized() {
     let user = Address::generate(&env);
 
     // Try to update tags as non-admin
-    client.update_event_tags(
-        &user,
-        &market_id,
-        &vec![&env, String::from_str(&env, "Hack")],
+    assert_eq!(
+        client.try_update_event_tags(
+            &user,
+            &market_id,
+            &vec![&env, String::from_str(&env, "Hack")],
+        ),
+        Err(Ok(Error::Unauthorized))
     );
 }
 
@@ -252,8 +260,8 @@ impl TokenTestSetup {
         // Store TokenID in contract
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         // Initialize the contract
@@ -293,6 +301,9 @@ impl TokenTestSetup {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 100,
                 comparison: String::from_str(&env, "gte"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             &None,
             &86400u64,
@@ -340,12 +351,14 @@ fn test_category_tags_do_not_affect_resolution_and_payouts() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &10_0000000,
+        &None,
     );
     client.place_bet(
         &setup.user2,
         &setup.market_id,
         &String::from_str(&setup.env, "no"),
         &20_0000000,
+        &None,
     );
 
     // Payout multiplier for "yes" should be (30/10)*100 = 300 and unchanged by category/tags
@@ -374,7 +387,6 @@ fn test_category_tags_do_not_affect_resolution_and_payouts() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #111)")]
 fn test_update_category_after_bets_forbidden() {
     let setup = TokenTestSetup::new();
     let client = setup.client();
@@ -385,12 +397,160 @@ fn test_update_category_after_bets_forbidden() {
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
         &10_0000000,
+        &None,
     );
 
     // Attempt to update category after bets have been placed -> should panic with #111
-    client.update_event_category(
-        &setup.admin,
-        &setup.market_id,
-        &Some(String::from_str(&setup.env, "Blocked")),
+    assert_eq!(
+        client.try_update_event_category(
+            &setup.admin,
+            &setup.market_id,
+            &Some(String::from_str(&setup.env, "Blocked")),
+        ),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+}
+
+// ===== MARKET METADATA =====
+
+#[test]
+fn test_set_market_metadata_and_query_by_category() {
+    let (env, client, admin) = setup_test();
+    let btc = create_test_market(&env, &client, &admin, "Bitcoin");
+    let eth = create_test_market(&env, &client, &admin, "Ethereum");
+    let match_day = create_test_market(&env, &client, &admin, "Arsenal");
+    let crypto = Symbol::new(&env, "crypto");
+    let sports = Symbol::new(&env, "sports");
+    let description = String::from_str(&env, "Resolves on the BTC/USD Reflector feed.");
+    let url = String::from_str(&env, "https://example.com/markets/btc");
+
+    assert!(client.get_market_metadata(&btc).is_none());
+
+    client.set_market_metadata(&admin, &btc, &crypto, &description, &url);
+    client.set_market_metadata(
+        &admin,
+        &eth,
+        &crypto,
+        &String::from_str(&env, ""),
+        &String::from_str(&env, ""),
+    );
+    client.set_market_metadata(
+        &admin,
+        &match_day,
+        &sports,
+        &String::from_str(&env, ""),
+        &String::from_str(&env, ""),
+    );
+
+    let metadata = client.get_market_metadata(&btc).unwrap();
+    assert_eq!(metadata.category, crypto);
+    assert_eq!(metadata.description, description);
+    assert_eq!(metadata.reference_url, url);
+    // The Market struct itself is untouched
+    assert!(client.get_market(&btc).unwrap().category.is_none());
+
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &0, &10),
+        vec![&env, btc.clone(), eth.clone()]
+    );
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &1, &10),
+        vec![&env, eth.clone()]
+    );
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &0, &1),
+        vec![&env, btc.clone()]
+    );
+    assert!(client
+        .get_markets_by_category(&Symbol::new(&env, "politics"), &0, &10)
+        .is_empty());
+
+    // Re-categorising moves the market between indexes
+    client.set_market_metadata(&admin, &btc, &sports, &description, &url);
+    assert_eq!(
+        client.get_markets_by_category(&crypto, &0, &10),
+        vec![&env, eth]
+    );
+    assert_eq!(
+        client.get_markets_by_category(&sports, &0, &10),
+        vec![&env, match_day, btc]
+    );
+}
+
+#[test]
+fn test_set_market_metadata_validation() {
+    let (env, client, admin) = setup_test();
+    let market_id = create_test_market(&env, &client, &admin, "Bitcoin");
+    let crypto = Symbol::new(&env, "crypto");
+    let empty = String::from_str(&env, "");
+    let long_description = String::from_str(
+        &env,
+        &"d".repeat(crate::config::MAX_METADATA_DESCRIPTION_LENGTH as usize + 1),
+    );
+    let long_url = String::from_str(
+        &env,
+        &"u".repeat(crate::config::MAX_REFERENCE_URL_LENGTH as usize + 1),
+    );
+
+    assert_eq!(
+        client.try_set_market_metadata(&admin, &market_id, &crypto, &long_description, &empty),
+        Err(Ok(Error::MetadataTooLong))
+    );
+    assert_eq!(
+        client.try_set_market_metadata(&admin, &market_id, &crypto, &empty, &long_url),
+        Err(Ok(Error::MetadataTooLong))
+    );
+    assert_eq!(
+        client.try_set_market_metadata(
+            &Address::generate(&env),
+            &market_id,
+            &crypto,
+            &empty,
+            &empty
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_market_metadata(
+            &admin,
+            &Symbol::new(&env, "missing"),
+            &crypto,
+            &empty,
+            &empty
+        ),
+        Err(Ok(Error::MarketNotFound))
+    );
+    assert!(client.get_market_metadata(&market_id).is_none());
+    assert!(client.get_markets_by_category(&crypto, &0, &10).is_empty());
+}
+
+#[test]
+fn test_set_market_metadata_length_boundaries() {
+    let (env, client, admin) = setup_test();
+    let market_id = create_test_market(&env, &client, &admin, "Bitcoin");
+    let crypto = Symbol::new(&env, "crypto");
+    let max_description = crate::config::MAX_METADATA_DESCRIPTION_LENGTH as usize;
+    let max_url = crate::config::MAX_REFERENCE_URL_LENGTH as usize;
+    assert_eq!(max_description, 500);
+
+    // Exactly at the limits is accepted
+    let description = String::from_str(&env, &"d".repeat(max_description));
+    let url = String::from_str(&env, &"u".repeat(max_url));
+    client.set_market_metadata(&admin, &market_id, &crypto, &description, &url);
+    let metadata = client.get_market_metadata(&market_id).unwrap();
+    assert_eq!(metadata.description, description);
+    assert_eq!(metadata.reference_url, url);
+
+    // One character over either limit is rejected and keeps the stored metadata
+    let long_description = String::from_str(&env, &"d".repeat(max_description + 1));
+    let long_url = String::from_str(&env, &"u".repeat(max_url + 1));
+    assert_eq!(
+        client.try_set_market_metadata(&admin, &market_id, &crypto, &long_description, &url),
+        Err(Ok(Error::MetadataTooLong))
+    );
+    assert_eq!(
+        client.try_set_market_metadata(&admin, &market_id, &crypto, &description, &long_url),
+        Err(Ok(Error::MetadataTooLong))
     );
+    assert_eq!(client.get_market_metadata(&market_id), Some(metadata));
 }
@@ -158,7 +158,7 @@ impl CircuitBreaker {
         env.storage()
             .instance()
             .get(&Symbol::new(env, Self::CONFIG_KEY))
-            .ok_or(Error::CBNotInitialized)
+            .ok_or(Error::ConfigNotFound)
     }
 
     /// Update circuit breaker configuration
@@ -196,7 +196,7 @@ impl CircuitBreaker {
         env.storage()
             .instance()
             .get(&Symbol::new(env, Self::STATE_KEY))
-            .ok_or(Error::CBNotInitialized)
+            .ok_or(Error::ConfigNotFound)
     }
 
     /// Update circuit breaker state
@@ -219,7 +219,7 @@ impl CircuitBreaker {
 
         // Check if already paused
         if state.state == BreakerState::Open {
-            return Err(Error::CBAlreadyOpen);
+            return Err(Error::InvalidState);
         }
 
         // Update state
@@ -365,7 +365,7 @@ impl CircuitBreaker {
 
         // Check if circuit breaker is open
         if state.state != BreakerState::Open && state.state != BreakerState::HalfOpen {
-            return Err(Error::CBNotOpen);
+            return Err(Error::InvalidState);
         }
 
         // Reset state
@@ -494,7 +494,7 @@ impl CircuitBreaker {
         env.storage()
             .instance()
             .get(&Symbol::new(env, Self::EVENTS_KEY))
-            .ok_or(Error::CBNotInitialized)
+            .ok_or(Error::ConfigNotFound)
     }
 
     // ===== STATUS AND MONITORING =====
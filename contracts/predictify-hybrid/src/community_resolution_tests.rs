@@ -0,0 +1,294 @@
+#![cfg(test)]
+
+//! Community vote resolution tests.
+//!
+//! When every oracle has failed and the resolution timeout plus grace period
+//! has passed, bettors resolve the market by a stake-weighted vote; a tie or a
+//! missed quorum voids it and refunds everyone.
+
+use crate::bets::BetStorage;
+use crate::errors::Error;
+use crate::pyth_oracle_tests::MockPythContract;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{Bet, Market, MarketState, OracleConfig, OracleProvider, OracleSigner};
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+const RESOLUTION_TIMEOUT: u64 = 3600;
+
+/// Create a market on an oracle that never publishes and place the given bets.
+fn create_failing_market(test: &PredictifyTest, bets: &[(&Address, &str, i128)]) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address: oracle,
+            feed_id: String::from_str(&test.env, BTC_FEED),
+            threshold: 2_500_000_000_000,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &RESOLUTION_TIMEOUT,
+        &None,
+        &None,
+        &None,
+    );
+    for (user, outcome, amount) in bets {
+        client.place_bet(
+            user,
+            &market_id,
+            &String::from_str(&test.env, outcome),
+            amount,
+            &None,
+        );
+    }
+    market_id
+}
+
+fn set_time_after_end(test: &PredictifyTest, market_id: &Symbol, seconds: u64) {
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+}
+
+/// Let the oracle time out, record the failure and open a vote as `opener`.
+fn open_vote(test: &PredictifyTest, market_id: &Symbol, opener: &Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    set_time_after_end(test, market_id, RESOLUTION_TIMEOUT + 1);
+    client.resolve_market(market_id);
+
+    let grace = client.get_community_vote_config().grace_period;
+    set_time_after_end(test, market_id, RESOLUTION_TIMEOUT + grace);
+    test.env.mock_all_auths();
+    client.open_community_vote(opener, market_id);
+}
+
+fn end_voting(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let ends_at = client.get_community_vote(market_id).unwrap().ends_at;
+    let market = client.get_market(market_id).unwrap();
+    set_time_after_end(test, market_id, ends_at - market.end_time);
+}
+
+#[test]
+fn test_community_vote_clean_majority_resolves_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let carol = test.create_funded_user();
+    let market_id = create_failing_market(
+        &test,
+        &[
+            (&alice, "yes", 40_0000000),
+            (&bob, "no", 30_0000000),
+            (&carol, "no", 20_0000000),
+        ],
+    );
+
+    // Not before the oracle failure is recorded and the grace period passes
+    test.env.mock_all_auths();
+    set_time_after_end(&test, &market_id, RESOLUTION_TIMEOUT + 1);
+    assert_eq!(
+        client.try_open_community_vote(&alice, &market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    client.resolve_market(&market_id);
+    assert_eq!(
+        client.try_open_community_vote(&alice, &market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    let outsider = Address::generate(&test.env);
+    let grace = client.get_community_vote_config().grace_period;
+    set_time_after_end(&test, &market_id, RESOLUTION_TIMEOUT + grace);
+    assert_eq!(
+        client.try_open_community_vote(&outsider, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.open_community_vote(&alice, &market_id);
+    assert_eq!(
+        client.try_open_community_vote(&bob, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.cast_community_vote(&alice, &market_id, &yes);
+    client.cast_community_vote(&bob, &market_id, &no);
+    client.cast_community_vote(&carol, &market_id, &no);
+    assert_eq!(
+        client.try_cast_community_vote(&bob, &market_id, &yes),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_cast_community_vote(&outsider, &market_id, &yes),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let vote = client.get_community_vote(&market_id).unwrap();
+    assert_eq!(vote.tallies.get(yes.clone()), Some(40_0000000));
+    assert_eq!(vote.tallies.get(no.clone()), Some(50_0000000));
+    assert_eq!(
+        client.try_finalize_community_vote(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    end_voting(&test, &market_id);
+    assert_eq!(
+        client.try_cast_community_vote(&outsider, &market_id, &yes),
+        Err(Ok(Error::MarketClosed))
+    );
+    assert_eq!(client.finalize_community_vote(&market_id), Some(no.clone()));
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, no]));
+    assert_eq!(
+        client.try_finalize_community_vote(&market_id),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_community_vote_tie_refunds_bettors() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let market_id = create_failing_market(
+        &test,
+        &[(&alice, "yes", 30_0000000), (&bob, "no", 30_0000000)],
+    );
+    let alice_before = token.balance(&alice);
+
+    open_vote(&test, &market_id, &alice);
+    client.cast_community_vote(&alice, &market_id, &String::from_str(&test.env, "yes"));
+    client.cast_community_vote(&bob, &market_id, &String::from_str(&test.env, "no"));
+
+    // Refunds stay blocked for bettors while the vote is running
+    assert_eq!(
+        client.try_refund_on_oracle_failure(&bob, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    end_voting(&test, &market_id);
+    assert_eq!(client.finalize_community_vote(&market_id), None);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Voided);
+    assert!(market.winning_outcomes.is_none());
+    client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(token.balance(&alice), alice_before + 30_0000000);
+}
+
+#[test]
+fn test_community_vote_without_quorum_refunds_bettors() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let market_id = create_failing_market(
+        &test,
+        &[(&alice, "yes", 20_0000000), (&bob, "no", 80_0000000)],
+    );
+    let bob_before = token.balance(&bob);
+
+    // Only 20% of the stake votes against a 50% quorum
+    open_vote(&test, &market_id, &alice);
+    client.cast_community_vote(&alice, &market_id, &String::from_str(&test.env, "yes"));
+
+    end_voting(&test, &market_id);
+    assert_eq!(client.finalize_community_vote(&market_id), None);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Voided
+    );
+    client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(token.balance(&bob), bob_before + 80_0000000);
+}
+
+#[test]
+fn test_community_vote_weight_comes_from_stake_snapshot() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let carol = test.create_funded_user();
+    let market_id = create_failing_market(
+        &test,
+        &[
+            (&alice, "yes", 40_0000000),
+            (&bob, "no", 30_0000000),
+            (&carol, "no", 20_0000000),
+        ],
+    );
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    // Carol pulls her bet before the deadline and ends up with no weight
+    client.withdraw_bet(&carol, &market_id);
+    assert!(client.get_vote_tally(&market_id).is_empty());
+
+    open_vote(&test, &market_id, &alice);
+    let snapshot = client.get_stake_snapshot(&market_id).unwrap();
+    assert_eq!(snapshot.total, 70_0000000);
+    assert_eq!(snapshot.stakes.get(carol.clone()), None);
+
+    // Stake recorded after the snapshot does not count
+    test.env.as_contract(&test.contract_id, || {
+        let mut bet = BetStorage::get_bet(&test.env, &market_id, &bob).unwrap();
+        bet.amount = 500_0000000;
+        BetStorage::store_bet(&test.env, &bet).unwrap();
+        let late = Bet::new(&test.env, carol.clone(), market_id.clone(), no.clone(), 500_0000000);
+        BetStorage::store_bet(&test.env, &late).unwrap();
+    });
+
+    assert_eq!(
+        client.try_cast_community_vote(&carol, &market_id, &no),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.cast_community_vote(&bob, &market_id, &no);
+    client.cast_community_vote(&alice, &market_id, &yes);
+
+    assert_eq!(
+        client.get_vote_tally(&market_id),
+        vec![&test.env, (yes.clone(), 40_0000000), (no, 30_0000000)]
+    );
+
+    end_voting(&test, &market_id);
+    assert_eq!(client.finalize_community_vote(&market_id), Some(yes));
+}
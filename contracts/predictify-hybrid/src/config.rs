@@ -2,6 +2,7 @@ extern crate alloc;
 use soroban_sdk::{contracttype, Address, Env, String, Symbol};
 
 use crate::errors::Error;
+use crate::storage::DataKey;
 
 /// Configuration management system for Predictify Hybrid contract
 ///
@@ -17,18 +18,34 @@ use crate::errors::Error;
 /// Percentage denominator for calculations (100%)
 pub const PERCENTAGE_DENOMINATOR: i128 = 100;
 
+/// Release number returned by `get_version`. Bump with every release so
+/// indexers can tell when a deployed instance has been upgraded.
+pub const CONTRACT_VERSION: u32 = 1;
+
+/// Longest delay the admin may put between proposing and executing an upgrade (30 days)
+pub const MAX_UPGRADE_TIMELOCK_SECONDS: u64 = 30 * 24 * 60 * 60;
+
 /// Maximum market duration in days
 pub const MAX_MARKET_DURATION_DAYS: u32 = 365;
 
 /// Minimum market duration in days
 pub const MIN_MARKET_DURATION_DAYS: u32 = 1;
 
-/// Maximum number of outcomes per market
+/// Maximum number of outcomes per market (default for the admin-configurable limit)
 pub const MAX_MARKET_OUTCOMES: u32 = 10;
 
+/// Upper bound the admin may raise the outcome limit to
+pub const MAX_CONFIGURABLE_MARKET_OUTCOMES: u32 = 50;
+
 /// Minimum number of outcomes per market
 pub const MIN_MARKET_OUTCOMES: u32 = 2;
 
+/// Outcome that profits when a scalar market's value lands high in its range
+pub const SCALAR_LONG_OUTCOME: &str = "long";
+
+/// Outcome that profits when a scalar market's value lands low in its range
+pub const SCALAR_SHORT_OUTCOME: &str = "short";
+
 /// Maximum question length in characters
 pub const MAX_QUESTION_LENGTH: u32 = 500;
 
@@ -36,7 +53,7 @@ pub const MAX_QUESTION_LENGTH: u32 = 500;
 pub const MIN_QUESTION_LENGTH: u32 = 10;
 
 /// Maximum outcome length in characters
-pub const MAX_OUTCOME_LENGTH: u32 = 100;
+pub const MAX_OUTCOME_LENGTH: u32 = 32;
 
 /// Minimum outcome length in characters
 pub const MIN_OUTCOME_LENGTH: u32 = 2;
@@ -62,6 +79,21 @@ pub const MAX_CATEGORY_LENGTH: u32 = 100;
 /// Minimum category name length in characters
 pub const MIN_CATEGORY_LENGTH: u32 = 2;
 
+/// Maximum market metadata description length in characters
+pub const MAX_METADATA_DESCRIPTION_LENGTH: u32 = 500;
+
+/// Maximum market reference URL length in characters
+pub const MAX_REFERENCE_URL_LENGTH: u32 = 256;
+
+/// Maximum number of bettors on a private market's allowlist
+pub const MAX_MARKET_ALLOWLIST_SIZE: u32 = 100;
+
+// ===== TOKEN CONSTANTS =====
+
+/// Decimals that the default amount constants (bet bounds, fees) are written in.
+/// Amounts are rescaled to the staking token's own decimals when it differs.
+pub const DEFAULT_TOKEN_DECIMALS: u32 = 7;
+
 // ===== FEE CONSTANTS =====
 
 /// Default platform fee percentage (2%)
@@ -85,6 +117,76 @@ pub const MAX_PLATFORM_FEE_PERCENTAGE: i128 = 10;
 /// Minimum platform fee percentage
 pub const MIN_PLATFORM_FEE_PERCENTAGE: i128 = 0;
 
+/// Default platform fee in basis points (2%)
+pub const DEFAULT_PLATFORM_FEE_BPS: u32 = 200;
+
+/// Maximum platform fee in basis points (10%)
+pub const MAX_PLATFORM_FEE_BPS: u32 = 1_000;
+
+/// Maximum per-market creator fee in basis points (3%)
+pub const MAX_CREATOR_FEE_BPS: u32 = 300;
+
+/// Storage key for the global platform fee in basis points
+pub const FEE_BPS_STORAGE_KEY: &str = "fee_bps";
+
+/// Storage key for the pool-size fee tiers applied at resolution
+pub const FEE_TIERS_STORAGE_KEY: &str = "pool_fee_tiers";
+
+/// Storage key for the flat fee non-admin creators pay per market
+pub const CREATION_FEE_STORAGE_KEY: &str = "mkt_creation_fee";
+
+/// Default refundable bond non-admin creators post per market (10 XLM)
+pub const DEFAULT_CREATION_BOND: i128 = 100_000_000;
+
+/// Storage key for the refundable bond non-admin creators post per market
+pub const CREATION_BOND_STORAGE_KEY: &str = "mkt_creation_bond";
+
+/// Storage key for the keeper incentive in basis points of a market's platform fee
+pub const KEEPER_BPS_STORAGE_KEY: &str = "keeper_bps";
+
+/// Storage key for the reward paid to keepers that settle overdue markets
+pub const KEEPER_REWARD_STORAGE_KEY: &str = "keeper_reward";
+
+/// Storage key for the number of entries ever written to the admin log
+pub const ADMIN_LOG_COUNT_STORAGE_KEY: &str = "admin_log_count";
+
+/// Number of most recent admin actions retained by the on-chain admin log
+pub const ADMIN_LOG_CAPACITY: u32 = 200;
+
+/// Storage key for the delay between scheduling and executing a timelocked admin action
+pub const ADMIN_ACTION_DELAY_STORAGE_KEY: &str = "admin_action_delay";
+
+/// Storage key for the timelocked admin actions waiting out their delay
+pub const PENDING_ACTIONS_STORAGE_KEY: &str = "pending_actions";
+
+/// Storage key for the identifier of the next scheduled admin action
+pub const NEXT_ACTION_ID_STORAGE_KEY: &str = "next_action_id";
+
+/// Default delay before a timelocked admin action can be executed (24 hours)
+pub const DEFAULT_ADMIN_ACTION_DELAY_SECONDS: u64 = 24 * 60 * 60;
+
+/// Longest admin action delay the admin may set (30 days)
+pub const MAX_ADMIN_ACTION_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Most timelocked admin actions that may be pending at once
+pub const MAX_PENDING_ACTIONS: u32 = 20;
+
+/// Storage key for the admin signer set and approval threshold
+pub const ADMIN_SIGNERS_STORAGE_KEY: &str = "admin_signers";
+
+/// Storage key for the identifier of the next proposed multisig admin operation
+pub const NEXT_MULTISIG_OP_ID_STORAGE_KEY: &str = "next_msig_op_id";
+
+/// Largest admin signer set
+pub const MAX_ADMIN_SIGNERS: u32 = 10;
+
+/// Default share of the platform fee on a referred user's winnings paid to
+/// their referrer, in basis points (10%)
+pub const DEFAULT_REFERRAL_BPS: u32 = 1_000;
+
+/// Storage key for the referral share in basis points of the platform fee
+pub const REFERRAL_BPS_STORAGE_KEY: &str = "referral_bps";
+
 // ===== VOTING CONSTANTS =====
 
 /// Minimum vote stake (0.1 XLM)
@@ -108,6 +210,18 @@ pub const HIGH_ACTIVITY_THRESHOLD: u32 = 100;
 /// Dispute extension hours
 pub const DISPUTE_EXTENSION_HOURS: u32 = 24;
 
+/// Reward paid from the fee vault to an upheld result disputer, in basis points of
+/// their stake (50%)
+pub const DISPUTE_REWARD_BPS: i128 = 5_000;
+
+/// Appeals allowed after the first ruling on a result dispute. Each needs twice
+/// the previous bond, and the ruling on the last one is final.
+pub const MAX_DISPUTE_APPEAL_ROUNDS: u32 = 2;
+
+/// Share of a rejected appeal's bond paid to the party whose upheld dispute it
+/// challenged, in basis points (50%); the rest goes to the fee vault
+pub const DISPUTE_BOND_WINNER_SHARE_BPS: i128 = 5_000;
+
 // ===== EXTENSION CONSTANTS =====
 
 /// Maximum extension days
@@ -148,11 +262,39 @@ pub const MIN_VOTES_FOR_CONSENSUS: u32 = 5;
 /// with no oracle result, anyone may trigger refund on oracle failure.
 pub const DEFAULT_RESOLUTION_TIMEOUT_SECONDS: u64 = 604_800;
 
+/// Maximum resolution timeout accepted at market creation (1 year)
+pub const MAX_RESOLUTION_TIMEOUT_SECONDS: u64 = 31_536_000;
+
+/// Default dispute window after resolution (24 hours)
+pub const DEFAULT_DISPUTE_WINDOW_SECONDS: u64 = 86_400;
+
+/// Grace period added after the resolution timeout and dispute window before an
+/// unresolved market can be voided by anyone (7 days)
+pub const RESOLUTION_DEADLINE_GRACE_SECONDS: u64 = 604_800;
+
+/// Minimum dispute window, for the default and per-market values (1 hour)
+pub const MIN_DISPUTE_WINDOW_SECONDS: u64 = 3_600;
+
+/// Maximum dispute window, for the default and per-market values (7 days)
+pub const MAX_DISPUTE_WINDOW_SECONDS: u64 = 604_800;
+
 // ===== ORACLE CONSTANTS =====
 
 /// Maximum oracle price age (1 hour)
 pub const MAX_ORACLE_PRICE_AGE: u64 = 3600;
 
+/// Maximum number of primary oracles aggregated for one market
+pub const MAX_PRIMARY_ORACLES: u32 = 3;
+
+/// Default wait after the resolution timeout before a community vote may open (3 days)
+pub const DEFAULT_COMMUNITY_VOTE_GRACE_PERIOD: u64 = 259_200;
+
+/// Default length of a community vote (3 days)
+pub const DEFAULT_COMMUNITY_VOTING_PERIOD: u64 = 259_200;
+
+/// Default share of stake that must take part in a community vote (50%)
+pub const DEFAULT_COMMUNITY_VOTE_QUORUM_BPS: u32 = 5_000;
+
 /// Oracle retry attempts
 pub const ORACLE_RETRY_ATTEMPTS: u32 = 3;
 
@@ -161,12 +303,18 @@ pub const ORACLE_TIMEOUT_SECONDS: u64 = 30;
 
 // ===== STORAGE CONSTANTS =====
 
-/// Storage key for admin address
+/// Legacy storage key for admin address, now `DataKey::Admin`
 pub const ADMIN_STORAGE_KEY: &str = "Admin";
 
-/// Storage key for token ID
+/// Legacy storage key for token ID, now `DataKey::Token`
 pub const TOKEN_ID_STORAGE_KEY: &str = "TokenID";
 
+/// Legacy storage key for the staking token's decimals, now `DataKey::TokenDecimals`
+pub const TOKEN_DECIMALS_STORAGE_KEY: &str = "TokenDecimals";
+
+/// Legacy storage key for the staking token's symbol, now `DataKey::TokenSymbol`
+pub const TOKEN_SYMBOL_STORAGE_KEY: &str = "TokenSymbol";
+
 /// Storage key for fee configuration
 pub const FEE_CONFIG_STORAGE_KEY: &str = "FeeConfig";
 
@@ -176,6 +324,12 @@ pub const RESOLUTION_ANALYTICS_STORAGE_KEY: &str = "ResolutionAnalytics";
 /// Storage key for oracle statistics
 pub const ORACLE_STATS_STORAGE_KEY: &str = "OracleStats";
 
+/// Remaining TTL, in ledgers, at which market and bet entries are extended (~7 days)
+pub const DEFAULT_TTL_THRESHOLD_LEDGERS: u32 = 120_960;
+
+/// TTL, in ledgers, market and bet entries are extended to (~30 days)
+pub const DEFAULT_TTL_EXTEND_TO_LEDGERS: u32 = 518_400;
+
 // ===== CONFIGURATION STRUCTS =====
 
 /// Deployment environment specification for the Predictify Hybrid contract.
@@ -2044,7 +2198,7 @@ impl ConfigManager {
     ///
     /// Configuration storage:
     /// - Uses persistent storage for durability across contract calls
-    /// - Stores under the "ContractConfig" key for consistent retrieval
+    /// - Stores under `DataKey::ContractConfig` for consistent retrieval
     /// - Overwrites any existing configuration
     /// - Atomic operation ensuring consistency
     ///
@@ -2056,11 +2210,31 @@ impl ConfigManager {
     /// - Environment-specific deployments
     /// - Configuration resets and migrations
     pub fn store_config(env: &Env, config: &ContractConfig) -> Result<(), Error> {
-        let key = Symbol::new(env, "ContractConfig");
-        env.storage().persistent().set(&key, config);
+        let key = DataKey::ContractConfig;
+        env.storage().instance().set(&key, config);
         Ok(())
     }
 
+    /// Decimals of the staking token recorded by `set_token`, or
+    /// `DEFAULT_TOKEN_DECIMALS` when none has been recorded.
+    pub fn get_token_decimals(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenDecimals)
+            .unwrap_or(DEFAULT_TOKEN_DECIMALS)
+    }
+
+    /// Convert an amount written at `DEFAULT_TOKEN_DECIMALS` into base units of
+    /// the staking token, so `1_0000000` is always one whole token.
+    pub fn to_token_units(env: &Env, amount: i128) -> i128 {
+        let decimals = Self::get_token_decimals(env);
+        if decimals >= DEFAULT_TOKEN_DECIMALS {
+            amount.saturating_mul(10i128.saturating_pow(decimals - DEFAULT_TOKEN_DECIMALS))
+        } else {
+            amount / 10i128.pow(DEFAULT_TOKEN_DECIMALS - decimals)
+        }
+    }
+
     /// Retrieves the current contract configuration from persistent storage.
     ///
     /// This function loads the previously stored contract configuration from
@@ -2114,12 +2288,12 @@ impl ConfigManager {
     /// - Oracle integration and resolution
     /// - Admin operations and updates
     pub fn get_config(env: &Env) -> Result<ContractConfig, Error> {
-        let key = Symbol::new(env, "ContractConfig");
+        let key = DataKey::ContractConfig;
         // Check if key exists before trying to get it to avoid segfaults
         match env
             .storage()
-            .persistent()
-            .get::<Symbol, ContractConfig>(&key)
+            .instance()
+            .get::<DataKey, ContractConfig>(&key)
         {
             Some(config) => Ok(config),
             None => Err(Error::ConfigNotFound),
@@ -2419,6 +2593,38 @@ impl ConfigManager {
         Ok(cfg)
     }
 
+    /// Update maximum accepted oracle price age (requires admin with update_config permission)
+    pub fn update_oracle_max_price_age(
+        env: &Env,
+        admin: Address,
+        max_price_age: u64,
+    ) -> Result<ContractConfig, Error> {
+        crate::admin::AdminAccessControl::validate_admin_for_action(env, &admin, "update_config")?;
+
+        let mut cfg = Self::get_config(env)?;
+        let old = cfg.oracle.max_price_age;
+        cfg.oracle.max_price_age = max_price_age;
+
+        ConfigValidator::validate_oracle_config(&cfg.oracle)?;
+        Self::update_config(env, &cfg)?;
+
+        let change_type = String::from_str(env, "oracle_max_price_age");
+        let old_s = String::from_str(env, &alloc::format!("{}", old));
+        let new_s = String::from_str(env, &alloc::format!("{}", max_price_age));
+        crate::events::EventEmitter::emit_config_updated(env, &admin, &change_type, &old_s, &new_s);
+
+        let record = ConfigUpdateRecord {
+            updated_by: admin,
+            change_type,
+            old_value: old_s,
+            new_value: new_s,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::push_history(env, &record);
+
+        Ok(cfg)
+    }
+
     /// Update market limits (requires admin with update_config permission)
     pub fn update_market_limits(
         env: &Env,
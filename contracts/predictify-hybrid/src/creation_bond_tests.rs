@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+//! Market creation bond tests.
+//!
+//! Non-admin creators post a refundable bond with every market. It comes back
+//! when the market resolves or is voided for low participation, and goes to
+//! the fee vault when the admin cancels the market as `Unresolvable` or
+//! `Malicious`.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, OracleConfig, OracleProvider, OracleSigner};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const BOND: i128 = 50_000_000;
+
+/// An allowlisted, funded creator; the creation fee is waived so balances
+/// only move by the bond
+fn bonded_creator(test: &PredictifyTest) -> Address {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let creator = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.add_market_creator(&test.admin, &creator);
+    client.set_creation_fee(&test.admin, &0);
+    client.set_creation_bond(&test.admin, &BOND);
+    creator
+}
+
+fn create_market_as(
+    test: &PredictifyTest,
+    creator: &Address,
+    min_pool_size: Option<i128>,
+) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        creator,
+        &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2_500_000,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &86_400,
+        &min_pool_size,
+        &None,
+        &None,
+    )
+}
+
+fn advance_past_end(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+}
+
+#[test]
+fn test_creation_bond_returned_on_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = bonded_creator(&test);
+    let before = token.balance(&creator);
+
+    let market_id = create_market_as(&test, &creator, None);
+    assert_eq!(token.balance(&creator), before - BOND);
+    assert_eq!(client.get_market_creation_bond(&market_id), BOND);
+
+    advance_past_end(&test, &market_id);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(token.balance(&creator), before);
+    assert_eq!(client.get_market_creation_bond(&market_id), 0);
+
+    // The admin never posts a bond
+    let admin_before = token.balance(&test.admin);
+    let admin_market = create_market_as(&test, &test.admin, None);
+    assert_eq!(token.balance(&test.admin), admin_before);
+    assert_eq!(client.get_market_creation_bond(&admin_market), 0);
+}
+
+#[test]
+fn test_creation_bond_returned_when_voided_for_low_participation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = bonded_creator(&test);
+    let before = token.balance(&creator);
+
+    let market_id = create_market_as(&test, &creator, Some(1_000_000_000));
+    advance_past_end(&test, &market_id);
+    client.void_market(&market_id);
+    assert_eq!(token.balance(&creator), before);
+    assert_eq!(client.get_market_creation_bond(&market_id), 0);
+}
+
+#[test]
+fn test_creation_bond_slashed_for_malicious_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = bonded_creator(&test);
+    let before = token.balance(&creator);
+    let vault_before = client.get_fee_vault_balance(&None);
+
+    let market_id = create_market_as(&test, &creator, None);
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Malicious, &None);
+    assert_eq!(token.balance(&creator), before - BOND);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + BOND);
+    assert_eq!(client.get_market_creation_bond(&market_id), 0);
+
+    // An administrative cancellation returns the bond instead
+    let market_id = create_market_as(&test, &creator, None);
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+    assert_eq!(token.balance(&creator), before - BOND);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + BOND);
+}
+
+#[test]
+fn test_bond_held_after_oracle_failure_is_ruled_on_by_admin() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = bonded_creator(&test);
+    let before = token.balance(&creator);
+    let vault_before = client.get_fee_vault_balance(&None);
+
+    // Refunding bettors does not decide whether the market was junk
+    let market_id = create_market_as(&test, &creator, None);
+    advance_past_end(&test, &market_id);
+    client.refund_on_oracle_failure(&test.admin, &market_id);
+    assert_eq!(client.get_market_creation_bond(&market_id), BOND);
+
+    assert_eq!(
+        client.cancel_event(&test.admin, &market_id, &CancelReason::Unresolvable, &None),
+        0
+    );
+    assert_eq!(token.balance(&creator), before - BOND);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + BOND);
+    assert_eq!(client.get_market_creation_bond(&market_id), 0);
+
+    // Only the admin rules on bonds
+    assert_eq!(
+        client.try_cancel_event(&creator, &market_id, &CancelReason::Administrative, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+}
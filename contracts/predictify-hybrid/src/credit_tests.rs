@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+//! Free-bet credit tests.
+//!
+//! Credit-backed stakes join the pools normally, but the credited principal
+//! returns to the fee vault at settlement: a winner keeps only the winnings.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, String, Symbol};
+
+const STAKE: i128 = 100_000_000;
+const CREDIT_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+fn grant(test: &PredictifyTest, user: &Address, amount: i128) -> u64 {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let expires_at = test.env.ledger().timestamp() + CREDIT_LIFETIME;
+    client.grant_credit(&test.admin, user, &amount, &expires_at);
+    expires_at
+}
+
+fn resolve(test: &PredictifyTest, market_id: &Symbol, outcome: &str) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(
+        &test.admin,
+        market_id,
+        &String::from_str(&test.env, outcome),
+    );
+    test.advance_past_payout_unlock(market_id);
+}
+
+#[test]
+fn test_credit_win_pays_only_winnings() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let user = Address::generate(&test.env);
+    let other = test.create_funded_user();
+
+    let admin_before = token.balance(&test.admin);
+    grant(&test, &user, STAKE);
+    assert_eq!(token.balance(&test.admin), admin_before - STAKE);
+
+    let bet = client.place_bet_with_credit(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+    );
+    assert_eq!(bet.amount, STAKE);
+    assert_eq!(client.get_bet_credit(&user), None);
+    assert_eq!(client.get_market(&market_id).unwrap().total_staked, STAKE);
+    assert_eq!(
+        client.try_withdraw_bet(&user, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    client.place_bet(
+        &other,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+    resolve(&test, &market_id, "yes");
+
+    // 196M payout on the 200M pool, less the 100M credited principal
+    let vault_before = client.get_fee_vault_balance(&None);
+    assert_eq!(client.claim_winnings(&user, &market_id), 96_000_000);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + STAKE);
+    test.assert_solvent();
+}
+
+#[test]
+fn test_credit_loss_pays_nothing() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = Address::generate(&test.env);
+    let winner = test.create_funded_user();
+    grant(&test, &user, 2 * STAKE);
+
+    client.place_bet_with_credit(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+    );
+    assert_eq!(client.get_bet_credit(&user).unwrap().amount, STAKE);
+    client.place_bet(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    resolve(&test, &market_id, "yes");
+
+    assert_eq!(client.claim_winnings(&user, &market_id), 0);
+    // The winner is paid out of the credit-backed stake like any other
+    assert_eq!(client.claim_winnings(&winner, &market_id), 196_000_000);
+    test.assert_solvent();
+}
+
+#[test]
+fn test_expired_credit_unusable_and_reclaimable() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let user = Address::generate(&test.env);
+    let yes = String::from_str(&test.env, "yes");
+
+    let admin_before = token.balance(&test.admin);
+    let expires_at = grant(&test, &user, STAKE);
+    assert_eq!(
+        client.try_place_bet_with_credit(&user, &market_id, &yes, &(STAKE + 1)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = expires_at);
+    assert_eq!(
+        client.try_place_bet_with_credit(&user, &market_id, &yes, &STAKE),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    assert_eq!(client.reclaim_credit(&test.admin, &user), STAKE);
+    assert_eq!(token.balance(&test.admin), admin_before);
+    assert_eq!(client.get_bet_credit(&user), None);
+    assert_eq!(
+        client.try_grant_credit(&user, &user, &STAKE, &(expires_at + 1)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,183 @@
+//! Promotional free-bet credits.
+//!
+//! The admin grants a user credit by depositing the same amount of the global
+//! staking token, so every credit is fully backed. Credit is spent with
+//! `place_bet_with_credit` and the stake joins the pools like any other. The
+//! credited principal is never paid out to the user: on a win or a refund it is
+//! deducted from the payout and returned to the fee vault, so the user keeps
+//! only the winnings. Credit that has expired can no longer be spent and its
+//! backing can be reclaimed by the admin.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::amounts;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::fees::FeeTracker;
+use crate::markets::MarketUtils;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::storage::DataKey;
+use crate::types::BetCredit;
+
+/// Free-bet credit ledger.
+pub struct CreditManager;
+
+impl CreditManager {
+    /// Credit held by `user`, including credit that has expired.
+    pub fn get_credit(env: &Env, user: &Address) -> Option<BetCredit> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("credit"), user.clone()))
+    }
+
+    /// Part of `user`'s stake on a market that was paid with credit.
+    pub fn get_credit_stake(env: &Env, market_id: &Symbol, user: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("crd_stake"), market_id.clone(), user.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Grant `amount` of credit to `user`, spendable until `expires_at`.
+    ///
+    /// The admin deposits `amount` of the global staking token to back it. Credit
+    /// already held is topped up and takes the new expiry.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidInput` - `amount` is not positive or `expires_at` has passed
+    /// * `Error::InsufficientBalance` - Admin cannot fund the deposit
+    pub fn grant_credit(
+        env: &Env,
+        admin: &Address,
+        user: &Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<BetCredit, Error> {
+        admin.require_auth();
+        Self::require_admin(env, admin)?;
+        if amount <= 0 || expires_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidInput);
+        }
+
+        let held = Self::get_credit(env, user).map(|c| c.amount).unwrap_or(0);
+        let credit = BetCredit {
+            amount: amounts::checked_add(held, amount)?,
+            expires_at,
+        };
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        if token_client.balance(admin) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        ReentrancyGuard::guarded(env, || {
+            MarketUtils::receive_tokens(env, &token_client, admin, amount)
+        })
+        .map_err(|_| Error::InvalidState)??;
+
+        Self::set_credit(env, user, &credit);
+        EventEmitter::emit_credit_granted(env, user, admin, amount, &credit);
+        Ok(credit)
+    }
+
+    /// Withdraw `user`'s unspent credit and return its backing to the admin.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::NothingToClaim` - `user` holds no credit
+    pub fn reclaim_credit(env: &Env, admin: &Address, user: &Address) -> Result<i128, Error> {
+        admin.require_auth();
+        Self::require_admin(env, admin)?;
+        let credit = Self::get_credit(env, user).ok_or(Error::NothingToClaim)?;
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("credit"), user.clone()));
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), admin, &credit.amount)
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        EventEmitter::emit_credit_reclaimed(env, user, admin, &credit);
+        Ok(credit.amount)
+    }
+
+    /// Spend `amount` of `user`'s credit on a bet in a global-token market.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InsufficientBalance` - Credit is missing, expired or too small
+    pub fn spend(env: &Env, market_id: &Symbol, user: &Address, amount: i128) -> Result<(), Error> {
+        let mut credit = Self::get_credit(env, user).ok_or(Error::InsufficientBalance)?;
+        if env.ledger().timestamp() >= credit.expires_at || credit.amount < amount {
+            return Err(Error::InsufficientBalance);
+        }
+
+        credit.amount -= amount;
+        if credit.amount > 0 {
+            Self::set_credit(env, user, &credit);
+        } else {
+            env.storage()
+                .persistent()
+                .remove(&(symbol_short!("credit"), user.clone()));
+        }
+
+        let staked = amounts::checked_add(Self::get_credit_stake(env, market_id, user), amount)?;
+        env.storage().persistent().set(
+            &(symbol_short!("crd_stake"), market_id.clone(), user.clone()),
+            &staked,
+        );
+        Ok(())
+    }
+
+    /// Return the credited principal of `user`'s position out of `payout` to the
+    /// fee vault, and return what is left for the user.
+    ///
+    /// Call once the position is settled (won, lost or refunded); the credit
+    /// stake is cleared so it is never repaid twice.
+    pub fn settle(env: &Env, market_id: &Symbol, user: &Address, payout: i128) -> Result<i128, Error> {
+        let principal = Self::get_credit_stake(env, market_id, user);
+        if principal == 0 {
+            return Ok(payout);
+        }
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("crd_stake"), market_id.clone(), user.clone()));
+
+        let repaid = principal.min(payout);
+        if repaid > 0 {
+            FeeTracker::credit_fee_vault(env, None, repaid)?;
+        }
+        amounts::checked_sub(payout, repaid)
+    }
+
+    /// Reject exits that would turn a credit-backed position into tokens before
+    /// settlement (withdrawal, cash-out, cancellation, transfer).
+    pub fn require_no_credit_stake(env: &Env, market_id: &Symbol, user: &Address) -> Result<(), Error> {
+        if Self::get_credit_stake(env, market_id, user) > 0 {
+            return Err(Error::InvalidState);
+        }
+        Ok(())
+    }
+
+    fn set_credit(env: &Env, user: &Address, credit: &BetCredit) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("credit"), user.clone()), credit);
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != &stored {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+#![cfg(test)]
+
+//! Cross-rate market tests.
+//!
+//! An `OracleConfig` with a `quote_feed_id` resolves on the ratio of two feeds
+//! read from the same provider, such as BTC/ETH. A zero or missing quote price
+//! is an oracle failure, so after the resolution timeout the fallback oracle
+//! takes over exactly as it would for a single feed.
+
+use crate::errors::Error;
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::reflector_oracle_tests::{MockReflectorContract, MockReflectorContractClient};
+use crate::test::PredictifyTest;
+use crate::types::{
+    OracleConfig, OracleProvider, OracleSigner, PythPriceData, ReflectorAsset, ReflectorPriceData,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const RESOLUTION_TIMEOUT: u64 = 3600;
+
+/// "Will BTC/ETH exceed 20?" on Reflector, whose prices are in cents
+fn btc_eth_config(test: &PredictifyTest, reflector: &Address) -> OracleConfig {
+    OracleConfig {
+        provider: OracleProvider::Reflector,
+        oracle_address: reflector.clone(),
+        feed_id: String::from_str(&test.env, "BTC/USD"),
+        threshold: 2_000, // 20.00
+        comparison: String::from_str(&test.env, "gt"),
+        twap_records: None,
+        quote_feed_id: Some(String::from_str(&test.env, "ETH/USD")),
+        signer: OracleSigner::None,
+    }
+}
+
+fn create_market(
+    test: &PredictifyTest,
+    config: &OracleConfig,
+    fallback: Option<OracleConfig>,
+) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC/ETH exceed 20?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        config,
+        &fallback,
+        &RESOLUTION_TIMEOUT,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn advance_past_end_by(test: &PredictifyTest, market_id: &Symbol, seconds: u64) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time + seconds);
+}
+
+fn publish_reflector(test: &PredictifyTest, reflector: &Address, asset: &str, price: i128) {
+    MockReflectorContractClient::new(&test.env, reflector).set_price(
+        &ReflectorAsset::Other(Symbol::new(&test.env, asset)),
+        &ReflectorPriceData {
+            price,
+            timestamp: test.env.ledger().timestamp(),
+            source: String::from_str(&test.env, "Reflector"),
+        },
+    );
+}
+
+fn publish_pyth(test: &PredictifyTest, pyth: &Address, feed_id: &str, price: i64) {
+    MockPythContractClient::new(&test.env, pyth).set_feed_price(
+        &String::from_str(&test.env, feed_id),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: test.env.ledger().timestamp(),
+        },
+    );
+}
+
+#[test]
+fn test_cross_rate_market_resolves_on_ratio() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_market(&test, &btc_eth_config(&test, &reflector), None);
+
+    // $60,000 / $2,500 = 24.00, above the threshold of 20.00
+    advance_past_end_by(&test, &market_id, 1);
+    publish_reflector(&test, &reflector, "BTC", 6_000_000);
+    publish_reflector(&test, &reflector, "ETH", 250_000);
+
+    let fetched = client.fetch_oracle_result(&market_id);
+    assert_eq!(fetched.price, 2_400);
+    assert_eq!(fetched.outcome, String::from_str(&test.env, "yes"));
+    client.resolve_market(&market_id);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+}
+
+#[test]
+fn test_zero_or_missing_quote_is_an_oracle_failure() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = test.env.register(MockReflectorContract, ());
+    let fallback = test.env.register(MockReflectorContract, ());
+    let market_id = create_market(
+        &test,
+        &btc_eth_config(&test, &primary),
+        Some(btc_eth_config(&test, &fallback)),
+    );
+    advance_past_end_by(&test, &market_id, 1);
+    publish_reflector(&test, &primary, "BTC", 6_000_000);
+
+    // A missing quote never falls back to Reflector's built-in test prices
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+    publish_reflector(&test, &primary, "ETH", 0);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    // Past the timeout the fallback answers: $60,000 / $4,000 = 15.00
+    advance_past_end_by(&test, &market_id, RESOLUTION_TIMEOUT + 1);
+    publish_reflector(&test, &fallback, "BTC", 6_000_000);
+    publish_reflector(&test, &fallback, "ETH", 400_000);
+    let fetched = client.fetch_oracle_result(&market_id);
+    assert_eq!(fetched.price, 1_500);
+    assert_eq!(fetched.outcome, String::from_str(&test.env, "no"));
+}
+
+#[test]
+fn test_pyth_cross_rate_uses_eight_decimals() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pyth = test.env.register(MockPythContract, ());
+    let config = OracleConfig {
+        provider: OracleProvider::Pyth,
+        oracle_address: pyth.clone(),
+        feed_id: String::from_str(&test.env, "Crypto.BTC/USD"),
+        threshold: 2_000_000_000,
+        comparison: String::from_str(&test.env, "gt"),
+        twap_records: None,
+        quote_feed_id: Some(String::from_str(&test.env, "Crypto.ETH/USD")),
+        signer: OracleSigner::None,
+    };
+    let market_id = create_market(&test, &config, None);
+    advance_past_end_by(&test, &market_id, 1);
+    publish_pyth(&test, &pyth, "Crypto.BTC/USD", 6_000_000_000_000);
+
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    // $60,000 / $3,000 = 20.00000000, not above the threshold
+    publish_pyth(&test, &pyth, "Crypto.ETH/USD", 300_000_000_000);
+    let fetched = client.fetch_oracle_result(&market_id);
+    assert_eq!(fetched.price, 2_000_000_000);
+    assert_eq!(fetched.outcome, String::from_str(&test.env, "no"));
+}
+
+#[test]
+fn test_create_market_rejects_invalid_quote_feed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+
+    for quote in ["", "BTC/USD"] {
+        let mut config = btc_eth_config(&test, &reflector);
+        config.quote_feed_id = Some(String::from_str(&test.env, quote));
+        test.env.mock_all_auths();
+        let result = client.try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Will BTC/ETH exceed 20?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &config,
+            &None,
+            &RESOLUTION_TIMEOUT,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidOracleConfig)));
+    }
+}
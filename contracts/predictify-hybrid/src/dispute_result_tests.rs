@@ -0,0 +1,429 @@
+#![cfg(test)]
+
+//! Result dispute tests.
+//!
+//! `dispute_result` stakes on a different outcome after resolution and before the
+//! dispute window closes; an open dispute blocks payouts until the admin settles it
+//! with `resolve_dispute`. Rulings can be appealed twice with doubling bonds.
+
+use crate::admin::Role;
+use crate::disputes::DisputeStatus;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::MarketState;
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, vec, String, Symbol};
+
+const MIN_STAKE: i128 = 10_000_000;
+
+/// Creates a market with one "yes" and one "no" bet of 100 XLM and resolves it to
+/// "yes" inside the dispute window. Returns (market_id, winner, loser).
+fn resolved_market(test: &PredictifyTest) -> (Symbol, soroban_sdk::Address, soroban_sdk::Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+        &None,
+    );
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &100_0000000,
+        &None,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    (market_id, winner, loser)
+}
+
+fn close_dispute_window(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market = client.get_market(market_id).unwrap();
+    test.set_time(market.end_time + market.dispute_window_seconds + 1);
+}
+
+#[test]
+fn test_dispute_result_blocks_distribution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let (market_id, winner, loser) = resolved_market(&test);
+
+    let loser_before = token_client.balance(&loser);
+    let contract_before = token_client.balance(&test.contract_id);
+    test.env.mock_all_auths();
+    client.dispute_result(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &MIN_STAKE,
+    );
+
+    assert_eq!(token_client.balance(&loser), loser_before - MIN_STAKE);
+    assert_eq!(
+        token_client.balance(&test.contract_id),
+        contract_before + MIN_STAKE
+    );
+    let dispute = client.get_result_dispute(&market_id).unwrap();
+    assert_eq!(dispute.user, loser);
+    assert_eq!(dispute.stake, MIN_STAKE);
+    assert_eq!(dispute.status, DisputeStatus::Active);
+    assert_eq!(
+        dispute.proposed_outcome,
+        Some(String::from_str(&test.env, "no"))
+    );
+
+    // Payouts stay blocked after the window while the dispute is open
+    close_dispute_window(&test, &market_id);
+    assert_eq!(
+        client.try_distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_claim_winnings(&winner, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_undisputed_market_distributes_as_before() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, winner, _) = resolved_market(&test);
+
+    assert!(client.get_result_dispute(&market_id).is_none());
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+
+    // Pool 200 XLM, winning stake 100 XLM, 2% fee
+    close_dispute_window(&test, &market_id);
+    let (paid, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(paid, 196_0000000);
+    assert!(client.has_claimed(&market_id, &winner));
+}
+
+#[test]
+fn test_dispute_result_rejects_invalid_requests() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let disputer = test.create_funded_user();
+    let no = String::from_str(&test.env, "no");
+
+    // Not resolved yet
+    let unresolved = test.create_test_market();
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_dispute_result(&disputer, &unresolved, &no, &MIN_STAKE),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    let (market_id, _, loser) = resolved_market(&test);
+
+    // Unknown outcome or the outcome that already won
+    assert_eq!(
+        client.try_dispute_result(
+            &disputer,
+            &market_id,
+            &String::from_str(&test.env, "maybe"),
+            &MIN_STAKE
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert_eq!(
+        client.try_dispute_result(
+            &disputer,
+            &market_id,
+            &String::from_str(&test.env, "yes"),
+            &MIN_STAKE
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+
+    // Below the minimum stake
+    assert_eq!(
+        client.try_dispute_result(&disputer, &market_id, &no, &(MIN_STAKE - 1)),
+        Err(Ok(Error::InsufficientStake))
+    );
+
+    // Only one open dispute per market
+    client.dispute_result(&loser, &market_id, &no, &MIN_STAKE);
+    assert_eq!(
+        client.try_dispute_result(&disputer, &market_id, &no, &MIN_STAKE),
+        Err(Ok(Error::AlreadyDisputed))
+    );
+}
+
+#[test]
+fn test_dispute_result_after_window_closes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _, loser) = resolved_market(&test);
+
+    close_dispute_window(&test, &market_id);
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_dispute_result(
+            &loser,
+            &market_id,
+            &String::from_str(&test.env, "no"),
+            &MIN_STAKE
+        ),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+}
+
+#[test]
+fn test_set_min_dispute_stake() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_min_dispute_stake(), MIN_STAKE);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_min_dispute_stake(&test.user, &(5 * MIN_STAKE)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_min_dispute_stake(&test.admin, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_min_dispute_stake(&test.admin, &(5 * MIN_STAKE));
+    assert_eq!(client.get_min_dispute_stake(), 5 * MIN_STAKE);
+
+    let (market_id, _, loser) = resolved_market(&test);
+    let no = String::from_str(&test.env, "no");
+    assert_eq!(
+        client.try_dispute_result(&loser, &market_id, &no, &MIN_STAKE),
+        Err(Ok(Error::InsufficientStake))
+    );
+    client.dispute_result(&loser, &market_id, &no, &(5 * MIN_STAKE));
+}
+
+#[test]
+fn test_resolve_dispute_upheld_overturns_outcome_and_rewards_disputer() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let no = String::from_str(&test.env, "no");
+
+    // Fund the fee vault with another market's 2% fee; once its fees are
+    // collected that market is settled and its result can no longer be disputed
+    let (funding_market, _, funding_loser) = resolved_market(&test);
+    test.env.mock_all_auths();
+    assert_eq!(client.collect_fees(&test.admin, &funding_market), 4_0000000);
+    assert_eq!(
+        client.try_dispute_result(&funding_loser, &funding_market, &no, &MIN_STAKE),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let (market_id, winner, loser) = resolved_market(&test);
+    let vault_before = client.get_fee_vault_balance(&None);
+
+    client.dispute_result(&loser, &market_id, &no, &MIN_STAKE);
+    let loser_before = token_client.balance(&loser);
+
+    // Upholding requires the disputer's outcome
+    assert_eq!(
+        client.try_resolve_dispute(
+            &test.admin,
+            &market_id,
+            &String::from_str(&test.env, "yes"),
+            &true
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&test.user, &market_id, &no, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.resolve_dispute(&test.admin, &market_id, &no, &true);
+
+    // Stake back plus a 50% reward from the vault
+    let reward = MIN_STAKE / 2;
+    assert_eq!(
+        token_client.balance(&loser),
+        loser_before + MIN_STAKE + reward
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before - reward);
+    assert_eq!(
+        client.get_result_dispute(&market_id).unwrap().status,
+        DisputeStatus::Resolved
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, no.clone()]));
+
+    // The ruling opens a half-length appeal window; once it passes, the new
+    // winner is paid
+    let now = test.env.ledger().timestamp();
+    assert_eq!(
+        market.payout_unlock_at,
+        Some(now + market.dispute_window_seconds / 2)
+    );
+    test.advance_past_payout_unlock(&market_id);
+    let (paid, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(paid, 196_0000000);
+    assert!(client.has_claimed(&market_id, &loser));
+    assert!(!client.has_claimed(&market_id, &winner));
+
+    // Settled disputes cannot be resolved twice
+    assert_eq!(
+        client.try_resolve_dispute(&test.admin, &market_id, &no, &true),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+}
+
+#[test]
+fn test_resolve_dispute_rejected_slashes_stake_into_vault() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let (market_id, winner, loser) = resolved_market(&test);
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    let loser_before = token_client.balance(&loser);
+    client.dispute_result(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &MIN_STAKE,
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Disputed
+    );
+    let vault_before = client.get_fee_vault_balance(&None);
+
+    // Rejecting must confirm a recorded winning outcome
+    assert_eq!(
+        client.try_resolve_dispute(
+            &test.admin,
+            &market_id,
+            &String::from_str(&test.env, "no"),
+            &false
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    client.resolve_dispute(&test.admin, &market_id, &yes, &false);
+
+    assert_eq!(token_client.balance(&loser), loser_before - MIN_STAKE);
+    assert_eq!(
+        client.get_fee_vault_balance(&None),
+        vault_before + MIN_STAKE
+    );
+    assert_eq!(
+        client.get_result_dispute(&market_id).unwrap().status,
+        DisputeStatus::Rejected
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes]));
+
+    // After the appeal window the original winner can claim
+    test.advance_past_payout_unlock(&market_id);
+    assert!(client.claim_winnings(&winner, &market_id) > 0);
+}
+
+#[test]
+fn test_dispute_escalates_through_two_appeal_rounds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let (funding_market, _, _) = resolved_market(&test);
+    test.env.mock_all_auths();
+    client.collect_fees(&test.admin, &funding_market);
+
+    let (market_id, winner, loser) = resolved_market(&test);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let window = client.get_market(&market_id).unwrap().dispute_window_seconds;
+
+    // Round 0: the loser disputes and the admin upholds it
+    let loser_start = token_client.balance(&loser);
+    client.dispute_result(&loser, &market_id, &no, &MIN_STAKE);
+    client.resolve_dispute(&test.admin, &market_id, &no, &true);
+    assert_eq!(
+        token_client.balance(&loser),
+        loser_start + MIN_STAKE / 2
+    );
+    let now = test.env.ledger().timestamp();
+    assert_eq!(
+        client.get_market(&market_id).unwrap().payout_unlock_at,
+        Some(now + window / 2)
+    );
+
+    // Round 1: the appeal must double the bond; an arbitrator now rejects it
+    // and the upheld disputer shares in the forfeited bond
+    let arbitrator = soroban_sdk::Address::generate(&test.env);
+    client.grant_role(&test.admin, &Role::Arbitrator, &arbitrator);
+    assert_eq!(
+        client.try_dispute_result(&winner, &market_id, &yes, &(2 * MIN_STAKE - 1)),
+        Err(Ok(Error::InsufficientStake))
+    );
+    let winner_start = token_client.balance(&winner);
+    let vault_start = client.get_fee_vault_balance(&None);
+    client.dispute_result(&winner, &market_id, &yes, &(2 * MIN_STAKE));
+    client.resolve_dispute(&arbitrator, &market_id, &no, &false);
+    assert_eq!(token_client.balance(&winner), winner_start - 2 * MIN_STAKE);
+    assert_eq!(
+        token_client.balance(&loser),
+        loser_start + MIN_STAKE / 2 + MIN_STAKE
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), vault_start + MIN_STAKE);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().payout_unlock_at,
+        Some(now + window / 4)
+    );
+
+    // Round 2: the final appeal; its ruling closes the window
+    let appellant = test.create_funded_user();
+    let appellant_start = token_client.balance(&appellant);
+    assert_eq!(
+        client.try_dispute_result(&appellant, &market_id, &yes, &(2 * MIN_STAKE)),
+        Err(Ok(Error::InsufficientStake))
+    );
+    client.dispute_result(&appellant, &market_id, &yes, &(4 * MIN_STAKE));
+    client.resolve_dispute(&arbitrator, &market_id, &no, &false);
+    assert_eq!(
+        token_client.balance(&appellant),
+        appellant_start - 4 * MIN_STAKE
+    );
+    // Round 1 was rejected, so nobody defended: the whole bond goes to the vault
+    assert_eq!(
+        client.get_fee_vault_balance(&None),
+        vault_start + 5 * MIN_STAKE
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().payout_unlock_at,
+        Some(now)
+    );
+    assert_eq!(
+        client.try_dispute_result(&winner, &market_id, &yes, &(8 * MIN_STAKE)),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+
+    let disputes = client.get_disputes(&market_id);
+    assert_eq!(disputes.len(), 3);
+    assert_eq!(disputes.get(0).unwrap().status, DisputeStatus::Resolved);
+    assert_eq!(disputes.get(1).unwrap().user, winner);
+    assert_eq!(disputes.get(1).unwrap().status, DisputeStatus::Rejected);
+    assert_eq!(disputes.get(2).unwrap().stake, 4 * MIN_STAKE);
+    assert_eq!(disputes.get(2).unwrap().status, DisputeStatus::Rejected);
+
+    let (paid, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(paid, 196_0000000);
+}
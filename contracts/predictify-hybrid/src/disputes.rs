@@ -2,11 +2,46 @@
 
 use crate::{
     errors::Error,
-    markets::MarketStateManager,
-    types::Market,
+    markets::{MarketStateLogic, MarketStateManager},
+    optimistic::OptimisticResolutionManager,
+    storage::DataKey,
+    types::{Market, MarketEvent, MarketState},
     voting::{VotingUtils, DISPUTE_EXTENSION_HOURS, MIN_DISPUTE_STAKE},
 };
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Map, String, Symbol, Vec};
+
+// ===== ARBITRATION CLIENT =====
+
+/// Client for an external arbitration contract.
+///
+/// The contract is expected to expose `arbitrate(market_id) -> String`,
+/// returning the outcome it rules for. The call goes through
+/// `try_invoke_contract`, so a missing contract, a trapped call, or an
+/// unexpected return type yields `None`.
+pub struct ArbitrationContractClient<'a> {
+    env: &'a Env,
+    contract_id: Address,
+}
+
+impl<'a> ArbitrationContractClient<'a> {
+    /// Create a new arbitration contract client
+    pub fn new(env: &'a Env, contract_id: Address) -> Self {
+        Self { env, contract_id }
+    }
+
+    /// Ask the contract to rule on a market, or `None` if the call fails
+    pub fn arbitrate(&self, market_id: &Symbol) -> Option<String> {
+        let args = soroban_sdk::vec![self.env, market_id.into_val(self.env)];
+        match self.env.try_invoke_contract::<String, soroban_sdk::Error>(
+            &self.contract_id,
+            &Symbol::new(self.env, "arbitrate"),
+            args,
+        ) {
+            Ok(Ok(outcome)) => Some(outcome),
+            _ => None,
+        }
+    }
+}
 
 // ===== DISPUTE STRUCTURES =====
 
@@ -24,6 +59,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 /// * `timestamp` - When the dispute was created (ledger timestamp)
 /// * `reason` - Optional explanation for why the dispute was raised
 /// * `status` - Current status of the dispute (Active, Resolved, etc.)
+/// * `proposed_outcome` - Outcome the disputer claims is correct (result disputes only)
 ///
 /// # Example
 ///
@@ -41,6 +77,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 ///     timestamp: env.ledger().timestamp(),
 ///     reason: Some(String::from_str(&env, "Oracle data appears incorrect")),
 ///     status: DisputeStatus::Active,
+///     proposed_outcome: None,
 /// };
 ///
 /// // Dispute is now active and awaiting community voting
@@ -61,6 +98,7 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol,
 /// - Winners receive their stake back plus rewards
 /// - Losers forfeit their stake to the winning side
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Dispute {
     pub user: Address,
     pub market_id: Symbol,
@@ -68,6 +106,7 @@ pub struct Dispute {
     pub timestamp: u64,
     pub reason: Option<String>,
     pub status: DisputeStatus,
+    pub proposed_outcome: Option<String>,
 }
 
 /// Represents the current lifecycle status of a dispute.
@@ -117,6 +156,7 @@ pub struct Dispute {
 /// - **Rejected**: Oracle result upheld, original outcome stands
 /// - **Expired**: Insufficient community engagement, original outcome stands
 #[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DisputeStatus {
     Active,
     Resolved,
@@ -835,6 +875,7 @@ impl DisputeManager {
             timestamp: env.ledger().timestamp(),
             reason,
             status: DisputeStatus::Active,
+            proposed_outcome: None,
         };
 
         // Add dispute to market
@@ -845,6 +886,7 @@ impl DisputeManager {
 
         // Update market in storage
         MarketStateManager::update_market(env, &market_id, &market);
+        crate::escrow::EscrowManager::credit(env, &market_id, stake)?;
 
         // Emit dispute created event
         crate::events::EventEmitter::emit_dispute_created(
@@ -858,6 +900,324 @@ impl DisputeManager {
         Ok(())
     }
 
+    /// Disputes a market's recorded result by staking on a different outcome.
+    ///
+    /// Allowed once the market has winning outcomes and until
+    /// `resolved_at + dispute_window_seconds`. The stake is transferred into the
+    /// contract in the market's token and the dispute is stored against the market,
+    /// which moves to `Disputed` until the ruling; while it is open, payouts are
+    /// blocked. Only one open result dispute is allowed per market.
+    ///
+    /// A dispute filed after an earlier one was ruled on is an appeal. Up to
+    /// `MAX_DISPUTE_APPEAL_ROUNDS` appeals are accepted, each inside the shortened
+    /// window the previous ruling opened and with at least twice its stake.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotResolved` - The market has no recorded result yet
+    /// * `Error::DisputeCondNotMet` - The dispute window has closed or no appeals remain
+    /// * `Error::InvalidOutcome` - Unknown outcome, or already a winning outcome
+    /// * `Error::InvalidInput` - Stake is zero or negative
+    /// * `Error::InsufficientStake` - Stake below the minimum or the required appeal bond
+    /// * `Error::AlreadyDisputed` - The market already has an open dispute
+    /// * `Error::InvalidState` - The market is not `Resolved`, e.g. it was settled
+    pub fn dispute_result(
+        env: &Env,
+        user: Address,
+        market_id: Symbol,
+        proposed_outcome: String,
+        stake: i128,
+    ) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+        if env.ledger().timestamp() >= market.payout_unlock_time() {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if !market.outcomes.contains(&proposed_outcome)
+            || winning_outcomes.contains(&proposed_outcome)
+        {
+            return Err(Error::InvalidOutcome);
+        }
+        crate::amounts::require_positive(stake)?;
+        if DisputeUtils::has_open_result_dispute(env, &market_id) {
+            return Err(Error::AlreadyDisputed);
+        }
+        let history = DisputeUtils::get_result_dispute_history(env, &market_id);
+        if history.len() > crate::config::MAX_DISPUTE_APPEAL_ROUNDS {
+            return Err(Error::DisputeCondNotMet);
+        }
+        let min_stake = match history.last() {
+            Some(previous) => crate::amounts::checked_add(previous.stake, previous.stake)?,
+            None => DisputeUtils::get_min_result_dispute_stake(env),
+        };
+        if stake < min_stake {
+            return Err(Error::InsufficientStake);
+        }
+        let old_state = market.state;
+        MarketStateLogic::transition(&mut market, MarketEvent::Dispute)?;
+
+        let token_client = crate::markets::MarketUtils::get_market_token_client(env, &market)?;
+        crate::markets::MarketUtils::receive_tokens(env, &token_client, &user, stake)?;
+
+        let dispute = Dispute {
+            user: user.clone(),
+            market_id: market_id.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+            reason: None,
+            status: DisputeStatus::Active,
+            proposed_outcome: Some(proposed_outcome.clone()),
+        };
+        DisputeUtils::store_result_dispute(env, &dispute);
+        crate::escrow::EscrowManager::credit(env, &market_id, stake)?;
+        MarketStateManager::update_market(env, &market_id, &market);
+
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            &market_id,
+            &old_state,
+            &MarketState::Disputed,
+            &String::from_str(env, "Result disputed"),
+        );
+        crate::events::EventEmitter::emit_result_disputed(
+            env,
+            &market_id,
+            &user,
+            &proposed_outcome,
+            stake,
+        );
+
+        Ok(())
+    }
+
+    /// Settles a market's open result dispute (see [`DisputeUtils::require_arbiter`]).
+    ///
+    /// When `uphold` is true, `final_outcome` must be the disputer's proposed outcome:
+    /// `winning_outcomes` is rewritten to it, and the disputer gets their stake back
+    /// plus a reward of `DISPUTE_REWARD_BPS` of the stake taken from the fee vault
+    /// (capped at the vault balance). Otherwise `final_outcome` must be one of the
+    /// recorded winning outcomes and the stake is forfeited. If the rejected dispute
+    /// appealed an upheld one, that disputer receives `DISPUTE_BOND_WINNER_SHARE_BPS`
+    /// of the forfeited stake and the rest goes to the fee vault; otherwise all of it
+    /// does.
+    ///
+    /// Rulings before the last appeal round reopen the dispute window for half as
+    /// long as the previous one, so the outcome can be appealed. The ruling on the
+    /// final appeal closes the window immediately so payouts can proceed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller may not settle disputes on this market
+    /// * `Error::DisputeCondNotMet` - The market has no open result dispute
+    /// * `Error::InvalidOutcome` - `final_outcome` does not match the decision
+    pub fn resolve_result_dispute(
+        env: &Env,
+        admin: Address,
+        market_id: Symbol,
+        final_outcome: String,
+        uphold: bool,
+    ) -> Result<(), Error> {
+        DisputeUtils::require_arbiter(env, &market_id, &admin)?;
+        Self::settle_result_dispute(env, market_id, final_outcome, uphold)
+    }
+
+    /// Settles a market's open result dispute with `outcome` (arbiter only).
+    ///
+    /// Like [`Self::resolve_result_dispute`], but the ruling follows from the
+    /// outcome: naming the disputer's proposed outcome upholds the dispute, and
+    /// naming a recorded winning outcome rejects it. A challenged optimistic
+    /// proposal is settled instead, if the market has one.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller may not settle disputes on this market
+    /// * `Error::DisputeCondNotMet` - The market has no open result dispute
+    /// * `Error::InvalidOutcome` - `outcome` is neither the proposed nor a winning outcome
+    pub fn arbitrate(
+        env: &Env,
+        arbitrator: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<(), Error> {
+        DisputeUtils::require_arbiter(env, &market_id, &arbitrator)?;
+        if OptimisticResolutionManager::is_challenged(env, &market_id) {
+            return OptimisticResolutionManager::settle_challenge(env, &market_id, &outcome);
+        }
+        let uphold = Self::is_upheld_by(env, &market_id, &outcome)?;
+        Self::settle_result_dispute(env, market_id, outcome, uphold)
+    }
+
+    /// Settles a market's open result dispute with the outcome returned by its
+    /// arbitration contract. Anyone may trigger this; returns the outcome.
+    /// A challenged optimistic proposal is settled the same way.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - No arbitration contract is set, or the call failed
+    /// * `Error::DisputeCondNotMet` - The market has no open result dispute
+    /// * `Error::InvalidOutcome` - The contract returned an outcome that fits neither side
+    pub fn arbitrate_by_contract(env: &Env, market_id: Symbol) -> Result<String, Error> {
+        let contract =
+            DisputeUtils::get_arbitration_contract(env, &market_id).ok_or(Error::InvalidState)?;
+        let challenged = OptimisticResolutionManager::is_challenged(env, &market_id);
+        if !challenged && !DisputeUtils::has_open_result_dispute(env, &market_id) {
+            return Err(Error::DisputeCondNotMet);
+        }
+        let outcome = ArbitrationContractClient::new(env, contract)
+            .arbitrate(&market_id)
+            .ok_or(Error::InvalidState)?;
+        if challenged {
+            OptimisticResolutionManager::settle_challenge(env, &market_id, &outcome)?;
+            return Ok(outcome);
+        }
+        let uphold = Self::is_upheld_by(env, &market_id, &outcome)?;
+        Self::settle_result_dispute(env, market_id, outcome.clone(), uphold)?;
+        Ok(outcome)
+    }
+
+    /// Whether ruling for `outcome` upholds the market's open result dispute
+    fn is_upheld_by(env: &Env, market_id: &Symbol, outcome: &String) -> Result<bool, Error> {
+        let dispute = DisputeUtils::get_result_dispute(env, market_id)
+            .filter(|d| d.status == DisputeStatus::Active)
+            .ok_or(Error::DisputeCondNotMet)?;
+        Ok(dispute.proposed_outcome.as_ref() == Some(outcome))
+    }
+
+    fn settle_result_dispute(
+        env: &Env,
+        market_id: Symbol,
+        final_outcome: String,
+        uphold: bool,
+    ) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        let mut dispute = DisputeUtils::get_result_dispute(env, &market_id)
+            .filter(|d| d.status == DisputeStatus::Active)
+            .ok_or(Error::DisputeCondNotMet)?;
+        let old_state = market.state;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+
+        let token_client = crate::markets::MarketUtils::get_market_token_client(env, &market)?;
+        let vault_token = market.token.clone();
+        let mut winners = Vec::new(env);
+        let mut losers = Vec::new(env);
+        let fee_amount;
+        // Bond paid out once the settlement is stored
+        let payout: Option<(Address, i128)>;
+
+        if uphold {
+            if dispute.proposed_outcome.as_ref() != Some(&final_outcome) {
+                return Err(Error::InvalidOutcome);
+            }
+
+            MarketStateLogic::transition(&mut market, MarketEvent::Overturn)?;
+            let mut new_outcomes = Vec::new(env);
+            new_outcomes.push_back(final_outcome.clone());
+            market.winning_outcomes = Some(new_outcomes.clone());
+            let _ = crate::bets::BetManager::resolve_market_bets(env, &market_id, &new_outcomes);
+            crate::markets::MarketUtils::publish_resolution(
+                env,
+                &market_id,
+                &mut market,
+                crate::events::ResolutionSource::Dispute,
+            );
+
+            let vault_balance =
+                crate::fees::FeeTracker::get_fee_vault_balance(env, vault_token.as_ref());
+            let reward = crate::amounts::mul_div(
+                dispute.stake,
+                crate::config::DISPUTE_REWARD_BPS,
+                10_000,
+            )?
+            .min(vault_balance)
+            .max(0);
+            crate::fees::FeeTracker::debit_fee_vault(env, vault_token.as_ref(), reward)?;
+            payout = Some((
+                dispute.user.clone(),
+                crate::amounts::checked_add(dispute.stake, reward)?,
+            ));
+
+            dispute.status = DisputeStatus::Resolved;
+            winners.push_back(dispute.user.clone());
+            fee_amount = reward;
+        } else {
+            if !winning_outcomes.contains(&final_outcome) {
+                return Err(Error::InvalidOutcome);
+            }
+            MarketStateLogic::transition(&mut market, MarketEvent::Resolve)?;
+
+            // The appeal lost: the disputer it challenged shares in the forfeited stake
+            let history = DisputeUtils::get_result_dispute_history(env, &market_id);
+            let defender = history
+                .len()
+                .checked_sub(2)
+                .and_then(|i| history.get(i))
+                .filter(|d| d.status == DisputeStatus::Resolved)
+                .map(|d| d.user);
+            let defender_share = match &defender {
+                Some(_) => crate::amounts::mul_div(
+                    dispute.stake,
+                    crate::config::DISPUTE_BOND_WINNER_SHARE_BPS,
+                    10_000,
+                )?,
+                None => 0,
+            };
+            if let Some(defender) = &defender {
+                winners.push_back(defender.clone());
+            }
+            payout = defender.map(|defender| (defender, defender_share));
+            let vault_share = crate::amounts::checked_sub(dispute.stake, defender_share)?;
+            crate::fees::FeeTracker::credit_fee_vault(env, vault_token.as_ref(), vault_share)?;
+
+            dispute.status = DisputeStatus::Rejected;
+            losers.push_back(dispute.user.clone());
+            fee_amount = vault_share;
+        }
+
+        // Reopen a shorter window for an appeal, or close it after the final round
+        let now = env.ledger().timestamp();
+        let round = DisputeUtils::get_result_dispute_history(env, &market_id).len() - 1;
+        market.payout_unlock_at = if round < crate::config::MAX_DISPUTE_APPEAL_ROUNDS {
+            Some(now.saturating_add(market.dispute_window_seconds >> (round + 1)))
+        } else {
+            Some(now)
+        };
+        MarketStateManager::update_market(env, &market_id, &market);
+        DisputeUtils::store_result_dispute(env, &dispute);
+        crate::escrow::EscrowManager::debit(env, &market_id, dispute.stake)?;
+
+        if let Some((recipient, amount)) = payout {
+            crate::reentrancy_guard::ReentrancyGuard::guarded(env, || {
+                token_client.transfer(&env.current_contract_address(), &recipient, &amount)
+            })
+            .map_err(|_| Error::InvalidState)?;
+        }
+
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            &market_id,
+            &old_state,
+            &market.state,
+            &String::from_str(env, "Result dispute ruled on"),
+        );
+        crate::events::EventEmitter::emit_dispute_resolved(
+            env,
+            &market_id,
+            &final_outcome,
+            &winners,
+            &losers,
+            fee_amount,
+        );
+
+        Ok(())
+    }
+
     /// Resolves a dispute by combining oracle data with community voting results.
     ///
     /// This function determines the final outcome of a disputed market by analyzing
@@ -1377,6 +1737,7 @@ impl DisputeManager {
 
         // Add vote to dispute voting
         DisputeUtils::add_vote_to_dispute(env, &dispute_id, dispute_vote)?;
+        crate::escrow::EscrowManager::credit(env, &market_id, stake)?;
 
         // Emit dispute vote event
         DisputeUtils::emit_dispute_vote_event(env, &dispute_id, &user, vote, stake);
@@ -1923,7 +2284,7 @@ impl DisputeValidator {
     /// Validate admin permissions
     pub fn validate_admin_permissions(env: &Env, admin: &Address) -> Result<(), Error> {
         let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+            env.storage().instance().get(&DataKey::Admin);
 
         match stored_admin {
             Some(stored_admin) => {
@@ -1953,11 +2314,6 @@ impl DisputeValidator {
             return Err(Error::AlreadyDisputed);
         }
 
-        // Check if user has voted (optional requirement)
-        if !market.votes.contains_key(user.clone()) {
-            // Allow disputes even from non-voters, but could be made optional
-        }
-
         Ok(())
     }
 
@@ -1991,7 +2347,7 @@ impl DisputeValidator {
 
         // Check if voting is still active
         if !matches!(voting_data.status, DisputeVotingStatus::Active) {
-            return Err(Error::DisputeVoteDenied);
+            return Err(Error::DisputeVoteExpired);
         }
 
         Ok(())
@@ -2007,7 +2363,7 @@ impl DisputeValidator {
 
         for vote in votes.iter() {
             if vote.user == *user {
-                return Err(Error::DisputeAlreadyVoted);
+                return Err(Error::AlreadyVoted);
             }
         }
 
@@ -2062,13 +2418,13 @@ impl DisputeValidator {
         }
 
         if !has_participated {
-            return Err(Error::DisputeNoEscalate);
+            return Err(Error::DisputeCondNotMet);
         }
 
         // Check if escalation already exists
         let escalation = DisputeUtils::get_dispute_escalation(env, dispute_id);
         if escalation.is_some() {
-            return Err(Error::DisputeNoEscalate);
+            return Err(Error::DisputeCondNotMet);
         }
 
         Ok(())
@@ -2126,9 +2482,10 @@ impl DisputeUtils {
     pub fn add_dispute_to_market(market: &mut Market, dispute: Dispute) -> Result<(), Error> {
         // Add dispute stake to market
         let current_stake = market.dispute_stakes.get(dispute.user.clone()).unwrap_or(0);
-        market
-            .dispute_stakes
-            .set(dispute.user, current_stake + dispute.stake);
+        market.dispute_stakes.set(
+            dispute.user,
+            crate::amounts::checked_add(current_stake, dispute.stake)?,
+        );
 
         // Update total dispute stakes - this is calculated automatically by the method
         // No need to assign it back since it's a computed value
@@ -2179,9 +2536,13 @@ impl DisputeUtils {
         DisputeValidator::validate_resolution_parameters(market, &final_outcome)?;
 
         // Set the winning outcome(s) - convert single outcome to vector
-        let mut winning_outcomes = Vec::new(market.votes.env());
+        let mut winning_outcomes = Vec::new(market.outcomes.env());
         winning_outcomes.push_back(final_outcome);
         market.winning_outcomes = Some(winning_outcomes);
+        if market.resolved_at.is_none() {
+            let now = market.outcomes.env().ledger().timestamp();
+            market.mark_resolved(now);
+        }
 
         Ok(())
     }
@@ -2203,6 +2564,7 @@ impl DisputeUtils {
                     timestamp: env.ledger().timestamp(),
                     reason: None,
                     status: DisputeStatus::Active,
+                    proposed_outcome: None,
                 };
                 disputes.push_back(dispute);
             }
@@ -2442,6 +2804,96 @@ impl DisputeUtils {
         env.storage().persistent().set(&event_key, &event_data);
     }
 
+    /// Minimum stake for `dispute_result` (admin-configurable).
+    pub fn get_min_result_dispute_stake(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("dsp_min"))
+            .unwrap_or(MIN_DISPUTE_STAKE)
+    }
+
+    /// Set the minimum stake for `dispute_result`.
+    pub fn set_min_result_dispute_stake(env: &Env, stake: i128) {
+        env.storage().persistent().set(&symbol_short!("dsp_min"), &stake);
+    }
+
+    /// Get the result dispute recorded for a market, if any
+    pub fn get_result_dispute(env: &Env, market_id: &Symbol) -> Option<Dispute> {
+        let key = (symbol_short!("res_disp"), market_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Store the result dispute for a market.
+    ///
+    /// The dispute is also recorded in the market's dispute history: it replaces
+    /// the last round while that round is still open, and starts a new round
+    /// otherwise.
+    pub fn store_result_dispute(env: &Env, dispute: &Dispute) {
+        let key = (symbol_short!("res_disp"), dispute.market_id.clone());
+        env.storage().persistent().set(&key, dispute);
+
+        let mut history = Self::get_result_dispute_history(env, &dispute.market_id);
+        match history.last() {
+            Some(last) if last.status == DisputeStatus::Active => {
+                history.set(history.len() - 1, dispute.clone());
+            }
+            _ => history.push_back(dispute.clone()),
+        }
+        let history_key = (symbol_short!("disp_hist"), dispute.market_id.clone());
+        env.storage().persistent().set(&history_key, &history);
+    }
+
+    /// Contract that settles this market's result disputes, if one is set
+    pub fn get_arbitration_contract(env: &Env, market_id: &Symbol) -> Option<Address> {
+        let key = (symbol_short!("arb_ctr"), market_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Set or clear the contract that settles this market's result disputes
+    pub fn set_arbitration_contract(env: &Env, market_id: &Symbol, contract: &Option<Address>) {
+        let key = (symbol_short!("arb_ctr"), market_id.clone());
+        match contract {
+            Some(contract) => env.storage().persistent().set(&key, contract),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    /// Check that `caller` may settle result disputes on `market_id`.
+    ///
+    /// A market with an arbitration contract is settled only through
+    /// `arbitrate_by_contract`. Otherwise, once any address has been granted the
+    /// `Arbitrator` role, only role holders may settle; the admin does not
+    /// count. With no arbitrator granted, the admin settles.
+    pub fn require_arbiter(env: &Env, market_id: &Symbol, caller: &Address) -> Result<(), Error> {
+        if Self::get_arbitration_contract(env, market_id).is_some() {
+            return Err(Error::Unauthorized);
+        }
+        let role = crate::admin::Role::Arbitrator;
+        if crate::admin::RoleManager::is_role_granted(env, role) {
+            if !crate::admin::RoleManager::has_granted_role(env, role, caller) {
+                return Err(Error::Unauthorized);
+            }
+            return Ok(());
+        }
+        DisputeValidator::validate_admin_permissions(env, caller)
+    }
+
+    /// Every result dispute round for a market, oldest first
+    pub fn get_result_dispute_history(env: &Env, market_id: &Symbol) -> Vec<Dispute> {
+        let key = (symbol_short!("disp_hist"), market_id.clone());
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Check whether a market has a result dispute awaiting settlement
+    pub fn has_open_result_dispute(env: &Env, market_id: &Symbol) -> bool {
+        Self::get_result_dispute(env, market_id)
+            .map(|d| d.status == DisputeStatus::Active)
+            .unwrap_or(false)
+    }
+
     /// Store dispute timeout
     pub fn store_dispute_timeout(
         env: &Env,
@@ -2459,7 +2911,7 @@ impl DisputeUtils {
         env.storage()
             .persistent()
             .get(&key)
-            .ok_or(Error::TimeoutNotSet)
+            .ok_or(Error::ConfigNotFound)
     }
 
     /// Check if dispute timeout exists
@@ -2558,16 +3010,12 @@ impl DisputeAnalytics {
 
     /// Calculate community consensus
     pub fn calculate_community_consensus(env: &Env, market: &Market) -> CommunityConsensus {
-        let mut outcome_totals = Map::new(env);
-        let mut total_votes = 0;
-
-        // Calculate total stakes for each outcome
-        for (user, outcome) in market.votes.iter() {
-            let stake = market.stakes.get(user).unwrap_or(0);
-            let current_total = outcome_totals.get(outcome.clone()).unwrap_or(0);
-            outcome_totals.set(outcome, current_total + stake);
-            total_votes += stake;
-        }
+        // Total stake for each outcome
+        let outcome_totals = market.outcome_pools.clone();
+        let total_votes = outcome_totals
+            .values()
+            .iter()
+            .fold(0i128, |total, stake| total + stake);
 
         // Find the outcome with highest stake
         let mut winning_outcome = String::from_str(env, "");
@@ -2610,7 +3058,7 @@ impl DisputeAnalytics {
 
     /// Calculate dispute participation rate
     pub fn calculate_dispute_participation_rate(market: &Market) -> f64 {
-        let total_voters = market.votes.len();
+        let total_voters = market.position_count();
         let total_disputers = market.dispute_stakes.len();
 
         if total_voters == 0 {
@@ -2687,6 +3135,7 @@ pub mod testing {
             timestamp: env.ledger().timestamp(),
             reason: Some(String::from_str(env, "Test dispute")),
             status: DisputeStatus::Active,
+            proposed_outcome: None,
         }
     }
 
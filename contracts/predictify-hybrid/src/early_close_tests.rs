@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+//! Early market close tests.
+//!
+//! `close_market_early` ends a market before its scheduled `end_time` when the
+//! outcome is already known, without skipping the dispute window.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, String};
+
+const DAY: u64 = 24 * 60 * 60;
+const STAKE: i128 = 10_000_000;
+
+#[test]
+fn test_close_30_day_market_on_day_two_and_pay_out() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let created_at = test.env.ledger().timestamp();
+    let scheduled_end = client.get_market(&market_id).unwrap().end_time;
+    assert_eq!(scheduled_end, created_at + 30 * DAY);
+
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    client.place_bet(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+
+    let day_two = created_at + 2 * DAY;
+    test.env.ledger().with_mut(|li| li.timestamp = day_two);
+    client.close_market_early(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "Team eliminated"),
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.closed_early_at, Some(day_two));
+    assert_eq!(market.end_time, day_two);
+
+    let late = test.create_funded_user();
+    assert_eq!(
+        client.try_place_bet(
+            &late,
+            &market_id,
+            &String::from_str(&test.env, "yes"),
+            &STAKE,
+            &None
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // The dispute window still applies after an early close
+    let resolved = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        resolved.payout_unlock_at,
+        Some(day_two + resolved.dispute_window_seconds)
+    );
+    assert!(client.try_claim_winnings(&winner, &market_id).is_err());
+
+    test.advance_past_payout_unlock(&market_id);
+    let payout = client.claim_winnings(&winner, &market_id);
+    assert!(payout > STAKE);
+    assert!(client.has_claimed(&market_id, &winner));
+}
+
+#[test]
+fn test_close_market_early_errors() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let reason = String::from_str(&test.env, "Outcome known");
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_close_market_early(&outsider, &market_id, &reason),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.close_market_early(&test.admin, &market_id, &reason);
+    assert_eq!(
+        client.try_close_market_early(&test.admin, &market_id, &reason),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(
+        client.try_close_market_early(&test.admin, &market_id, &reason),
+        Err(Ok(Error::MarketResolved))
+    );
+}
@@ -43,7 +43,6 @@ fn test_error_numeric_codes_oracle_range() {
     assert_eq!(Error::OracleNoConsensus as u32, 203);
     assert_eq!(Error::OracleVerified as u32, 204);
     assert_eq!(Error::MarketNotReady as u32, 205);
-    assert_eq!(Error::FallbackOracleUnavailable as u32, 206);
     assert_eq!(Error::ResolutionTimeoutReached as u32, 207);
 }
 
@@ -64,28 +63,24 @@ fn test_error_numeric_codes_additional_range() {
     assert_eq!(Error::ConfigNotFound as u32, 403);
     assert_eq!(Error::AlreadyDisputed as u32, 404);
     assert_eq!(Error::DisputeVoteExpired as u32, 405);
-    assert_eq!(Error::DisputeVoteDenied as u32, 406);
-    assert_eq!(Error::DisputeAlreadyVoted as u32, 407);
     assert_eq!(Error::DisputeCondNotMet as u32, 408);
     assert_eq!(Error::DisputeFeeFailed as u32, 409);
-    assert_eq!(Error::DisputeNoEscalate as u32, 410);
     assert_eq!(Error::ThresholdBelowMin as u32, 411);
     assert_eq!(Error::ThresholdTooHigh as u32, 412);
     assert_eq!(Error::FeeAlreadyCollected as u32, 413);
-    assert_eq!(Error::NoFeesToCollect as u32, 414);
     assert_eq!(Error::InvalidExtensionDays as u32, 415);
     assert_eq!(Error::ExtensionDenied as u32, 416);
     assert_eq!(Error::AdminNotSet as u32, 418);
-    assert_eq!(Error::TimeoutNotSet as u32, 419);
     assert_eq!(Error::InvalidTimeoutHours as u32, 422);
+    assert_eq!(Error::FeeIncreaseTimelocked as u32, 423);
+    assert_eq!(Error::CannotRecoverStakingToken as u32, 424);
 }
 
 #[test]
 fn test_error_numeric_codes_circuit_breaker_range() {
-    assert_eq!(Error::CBNotInitialized as u32, 500);
-    assert_eq!(Error::CBAlreadyOpen as u32, 501);
-    assert_eq!(Error::CBNotOpen as u32, 502);
     assert_eq!(Error::CBOpen as u32, 503);
+    assert_eq!(Error::ContractPaused as u32, 504);
+    assert_eq!(Error::MarketFrozen as u32, 505);
 }
 
 // ===== ERROR CODE STRING TESTS =====
@@ -105,6 +100,10 @@ fn test_error_code_strings_user_operation() {
     assert_eq!(Error::AlreadyBet.code(), "ALREADY_BET");
     assert_eq!(Error::BetsAlreadyPlaced.code(), "BETS_ALREADY_PLACED");
     assert_eq!(Error::InsufficientBalance.code(), "INSUFFICIENT_BALANCE");
+    assert_eq!(Error::BetBelowMinimum.code(), "BET_BELOW_MINIMUM");
+    assert_eq!(Error::InvalidAmount.code(), "INVALID_AMOUNT");
+    assert_eq!(Error::ConflictingOutcome.code(), "CONFLICTING_OUTCOME");
+    assert_eq!(Error::AddressBlocked.code(), "ADDRESS_BLOCKED");
 }
 
 #[test]
@@ -115,10 +114,6 @@ fn test_error_code_strings_oracle() {
     assert_eq!(Error::OracleNoConsensus.code(), "ORACLE_NO_CONSENSUS");
     assert_eq!(Error::OracleVerified.code(), "ORACLE_VERIFIED");
     assert_eq!(Error::MarketNotReady.code(), "MARKET_NOT_READY");
-    assert_eq!(
-        Error::FallbackOracleUnavailable.code(),
-        "FALLBACK_ORACLE_UNAVAILABLE"
-    );
     assert_eq!(
         Error::ResolutionTimeoutReached.code(),
         "RESOLUTION_TIMEOUT_REACHED"
@@ -132,6 +127,7 @@ fn test_error_code_strings_validation() {
     assert_eq!(Error::InvalidDuration.code(), "INVALID_DURATION");
     assert_eq!(Error::InvalidThreshold.code(), "INVALID_THRESHOLD");
     assert_eq!(Error::InvalidComparison.code(), "INVALID_COMPARISON");
+    assert_eq!(Error::MetadataTooLong.code(), "METADATA_TOO_LONG");
 }
 
 #[test]
@@ -145,11 +141,6 @@ fn test_error_code_strings_additional() {
         Error::DisputeVoteExpired.code(),
         "DISPUTE_VOTING_PERIOD_EXPIRED"
     );
-    assert_eq!(
-        Error::DisputeVoteDenied.code(),
-        "DISPUTE_VOTING_NOT_ALLOWED"
-    );
-    assert_eq!(Error::DisputeAlreadyVoted.code(), "DISPUTE_ALREADY_VOTED");
     assert_eq!(
         Error::DisputeCondNotMet.code(),
         "DISPUTE_RESOLUTION_CONDITIONS_NOT_MET"
@@ -158,33 +149,28 @@ fn test_error_code_strings_additional() {
         Error::DisputeFeeFailed.code(),
         "DISPUTE_FEE_DISTRIBUTION_FAILED"
     );
-    assert_eq!(
-        Error::DisputeNoEscalate.code(),
-        "DISPUTE_ESCALATION_NOT_ALLOWED"
-    );
     assert_eq!(Error::ThresholdBelowMin.code(), "THRESHOLD_BELOW_MINIMUM");
     assert_eq!(Error::ThresholdTooHigh.code(), "THRESHOLD_EXCEEDS_MAXIMUM");
     assert_eq!(Error::FeeAlreadyCollected.code(), "FEE_ALREADY_COLLECTED");
-    assert_eq!(Error::NoFeesToCollect.code(), "NO_FEES_TO_COLLECT");
     assert_eq!(
         Error::InvalidExtensionDays.code(),
         "INVALID_EXTENSION_DAYS"
     );
     assert_eq!(Error::ExtensionDenied.code(), "EXTENSION_DENIED");
     assert_eq!(Error::AdminNotSet.code(), "ADMIN_NOT_SET");
-    assert_eq!(Error::TimeoutNotSet.code(), "DISPUTE_TIMEOUT_NOT_SET");
     assert_eq!(Error::InvalidTimeoutHours.code(), "INVALID_TIMEOUT_HOURS");
+    assert_eq!(Error::FeeIncreaseTimelocked.code(), "FEE_INCREASE_TIMELOCKED");
+    assert_eq!(
+        Error::CannotRecoverStakingToken.code(),
+        "CANNOT_RECOVER_STAKING_TOKEN"
+    );
 }
 
 #[test]
 fn test_error_code_strings_circuit_breaker() {
-    assert_eq!(
-        Error::CBNotInitialized.code(),
-        "CIRCUIT_BREAKER_NOT_INITIALIZED"
-    );
-    assert_eq!(Error::CBAlreadyOpen.code(), "CIRCUIT_BREAKER_ALREADY_OPEN");
-    assert_eq!(Error::CBNotOpen.code(), "CIRCUIT_BREAKER_NOT_OPEN");
     assert_eq!(Error::CBOpen.code(), "CIRCUIT_BREAKER_OPEN");
+    assert_eq!(Error::ContractPaused.code(), "CONTRACT_PAUSED");
+    assert_eq!(Error::MarketFrozen.code(), "MARKET_FROZEN");
 }
 
 // ===== ERROR DESCRIPTION TESTS =====
@@ -237,6 +223,22 @@ fn test_error_descriptions_user_operation() {
         Error::InsufficientBalance.description(),
         "Insufficient balance for operation"
     );
+    assert_eq!(
+        Error::BetBelowMinimum.description(),
+        "Bet amount is below the minimum bet"
+    );
+    assert_eq!(
+        Error::InvalidAmount.description(),
+        "Amount must be positive"
+    );
+    assert_eq!(
+        Error::ConflictingOutcome.description(),
+        "Bet conflicts with the user's position on another outcome"
+    );
+    assert_eq!(
+        Error::AddressBlocked.description(),
+        "Address is blocked by the compliance blacklist"
+    );
 }
 
 #[test]
@@ -265,10 +267,6 @@ fn test_error_descriptions_oracle() {
         Error::MarketNotReady.description(),
         "Market not ready for oracle verification"
     );
-    assert_eq!(
-        Error::FallbackOracleUnavailable.description(),
-        "Fallback oracle is unavailable or unhealthy"
-    );
     assert_eq!(
         Error::ResolutionTimeoutReached.description(),
         "Resolution timeout has been reached"
@@ -297,6 +295,10 @@ fn test_error_descriptions_validation() {
         Error::InvalidComparison.description(),
         "Invalid comparison operator"
     );
+    assert_eq!(
+        Error::MetadataTooLong.description(),
+        "Market metadata field exceeds its maximum length"
+    );
 }
 
 #[test]
@@ -316,14 +318,6 @@ fn test_error_descriptions_additional() {
         Error::DisputeVoteExpired.description(),
         "Dispute voting period expired"
     );
-    assert_eq!(
-        Error::DisputeVoteDenied.description(),
-        "Dispute voting not allowed"
-    );
-    assert_eq!(
-        Error::DisputeAlreadyVoted.description(),
-        "Already voted in dispute"
-    );
     assert_eq!(
         Error::DisputeCondNotMet.description(),
         "Dispute resolution conditions not met"
@@ -332,10 +326,6 @@ fn test_error_descriptions_additional() {
         Error::DisputeFeeFailed.description(),
         "Dispute fee distribution failed"
     );
-    assert_eq!(
-        Error::DisputeNoEscalate.description(),
-        "Dispute escalation not allowed"
-    );
     assert_eq!(
         Error::ThresholdBelowMin.description(),
         "Threshold below minimum"
@@ -348,7 +338,6 @@ fn test_error_descriptions_additional() {
         Error::FeeAlreadyCollected.description(),
         "Fee already collected"
     );
-    assert_eq!(Error::NoFeesToCollect.description(), "No fees to collect");
     assert_eq!(
         Error::InvalidExtensionDays.description(),
         "Invalid extension days"
@@ -361,34 +350,28 @@ fn test_error_descriptions_additional() {
         Error::AdminNotSet.description(),
         "Admin address is not set (initialization missing)"
     );
-    assert_eq!(
-        Error::TimeoutNotSet.description(),
-        "Dispute timeout not set"
-    );
     assert_eq!(
         Error::InvalidTimeoutHours.description(),
         "Invalid timeout hours"
     );
-}
-
-#[test]
-fn test_error_descriptions_circuit_breaker() {
-    assert_eq!(
-        Error::CBNotInitialized.description(),
-        "Circuit breaker not initialized"
-    );
     assert_eq!(
-        Error::CBAlreadyOpen.description(),
-        "Circuit breaker is already open (paused)"
+        Error::FeeIncreaseTimelocked.description(),
+        "Fee increases must be scheduled with schedule_action"
     );
     assert_eq!(
-        Error::CBNotOpen.description(),
-        "Circuit breaker is not open (cannot recover)"
+        Error::CannotRecoverStakingToken.description(),
+        "Token backs escrow or a fee vault and cannot be recovered"
     );
+}
+
+#[test]
+fn test_error_descriptions_circuit_breaker() {
     assert_eq!(
         Error::CBOpen.description(),
         "Circuit breaker is open (operations blocked)"
     );
+    assert_eq!(Error::ContractPaused.description(), "Contract is paused");
+    assert_eq!(Error::MarketFrozen.description(), "Market is frozen");
 }
 
 // ===== DESCRIPTION NON-EMPTY TESTS =====
@@ -409,43 +392,43 @@ fn test_all_error_descriptions_are_non_empty() {
     assert!(!Error::AlreadyBet.description().is_empty());
     assert!(!Error::BetsAlreadyPlaced.description().is_empty());
     assert!(!Error::InsufficientBalance.description().is_empty());
+    assert!(!Error::BetBelowMinimum.description().is_empty());
+    assert!(!Error::InvalidAmount.description().is_empty());
+    assert!(!Error::ConflictingOutcome.description().is_empty());
+    assert!(!Error::AddressBlocked.description().is_empty());
     assert!(!Error::OracleUnavailable.description().is_empty());
     assert!(!Error::InvalidOracleConfig.description().is_empty());
     assert!(!Error::OracleStale.description().is_empty());
     assert!(!Error::OracleNoConsensus.description().is_empty());
     assert!(!Error::OracleVerified.description().is_empty());
     assert!(!Error::MarketNotReady.description().is_empty());
-    assert!(!Error::FallbackOracleUnavailable.description().is_empty());
     assert!(!Error::ResolutionTimeoutReached.description().is_empty());
     assert!(!Error::InvalidQuestion.description().is_empty());
     assert!(!Error::InvalidOutcomes.description().is_empty());
     assert!(!Error::InvalidDuration.description().is_empty());
     assert!(!Error::InvalidThreshold.description().is_empty());
     assert!(!Error::InvalidComparison.description().is_empty());
+    assert!(!Error::MetadataTooLong.description().is_empty());
     assert!(!Error::InvalidState.description().is_empty());
     assert!(!Error::InvalidInput.description().is_empty());
     assert!(!Error::InvalidFeeConfig.description().is_empty());
     assert!(!Error::ConfigNotFound.description().is_empty());
     assert!(!Error::AlreadyDisputed.description().is_empty());
     assert!(!Error::DisputeVoteExpired.description().is_empty());
-    assert!(!Error::DisputeVoteDenied.description().is_empty());
-    assert!(!Error::DisputeAlreadyVoted.description().is_empty());
     assert!(!Error::DisputeCondNotMet.description().is_empty());
     assert!(!Error::DisputeFeeFailed.description().is_empty());
-    assert!(!Error::DisputeNoEscalate.description().is_empty());
     assert!(!Error::ThresholdBelowMin.description().is_empty());
     assert!(!Error::ThresholdTooHigh.description().is_empty());
     assert!(!Error::FeeAlreadyCollected.description().is_empty());
-    assert!(!Error::NoFeesToCollect.description().is_empty());
     assert!(!Error::InvalidExtensionDays.description().is_empty());
     assert!(!Error::ExtensionDenied.description().is_empty());
     assert!(!Error::AdminNotSet.description().is_empty());
-    assert!(!Error::TimeoutNotSet.description().is_empty());
     assert!(!Error::InvalidTimeoutHours.description().is_empty());
-    assert!(!Error::CBNotInitialized.description().is_empty());
-    assert!(!Error::CBAlreadyOpen.description().is_empty());
-    assert!(!Error::CBNotOpen.description().is_empty());
+    assert!(!Error::FeeIncreaseTimelocked.description().is_empty());
+    assert!(!Error::CannotRecoverStakingToken.description().is_empty());
     assert!(!Error::CBOpen.description().is_empty());
+    assert!(!Error::ContractPaused.description().is_empty());
+    assert!(!Error::MarketFrozen.description().is_empty());
 }
 
 #[test]
@@ -464,43 +447,43 @@ fn test_all_error_codes_are_non_empty() {
     assert!(!Error::AlreadyBet.code().is_empty());
     assert!(!Error::BetsAlreadyPlaced.code().is_empty());
     assert!(!Error::InsufficientBalance.code().is_empty());
+    assert!(!Error::BetBelowMinimum.code().is_empty());
+    assert!(!Error::InvalidAmount.code().is_empty());
+    assert!(!Error::ConflictingOutcome.code().is_empty());
+    assert!(!Error::AddressBlocked.code().is_empty());
     assert!(!Error::OracleUnavailable.code().is_empty());
     assert!(!Error::InvalidOracleConfig.code().is_empty());
     assert!(!Error::OracleStale.code().is_empty());
     assert!(!Error::OracleNoConsensus.code().is_empty());
     assert!(!Error::OracleVerified.code().is_empty());
     assert!(!Error::MarketNotReady.code().is_empty());
-    assert!(!Error::FallbackOracleUnavailable.code().is_empty());
     assert!(!Error::ResolutionTimeoutReached.code().is_empty());
     assert!(!Error::InvalidQuestion.code().is_empty());
     assert!(!Error::InvalidOutcomes.code().is_empty());
     assert!(!Error::InvalidDuration.code().is_empty());
     assert!(!Error::InvalidThreshold.code().is_empty());
     assert!(!Error::InvalidComparison.code().is_empty());
+    assert!(!Error::MetadataTooLong.code().is_empty());
     assert!(!Error::InvalidState.code().is_empty());
     assert!(!Error::InvalidInput.code().is_empty());
     assert!(!Error::InvalidFeeConfig.code().is_empty());
     assert!(!Error::ConfigNotFound.code().is_empty());
     assert!(!Error::AlreadyDisputed.code().is_empty());
     assert!(!Error::DisputeVoteExpired.code().is_empty());
-    assert!(!Error::DisputeVoteDenied.code().is_empty());
-    assert!(!Error::DisputeAlreadyVoted.code().is_empty());
     assert!(!Error::DisputeCondNotMet.code().is_empty());
     assert!(!Error::DisputeFeeFailed.code().is_empty());
-    assert!(!Error::DisputeNoEscalate.code().is_empty());
     assert!(!Error::ThresholdBelowMin.code().is_empty());
     assert!(!Error::ThresholdTooHigh.code().is_empty());
     assert!(!Error::FeeAlreadyCollected.code().is_empty());
-    assert!(!Error::NoFeesToCollect.code().is_empty());
     assert!(!Error::InvalidExtensionDays.code().is_empty());
     assert!(!Error::ExtensionDenied.code().is_empty());
     assert!(!Error::AdminNotSet.code().is_empty());
-    assert!(!Error::TimeoutNotSet.code().is_empty());
     assert!(!Error::InvalidTimeoutHours.code().is_empty());
-    assert!(!Error::CBNotInitialized.code().is_empty());
-    assert!(!Error::CBAlreadyOpen.code().is_empty());
-    assert!(!Error::CBNotOpen.code().is_empty());
+    assert!(!Error::FeeIncreaseTimelocked.code().is_empty());
+    assert!(!Error::CannotRecoverStakingToken.code().is_empty());
     assert!(!Error::CBOpen.code().is_empty());
+    assert!(!Error::ContractPaused.code().is_empty());
+    assert!(!Error::MarketFrozen.code().is_empty());
 }
 
 // ===== CODE UNIQUENESS TESTS =====
@@ -522,43 +505,43 @@ fn test_error_numeric_codes_are_unique() {
         Error::AlreadyBet as u32,
         Error::BetsAlreadyPlaced as u32,
         Error::InsufficientBalance as u32,
+        Error::BetBelowMinimum as u32,
+        Error::InvalidAmount as u32,
+        Error::ConflictingOutcome as u32,
+        Error::AddressBlocked as u32,
         Error::OracleUnavailable as u32,
         Error::InvalidOracleConfig as u32,
         Error::OracleStale as u32,
         Error::OracleNoConsensus as u32,
         Error::OracleVerified as u32,
         Error::MarketNotReady as u32,
-        Error::FallbackOracleUnavailable as u32,
         Error::ResolutionTimeoutReached as u32,
         Error::InvalidQuestion as u32,
         Error::InvalidOutcomes as u32,
         Error::InvalidDuration as u32,
         Error::InvalidThreshold as u32,
         Error::InvalidComparison as u32,
+        Error::MetadataTooLong as u32,
         Error::InvalidState as u32,
         Error::InvalidInput as u32,
         Error::InvalidFeeConfig as u32,
         Error::ConfigNotFound as u32,
         Error::AlreadyDisputed as u32,
         Error::DisputeVoteExpired as u32,
-        Error::DisputeVoteDenied as u32,
-        Error::DisputeAlreadyVoted as u32,
         Error::DisputeCondNotMet as u32,
         Error::DisputeFeeFailed as u32,
-        Error::DisputeNoEscalate as u32,
         Error::ThresholdBelowMin as u32,
         Error::ThresholdTooHigh as u32,
         Error::FeeAlreadyCollected as u32,
-        Error::NoFeesToCollect as u32,
         Error::InvalidExtensionDays as u32,
         Error::ExtensionDenied as u32,
         Error::AdminNotSet as u32,
-        Error::TimeoutNotSet as u32,
         Error::InvalidTimeoutHours as u32,
-        Error::CBNotInitialized as u32,
-        Error::CBAlreadyOpen as u32,
-        Error::CBNotOpen as u32,
+        Error::FeeIncreaseTimelocked as u32,
+        Error::CannotRecoverStakingToken as u32,
         Error::CBOpen as u32,
+        Error::ContractPaused as u32,
+        Error::MarketFrozen as u32,
     ];
 
     // Verify all codes are unique using a simple O(n^2) uniqueness check
@@ -589,43 +572,43 @@ fn test_error_string_codes_are_unique() {
         Error::AlreadyBet.code(),
         Error::BetsAlreadyPlaced.code(),
         Error::InsufficientBalance.code(),
+        Error::BetBelowMinimum.code(),
+        Error::InvalidAmount.code(),
+        Error::ConflictingOutcome.code(),
+        Error::AddressBlocked.code(),
         Error::OracleUnavailable.code(),
         Error::InvalidOracleConfig.code(),
         Error::OracleStale.code(),
         Error::OracleNoConsensus.code(),
         Error::OracleVerified.code(),
         Error::MarketNotReady.code(),
-        Error::FallbackOracleUnavailable.code(),
         Error::ResolutionTimeoutReached.code(),
         Error::InvalidQuestion.code(),
         Error::InvalidOutcomes.code(),
         Error::InvalidDuration.code(),
         Error::InvalidThreshold.code(),
         Error::InvalidComparison.code(),
+        Error::MetadataTooLong.code(),
         Error::InvalidState.code(),
         Error::InvalidInput.code(),
         Error::InvalidFeeConfig.code(),
         Error::ConfigNotFound.code(),
         Error::AlreadyDisputed.code(),
         Error::DisputeVoteExpired.code(),
-        Error::DisputeVoteDenied.code(),
-        Error::DisputeAlreadyVoted.code(),
         Error::DisputeCondNotMet.code(),
         Error::DisputeFeeFailed.code(),
-        Error::DisputeNoEscalate.code(),
         Error::ThresholdBelowMin.code(),
         Error::ThresholdTooHigh.code(),
         Error::FeeAlreadyCollected.code(),
-        Error::NoFeesToCollect.code(),
         Error::InvalidExtensionDays.code(),
         Error::ExtensionDenied.code(),
         Error::AdminNotSet.code(),
-        Error::TimeoutNotSet.code(),
         Error::InvalidTimeoutHours.code(),
-        Error::CBNotInitialized.code(),
-        Error::CBAlreadyOpen.code(),
-        Error::CBNotOpen.code(),
+        Error::FeeIncreaseTimelocked.code(),
+        Error::CannotRecoverStakingToken.code(),
         Error::CBOpen.code(),
+        Error::ContractPaused.code(),
+        Error::MarketFrozen.code(),
     ];
 
     for i in 0..codes.len() {
@@ -676,7 +659,6 @@ fn test_oracle_errors_in_range_200_to_207() {
         Error::OracleNoConsensus as u32,
         Error::OracleVerified as u32,
         Error::MarketNotReady as u32,
-        Error::FallbackOracleUnavailable as u32,
         Error::ResolutionTimeoutReached as u32,
     ];
     for &code in oracle_errs {
@@ -707,17 +689,16 @@ fn test_validation_errors_in_range_300_to_304() {
 }
 
 #[test]
-fn test_circuit_breaker_errors_in_range_500_to_503() {
+fn test_circuit_breaker_errors_in_range_500_to_505() {
     let cb_errs = &[
-        Error::CBNotInitialized as u32,
-        Error::CBAlreadyOpen as u32,
-        Error::CBNotOpen as u32,
         Error::CBOpen as u32,
+        Error::ContractPaused as u32,
+        Error::MarketFrozen as u32,
     ];
     for &code in cb_errs {
         assert!(
-            code >= 500 && code <= 503,
-            "Circuit breaker error {} not in range 500-503",
+            code >= 500 && code <= 505,
+            "Circuit breaker error {} not in range 500-505",
             code
         );
     }
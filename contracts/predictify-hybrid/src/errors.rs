@@ -14,7 +14,7 @@ pub enum Error {
     Unauthorized = 100,
     /// Market not found
     MarketNotFound = 101,
-    /// Market is closed (has ended)
+    /// Market is closed (has ended, or betting closed at its bet deadline)
     MarketClosed = 102,
     /// Market is already resolved
     MarketResolved = 103,
@@ -36,6 +36,14 @@ pub enum Error {
     BetsAlreadyPlaced = 111,
     /// Insufficient balance
     InsufficientBalance = 112,
+    /// Bet amount is below the effective minimum bet
+    BetBelowMinimum = 113,
+    /// Amount is zero or negative
+    InvalidAmount = 114,
+    /// User already holds a position on a different outcome of this market
+    ConflictingOutcome = 115,
+    /// Address is on the compliance blacklist
+    AddressBlocked = 116,
     // FundsLocked removed to save space
 
     // ===== ORACLE ERRORS =====
@@ -51,8 +59,7 @@ pub enum Error {
     OracleVerified = 204,
     /// Market not ready for oracle verification
     MarketNotReady = 205,
-    /// Fallback oracle is unavailable or unhealthy
-    FallbackOracleUnavailable = 206,
+    // FallbackOracleUnavailable (206) removed to save space
     /// Resolution timeout has been reached
     ResolutionTimeoutReached = 207,
 
@@ -67,6 +74,8 @@ pub enum Error {
     InvalidThreshold = 303,
     /// Invalid comparison operator
     InvalidComparison = 304,
+    /// Market metadata description or reference URL is too long
+    MetadataTooLong = 305,
 
     // ===== ADDITIONAL ERRORS =====
     /// Invalid state
@@ -79,45 +88,45 @@ pub enum Error {
     ConfigNotFound = 403,
     /// Already disputed
     AlreadyDisputed = 404,
-    /// Dispute voting period expired
+    /// Dispute voting period expired or voting is no longer active
     DisputeVoteExpired = 405,
-    /// Dispute voting not allowed
-    DisputeVoteDenied = 406,
-    /// Already voted in dispute
-    DisputeAlreadyVoted = 407,
+    // DisputeVoteDenied (406) folded into DisputeVoteExpired
+    // DisputeAlreadyVoted (407) folded into AlreadyVoted
     /// Dispute resolution conditions not met
     DisputeCondNotMet = 408,
     /// Dispute fee distribution failed
     DisputeFeeFailed = 409,
-    /// Dispute escalation not allowed
-    DisputeNoEscalate = 410,
+    // DisputeNoEscalate (410) folded into DisputeCondNotMet
     /// Threshold below minimum
     ThresholdBelowMin = 411,
     /// Threshold exceeds maximum
     ThresholdTooHigh = 412,
     /// Fee already collected
     FeeAlreadyCollected = 413,
-    /// No fees to collect
-    NoFeesToCollect = 414,
+    // NoFeesToCollect (414) folded into NothingToClaim
     /// Invalid extension days
     InvalidExtensionDays = 415,
     /// Extension not allowed or exceeded
     ExtensionDenied = 416,
     /// Admin address is not set (initialization missing)
     AdminNotSet = 418,
-    /// Dispute timeout not set
-    TimeoutNotSet = 419,
+    // TimeoutNotSet (419) folded into ConfigNotFound
     /// Invalid timeout hours
     InvalidTimeoutHours = 422,
+    /// Fee increase must be scheduled through the admin action timelock
+    FeeIncreaseTimelocked = 423,
+    /// Token backs escrow or a fee vault and cannot be recovered
+    CannotRecoverStakingToken = 424,
     // ===== CIRCUIT BREAKER ERRORS =====
-    /// Circuit breaker not initialized
-    CBNotInitialized = 500,
-    /// Circuit breaker is already open (paused)
-    CBAlreadyOpen = 501,
-    /// Circuit breaker is not open (cannot recover)
-    CBNotOpen = 502,
+    // CBNotInitialized (500) folded into ConfigNotFound
+    // CBAlreadyOpen (501) folded into InvalidState
+    // CBNotOpen (502) folded into InvalidState
     /// Circuit breaker is open (operations blocked)
     CBOpen = 503,
+    /// Contract is paused by a pauser
+    ContractPaused = 504,
+    /// Market is frozen by the admin
+    MarketFrozen = 505,
 }
 
 // ===== ERROR CATEGORIZATION AND RECOVERY SYSTEM =====
@@ -904,7 +913,7 @@ impl ErrorHandler {
                 ErrorCategory::Market,
                 RecoveryStrategy::Abort,
             ),
-            Error::InsufficientStake => (
+            Error::InsufficientStake | Error::BetBelowMinimum => (
                 ErrorSeverity::Medium,
                 ErrorCategory::UserOperation,
                 RecoveryStrategy::Retry,
@@ -963,7 +972,7 @@ impl ErrorHandler {
                 &Env::default(),
                 "Please ensure you have the required permissions to perform this action.",
             ),
-            (Error::InsufficientStake, _) => String::from_str(
+            (Error::InsufficientStake | Error::BetBelowMinimum, _) => String::from_str(
                 &Env::default(),
                 "Please increase your stake amount to meet the minimum requirement.",
             ),
@@ -1083,6 +1092,10 @@ impl Error {
                 "Bets have already been placed on this market (cannot update)"
             }
             Error::InsufficientBalance => "Insufficient balance for operation",
+            Error::BetBelowMinimum => "Bet amount is below the minimum bet",
+            Error::InvalidAmount => "Amount must be positive",
+            Error::ConflictingOutcome => "Bet conflicts with the user's position on another outcome",
+            Error::AddressBlocked => "Address is blocked by the compliance blacklist",
             Error::OracleUnavailable => "Oracle is unavailable",
             Error::InvalidOracleConfig => "Invalid oracle configuration",
             Error::InvalidQuestion => "Invalid question format",
@@ -1090,36 +1103,32 @@ impl Error {
             Error::InvalidDuration => "Invalid duration specified",
             Error::InvalidThreshold => "Invalid threshold value",
             Error::InvalidComparison => "Invalid comparison operator",
+            Error::MetadataTooLong => "Market metadata field exceeds its maximum length",
             Error::InvalidState => "Invalid state",
             Error::InvalidInput => "Invalid input",
             Error::InvalidFeeConfig => "Invalid fee configuration",
             Error::ConfigNotFound => "Configuration not found",
             Error::AlreadyDisputed => "Already disputed",
             Error::DisputeVoteExpired => "Dispute voting period expired",
-            Error::DisputeVoteDenied => "Dispute voting not allowed",
-            Error::DisputeAlreadyVoted => "Already voted in dispute",
             Error::DisputeCondNotMet => "Dispute resolution conditions not met",
             Error::DisputeFeeFailed => "Dispute fee distribution failed",
-            Error::DisputeNoEscalate => "Dispute escalation not allowed",
             Error::ThresholdBelowMin => "Threshold below minimum",
             Error::ThresholdTooHigh => "Threshold exceeds maximum",
             Error::FeeAlreadyCollected => "Fee already collected",
-            Error::NoFeesToCollect => "No fees to collect",
             Error::InvalidExtensionDays => "Invalid extension days",
             Error::ExtensionDenied => "Extension not allowed or exceeded",
             Error::AdminNotSet => "Admin address is not set (initialization missing)",
-            Error::TimeoutNotSet => "Dispute timeout not set",
             Error::InvalidTimeoutHours => "Invalid timeout hours",
+            Error::FeeIncreaseTimelocked => "Fee increases must be scheduled with schedule_action",
+            Error::CannotRecoverStakingToken => "Token backs escrow or a fee vault and cannot be recovered",
             Error::OracleStale => "Oracle data is stale or timed out",
             Error::OracleNoConsensus => "Oracle consensus not reached",
             Error::OracleVerified => "Oracle result already verified",
             Error::MarketNotReady => "Market not ready for oracle verification",
-            Error::FallbackOracleUnavailable => "Fallback oracle is unavailable or unhealthy",
             Error::ResolutionTimeoutReached => "Resolution timeout has been reached",
-            Error::CBNotInitialized => "Circuit breaker not initialized",
-            Error::CBAlreadyOpen => "Circuit breaker is already open (paused)",
-            Error::CBNotOpen => "Circuit breaker is not open (cannot recover)",
             Error::CBOpen => "Circuit breaker is open (operations blocked)",
+            Error::ContractPaused => "Contract is paused",
+            Error::MarketFrozen => "Market is frozen",
         }
     }
 
@@ -1201,6 +1210,10 @@ impl Error {
             Error::AlreadyBet => "ALREADY_BET",
             Error::BetsAlreadyPlaced => "BETS_ALREADY_PLACED",
             Error::InsufficientBalance => "INSUFFICIENT_BALANCE",
+            Error::BetBelowMinimum => "BET_BELOW_MINIMUM",
+            Error::InvalidAmount => "INVALID_AMOUNT",
+            Error::ConflictingOutcome => "CONFLICTING_OUTCOME",
+            Error::AddressBlocked => "ADDRESS_BLOCKED",
             Error::OracleUnavailable => "ORACLE_UNAVAILABLE",
             Error::InvalidOracleConfig => "INVALID_ORACLE_CONFIG",
             Error::InvalidQuestion => "INVALID_QUESTION",
@@ -1208,36 +1221,32 @@ impl Error {
             Error::InvalidDuration => "INVALID_DURATION",
             Error::InvalidThreshold => "INVALID_THRESHOLD",
             Error::InvalidComparison => "INVALID_COMPARISON",
+            Error::MetadataTooLong => "METADATA_TOO_LONG",
             Error::InvalidState => "INVALID_STATE",
             Error::InvalidInput => "INVALID_INPUT",
             Error::InvalidFeeConfig => "INVALID_FEE_CONFIG",
             Error::ConfigNotFound => "CONFIGURATION_NOT_FOUND",
             Error::AlreadyDisputed => "ALREADY_DISPUTED",
             Error::DisputeVoteExpired => "DISPUTE_VOTING_PERIOD_EXPIRED",
-            Error::DisputeVoteDenied => "DISPUTE_VOTING_NOT_ALLOWED",
-            Error::DisputeAlreadyVoted => "DISPUTE_ALREADY_VOTED",
             Error::DisputeCondNotMet => "DISPUTE_RESOLUTION_CONDITIONS_NOT_MET",
             Error::DisputeFeeFailed => "DISPUTE_FEE_DISTRIBUTION_FAILED",
-            Error::DisputeNoEscalate => "DISPUTE_ESCALATION_NOT_ALLOWED",
             Error::ThresholdBelowMin => "THRESHOLD_BELOW_MINIMUM",
             Error::ThresholdTooHigh => "THRESHOLD_EXCEEDS_MAXIMUM",
             Error::FeeAlreadyCollected => "FEE_ALREADY_COLLECTED",
-            Error::NoFeesToCollect => "NO_FEES_TO_COLLECT",
             Error::InvalidExtensionDays => "INVALID_EXTENSION_DAYS",
             Error::ExtensionDenied => "EXTENSION_DENIED",
             Error::AdminNotSet => "ADMIN_NOT_SET",
-            Error::TimeoutNotSet => "DISPUTE_TIMEOUT_NOT_SET",
             Error::InvalidTimeoutHours => "INVALID_TIMEOUT_HOURS",
+            Error::FeeIncreaseTimelocked => "FEE_INCREASE_TIMELOCKED",
+            Error::CannotRecoverStakingToken => "CANNOT_RECOVER_STAKING_TOKEN",
             Error::OracleStale => "ORACLE_STALE",
             Error::OracleNoConsensus => "ORACLE_NO_CONSENSUS",
             Error::OracleVerified => "ORACLE_VERIFIED",
             Error::MarketNotReady => "MARKET_NOT_READY",
-            Error::FallbackOracleUnavailable => "FALLBACK_ORACLE_UNAVAILABLE",
             Error::ResolutionTimeoutReached => "RESOLUTION_TIMEOUT_REACHED",
-            Error::CBNotInitialized => "CIRCUIT_BREAKER_NOT_INITIALIZED",
-            Error::CBAlreadyOpen => "CIRCUIT_BREAKER_ALREADY_OPEN",
-            Error::CBNotOpen => "CIRCUIT_BREAKER_NOT_OPEN",
             Error::CBOpen => "CIRCUIT_BREAKER_OPEN",
+            Error::ContractPaused => "CONTRACT_PAUSED",
+            Error::MarketFrozen => "MARKET_FROZEN",
         }
     }
 }
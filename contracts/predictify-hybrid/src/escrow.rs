@@ -0,0 +1,146 @@
+//! Per-market escrow sub-accounting.
+//!
+//! Every market shares the contract's token balance, so each one also keeps its
+//! own ledger of the funds it holds: stakes, dispute bonds and its reserved fee.
+//! Funds entering a market are credited to its escrow; payouts, refunds,
+//! collected fees and released bonds are debited. A debit larger than the
+//! market's escrow fails, so a faulty payout in one market can never spend
+//! another market's funds.
+//!
+//! Winning shares are rounded down, so each market also tracks how much of its
+//! pool after the platform fee it has paid out. The last winner paid, whichever
+//! path settles them, takes the remainder, and a resolved market's payouts plus
+//! its fee always add up to its pool exactly.
+
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+use crate::amounts;
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::types::Market;
+
+/// Per-market escrow ledger.
+pub struct EscrowManager;
+
+impl EscrowManager {
+    /// Funds currently escrowed by a market.
+    pub fn get(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MarketEscrow(market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Record `amount` entering a market's escrow.
+    pub fn credit(env: &Env, market_id: &Symbol, amount: i128) -> Result<(), Error> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let escrowed = amounts::checked_add(Self::get(env, market_id), amount)?;
+        Self::set(env, market_id, escrowed);
+        Ok(())
+    }
+
+    /// Record `amount` leaving a market's escrow.
+    ///
+    /// Returns `Error::InvalidState` if the market does not hold `amount`.
+    pub fn debit(env: &Env, market_id: &Symbol, amount: i128) -> Result<(), Error> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let escrowed = Self::get(env, market_id);
+        if amount < 0 || amount > escrowed {
+            return Err(Error::InvalidState);
+        }
+        Self::set(env, market_id, escrowed - amount);
+        Ok(())
+    }
+
+    /// Sum of the escrows of every market on the global staking token, kept
+    /// up to date by `credit` and `debit`.
+    pub fn global_token_total(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::GlobalEscrowTotal)
+            .unwrap_or(0)
+    }
+
+    /// Record a winning position paid `entitlement`, its pro-rata share of the
+    /// pool after the platform fee rounded down, and return the rounding
+    /// remainder owed on top: zero for every winner but the last one paid.
+    ///
+    /// Positions without stake are not counted. Callers settle each winning
+    /// position exactly once, before marking it claimed.
+    pub fn settle_winner_share(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user_stake: i128,
+        entitlement: i128,
+    ) -> Result<i128, Error> {
+        if user_stake <= 0 {
+            return Ok(0);
+        }
+        let key = (symbol_short!("dist"), market_id.clone());
+        let (distributed, winners_left): (i128, u32) = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| (0, Self::unpaid_winners(market)));
+        let mut distributed = amounts::checked_add(distributed, entitlement)?;
+        let winners_left = winners_left.saturating_sub(1);
+
+        let mut remainder = 0;
+        if winners_left == 0 {
+            let fee = crate::fees::FeeTracker::market_fee_amount(market);
+            let pool = amounts::checked_sub(market.total_staked, fee)?;
+            remainder = pool.saturating_sub(distributed).max(0);
+            distributed = amounts::checked_add(distributed, remainder)?;
+        }
+        env.storage()
+            .persistent()
+            .set(&key, &(distributed, winners_left));
+        Ok(remainder)
+    }
+
+    /// Winnings a resolved market has paid out so far, including creator shares
+    /// and its rounding remainder once the last winner is paid.
+    pub fn get_distributed(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get::<_, (i128, u32)>(&(symbol_short!("dist"), market_id.clone()))
+            .map(|(distributed, _)| distributed)
+            .unwrap_or(0)
+    }
+
+    /// Positions backing the market's winning outcome(s), from the per-outcome
+    /// counts, so the first winner settled never scans the positions.
+    fn unpaid_winners(market: &Market) -> u32 {
+        let winning_outcomes = match &market.winning_outcomes {
+            Some(outcomes) => outcomes,
+            None => return 0,
+        };
+        winning_outcomes
+            .iter()
+            .fold(0u32, |total, outcome| {
+                total.saturating_add(market.outcome_position_count(&outcome))
+            })
+    }
+
+    fn set(env: &Env, market_id: &Symbol, escrowed: i128) {
+        let market: Option<Market> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()));
+        if market.map_or(true, |market| market.token.is_none()) {
+            let delta = escrowed - Self::get(env, market_id);
+            env.storage().instance().set(
+                &DataKey::GlobalEscrowTotal,
+                &Self::global_token_total(env).saturating_add(delta),
+            );
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MarketEscrow(market_id.clone()), &escrowed);
+    }
+}
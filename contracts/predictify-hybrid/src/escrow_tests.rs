@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+//! Escrow sub-accounting tests.
+//!
+//! Every market keeps its own escrow ledger next to the shared token balance.
+//! `reconcile_market` compares it against what the market's positions still
+//! owe, which must agree at every step of the lifecycle, and a payout larger
+//! than a market's escrow is rejected instead of spending another market's funds.
+
+use crate::bets::BetStorage;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::CancelReason;
+use crate::PredictifyHybridClient;
+use crate::MAX_PAYOUT_PAGE_SIZE;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, String, Symbol};
+
+const DISPUTE_BOND: i128 = 10_000_000;
+
+fn bet(test: &PredictifyTest, market_id: &Symbol, outcome: &str, amount: i128) -> Address {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        market_id,
+        &String::from_str(&test.env, outcome),
+        &amount,
+        &None,
+    );
+    user
+}
+
+fn resolve_yes(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, market_id, &String::from_str(&test.env, "yes"));
+}
+
+/// Assert the market's escrow is `expected` and matches its positions, and the
+/// contract holds enough tokens for every escrow plus the fee vault.
+fn assert_reconciled(client: &PredictifyHybridClient, market_id: &Symbol, expected: i128) {
+    assert_eq!(client.reconcile_market(market_id), (expected, expected));
+    let (escrow_total, vault_total, token_balance) = client.reconcile();
+    assert!(escrow_total >= expected);
+    assert!(token_balance >= escrow_total + vault_total);
+}
+
+#[test]
+fn test_escrow_reconciles_through_full_lifecycle() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    assert_reconciled(&client, &market_id, 0);
+
+    let first = bet(&test, &market_id, "yes", 300_000_000);
+    assert_reconciled(&client, &market_id, 300_000_000);
+    bet(&test, &market_id, "yes", 100_000_000);
+    assert_reconciled(&client, &market_id, 400_000_000);
+    let loser = bet(&test, &market_id, "no", 600_000_000);
+    assert_reconciled(&client, &market_id, 1_000_000_000);
+
+    // An early exit releases the whole stake, penalty included, from escrow
+    let leaver = bet(&test, &market_id, "no", 100_000_000);
+    assert_reconciled(&client, &market_id, 1_100_000_000);
+    client.withdraw_bet(&leaver, &market_id);
+    assert_reconciled(&client, &market_id, 1_000_000_000);
+
+    // Resolution moves nothing: winnings and the fee are still owed
+    resolve_yes(&test, &market_id);
+    assert_reconciled(&client, &market_id, 1_000_000_000);
+
+    // A rejected dispute bond leaves escrow for the fee vault
+    client.dispute_result(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &DISPUTE_BOND,
+    );
+    assert_reconciled(&client, &market_id, 1_000_000_000 + DISPUTE_BOND);
+    client.resolve_dispute(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &false,
+    );
+    assert_reconciled(&client, &market_id, 1_000_000_000);
+    test.advance_past_payout_unlock(&market_id);
+
+    // Winners share 980 of the 1,000 pool 3:1; the fee is 20
+    assert_eq!(client.claim_winnings(&first, &market_id), 735_000_000);
+    assert_reconciled(&client, &market_id, 265_000_000);
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 20_000_000);
+    assert_reconciled(&client, &market_id, 245_000_000);
+    assert_eq!(
+        client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE).0,
+        245_000_000
+    );
+    assert_reconciled(&client, &market_id, 0);
+    assert_eq!(client.reconcile().0, 0);
+}
+
+#[test]
+fn test_escrow_released_by_cancellation_refunds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    bet(&test, &market_id, "yes", 200_000_000);
+    bet(&test, &market_id, "no", 50_000_000);
+    assert_reconciled(&client, &market_id, 250_000_000);
+
+    // Cancelling keeps the stakes escrowed until they are refunded
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+    assert_reconciled(&client, &market_id, 250_000_000);
+    client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_reconciled(&client, &market_id, 0);
+}
+
+#[test]
+fn test_payout_beyond_market_escrow_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let healthy = test.create_test_market();
+    let healthy_winner = bet(&test, &healthy, "yes", 100_000_000);
+    bet(&test, &healthy, "no", 100_000_000);
+
+    let faulty = test.create_test_market();
+    let faulty_winner = bet(&test, &faulty, "yes", 100_000_000);
+    bet(&test, &faulty, "no", 100_000_000);
+    resolve_yes(&test, &healthy);
+    resolve_yes(&test, &faulty);
+    test.advance_past_payout_unlock(&healthy);
+    test.advance_past_payout_unlock(&faulty);
+
+    // Simulate an accounting bug that inflates one position past the pool
+    test.env.as_contract(&test.contract_id, || {
+        let mut position = BetStorage::get_bet(&test.env, &faulty, &faulty_winner).unwrap();
+        position.amount = 200_000_000;
+        BetStorage::store_bet(&test.env, &position).unwrap();
+    });
+
+    // The shared balance could cover it, but the faulty market's escrow cannot
+    assert_eq!(
+        client.try_claim_winnings(&faulty_winner, &faulty),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(client.reconcile_market(&faulty).0, 200_000_000);
+    assert_eq!(client.reconcile_market(&healthy), (200_000_000, 200_000_000));
+
+    assert_eq!(client.claim_winnings(&healthy_winner, &healthy), 196_000_000);
+    assert_reconciled(&client, &healthy, 4_000_000);
+}
+
+#[test]
+fn test_global_escrow_total_follows_credits_and_debits() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    assert_eq!(client.reconcile().0, 0);
+
+    bet(&test, &first, "yes", 200_000_000);
+    bet(&test, &second, "no", 50_000_000);
+    assert_eq!(client.reconcile().0, 250_000_000);
+
+    client.cancel_event(&test.admin, &first, &CancelReason::Administrative, &None);
+    client.process_refunds(&first, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(client.reconcile().0, 50_000_000);
+    assert_eq!(
+        client.reconcile().0,
+        client.reconcile_market(&first).0 + client.reconcile_market(&second).0
+    );
+}
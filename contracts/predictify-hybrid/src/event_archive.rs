@@ -6,6 +6,8 @@
 
 use crate::errors::Error;
 use crate::market_id_generator::MarketIdGenerator;
+use crate::markets::MarketStateManager;
+use crate::storage::DataKey;
 use crate::types::{EventHistoryEntry, Market, MarketState};
 use soroban_sdk::{panic_with_error, Address, Env, String, Symbol, Vec};
 
@@ -36,21 +38,17 @@ impl EventArchive {
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
 
         if admin != &stored_admin {
             return Err(Error::Unauthorized);
         }
 
-        let market: Market = env
-            .storage()
-            .persistent()
-            .get(market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
 
-        if market.state != MarketState::Resolved && market.state != MarketState::Cancelled {
+        if market.state != MarketState::Resolved && !market.state.is_refunding() {
             return Err(Error::InvalidState);
         }
 
@@ -157,7 +155,7 @@ impl EventArchive {
                     if let Some(market) = env
                         .storage()
                         .persistent()
-                        .get::<Symbol, Market>(&entry.market_id)
+                        .get::<DataKey, Market>(&DataKey::Market(entry.market_id.clone()))
                     {
                         result.push_back(Self::market_to_history_entry(
                             env,
@@ -193,7 +191,7 @@ impl EventArchive {
                 if let Some(market) = env
                     .storage()
                     .persistent()
-                    .get::<Symbol, Market>(&entry.market_id)
+                    .get::<DataKey, Market>(&DataKey::Market(entry.market_id.clone()))
                 {
                     if market.state == status {
                         result.push_back(Self::market_to_history_entry(
@@ -231,7 +229,7 @@ impl EventArchive {
                 if let Some(market) = env
                     .storage()
                     .persistent()
-                    .get::<Symbol, Market>(&entry.market_id)
+                    .get::<DataKey, Market>(&DataKey::Market(entry.market_id.clone()))
                 {
                     // Match against dedicated category field if set, otherwise oracle feed_id
                     let market_category = market
@@ -278,7 +276,7 @@ impl EventArchive {
                 if let Some(market) = env
                     .storage()
                     .persistent()
-                    .get::<Symbol, Market>(&entry.market_id)
+                    .get::<DataKey, Market>(&DataKey::Market(entry.market_id.clone()))
                 {
                     // Check if any of the market's tags match any of the query tags
                     let mut matched = false;
@@ -1,7 +1,11 @@
 #![cfg(test)]
 
+use crate::config::MAX_RESOLUTION_TIMEOUT_SECONDS;
 use crate::errors::Error;
-use crate::types::{EventVisibility, MarketState, OracleConfig, OracleProvider};
+use crate::storage::DataKey;
+use crate::types::{
+    CancelReason, EventVisibility, MarketState, OracleConfig, OracleProvider, OracleSigner,
+};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
 use soroban_sdk::{symbol_short, token::StellarAssetClient, vec, Address, Env, String, Symbol, Vec};
@@ -36,8 +40,8 @@ impl TestSetup {
         // Configure token used for creation fee collection and fund admin balance.
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_address);
+                .instance()
+                .set(&DataKey::Token, &token_address);
         });
         let token_client = StellarAssetClient::new(&env, &token_address);
         env.mock_all_auths();
@@ -69,6 +73,9 @@ fn test_create_event_success() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     let event_id = client.create_event(
@@ -102,7 +109,6 @@ fn test_create_event_success() {
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #400)")] // Error::InvalidState = 400
 fn test_create_event_without_token_configuration_fails() {
     let env = Env::default();
     env.mock_all_auths();
@@ -130,17 +136,23 @@ fn test_create_event_without_token_configuration_fails() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
-    client.create_event(
-        &admin,
-        &description,
-        &outcomes,
-        &end_time,
-        &oracle_config,
-        &None,
-        &0,
-        &EventVisibility::Public,
+    assert_eq!(
+        client.try_create_event(
+            &admin,
+            &description,
+            &outcomes,
+            &end_time,
+            &oracle_config,
+            &None,
+            &0,
+            &EventVisibility::Public,
+        ),
+        Err(Ok(Error::InvalidState))
     );
 }
 
@@ -162,6 +174,9 @@ fn test_create_market_success() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     let market_id = client.create_market(
@@ -171,7 +186,7 @@ fn test_create_market_success() {
         &duration_days,
         &oracle_config,
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -180,8 +195,116 @@ fn test_create_market_success() {
     assert!(client.get_market(&market_id).is_some());
 }
 
+fn try_create_market_with(
+    setup: &TestSetup,
+    duration_days: u32,
+    resolution_timeout: u64,
+) -> Result<Symbol, Error> {
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+    client
+        .try_create_market(
+            &setup.admin,
+            &String::from_str(&setup.env, "Will this market be created?"),
+            &vec![
+                &setup.env,
+                String::from_str(&setup.env, "Yes"),
+                String::from_str(&setup.env, "No"),
+            ],
+            &duration_days,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&setup.env),
+                feed_id: String::from_str(&setup.env, "BTC/USD"),
+                threshold: 50000,
+                comparison: String::from_str(&setup.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &resolution_timeout,
+            &None,
+            &None,
+            &None,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_create_market_default_duration_bounds() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+    assert_eq!(client.get_market_duration_limits(), (86_400, 365 * 86_400));
+
+    // A zero duration would end the market at creation
+    assert_eq!(
+        try_create_market_with(&setup, 0, 86400),
+        Err(Error::InvalidDuration)
+    );
+    assert!(try_create_market_with(&setup, 1, 86400).is_ok());
+    assert!(try_create_market_with(&setup, 365, 86400).is_ok());
+    assert_eq!(
+        try_create_market_with(&setup, 366, 86400),
+        Err(Error::ThresholdTooHigh)
+    );
+}
+
+#[test]
+fn test_create_market_respects_configured_duration_limits() {
+    let setup = TestSetup::new();
+    let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
+    let day = 86_400;
+
+    assert_eq!(
+        client.try_set_market_duration_limits(
+            &Address::generate(&setup.env),
+            &(2 * day),
+            &(10 * day)
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_market_duration_limits(&setup.admin, &0, &(10 * day)),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_market_duration_limits(&setup.admin, &(10 * day), &(2 * day)),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.set_market_duration_limits(&setup.admin, &(2 * day), &(10 * day));
+    assert_eq!(client.get_market_duration_limits(), (2 * day, 10 * day));
+
+    assert_eq!(
+        try_create_market_with(&setup, 1, 86400),
+        Err(Error::ThresholdBelowMin)
+    );
+    assert!(try_create_market_with(&setup, 2, 86400).is_ok());
+    assert!(try_create_market_with(&setup, 10, 86400).is_ok());
+    assert_eq!(
+        try_create_market_with(&setup, 11, 86400),
+        Err(Error::ThresholdTooHigh)
+    );
+}
+
+#[test]
+fn test_create_market_resolution_timeout_bounds() {
+    let setup = TestSetup::new();
+
+    assert_eq!(
+        try_create_market_with(&setup, 30, 0),
+        Err(Error::InvalidTimeoutHours)
+    );
+    assert!(try_create_market_with(&setup, 30, 1).is_ok());
+    assert!(try_create_market_with(&setup, 30, MAX_RESOLUTION_TIMEOUT_SECONDS).is_ok());
+    assert_eq!(
+        try_create_market_with(&setup, 30, MAX_RESOLUTION_TIMEOUT_SECONDS + 1),
+        Err(Error::InvalidTimeoutHours)
+    );
+}
+
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #100)")] // Error::Unauthorized = 100
 fn test_create_event_unauthorized() {
     let setup = TestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -200,22 +323,27 @@ fn test_create_event_unauthorized() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
-    client.create_event(
-        &non_admin,
-        &description,
-        &outcomes,
-        &end_time,
-        &oracle_config,
-        &None,
-        &0,
-        &EventVisibility::Public,
+    assert_eq!(
+        client.try_create_event(
+            &non_admin,
+            &description,
+            &outcomes,
+            &end_time,
+            &oracle_config,
+            &None,
+            &0,
+            &EventVisibility::Public,
+        ),
+        Err(Ok(Error::Unauthorized))
     );
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #302)")] // Error::InvalidDuration = 302
 fn test_create_event_invalid_end_time() {
     let setup = TestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -233,22 +361,27 @@ fn test_create_event_invalid_end_time() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
-    client.create_event(
-        &setup.admin,
-        &description,
-        &outcomes,
-        &end_time,
-        &oracle_config,
-        &None,
-        &0,
-        &EventVisibility::Public,
+    assert_eq!(
+        client.try_create_event(
+            &setup.admin,
+            &description,
+            &outcomes,
+            &end_time,
+            &oracle_config,
+            &None,
+            &0,
+            &EventVisibility::Public,
+        ),
+        Err(Ok(Error::InvalidDuration))
     );
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #301)")] // Error::InvalidDuration = 302
 fn test_create_event_empty_outcomes() {
     let setup = TestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -262,23 +395,27 @@ fn test_create_event_empty_outcomes() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
-    client.create_event(
-        &setup.admin,
-        &description,
-        &outcomes,
-        &end_time,
-        &oracle_config,
-        &None,
-        &0,
-        &EventVisibility::Public,
+    assert_eq!(
+        client.try_create_event(
+            &setup.admin,
+            &description,
+            &outcomes,
+            &end_time,
+            &oracle_config,
+            &None,
+            &0,
+            &EventVisibility::Public,
+        ),
+        Err(Ok(Error::InvalidOutcomes))
     );
 }
 
 #[test]
-
-#[should_panic(expected = "HostError: Error(Contract, #401)")] // Error::InvalidInput = 401
 fn test_create_event_limit_enforced() {
     let setup = TestSetup::new();
     let client = PredictifyHybridClient::new(&setup.env, &setup.contract_id);
@@ -296,10 +433,13 @@ fn test_create_event_limit_enforced() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
-    // The default limit is 20. Creating 21 events should panic on the 21st.
-    for _ in 0..21 {
+    // The default limit is 20. Creating 21 events should fail on the 21st.
+    for _ in 0..20 {
         client.create_market(
             &setup.admin,
             &description,
@@ -307,12 +447,27 @@ fn test_create_event_limit_enforced() {
             &1, // duration_days
             &oracle_config,
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
         );
     }
+    assert_eq!(
+        client.try_create_market(
+            &setup.admin,
+            &description,
+            &outcomes,
+            &1, // duration_days
+            &oracle_config,
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
 }
 
 #[test]
@@ -333,6 +488,9 @@ fn test_decrement_on_cancel_frees_slot() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     // Create 20 events, reaching the limit.
@@ -345,7 +503,7 @@ fn test_decrement_on_cancel_frees_slot() {
             &1, // duration_days
             &oracle_config,
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -358,6 +516,7 @@ fn test_decrement_on_cancel_frees_slot() {
         client.cancel_event(
             &setup.admin,
             &event_id,
+            &CancelReason::Administrative,
             &Some(String::from_str(&setup.env, "Cancellation")),
         );
     }
@@ -370,7 +529,7 @@ fn test_decrement_on_cancel_frees_slot() {
         &1, // duration_days
         &oracle_config,
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -395,6 +554,9 @@ fn test_event_id_unique() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     let event_id_1 = client.create_event(
@@ -440,6 +602,9 @@ fn test_event_storage_consistency() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     let event_id = client.create_event(
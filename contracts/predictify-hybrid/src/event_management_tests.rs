@@ -1,6 +1,7 @@
 #![cfg(test)]
 
 use crate::errors::Error;
+use crate::storage::DataKey;
 use crate::types::{OracleConfig, OracleProvider};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
@@ -30,8 +31,8 @@ impl TestSetup {
         // Store TokenID in contract
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         // Initialize the contract
@@ -0,0 +1,779 @@
+#![cfg(test)]
+
+//! Event schema tests.
+//!
+//! Every published event must use the `(topic, EVENT_SCHEMA_VERSION, ...ids)`
+//! topic layout, match its `get_events_abi` entry and decode back into its
+//! data struct.
+
+use crate::events::*;
+use crate::fees::{FeeWithdrawalSchedule, FeeWithdrawalStatus};
+use crate::gas::GasTracker;
+use crate::markets::{MarketPauseManager, MarketStateLogic};
+use crate::monitoring::{ContractMonitor, MonitoringTestingUtils};
+use crate::oracles::{OracleMetadata, OracleWhitelist};
+use crate::test::PredictifyTest;
+use crate::types::{
+    EventVisibility, MarketState, MarketType, OracleProvider, PendingAction, ReflectorAsset,
+    ResolveMode, TimelockedAction,
+};
+use crate::PredictifyHybridClient;
+use core::fmt::Debug;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{
+    symbol_short, vec, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal, TryIntoVal, Val,
+    Vec,
+};
+
+/// Runs `emit` as the contract, then checks the last published event's topic
+/// header and ABI entry and decodes its data into `T`.
+fn round_trip<T>(test: &PredictifyTest, emit: impl FnOnce()) -> (Vec<Val>, T)
+where
+    T: TryFromVal<Env, Val>,
+    T::Error: Debug,
+{
+    test.env.as_contract(&test.contract_id, emit);
+
+    let (contract, topics, data) = test.env.events().all().last().unwrap();
+    assert_eq!(contract, test.contract_id);
+    let topic: Symbol = topics.get(0).unwrap().try_into_val(&test.env).unwrap();
+    let version: u32 = topics.get(1).unwrap().try_into_val(&test.env).unwrap();
+    assert_eq!(version, EVENT_SCHEMA_VERSION);
+
+    let abi = EventDocumentation::get_events_abi(&test.env);
+    assert!(
+        abi.iter()
+            .any(|entry| entry.topic == topic && entry.ids.len() + 2 == topics.len()),
+        "{:?} has no matching ABI entry",
+        topic
+    );
+
+    (topics, data.try_into_val(&test.env).unwrap())
+}
+
+/// Asserts the last published event decodes to `expected`.
+fn assert_round_trip<T>(test: &PredictifyTest, emit: impl FnOnce(), expected: T)
+where
+    T: TryFromVal<Env, Val> + Debug + PartialEq,
+    T::Error: Debug,
+{
+    let (_, decoded): (Vec<Val>, T) = round_trip(test, emit);
+    assert_eq!(decoded, expected);
+}
+
+#[test]
+fn test_version_info_exposes_event_schema_version() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let info = client.get_version_info();
+    assert_eq!(info.event_schema_version, EVENT_SCHEMA_VERSION);
+    assert_eq!(info.contract_version, client.get_version());
+}
+
+#[test]
+fn test_events_abi_has_no_duplicate_layouts() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let abi = client.get_events_abi();
+    for (i, entry) in abi.iter().enumerate() {
+        for other in abi.iter().skip(i + 1) {
+            assert!(entry.topic != other.topic || entry.ids != other.ids);
+        }
+    }
+    let bet = abi
+        .iter()
+        .find(|e| e.topic == symbol_short!("bet"))
+        .unwrap();
+    assert_eq!(
+        bet.ids,
+        vec![
+            &test.env,
+            Symbol::new(&test.env, "market_id"),
+            symbol_short!("bettor")
+        ]
+    );
+    assert_eq!(bet.data, Symbol::new(&test.env, "BetPlacedEvent"));
+}
+
+#[test]
+fn test_market_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let market_id = test.market_id.clone();
+    let user = Address::generate(env);
+    let yes = String::from_str(env, "yes");
+    let ts = env.ledger().timestamp();
+
+    let (topics, event): (Vec<Val>, BetPlacedEvent) = round_trip(&test, || {
+        EventEmitter::emit_bet_placed(env, &market_id, &user, &yes, 10, 30, 90)
+    });
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("bet"),
+            EVENT_SCHEMA_VERSION,
+            market_id.clone(),
+            user.clone()
+        )
+            .into_val(env)
+    );
+    assert_eq!(
+        event,
+        BetPlacedEvent {
+            market_id: market_id.clone(),
+            bettor: user.clone(),
+            outcome: yes.clone(),
+            amount: 10,
+            total_stake: 30,
+            pool_total: 90,
+            timestamp: ts,
+        }
+    );
+
+    assert_round_trip(
+        &test,
+        || {
+            EventEmitter::emit_resolved(
+                env,
+                &market_id,
+                &vec![env, yes.clone()],
+                ResolutionSource::Manual,
+            )
+        },
+        ResolvedEvent {
+            market_id: market_id.clone(),
+            winning_outcomes: vec![env, yes.clone()],
+            source: ResolutionSource::Manual,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_payout(env, &market_id, &user, 5),
+        PayoutEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount: 5,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_refund_mode(env, &market_id, 7),
+        RefundModeEvent {
+            market_id: market_id.clone(),
+            total_refundable: 7,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_cancelled(env, &market_id, &yes),
+        CancelEvent {
+            market_id: market_id.clone(),
+            reason: yes.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_claim_result(env, &market_id, &user, 0, 3),
+        ClaimResultEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount: 0,
+            error_code: 3,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_max_pool_size_set(env, &market_id, &user, Some(100)),
+        MaxPoolSizeSetEvent {
+            market_id: market_id.clone(),
+            creator: user.clone(),
+            max_pool_size: Some(100),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_user_bet_cap_updated(env, &user, &market_id, None),
+        UserBetCapUpdatedEvent {
+            scope: market_id.clone(),
+            caller: user.clone(),
+            max_bet_per_user: None,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_market_type_set(env, &market_id, &user, MarketType::Categorical),
+        MarketTypeSetEvent {
+            market_id: market_id.clone(),
+            creator: user.clone(),
+            market_type: MarketType::Categorical,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_keeper_paid(env, &market_id, &user, 11),
+        KeeperPaidEvent {
+            market_id: market_id.clone(),
+            keeper: user.clone(),
+            amount: 11,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_creator_fee_set(env, &market_id, &user, 150),
+        CreatorFeeSetEvent {
+            market_id: market_id.clone(),
+            creator: user.clone(),
+            bps: 150,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_market_oracles_updated(env, &market_id, &user, 2),
+        MarketOraclesUpdatedEvent {
+            market_id: market_id.clone(),
+            caller: user.clone(),
+            oracle_count: 2,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_resolve_mode_set(env, &market_id, &user, ResolveMode::Hybrid),
+        ResolveModeSetEvent {
+            market_id: market_id.clone(),
+            creator: user.clone(),
+            mode: ResolveMode::Hybrid,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_template_set(env, 4, &user),
+        TemplateSetEvent {
+            template_id: 4,
+            admin: user.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_series_rolled(env, 2, 6, &market_id),
+        SeriesRolledEvent {
+            series_id: 2,
+            index: 6,
+            market_id: market_id.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || {
+            MarketStateLogic::emit_state_change_event(
+                env,
+                &market_id,
+                MarketState::Active,
+                MarketState::Ended,
+            )
+        },
+        MarketStateChangedEvent {
+            market_id: market_id.clone(),
+            old_state: MarketState::Active,
+            new_state: MarketState::Ended,
+        },
+    );
+}
+
+#[test]
+fn test_community_vote_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let market_id = test.market_id.clone();
+    let voter = Address::generate(env);
+    let yes = String::from_str(env, "yes");
+    let ts = env.ledger().timestamp();
+
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_community_vote_opened(env, &market_id, &voter, ts + 60),
+        CommunityVoteOpenedEvent {
+            market_id: market_id.clone(),
+            opened_by: voter.clone(),
+            ends_at: ts + 60,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_community_vote_cast(env, &market_id, &voter, &yes, 40),
+        CommunityVoteCastEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            outcome: yes.clone(),
+            weight: 40,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_community_vote_finalized(env, &market_id, &Some(yes.clone()), 40),
+        CommunityVoteFinalizedEvent {
+            market_id: market_id.clone(),
+            outcome: Some(yes.clone()),
+            total_weight: 40,
+            timestamp: ts,
+        },
+    );
+}
+
+#[test]
+fn test_fee_and_balance_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let market_id = test.market_id.clone();
+    let admin = test.admin.clone();
+    let user = Address::generate(env);
+    let ts = env.ledger().timestamp();
+    let fee_type = String::from_str(env, "platform_fee");
+
+    let (topics, event): (Vec<Val>, FeeCollectedEvent) = round_trip(&test, || {
+        EventEmitter::emit_fee_collected(env, &market_id, &admin, 20, &fee_type)
+    });
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("fees"),
+            EVENT_SCHEMA_VERSION,
+            symbol_short!("collect"),
+            market_id.clone()
+        )
+            .into_val(env)
+    );
+    assert_eq!(
+        event,
+        FeeCollectedEvent {
+            market_id: market_id.clone(),
+            collector: admin.clone(),
+            amount: 20,
+            fee_type: fee_type.clone(),
+            timestamp: ts,
+        }
+    );
+
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_fee_withdrawn(env, &admin, 8, 12, ts),
+        FeeWithdrawnEvent {
+            admin: admin.clone(),
+            amount: 8,
+            remaining_fees: 12,
+            timestamp: ts,
+        },
+    );
+    let schedule = FeeWithdrawalSchedule {
+        timelock_seconds: 60,
+        max_withdrawal_bps: 5_000,
+    };
+    assert_round_trip(
+        &test,
+        || {
+            EventEmitter::emit_fee_withdrawal_attempt(
+                env,
+                &admin,
+                10,
+                20,
+                10,
+                FeeWithdrawalStatus::Executed,
+                0,
+                ts + 60,
+                &schedule,
+            )
+        },
+        FeeWithdrawalAttemptEvent {
+            admin: admin.clone(),
+            requested_amount: 10,
+            available_fees: 20,
+            withdrawal_amount: 10,
+            status: FeeWithdrawalStatus::Executed,
+            last_withdrawal_ts: 0,
+            next_allowed_ts: ts + 60,
+            timelock_seconds: 60,
+            max_withdrawal_bps: 5_000,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_creator_fees_withdrawn(env, &user, 3),
+        CreatorFeesWithdrawnEvent {
+            creator: user.clone(),
+            amount: 3,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_referrer_set(env, &user, &admin),
+        ReferrerSetEvent {
+            user: user.clone(),
+            referrer: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_referral_earnings_withdrawn(env, &user, 4),
+        ReferralEarningsWithdrawnEvent {
+            referrer: user.clone(),
+            amount: 4,
+            timestamp: ts,
+        },
+    );
+    let operation = String::from_str(env, "deposit");
+    assert_round_trip(
+        &test,
+        || {
+            EventEmitter::emit_balance_changed(
+                env,
+                &user,
+                &ReflectorAsset::Stellar,
+                &operation,
+                50,
+                50,
+            )
+        },
+        BalanceChangedEvent {
+            user: user.clone(),
+            asset: ReflectorAsset::Stellar,
+            operation: operation.clone(),
+            amount: 50,
+            new_balance: 50,
+            timestamp: ts,
+        },
+    );
+}
+
+#[test]
+fn test_admin_and_config_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let market_id = test.market_id.clone();
+    let admin = test.admin.clone();
+    let token = Address::generate(env);
+    let ts = env.ledger().timestamp();
+
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_contract_initialized(env, &admin, 2),
+        ContractInitializedEvent {
+            admin: admin.clone(),
+            platform_fee_percentage: 2,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_platform_fee_set(env, 3, &admin),
+        PlatformFeeSetEvent {
+            fee_percentage: 3,
+            set_by: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_token_updated(env, &admin, &None, &token),
+        TokenUpdatedEvent {
+            admin: admin.clone(),
+            previous_token: None,
+            token: token.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_fee_bps_updated(env, &admin, 200, 250),
+        FeeBpsUpdatedEvent {
+            admin: admin.clone(),
+            previous_bps: 200,
+            new_bps: 250,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_creation_fee_updated(env, &admin, 1, 2),
+        CreationFeeUpdatedEvent {
+            admin: admin.clone(),
+            previous_fee: 1,
+            new_fee: 2,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_market_token_set(env, &market_id, &admin, &token),
+        MarketTokenSetEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            token: token.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || {
+            EventEmitter::emit_event_visibility_set(
+                env,
+                &market_id,
+                &EventVisibility::Private,
+                &admin,
+            )
+        },
+        EventVisibilitySetEvent {
+            event_id: market_id.clone(),
+            visibility: EventVisibility::Private,
+            admin: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_market_creator_updated(env, &token, true, &admin),
+        MarketCreatorUpdatedEvent {
+            creator: token.clone(),
+            allowed: true,
+            admin: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    let addresses = vec![env, token.clone()];
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_allowlist_updated(env, &market_id, &addresses, &admin),
+        AllowlistUpdatedEvent {
+            event_id: market_id.clone(),
+            addresses: addresses.clone(),
+            admin: admin.clone(),
+            timestamp: ts,
+        },
+    );
+}
+
+#[test]
+fn test_upgrade_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let admin = test.admin.clone();
+    let hash = BytesN::from_array(env, &[7; 32]);
+    let ts = env.ledger().timestamp();
+    let upgrade = TimelockedAction::Upgrade(hash.clone());
+    let pending = PendingAction {
+        id: 3,
+        action: upgrade.clone(),
+        scheduled_by: admin.clone(),
+        scheduled_at: ts,
+        execute_after: ts + 100,
+    };
+
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_action_scheduled(env, &admin, &pending),
+        ActionScheduledEvent {
+            admin: admin.clone(),
+            action: upgrade.clone(),
+            execute_after: ts + 100,
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_action_cancelled(env, &admin, 3, &upgrade),
+        ActionCancelledEvent {
+            admin: admin.clone(),
+            action: upgrade.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_action_executed(env, &admin, 3, &upgrade),
+        ActionExecutedEvent {
+            scheduled_by: admin.clone(),
+            action: upgrade.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_upgrade_executed(env, &admin, &hash, 1),
+        UpgradeExecutedEvent {
+            admin: admin.clone(),
+            new_wasm_hash: hash.clone(),
+            previous_version: 1,
+            timestamp: ts,
+        },
+    );
+}
+
+#[test]
+fn test_pause_and_resume_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let market_id = test.create_test_market();
+    let admin = test.admin.clone();
+    let ts = env.ledger().timestamp();
+
+    assert_round_trip(
+        &test,
+        || MarketPauseManager::pause_market(env, admin.clone(), &market_id, 24).unwrap(),
+        MarketPausedEvent {
+            market_id: market_id.clone(),
+            duration_hours: 24,
+            admin: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || MarketPauseManager::resume_market(env, admin.clone(), &market_id).unwrap(),
+        MarketResumedEvent {
+            market_id: market_id.clone(),
+            admin: Some(admin.clone()),
+            timestamp: ts,
+        },
+    );
+}
+
+#[test]
+fn test_oracle_whitelist_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let admin = Address::generate(env);
+    let other_admin = Address::generate(env);
+    let oracle = Address::generate(env);
+    let ts = env.ledger().timestamp();
+
+    assert_round_trip(
+        &test,
+        || OracleWhitelist::initialize(env, admin.clone()).unwrap(),
+        WhitelistAdminEvent {
+            admin: admin.clone(),
+            authorized_by: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || OracleWhitelist::add_admin(env, admin.clone(), other_admin.clone()).unwrap(),
+        WhitelistAdminEvent {
+            admin: other_admin.clone(),
+            authorized_by: admin.clone(),
+            timestamp: ts,
+        },
+    );
+    assert_round_trip(
+        &test,
+        || OracleWhitelist::remove_admin(env, admin.clone(), other_admin.clone()).unwrap(),
+        WhitelistAdminEvent {
+            admin: other_admin.clone(),
+            authorized_by: admin.clone(),
+            timestamp: ts,
+        },
+    );
+
+    let metadata = OracleMetadata {
+        provider: OracleProvider::Reflector,
+        contract_address: oracle.clone(),
+        added_at: ts,
+        added_by: admin.clone(),
+        last_health_check: ts,
+        is_active: true,
+        description: String::from_str(env, "Reflector"),
+    };
+    assert_round_trip(
+        &test,
+        || {
+            OracleWhitelist::add_oracle_to_whitelist(env, admin.clone(), oracle.clone(), metadata)
+                .unwrap()
+        },
+        OracleWhitelistedEvent {
+            oracle: oracle.clone(),
+            provider: OracleProvider::Reflector,
+            timestamp: ts,
+        },
+    );
+    let status = OracleStatusEvent {
+        oracle: oracle.clone(),
+        admin: admin.clone(),
+        timestamp: ts,
+    };
+    assert_round_trip(
+        &test,
+        || OracleWhitelist::deactivate_oracle(env, admin.clone(), oracle.clone()).unwrap(),
+        status.clone(),
+    );
+    assert_round_trip(
+        &test,
+        || OracleWhitelist::reactivate_oracle(env, admin.clone(), oracle.clone()).unwrap(),
+        status.clone(),
+    );
+    assert_round_trip(
+        &test,
+        || {
+            OracleWhitelist::remove_oracle_from_whitelist(env, admin.clone(), oracle.clone())
+                .unwrap()
+        },
+        status,
+    );
+}
+
+#[test]
+fn test_gas_monitoring_and_recovery_event_round_trips() {
+    let test = PredictifyTest::setup();
+    let env = &test.env;
+    let market_id = test.market_id.clone();
+    let ts = env.ledger().timestamp();
+
+    assert_round_trip(
+        &test,
+        || {
+            let marker = GasTracker::start_tracking(env);
+            GasTracker::end_tracking(env, symbol_short!("test_op"), marker);
+        },
+        GasUsedEvent {
+            operation: symbol_short!("test_op"),
+            cost: 0,
+        },
+    );
+
+    let alert = MonitoringTestingUtils::create_test_monitoring_alert(env);
+    assert_round_trip(
+        &test,
+        || ContractMonitor::emit_monitoring_alert(env, alert.clone()).unwrap(),
+        alert.clone(),
+    );
+
+    let action = String::from_str(env, "partial_refund");
+    let status = String::from_str(env, "done");
+    assert_round_trip(
+        &test,
+        || EventEmitter::emit_recovery_event(env, &market_id, &action, &status),
+        RecoveryEvent {
+            market_id: market_id.clone(),
+            action: action.clone(),
+            status: status.clone(),
+            timestamp: ts,
+        },
+    );
+}
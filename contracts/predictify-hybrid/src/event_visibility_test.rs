@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod event_visibility_tests {
+    use crate::storage::DataKey;
     use crate::types::{EventVisibility, OracleConfig, OracleProvider};
     use crate::{PredictifyHybrid, PredictifyHybridClient};
     use soroban_sdk::testutils::{Address as _, Ledger};
@@ -30,8 +31,8 @@ mod event_visibility_tests {
         // Configure token used for fees and staking
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         // Fund admin with tokens so creation fees can be paid
@@ -185,7 +186,6 @@ mod event_visibility_tests {
     }
 
     #[test]
-    #[should_panic]
     fn test_private_event_blocks_non_allowlisted_user() {
         let (env, contract_id, admin, user1, user2) = setup_test_env();
         let client = PredictifyHybridClient::new(&env, &contract_id);
@@ -207,12 +207,15 @@ mod event_visibility_tests {
         let addresses = Vec::from_array(&env, [user1.clone()]);
         client.add_to_allowlist(&admin, &event_id, &addresses);
 
-        // Try to bet with user2 (not on allowlist) - should panic
-        client.place_bet(
-            &user2,
-            &event_id,
-            &String::from_str(&env, "yes"),
-            &1_000_000,
-        );
+        // Try to bet with user2 (not on allowlist) - should be rejected
+        assert!(client
+            .try_place_bet(
+                &user2,
+                &event_id,
+                &String::from_str(&env, "yes"),
+                &1_000_000,
+                &None,
+            )
+            .is_err());
     }
 }
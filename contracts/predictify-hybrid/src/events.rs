@@ -3,6 +3,7 @@ extern crate alloc;
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
 
+use crate::admin::Role;
 use crate::config::Environment;
 use crate::errors::Error;
 use crate::types::OracleProvider;
@@ -59,6 +60,8 @@ pub enum AdminRole {
 ///         String::from_str(&env, "No")
 ///     ],
 ///     admin: admin.clone(),
+///     creator: admin.clone(),
+///     creation_fee: 0,
 ///     end_time: 1735689600, // Dec 31, 2024
 ///     timestamp: env.ledger().timestamp(),
 /// };
@@ -96,6 +99,10 @@ pub struct MarketCreatedEvent {
     pub outcomes: Vec<String>,
     /// Market admin
     pub admin: Address,
+    /// Address that created the market
+    pub creator: Address,
+    /// Creation fee the creator paid (0 for the admin)
+    pub creation_fee: i128,
     /// Market end time
     pub end_time: u64,
     /// Creation timestamp
@@ -234,6 +241,8 @@ pub struct VoteCastEvent {
 ///     bettor: bettor.clone(),
 ///     outcome: String::from_str(&env, "Yes"),
 ///     amount: 10_000_000, // 1.0 XLM locked
+///     total_stake: 10_000_000, // first bet on this market
+///     pool_total: 50_000_000,
 ///     timestamp: env.ledger().timestamp(),
 /// };
 ///
@@ -280,10 +289,76 @@ pub struct BetPlacedEvent {
     pub outcome: String,
     /// Amount locked
     pub amount: i128,
+    /// Cumulative stake of the bettor's position after this bet
+    pub total_stake: i128,
+    /// Market's total staked amount after this bet
+    pub pool_total: i128,
     /// Bet placement timestamp
     pub timestamp: u64,
 }
 
+/// Event emitted when a bettor cashes out a position before resolution.
+///
+/// Published as topics `("cash_out", v, market_id, bettor)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CashedOutEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Bettor address
+    pub bettor: Address,
+    /// Outcome of the cashed-out position
+    pub outcome: String,
+    /// Stake of the position
+    pub stake: i128,
+    /// Amount paid to the bettor
+    pub paid: i128,
+    /// Cash-out fee credited to the fee vault
+    pub fee: i128,
+    /// Cash-out timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a bettor moves their position to another address.
+///
+/// Published as topics `("pos_xfer", v, market_id, from)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionTransferredEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Previous holder of the position
+    pub from: Address,
+    /// New holder of the position
+    pub to: Address,
+    /// Outcome of the position
+    pub outcome: String,
+    /// Stake moved
+    pub amount: i128,
+    /// Recipient's stake after merging into any position they already held
+    pub position_amount: i128,
+    /// Transfer timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a bettor withdraws a position before the betting deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetWithdrawnEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Bettor address
+    pub bettor: Address,
+    /// Outcome of the withdrawn position
+    pub outcome: String,
+    /// Amount refunded to the bettor
+    pub refunded: i128,
+    /// Early-exit penalty kept in the fee vault
+    pub penalty: i128,
+    /// Withdrawal timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a bet's status is updated (won, lost, refunded).
 ///
 /// This event tracks bet resolution status changes, providing transparency
@@ -575,6 +650,22 @@ pub struct DisputeCreatedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a resolved market's result is disputed with a stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultDisputedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Disputer address
+    pub disputer: Address,
+    /// Outcome the disputer claims is correct
+    pub proposed_outcome: String,
+    /// Dispute stake
+    pub stake: i128,
+    /// Dispute timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a dispute is successfully resolved with final outcome and rewards.
 ///
 /// This event captures the complete dispute resolution process, including the final
@@ -1095,6 +1186,52 @@ pub struct MarketClosedEvent {
     pub timestamp: u64,
 }
 
+/// Market closed ahead of its scheduled end, emitted by `close_market_early`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketClosedEarlyEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who closed it
+    pub admin: Address,
+    /// Why the outcome is already known
+    pub reason: String,
+    /// End time the market was scheduled for
+    pub original_end_time: u64,
+    /// Close timestamp, now the market's end time
+    pub timestamp: u64,
+}
+
+/// Market frozen or unfrozen event, emitted by `freeze_market` / `unfreeze_market`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFrozenEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who changed the flag
+    pub admin: Address,
+    /// New value of the frozen flag
+    pub frozen: bool,
+    /// Change timestamp
+    pub timestamp: u64,
+}
+
+/// Market cancelled event, emitted by `cancel_event`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketCancelledEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who cancelled it
+    pub admin: Address,
+    /// Cancellation reason
+    pub reason: String,
+    /// Total stake owed back to bettors (returned by `process_refunds`)
+    pub total_refundable: i128,
+    /// Cancellation timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a market is refunded due to oracle resolution failure or timeout.
 ///
 /// Emitted after all bets are refunded in full (no fee deduction). The market is marked
@@ -1143,6 +1280,24 @@ pub struct AdminTransferredEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when the admin proposes a successor (two-step transfer).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposedEvent {
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when a pending admin proposal is withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposalCancelledEvent {
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+    pub timestamp: u64,
+}
+
 /// Event emitted when the contract is paused by admin.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1159,6 +1314,26 @@ pub struct ContractUnpausedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when an operational role is granted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrantedEvent {
+    pub role: Role,
+    pub account: Address,
+    pub granted_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event emitted when an operational role is revoked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevokedEvent {
+    pub role: Role,
+    pub account: Address,
+    pub revoked_by: Address,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractInitializedEvent {
@@ -1280,6 +1455,20 @@ pub struct FallbackUsedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when neither the primary nor the fallback oracle could resolve a market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleResolutionFailedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Primary oracle address
+    pub primary_oracle: Address,
+    /// Fallback oracle address, if one was configured
+    pub fallback_oracle: Option<Address>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a market resolution timeout is reached.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1487,6 +1676,18 @@ pub struct ClaimPeriodUpdatedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when the default dispute window is updated.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeWindowUpdatedEvent {
+    /// Admin who updated the dispute window
+    pub admin: Address,
+    /// New default dispute window in seconds
+    pub dispute_window_seconds: u64,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when market-specific claim period is updated.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1513,6 +1714,74 @@ pub struct TreasuryUpdatedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when the staking token is configured or switched.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenUpdatedEvent {
+    /// Admin who updated the token
+    pub admin: Address,
+    /// Previously configured token (None on first configuration)
+    pub previous_token: Option<Address>,
+    /// New staking token
+    pub token: Address,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when the pool-size fee tiers change.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTiersUpdatedEvent {
+    /// Admin who updated the tiers
+    pub admin: Address,
+    /// New `(pool breakpoint, bps)` schedule; empty when cleared
+    pub tiers: Vec<(i128, u32)>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when the global platform fee (basis points) changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBpsUpdatedEvent {
+    /// Admin who updated the fee
+    pub admin: Address,
+    /// Previous fee in basis points
+    pub previous_bps: u32,
+    /// New fee in basis points
+    pub new_bps: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when the market creation fee changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationFeeUpdatedEvent {
+    /// Admin who updated the fee
+    pub admin: Address,
+    /// Previous fee
+    pub previous_fee: i128,
+    /// New fee
+    pub new_fee: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a market is assigned its own staking token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketTokenSetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Admin who set the token
+    pub admin: Address,
+    /// Staking token for the market
+    pub token: Address,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when unclaimed winnings are swept after timeout.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1689,6 +1958,22 @@ pub struct TagsUpdatedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a market's display metadata is set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketMetadataUpdatedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Previous category (None if metadata was not set)
+    pub old_category: Option<Symbol>,
+    /// New category
+    pub new_category: Symbol,
+    /// Admin who updated
+    pub admin: Address,
+    /// Update timestamp
+    pub timestamp: u64,
+}
+
 /// Contract rollback event - emitted when contract is rolled back to previous version
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1715,6 +2000,46 @@ pub struct UpgradeProposalCreatedEvent {
     pub timestamp: u64,
 }
 
+/// Action scheduled event - emitted when the admin schedules a timelocked action
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionScheduledEvent {
+    /// Admin who scheduled the action
+    pub admin: Address,
+    /// Operation to apply
+    pub action: crate::types::TimelockedAction,
+    /// Earliest time the action may be executed
+    pub execute_after: u64,
+    /// Scheduling timestamp
+    pub timestamp: u64,
+}
+
+/// Action executed event - emitted when a timelocked action takes effect
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionExecutedEvent {
+    /// Admin who scheduled the action
+    pub scheduled_by: Address,
+    /// Operation applied
+    pub action: crate::types::TimelockedAction,
+    /// Execution timestamp
+    pub timestamp: u64,
+}
+
+/// Upgrade executed event - emitted just before the contract Wasm is replaced
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeExecutedEvent {
+    /// Admin who executed the upgrade
+    pub admin: Address,
+    /// Hash of the Wasm now running
+    pub new_wasm_hash: soroban_sdk::BytesN<32>,
+    /// `get_version` of the code being replaced
+    pub previous_version: u32,
+    /// Execution timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when circuit breaker state changes
 ///
 /// This event provides comprehensive information about circuit breaker
@@ -1796,1145 +2121,2545 @@ pub struct MinPoolSizeNotMetEvent {
     pub timestamp: u64,
 }
 
-// ===== EVENT EMISSION UTILITIES =====
+/// How a market's winning outcome was decided, published with the `resolved` event.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResolutionSource {
+    /// Oracle price (primary or fallback), possibly combined with community votes
+    Oracle,
+    /// Set by an admin or `Resolver`
+    Manual,
+    /// Overturned by an upheld result dispute
+    Dispute,
+    /// Settled by a stake-weighted vote of bettors after every oracle failed
+    Community,
+    /// Proposed with a bond and left unchallenged, or settled by arbitration
+    /// of the challenge
+    Proposal,
+}
 
-/// Event emission utilities
-pub struct EventEmitter;
+// ===== PUBLISHED EVENT DATA =====
+//
+// Every event published to `env.events()` uses the topic layout
+// `(topic, EVENT_SCHEMA_VERSION, ...ids)` and one of the structs below (or
+// another `*Event` struct in this module) as its data.
 
-impl EventEmitter {
-    /// Emit market created event
-    pub fn emit_market_created(
-        env: &Env,
-        market_id: &Symbol,
-        question: &String,
-        outcomes: &Vec<String>,
-        admin: &Address,
-        end_time: u64,
-    ) {
-        let event = MarketCreatedEvent {
-            market_id: market_id.clone(),
-            question: question.clone(),
-            outcomes: outcomes.clone(),
-            admin: admin.clone(),
-            end_time,
-            timestamp: env.ledger().timestamp(),
-        };
+/// Data for `("pool_cap", v, market_id)`; `max_pool_size` is `None` when cleared.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxPoolSizeSetEvent {
+    pub market_id: Symbol,
+    pub creator: Address,
+    pub max_pool_size: Option<i128>,
+    pub timestamp: u64,
+}
 
-        Self::store_event(env, &symbol_short!("mkt_crt"), &event);
-    }
+/// Data for `("snipe_cfg", v, market_id)`; when cleared, `enabled` is false
+/// and `config` is all zeroes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AntiSnipeSetEvent {
+    pub market_id: Symbol,
+    pub creator: Address,
+    pub enabled: bool,
+    pub config: crate::types::AntiSnipeConfig,
+    pub timestamp: u64,
+}
 
-    /// Emit fallback used event
-    pub fn emit_fallback_used(
-        env: &Env,
-        market_id: &Symbol,
-        primary_oracle: &Address,
-        fallback_oracle: &Address,
-    ) {
-        let event = FallbackUsedEvent {
-            market_id: market_id.clone(),
-            primary_oracle: primary_oracle.clone(),
-            fallback_oracle: fallback_oracle.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
+/// Data for `("snipe_ext", v, market_id, bettor)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetWindowExtendedEvent {
+    pub market_id: Symbol,
+    pub bettor: Address,
+    pub amount: i128,
+    pub new_deadline: u64,
+    pub total_extension_seconds: u64,
+    pub timestamp: u64,
+}
 
-        Self::store_event(env, &symbol_short!("fbk_used"), &event);
-    }
+/// Data for `("user_cap", v, scope)`; `max_bet_per_user` is `None` when cleared.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserBetCapUpdatedEvent {
+    pub scope: Symbol,
+    pub caller: Address,
+    pub max_bet_per_user: Option<i128>,
+    pub timestamp: u64,
+}
 
-    /// Emit resolution timeout event
-    pub fn emit_resolution_timeout(env: &Env, market_id: &Symbol, timeout_timestamp: u64) {
-        let event = ResolutionTimeoutEvent {
-            market_id: market_id.clone(),
-            timeout_timestamp,
-        };
+/// Data for `("claim_res", v, market_id, user)`, one per market in a `claim_all` batch.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimResultEvent {
+    pub market_id: Symbol,
+    pub user: Address,
+    pub amount: i128,
+    /// 0 when the claim went through, otherwise the `Error` code
+    pub error_code: u32,
+}
 
-        Self::store_event(env, &symbol_short!("res_tmo"), &event);
-    }
+/// Data for `("mkt_type", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketTypeSetEvent {
+    pub market_id: Symbol,
+    pub creator: Address,
+    pub market_type: crate::types::MarketType,
+    pub timestamp: u64,
+}
+
+/// Data for `("mkt_acces", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketAccessUpdatedEvent {
+    pub market_id: Symbol,
+    pub updated_by: Address,
+    pub access: crate::types::MarketAccess,
+    pub added: Vec<Address>,
+    pub removed: Vec<Address>,
+    pub timestamp: u64,
+}
+
+/// Data for `("blacklist", v, address)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressBlacklistedEvent {
+    pub address: Address,
+    pub blocked: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("blk_rel", v, market_id, address)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockedFundsReleasedEvent {
+    pub market_id: Symbol,
+    pub address: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("tok_recov", v, token)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenRecoveredEvent {
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("crd_grant", v, user)` and `("crd_recl", v, user)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetCreditEvent {
+    pub user: Address,
+    pub admin: Address,
+    /// Credit granted, or reclaimed
+    pub amount: i128,
+    /// Credit left to the user
+    pub balance: i128,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Data for `("keep_paid", v, market_id, keeper)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperPaidEvent {
+    pub market_id: Symbol,
+    pub keeper: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("crtr_fee", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeeSetEvent {
+    pub market_id: Symbol,
+    pub creator: Address,
+    pub bps: u32,
+    pub timestamp: u64,
+}
+
+/// Data for `("mkt_fee", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFeeSetEvent {
+    pub market_id: Symbol,
+    pub admin: Address,
+    pub previous_bps: u32,
+    pub new_bps: u32,
+    pub timestamp: u64,
+}
+
+/// Data for `("mkt_orcl", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketOraclesUpdatedEvent {
+    pub market_id: Symbol,
+    pub caller: Address,
+    pub oracle_count: u32,
+    pub timestamp: u64,
+}
+
+/// Data for `("cv_open", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommunityVoteOpenedEvent {
+    pub market_id: Symbol,
+    pub opened_by: Address,
+    pub ends_at: u64,
+    pub timestamp: u64,
+}
+
+/// Data for `("cv_vote", v, market_id, voter)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommunityVoteCastEvent {
+    pub market_id: Symbol,
+    pub voter: Address,
+    pub outcome: String,
+    pub weight: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("cv_final", v, market_id)`; `outcome` is `None` when the vote was voided.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommunityVoteFinalizedEvent {
+    pub market_id: Symbol,
+    pub outcome: Option<String>,
+    pub total_weight: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("tmpl_set", v, template_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateSetEvent {
+    pub template_id: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("series", v, series_id)`, once per market a series creates.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeriesRolledEvent {
+    pub series_id: u32,
+    pub index: u32,
+    pub market_id: Symbol,
+    pub timestamp: u64,
+}
+
+/// Data for `("rslv_mode", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolveModeSetEvent {
+    pub market_id: Symbol,
+    pub creator: Address,
+    pub mode: crate::types::ResolveMode,
+    pub timestamp: u64,
+}
+
+/// Data for `("rslt_prop", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultProposedEvent {
+    pub market_id: Symbol,
+    pub proposer: Address,
+    pub outcome: String,
+    pub bond: i128,
+    pub challenge_deadline: u64,
+    pub timestamp: u64,
+}
+
+/// Data for `("rslt_chal", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultChallengedEvent {
+    pub market_id: Symbol,
+    pub challenger: Address,
+    pub bond: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("crt_bond", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationBondReleasedEvent {
+    pub market_id: Symbol,
+    pub creator: Address,
+    pub amount: i128,
+    /// Whether the bond went to the fee vault instead of back to the creator
+    pub slashed: bool,
+    pub timestamp: u64,
+}
+
+/// Data for `("crtr_wd", v, creator)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorFeesWithdrawnEvent {
+    pub creator: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("ref_set", v, user)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferrerSetEvent {
+    pub user: Address,
+    pub referrer: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("ref_wd", v, referrer)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralEarningsWithdrawnEvent {
+    pub referrer: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("msig_prop", v, op_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigOpProposedEvent {
+    pub proposer: Address,
+    pub op: crate::types::MultisigOp,
+    pub timestamp: u64,
+}
+
+/// Data for `("msig_appr", v, op_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigOpApprovedEvent {
+    pub signer: Address,
+    /// Approvals collected so far, the proposer's included
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+/// Data for `("msig_exec", v, op_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigOpExecutedEvent {
+    /// Signer whose approval reached the threshold
+    pub executor: Address,
+    pub op: crate::types::MultisigOp,
+    pub timestamp: u64,
+}
+
+/// Data for `("act_cncl", v, action_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionCancelledEvent {
+    pub admin: Address,
+    pub action: crate::types::TimelockedAction,
+    pub timestamp: u64,
+}
+
+/// Data for `("bal_chg", v, user, asset)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceChangedEvent {
+    pub user: Address,
+    pub asset: crate::types::ReflectorAsset,
+    pub operation: String,
+    pub amount: i128,
+    pub new_balance: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("resolved", v, market_id)`, published once per resolution
+/// including when an upheld dispute replaces the winning outcome.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedEvent {
+    pub market_id: Symbol,
+    pub winning_outcomes: Vec<String>,
+    pub source: ResolutionSource,
+    pub timestamp: u64,
+}
+
+/// Data for `("payout", v, market_id, user)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutEvent {
+    pub market_id: Symbol,
+    pub user: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("refund", v, market_id)` when a market resolves to outcome(s)
+/// nobody backed and converts to refund mode.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundModeEvent {
+    pub market_id: Symbol,
+    pub total_refundable: i128,
+    pub timestamp: u64,
+}
+
+/// Data for `("cancel", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelEvent {
+    pub market_id: Symbol,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+/// Data for `("evt_vis", v, event_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventVisibilitySetEvent {
+    pub event_id: Symbol,
+    pub visibility: crate::types::EventVisibility,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("mkt_crtr", v, creator)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketCreatorUpdatedEvent {
+    pub creator: Address,
+    pub allowed: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("allowlst", v, event_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistUpdatedEvent {
+    pub event_id: Symbol,
+    pub addresses: Vec<Address>,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("gas_used", v, operation)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GasUsedEvent {
+    pub operation: Symbol,
+    pub cost: u64,
+}
+
+/// Data for `("mkt_state", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketStateChangedEvent {
+    pub market_id: Symbol,
+    pub old_state: crate::types::MarketState,
+    pub new_state: crate::types::MarketState,
+}
+
+/// Data for `("mkt_pause", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketPausedEvent {
+    pub market_id: Symbol,
+    pub duration_hours: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("mkt_resum", v, market_id)`; `admin` is `None` when the pause expired.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketResumedEvent {
+    pub market_id: Symbol,
+    pub admin: Option<Address>,
+    pub timestamp: u64,
+}
+
+/// Data for the oracle whitelist admin events `("wl_init", v, admin)`,
+/// `("wl_add", v, admin)` and `("wl_remove", v, admin)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistAdminEvent {
+    pub admin: Address,
+    /// Existing admin who authorized the change; the admin itself on init
+    pub authorized_by: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("orcl_add", v, oracle)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleWhitelistedEvent {
+    pub oracle: Address,
+    pub provider: OracleProvider,
+    pub timestamp: u64,
+}
+
+/// Data for the oracle status events `("orcl_rm", v, oracle)`,
+/// `("orcl_off", v, oracle)` and `("orcl_on", v, oracle)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleStatusEvent {
+    pub oracle: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Data for `("orcl_hlth", v, oracle)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleHealthCheckedEvent {
+    pub oracle: Address,
+    pub healthy: bool,
+    pub timestamp: u64,
+}
+
+/// Data for `("recovery", v, market_id)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryEvent {
+    pub market_id: Symbol,
+    pub action: String,
+    pub status: String,
+    pub timestamp: u64,
+}
+
+// ===== EVENT EMISSION UTILITIES =====
+
+/// Event emission utilities
+pub struct EventEmitter;
+
+impl EventEmitter {
+    /// Emit market created event
+    pub fn emit_market_created(
+        env: &Env,
+        market_id: &Symbol,
+        market: &crate::types::Market,
+        creation_fee: i128,
+    ) {
+        let event = MarketCreatedEvent {
+            market_id: market_id.clone(),
+            question: market.question.clone(),
+            outcomes: market.outcomes.clone(),
+            admin: market.admin.clone(),
+            creator: market.creator.clone(),
+            creation_fee,
+            end_time: market.end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("mkt_crt"), &event);
+    }
+
+    /// Emit fallback used event
+    pub fn emit_fallback_used(
+        env: &Env,
+        market_id: &Symbol,
+        primary_oracle: &Address,
+        fallback_oracle: &Address,
+    ) {
+        let event = FallbackUsedEvent {
+            market_id: market_id.clone(),
+            primary_oracle: primary_oracle.clone(),
+            fallback_oracle: fallback_oracle.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("fbk_used"), &event);
+    }
+
+    /// Emit oracle resolution failed event
+    pub fn emit_oracle_resolution_failed(
+        env: &Env,
+        market_id: &Symbol,
+        primary_oracle: &Address,
+        fallback_oracle: &Option<Address>,
+    ) {
+        let event = OracleResolutionFailedEvent {
+            market_id: market_id.clone(),
+            primary_oracle: primary_oracle.clone(),
+            fallback_oracle: fallback_oracle.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_fail"), &event);
+    }
+
+    /// Emit resolution timeout event
+    pub fn emit_resolution_timeout(env: &Env, market_id: &Symbol, timeout_timestamp: u64) {
+        let event = ResolutionTimeoutEvent {
+            market_id: market_id.clone(),
+            timeout_timestamp,
+        };
+
+        Self::store_event(env, &symbol_short!("res_tmo"), &event);
+    }
 
     /// Emit event created event
     pub fn emit_event_created(
         env: &Env,
-        event_id: &Symbol,
-        description: &String,
-        outcomes: &Vec<String>,
+        event_id: &Symbol,
+        description: &String,
+        outcomes: &Vec<String>,
+        admin: &Address,
+        end_time: u64,
+    ) {
+        let event = EventCreatedEvent {
+            event_id: event_id.clone(),
+            description: description.clone(),
+            outcomes: outcomes.clone(),
+            creation_fee_amount: crate::fees::MARKET_CREATION_FEE,
+            admin: admin.clone(),
+            end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("evt_crt"), &event);
+    }
+
+    /// Emit vote cast event
+    pub fn emit_vote_cast(
+        env: &Env,
+        market_id: &Symbol,
+        voter: &Address,
+        outcome: &String,
+        stake: i128,
+    ) {
+        let event = VoteCastEvent {
+            market_id: market_id.clone(),
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("vote"), &event);
+    }
+
+    /// Emit statistics updated event
+    pub fn emit_statistics_updated(
+        env: &Env,
+        total_volume: i128,
+        total_bets: u64,
+        active_markets: u32,
+    ) {
+        let event = StatisticsUpdatedEvent {
+            total_volume,
+            total_bets,
+            active_markets,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("stats_upd"), &event);
+    }
+
+    /// Emit bet placed event when a user places a bet on a market
+    ///
+    /// This function emits an event when a user successfully places a bet,
+    /// locking their funds in the contract for the duration of the market.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market identifier
+    /// - `bettor` - Address of the user placing the bet
+    /// - `outcome` - The outcome the user is betting on
+    /// - `amount` - The amount of funds locked for this bet
+    /// - `total_stake` - The bettor's cumulative stake on the market after this bet
+    /// - `pool_total` - The market's total staked amount after this bet
+    ///
+    /// Published as topics `("bet", v, market_id, bettor)` with a
+    /// [`BetPlacedEvent`] as data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// EventEmitter::emit_bet_placed(
+    ///     &env,
+    ///     &market_id,
+    ///     &user_address,
+    ///     &String::from_str(&env, "Yes"),
+    ///     10_000_000, // 1.0 XLM
+    ///     30_000_000, // 3.0 XLM position after this bet
+    ///     90_000_000, // 9.0 XLM in the market pool
+    /// );
+    /// ```
+    pub fn emit_bet_placed(
+        env: &Env,
+        market_id: &Symbol,
+        bettor: &Address,
+        outcome: &String,
+        amount: i128,
+        total_stake: i128,
+        pool_total: i128,
+    ) {
+        let event = BetPlacedEvent {
+            market_id: market_id.clone(),
+            bettor: bettor.clone(),
+            outcome: outcome.clone(),
+            amount,
+            total_stake,
+            pool_total,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                symbol_short!("bet"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                bettor.clone(),
+            ),
+            event.clone(),
+        );
+
+        Self::store_event(env, &symbol_short!("bet_plc"), &event);
+    }
+
+    /// Emit bet status updated event when a bet's status changes
+    ///
+    /// This function emits an event when a bet's status is updated
+    /// (e.g., Active → Won, Active → Lost, Active → Refunded).
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market identifier
+    /// - `bettor` - Address of the bettor
+    /// - `old_status` - Previous bet status
+    /// - `new_status` - New bet status
+    /// - `payout_amount` - Optional payout amount (if bet won)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// EventEmitter::emit_bet_status_updated(
+    ///     &env,
+    ///     &market_id,
+    ///     &user_address,
+    ///     &String::from_str(&env, "Active"),
+    ///     &String::from_str(&env, "Won"),
+    ///     Some(15_000_000) // 1.5 XLM payout
+    /// );
+    /// ```
+    pub fn emit_bet_status_updated(
+        env: &Env,
+        market_id: &Symbol,
+        bettor: &Address,
+        old_status: &String,
+        new_status: &String,
+        payout_amount: Option<i128>,
+    ) {
+        let event = BetStatusUpdatedEvent {
+            market_id: market_id.clone(),
+            bettor: bettor.clone(),
+            old_status: old_status.clone(),
+            new_status: new_status.clone(),
+            payout_amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("bet_upd"), &event);
+    }
+
+    /// Emit cashed out event
+    pub fn emit_cashed_out(
+        env: &Env,
+        market_id: &Symbol,
+        bettor: &Address,
+        outcome: &String,
+        stake: i128,
+        paid: i128,
+        fee: i128,
+    ) {
+        let event = CashedOutEvent {
+            market_id: market_id.clone(),
+            bettor: bettor.clone(),
+            outcome: outcome.clone(),
+            stake,
+            paid,
+            fee,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                symbol_short!("cash_out"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                bettor.clone(),
+            ),
+            event,
+        );
+    }
+
+    /// Emit position transferred event
+    pub fn emit_position_transferred(
+        env: &Env,
+        market_id: &Symbol,
+        from: &Address,
+        to: &Address,
+        outcome: &String,
+        amount: i128,
+        position_amount: i128,
+    ) {
+        let event = PositionTransferredEvent {
+            market_id: market_id.clone(),
+            from: from.clone(),
+            to: to.clone(),
+            outcome: outcome.clone(),
+            amount,
+            position_amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                symbol_short!("pos_xfer"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                from.clone(),
+            ),
+            event,
+        );
+    }
+
+    /// Emit bet withdrawn event
+    pub fn emit_bet_withdrawn(
+        env: &Env,
+        market_id: &Symbol,
+        bettor: &Address,
+        outcome: &String,
+        refunded: i128,
+        penalty: i128,
+    ) {
+        let event = BetWithdrawnEvent {
+            market_id: market_id.clone(),
+            bettor: bettor.clone(),
+            outcome: outcome.clone(),
+            refunded,
+            penalty,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("bet_wdrw"), &event);
+    }
+
+    /// Emit oracle result event
+    pub fn emit_oracle_result(
+        env: &Env,
+        market_id: &Symbol,
+        result: &String,
+        provider: &String,
+        feed_id: &String,
+        price: i128,
+        threshold: i128,
+        comparison: &String,
+    ) {
+        let event = OracleResultEvent {
+            market_id: market_id.clone(),
+            result: result.clone(),
+            provider: provider.clone(),
+            feed_id: feed_id.clone(),
+            price,
+            threshold,
+            comparison: comparison.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("oracle_rs"), &event);
+    }
+
+    // ===== ORACLE RESULT VERIFICATION EVENT EMISSION METHODS =====
+
+    /// Emit oracle verification initiated event
+    ///
+    /// This event is emitted when automatic oracle result verification begins
+    /// for a market that has ended.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market being verified
+    /// - `initiator` - Address that initiated verification
+    /// - `feed_id` - Oracle feed being queried
+    /// - `oracle_count` - Number of oracle sources to query
+    pub fn emit_oracle_verification_initiated(
+        env: &Env,
+        market_id: &Symbol,
+        initiator: &Address,
+        feed_id: &String,
+        oracle_count: u32,
+    ) {
+        let event = OracleVerificationInitiatedEvent {
+            market_id: market_id.clone(),
+            initiator: initiator.clone(),
+            feed_id: feed_id.clone(),
+            oracle_count,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_init"), &event);
+    }
+
+    /// Emit oracle result verified event
+    ///
+    /// This event is emitted when oracle result verification completes successfully,
+    /// capturing the full verification details for transparency and auditability.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market that was verified
+    /// - `outcome` - Determined outcome
+    /// - `price` - Fetched price from oracle
+    /// - `threshold` - Configured threshold
+    /// - `comparison` - Comparison operator used
+    /// - `provider` - Oracle provider name
+    /// - `feed_id` - Feed ID used
+    /// - `confidence_score` - Confidence score (0-100)
+    /// - `sources_consulted` - Number of oracle sources consulted
+    /// - `is_final` - Whether this is the final verified result
+    pub fn emit_oracle_result_verified(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        price: i128,
+        threshold: i128,
+        comparison: &String,
+        provider: &String,
+        feed_id: &String,
+        confidence_score: u32,
+        sources_consulted: u32,
+        is_final: bool,
+    ) {
+        let event = OracleResultVerifiedEvent {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            price,
+            threshold,
+            comparison: comparison.clone(),
+            provider: provider.clone(),
+            feed_id: feed_id.clone(),
+            confidence_score,
+            sources_consulted,
+            verification_status: String::from_str(env, "Verified"),
+            is_final,
+            timestamp: env.ledger().timestamp(),
+            block_number: env.ledger().sequence(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_ver"), &event);
+    }
+
+    /// Emit oracle verification failed event
+    ///
+    /// This event is emitted when oracle verification fails, capturing
+    /// error details for debugging and fallback triggering.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market that failed verification
+    /// - `error_code` - Error code
+    /// - `error_message` - Description of the failure
+    /// - `attempted_providers` - Number of providers attempted
+    /// - `fallback_available` - Whether fallback is available
+    pub fn emit_oracle_verification_failed(
+        env: &Env,
+        market_id: &Symbol,
+        error_code: u32,
+        error_message: &String,
+        attempted_providers: u32,
+        fallback_available: bool,
+    ) {
+        let event = OracleVerificationFailedEvent {
+            market_id: market_id.clone(),
+            error_code,
+            error_message: error_message.clone(),
+            attempted_providers,
+            fallback_available,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_fail"), &event);
+    }
+
+    /// Emit oracle consensus reached event
+    ///
+    /// This event is emitted when multiple oracle sources reach consensus
+    /// on an outcome, providing enhanced security through agreement.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market being verified
+    /// - `consensus_outcome` - The agreed-upon outcome
+    /// - `agreeing_sources` - Number of sources that agreed
+    /// - `total_sources` - Total sources consulted
+    /// - `average_price` - Average price across sources
+    /// - `price_variance` - Price variance/deviation
+    pub fn emit_oracle_consensus_reached(
+        env: &Env,
+        market_id: &Symbol,
+        consensus_outcome: &String,
+        agreeing_sources: u32,
+        total_sources: u32,
+        average_price: i128,
+        price_variance: i128,
+    ) {
+        let agreement_percentage = if total_sources > 0 {
+            (agreeing_sources * 100) / total_sources
+        } else {
+            0
+        };
+
+        let event = OracleConsensusReachedEvent {
+            market_id: market_id.clone(),
+            consensus_outcome: consensus_outcome.clone(),
+            agreeing_sources,
+            total_sources,
+            agreement_percentage,
+            average_price,
+            price_variance,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_cons"), &event);
+    }
+
+    /// Emit oracle health status event
+    ///
+    /// This event is emitted when an oracle's health status changes,
+    /// enabling monitoring and alerting for oracle availability.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `oracle_address` - Oracle contract address
+    /// - `provider` - Provider name
+    /// - `previous_status` - Previous health status
+    /// - `current_status` - Current health status
+    /// - `consecutive_failures` - Number of consecutive failures
+    pub fn emit_oracle_health_status(
+        env: &Env,
+        oracle_address: &Address,
+        provider: &String,
+        previous_status: bool,
+        current_status: bool,
+        consecutive_failures: u32,
+    ) {
+        let event = OracleHealthStatusEvent {
+            oracle_address: oracle_address.clone(),
+            provider: provider.clone(),
+            previous_status,
+            current_status,
+            consecutive_failures,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("orc_hlth"), &event);
+    }
+
+    /// Emit market resolved event
+    pub fn emit_market_resolved(
+        env: &Env,
+        market_id: &Symbol,
+        final_outcome: &String,
+        oracle_result: &String,
+        community_consensus: &String,
+        resolution_method: &String,
+        confidence_score: i128,
+    ) {
+        let event = MarketResolvedEvent {
+            market_id: market_id.clone(),
+            final_outcome: final_outcome.clone(),
+            oracle_result: oracle_result.clone(),
+            community_consensus: community_consensus.clone(),
+            resolution_method: resolution_method.clone(),
+            confidence_score,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("mkt_res"), &event);
+    }
+
+    /// Emit event when minimum pool size is not met at resolution time
+    pub fn emit_min_pool_size_not_met(
+        env: &Env,
+        market_id: &Symbol,
+        current_pool: i128,
+        required_min: i128,
+    ) {
+        let event = MinPoolSizeNotMetEvent {
+            market_id: market_id.clone(),
+            current_pool,
+            required_min,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("pool_lo"), &event);
+    }
+
+    /// Emit dispute created event
+    pub fn emit_dispute_created(
+        env: &Env,
+        market_id: &Symbol,
+        disputer: &Address,
+        stake: i128,
+        reason: Option<String>,
+    ) {
+        let event = DisputeCreatedEvent {
+            market_id: market_id.clone(),
+            disputer: disputer.clone(),
+            stake,
+            reason,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("dispt_crt"), &event);
+    }
+
+    /// Emit result disputed event
+    pub fn emit_result_disputed(
+        env: &Env,
+        market_id: &Symbol,
+        disputer: &Address,
+        proposed_outcome: &String,
+        stake: i128,
+    ) {
+        let event = ResultDisputedEvent {
+            market_id: market_id.clone(),
+            disputer: disputer.clone(),
+            proposed_outcome: proposed_outcome.clone(),
+            stake,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("rslt_disp"), &event);
+    }
+
+    /// Emit dispute resolved event
+    pub fn emit_dispute_resolved(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        winners: &Vec<Address>,
+        losers: &Vec<Address>,
+        fee_distribution: i128,
+    ) {
+        let event = DisputeResolvedEvent {
+            market_id: market_id.clone(),
+            outcome: outcome.clone(),
+            winners: winners.clone(),
+            losers: losers.clone(),
+            fee_distribution,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("dispt_res"), &event);
+    }
+
+    /// Emit fee collected event
+    pub fn emit_fee_collected(
+        env: &Env,
+        market_id: &Symbol,
+        collector: &Address,
+        amount: i128,
+        fee_type: &String,
+    ) {
+        let event = FeeCollectedEvent {
+            market_id: market_id.clone(),
+            collector: collector.clone(),
+            amount,
+            fee_type: fee_type.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                symbol_short!("fees"),
+                EVENT_SCHEMA_VERSION,
+                symbol_short!("collect"),
+                market_id.clone(),
+            ),
+            event.clone(),
+        );
+
+        Self::store_event(env, &symbol_short!("fee_col"), &event);
+    }
+
+    /// Emit an admin fee withdrawal attempt event.
+    ///
+    /// This event is emitted for both successful and blocked attempts, enabling
+    /// off-chain monitoring of fee withdrawal behavior.
+    pub fn emit_fee_withdrawal_attempt(
+        env: &Env,
+        admin: &Address,
+        requested_amount: i128,
+        available_fees: i128,
+        withdrawal_amount: i128,
+        status: crate::fees::FeeWithdrawalStatus,
+        last_withdrawal_ts: u64,
+        next_allowed_ts: u64,
+        schedule: &crate::fees::FeeWithdrawalSchedule,
+    ) {
+        let event = FeeWithdrawalAttemptEvent {
+            admin: admin.clone(),
+            requested_amount,
+            available_fees,
+            withdrawal_amount,
+            status,
+            last_withdrawal_ts,
+            next_allowed_ts,
+            timelock_seconds: schedule.timelock_seconds,
+            max_withdrawal_bps: schedule.max_withdrawal_bps,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        // Publish to the Soroban event stream
+        env.events().publish(
+            (symbol_short!("fwd_att"), EVENT_SCHEMA_VERSION, admin.clone()),
+            event.clone(),
+        );
+
+        // Also store the last event for simple on-chain querying/debugging
+        Self::store_event(env, &symbol_short!("fwd_att"), &event);
+    }
+
+    /// Emit an admin fee withdrawal success event.
+    pub fn emit_fee_withdrawn(
+        env: &Env,
+        admin: &Address,
+        amount: i128,
+        remaining_fees: i128,
+        timestamp: u64,
+    ) {
+        let event = FeeWithdrawnEvent {
+            admin: admin.clone(),
+            amount,
+            remaining_fees,
+            timestamp,
+        };
+
+        env.events().publish(
+            (
+                symbol_short!("fees"),
+                EVENT_SCHEMA_VERSION,
+                symbol_short!("withdraw"),
+                admin.clone(),
+            ),
+            event.clone(),
+        );
+        Self::store_event(env, &symbol_short!("fwd_ok"), &event);
+    }
+
+    /// Emit extension requested event
+    pub fn emit_extension_requested(
+        env: &Env,
+        market_id: &Symbol,
+        admin: &Address,
+        additional_days: u32,
+        reason: &String,
+        fee: i128,
+    ) {
+        let event = ExtensionRequestedEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            additional_days,
+            reason: reason.clone(),
+            fee,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("ext_req"), &event);
+    }
+
+    /// Emit configuration updated event
+    pub fn emit_config_updated(
+        env: &Env,
+        updated_by: &Address,
+        config_type: &String,
+        old_value: &String,
+        new_value: &String,
+    ) {
+        let event = ConfigUpdatedEvent {
+            updated_by: updated_by.clone(),
+            config_type: config_type.clone(),
+            old_value: old_value.clone(),
+            new_value: new_value.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("cfg_upd"), &event);
+    }
+
+    /// Emit bet limits updated event (global or per-event).
+    pub fn emit_bet_limits_updated(
+        env: &Env,
         admin: &Address,
-        end_time: u64,
+        scope: &Symbol,
+        min_bet: i128,
+        max_bet: i128,
     ) {
-        let event = EventCreatedEvent {
-            event_id: event_id.clone(),
-            description: description.clone(),
-            outcomes: outcomes.clone(),
-            creation_fee_amount: crate::fees::MARKET_CREATION_FEE,
+        let event = BetLimitsUpdatedEvent {
             admin: admin.clone(),
-            end_time,
+            scope: scope.clone(),
+            min_bet,
+            max_bet,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("evt_crt"), &event);
+        Self::store_event(env, &symbol_short!("bet_lim"), &event);
     }
 
-    /// Emit vote cast event
-    pub fn emit_vote_cast(
+    /// Emit market max pool size set event (`None` clears the cap).
+    pub fn emit_max_pool_size_set(
         env: &Env,
         market_id: &Symbol,
-        voter: &Address,
-        outcome: &String,
-        stake: i128,
+        creator: &Address,
+        max_pool_size: Option<i128>,
     ) {
-        let event = VoteCastEvent {
+        let event = MaxPoolSizeSetEvent {
             market_id: market_id.clone(),
-            voter: voter.clone(),
-            outcome: outcome.clone(),
-            stake,
+            creator: creator.clone(),
+            max_pool_size,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("vote"), &event);
+        env.events().publish(
+            (symbol_short!("pool_cap"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit statistics updated event
-    pub fn emit_statistics_updated(
+    /// Emit market anti-sniping settings set event (`None` clears them).
+    pub fn emit_anti_snipe_set(
         env: &Env,
-        total_volume: i128,
-        total_bets: u64,
-        active_markets: u32,
+        market_id: &Symbol,
+        creator: &Address,
+        config: &Option<crate::types::AntiSnipeConfig>,
     ) {
-        let event = StatisticsUpdatedEvent {
-            total_volume,
-            total_bets,
-            active_markets,
+        let event = AntiSnipeSetEvent {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            enabled: config.is_some(),
+            config: config.clone().unwrap_or(crate::types::AntiSnipeConfig {
+                window_seconds: 0,
+                extension_seconds: 0,
+                threshold_amount: 0,
+                max_extension_seconds: 0,
+            }),
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("stats_upd"), &event);
+        env.events().publish(
+            (symbol_short!("snipe_cfg"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit bet placed event when a user places a bet on a market
-    ///
-    /// This function emits an event when a user successfully places a bet,
-    /// locking their funds in the contract for the duration of the market.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market identifier
-    /// - `bettor` - Address of the user placing the bet
-    /// - `outcome` - The outcome the user is betting on
-    /// - `amount` - The amount of funds locked for this bet
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// EventEmitter::emit_bet_placed(
-    ///     &env,
-    ///     &market_id,
-    ///     &user_address,
-    ///     &String::from_str(&env, "Yes"),
-    ///     10_000_000 // 1.0 XLM
-    /// );
-    /// ```
-    pub fn emit_bet_placed(
+    /// Emit betting deadline extended by anti-sniping event.
+    pub fn emit_bet_window_extended(
         env: &Env,
         market_id: &Symbol,
         bettor: &Address,
-        outcome: &String,
         amount: i128,
+        new_deadline: u64,
+        total_extension_seconds: u64,
     ) {
-        let event = BetPlacedEvent {
+        let event = BetWindowExtendedEvent {
             market_id: market_id.clone(),
             bettor: bettor.clone(),
-            outcome: outcome.clone(),
             amount,
+            new_deadline,
+            total_extension_seconds,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                symbol_short!("snipe_ext"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                bettor.clone(),
+            ),
+            event,
+        );
+    }
+
+    /// Emit per-user exposure cap updated event (`None` clears the cap).
+    pub fn emit_user_bet_cap_updated(
+        env: &Env,
+        caller: &Address,
+        scope: &Symbol,
+        max_bet_per_user: Option<i128>,
+    ) {
+        let event = UserBetCapUpdatedEvent {
+            scope: scope.clone(),
+            caller: caller.clone(),
+            max_bet_per_user,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("user_cap"), EVENT_SCHEMA_VERSION, scope.clone()),
+            event,
+        );
+    }
+
+    /// Emit error logged event
+    pub fn emit_error_logged(
+        env: &Env,
+        error_code: u32,
+        message: &String,
+        context: &String,
+        user: Option<Address>,
+        market_id: Option<Symbol>,
+    ) {
+        let event = ErrorLoggedEvent {
+            error_code,
+            message: message.clone(),
+            context: context.clone(),
+            user,
+            market_id,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("err_log"), &event);
+    }
+
+    /// Emit error recovery event
+    pub fn emit_error_recovery_event(
+        env: &Env,
+        error_code: u32,
+        recovery_strategy: &String,
+        recovery_status: String,
+        recovery_attempts: u32,
+        user: Option<Address>,
+        market_id: Option<Symbol>,
+    ) {
+        let event = ErrorRecoveryEvent {
+            error_code,
+            recovery_strategy: recovery_strategy.clone(),
+            recovery_status,
+            recovery_attempts,
+            user,
+            market_id,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("err_rec"), &event);
+    }
+
+    /// Emit performance metric event
+    pub fn emit_performance_metric(
+        env: &Env,
+        metric_name: &String,
+        value: i128,
+        unit: &String,
+        context: &String,
+    ) {
+        let event = PerformanceMetricEvent {
+            metric_name: metric_name.clone(),
+            value,
+            unit: unit.clone(),
+            context: context.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("perf_met"), &event);
+    }
+
+    /// Emit admin action logged event
+    pub fn emit_admin_action_logged(env: &Env, admin: &Address, action: &str, success: &bool) {
+        let event = AdminActionEvent {
+            admin: admin.clone(),
+            action: String::from_str(env, action),
+            target: None,
+            timestamp: env.ledger().timestamp(),
+            success: *success,
+        };
+
+        Self::store_event(env, &symbol_short!("adm_act"), &event);
+    }
+
+    /// Emit admin initialized event
+    pub fn emit_admin_initialized(env: &Env, admin: &Address) {
+        let event = AdminInitializedEvent {
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("adm_init"), &event);
+    }
+
+    /// Emit admin transferred event (primary admin role transferred to new address).
+    pub fn emit_admin_transferred(env: &Env, previous_admin: &Address, new_admin: &Address) {
+        let event = AdminTransferredEvent {
+            previous_admin: previous_admin.clone(),
+            new_admin: new_admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("adm_xfer"), &event);
+    }
+
+    /// Emit admin proposed event (pending successor awaiting acceptance).
+    pub fn emit_admin_proposed(env: &Env, current_admin: &Address, proposed_admin: &Address) {
+        let event = AdminProposedEvent {
+            current_admin: current_admin.clone(),
+            proposed_admin: proposed_admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("adm_prop"), &event);
+    }
+
+    /// Emit admin proposal cancelled event.
+    pub fn emit_admin_proposal_cancelled(
+        env: &Env,
+        current_admin: &Address,
+        proposed_admin: &Address,
+    ) {
+        let event = AdminProposalCancelledEvent {
+            current_admin: current_admin.clone(),
+            proposed_admin: proposed_admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("adm_pcnl"), &event);
+    }
+
+    /// Emit contract paused event.
+    pub fn emit_contract_paused(env: &Env, admin: &Address) {
+        let event = ContractPausedEvent {
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("ctr_pause"), &event);
+    }
+
+    /// Emit contract unpaused event.
+    pub fn emit_contract_unpaused(env: &Env, admin: &Address) {
+        let event = ContractUnpausedEvent {
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("ctr_unp"), &event);
+    }
+
+    /// Emit role granted event.
+    pub fn emit_role_granted(env: &Env, role: &Role, account: &Address, granted_by: &Address) {
+        let event = RoleGrantedEvent {
+            role: *role,
+            account: account.clone(),
+            granted_by: granted_by.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("role_grt"), &event);
+    }
+
+    /// Emit role revoked event.
+    pub fn emit_role_revoked(env: &Env, role: &Role, account: &Address, revoked_by: &Address) {
+        let event = RoleRevokedEvent {
+            role: *role,
+            account: account.clone(),
+            revoked_by: revoked_by.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("role_rvk"), &event);
+    }
+
+    /// Emit contract initialized event (full initialization with platform fee)
+    pub fn emit_contract_initialized(env: &Env, admin: &Address, fee: i128) {
+        let event = ContractInitializedEvent {
+            admin: admin.clone(), // Clone because the struct owns the Address
+            platform_fee_percentage: fee,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (Symbol::new(env, "contract_initialized"), EVENT_SCHEMA_VERSION),
+            event,
+        );
+    }
+
+    pub fn emit_platform_fee_set(env: &Env, fee: i128, admin: &Address) {
+        let event = PlatformFeeSetEvent {
+            fee_percentage: fee,
+            set_by: admin.clone(),
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("bet_plc"), &event);
+        env.events().publish(
+            (Symbol::new(env, "platform_fee_set"), EVENT_SCHEMA_VERSION),
+            event,
+        );
     }
 
-    /// Emit bet status updated event when a bet's status changes
-    ///
-    /// This function emits an event when a bet's status is updated
-    /// (e.g., Active → Won, Active → Lost, Active → Refunded).
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market identifier
-    /// - `bettor` - Address of the bettor
-    /// - `old_status` - Previous bet status
-    /// - `new_status` - New bet status
-    /// - `payout_amount` - Optional payout amount (if bet won)
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// EventEmitter::emit_bet_status_updated(
-    ///     &env,
-    ///     &market_id,
-    ///     &user_address,
-    ///     &String::from_str(&env, "Active"),
-    ///     &String::from_str(&env, "Won"),
-    ///     Some(15_000_000) // 1.5 XLM payout
-    /// );
-    /// ```
-    pub fn emit_bet_status_updated(
-        env: &Env,
-        market_id: &Symbol,
-        bettor: &Address,
-        old_status: &String,
-        new_status: &String,
-        payout_amount: Option<i128>,
-    ) {
-        let event = BetStatusUpdatedEvent {
-            market_id: market_id.clone(),
-            bettor: bettor.clone(),
-            old_status: old_status.clone(),
-            new_status: new_status.clone(),
-            payout_amount,
+    /// Emit config initialized event
+    pub fn emit_config_initialized(env: &Env, admin: &Address, environment: &Environment) {
+        let event = ConfigInitializedEvent {
+            admin: admin.clone(),
+            environment: String::from_str(
+                env,
+                match environment {
+                    Environment::Development => "Development",
+                    Environment::Testnet => "Testnet",
+                    Environment::Mainnet => "Mainnet",
+                    Environment::Custom => "Custom",
+                },
+            ),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("bet_upd"), &event);
+        Self::store_event(env, &symbol_short!("cfg_init"), &event);
     }
 
-    /// Emit oracle result event
-    pub fn emit_oracle_result(
+    /// Emit admin role assigned event
+    pub fn emit_admin_role_assigned(
         env: &Env,
-        market_id: &Symbol,
-        result: &String,
-        provider: &String,
-        feed_id: &String,
-        price: i128,
-        threshold: i128,
-        comparison: &String,
+        admin: &Address,
+        role: &AdminRole,
+        assigned_by: &Address,
     ) {
-        let event = OracleResultEvent {
-            market_id: market_id.clone(),
-            result: result.clone(),
-            provider: provider.clone(),
-            feed_id: feed_id.clone(),
-            price,
-            threshold,
-            comparison: comparison.clone(),
+        let event = AdminRoleEvent {
+            admin: admin.clone(),
+            role: String::from_str(
+                env,
+                match role {
+                    AdminRole::Owner => "Owner",
+                    AdminRole::Admin => "Admin",
+                    AdminRole::Moderator => "Moderator",
+                },
+            ),
+            assigned_by: assigned_by.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("oracle_rs"), &event);
+        Self::store_event(env, &symbol_short!("adm_role"), &event);
     }
 
-    // ===== ORACLE RESULT VERIFICATION EVENT EMISSION METHODS =====
+    /// Emit admin role deactivated event
+    pub fn emit_admin_role_deactivated(env: &Env, admin: &Address, deactivated_by: &Address) {
+        let event = AdminRoleEvent {
+            admin: admin.clone(),
+            role: String::from_str(env, "deactivated"),
+            assigned_by: deactivated_by.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
 
-    /// Emit oracle verification initiated event
-    ///
-    /// This event is emitted when automatic oracle result verification begins
-    /// for a market that has ended.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market being verified
-    /// - `initiator` - Address that initiated verification
-    /// - `feed_id` - Oracle feed being queried
-    /// - `oracle_count` - Number of oracle sources to query
-    pub fn emit_oracle_verification_initiated(
-        env: &Env,
-        market_id: &Symbol,
-        initiator: &Address,
-        feed_id: &String,
-        oracle_count: u32,
-    ) {
-        let event = OracleVerificationInitiatedEvent {
+        Self::store_event(env, &symbol_short!("adm_deact"), &event);
+    }
+
+    /// Emit market closed event
+    pub fn emit_market_closed(env: &Env, market_id: &Symbol, admin: &Address) {
+        let event = MarketClosedEvent {
             market_id: market_id.clone(),
-            initiator: initiator.clone(),
-            feed_id: feed_id.clone(),
-            oracle_count,
+            admin: admin.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("orc_init"), &event);
+        Self::store_event(env, &symbol_short!("mkt_close"), &event);
     }
 
-    /// Emit oracle result verified event
-    ///
-    /// This event is emitted when oracle result verification completes successfully,
-    /// capturing the full verification details for transparency and auditability.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market that was verified
-    /// - `outcome` - Determined outcome
-    /// - `price` - Fetched price from oracle
-    /// - `threshold` - Configured threshold
-    /// - `comparison` - Comparison operator used
-    /// - `provider` - Oracle provider name
-    /// - `feed_id` - Feed ID used
-    /// - `confidence_score` - Confidence score (0-100)
-    /// - `sources_consulted` - Number of oracle sources consulted
-    /// - `is_final` - Whether this is the final verified result
-    pub fn emit_oracle_result_verified(
+    /// Emit market cancelled event
+    pub fn emit_market_cancelled(
         env: &Env,
         market_id: &Symbol,
-        outcome: &String,
-        price: i128,
-        threshold: i128,
-        comparison: &String,
-        provider: &String,
-        feed_id: &String,
-        confidence_score: u32,
-        sources_consulted: u32,
-        is_final: bool,
+        admin: &Address,
+        reason: &String,
+        total_refundable: i128,
     ) {
-        let event = OracleResultVerifiedEvent {
+        Self::emit_cancelled(env, market_id, reason);
+
+        let event = MarketCancelledEvent {
             market_id: market_id.clone(),
-            outcome: outcome.clone(),
-            price,
-            threshold,
-            comparison: comparison.clone(),
-            provider: provider.clone(),
-            feed_id: feed_id.clone(),
-            confidence_score,
-            sources_consulted,
-            verification_status: String::from_str(env, "Verified"),
-            is_final,
+            admin: admin.clone(),
+            reason: reason.clone(),
+            total_refundable,
             timestamp: env.ledger().timestamp(),
-            block_number: env.ledger().sequence(),
         };
 
-        Self::store_event(env, &symbol_short!("orc_ver"), &event);
+        Self::store_event(env, &symbol_short!("mkt_canc"), &event);
     }
 
-    /// Emit oracle verification failed event
-    ///
-    /// This event is emitted when oracle verification fails, capturing
-    /// error details for debugging and fallback triggering.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market that failed verification
-    /// - `error_code` - Error code
-    /// - `error_message` - Description of the failure
-    /// - `attempted_providers` - Number of providers attempted
-    /// - `fallback_available` - Whether fallback is available
-    pub fn emit_oracle_verification_failed(
+    /// Emit market closed early event.
+    pub fn emit_market_closed_early(
         env: &Env,
         market_id: &Symbol,
-        error_code: u32,
-        error_message: &String,
-        attempted_providers: u32,
-        fallback_available: bool,
+        admin: &Address,
+        reason: &String,
+        original_end_time: u64,
     ) {
-        let event = OracleVerificationFailedEvent {
+        let event = MarketClosedEarlyEvent {
             market_id: market_id.clone(),
-            error_code,
-            error_message: error_message.clone(),
-            attempted_providers,
-            fallback_available,
+            admin: admin.clone(),
+            reason: reason.clone(),
+            original_end_time,
             timestamp: env.ledger().timestamp(),
         };
+        Self::store_event(env, &symbol_short!("mkt_early"), &event);
+    }
 
-        Self::store_event(env, &symbol_short!("orc_fail"), &event);
+    /// Emit market frozen event (`frozen` is false when the market is unfrozen).
+    pub fn emit_market_frozen(env: &Env, market_id: &Symbol, admin: &Address, frozen: bool) {
+        let event = MarketFrozenEvent {
+            market_id: market_id.clone(),
+            admin: admin.clone(),
+            frozen,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("mkt_frz"), &event);
     }
 
-    /// Emit oracle consensus reached event
-    ///
-    /// This event is emitted when multiple oracle sources reach consensus
-    /// on an outcome, providing enhanced security through agreement.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market being verified
-    /// - `consensus_outcome` - The agreed-upon outcome
-    /// - `agreeing_sources` - Number of sources that agreed
-    /// - `total_sources` - Total sources consulted
-    /// - `average_price` - Average price across sources
-    /// - `price_variance` - Price variance/deviation
-    pub fn emit_oracle_consensus_reached(
-        env: &Env,
-        market_id: &Symbol,
-        consensus_outcome: &String,
-        agreeing_sources: u32,
-        total_sources: u32,
-        average_price: i128,
-        price_variance: i128,
-    ) {
-        let agreement_percentage = if total_sources > 0 {
-            (agreeing_sources * 100) / total_sources
-        } else {
-            0
+    /// Emit refund on oracle failure event (market cancelled, all bets refunded in full).
+    pub fn emit_refund_on_oracle_failure(env: &Env, market_id: &Symbol, total_refunded: i128) {
+        Self::emit_cancelled(env, market_id, &String::from_str(env, "Oracle resolution failed"));
+        let event = RefundOnOracleFailureEvent {
+            market_id: market_id.clone(),
+            total_refunded,
+            timestamp: env.ledger().timestamp(),
         };
+        Self::store_event(env, &symbol_short!("ref_oracl"), &event);
+    }
 
-        let event = OracleConsensusReachedEvent {
+    /// Emit market finalized event
+    pub fn emit_market_finalized(env: &Env, market_id: &Symbol, admin: &Address, outcome: &String) {
+        let event = MarketFinalizedEvent {
             market_id: market_id.clone(),
-            consensus_outcome: consensus_outcome.clone(),
-            agreeing_sources,
-            total_sources,
-            agreement_percentage,
-            average_price,
-            price_variance,
+            admin: admin.clone(),
+            outcome: outcome.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("orc_cons"), &event);
+        Self::store_event(env, &symbol_short!("mkt_final"), &event);
     }
 
-    /// Emit oracle health status event
-    ///
-    /// This event is emitted when an oracle's health status changes,
-    /// enabling monitoring and alerting for oracle availability.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `oracle_address` - Oracle contract address
-    /// - `provider` - Provider name
-    /// - `previous_status` - Previous health status
-    /// - `current_status` - Current health status
-    /// - `consecutive_failures` - Number of consecutive failures
-    pub fn emit_oracle_health_status(
+    /// Emit dispute timeout set event
+    pub fn emit_dispute_timeout_set(
         env: &Env,
-        oracle_address: &Address,
-        provider: &String,
-        previous_status: bool,
-        current_status: bool,
-        consecutive_failures: u32,
+        dispute_id: &Symbol,
+        market_id: &Symbol,
+        timeout_hours: u32,
+        set_by: &Address,
     ) {
-        let event = OracleHealthStatusEvent {
-            oracle_address: oracle_address.clone(),
-            provider: provider.clone(),
-            previous_status,
-            current_status,
-            consecutive_failures,
+        let event = DisputeTimeoutSetEvent {
+            dispute_id: dispute_id.clone(),
+            market_id: market_id.clone(),
+            timeout_hours,
+            set_by: set_by.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("orc_hlth"), &event);
+        Self::store_event(env, &symbol_short!("tout_set"), &event);
     }
 
-    /// Emit market resolved event
-    pub fn emit_market_resolved(
+    /// Emit dispute timeout expired event
+    pub fn emit_dispute_timeout_expired(
         env: &Env,
+        dispute_id: &Symbol,
         market_id: &Symbol,
-        final_outcome: &String,
-        oracle_result: &String,
-        community_consensus: &String,
+        outcome: &String,
         resolution_method: &String,
-        confidence_score: i128,
     ) {
-        let event = MarketResolvedEvent {
+        let event = DisputeTimeoutExpiredEvent {
+            dispute_id: dispute_id.clone(),
             market_id: market_id.clone(),
-            final_outcome: final_outcome.clone(),
-            oracle_result: oracle_result.clone(),
-            community_consensus: community_consensus.clone(),
+            expiration_timestamp: env.ledger().timestamp(),
+            outcome: outcome.clone(),
             resolution_method: resolution_method.clone(),
-            confidence_score,
-            timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("mkt_res"), &event);
+        Self::store_event(env, &symbol_short!("tout_exp"), &event);
     }
 
-    /// Emit event when minimum pool size is not met at resolution time
-    pub fn emit_min_pool_size_not_met(
+    /// Emit dispute timeout extended event
+    pub fn emit_dispute_timeout_extended(
         env: &Env,
+        dispute_id: &Symbol,
         market_id: &Symbol,
-        current_pool: i128,
-        required_min: i128,
+        additional_hours: u32,
+        extended_by: &Address,
     ) {
-        let event = MinPoolSizeNotMetEvent {
+        let event = DisputeTimeoutExtendedEvent {
+            dispute_id: dispute_id.clone(),
             market_id: market_id.clone(),
-            current_pool,
-            required_min,
+            additional_hours,
+            extended_by: extended_by.clone(),
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("pool_lo"), &event);
+
+        Self::store_event(env, &symbol_short!("tout_ext"), &event);
     }
 
-    /// Emit dispute created event
-    pub fn emit_dispute_created(
+    /// Emit dispute auto-resolved event
+    pub fn emit_dispute_auto_resolved(
         env: &Env,
+        dispute_id: &Symbol,
         market_id: &Symbol,
-        disputer: &Address,
-        stake: i128,
-        reason: Option<String>,
+        outcome: &String,
+        reason: &String,
     ) {
-        let event = DisputeCreatedEvent {
+        let event = DisputeAutoResolvedEvent {
+            dispute_id: dispute_id.clone(),
             market_id: market_id.clone(),
-            disputer: disputer.clone(),
-            stake,
-            reason,
+            outcome: outcome.clone(),
+            reason: reason.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("dispt_crt"), &event);
+        Self::store_event(env, &symbol_short!("auto_res"), &event);
     }
 
-    /// Emit dispute resolved event
-    pub fn emit_dispute_resolved(
-        env: &Env,
-        market_id: &Symbol,
-        outcome: &String,
-        winners: &Vec<Address>,
-        losers: &Vec<Address>,
-        fee_distribution: i128,
-    ) {
-        let event = DisputeResolvedEvent {
+    /// Emit storage cleanup event
+    pub fn emit_storage_cleanup_event(env: &Env, market_id: &Symbol, cleanup_type: &String) {
+        let event = StorageCleanupEvent {
             market_id: market_id.clone(),
-            outcome: outcome.clone(),
-            winners: winners.clone(),
-            losers: losers.clone(),
-            fee_distribution,
+            cleanup_type: cleanup_type.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("dispt_res"), &event);
+        Self::store_event(env, &symbol_short!("stor_cln"), &event);
     }
 
-    /// Emit fee collected event
-    pub fn emit_fee_collected(
+    /// Emit storage optimization event
+    pub fn emit_storage_optimization_event(
         env: &Env,
         market_id: &Symbol,
-        collector: &Address,
-        amount: i128,
-        fee_type: &String,
+        optimization_type: &String,
     ) {
-        let event = FeeCollectedEvent {
+        let event = StorageOptimizationEvent {
             market_id: market_id.clone(),
-            collector: collector.clone(),
-            amount,
-            fee_type: fee_type.clone(),
+            optimization_type: optimization_type.clone(),
             timestamp: env.ledger().timestamp(),
         };
 
-        Self::store_event(env, &symbol_short!("fee_col"), &event);
+        Self::store_event(env, &symbol_short!("stor_opt"), &event);
     }
 
-    /// Emit an admin fee withdrawal attempt event.
-    ///
-    /// This event is emitted for both successful and blocked attempts, enabling
-    /// off-chain monitoring of fee withdrawal behavior.
-    pub fn emit_fee_withdrawal_attempt(
+    /// Emit storage migration event
+    pub fn emit_storage_migration_event(
         env: &Env,
-        admin: &Address,
-        requested_amount: i128,
-        available_fees: i128,
-        withdrawal_amount: i128,
-        status: crate::fees::FeeWithdrawalStatus,
-        last_withdrawal_ts: u64,
-        next_allowed_ts: u64,
-        schedule: &crate::fees::FeeWithdrawalSchedule,
+        migration_id: &Symbol,
+        from_format: &String,
+        to_format: &String,
+        markets_migrated: u32,
     ) {
-        let event = FeeWithdrawalAttemptEvent {
-            admin: admin.clone(),
-            requested_amount,
-            available_fees,
-            withdrawal_amount,
-            status,
-            last_withdrawal_ts,
-            next_allowed_ts,
-            timelock_seconds: schedule.timelock_seconds,
-            max_withdrawal_bps: schedule.max_withdrawal_bps,
+        let event = StorageMigrationEvent {
+            migration_id: migration_id.clone(),
+            from_format: from_format.clone(),
+            to_format: to_format.clone(),
+            markets_migrated,
             timestamp: env.ledger().timestamp(),
         };
 
-        // Publish to the Soroban event stream
-        env.events()
-            .publish((symbol_short!("fwd_att"), admin.clone()), event.clone());
+        Self::store_event(env, &symbol_short!("stor_mig"), &event);
+    }
 
-        // Also store the last event for simple on-chain querying/debugging
-        Self::store_event(env, &symbol_short!("fwd_att"), &event);
+    /// Emit circuit breaker event
+    pub fn emit_circuit_breaker_event(env: &Env, event: &CircuitBreakerEvent) {
+        Self::store_event(env, &symbol_short!("cb_event"), event);
     }
 
-    /// Emit an admin fee withdrawal success event.
-    pub fn emit_fee_withdrawn(
-        env: &Env,
-        admin: &Address,
-        amount: i128,
-        remaining_fees: i128,
-        timestamp: u64,
-    ) {
-        let event = FeeWithdrawnEvent {
-            admin: admin.clone(),
-            amount,
-            remaining_fees,
-            timestamp,
+    /// Emit oracle degradation event when oracle service fails
+    pub fn emit_oracle_degradation(env: &Env, oracle: &OracleProvider, reason: &String) {
+        let event = OracleDegradationEvent {
+            oracle: oracle.clone(),
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
         };
+        Self::store_event(env, &symbol_short!("ora_deg"), &event);
+    }
 
-        env.events()
-            .publish((symbol_short!("fwd_ok"), admin.clone()), event.clone());
-        Self::store_event(env, &symbol_short!("fwd_ok"), &event);
+    /// Emit oracle recovery event when oracle service recovers
+    pub fn emit_oracle_recovery(env: &Env, oracle: &OracleProvider, message: &String) {
+        let event = OracleRecoveryEvent {
+            oracle: oracle.clone(),
+            recovery_message: message.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("ora_rec"), &event);
     }
 
-    /// Emit extension requested event
-    pub fn emit_extension_requested(
+    /// Emit manual resolution required event when automatic resolution fails
+    pub fn emit_manual_resolution_required(env: &Env, market_id: &Symbol, reason: &String) {
+        let event = ManualResolutionRequiredEvent {
+            market_id: market_id.clone(),
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("man_res"), &event);
+    }
+
+    /// Emit state change event when market state transitions
+    ///
+    /// This function emits an event whenever a market transitions between states,
+    /// providing complete transparency and audit trail for state changes.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market identifier
+    /// - `old_state` - Previous market state
+    /// - `new_state` - New market state after transition
+    /// - `reason` - Reason for state change
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// EventEmitter::emit_state_change_event(
+    ///     &env,
+    ///     &market_id,
+    ///     &MarketState::Active,
+    ///     &MarketState::Ended,
+    ///     &String::from_str(&env, "Voting period completed")
+    /// );
+    /// ```
+    pub fn emit_state_change_event(
         env: &Env,
         market_id: &Symbol,
-        admin: &Address,
-        additional_days: u32,
+        old_state: &crate::types::MarketState,
+        new_state: &crate::types::MarketState,
         reason: &String,
-        fee: i128,
     ) {
-        let event = ExtensionRequestedEvent {
+        let event = StateChangeEvent {
             market_id: market_id.clone(),
-            admin: admin.clone(),
-            additional_days,
+            old_state: old_state.clone(),
+            new_state: new_state.clone(),
             reason: reason.clone(),
-            fee,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("ext_req"), &event);
+        Self::store_event(env, &symbol_short!("st_chng"), &event);
     }
 
-    /// Emit configuration updated event
-    pub fn emit_config_updated(
-        env: &Env,
-        updated_by: &Address,
-        config_type: &String,
-        old_value: &String,
-        new_value: &String,
-    ) {
-        let event = ConfigUpdatedEvent {
-            updated_by: updated_by.clone(),
-            config_type: config_type.clone(),
-            old_value: old_value.clone(),
-            new_value: new_value.clone(),
+    /// Emit winnings claimed event when user claims payout
+    ///
+    /// This function emits an event whenever a user successfully claims their
+    /// winnings from a resolved market, providing transparency for all payouts.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `market_id` - Market identifier
+    /// - `user` - User address claiming winnings
+    /// - `amount` - Amount claimed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// EventEmitter::emit_winnings_claimed(
+    ///     &env,
+    ///     &market_id,
+    ///     &user_address,
+    ///     1_500_000_000 // 150 tokens
+    /// );
+    /// ```
+    pub fn emit_winnings_claimed(env: &Env, market_id: &Symbol, user: &Address, amount: i128) {
+        Self::emit_payout(env, market_id, user, amount);
+        let event = WinningsClaimedEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("cfg_upd"), &event);
+        Self::store_event(env, &symbol_short!("win_clm"), &event);
     }
 
-    /// Emit bet limits updated event (global or per-event).
-    pub fn emit_bet_limits_updated(
+    /// Emit winnings claimed batch event
+    ///
+    /// Emits an event when a user claims winnings from multiple markets in a batch.
+    ///
+    /// # Parameters
+    ///
+    /// - `env` - Soroban environment
+    /// - `user` - User address claiming winnings
+    /// - `market_claims` - Vector of (market_id, claim_amount) tuples
+    /// - `total_amount` - Total amount claimed across all markets
+    pub fn emit_winnings_claimed_batch(
         env: &Env,
-        admin: &Address,
-        scope: &Symbol,
-        min_bet: i128,
-        max_bet: i128,
+        user: &Address,
+        market_claims: &Vec<(Symbol, i128)>,
+        total_amount: i128,
     ) {
-        let event = BetLimitsUpdatedEvent {
-            admin: admin.clone(),
-            scope: scope.clone(),
-            min_bet,
-            max_bet,
-            timestamp: env.ledger().timestamp(),
-        };
-        Self::store_event(env, &symbol_short!("bet_lim"), &event);
-    }
+        for (market_id, amount) in market_claims.iter() {
+            if amount > 0 {
+                Self::emit_payout(env, &market_id, user, amount);
+            }
+        }
 
-    /// Emit error logged event
-    pub fn emit_error_logged(
-        env: &Env,
-        error_code: u32,
-        message: &String,
-        context: &String,
-        user: Option<Address>,
-        market_id: Option<Symbol>,
-    ) {
-        let event = ErrorLoggedEvent {
-            error_code,
-            message: message.clone(),
-            context: context.clone(),
-            user,
-            market_id,
+        let event = WinningsClaimedBatchEvent {
+            user: user.clone(),
+            market_claims: market_claims.clone(),
+            total_amount,
+            claim_count: market_claims.len() as u32,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("err_log"), &event);
-    }
-
-    /// Emit error recovery event
-    pub fn emit_error_recovery_event(
+        Self::store_event(env, &symbol_short!("win_btc"), &event);
+          }
+    /// Emit the outcome of one market in a `claim_all` batch.
+    ///
+    /// `error_code` is 0 when the claim went through, otherwise the `Error`
+    /// code that caused the market to be skipped.
+    pub fn emit_claim_result(
         env: &Env,
+        market_id: &Symbol,
+        user: &Address,
+        amount: i128,
         error_code: u32,
-        recovery_strategy: &String,
-        recovery_status: String,
-        recovery_attempts: u32,
-        user: Option<Address>,
-        market_id: Option<Symbol>,
     ) {
-        let event = ErrorRecoveryEvent {
+        let event = ClaimResultEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
             error_code,
-            recovery_strategy: recovery_strategy.clone(),
-            recovery_status,
-            recovery_attempts,
-            user,
-            market_id,
-            timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("err_rec"), &event);
+        env.events().publish(
+            (
+                symbol_short!("claim_res"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                user.clone(),
+            ),
+            event,
+        );
     }
 
-    /// Emit performance metric event
-    pub fn emit_performance_metric(
-        env: &Env,
-        metric_name: &String,
-        value: i128,
-        unit: &String,
-        context: &String,
-    ) {
-        let event = PerformanceMetricEvent {
-            metric_name: metric_name.clone(),
-            value,
-            unit: unit.clone(),
-            context: context.clone(),
+    /// Emit global claim period updated event.
+    pub fn emit_claim_period_updated(env: &Env, admin: &Address, claim_period_seconds: u64) {
+        let event = ClaimPeriodUpdatedEvent {
+            admin: admin.clone(),
+            claim_period_seconds,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("perf_met"), &event);
+        Self::store_event(env, &symbol_short!("clm_prd"), &event);
     }
 
-    /// Emit admin action logged event
-    pub fn emit_admin_action_logged(env: &Env, admin: &Address, action: &str, success: &bool) {
-        let event = AdminActionEvent {
+    /// Emit default dispute window updated event.
+    pub fn emit_dispute_window_updated(env: &Env, admin: &Address, dispute_window_seconds: u64) {
+        let event = DisputeWindowUpdatedEvent {
             admin: admin.clone(),
-            action: String::from_str(env, action),
-            target: None,
+            dispute_window_seconds,
             timestamp: env.ledger().timestamp(),
-            success: *success,
         };
-
-        Self::store_event(env, &symbol_short!("adm_act"), &event);
+        Self::store_event(env, &symbol_short!("dsp_win"), &event);
     }
 
-    /// Emit admin initialized event
-    pub fn emit_admin_initialized(env: &Env, admin: &Address) {
-        let event = AdminInitializedEvent {
+    /// Emit market claim period updated event.
+    pub fn emit_market_claim_period_updated(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        claim_period_seconds: u64,
+    ) {
+        let event = MarketClaimPeriodUpdatedEvent {
+            market_id: market_id.clone(),
             admin: admin.clone(),
+            claim_period_seconds,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("adm_init"), &event);
+        Self::store_event(env, &symbol_short!("m_clm_pd"), &event);
     }
 
-    /// Emit admin transferred event (primary admin role transferred to new address).
-    pub fn emit_admin_transferred(env: &Env, previous_admin: &Address, new_admin: &Address) {
-        let event = AdminTransferredEvent {
-            previous_admin: previous_admin.clone(),
-            new_admin: new_admin.clone(),
+    /// Emit treasury updated event.
+    pub fn emit_treasury_updated(env: &Env, admin: &Address, treasury: &Address) {
+        let event = TreasuryUpdatedEvent {
+            admin: admin.clone(),
+            treasury: treasury.clone(),
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("adm_xfer"), &event);
+        Self::store_event(env, &symbol_short!("treas_up"), &event);
     }
 
-    /// Emit contract paused event.
-    pub fn emit_contract_paused(env: &Env, admin: &Address) {
-        let event = ContractPausedEvent {
+    /// Emit staking token updated event.
+    pub fn emit_token_updated(
+        env: &Env,
+        admin: &Address,
+        previous_token: &Option<Address>,
+        token: &Address,
+    ) {
+        let event = TokenUpdatedEvent {
             admin: admin.clone(),
+            previous_token: previous_token.clone(),
+            token: token.clone(),
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("ctr_pause"), &event);
+        env.events()
+            .publish((symbol_short!("token_up"), EVENT_SCHEMA_VERSION), event);
     }
 
-    /// Emit contract unpaused event.
-    pub fn emit_contract_unpaused(env: &Env, admin: &Address) {
-        let event = ContractUnpausedEvent {
+    /// Emit platform fee (basis points) updated event.
+    pub fn emit_fee_bps_updated(env: &Env, admin: &Address, previous_bps: u32, new_bps: u32) {
+        let event = FeeBpsUpdatedEvent {
             admin: admin.clone(),
+            previous_bps,
+            new_bps,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("ctr_unp"), &event);
+        env.events()
+            .publish((symbol_short!("fee_bps"), EVENT_SCHEMA_VERSION), event);
     }
 
-    /// Emit contract initialized event (full initialization with platform fee)
-    pub fn emit_contract_initialized(env: &Env, admin: &Address, fee: i128) {
-        let event = ContractInitializedEvent {
-            admin: admin.clone(), // Clone because the struct owns the Address
-            platform_fee_percentage: fee,
+    /// Emit pool-size fee tiers updated event.
+    pub fn emit_fee_tiers_updated(env: &Env, admin: &Address, tiers: &Vec<(i128, u32)>) {
+        let event = FeeTiersUpdatedEvent {
+            admin: admin.clone(),
+            tiers: tiers.clone(),
             timestamp: env.ledger().timestamp(),
         };
         env.events()
-            .publish((Symbol::new(env, "contract_initialized"),), event);
+            .publish((symbol_short!("fee_tier"), EVENT_SCHEMA_VERSION), event);
     }
 
-    pub fn emit_platform_fee_set(env: &Env, fee: i128, admin: &Address) {
-        let event = PlatformFeeSetEvent {
-            fee_percentage: fee,
-            set_by: admin.clone(),
+    /// Emit market type set event.
+    pub fn emit_market_type_set(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        market_type: crate::types::MarketType,
+    ) {
+        let event = MarketTypeSetEvent {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            market_type,
             timestamp: env.ledger().timestamp(),
         };
-        env.events()
-            .publish((Symbol::new(env, "platform_fee_set"),), event);
+        env.events().publish(
+            (symbol_short!("mkt_type"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit config initialized event
-    pub fn emit_config_initialized(env: &Env, admin: &Address, environment: &Environment) {
-        let event = ConfigInitializedEvent {
-            admin: admin.clone(),
-            environment: String::from_str(
-                env,
-                match environment {
-                    Environment::Development => "Development",
-                    Environment::Testnet => "Testnet",
-                    Environment::Mainnet => "Mainnet",
-                    Environment::Custom => "Custom",
-                },
-            ),
+    /// Emit market access or bettor allowlist updated event.
+    pub fn emit_market_access_updated(
+        env: &Env,
+        market_id: &Symbol,
+        updated_by: &Address,
+        access: crate::types::MarketAccess,
+        added: &Vec<Address>,
+        removed: &Vec<Address>,
+    ) {
+        let event = MarketAccessUpdatedEvent {
+            market_id: market_id.clone(),
+            updated_by: updated_by.clone(),
+            access,
+            added: added.clone(),
+            removed: removed.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("mkt_acces"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
+    }
+
+    /// Emit address blocked or unblocked event.
+    pub fn emit_address_blacklisted(env: &Env, address: &Address, blocked: bool, admin: &Address) {
+        let event = AddressBlacklistedEvent {
+            address: address.clone(),
+            blocked,
+            admin: admin.clone(),
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("cfg_init"), &event);
+        env.events().publish(
+            (symbol_short!("blacklist"), EVENT_SCHEMA_VERSION, address.clone()),
+            event,
+        );
     }
 
-    /// Emit admin role assigned event
-    pub fn emit_admin_role_assigned(
+    /// Emit blocked funds released event.
+    pub fn emit_blocked_funds_released(
         env: &Env,
+        market_id: &Symbol,
+        address: &Address,
+        recipient: &Address,
+        amount: i128,
         admin: &Address,
-        role: &AdminRole,
-        assigned_by: &Address,
     ) {
-        let event = AdminRoleEvent {
+        let event = BlockedFundsReleasedEvent {
+            market_id: market_id.clone(),
+            address: address.clone(),
+            recipient: recipient.clone(),
+            amount,
             admin: admin.clone(),
-            role: String::from_str(
-                env,
-                match role {
-                    AdminRole::Owner => "Owner",
-                    AdminRole::Admin => "Admin",
-                    AdminRole::Moderator => "Moderator",
-                },
-            ),
-            assigned_by: assigned_by.clone(),
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("adm_role"), &event);
+        env.events().publish(
+            (
+                symbol_short!("blk_rel"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                address.clone(),
+            ),
+            event,
+        );
     }
 
-    /// Emit admin role deactivated event
-    pub fn emit_admin_role_deactivated(env: &Env, admin: &Address, deactivated_by: &Address) {
-        let event = AdminRoleEvent {
+    /// Emit free-bet credit granted event.
+    pub fn emit_credit_granted(
+        env: &Env,
+        user: &Address,
+        admin: &Address,
+        amount: i128,
+        credit: &crate::types::BetCredit,
+    ) {
+        let event = BetCreditEvent {
+            user: user.clone(),
             admin: admin.clone(),
-            role: String::from_str(env, "deactivated"),
-            assigned_by: deactivated_by.clone(),
+            amount,
+            balance: credit.amount,
+            expires_at: credit.expires_at,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("adm_deact"), &event);
+        env.events().publish(
+            (symbol_short!("crd_grant"), EVENT_SCHEMA_VERSION, user.clone()),
+            event,
+        );
     }
 
-    /// Emit market closed event
-    pub fn emit_market_closed(env: &Env, market_id: &Symbol, admin: &Address) {
-        let event = MarketClosedEvent {
-            market_id: market_id.clone(),
+    /// Emit free-bet credit reclaimed event.
+    pub fn emit_credit_reclaimed(
+        env: &Env,
+        user: &Address,
+        admin: &Address,
+        credit: &crate::types::BetCredit,
+    ) {
+        let event = BetCreditEvent {
+            user: user.clone(),
             admin: admin.clone(),
+            amount: credit.amount,
+            balance: 0,
+            expires_at: credit.expires_at,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("mkt_close"), &event);
+        env.events().publish(
+            (symbol_short!("crd_recl"), EVENT_SCHEMA_VERSION, user.clone()),
+            event,
+        );
     }
 
-    /// Emit refund on oracle failure event (market cancelled, all bets refunded in full).
-    pub fn emit_refund_on_oracle_failure(env: &Env, market_id: &Symbol, total_refunded: i128) {
-        let event = RefundOnOracleFailureEvent {
-            market_id: market_id.clone(),
-            total_refunded,
+    /// Emit event for a foreign token recovered by the admin.
+    pub fn emit_token_recovered(
+        env: &Env,
+        token: &Address,
+        recipient: &Address,
+        amount: i128,
+        admin: &Address,
+    ) {
+        let event = TokenRecoveredEvent {
+            token: token.clone(),
+            recipient: recipient.clone(),
+            amount,
+            admin: admin.clone(),
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("ref_oracl"), &event);
+        env.events().publish(
+            (symbol_short!("tok_recov"), EVENT_SCHEMA_VERSION, token.clone()),
+            event,
+        );
     }
 
-    /// Emit market finalized event
-    pub fn emit_market_finalized(env: &Env, market_id: &Symbol, admin: &Address, outcome: &String) {
-        let event = MarketFinalizedEvent {
+    /// Emit keeper incentive paid event.
+    pub fn emit_keeper_paid(env: &Env, market_id: &Symbol, keeper: &Address, amount: i128) {
+        let event = KeeperPaidEvent {
             market_id: market_id.clone(),
-            admin: admin.clone(),
-            outcome: outcome.clone(),
+            keeper: keeper.clone(),
+            amount,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("mkt_final"), &event);
+        env.events().publish(
+            (
+                symbol_short!("keep_paid"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                keeper.clone(),
+            ),
+            event,
+        );
     }
 
-    /// Emit dispute timeout set event
-    pub fn emit_dispute_timeout_set(
-        env: &Env,
-        dispute_id: &Symbol,
-        market_id: &Symbol,
-        timeout_hours: u32,
-        set_by: &Address,
-    ) {
-        let event = DisputeTimeoutSetEvent {
-            dispute_id: dispute_id.clone(),
+    /// Emit creator fee set event.
+    pub fn emit_creator_fee_set(env: &Env, market_id: &Symbol, creator: &Address, bps: u32) {
+        let event = CreatorFeeSetEvent {
             market_id: market_id.clone(),
-            timeout_hours,
-            set_by: set_by.clone(),
+            creator: creator.clone(),
+            bps,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("tout_set"), &event);
+        env.events().publish(
+            (symbol_short!("crtr_fee"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit dispute timeout expired event
-    pub fn emit_dispute_timeout_expired(
+    /// Emit market platform fee override event.
+    pub fn emit_market_fee_set(
         env: &Env,
-        dispute_id: &Symbol,
         market_id: &Symbol,
-        outcome: &String,
-        resolution_method: &String,
+        admin: &Address,
+        previous_bps: u32,
+        new_bps: u32,
     ) {
-        let event = DisputeTimeoutExpiredEvent {
-            dispute_id: dispute_id.clone(),
+        let event = MarketFeeSetEvent {
             market_id: market_id.clone(),
-            expiration_timestamp: env.ledger().timestamp(),
-            outcome: outcome.clone(),
-            resolution_method: resolution_method.clone(),
+            admin: admin.clone(),
+            previous_bps,
+            new_bps,
+            timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("tout_exp"), &event);
+        env.events().publish(
+            (symbol_short!("mkt_fee"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit dispute timeout extended event
-    pub fn emit_dispute_timeout_extended(
+    /// Emit market primary oracle set updated event.
+    pub fn emit_market_oracles_updated(
         env: &Env,
-        dispute_id: &Symbol,
         market_id: &Symbol,
-        additional_hours: u32,
-        extended_by: &Address,
+        caller: &Address,
+        oracle_count: u32,
     ) {
-        let event = DisputeTimeoutExtendedEvent {
-            dispute_id: dispute_id.clone(),
+        let event = MarketOraclesUpdatedEvent {
             market_id: market_id.clone(),
-            additional_hours,
-            extended_by: extended_by.clone(),
+            caller: caller.clone(),
+            oracle_count,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("tout_ext"), &event);
+        env.events().publish(
+            (symbol_short!("mkt_orcl"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit dispute auto-resolved event
-    pub fn emit_dispute_auto_resolved(
+    /// Emit community vote opened event.
+    pub fn emit_community_vote_opened(
         env: &Env,
-        dispute_id: &Symbol,
         market_id: &Symbol,
-        outcome: &String,
-        reason: &String,
+        opened_by: &Address,
+        ends_at: u64,
     ) {
-        let event = DisputeAutoResolvedEvent {
-            dispute_id: dispute_id.clone(),
+        let event = CommunityVoteOpenedEvent {
             market_id: market_id.clone(),
-            outcome: outcome.clone(),
-            reason: reason.clone(),
+            opened_by: opened_by.clone(),
+            ends_at,
             timestamp: env.ledger().timestamp(),
         };
+        env.events().publish(
+            (symbol_short!("cv_open"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
+    }
 
-        Self::store_event(env, &symbol_short!("auto_res"), &event);
+    /// Emit market template created or updated event.
+    pub fn emit_template_set(env: &Env, template_id: u32, admin: &Address) {
+        let event = TemplateSetEvent {
+            template_id,
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("tmpl_set"), EVENT_SCHEMA_VERSION, template_id),
+            event,
+        );
     }
 
-    /// Emit storage cleanup event
-    pub fn emit_storage_cleanup_event(env: &Env, market_id: &Symbol, cleanup_type: &String) {
-        let event = StorageCleanupEvent {
+    /// Emit market series rolled event, for every market a series creates.
+    pub fn emit_series_rolled(env: &Env, series_id: u32, index: u32, market_id: &Symbol) {
+        let event = SeriesRolledEvent {
+            series_id,
+            index,
             market_id: market_id.clone(),
-            cleanup_type: cleanup_type.clone(),
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("stor_cln"), &event);
+        env.events().publish(
+            (symbol_short!("series"), EVENT_SCHEMA_VERSION, series_id),
+            event,
+        );
     }
 
-    /// Emit storage optimization event
-    pub fn emit_storage_optimization_event(
+    /// Emit market resolve mode set event.
+    pub fn emit_resolve_mode_set(
         env: &Env,
         market_id: &Symbol,
-        optimization_type: &String,
+        creator: &Address,
+        mode: crate::types::ResolveMode,
     ) {
-        let event = StorageOptimizationEvent {
+        let event = ResolveModeSetEvent {
             market_id: market_id.clone(),
-            optimization_type: optimization_type.clone(),
+            creator: creator.clone(),
+            mode,
             timestamp: env.ledger().timestamp(),
         };
-
-        Self::store_event(env, &symbol_short!("stor_opt"), &event);
+        env.events().publish(
+            (symbol_short!("rslv_mode"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit storage migration event
-    pub fn emit_storage_migration_event(
+    /// Emit event for a market's creation bond returned or slashed.
+    pub fn emit_creation_bond_released(
         env: &Env,
-        migration_id: &Symbol,
-        from_format: &String,
-        to_format: &String,
-        markets_migrated: u32,
+        market_id: &Symbol,
+        creator: &Address,
+        amount: i128,
+        slashed: bool,
     ) {
-        let event = StorageMigrationEvent {
-            migration_id: migration_id.clone(),
-            from_format: from_format.clone(),
-            to_format: to_format.clone(),
-            markets_migrated,
-            timestamp: env.ledger().timestamp(),
-        };
-
-        Self::store_event(env, &symbol_short!("stor_mig"), &event);
-    }
-
-    /// Emit circuit breaker event
-    pub fn emit_circuit_breaker_event(env: &Env, event: &CircuitBreakerEvent) {
-        Self::store_event(env, &symbol_short!("cb_event"), event);
-    }
-
-    /// Emit oracle degradation event when oracle service fails
-    pub fn emit_oracle_degradation(env: &Env, oracle: &OracleProvider, reason: &String) {
-        let event = OracleDegradationEvent {
-            oracle: oracle.clone(),
-            reason: reason.clone(),
+        let event = CreationBondReleasedEvent {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            amount,
+            slashed,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("ora_deg"), &event);
+        env.events().publish(
+            (symbol_short!("crt_bond"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit oracle recovery event when oracle service recovers
-    pub fn emit_oracle_recovery(env: &Env, oracle: &OracleProvider, message: &String) {
-        let event = OracleRecoveryEvent {
-            oracle: oracle.clone(),
-            recovery_message: message.clone(),
+    /// Emit event for a result proposed on an optimistic market.
+    pub fn emit_result_proposed(
+        env: &Env,
+        market_id: &Symbol,
+        proposer: &Address,
+        outcome: &String,
+        bond: i128,
+        challenge_deadline: u64,
+    ) {
+        let event = ResultProposedEvent {
+            market_id: market_id.clone(),
+            proposer: proposer.clone(),
+            outcome: outcome.clone(),
+            bond,
+            challenge_deadline,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("ora_rec"), &event);
+        env.events().publish(
+            (symbol_short!("rslt_prop"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
-    /// Emit manual resolution required event when automatic resolution fails
-    pub fn emit_manual_resolution_required(env: &Env, market_id: &Symbol, reason: &String) {
-        let event = ManualResolutionRequiredEvent {
+    /// Emit event for a proposed result challenged with a counter-bond.
+    pub fn emit_result_challenged(env: &Env, market_id: &Symbol, challenger: &Address, bond: i128) {
+        let event = ResultChallengedEvent {
             market_id: market_id.clone(),
-            reason: reason.clone(),
+            challenger: challenger.clone(),
+            bond,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("man_res"), &event);
-    }
-
-    /// Emit state change event when market state transitions
-    ///
-    /// This function emits an event whenever a market transitions between states,
-    /// providing complete transparency and audit trail for state changes.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market identifier
-    /// - `old_state` - Previous market state
-    /// - `new_state` - New market state after transition
-    /// - `reason` - Reason for state change
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// EventEmitter::emit_state_change_event(
-    ///     &env,
-    ///     &market_id,
-    ///     &MarketState::Active,
-    ///     &MarketState::Ended,
-    ///     &String::from_str(&env, "Voting period completed")
-    /// );
-    /// ```
-    pub fn emit_state_change_event(
+        env.events().publish(
+            (symbol_short!("rslt_chal"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
+    }
+
+    /// Emit community vote cast event.
+    pub fn emit_community_vote_cast(
         env: &Env,
         market_id: &Symbol,
-        old_state: &crate::types::MarketState,
-        new_state: &crate::types::MarketState,
-        reason: &String,
+        voter: &Address,
+        outcome: &String,
+        weight: i128,
     ) {
-        let event = StateChangeEvent {
+        let event = CommunityVoteCastEvent {
             market_id: market_id.clone(),
-            old_state: old_state.clone(),
-            new_state: new_state.clone(),
-            reason: reason.clone(),
+            voter: voter.clone(),
+            outcome: outcome.clone(),
+            weight,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("st_chng"), &event);
+        env.events().publish(
+            (
+                symbol_short!("cv_vote"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                voter.clone(),
+            ),
+            event,
+        );
     }
 
-    /// Emit winnings claimed event when user claims payout
-    ///
-    /// This function emits an event whenever a user successfully claims their
-    /// winnings from a resolved market, providing transparency for all payouts.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `market_id` - Market identifier
-    /// - `user` - User address claiming winnings
-    /// - `amount` - Amount claimed
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// EventEmitter::emit_winnings_claimed(
-    ///     &env,
-    ///     &market_id,
-    ///     &user_address,
-    ///     1_500_000_000 // 150 tokens
-    /// );
-    /// ```
-    pub fn emit_winnings_claimed(env: &Env, market_id: &Symbol, user: &Address, amount: i128) {
-        let event = WinningsClaimedEvent {
+    /// Emit community vote finalized event; `outcome` is `None` when voided.
+    pub fn emit_community_vote_finalized(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &Option<String>,
+        total_weight: i128,
+    ) {
+        let event = CommunityVoteFinalizedEvent {
             market_id: market_id.clone(),
-            user: user.clone(),
+            outcome: outcome.clone(),
+            total_weight,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("cv_final"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
+    }
+
+    /// Emit creator fees withdrawn event.
+    pub fn emit_creator_fees_withdrawn(env: &Env, creator: &Address, amount: i128) {
+        let event = CreatorFeesWithdrawnEvent {
+            creator: creator.clone(),
             amount,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("win_clm"), &event);
+        env.events().publish(
+            (symbol_short!("crtr_wd"), EVENT_SCHEMA_VERSION, creator.clone()),
+            event,
+        );
     }
 
-    /// Emit winnings claimed batch event
-    ///
-    /// Emits an event when a user claims winnings from multiple markets in a batch.
-    ///
-    /// # Parameters
-    ///
-    /// - `env` - Soroban environment
-    /// - `user` - User address claiming winnings
-    /// - `market_claims` - Vector of (market_id, claim_amount) tuples
-    /// - `total_amount` - Total amount claimed across all markets
-    pub fn emit_winnings_claimed_batch(
-        env: &Env,
-        user: &Address,
-        market_claims: &Vec<(Symbol, i128)>,
-        total_amount: i128,
-    ) {
-        let event = WinningsClaimedBatchEvent {
+    /// Emit referrer attached event.
+    pub fn emit_referrer_set(env: &Env, user: &Address, referrer: &Address) {
+        let event = ReferrerSetEvent {
             user: user.clone(),
-            market_claims: market_claims.clone(),
-            total_amount,
-            claim_count: market_claims.len() as u32,
+            referrer: referrer.clone(),
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("win_btc"), &event);
-          }
-    /// Emit global claim period updated event.
-    pub fn emit_claim_period_updated(env: &Env, admin: &Address, claim_period_seconds: u64) {
-        let event = ClaimPeriodUpdatedEvent {
-            admin: admin.clone(),
-            claim_period_seconds,
+        env.events().publish(
+            (symbol_short!("ref_set"), EVENT_SCHEMA_VERSION, user.clone()),
+            event,
+        );
+    }
+
+    /// Emit referral earnings withdrawn event.
+    pub fn emit_referral_earnings_withdrawn(env: &Env, referrer: &Address, amount: i128) {
+        let event = ReferralEarningsWithdrawnEvent {
+            referrer: referrer.clone(),
+            amount,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("clm_prd"), &event);
+        env.events().publish(
+            (symbol_short!("ref_wd"), EVENT_SCHEMA_VERSION, referrer.clone()),
+            event,
+        );
     }
 
-    /// Emit market claim period updated event.
-    pub fn emit_market_claim_period_updated(
-        env: &Env,
-        admin: &Address,
-        market_id: &Symbol,
-        claim_period_seconds: u64,
-    ) {
-        let event = MarketClaimPeriodUpdatedEvent {
-            market_id: market_id.clone(),
+    /// Emit market creation fee updated event.
+    pub fn emit_creation_fee_updated(env: &Env, admin: &Address, previous_fee: i128, new_fee: i128) {
+        let event = CreationFeeUpdatedEvent {
             admin: admin.clone(),
-            claim_period_seconds,
+            previous_fee,
+            new_fee,
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("m_clm_pd"), &event);
+        env.events()
+            .publish((symbol_short!("crt_fee"), EVENT_SCHEMA_VERSION), event);
     }
 
-    /// Emit treasury updated event.
-    pub fn emit_treasury_updated(env: &Env, admin: &Address, treasury: &Address) {
-        let event = TreasuryUpdatedEvent {
+    /// Emit market token set event.
+    pub fn emit_market_token_set(env: &Env, market_id: &Symbol, admin: &Address, token: &Address) {
+        let event = MarketTokenSetEvent {
+            market_id: market_id.clone(),
             admin: admin.clone(),
-            treasury: treasury.clone(),
+            token: token.clone(),
             timestamp: env.ledger().timestamp(),
         };
-        Self::store_event(env, &symbol_short!("treas_up"), &event);
+        env.events().publish(
+            (symbol_short!("mkt_token"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
     }
 
     /// Emit unclaimed winnings swept event.
@@ -3133,6 +4858,24 @@ impl EventEmitter {
         Self::store_event(env, &symbol_short!("mkt_cat"), &event);
     }
 
+    /// Emit market metadata updated event
+    pub fn emit_market_metadata_updated(
+        env: &Env,
+        market_id: &Symbol,
+        old_category: &Option<Symbol>,
+        new_category: &Symbol,
+        admin: &Address,
+    ) {
+        let event = MarketMetadataUpdatedEvent {
+            market_id: market_id.clone(),
+            old_category: old_category.clone(),
+            new_category: new_category.clone(),
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("mkt_meta"), &event);
+    }
+
     /// Emit market tags updated event
     ///
     /// This function emits an event when a market's tags are updated,
@@ -3327,6 +5070,121 @@ impl EventEmitter {
         Self::store_event(env, &symbol_short!("up_prop"), &event);
     }
 
+    /// Emit timelocked action scheduled event.
+    pub fn emit_action_scheduled(
+        env: &Env,
+        admin: &Address,
+        pending: &crate::types::PendingAction,
+    ) {
+        let event = ActionScheduledEvent {
+            admin: admin.clone(),
+            action: pending.action.clone(),
+            execute_after: pending.execute_after,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("act_sched"), EVENT_SCHEMA_VERSION, pending.id),
+            event,
+        );
+    }
+
+    /// Emit timelocked action executed event.
+    pub fn emit_action_executed(
+        env: &Env,
+        scheduled_by: &Address,
+        action_id: u64,
+        action: &crate::types::TimelockedAction,
+    ) {
+        let event = ActionExecutedEvent {
+            scheduled_by: scheduled_by.clone(),
+            action: action.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("act_exec"), EVENT_SCHEMA_VERSION, action_id),
+            event,
+        );
+    }
+
+    /// Emit multisig admin operation proposed event.
+    pub fn emit_multisig_op_proposed(env: &Env, proposal: &crate::types::MultisigProposal) {
+        let event = MultisigOpProposedEvent {
+            proposer: proposal.proposer.clone(),
+            op: proposal.op.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("msig_prop"), EVENT_SCHEMA_VERSION, proposal.id),
+            event,
+        );
+    }
+
+    /// Emit multisig admin operation approved event.
+    pub fn emit_multisig_op_approved(env: &Env, op_id: u64, signer: &Address, approvals: u32) {
+        let event = MultisigOpApprovedEvent {
+            signer: signer.clone(),
+            approvals,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("msig_appr"), EVENT_SCHEMA_VERSION, op_id),
+            event,
+        );
+    }
+
+    /// Emit multisig admin operation executed event.
+    pub fn emit_multisig_op_executed(
+        env: &Env,
+        op_id: u64,
+        executor: &Address,
+        op: &crate::types::MultisigOp,
+    ) {
+        let event = MultisigOpExecutedEvent {
+            executor: executor.clone(),
+            op: op.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("msig_exec"), EVENT_SCHEMA_VERSION, op_id),
+            event,
+        );
+    }
+
+    /// Emit timelocked action cancelled event.
+    pub fn emit_action_cancelled(
+        env: &Env,
+        admin: &Address,
+        action_id: u64,
+        action: &crate::types::TimelockedAction,
+    ) {
+        let event = ActionCancelledEvent {
+            admin: admin.clone(),
+            action: action.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("act_cncl"), EVENT_SCHEMA_VERSION, action_id),
+            event,
+        );
+    }
+
+    /// Emit upgrade executed event.
+    pub fn emit_upgrade_executed(
+        env: &Env,
+        admin: &Address,
+        new_wasm_hash: &soroban_sdk::BytesN<32>,
+        previous_version: u32,
+    ) {
+        let event = UpgradeExecutedEvent {
+            admin: admin.clone(),
+            new_wasm_hash: new_wasm_hash.clone(),
+            previous_version,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events()
+            .publish((symbol_short!("upg_exec"), EVENT_SCHEMA_VERSION), event);
+    }
+
     /// Emit balance changed event for deposits and withdrawals
     pub fn emit_balance_changed(
         env: &Env,
@@ -3336,14 +5194,90 @@ impl EventEmitter {
         amount: i128,
         new_balance: i128,
     ) {
+        let event = BalanceChangedEvent {
+            user: user.clone(),
+            asset: asset.clone(),
+            operation: operation.clone(),
+            amount,
+            new_balance,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                symbol_short!("bal_chg"),
+                EVENT_SCHEMA_VERSION,
+                user.clone(),
+                asset.clone(),
+            ),
+            event,
+        );
+    }
+
+    /// Publish a `("resolved", v, market_id)` event with a [`ResolvedEvent`].
+    ///
+    /// Emitted once per resolution, including when an upheld dispute replaces
+    /// the winning outcome.
+    pub fn emit_resolved(
+        env: &Env,
+        market_id: &Symbol,
+        winning_outcomes: &Vec<String>,
+        source: ResolutionSource,
+    ) {
+        let event = ResolvedEvent {
+            market_id: market_id.clone(),
+            winning_outcomes: winning_outcomes.clone(),
+            source,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("resolved"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
+    }
+
+    /// Publish a `("payout", v, market_id, user)` event with the amount paid.
+    pub fn emit_payout(env: &Env, market_id: &Symbol, user: &Address, amount: i128) {
+        let event = PayoutEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
         env.events().publish(
-            (symbol_short!("bal_chg"), user, asset.clone()),
             (
-                operation.clone(),
-                amount,
-                new_balance,
-                env.ledger().timestamp(),
+                symbol_short!("payout"),
+                EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+                user.clone(),
             ),
+            event,
+        );
+    }
+
+    /// Publish a `("refund", v, market_id)` event with the total refundable stake when
+    /// a market resolves to outcome(s) nobody backed and converts to refund mode.
+    pub fn emit_refund_mode(env: &Env, market_id: &Symbol, total_refundable: i128) {
+        let event = RefundModeEvent {
+            market_id: market_id.clone(),
+            total_refundable,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("refund"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
+        );
+    }
+
+    /// Publish a `("cancel", v, market_id)` event with the cancellation reason.
+    pub fn emit_cancelled(env: &Env, market_id: &Symbol, reason: &String) {
+        let event = CancelEvent {
+            market_id: market_id.clone(),
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("cancel"), EVENT_SCHEMA_VERSION, market_id.clone()),
+            event,
         );
     }
 
@@ -3362,9 +5296,34 @@ impl EventEmitter {
         visibility: &crate::types::EventVisibility,
         admin: &Address,
     ) {
+        let event = EventVisibilitySetEvent {
+            event_id: event_id.clone(),
+            visibility: *visibility,
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (symbol_short!("evt_vis"), EVENT_SCHEMA_VERSION, event_id.clone()),
+            event,
+        );
+    }
+
+    /// Emit market creator allowlist updated event
+    pub fn emit_market_creator_updated(
+        env: &Env,
+        creator: &Address,
+        allowed: bool,
+        admin: &Address,
+    ) {
+        let event = MarketCreatorUpdatedEvent {
+            creator: creator.clone(),
+            allowed,
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
         env.events().publish(
-            (symbol_short!("evt_vis"), event_id.clone()),
-            (visibility.clone(), admin.clone(), env.ledger().timestamp()),
+            (symbol_short!("mkt_crtr"), EVENT_SCHEMA_VERSION, creator.clone()),
+            event,
         );
     }
 
@@ -3375,9 +5334,15 @@ impl EventEmitter {
         addresses: &Vec<Address>,
         admin: &Address,
     ) {
+        let event = AllowlistUpdatedEvent {
+            event_id: event_id.clone(),
+            addresses: addresses.clone(),
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
         env.events().publish(
-            (symbol_short!("allowlst"), event_id.clone()),
-            (addresses.clone(), admin.clone(), env.ledger().timestamp()),
+            (symbol_short!("allowlst"), EVENT_SCHEMA_VERSION, event_id.clone()),
+            event,
         );
     }
 }
@@ -3743,6 +5708,8 @@ impl EventTestingUtils {
                 String::from_str(env, "no"),
             ],
             admin: admin.clone(),
+            creator: admin.clone(),
+            creation_fee: 0,
             end_time: env.ledger().timestamp() + 86400,
             timestamp: env.ledger().timestamp(),
         }
@@ -3892,8 +5859,28 @@ pub struct MarketEventSummary {
     pub details: String,
 }
 
+/// Published layout of one event, as listed by `get_events_abi`.
+///
+/// Topics are `(topic, EVENT_SCHEMA_VERSION, ids...)`; literal sub-topics
+/// such as `collect` in `("fees", v, "collect", market_id)` appear in `ids`
+/// as-is.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventAbi {
+    /// First topic
+    pub topic: Symbol,
+    /// Names of the topics following the schema version
+    pub ids: Vec<Symbol>,
+    /// Name of the data struct
+    pub data: Symbol,
+}
+
 // ===== EVENT CONSTANTS =====
 
+/// Version of the published event layout, carried as the second topic of
+/// every event. Bump on any change to a topic or an event data struct.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
 /// Event system constants
 pub const MAX_EVENTS_PER_QUERY: u32 = 100;
 pub const EVENT_RETENTION_DAYS: u64 = 30 * 24 * 60 * 60; // 30 days
@@ -3962,13 +5949,105 @@ impl EventDocumentation {
         docs
     }
 
+    /// Get the topic layout and data struct of every published event.
+    ///
+    /// Must be kept in step with `EVENT_SCHEMA_VERSION`.
+    pub fn get_events_abi(env: &Env) -> Vec<EventAbi> {
+        let layouts: [(&str, &[&str], &str); 70] = [
+            ("bet", &["market_id", "bettor"], "BetPlacedEvent"),
+            ("pos_xfer", &["market_id", "from"], "PositionTransferredEvent"),
+            ("cash_out", &["market_id", "bettor"], "CashedOutEvent"),
+            ("fees", &["collect", "market_id"], "FeeCollectedEvent"),
+            ("fees", &["withdraw", "admin"], "FeeWithdrawnEvent"),
+            ("fwd_att", &["admin"], "FeeWithdrawalAttemptEvent"),
+            ("pool_cap", &["market_id"], "MaxPoolSizeSetEvent"),
+            ("snipe_cfg", &["market_id"], "AntiSnipeSetEvent"),
+            ("snipe_ext", &["market_id", "bettor"], "BetWindowExtendedEvent"),
+            ("user_cap", &["scope"], "UserBetCapUpdatedEvent"),
+            ("contract_initialized", &[], "ContractInitializedEvent"),
+            ("platform_fee_set", &[], "PlatformFeeSetEvent"),
+            ("claim_res", &["market_id", "user"], "ClaimResultEvent"),
+            ("token_up", &[], "TokenUpdatedEvent"),
+            ("fee_bps", &[], "FeeBpsUpdatedEvent"),
+            ("fee_tier", &[], "FeeTiersUpdatedEvent"),
+            ("crt_fee", &[], "CreationFeeUpdatedEvent"),
+            ("crt_bond", &["market_id"], "CreationBondReleasedEvent"),
+            ("mkt_type", &["market_id"], "MarketTypeSetEvent"),
+            ("mkt_acces", &["market_id"], "MarketAccessUpdatedEvent"),
+            ("blacklist", &["address"], "AddressBlacklistedEvent"),
+            ("blk_rel", &["market_id", "address"], "BlockedFundsReleasedEvent"),
+            ("tok_recov", &["token"], "TokenRecoveredEvent"),
+            ("crd_grant", &["user"], "BetCreditEvent"),
+            ("crd_recl", &["user"], "BetCreditEvent"),
+            ("keep_paid", &["market_id", "keeper"], "KeeperPaidEvent"),
+            ("crtr_fee", &["market_id"], "CreatorFeeSetEvent"),
+            ("mkt_fee", &["market_id"], "MarketFeeSetEvent"),
+            ("mkt_orcl", &["market_id"], "MarketOraclesUpdatedEvent"),
+            ("cv_open", &["market_id"], "CommunityVoteOpenedEvent"),
+            ("cv_vote", &["market_id", "voter"], "CommunityVoteCastEvent"),
+            ("cv_final", &["market_id"], "CommunityVoteFinalizedEvent"),
+            ("tmpl_set", &["template_id"], "TemplateSetEvent"),
+            ("series", &["series_id"], "SeriesRolledEvent"),
+            ("rslv_mode", &["market_id"], "ResolveModeSetEvent"),
+            ("rslt_prop", &["market_id"], "ResultProposedEvent"),
+            ("rslt_chal", &["market_id"], "ResultChallengedEvent"),
+            ("crtr_wd", &["creator"], "CreatorFeesWithdrawnEvent"),
+            ("ref_set", &["user"], "ReferrerSetEvent"),
+            ("ref_wd", &["referrer"], "ReferralEarningsWithdrawnEvent"),
+            ("mkt_token", &["market_id"], "MarketTokenSetEvent"),
+            ("act_sched", &["action_id"], "ActionScheduledEvent"),
+            ("act_exec", &["action_id"], "ActionExecutedEvent"),
+            ("act_cncl", &["action_id"], "ActionCancelledEvent"),
+            ("msig_prop", &["op_id"], "MultisigOpProposedEvent"),
+            ("msig_appr", &["op_id"], "MultisigOpApprovedEvent"),
+            ("msig_exec", &["op_id"], "MultisigOpExecutedEvent"),
+            ("upg_exec", &[], "UpgradeExecutedEvent"),
+            ("bal_chg", &["user", "asset"], "BalanceChangedEvent"),
+            ("resolved", &["market_id"], "ResolvedEvent"),
+            ("payout", &["market_id", "user"], "PayoutEvent"),
+            ("refund", &["market_id"], "RefundModeEvent"),
+            ("cancel", &["market_id"], "CancelEvent"),
+            ("evt_vis", &["event_id"], "EventVisibilitySetEvent"),
+            ("mkt_crtr", &["creator"], "MarketCreatorUpdatedEvent"),
+            ("allowlst", &["event_id"], "AllowlistUpdatedEvent"),
+            ("mkt_state", &["market_id"], "MarketStateChangedEvent"),
+            ("mkt_pause", &["market_id"], "MarketPausedEvent"),
+            ("mkt_resum", &["market_id"], "MarketResumedEvent"),
+            ("gas_used", &["operation"], "GasUsedEvent"),
+            ("monitoring_alert", &["alert_id"], "MonitoringAlert"),
+            ("wl_init", &["admin"], "WhitelistAdminEvent"),
+            ("wl_add", &["admin"], "WhitelistAdminEvent"),
+            ("wl_remove", &["admin"], "WhitelistAdminEvent"),
+            ("orcl_add", &["oracle"], "OracleWhitelistedEvent"),
+            ("orcl_rm", &["oracle"], "OracleStatusEvent"),
+            ("orcl_off", &["oracle"], "OracleStatusEvent"),
+            ("orcl_on", &["oracle"], "OracleStatusEvent"),
+            ("orcl_hlth", &["oracle"], "OracleHealthCheckedEvent"),
+            ("recovery", &["market_id"], "RecoveryEvent"),
+        ];
+
+        let mut abi = Vec::new(env);
+        for (topic, ids, data) in layouts.iter() {
+            let mut id_symbols = Vec::new(env);
+            for id in ids.iter() {
+                id_symbols.push_back(Symbol::new(env, id));
+            }
+            abi.push_back(EventAbi {
+                topic: Symbol::new(env, topic),
+                ids: id_symbols,
+                data: Symbol::new(env, data),
+            });
+        }
+        abi
+    }
+
     /// Get usage examples
     pub fn get_usage_examples(env: &Env) -> Map<String, String> {
         let mut examples = Map::new(env);
 
         examples.set(
             String::from_str(env, "EmitMarketCreated"),
-            String::from_str(env, "EventEmitter::emit_market_created(env, market_id, question, outcomes, admin, end_time)"),
+            String::from_str(env, "EventEmitter::emit_market_created(env, market_id, market, creation_fee)"),
         );
         examples.set(
             String::from_str(&env, "EmitVoteCast"),
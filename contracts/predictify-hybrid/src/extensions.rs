@@ -1,6 +1,7 @@
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, String, Symbol, Vec};
 
 use crate::errors::Error;
+use crate::storage::DataKey;
 use crate::types::*;
 
 /// Market extension management system for Predictify Hybrid contract
@@ -222,6 +223,75 @@ impl ExtensionManager {
         Ok(())
     }
 
+    /// Moves an unresolved market's end time forward to `new_end_time`.
+    ///
+    /// Used when the underlying event is postponed. The end time can only
+    /// move later, and the whole extension (rounded up to days) counts against
+    /// the market's `max_extension_days`. A bet deadline set via
+    /// `bet_deadline_mins_before_end` shifts by the same amount so the cutoff
+    /// stays the same distance before the end. Existing bets are untouched.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketResolved` - The market already has a result
+    /// * `Error::MarketClosed` - The original end time has already passed
+    /// * `Error::InvalidExtensionDays` - `new_end_time` is not after the current end time
+    /// * `Error::ExtensionDenied` - The extension exceeds the market's limits
+    pub fn extend_market_to(
+        env: &Env,
+        admin: Address,
+        market_id: Symbol,
+        new_end_time: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        ExtensionValidator::can_extend_market(env, &market_id, &admin)?;
+
+        if market.winning_outcomes.is_some() || market.oracle_result.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() >= market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if new_end_time <= market.end_time {
+            return Err(Error::InvalidExtensionDays);
+        }
+
+        let delta = new_end_time - market.end_time;
+        let additional_days = delta.div_ceil(24 * 60 * 60);
+        if additional_days > MAX_EXTENSION_DAYS as u64 {
+            return Err(Error::ExtensionDenied);
+        }
+        let additional_days = additional_days as u32;
+        ExtensionValidator::check_extension_limits(env, &market_id, additional_days)?;
+
+        let fee_amount = ExtensionUtils::handle_extension_fees(env, &market_id, additional_days)?;
+        let extension =
+            MarketExtension::new(env, additional_days, admin.clone(), reason.clone(), fee_amount);
+
+        let old_end_time = market.end_time;
+        market.end_time = new_end_time;
+        if market.bet_deadline > 0 {
+            market.bet_deadline += delta;
+        }
+        market.total_extension_days += additional_days;
+        market.extension_history.push_back(extension);
+        MarketStateManager::update_market(env, &market_id, &market);
+
+        crate::events::EventEmitter::emit_market_deadline_extended(
+            env,
+            &market_id,
+            old_end_time,
+            new_end_time,
+            additional_days,
+            &admin,
+            &reason,
+            fee_amount,
+        );
+
+        Ok(())
+    }
+
     /// Retrieves the complete extension history for a specific market.
     ///
     /// This function returns a chronological list of all extensions that have been
@@ -553,6 +623,126 @@ impl ExtensionManager {
     }
 }
 
+// ===== ANTI-SNIPING =====
+
+/// Automatic betting deadline extensions against last-second bets.
+///
+/// With an `AntiSnipeConfig` set, a bet of at least `threshold_amount` that
+/// lands within `window_seconds` of the betting deadline moves the deadline
+/// back by `extension_seconds`, until `max_extension_seconds` is used up.
+/// `end_time` follows when the new deadline would pass it.
+pub struct AntiSnipeManager;
+
+impl AntiSnipeManager {
+    /// Anti-sniping settings of a market, if any.
+    pub fn get_config(env: &Env, market_id: &Symbol) -> Option<AntiSnipeConfig> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AntiSnipe(market_id.clone()))
+    }
+
+    /// Seconds anti-sniping has added to the market's betting deadline so far.
+    pub fn get_extended_seconds(env: &Env, market_id: &Symbol) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AntiSnipeExtended(market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Set or clear a market's anti-sniping settings (market creator only,
+    /// before the first bet).
+    pub fn set_config(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+        config: Option<AntiSnipeConfig>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+
+        let key = DataKey::AntiSnipe(market_id.clone());
+        match &config {
+            Some(cfg) => {
+                if cfg.window_seconds == 0
+                    || cfg.extension_seconds == 0
+                    || cfg.threshold_amount <= 0
+                    || cfg.max_extension_seconds < cfg.extension_seconds
+                {
+                    return Err(Error::InvalidInput);
+                }
+                env.storage().persistent().set(&key, cfg);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+
+        crate::events::EventEmitter::emit_anti_snipe_set(env, market_id, creator, &config);
+        Ok(())
+    }
+
+    /// Extend the betting deadline of `market` if a bet of `amount` by `bettor`
+    /// placed now qualifies, and return the seconds added.
+    ///
+    /// Called by bet placement before it stores `market`.
+    pub fn apply(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        bettor: &Address,
+        amount: i128,
+    ) -> u64 {
+        let config = match Self::get_config(env, market_id) {
+            Some(config) => config,
+            None => return 0,
+        };
+        let deadline = market.betting_deadline();
+        let now = env.ledger().timestamp();
+        if amount < config.threshold_amount || deadline.saturating_sub(now) > config.window_seconds
+        {
+            return 0;
+        }
+
+        let extended = Self::get_extended_seconds(env, market_id);
+        let extension = config
+            .extension_seconds
+            .min(config.max_extension_seconds.saturating_sub(extended));
+        if extension == 0 {
+            return 0;
+        }
+
+        let new_deadline = deadline.saturating_add(extension);
+        if market.bet_deadline > 0 {
+            market.bet_deadline = new_deadline;
+        }
+        if market.end_time < new_deadline {
+            market.end_time = new_deadline;
+        }
+        market.resolution_deadline = market.compute_resolution_deadline();
+
+        let extended = extended + extension;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AntiSnipeExtended(market_id.clone()), &extended);
+        crate::events::EventEmitter::emit_bet_window_extended(
+            env,
+            market_id,
+            bettor,
+            amount,
+            new_deadline,
+            extended,
+        );
+        extension
+    }
+}
+
 // ===== EXTENSION VALIDATION =====
 
 /// Extension validation utilities
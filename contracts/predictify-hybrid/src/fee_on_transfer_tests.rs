@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+//! Fee-on-transfer token tests.
+//!
+//! Inbound transfers compare the contract's balance before and after, so a
+//! token that skims part of every transfer cannot leave a bet, deposit or
+//! dispute bond credited for more than the contract actually received.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::ReflectorAsset;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{contract, contractimpl, Address, Env, Map, String, Symbol};
+
+const STAKE: i128 = 10_000_000;
+
+/// Token that burns `skim_bps` of every transfer (1% unless reconfigured).
+#[contract]
+pub struct SkimmingToken;
+
+#[contractimpl]
+impl SkimmingToken {
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "SKIM")
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(&env, to, balance + amount);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        Self::balances(&env).get(id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let skim_bps: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "skim_bps"))
+            .unwrap_or(100);
+        let from_balance = Self::balance(env.clone(), from.clone());
+        Self::set_balance(&env, from, from_balance - amount);
+        let to_balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(&env, to, to_balance + amount - amount * skim_bps / 10_000);
+    }
+
+    pub fn set_skim_bps(env: Env, skim_bps: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "skim_bps"), &skim_bps);
+    }
+
+    fn balances(env: &Env) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, "balances"))
+            .unwrap_or(Map::new(env))
+    }
+
+    fn set_balance(env: &Env, id: Address, amount: i128) {
+        let mut balances = Self::balances(env);
+        balances.set(id, amount);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, "balances"), &balances);
+    }
+}
+
+#[test]
+fn test_bet_in_skimming_market_token_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    let token_id = test.env.register(SkimmingToken, ());
+    let token = SkimmingTokenClient::new(&test.env, &token_id);
+    client.set_market_token(&test.admin, &market_id, &token_id);
+
+    let bettor = test.create_funded_user();
+    token.mint(&bettor, &STAKE);
+    assert_eq!(
+        client.try_place_bet(
+            &bettor,
+            &market_id,
+            &String::from_str(&test.env, "yes"),
+            &STAKE,
+            &None,
+        ),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    // The transfer reverted with the bet; nothing was skimmed or recorded
+    assert_eq!(token.balance(&bettor), STAKE);
+    assert_eq!(token.balance(&test.contract_id), 0);
+    assert_eq!(client.get_market(&market_id).unwrap().total_staked, 0);
+}
+
+#[test]
+fn test_deposit_of_skimming_global_token_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let token_id = test.env.register(SkimmingToken, ());
+    let token = SkimmingTokenClient::new(&test.env, &token_id);
+    test.switch_token(&token_id);
+
+    let user = test.create_funded_user();
+    token.mint(&user, &STAKE);
+    assert_eq!(
+        client.try_deposit(&user, &ReflectorAsset::Stellar, &STAKE),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(client.get_balance(&user, &ReflectorAsset::Stellar).amount, 0);
+    assert_eq!(token.balance(&user), STAKE);
+}
+
+#[test]
+fn test_dispute_bond_in_skimming_token_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    let token_id = test.env.register(SkimmingToken, ());
+    let token = SkimmingTokenClient::new(&test.env, &token_id);
+    client.set_market_token(&test.admin, &market_id, &token_id);
+
+    // Bets go in while the token transfers in full, then it starts skimming
+    token.set_skim_bps(&0);
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    token.mint(&winner, &STAKE);
+    token.mint(&loser, &(STAKE * 2));
+    client.place_bet(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+    assert_eq!(token.balance(&test.contract_id), STAKE * 2);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    token.set_skim_bps(&100);
+    assert_eq!(
+        client.try_dispute_result(
+            &loser,
+            &market_id,
+            &String::from_str(&test.env, "no"),
+            &STAKE,
+        ),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(client.get_result_dispute(&market_id), None);
+    assert_eq!(token.balance(&loser), STAKE);
+}
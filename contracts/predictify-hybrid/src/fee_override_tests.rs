@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+//! Per-market platform fee override tests.
+//!
+//! `set_market_fee_bps` replaces the global fee snapshotted at creation for a
+//! single market. Payouts, fee reservation and `collect_fees` all follow the
+//! override, and a zero override makes a promotional market with no fee.
+
+use crate::config::{DEFAULT_PLATFORM_FEE_BPS, MAX_PLATFORM_FEE_BPS};
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::TimelockedAction;
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{String, Symbol};
+
+/// Create a market with an optional fee override, stake 60 XLM on "yes" and
+/// 40 XLM on "no", and resolve it to "yes" past the dispute window
+fn resolved_market(test: &PredictifyTest, fee_bps: Option<u32>) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    if let Some(bps) = fee_bps {
+        client.set_market_fee_bps(&test.admin, &market_id, &bps);
+    }
+
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.place_bet(&winner, &market_id, &yes, &60_0000000, &None);
+    client.place_bet(&loser, &market_id, &no, &40_0000000, &None);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+    market_id
+}
+
+#[test]
+fn test_market_fee_override_applies_to_payouts_and_collection() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // (override, effective fee, fee on the 100 XLM pool)
+    let cases = [
+        (None, DEFAULT_PLATFORM_FEE_BPS, 2_0000000),
+        (Some(50), 50, 5000000),
+        (Some(0), 0, 0),
+    ];
+    for (fee_override, effective_bps, fee) in cases {
+        let market_id = resolved_market(&test, fee_override);
+        assert_eq!(client.get_market(&market_id).unwrap().fee_bps, effective_bps);
+        assert_eq!(client.get_market_fees(&market_id), (fee, 0));
+
+        let (paid, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+        assert_eq!(paid, 100_0000000 - fee);
+        test.env.mock_all_auths();
+        assert_eq!(client.collect_fees(&test.admin, &market_id), fee);
+    }
+    assert_eq!(client.get_fee_vault_balance(&None), 2_0000000 + 5000000);
+}
+
+#[test]
+fn test_zero_fee_market_collects_nothing_without_error() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = resolved_market(&test, Some(0));
+
+    // Collection is recorded even before payouts, and only once
+    test.env.mock_all_auths();
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 0);
+    assert!(client.get_market(&market_id).unwrap().fee_collected);
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &market_id),
+        Err(Ok(Error::FeeAlreadyCollected))
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), 0);
+}
+
+#[test]
+fn test_market_fee_override_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+
+    assert_eq!(
+        client.try_set_market_fee_bps(&test.admin, &market_id, &(MAX_PLATFORM_FEE_BPS + 1)),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert_eq!(
+        client.try_set_market_fee_bps(&test.user, &market_id, &50),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Raising a market's fee waits out the admin action delay
+    assert_eq!(
+        client.try_set_market_fee_bps(&test.admin, &market_id, &MAX_PLATFORM_FEE_BPS),
+        Err(Ok(Error::FeeIncreaseTimelocked))
+    );
+    let action_id = test.schedule_due(TimelockedAction::SetMarketFeeBps(
+        market_id.clone(),
+        MAX_PLATFORM_FEE_BPS,
+    ));
+    client.execute_action(&action_id);
+
+    // The global fee no longer reaches a market once it is created
+    client.set_fee_bps(&test.admin, &0);
+    assert_eq!(client.get_market(&market_id).unwrap().fee_bps, MAX_PLATFORM_FEE_BPS);
+
+    // Bettors always see the fee before staking
+    let yes = String::from_str(&test.env, "yes");
+    client.place_bet(&test.user, &market_id, &yes, &10_000_000, &None);
+    assert_eq!(
+        client.try_set_market_fee_bps(&test.admin, &market_id, &0),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+}
+
+#[test]
+fn test_scheduled_market_fee_increase_needs_an_empty_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let action_id = test.schedule_due(TimelockedAction::SetMarketFeeBps(market_id.clone(), 500));
+
+    // A bet placed while the increase is pending keeps the fee it was shown
+    let yes = String::from_str(&test.env, "yes");
+    client.place_bet(&test.user, &market_id, &yes, &10_000_000, &None);
+    assert_eq!(
+        client.try_execute_action(&action_id),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().fee_bps,
+        DEFAULT_PLATFORM_FEE_BPS
+    );
+    assert_eq!(client.get_pending_actions().len(), 1);
+}
@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+//! Pool-size fee tier tests.
+//!
+//! `set_fee_tiers` maps pool breakpoints to platform fees. Each market's fee is
+//! fixed from its final pool when it resolves; markets below the first
+//! breakpoint, or with an admin fee override, keep their flat `fee_bps`, and
+//! no tier raises a market above the fee it snapshotted at creation.
+
+use crate::config::DEFAULT_PLATFORM_FEE_BPS;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::TimelockedAction;
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, String, Symbol, Vec};
+
+const XLM: i128 = 1_0000000;
+
+/// 3% from 10 XLM, 1.5% from 100 XLM and 0.5% from 1,000 XLM
+fn three_tiers(test: &PredictifyTest) -> Vec<(i128, u32)> {
+    vec![
+        &test.env,
+        (10 * XLM, 300),
+        (100 * XLM, 150),
+        (1_000 * XLM, 50),
+    ]
+}
+
+/// Create a market, split `pool` evenly between "yes" and "no", and resolve it
+/// to "yes" once payouts unlock
+fn resolved_market(test: &PredictifyTest, pool: i128, fee_override: Option<u32>) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    if let Some(bps) = fee_override {
+        client.set_market_fee_bps(&test.admin, &market_id, &bps);
+    }
+
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.place_bet(&test.create_funded_user(), &market_id, &yes, &(pool / 2), &None);
+    client.place_bet(&test.create_funded_user(), &market_id, &no, &(pool / 2), &None);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+    market_id
+}
+
+#[test]
+fn test_markets_pay_the_fee_of_their_pool_tier() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.set_fee_tiers(&test.admin, &three_tiers(&test));
+    assert_eq!(client.get_fee_tiers(), three_tiers(&test));
+
+    // (pool, fee bps at resolution); below the first breakpoint the flat fee
+    // applies, and the 3% tier is capped at the 2% snapshotted at creation
+    let cases = [
+        (4 * XLM, DEFAULT_PLATFORM_FEE_BPS),
+        (50 * XLM, DEFAULT_PLATFORM_FEE_BPS),
+        (100 * XLM, 150),
+        (1_500 * XLM, 50),
+    ];
+    for (pool, bps) in cases {
+        let market_id = resolved_market(&test, pool, None);
+        assert_eq!(client.get_market(&market_id).unwrap().fee_bps, bps);
+
+        let fee = pool * bps as i128 / 10_000;
+        assert_eq!(client.get_market_fees(&market_id), (fee, 0));
+        let (paid, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+        assert_eq!(paid, pool - fee);
+    }
+}
+
+#[test]
+fn test_fee_tiers_fixed_at_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+
+    // Without tiers the flat fee applies
+    let flat = resolved_market(&test, 200 * XLM, None);
+    assert_eq!(client.get_market(&flat).unwrap().fee_bps, DEFAULT_PLATFORM_FEE_BPS);
+
+    // Tiers set later reach open markets but not resolved ones
+    client.set_fee_tiers(&test.admin, &three_tiers(&test));
+    let tiered = resolved_market(&test, 200 * XLM, None);
+    assert_eq!(client.get_market(&tiered).unwrap().fee_bps, 150);
+    client.set_fee_tiers(&test.admin, &Vec::new(&test.env));
+    assert_eq!(client.get_market(&flat).unwrap().fee_bps, DEFAULT_PLATFORM_FEE_BPS);
+    assert_eq!(client.get_market(&tiered).unwrap().fee_bps, 150);
+
+    // An admin override wins over the tiers
+    client.set_fee_tiers(&test.admin, &three_tiers(&test));
+    let overridden = resolved_market(&test, 200 * XLM, Some(0));
+    assert_eq!(client.get_market(&overridden).unwrap().fee_bps, 0);
+    assert_eq!(client.collect_fees(&test.admin, &overridden), 0);
+}
+
+#[test]
+fn test_set_fee_tiers_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+
+    let invalid = [
+        // Breakpoints not strictly increasing
+        vec![&test.env, (100 * XLM, 150), (100 * XLM, 100)],
+        vec![&test.env, (100 * XLM, 150), (10 * XLM, 100)],
+        // Larger pools paying more
+        vec![&test.env, (10 * XLM, 100), (100 * XLM, 150)],
+        // Out of range
+        vec![&test.env, (-1, 100)],
+        vec![&test.env, (0, 1_001)],
+    ];
+    for tiers in invalid {
+        assert_eq!(
+            client.try_set_fee_tiers(&test.admin, &tiers),
+            Err(Ok(Error::InvalidFeeConfig))
+        );
+    }
+    assert_eq!(
+        client.try_set_fee_tiers(&test.user, &three_tiers(&test)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_fee_tiers(), Vec::new(&test.env));
+
+    // Equal fees across tiers are allowed
+    let flat = vec![&test.env, (0, 100), (100 * XLM, 100)];
+    client.set_fee_tiers(&test.admin, &flat);
+    assert_eq!(client.get_fee_tiers(), flat);
+}
+
+#[test]
+fn test_fee_tiers_never_raise_the_snapshotted_fee() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+
+    // Tiers above the 2% flat fee apply at once but cannot charge more than it
+    client.set_fee_tiers(&test.admin, &vec![&test.env, (0, 1_000), (100 * XLM, 500)]);
+    for pool in [10 * XLM, 200 * XLM] {
+        let market_id = resolved_market(&test, pool, None);
+        assert_eq!(
+            client.get_market(&market_id).unwrap().fee_bps,
+            DEFAULT_PLATFORM_FEE_BPS
+        );
+    }
+
+    // A market created after a scheduled increase snapshots the higher fee
+    client.execute_action(&test.schedule_due(TimelockedAction::SetFeeBps(800)));
+    let market_id = resolved_market(&test, 200 * XLM, None);
+    assert_eq!(client.get_market(&market_id).unwrap().fee_bps, 500);
+}
@@ -1,7 +1,10 @@
 use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, Map, String, Symbol, Vec};
 
+use crate::amounts;
+use crate::config::ConfigManager;
 use crate::errors::Error;
 use crate::markets::{MarketStateManager, MarketUtils};
+use crate::storage::DataKey;
 use crate::types::Market;
 
 /// Fee management system for Predictify Hybrid contract
@@ -715,19 +718,48 @@ pub struct FeeManager;
 
 impl FeeManager {
     /// Collect platform fees from a market
+    ///
+    /// Collects the fee reserved when the market resolved, exactly once: a second
+    /// call fails with `FeeAlreadyCollected`, and unresolved or cancelled markets are
+    /// rejected. Payouts already leave the fee in the contract, so this may run
+    /// before or after `distribute_payouts`. Markets settling in refund mode
+    /// collect `0`.
     pub fn collect_fees(env: &Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
         // Require authentication from the admin
         admin.require_auth();
 
-        // Validate admin permissions
-        FeeValidator::validate_admin_permissions(env, &admin)?;
+        // Validate fee manager role (the admin holds every role)
+        crate::admin::RoleManager::require_role(env, crate::admin::Role::FeeManager, &admin)?;
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
+        if market.state.is_refunding() {
+            return Err(Error::MarketClosed);
+        }
         FeeValidator::validate_market_for_fee_collection(&market)?;
 
-        // Calculate fee amount
-        let fee_amount = FeeCalculator::calculate_platform_fee(&market)?;
+        // Markets whose winning outcome nobody backed refund every stake in full,
+        // and a fee released in full to exempt winners or referrers leaves nothing,
+        // as does a zero-fee promotional market
+        let (accrued, _) = FeeTracker::get_market_fees(env, &market_id);
+        let fully_released = accrued == 0 && FeeTracker::is_market_fee_reserved(env, &market_id);
+        if crate::markets::MarketUtils::is_refund_mode(&market)
+            || fully_released
+            || market.fee_bps == 0
+        {
+            FeeTracker::record_market_fee_collected(env, &market_id, 0, 0);
+            MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
+            MarketStateManager::update_market(env, &market_id, &market);
+            return Ok(0);
+        }
+
+        // Collect the fee reserved at resolution (markets resolved before
+        // reservation existed fall back to the current calculation)
+        let fee_amount = if accrued > 0 {
+            accrued
+        } else {
+            FeeCalculator::calculate_platform_fee(&market)?
+        };
 
         // Validate fee amount
         FeeValidator::validate_fee_amount(fee_amount)?;
@@ -737,7 +769,10 @@ impl FeeManager {
         // NOTE: This intentionally does NOT transfer fees out of the contract.
         // Fees remain in the contract and must be withdrawn via the admin
         // fee withdrawal function which enforces a timelock/schedule.
-        FeeTracker::record_fee_collection(env, &market_id, fee_amount, &admin)?;
+        crate::escrow::EscrowManager::debit(env, &market_id, fee_amount)?;
+        FeeTracker::record_fee_collection(env, &market_id, fee_amount, &admin, &market.token)?;
+        FeeTracker::record_market_fee_collected(env, &market_id, fee_amount, fee_amount);
+        crate::statistics::StatisticsManager::record_platform_fees_collected(env, fee_amount);
 
         // Mark fees as collected
         MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
@@ -755,6 +790,561 @@ impl FeeManager {
         Ok(fee_amount)
     }
 
+    /// Get the global platform fee in basis points.
+    ///
+    /// Falls back to the legacy `platform_fee` entry, a whole percentage written
+    /// by `initialize`, for instances configured before the dedicated key
+    /// existed, then to the default.
+    pub fn get_fee_bps(env: &Env) -> u32 {
+        let key = Symbol::new(env, crate::config::FEE_BPS_STORAGE_KEY);
+        if let Some(bps) = env.storage().instance().get::<Symbol, u32>(&key) {
+            return bps;
+        }
+        let legacy: Option<i128> = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, "platform_fee"));
+        match legacy {
+            Some(percentage)
+                if (crate::config::MIN_PLATFORM_FEE_PERCENTAGE
+                    ..=crate::config::MAX_PLATFORM_FEE_PERCENTAGE)
+                    .contains(&percentage) =>
+            {
+                (percentage * 100) as u32
+            }
+            _ => crate::config::DEFAULT_PLATFORM_FEE_BPS,
+        }
+    }
+
+    /// Set the global platform fee in basis points (admin only).
+    ///
+    /// Only markets created afterwards are affected; existing markets keep the
+    /// fee snapshotted at their creation. Only a cut applies at once; an
+    /// increase is scheduled as `TimelockedAction::SetFeeBps`
+    /// (`FeeIncreaseTimelocked`).
+    pub fn set_fee_bps(env: &Env, admin: &Address, bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        if bps > crate::config::MAX_PLATFORM_FEE_BPS {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        // Increases must wait out the admin action delay
+        if bps > Self::get_fee_bps(env) {
+            return Err(Error::FeeIncreaseTimelocked);
+        }
+
+        Self::apply_fee_bps(env, admin, bps);
+        Ok(())
+    }
+
+    /// Store the global platform fee and announce the change; callers are
+    /// responsible for authorization and validation.
+    pub(crate) fn apply_fee_bps(env: &Env, admin: &Address, bps: u32) {
+        let previous_bps = Self::get_fee_bps(env);
+        Self::store_fee_bps(env, bps);
+
+        crate::events::EventEmitter::emit_fee_bps_updated(env, admin, previous_bps, bps);
+    }
+
+    /// Pool-size fee tiers as `(pool breakpoint, bps)`, ordered by breakpoint.
+    /// Empty when none are set.
+    pub fn get_fee_tiers(env: &Env) -> Vec<(i128, u32)> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::FEE_TIERS_STORAGE_KEY))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Set the pool-size fee tiers (admin only); an empty list clears them.
+    ///
+    /// A market whose final pool reaches a tier's breakpoint is charged that
+    /// tier's fee instead of its flat `fee_bps`, fixed when it resolves. Tiers
+    /// only ever lower a market's fee, never above the rate snapshotted at
+    /// creation, so they apply at once without the admin action timelock.
+    /// Breakpoints must be non-negative and strictly increasing, and fees
+    /// non-increasing and within `MAX_PLATFORM_FEE_BPS`, so larger pools never
+    /// pay a higher rate.
+    pub fn set_fee_tiers(
+        env: &Env,
+        admin: &Address,
+        tiers: &Vec<(i128, u32)>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        let mut previous: Option<(i128, u32)> = None;
+        for (breakpoint, bps) in tiers.iter() {
+            if breakpoint < 0 || bps > crate::config::MAX_PLATFORM_FEE_BPS {
+                return Err(Error::InvalidFeeConfig);
+            }
+            if let Some((last_breakpoint, last_bps)) = previous {
+                if breakpoint <= last_breakpoint || bps > last_bps {
+                    return Err(Error::InvalidFeeConfig);
+                }
+            }
+            previous = Some((breakpoint, bps));
+        }
+
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, crate::config::FEE_TIERS_STORAGE_KEY), tiers);
+        crate::events::EventEmitter::emit_fee_tiers_updated(env, admin, tiers);
+        Ok(())
+    }
+
+    /// Apply the fee tier matching `market`'s final pool to its `fee_bps`.
+    ///
+    /// Markets below the first breakpoint, or with an admin fee override, keep
+    /// their fee, and a tier above the snapshotted fee is capped at it. Returns
+    /// whether the fee changed.
+    pub fn apply_fee_tier(env: &Env, market_id: &Symbol, market: &mut Market) -> bool {
+        if Self::has_market_fee_override(env, market_id) {
+            return false;
+        }
+        let tier_bps = Self::get_fee_tiers(env)
+            .iter()
+            .filter(|(breakpoint, _)| market.total_staked >= *breakpoint)
+            .last()
+            .map(|(_, bps)| bps);
+        match tier_bps {
+            Some(bps) if bps < market.fee_bps => {
+                market.fee_bps = bps;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the admin overrode `market_id`'s platform fee.
+    pub fn has_market_fee_override(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(symbol_short!("fee_ovr"), market_id.clone()))
+    }
+
+    /// Persist the global platform fee without authorization checks.
+    pub(crate) fn store_fee_bps(env: &Env, bps: u32) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, crate::config::FEE_BPS_STORAGE_KEY), &bps);
+    }
+
+    /// Share of a market's platform fee, in basis points, paid to whoever resolves
+    /// it through `keeper_resolve`. Defaults to `0` (no incentive).
+    pub fn get_keeper_bps(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::KEEPER_BPS_STORAGE_KEY))
+            .unwrap_or(0)
+    }
+
+    /// Set the keeper incentive in basis points of the platform fee (admin only).
+    pub fn set_keeper_bps(env: &Env, admin: &Address, bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        if bps > 10_000 {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, crate::config::KEEPER_BPS_STORAGE_KEY), &bps);
+        Ok(())
+    }
+
+    /// Whether `addr` is exempt from the platform fee on its winnings.
+    pub fn is_fee_exempt(env: &Env, addr: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("fee_exmpt"), addr.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Add or remove `addr` from the platform fee exemption list (admin only).
+    pub fn set_fee_exempt(
+        env: &Env,
+        admin: &Address,
+        addr: &Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        let key = (symbol_short!("fee_exmpt"), addr.clone());
+        if exempt {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Pay `keeper` its share of a resolved market's platform fee out of the fee
+    /// vault of the market's token.
+    ///
+    /// The payout is capped at the vault balance, so an empty vault pays nothing
+    /// instead of failing the resolution. Returns the amount transferred.
+    pub fn pay_keeper_incentive(
+        env: &Env,
+        keeper: &Address,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> Result<i128, Error> {
+        let bps = Self::get_keeper_bps(env);
+        if bps == 0 {
+            return Ok(0);
+        }
+
+        let (accrued, _) = FeeTracker::get_market_fees(env, market_id);
+        let incentive = amounts::mul_div(accrued, bps as i128, 10_000)?;
+        Self::pay_keeper(env, keeper, market_id, market, incentive)
+    }
+
+    /// Reward paid to keepers that settle overdue markets. Defaults to nothing.
+    pub fn get_keeper_reward(env: &Env) -> crate::types::KeeperReward {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::KEEPER_REWARD_STORAGE_KEY))
+            .unwrap_or(crate::types::KeeperReward::Flat(0))
+    }
+
+    /// Set the reward paid to keepers that settle overdue markets (admin only).
+    pub fn set_keeper_reward(
+        env: &Env,
+        admin: &Address,
+        reward: &crate::types::KeeperReward,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        let valid = match *reward {
+            crate::types::KeeperReward::Flat(amount) => {
+                (0..=ConfigManager::to_token_units(env, MAX_FEE_AMOUNT)).contains(&amount)
+            }
+            crate::types::KeeperReward::Bps(bps) => bps <= 10_000,
+        };
+        if !valid {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, crate::config::KEEPER_REWARD_STORAGE_KEY), reward);
+        Ok(())
+    }
+
+    /// Pay `keeper` the settlement reward for performing `action` on a market.
+    ///
+    /// Each action pays at most once per market, so repeating it earns nothing,
+    /// and the reward is capped at the fee vault balance. Callers invoke this
+    /// only when the action changed the market's state. Returns the amount paid.
+    pub fn pay_keeper_reward(
+        env: &Env,
+        keeper: &Address,
+        market_id: &Symbol,
+        market: &Market,
+        action: Symbol,
+    ) -> Result<i128, Error> {
+        let key = (symbol_short!("keep_rwd"), market_id.clone(), action);
+        if env.storage().persistent().has(&key) {
+            return Ok(0);
+        }
+        env.storage().persistent().set(&key, &true);
+
+        let reward = match Self::get_keeper_reward(env) {
+            crate::types::KeeperReward::Flat(amount) => amount,
+            crate::types::KeeperReward::Bps(bps) => {
+                let (accrued, _) = FeeTracker::get_market_fees(env, market_id);
+                amounts::mul_div(accrued, bps as i128, 10_000)?
+            }
+        };
+        Self::pay_keeper(env, keeper, market_id, market, reward)
+    }
+
+    /// Transfer up to `amount` from the fee vault of `market`'s token to `keeper`.
+    ///
+    /// The payout is capped at the vault balance, so an empty vault pays nothing
+    /// instead of failing the keeper's action. A blocked keeper is paid nothing.
+    fn pay_keeper(
+        env: &Env,
+        keeper: &Address,
+        market_id: &Symbol,
+        market: &Market,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        let vault_balance = FeeTracker::get_fee_vault_balance(env, market.token.as_ref());
+        let amount = amount.min(vault_balance);
+        if amount <= 0 || crate::admin::ComplianceManager::is_blocked(env, keeper) {
+            return Ok(0);
+        }
+
+        FeeTracker::debit_fee_vault(env, market.token.as_ref(), amount)?;
+        let token_client = MarketUtils::get_market_token_client(env, market)?;
+        token_client.transfer(&env.current_contract_address(), keeper, &amount);
+
+        crate::events::EventEmitter::emit_keeper_paid(env, market_id, keeper, amount);
+        Ok(amount)
+    }
+
+    /// Get the flat fee non-admin creators pay per market, in base units of the
+    /// staking token. Defaults to `DEFAULT_MARKET_CREATION_FEE` (one whole token).
+    pub fn get_creation_fee(env: &Env) -> i128 {
+        ConfigManager::to_token_units(env, Self::get_configured_creation_fee(env))
+    }
+
+    /// The creation fee as configured, at `DEFAULT_TOKEN_DECIMALS`
+    fn get_configured_creation_fee(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::CREATION_FEE_STORAGE_KEY))
+            .unwrap_or(crate::config::DEFAULT_MARKET_CREATION_FEE)
+    }
+
+    /// Set the market creation fee at `DEFAULT_TOKEN_DECIMALS` (admin only).
+    /// `0` disables the fee.
+    pub fn set_creation_fee(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        if !(0..=MAX_FEE_AMOUNT).contains(&amount) {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        let previous = Self::get_configured_creation_fee(env);
+        env.storage().instance().set(
+            &Symbol::new(env, crate::config::CREATION_FEE_STORAGE_KEY),
+            &amount,
+        );
+
+        crate::events::EventEmitter::emit_creation_fee_updated(env, admin, previous, amount);
+        Ok(())
+    }
+
+    /// Charge `creator` the market creation fee into the global fee vault.
+    ///
+    /// Checks the balance up front so an underfunded creator gets
+    /// `InsufficientBalance` rather than a failed transfer.
+    pub fn charge_market_creation_fee(env: &Env, creator: &Address) -> Result<i128, Error> {
+        let fee = Self::get_creation_fee(env);
+        if fee == 0 {
+            return Ok(0);
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        if token_client.balance(creator) < fee {
+            return Err(Error::InsufficientBalance);
+        }
+        MarketUtils::receive_tokens(env, &token_client, creator, fee)?;
+
+        FeeTracker::credit_fee_vault(env, None, fee)?;
+        FeeTracker::record_creation_fee(env, creator, fee)?;
+        Ok(fee)
+    }
+
+    /// Get the refundable bond non-admin creators post per market, in base units
+    /// of the staking token. Defaults to `DEFAULT_CREATION_BOND`.
+    pub fn get_creation_bond(env: &Env) -> i128 {
+        let bond = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::CREATION_BOND_STORAGE_KEY))
+            .unwrap_or(crate::config::DEFAULT_CREATION_BOND);
+        ConfigManager::to_token_units(env, bond)
+    }
+
+    /// Set the market creation bond at `DEFAULT_TOKEN_DECIMALS` (admin only).
+    /// `0` disables the bond. Bonds already posted keep their amount.
+    pub fn set_creation_bond(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        if !(0..=MAX_FEE_AMOUNT).contains(&amount) {
+            return Err(Error::InvalidFeeConfig);
+        }
+        env.storage().instance().set(
+            &Symbol::new(env, crate::config::CREATION_BOND_STORAGE_KEY),
+            &amount,
+        );
+        Ok(())
+    }
+
+    /// Take the creation bond from `creator` and hold it against `market_id`.
+    ///
+    /// Checks the balance up front so an underfunded creator gets
+    /// `InsufficientBalance` rather than a failed transfer.
+    pub fn post_creation_bond(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+    ) -> Result<i128, Error> {
+        let bond = Self::get_creation_bond(env);
+        if bond == 0 {
+            return Ok(0);
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        if token_client.balance(creator) < bond {
+            return Err(Error::InsufficientBalance);
+        }
+        MarketUtils::receive_tokens(env, &token_client, creator, bond)?;
+
+        let key = (symbol_short!("crt_bond"), market_id.clone());
+        env.storage().persistent().set(&key, &bond);
+        Ok(bond)
+    }
+
+    /// Creation bond still held against a market (0 once released)
+    pub fn get_market_creation_bond(env: &Env, market_id: &Symbol) -> i128 {
+        let key = (symbol_short!("crt_bond"), market_id.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Release a market's creation bond: back to its creator, or into the
+    /// global fee vault when `slash` is set. A no-op once released.
+    pub fn release_creation_bond(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        slash: bool,
+    ) -> Result<(), Error> {
+        let bond = Self::get_market_creation_bond(env, market_id);
+        if bond == 0 {
+            return Ok(());
+        }
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("crt_bond"), market_id.clone()));
+
+        if slash {
+            FeeTracker::credit_fee_vault(env, None, bond)?;
+        } else {
+            let token_client = MarketUtils::get_token_client(env)?;
+            crate::reentrancy_guard::ReentrancyGuard::guarded(env, || {
+                token_client.transfer(&env.current_contract_address(), creator, &bond)
+            })
+            .map_err(|_| Error::InvalidState)?;
+        }
+
+        crate::events::EventEmitter::emit_creation_bond_released(
+            env, market_id, creator, bond, slash,
+        );
+        Ok(())
+    }
+
+    /// Override a market's platform fee in basis points (admin only).
+    ///
+    /// Replaces the global fee snapshotted at creation, up to the same
+    /// `MAX_PLATFORM_FEE_BPS` cap. Zero makes a promotional market that pays
+    /// winners without any platform fee. Fee tiers do not apply to overridden
+    /// markets. Only while the market is active with an empty pool, so bettors
+    /// always see the fee before staking. Only a cut applies at once; raising
+    /// the fee above the market's current `fee_bps` is scheduled as
+    /// `TimelockedAction::SetMarketFeeBps` (`FeeIncreaseTimelocked`).
+    pub fn set_market_fee_bps(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        bps: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if bps > crate::config::MAX_PLATFORM_FEE_BPS {
+            return Err(Error::InvalidFeeConfig);
+        }
+        // Increases must wait out the admin action delay
+        if bps > market.fee_bps {
+            return Err(Error::FeeIncreaseTimelocked);
+        }
+        Self::apply_market_fee_bps(env, admin, market_id, bps)
+    }
+
+    /// Store a market's fee override and announce it once the market is still
+    /// active with an empty pool; callers are responsible for authorization and
+    /// the fee cap.
+    pub(crate) fn apply_market_fee_bps(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        bps: u32,
+    ) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != crate::types::MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+
+        let previous_bps = market.fee_bps;
+        market.fee_bps = bps;
+        MarketStateManager::update_market(env, market_id, &market);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("fee_ovr"), market_id.clone()), &true);
+
+        crate::events::EventEmitter::emit_market_fee_set(env, market_id, admin, previous_bps, bps);
+        Ok(())
+    }
+
+    /// Set a market's creator fee in basis points (market creator only).
+    ///
+    /// Only while the market is active with an empty pool, so bettors always see the
+    /// fee before staking. Creator fees accrue in the global staking token, so markets
+    /// with their own `token` cannot carry one.
+    pub fn set_creator_fee_bps(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+        bps: u32,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if bps > crate::config::MAX_CREATOR_FEE_BPS {
+            return Err(Error::InvalidFeeConfig);
+        }
+        if market.state != crate::types::MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if market.token.is_some() {
+            return Err(Error::InvalidState);
+        }
+
+        market.creator_fee_bps = bps;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        crate::events::EventEmitter::emit_creator_fee_set(env, market_id, creator, bps);
+        Ok(())
+    }
+
+    /// Pay out every creator fee accrued to `creator` and return the amount.
+    pub fn withdraw_creator_fees(env: &Env, creator: &Address) -> Result<i128, Error> {
+        creator.require_auth();
+        crate::admin::ComplianceManager::require_not_blocked(env, creator)?;
+
+        let amount = FeeTracker::take_creator_fees(env, creator);
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        token_client.transfer(&env.current_contract_address(), creator, &amount);
+
+        crate::events::EventEmitter::emit_creator_fees_withdrawn(env, creator, amount);
+        Ok(amount)
+    }
+
     /// Process market/event creation fee and return the charged amount.
     pub fn process_creation_fee(env: &Env, admin: &Address) -> Result<i128, Error> {
         // Read configured fee (fallback to default constant if config is missing)
@@ -775,9 +1365,12 @@ impl FeeManager {
 
         // Get token client
         let token_client = MarketUtils::get_token_client(env)?;
+        if token_client.balance(admin) < creation_fee {
+            return Err(Error::InsufficientBalance);
+        }
 
         // Transfer creation fee from admin to contract
-        token_client.transfer(admin, &env.current_contract_address(), &creation_fee);
+        MarketUtils::receive_tokens(env, &token_client, admin, creation_fee)?;
 
         // Record creation fee
         FeeTracker::record_creation_fee(env, admin, creation_fee)?;
@@ -881,10 +1474,10 @@ impl FeeCalculator {
     /// Calculate platform fee for a market
     pub fn calculate_platform_fee(market: &Market) -> Result<i128, Error> {
         if market.total_staked == 0 {
-            return Err(Error::NoFeesToCollect);
+            return Err(Error::NothingToClaim);
         }
 
-        let fee_amount = (market.total_staked * PLATFORM_FEE_PERCENTAGE) / 100;
+        let fee_amount = amounts::mul_div(market.total_staked, market.fee_bps as i128, 10_000)?;
 
         if fee_amount < MIN_FEE_AMOUNT {
             return Err(Error::InsufficientStake);
@@ -903,8 +1496,8 @@ impl FeeCalculator {
             return Err(Error::NothingToClaim);
         }
 
-        let user_share = (user_stake * (100 - PLATFORM_FEE_PERCENTAGE)) / 100;
-        let payout = (user_share * total_pool) / winning_total;
+        let user_share = amounts::mul_div(user_stake, 100 - PLATFORM_FEE_PERCENTAGE, 100)?;
+        let payout = amounts::mul_div(user_share, total_pool, winning_total)?;
 
         Ok(payout)
     }
@@ -915,7 +1508,7 @@ impl FeeCalculator {
         let fee_percentage = PLATFORM_FEE_PERCENTAGE;
         let fee_amount = Self::calculate_platform_fee(market)?;
         let platform_fee = fee_amount;
-        let user_payout_amount = total_staked - fee_amount;
+        let user_payout_amount = amounts::checked_sub(total_staked, fee_amount)?;
 
         Ok(FeeBreakdown {
             total_staked,
@@ -939,7 +1532,7 @@ impl FeeCalculator {
             100 // No adjustment for small markets
         };
 
-        let adjusted_fee = (base_fee * size_multiplier) / 100;
+        let adjusted_fee = amounts::mul_div(base_fee, size_multiplier, 100)?;
 
         // Ensure minimum fee
         if adjusted_fee < MIN_FEE_AMOUNT {
@@ -1078,7 +1671,7 @@ impl FeeCalculator {
         let tier = Self::get_fee_tier_by_market_size(env, market.total_staked)?;
 
         // Calculate activity level
-        let vote_count = market.votes.len() as u32;
+        let vote_count = market.position_count();
         let activity_level = if vote_count >= ACTIVITY_LEVEL_HIGH {
             String::from_str(env, "High")
         } else if vote_count >= ACTIVITY_LEVEL_MEDIUM {
@@ -1151,7 +1744,7 @@ impl FeeValidator {
     /// Validate admin permissions
     pub fn validate_admin_permissions(env: &Env, admin: &Address) -> Result<(), Error> {
         let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+            env.storage().instance().get(&DataKey::Admin);
 
         match stored_admin {
             Some(stored_admin) => {
@@ -1176,8 +1769,8 @@ impl FeeValidator {
             return Err(Error::FeeAlreadyCollected);
         }
 
-        // Check if there are sufficient stakes
-        if market.total_staked < FEE_COLLECTION_THRESHOLD {
+        // Check if there are sufficient stakes (zero-fee markets owe nothing)
+        if market.fee_bps > 0 && market.total_staked < FEE_COLLECTION_THRESHOLD {
             return Err(Error::InsufficientStake);
         }
 
@@ -1326,11 +1919,15 @@ pub struct FeeTracker;
 
 impl FeeTracker {
     /// Record fee collection
+    ///
+    /// Fees from markets on the global token accrue to the main fee vault; fees
+    /// from markets with their own `token` accrue to that token's vault.
     pub fn record_fee_collection(
         env: &Env,
         market_id: &Symbol,
         amount: i128,
         admin: &Address,
+        token: &Option<Address>,
     ) -> Result<(), Error> {
         let collection = FeeCollection {
             market_id: market_id.clone(),
@@ -1351,17 +1948,195 @@ impl FeeTracker {
         history.push_back(collection);
         env.storage().persistent().set(&history_key, &history);
 
-        // Update total fees collected
-        let total_key = symbol_short!("tot_fees");
-        let current_total: i128 = env.storage().persistent().get(&total_key).unwrap_or(0);
+        // Update total fees collected for the market's token
+        let current_total = Self::get_fee_vault_balance(env, token.as_ref());
+        let new_total = current_total.checked_add(amount).ok_or(Error::InvalidInput)?;
+        Self::set_fee_vault_balance(env, token.as_ref(), new_total);
 
-        env.storage()
+        Ok(())
+    }
+
+    /// Get the fee vault balance for `token` (`None` = global staking token).
+    pub fn get_fee_vault_balance(env: &Env, token: Option<&Address>) -> i128 {
+        match token {
+            Some(token_id) => {
+                let vaults: Map<Address, i128> = env
+                    .storage()
+                    .persistent()
+                    .get(&TOKEN_FEE_VAULTS_KEY)
+                    .unwrap_or(Map::new(env));
+                vaults.get(token_id.clone()).unwrap_or(0)
+            }
+            None => env.storage().persistent().get(&DataKey::FeeVault).unwrap_or(0),
+        }
+    }
+
+    /// Add `amount` to the fee vault for `token` (`None` = global staking token).
+    pub fn credit_fee_vault(
+        env: &Env,
+        token: Option<&Address>,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let balance = Self::get_fee_vault_balance(env, token);
+        let new_balance = amounts::checked_add(balance, amount)?;
+        Self::set_fee_vault_balance(env, token, new_balance);
+        Ok(())
+    }
+
+    /// Remove `amount` from the fee vault for `token` (`None` = global staking token).
+    pub fn debit_fee_vault(env: &Env, token: Option<&Address>, amount: i128) -> Result<(), Error> {
+        let balance = Self::get_fee_vault_balance(env, token);
+        amounts::require_non_negative(amount)?;
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+        Self::set_fee_vault_balance(env, token, balance - amount);
+        Ok(())
+    }
+
+    /// Set the fee vault balance for `token` (`None` = global staking token).
+    fn set_fee_vault_balance(env: &Env, token: Option<&Address>, amount: i128) {
+        match token {
+            Some(token_id) => {
+                let mut vaults: Map<Address, i128> = env
+                    .storage()
+                    .persistent()
+                    .get(&TOKEN_FEE_VAULTS_KEY)
+                    .unwrap_or(Map::new(env));
+                vaults.set(token_id.clone(), amount);
+                env.storage().persistent().set(&TOKEN_FEE_VAULTS_KEY, &vaults);
+            }
+            None => env.storage().persistent().set(&DataKey::FeeVault, &amount),
+        }
+    }
+
+    /// Platform fee a resolved market owes: `fee_bps` of its pool, or nothing
+    /// in refund mode.
+    pub fn market_fee_amount(market: &Market) -> i128 {
+        if MarketUtils::is_refund_mode(market) {
+            0
+        } else {
+            // fee_bps <= 10_000, so the fee never exceeds the pool and cannot overflow
+            amounts::mul_div(market.total_staked, market.fee_bps as i128, 10_000).unwrap_or(0)
+        }
+    }
+
+    /// Reserve the platform fee of a resolved market.
+    ///
+    /// Winning payouts already deduct `fee_bps`, so the reserved amount is exactly
+    /// what they leave behind; refund mode reserves nothing. Called on every
+    /// resolution, including overturned disputes, until the fee is collected.
+    pub fn reserve_market_fee(env: &Env, market_id: &Symbol, market: &Market) {
+        if market.fee_collected {
+            return;
+        }
+        let mut fees: Map<Symbol, (i128, i128)> = env
+            .storage()
             .persistent()
-            .set(&total_key, &(current_total + amount));
+            .get(&MARKET_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        fees.set(market_id.clone(), (Self::market_fee_amount(market), 0));
+        env.storage().persistent().set(&MARKET_FEES_KEY, &fees);
+    }
+
+    /// `(accrued, collected)` platform fees for a market. Both are zero until the
+    /// market is resolved.
+    pub fn get_market_fees(env: &Env, market_id: &Symbol) -> (i128, i128) {
+        let fees: Map<Symbol, (i128, i128)> = env
+            .storage()
+            .persistent()
+            .get(&MARKET_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        fees.get(market_id.clone()).unwrap_or((0, 0))
+    }
+
+    /// Whether a market's platform fee was reserved at resolution.
+    pub fn is_market_fee_reserved(env: &Env, market_id: &Symbol) -> bool {
+        let fees: Map<Symbol, (i128, i128)> = env
+            .storage()
+            .persistent()
+            .get(&MARKET_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        fees.contains_key(market_id.clone())
+    }
+
+    /// Release up to `amount` of a market's platform fee, to a fee-exempt winner
+    /// or a referrer, and return the amount released.
+    ///
+    /// The released amount comes off the reserved fee, or off the fee vault when
+    /// the fee was already collected, in which case it returns to the market's
+    /// escrow for the caller to pay out.
+    pub fn release_market_fee(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Ok(0);
+        }
+        let (accrued, collected) = Self::get_market_fees(env, market_id);
+        let amount = amount.min(accrued);
+        if market.fee_collected {
+            Self::debit_fee_vault(env, market.token.as_ref(), amount)?;
+            crate::escrow::EscrowManager::credit(env, market_id, amount)?;
+            Self::record_market_fee_collected(env, market_id, accrued - amount, collected - amount);
+        } else {
+            Self::record_market_fee_collected(env, market_id, accrued - amount, collected);
+        }
+        Ok(amount)
+    }
 
+    /// Record `amount` as the collected platform fee of a market.
+    fn record_market_fee_collected(env: &Env, market_id: &Symbol, accrued: i128, amount: i128) {
+        let mut fees: Map<Symbol, (i128, i128)> = env
+            .storage()
+            .persistent()
+            .get(&MARKET_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        fees.set(market_id.clone(), (accrued, amount));
+        env.storage().persistent().set(&MARKET_FEES_KEY, &fees);
+    }
+
+    /// Creator fees accrued to `creator` and not yet withdrawn (global staking token).
+    pub fn get_creator_fees(env: &Env, creator: &Address) -> i128 {
+        let fees: Map<Address, i128> = env
+            .storage()
+            .persistent()
+            .get(&CREATOR_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        fees.get(creator.clone()).unwrap_or(0)
+    }
+
+    /// Add `amount` to the creator fees accrued to `creator`.
+    pub fn credit_creator_fees(env: &Env, creator: &Address, amount: i128) -> Result<(), Error> {
+        let mut fees: Map<Address, i128> = env
+            .storage()
+            .persistent()
+            .get(&CREATOR_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        let balance = fees.get(creator.clone()).unwrap_or(0);
+        let new_balance = amounts::checked_add(balance, amount)?;
+        fees.set(creator.clone(), new_balance);
+        env.storage().persistent().set(&CREATOR_FEES_KEY, &fees);
         Ok(())
     }
 
+    /// Clear the creator fees accrued to `creator`, returning the previous balance.
+    pub fn take_creator_fees(env: &Env, creator: &Address) -> i128 {
+        let mut fees: Map<Address, i128> = env
+            .storage()
+            .persistent()
+            .get(&CREATOR_FEES_KEY)
+            .unwrap_or(Map::new(env));
+        let balance = fees.get(creator.clone()).unwrap_or(0);
+        if balance != 0 {
+            fees.remove(creator.clone());
+            env.storage().persistent().set(&CREATOR_FEES_KEY, &fees);
+        }
+        balance
+    }
+
     /// Record creation fee
 
     pub fn record_creation_fee(env: &Env, _admin: &Address, amount: i128) -> Result<(), Error> {
@@ -1371,7 +2146,7 @@ impl FeeTracker {
 
         env.storage()
             .persistent()
-            .set(&creation_key, &(current_total + amount));
+            .set(&creation_key, &amounts::checked_add(current_total, amount)?);
 
         Ok(())
     }
@@ -1403,7 +2178,7 @@ impl FeeTracker {
 
     /// Get total fees collected
     pub fn get_total_fees_collected(env: &Env) -> Result<i128, Error> {
-        let total_key = symbol_short!("tot_fees");
+        let total_key = DataKey::FeeVault;
         Ok(env.storage().persistent().get(&total_key).unwrap_or(0))
     }
 
@@ -1458,9 +2233,12 @@ pub struct FeeWithdrawalSchedule {
     pub max_withdrawal_bps: u32,
 }
 
-const FEE_VAULT_KEY: Symbol = symbol_short!("tot_fees");
+const TOKEN_FEE_VAULTS_KEY: Symbol = symbol_short!("tok_fees");
+const CREATOR_FEES_KEY: Symbol = symbol_short!("crtr_fees");
+const MARKET_FEES_KEY: Symbol = symbol_short!("mkt_fees");
 const WITHDRAWAL_LAST_TS_KEY: Symbol = symbol_short!("wd_last");
-const WITHDRAWAL_SCHEDULE_KEY: Symbol = symbol_short!("wd_cfg");
+const TOKEN_WITHDRAWAL_LAST_TS_KEY: Symbol = symbol_short!("wd_last_t");
+pub(crate) const WITHDRAWAL_SCHEDULE_KEY: Symbol = symbol_short!("wd_cfg");
 
 /// Default timelock: 7 days.
 pub const DEFAULT_FEE_WITHDRAWAL_TIMELOCK_SECONDS: u64 = 7 * 24 * 60 * 60;
@@ -1474,7 +2252,7 @@ impl FeeWithdrawalManager {
     /// Get the current fee withdrawal schedule (or defaults if not set).
     pub fn get_schedule(env: &Env) -> FeeWithdrawalSchedule {
         env.storage()
-            .persistent()
+            .instance()
             .get(&WITHDRAWAL_SCHEDULE_KEY)
             .unwrap_or(FeeWithdrawalSchedule {
                 timelock_seconds: DEFAULT_FEE_WITHDRAWAL_TIMELOCK_SECONDS,
@@ -1503,7 +2281,7 @@ impl FeeWithdrawalManager {
         }
 
         // Only allow tightening if already set
-        if env.storage().persistent().has(&WITHDRAWAL_SCHEDULE_KEY) {
+        if env.storage().instance().has(&WITHDRAWAL_SCHEDULE_KEY) {
             let current = Self::get_schedule(env);
             if schedule.timelock_seconds < current.timelock_seconds {
                 return Err(Error::InvalidInput);
@@ -1514,7 +2292,7 @@ impl FeeWithdrawalManager {
         }
 
         env.storage()
-            .persistent()
+            .instance()
             .set(&WITHDRAWAL_SCHEDULE_KEY, schedule);
         Ok(())
     }
@@ -1527,29 +2305,84 @@ impl FeeWithdrawalManager {
             .unwrap_or(0u64)
     }
 
-    /// Withdraw collected fees to the admin address, enforcing the configured schedule.
+    /// Returns the last successful withdrawal timestamp for a token vault
+    /// (`None` = global staking token). Each vault has its own timelock.
+    fn get_last_withdrawal_ts_for(env: &Env, token: Option<&Address>) -> u64 {
+        match token {
+            Some(token_id) => {
+                let last: Map<Address, u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&TOKEN_WITHDRAWAL_LAST_TS_KEY)
+                    .unwrap_or(Map::new(env));
+                last.get(token_id.clone()).unwrap_or(0)
+            }
+            None => Self::get_last_withdrawal_ts(env),
+        }
+    }
+
+    fn set_last_withdrawal_ts_for(env: &Env, token: Option<&Address>, timestamp: u64) {
+        match token {
+            Some(token_id) => {
+                let mut last: Map<Address, u64> = env
+                    .storage()
+                    .persistent()
+                    .get(&TOKEN_WITHDRAWAL_LAST_TS_KEY)
+                    .unwrap_or(Map::new(env));
+                last.set(token_id.clone(), timestamp);
+                env.storage()
+                    .persistent()
+                    .set(&TOKEN_WITHDRAWAL_LAST_TS_KEY, &last);
+            }
+            None => env
+                .storage()
+                .persistent()
+                .set(&WITHDRAWAL_LAST_TS_KEY, &timestamp),
+        }
+    }
+
+    /// Withdraw collected fees to `recipient`, enforcing the configured schedule.
     ///
     /// If the schedule conditions are not met (no fees / timelock), this returns `Ok(0)`
     /// and emits a `FeeWithdrawalAttemptEvent` for observability.
     pub fn withdraw_fees(
         env: &Env,
         admin: &Address,
+        recipient: &Address,
+        requested_amount: i128,
+    ) -> Result<i128, Error> {
+        Self::withdraw_fees_for_token(env, admin, recipient, None, requested_amount)
+    }
+
+    /// Withdraw collected fees held in a specific token vault (`None` = global token).
+    ///
+    /// Same schedule rules as `withdraw_fees`, with the timelock tracked per vault.
+    /// Requests above the vault balance fail with `InsufficientBalance`, so a
+    /// withdrawal can never reach into market pools held by the contract.
+    pub fn withdraw_fees_for_token(
+        env: &Env,
+        admin: &Address,
+        recipient: &Address,
+        token: Option<&Address>,
         requested_amount: i128,
     ) -> Result<i128, Error> {
         if requested_amount < 0 {
             return Err(Error::InvalidInput);
         }
+        if requested_amount > FeeTracker::get_fee_vault_balance(env, token) {
+            return Err(Error::InsufficientBalance);
+        }
 
         let now = env.ledger().timestamp();
         let schedule = Self::get_schedule(env);
-        let last_withdrawal_ts = Self::get_last_withdrawal_ts(env);
+        let last_withdrawal_ts = Self::get_last_withdrawal_ts_for(env, token);
         let next_allowed_ts = if last_withdrawal_ts == 0 {
             0
         } else {
             last_withdrawal_ts.saturating_add(schedule.timelock_seconds)
         };
 
-        let available_fees: i128 = env.storage().persistent().get(&FEE_VAULT_KEY).unwrap_or(0);
+        let available_fees = FeeTracker::get_fee_vault_balance(env, token);
         if available_fees <= 0 {
             crate::events::EventEmitter::emit_fee_withdrawal_attempt(
                 env,
@@ -1639,14 +2472,23 @@ impl FeeWithdrawalManager {
         let remaining_fees = available_fees
             .checked_sub(withdrawal_amount)
             .ok_or(Error::InvalidInput)?;
-        env.storage()
-            .persistent()
-            .set(&FEE_VAULT_KEY, &remaining_fees);
-        env.storage()
-            .persistent()
-            .set(&WITHDRAWAL_LAST_TS_KEY, &now);
-
-        FeeUtils::transfer_fees_to_admin(env, admin, withdrawal_amount)?;
+        FeeTracker::set_fee_vault_balance(env, token, remaining_fees);
+        Self::set_last_withdrawal_ts_for(env, token, now);
+
+        match token {
+            Some(token_id) => {
+                soroban_sdk::token::Client::new(env, token_id).transfer(
+                    &env.current_contract_address(),
+                    recipient,
+                    &withdrawal_amount,
+                );
+            }
+            None => MarketUtils::get_token_client(env)?.transfer(
+                &env.current_contract_address(),
+                recipient,
+                &withdrawal_amount,
+            ),
+        }
 
         crate::events::EventEmitter::emit_fee_withdrawn(
             env,
@@ -1669,7 +2511,7 @@ impl FeeConfigManager {
     /// Store fee configuration
     pub fn store_fee_config(env: &Env, config: &FeeConfig) -> Result<(), Error> {
         let config_key = symbol_short!("fee_cfg");
-        env.storage().persistent().set(&config_key, config);
+        env.storage().instance().set(&config_key, config);
         Ok(())
     }
 
@@ -1678,7 +2520,7 @@ impl FeeConfigManager {
         let config_key = symbol_short!("fee_cfg");
         Ok(env
             .storage()
-            .persistent()
+            .instance()
             .get(&config_key)
             .unwrap_or(FeeConfig {
                 platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
@@ -1934,8 +2776,8 @@ mod tests {
         env.as_contract(&contract_id, || {
             // Set admin in storage
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "Admin"), &admin);
+                .instance()
+                .set(&DataKey::Admin, &admin);
 
             // Valid admin
             assert!(FeeValidator::validate_admin_permissions(&env, &admin).is_ok());
@@ -1,4 +1,5 @@
 #![allow(dead_code)]
+use crate::events::{GasUsedEvent, EVENT_SCHEMA_VERSION};
 use soroban_sdk::{contracttype, symbol_short, Env, Symbol};
 
 /// Stores the gas limit configured by an admin for a specific operation.
@@ -53,10 +54,13 @@ impl GasTracker {
         #[cfg(test)]
         let actual_cost = env.storage().temporary().get::<Symbol, u64>(&symbol_short!("t_gas")).unwrap_or(0);
 
-        // Publish observability event: [ "gas_used", operation.clone() ] -> cost_used
+        // Publish observability event: [ "gas_used", v, operation ] -> GasUsedEvent
         env.events().publish(
-            (symbol_short!("gas_used"), operation.clone()),
-            actual_cost,
+            (symbol_short!("gas_used"), EVENT_SCHEMA_VERSION, operation.clone()),
+            GasUsedEvent {
+                operation: operation.clone(),
+                cost: actual_cost,
+            },
         );
 
         // Optional: admin-set gas budget cap per call (abort if exceeded)
@@ -1,7 +1,9 @@
 #![cfg(test)]
 
 use soroban_sdk::{testutils::{Events, Address as _, Ledger}, vec, Env, String, Symbol, symbol_short, Val, TryIntoVal, Address, token::StellarAssetClient};
+use crate::events::EVENT_SCHEMA_VERSION;
 use crate::gas::GasTracker;
+use crate::storage::DataKey;
 use crate::PredictifyHybrid;
 
 #[test]
@@ -38,10 +40,12 @@ fn test_gas_tracking_observability() {
     // Event structure: (ContractAddress, Topics, Data)
     let topics = &last_event.1;
     let topic_0: Symbol = topics.get(0).unwrap().try_into_val(&env).unwrap();
-    let topic_1: Symbol = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    let version: u32 = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    let topic_2: Symbol = topics.get(2).unwrap().try_into_val(&env).unwrap();
     
     assert_eq!(topic_0, symbol_short!("gas_used"));
-    assert_eq!(topic_1, operation);
+    assert_eq!(version, EVENT_SCHEMA_VERSION);
+    assert_eq!(topic_2, operation);
 }
 
 #[test]
@@ -96,7 +100,13 @@ fn test_integration_with_vote() {
     // Create a market
     let question = String::from_str(&env, "Test Question?");
     let outcomes = vec![&env, String::from_str(&env, "Yes"), String::from_str(&env, "No")];
-    let oracle_config = crate::OracleConfig::none_sentinel(&env);
+    let oracle_config = crate::OracleConfig::new(
+        crate::OracleProvider::Reflector,
+        Address::generate(&env),
+        String::from_str(&env, "BTC/USD"),
+        2_500_000,
+        String::from_str(&env, "gt"),
+    );
     
     let market_id = client.create_market(
         &admin,
@@ -119,8 +129,8 @@ fn test_integration_with_vote() {
     // Set token for staking in contract storage
     env.as_contract(&contract_id, || {
         env.storage()
-            .persistent()
-            .set(&Symbol::new(&env, "TokenID"), &token_id);
+            .instance()
+            .set(&DataKey::Token, &token_id);
     });
 
     // Fund user with tokens and approve contract
@@ -145,7 +155,7 @@ fn test_integration_with_vote() {
     }).expect("Gas used event should be emitted");
     
     let topics = &gas_event.1;
-    let operation: Symbol = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    let operation: Symbol = topics.get(2).unwrap().try_into_val(&env).unwrap();
     assert_eq!(operation, symbol_short!("vote"));
 }
 
@@ -164,7 +174,13 @@ fn test_integration_with_resolve_manual() {
     // Create a market
     let question = String::from_str(&env, "Test Question?");
     let outcomes = vec![&env, String::from_str(&env, "Yes"), String::from_str(&env, "No")];
-    let oracle_config = crate::OracleConfig::none_sentinel(&env);
+    let oracle_config = crate::OracleConfig::new(
+        crate::OracleProvider::Reflector,
+        Address::generate(&env),
+        String::from_str(&env, "BTC/USD"),
+        2_500_000,
+        String::from_str(&env, "gt"),
+    );
     
     let market_id = client.create_market(
         &admin,
@@ -187,8 +203,8 @@ fn test_integration_with_resolve_manual() {
     // Set token for staking in contract storage
     env.as_contract(&contract_id, || {
         env.storage()
-            .persistent()
-            .set(&Symbol::new(&env, "TokenID"), &token_id);
+            .instance()
+            .set(&DataKey::Token, &token_id);
     });
 
     // Fast forward to end of market
@@ -209,6 +225,6 @@ fn test_integration_with_resolve_manual() {
     }).expect("Gas used event should be emitted");
     
     let topics = &gas_event.1;
-    let operation: Symbol = topics.get(1).unwrap().try_into_val(&env).unwrap();
+    let operation: Symbol = topics.get(2).unwrap().try_into_val(&env).unwrap();
     assert_eq!(operation, symbol_short!("res_man"));
 }
@@ -109,8 +109,8 @@ impl GasTestContext {
         // Set token for staking
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_test.token_id);
+                .instance()
+                .set(&DataKey::Token, &token_test.token_id);
         });
 
         // Fund admin and user
@@ -156,6 +156,9 @@ impl GasTestContext {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 1000,
                 comparison: String::from_str(&self.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             &None,
             &3600,
@@ -183,7 +186,7 @@ fn test_gas_initialize_baseline() {
     
     // Verify: Admin stored correctly
     let stored_admin = env.as_contract(&contract_id, || {
-        env.storage().persistent().get::<Symbol, Address>(&Symbol::new(&env, "Admin"))
+        env.storage().instance().get::<DataKey, Address>(&DataKey::Admin)
     });
     assert!(stored_admin.is_some());
     assert_eq!(stored_admin.unwrap(), admin);
@@ -214,6 +217,9 @@ fn test_gas_create_market_minimal() {
             feed_id: String::from_str(&ctx.env, "BTC"),
             threshold: 1000,
             comparison: String::from_str(&ctx.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
         &3600,
@@ -224,7 +230,7 @@ fn test_gas_create_market_minimal() {
     
     // Verify: Market created with minimal data
     let market = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id)
+        ctx.env.storage().persistent().get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
     });
     assert!(market.is_some());
 }
@@ -259,6 +265,9 @@ fn test_gas_create_market_maximal() {
             feed_id: String::from_str(&ctx.env, "BTCUSD"),
             threshold: 10000000,
             comparison: String::from_str(&ctx.env, "gte"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
         &3600,
@@ -268,7 +277,7 @@ fn test_gas_create_market_maximal() {
     );
     
     let market = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id)
+        ctx.env.storage().persistent().get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
     });
     assert!(market.is_some());
 }
@@ -291,10 +300,14 @@ fn test_gas_vote_single_user() {
     
     // Verify: Vote recorded correctly
     let market = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        ctx.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     assert_eq!(market.total_staked, 100_0000000);
-    assert_eq!(market.votes.len(), 1);
+    assert_eq!(market.position_count(), 1);
 }
 
 #[test]
@@ -318,10 +331,14 @@ fn test_gas_vote_multiple_users() {
     }
     
     let market = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        ctx.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     assert_eq!(market.total_staked, 250_0000000);
-    assert_eq!(market.votes.len(), 5);
+    assert_eq!(market.position_count(), 5);
 }
 
 #[test]
@@ -335,20 +352,28 @@ fn test_gas_tracking_does_not_alter_results() {
     client.vote(&ctx.user, &market_id, &String::from_str(&ctx.env, "yes"), &100_0000000);
     
     let market_before = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        ctx.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     
     // Query market (read-only operation)
     let _ = client.get_market(&market_id);
     
     let market_after = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        ctx.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     
     // Verify: State unchanged by read operations
     assert_eq!(market_before.total_staked, market_after.total_staked);
     assert_eq!(market_before.state, market_after.state);
-    assert_eq!(market_before.votes.len(), market_after.votes.len());
+    assert_eq!(market_before.position_count(), market_after.position_count());
 }
 
 #[test]
@@ -376,13 +401,16 @@ fn test_gas_storage_efficiency() {
     let market_id = ctx.create_minimal_market();
     
     let market = ctx.env.as_contract(&ctx.contract_id, || {
-        ctx.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        ctx.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     
     // New market should have empty collections
-    assert_eq!(market.votes.len(), 0);
-    assert_eq!(market.stakes.len(), 0);
-    assert_eq!(market.claimed.len(), 0);
+    assert_eq!(market.outcome_pools.len(), 0);
+    assert_eq!(market.outcome_positions.len(), 0);
     assert_eq!(market.total_staked, 0);
 }
 
@@ -412,6 +440,9 @@ fn test_gas_operations_within_expected_ranges() {
             feed_id: String::from_str(&ctx.env, "BTC"),
             threshold: 1000,
             comparison: String::from_str(&ctx.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
         &3600,
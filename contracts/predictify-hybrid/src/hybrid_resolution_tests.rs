@@ -0,0 +1,201 @@
+#![cfg(test)]
+
+//! Hybrid resolution tests.
+//!
+//! A market in `ResolveMode::Hybrid` resolves only when its oracle result and
+//! a stake-weighted community vote agree; otherwise it is disputed and left to
+//! the admin.
+
+use crate::errors::Error;
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{
+    Market, MarketState, OracleConfig, OracleProvider, OracleSigner, PythPriceData, ResolveMode,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+
+/// Create a hybrid BTC market on a mock Pyth oracle and place the given bets.
+fn create_hybrid_market(
+    test: &PredictifyTest,
+    bets: &[(&Address, &str, i128)],
+) -> (Symbol, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pyth = test.env.register(MockPythContract, ());
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address: pyth.clone(),
+            feed_id: String::from_str(&test.env, BTC_FEED),
+            threshold: 2_500_000_000_000,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &86400,
+        &None,
+        &None,
+        &None,
+    );
+    client.set_resolve_mode(&test.admin, &market_id, &ResolveMode::Hybrid);
+    for (user, outcome, amount) in bets {
+        client.place_bet(
+            user,
+            &market_id,
+            &String::from_str(&test.env, outcome),
+            amount,
+            &None,
+        );
+    }
+    (market_id, pyth)
+}
+
+/// Publish a $26,000 price after the market ends and let `resolve_market`
+/// record the "yes" oracle result and open the vote.
+fn resolve_with_oracle(test: &PredictifyTest, market_id: &Symbol, pyth: &Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+    test.set_time(market.end_time + 1);
+    MockPythContractClient::new(&test.env, pyth).set_feed_price(
+        &String::from_str(&test.env, BTC_FEED),
+        &PythPriceData {
+            price: 2_600_000_000_000,
+            conf: 0,
+            expo: -8,
+            publish_time: test.env.ledger().timestamp(),
+        },
+    );
+    client.resolve_market(market_id);
+}
+
+fn end_voting(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.set_time(client.get_community_vote(market_id).unwrap().ends_at);
+}
+
+#[test]
+fn test_hybrid_market_resolves_when_oracle_and_vote_agree() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let (market_id, pyth) = create_hybrid_market(
+        &test,
+        &[(&alice, "yes", 40_0000000), (&bob, "no", 20_0000000)],
+    );
+
+    // Only the creator, and only before the first bet
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_resolve_mode(&alice, &market_id, &ResolveMode::Standard),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_resolve_mode(&test.admin, &market_id, &ResolveMode::Standard),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+
+    resolve_with_oracle(&test, &market_id, &pyth);
+    let yes = String::from_str(&test.env, "yes");
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.oracle_result, Some(yes.clone()));
+    assert_eq!(market.winning_outcomes, None);
+    assert_eq!(market.state, MarketState::Ended);
+    assert!(client.get_community_vote(&market_id).is_some());
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    client.cast_community_vote(&alice, &market_id, &yes);
+    client.cast_community_vote(&bob, &market_id, &String::from_str(&test.env, "no"));
+    end_voting(&test, &market_id);
+    assert_eq!(
+        client.finalize_community_vote(&market_id),
+        Some(yes.clone())
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.oracle_result, Some(yes.clone()));
+    assert_eq!(market.community_result, Some(yes.clone()));
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes]));
+}
+
+#[test]
+fn test_hybrid_market_disputed_when_oracle_and_vote_disagree() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let (market_id, pyth) = create_hybrid_market(
+        &test,
+        &[(&alice, "yes", 20_0000000), (&bob, "no", 40_0000000)],
+    );
+
+    resolve_with_oracle(&test, &market_id, &pyth);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.cast_community_vote(&alice, &market_id, &yes);
+    client.cast_community_vote(&bob, &market_id, &no);
+    end_voting(&test, &market_id);
+    assert_eq!(client.finalize_community_vote(&market_id), Some(no.clone()));
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Disputed);
+    assert_eq!(market.oracle_result, Some(yes));
+    assert_eq!(market.community_result, Some(no));
+    assert_eq!(market.winning_outcomes, None);
+}
+
+#[test]
+fn test_hybrid_market_without_vote_quorum_goes_to_arbitration() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let (market_id, pyth) = create_hybrid_market(
+        &test,
+        &[(&alice, "yes", 20_0000000), (&bob, "no", 40_0000000)],
+    );
+
+    // Only a third of the stake votes, short of the default quorum
+    resolve_with_oracle(&test, &market_id, &pyth);
+    let yes = String::from_str(&test.env, "yes");
+    client.cast_community_vote(&alice, &market_id, &yes);
+    end_voting(&test, &market_id);
+    assert_eq!(client.finalize_community_vote(&market_id), None);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Disputed);
+    assert_eq!(market.community_result, None);
+
+    // The admin arbitrates
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.oracle_result, Some(yes.clone()));
+    assert_eq!(market.winning_outcomes, Some(vec![&test.env, yes]));
+}
@@ -51,8 +51,8 @@ impl IntegrationTestSuite {
         // Set token for staking
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         // Fund all users with tokens
@@ -95,9 +95,12 @@ impl IntegrationTestSuite {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2500000,
                 comparison: String::from_str(&self.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -139,7 +142,7 @@ impl IntegrationTestSuite {
             self.env
                 .storage()
                 .persistent()
-                .get::<Symbol, Market>(market_id)
+                .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
                 .unwrap()
         })
     }
@@ -190,7 +193,7 @@ fn test_complete_market_lifecycle() {
     let market = test_suite.get_market(&market_id);
     assert_eq!(market.total_staked, 310_0000000); // 310 XLM total
     assert_eq!(market.state, MarketState::Active);
-    assert_eq!(market.votes.len(), 5);
+    assert_eq!(market.position_count(), 5);
 
     // Step 4: Advance time to market end
     test_suite.advance_time(31); // Past 30-day duration
@@ -261,8 +264,8 @@ fn test_multi_user_market_scenarios() {
     let market_1_data = test_suite.get_market(&market_1);
     let market_2_data = test_suite.get_market(&market_2);
 
-    assert_eq!(market_1_data.votes.len(), 10);
-    assert_eq!(market_2_data.votes.len(), 10);
+    assert_eq!(market_1_data.position_count(), 10);
+    assert_eq!(market_2_data.position_count(), 10);
 
     // Advance time and resolve markets
     test_suite.advance_time(31);
@@ -345,7 +348,7 @@ fn test_stress_test_multiple_markets() {
     // Verify all markets have votes
     for market_id in market_ids.iter() {
         let market = test_suite.get_market(&market_id);
-        assert_eq!(market.votes.len(), 20);
+        assert_eq!(market.position_count(), 20);
         assert!(market.total_staked > 0);
     }
 
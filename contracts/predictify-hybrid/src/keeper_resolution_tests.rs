@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+//! Keeper resolution tests.
+//!
+//! `keeper_resolve` lets any address resolve an ended market and collect a
+//! configurable share of its platform fee from the fee vault.
+
+use crate::errors::Error;
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{MarketState, OracleConfig, OracleProvider, OracleSigner, PythPriceData};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+const STAKE: i128 = 10_000_000;
+
+fn create_pyth_market(test: &PredictifyTest, oracle: &Address) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address: oracle.clone(),
+            feed_id: String::from_str(&test.env, BTC_FEED),
+            threshold: 2_500_000_000_000, // $25,000 in 8-decimal units
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &3600,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn publish_price(test: &PredictifyTest, oracle: &Address, price: i64) {
+    MockPythContractClient::new(&test.env, oracle).set_feed_price(
+        &String::from_str(&test.env, BTC_FEED),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: test.env.ledger().timestamp(),
+        },
+    );
+}
+
+fn move_past_end(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+}
+
+fn set_fee_vault(test: &PredictifyTest, amount: i128) {
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .set(&DataKey::FeeVault, &amount);
+    });
+}
+
+#[test]
+fn test_random_address_resolves_and_receives_incentive() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &oracle);
+    let user = test.create_funded_user();
+    client.place_bet(&user, &market_id, &String::from_str(&test.env, "yes"), &STAKE, &None);
+
+    client.set_keeper_incentive_bps(&test.admin, &5_000);
+    set_fee_vault(&test, 1_000_000);
+
+    move_past_end(&test, &market_id);
+    publish_price(&test, &oracle, 2_600_000_000_000);
+
+    let keeper = Address::generate(&test.env);
+    let paid = client.keeper_resolve(&keeper, &market_id);
+
+    // Half of the 2% platform fee on the pool
+    assert_eq!(paid, STAKE * 200 / 10_000 / 2);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    assert_eq!(token.balance(&keeper), paid);
+    assert_eq!(client.get_market(&market_id).unwrap().state, MarketState::Resolved);
+    let vault: i128 = test.env.as_contract(&test.contract_id, || {
+        test.env.storage().persistent().get(&DataKey::FeeVault).unwrap()
+    });
+    assert_eq!(vault, 1_000_000 - paid);
+}
+
+#[test]
+fn test_keeper_incentive_capped_by_fee_vault() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &oracle);
+    let user = test.create_funded_user();
+    client.place_bet(&user, &market_id, &String::from_str(&test.env, "yes"), &STAKE, &None);
+    client.set_keeper_incentive_bps(&test.admin, &5_000);
+
+    move_past_end(&test, &market_id);
+    publish_price(&test, &oracle, 2_600_000_000_000);
+
+    // Empty vault: the market still resolves, the keeper just goes unpaid
+    let keeper = Address::generate(&test.env);
+    assert_eq!(client.keeper_resolve(&keeper, &market_id), 0);
+    assert_eq!(client.get_market(&market_id).unwrap().state, MarketState::Resolved);
+}
+
+#[test]
+fn test_keeper_resolve_errors() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &oracle);
+    let keeper = Address::generate(&test.env);
+
+    assert_eq!(
+        client.try_keeper_resolve(&keeper, &market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    move_past_end(&test, &market_id);
+    assert_eq!(
+        client.try_keeper_resolve(&keeper, &market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    publish_price(&test, &oracle, 2_600_000_000_000);
+    client.keeper_resolve(&keeper, &market_id);
+    assert_eq!(
+        client.try_keeper_resolve(&keeper, &market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_set_keeper_incentive_bps() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_keeper_incentive_bps(), 0);
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_set_keeper_incentive_bps(&outsider, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_keeper_incentive_bps(&test.admin, &10_001),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+
+    client.set_keeper_incentive_bps(&test.admin, &100);
+    assert_eq!(client.get_keeper_incentive_bps(), 100);
+}
@@ -0,0 +1,156 @@
+#![cfg(test)]
+
+//! Keeper settlement reward tests.
+//!
+//! `keeper_distribute_payouts`, `keeper_void_market` and `keeper_sweep_unclaimed`
+//! pay the configured keeper reward from the fee vault, once per market and
+//! action, and only when the call changed the market's state.
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{KeeperReward, MarketState};
+use crate::{PredictifyHybridClient, PAYOUT_CURSOR_DONE};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{Address, String, Symbol};
+
+const STAKE: i128 = 100_000_000;
+const REWARD: i128 = 1_000_000;
+
+/// Fund the fee vault with `amount` newly minted to the contract
+fn set_fee_vault(test: &PredictifyTest, amount: i128) {
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&test.contract_id, &amount);
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .set(&DataKey::FeeVault, &amount);
+    });
+}
+
+/// Market with two winners and a loser, resolved to "yes" with payouts unlocked
+fn resolved_market(test: &PredictifyTest) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.place_bet(&test.create_funded_user(), &market_id, &yes, &STAKE, &None);
+    client.place_bet(&test.create_funded_user(), &market_id, &yes, &STAKE, &None);
+    client.place_bet(&test.create_funded_user(), &market_id, &no, &STAKE, &None);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+    market_id
+}
+
+#[test]
+fn test_keeper_completing_distribution_is_rewarded_once() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    client.set_keeper_reward(&test.admin, &KeeperReward::Flat(REWARD));
+    set_fee_vault(&test, 10 * REWARD);
+    let market_id = resolved_market(&test);
+    let keeper = Address::generate(&test.env);
+
+    // Only the page that completes the distribution pays
+    assert_eq!(client.keeper_distribute_payouts(&keeper, &market_id, &2), 0);
+    assert_eq!(client.keeper_distribute_payouts(&keeper, &market_id, &2), REWARD);
+    assert_eq!(client.get_payout_cursor(&market_id), PAYOUT_CURSOR_DONE);
+    assert_eq!(token.balance(&keeper), REWARD);
+
+    // Nothing is left to do, so a second call earns nothing
+    assert_eq!(client.keeper_distribute_payouts(&keeper, &market_id, &2), 0);
+    assert_eq!(token.balance(&keeper), REWARD);
+    assert_eq!(client.get_fee_vault_balance(&None), 9 * REWARD);
+}
+
+#[test]
+fn test_keeper_void_and_sweep_are_rewarded_once() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    client.set_keeper_reward(&test.admin, &KeeperReward::Flat(REWARD));
+    set_fee_vault(&test, 10 * REWARD);
+    let keeper = Address::generate(&test.env);
+
+    // Void an abandoned market
+    let abandoned = test.create_test_market();
+    client.place_bet(
+        &test.user,
+        &abandoned,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    let deadline = client.get_market(&abandoned).unwrap().resolution_deadline;
+    test.env.ledger().with_mut(|li| li.timestamp = deadline);
+    assert_eq!(client.keeper_void_market(&keeper, &abandoned), REWARD);
+    assert_eq!(
+        client.get_market(&abandoned).unwrap().state,
+        MarketState::Voided
+    );
+    assert_eq!(client.keeper_void_market(&keeper, &abandoned), 0);
+
+    // Sweep a market whose winners never claimed
+    let market_id = resolved_market(&test);
+    let market = client.get_market(&market_id).unwrap();
+    let claim_period = market.payout_unlock_at.unwrap() - market.end_time + 10;
+    client.set_market_claim_period(&test.admin, &market_id, &claim_period);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.end_time + claim_period);
+    assert_eq!(client.keeper_sweep_unclaimed(&keeper, &market_id), REWARD);
+    assert_eq!(
+        client.try_keeper_sweep_unclaimed(&keeper, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), 8 * REWARD);
+}
+
+#[test]
+fn test_keeper_reward_in_bps_capped_by_fee_vault() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let keeper = Address::generate(&test.env);
+
+    // Half of the 2% fee on a 300M pool is 3M, but the vault only holds 1M
+    client.set_keeper_reward(&test.admin, &KeeperReward::Bps(5_000));
+    set_fee_vault(&test, REWARD);
+    let market_id = resolved_market(&test);
+    assert_eq!(
+        client.keeper_distribute_payouts(&keeper, &market_id, &10),
+        REWARD
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), 0);
+
+    // An empty vault pays nothing, but the distribution still completes
+    let other = resolved_market(&test);
+    assert_eq!(client.keeper_distribute_payouts(&keeper, &other, &10), 0);
+    assert_eq!(client.get_payout_cursor(&other), PAYOUT_CURSOR_DONE);
+}
+
+#[test]
+fn test_set_keeper_reward() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_keeper_reward(), KeeperReward::Flat(0));
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_set_keeper_reward(&outsider, &KeeperReward::Flat(REWARD)),
+        Err(Ok(Error::Unauthorized))
+    );
+    for invalid in [KeeperReward::Flat(-1), KeeperReward::Bps(10_001)] {
+        assert_eq!(
+            client.try_set_keeper_reward(&test.admin, &invalid),
+            Err(Ok(Error::InvalidFeeConfig))
+        );
+    }
+
+    client.set_keeper_reward(&test.admin, &KeeperReward::Bps(100));
+    assert_eq!(client.get_keeper_reward(), KeeperReward::Bps(100));
+}
@@ -14,13 +14,16 @@ static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
 // Module declarations - all modules enabled
 mod admin;
+mod amounts;
 mod balances;
 mod batch_operations;
 mod bets;
 mod circuit_breaker;
 mod config;
+mod credits;
 mod disputes;
 mod edge_cases;
+mod escrow;
 pub mod errors;
 mod event_archive;
 mod events;
@@ -33,15 +36,20 @@ mod market_analytics;
 mod market_id_generator;
 mod markets;
 mod monitoring;
+mod multisig;
+mod optimistic;
 mod oracles;
 mod performance_benchmarks;
 mod queries;
 mod rate_limiter;
 mod recovery;
 mod reentrancy_guard;
+mod referrals;
 mod resolution;
 mod statistics;
 mod storage;
+mod templates;
+mod timelock;
 mod types;
 mod upgrade_manager;
 mod utils;
@@ -110,6 +118,156 @@ mod unclaimed_winnings_timeout_tests;
 #[cfg(test)]
 mod metadata_validation_tests;
 
+#[cfg(test)]
+mod pyth_oracle_tests;
+
+#[cfg(test)]
+mod reflector_oracle_tests;
+
+#[cfg(test)]
+mod multi_oracle_tests;
+
+#[cfg(test)]
+mod community_resolution_tests;
+
+#[cfg(test)]
+mod hybrid_resolution_tests;
+
+#[cfg(test)]
+mod oracle_fallback_resolution_tests;
+
+#[cfg(test)]
+mod dispute_result_tests;
+
+#[cfg(test)]
+mod market_listing_tests;
+
+#[cfg(test)]
+mod role_tests;
+
+#[cfg(test)]
+mod state_event_tests;
+
+#[cfg(test)]
+mod multi_outcome_tests;
+
+#[cfg(test)]
+mod ttl_management_tests;
+
+#[cfg(test)]
+mod keeper_resolution_tests;
+
+#[cfg(test)]
+mod scalar_market_tests;
+
+#[cfg(test)]
+mod market_template_tests;
+
+#[cfg(test)]
+mod market_extension_tests;
+
+#[cfg(test)]
+mod early_close_tests;
+
+#[cfg(test)]
+mod resolution_deadline_tests;
+
+#[cfg(test)]
+mod arbitration_tests;
+
+#[cfg(test)]
+mod outcome_pool_tests;
+
+#[cfg(test)]
+mod reentrancy_tests;
+
+#[cfg(test)]
+mod market_number_tests;
+
+#[cfg(test)]
+mod referral_tests;
+
+#[cfg(test)]
+mod protocol_stats_tests;
+
+#[cfg(test)]
+mod user_stats_tests;
+
+#[cfg(test)]
+mod event_schema_tests;
+
+#[cfg(test)]
+mod simulation_tests;
+
+#[cfg(test)]
+mod market_access_tests;
+
+#[cfg(test)]
+mod anti_snipe_tests;
+
+#[cfg(test)]
+mod position_transfer_tests;
+
+#[cfg(test)]
+mod cash_out_tests;
+
+#[cfg(test)]
+mod blacklist_tests;
+
+#[cfg(test)]
+mod credit_tests;
+
+#[cfg(test)]
+mod native_xlm_tests;
+
+#[cfg(test)]
+mod fee_on_transfer_tests;
+
+#[cfg(test)]
+mod escrow_tests;
+
+#[cfg(test)]
+mod token_recovery_tests;
+
+#[cfg(test)]
+mod cross_rate_tests;
+
+#[cfg(test)]
+mod signed_oracle_tests;
+
+#[cfg(test)]
+mod optimistic_resolution_tests;
+
+#[cfg(test)]
+mod creation_bond_tests;
+
+#[cfg(test)]
+mod fee_override_tests;
+
+#[cfg(test)]
+mod fee_tier_tests;
+
+#[cfg(test)]
+mod payout_rounding_tests;
+
+#[cfg(test)]
+mod keeper_reward_tests;
+
+#[cfg(test)]
+mod admin_log_tests;
+
+#[cfg(test)]
+mod timelock_tests;
+
+#[cfg(test)]
+mod multisig_tests;
+
+#[cfg(test)]
+mod market_view_tests;
+
+#[cfg(test)]
+mod market_state_tests;
+
 // Re-export commonly used items
 use admin::{AdminAnalyticsResult, AdminInitializer, AdminManager, AdminPermission, AdminRole};
 pub use errors::Error;
@@ -124,20 +282,34 @@ use crate::graceful_degradation::{OracleBackup, OracleHealth};
 use crate::market_id_generator::MarketIdGenerator;
 use crate::reentrancy_guard::ReentrancyGuard;
 use crate::resolution::OracleResolution;
+use crate::storage::DataKey;
 use alloc::format;
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, Address, Env, Map, String, Symbol, Vec,
+    contract, contractimpl, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
 };
 
 #[contract]
 pub struct PredictifyHybrid;
 
-const PERCENTAGE_DENOMINATOR: i128 = 100;
+const FEE_BPS_DENOMINATOR: i128 = 10_000;
 const DEFAULT_CLAIM_PERIOD_SECONDS: u64 = 90 * 24 * 60 * 60;
 const GLOBAL_CLAIM_PERIOD_KEY: &str = "claim_timeout";
 const MARKET_CLAIM_PERIODS_KEY: &str = "claim_overrides";
+const DEFAULT_DISPUTE_WINDOW_KEY: &str = "dispute_window";
 const TREASURY_STORAGE_KEY: &str = "Treasury";
-const GLOBAL_MIN_POOL_SIZE_KEY: &str = "global_min_pool";
+pub(crate) const GLOBAL_MIN_POOL_SIZE_KEY: &str = "global_min_pool";
+const MARKET_DURATION_LIMITS_KEY: &str = "mkt_dur_limits";
+const MAX_MARKET_OUTCOMES_KEY: &str = "max_outcomes";
+const MARKET_CREATORS_KEY: &str = "mkt_creators";
+const PAYOUT_CURSORS_KEY: &str = "payout_cursors";
+
+/// Maximum number of markets `claim_all` accepts in one call.
+pub const MAX_CLAIM_ALL_MARKETS: u32 = 50;
+
+/// Maximum number of positions settled by a single `distribute_payouts` page.
+pub const MAX_PAYOUT_PAGE_SIZE: u32 = 100;
+/// Cursor returned by `distribute_payouts` once every position has been processed.
+pub const PAYOUT_CURSOR_DONE: u32 = u32::MAX;
 
 #[contractimpl]
 impl PredictifyHybrid {
@@ -153,12 +325,14 @@ impl PredictifyHybrid {
     ///
     /// * `env` - The Soroban environment for blockchain operations
     /// * `admin` - The address that will be granted administrative privileges
-    /// * `platform_fee_percentage` - Optional platform fee percentage (0-10%). If `None`, defaults to 2%
+    /// * `platform_fee_percentage` - Optional platform fee as a whole percentage (e.g. `Some(5)`
+    ///   for 5%), not basis points. Must be within 0-10%. If `None`, defaults to 2%
     ///
     /// # Panics
     ///
     /// This function will panic if:
-    /// - The contract has already been initialized (Error code 504: AlreadyInitialized)
+    /// - `admin` has not authorized the call
+    /// - The contract has already been initialized (Error code 400: InvalidState)
     /// - The admin address is invalid
     /// - The platform fee percentage is negative or exceeds 10%
     /// - Storage operations fail
@@ -195,8 +369,16 @@ impl PredictifyHybrid {
     /// # Re-initialization Prevention
     ///
     /// This function can only be called once. Any subsequent calls will panic with
-    /// `Error::AlreadyInitialized` to prevent admin takeover attacks.
-    pub fn initialize(env: Env, admin: Address, platform_fee_percentage: Option<i128>) {
+    /// `Error::InvalidState` to prevent admin takeover attacks. The admin must sign
+    /// the call, so a third party cannot initialize the contract under an address it
+    /// does not control; use `is_initialized` to check the deployment state.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        platform_fee_percentage: Option<i128>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
         // Determine platform fee (default 2% if not specified)
         let fee_percentage = platform_fee_percentage.unwrap_or(DEFAULT_PLATFORM_FEE_PERCENTAGE);
 
@@ -204,19 +386,17 @@ impl PredictifyHybrid {
         if fee_percentage < MIN_PLATFORM_FEE_PERCENTAGE
             || fee_percentage > MAX_PLATFORM_FEE_PERCENTAGE
         {
-            panic_with_error!(env, Error::InvalidFeeConfig);
+            return Err(Error::InvalidFeeConfig);
         }
 
         // Initialize admin (includes re-initialization check)
-        match AdminInitializer::initialize(&env, &admin) {
-            Ok(_) => (),
-            Err(e) => panic_with_error!(env, e),
-        }
+        AdminInitializer::initialize(&env, &admin)?;
 
-        // Store platform fee configuration in persistent storage
+        // Store platform fee configuration in instance storage
         env.storage()
-            .persistent()
+            .instance()
             .set(&Symbol::new(&env, "platform_fee"), &fee_percentage);
+        fees::FeeManager::store_fee_bps(&env, (fee_percentage * 100) as u32);
 
         // Initialize global claim timeout and treasury defaults
         env.storage().persistent().set(
@@ -236,26 +416,36 @@ impl PredictifyHybrid {
         // Emit initial claim period and treasury events
         EventEmitter::emit_claim_period_updated(&env, &admin, DEFAULT_CLAIM_PERIOD_SECONDS);
         EventEmitter::emit_treasury_updated(&env, &admin, &admin);
+        Ok(())
+    }
+
+    /// Returns true once `initialize` has stored the contract admin.
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Admin)
     }
 
     /// Updates the global claim period (in seconds) used when no market-specific override is set.
     ///
     /// Admin-only. `claim_period_seconds` must be greater than zero.
-    pub fn set_global_claim_period(env: Env, admin: Address, claim_period_seconds: u64) {
+    pub fn set_global_claim_period(
+        env: Env,
+        admin: Address,
+        claim_period_seconds: u64,
+    ) -> Result<(), Error> {
         admin.require_auth();
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
 
         if claim_period_seconds == 0 {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
         env.storage().persistent().set(
@@ -264,6 +454,7 @@ impl PredictifyHybrid {
         );
 
         EventEmitter::emit_claim_period_updated(&env, &admin, claim_period_seconds);
+        Ok(())
     }
 
     /// Sets a claim period override for a specific market.
@@ -274,21 +465,21 @@ impl PredictifyHybrid {
         admin: Address,
         market_id: Symbol,
         claim_period_seconds: u64,
-    ) {
+    ) -> Result<(), Error> {
         admin.require_auth();
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
 
         if claim_period_seconds == 0 {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
         let mut overrides: Map<Symbol, u64> = env
@@ -309,6 +500,7 @@ impl PredictifyHybrid {
             &market_id,
             claim_period_seconds,
         );
+        Ok(())
     }
 
     /// Returns the global claim period in seconds.
@@ -336,20 +528,70 @@ impl PredictifyHybrid {
         Self::get_market_claim_period(env, market_id).unwrap_or(global)
     }
 
-    /// Sets the treasury address where swept unclaimed winnings are transferred.
+    /// Sets the dispute window (in seconds) used by markets created without one.
     ///
-    /// Admin-only.
-    pub fn set_treasury(env: Env, admin: Address, treasury: Address) {
+    /// Admin-only. Must lie within `MIN_DISPUTE_WINDOW_SECONDS..=MAX_DISPUTE_WINDOW_SECONDS`.
+    /// Markets snapshot their window at creation, so existing markets are unaffected.
+    pub fn set_default_dispute_window(
+        env: Env,
+        admin: Address,
+        dispute_window_seconds: u64,
+    ) -> Result<(), Error> {
         admin.require_auth();
 
         let stored_admin: Address = env
             .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        Self::validate_dispute_window(dispute_window_seconds)?;
+
+        env.storage().persistent().set(
+            &Symbol::new(&env, DEFAULT_DISPUTE_WINDOW_KEY),
+            &dispute_window_seconds,
+        );
+
+        EventEmitter::emit_dispute_window_updated(&env, &admin, dispute_window_seconds);
+        Ok(())
+    }
+
+    /// Returns the default dispute window in seconds.
+    pub fn get_default_dispute_window(env: Env) -> u64 {
+        env.storage()
             .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .get(&Symbol::new(&env, DEFAULT_DISPUTE_WINDOW_KEY))
+            .unwrap_or(config::DEFAULT_DISPUTE_WINDOW_SECONDS)
+    }
+
+    fn validate_dispute_window(dispute_window_seconds: u64) -> Result<(), Error> {
+        if !(config::MIN_DISPUTE_WINDOW_SECONDS..=config::MAX_DISPUTE_WINDOW_SECONDS)
+            .contains(&dispute_window_seconds)
+        {
+            return Err(Error::InvalidInput);
+        }
+        Ok(())
+    }
+
+    /// Sets the treasury address that receives withdrawn fees and swept unclaimed
+    /// winnings.
+    ///
+    /// Admin-only.
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
 
         env.storage()
@@ -357,6 +599,7 @@ impl PredictifyHybrid {
             .set(&Symbol::new(&env, TREASURY_STORAGE_KEY), &treasury);
 
         EventEmitter::emit_treasury_updated(&env, &admin, &treasury);
+        Ok(())
     }
 
     /// Returns current treasury address if configured.
@@ -366,6 +609,197 @@ impl PredictifyHybrid {
             .get(&Symbol::new(&env, TREASURY_STORAGE_KEY))
     }
 
+    /// Recipient of fee withdrawals: the treasury, or the admin when none is set.
+    fn fee_recipient(env: &Env) -> Result<Address, Error> {
+        match Self::get_treasury(env.clone()) {
+            Some(treasury) => Ok(treasury),
+            None => env
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(Error::AdminNotSet),
+        }
+    }
+
+    /// Sets the staking token used for bets, refunds and payouts.
+    ///
+    /// Admin-only. The address must implement the token interface (it must answer
+    /// `decimals()` and `symbol()`); both are recorded so bet bounds, exposure caps
+    /// and creation fees, which are configured at `DEFAULT_TOKEN_DECIMALS`, are
+    /// converted to the token's decimals. Only the first token is set
+    /// here; switching to another one is a `TimelockedAction::SetToken` scheduled
+    /// with `schedule_action`. A switch is refused while any market still holds
+    /// escrow or the fee vault holds fees, so funds can never be stranded in the
+    /// previous asset, and a token with different decimals is also refused while
+    /// any market is open.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidState` - A token is already set
+    /// - `Error::InvalidInput` - Address does not implement the token interface
+    pub fn set_token(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        // Switching tokens must wait out the admin action delay
+        if env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::InvalidState);
+        }
+
+        Self::apply_token(&env, &admin, &token)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("set_token"),
+            admin::AdminLogTarget::Account(token),
+        );
+        Ok(())
+    }
+
+    /// Records `token` as the staking token; callers are responsible for authorization.
+    fn apply_token(env: &Env, admin: &Address, token: &Address) -> Result<(), Error> {
+        let token_client = soroban_sdk::token::Client::new(env, token);
+        let decimals = match token_client.try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => return Err(Error::InvalidInput),
+        };
+        let symbol = match token_client.try_symbol() {
+            Ok(Ok(symbol)) => symbol,
+            _ => return Err(Error::InvalidInput),
+        };
+
+        let key = DataKey::Token;
+        let previous: Option<Address> = env.storage().instance().get(&key);
+        if previous.as_ref() != Some(token) && previous.is_some() {
+            // Escrow and the global fee vault are held in the current token
+            if crate::markets::MarketUtils::has_open_pool(env)
+                || crate::fees::FeeTracker::get_fee_vault_balance(env, None) > 0
+            {
+                return Err(Error::BetsAlreadyPlaced);
+            }
+            if decimals != crate::config::ConfigManager::get_token_decimals(env)
+                && crate::markets::MarketUtils::has_open_market(env)
+            {
+                return Err(Error::BetsAlreadyPlaced);
+            }
+        }
+
+        env.storage().instance().set(&key, token);
+        env.storage().instance().set(&DataKey::TokenDecimals, &decimals);
+        env.storage().instance().set(&DataKey::TokenSymbol, &symbol);
+
+        EventEmitter::emit_token_updated(env, admin, &previous, token);
+        Ok(())
+    }
+
+    /// Returns the configured staking token.
+    ///
+    /// Returns `Error::ConfigNotFound` if no token has been set.
+    pub fn get_token(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::ConfigNotFound)
+    }
+
+    /// Returns the configured staking token with its decimals and symbol.
+    ///
+    /// Returns `Error::ConfigNotFound` if no token has been set.
+    pub fn get_token_info(env: Env) -> Result<(Address, u32, String), Error> {
+        let token = Self::get_token(env.clone())?;
+        let symbol = env
+            .storage()
+            .instance()
+            .get(&DataKey::TokenSymbol)
+            .unwrap_or_else(|| String::from_str(&env, ""));
+        Ok((
+            token,
+            crate::config::ConfigManager::get_token_decimals(&env),
+            symbol,
+        ))
+    }
+
+    /// Assigns a dedicated staking token to a market.
+    ///
+    /// Admin-only, and only while the market is active with an empty pool. Once set,
+    /// bets, refunds, payouts and collected fees for the market all use this token
+    /// instead of the global one configured via `set_token`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes or escrowed funds
+    /// - `Error::InvalidState` - Market carries a creator fee
+    /// - `Error::InvalidInput` - Address does not implement the token interface, or
+    ///   its decimals differ from the global token's
+    pub fn set_market_token(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        token: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 || escrow::EscrowManager::get(&env, &market_id) > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        // Creator fees accrue in the global token
+        if market.creator_fee_bps > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        // Bet limits and fees are scaled to the global token's decimals
+        let token_client = soroban_sdk::token::Client::new(&env, &token);
+        match token_client.try_decimals() {
+            Ok(Ok(decimals)) if decimals == crate::config::ConfigManager::get_token_decimals(&env) => {}
+            _ => return Err(Error::InvalidInput),
+        }
+
+        market.token = Some(token.clone());
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+
+        EventEmitter::emit_market_token_set(&env, &market_id, &admin, &token);
+        Ok(())
+    }
+
+    /// Returns the token a market is denominated in: its own token if one was
+    /// assigned via `set_market_token`, otherwise the global staking token.
+    pub fn get_market_token(env: Env, market_id: Symbol) -> Result<Address, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        match market.token {
+            Some(token) => Ok(token),
+            None => Self::get_token(env),
+        }
+    }
+
     /// Deposits funds into the user's balance.
     ///
     /// # Parameters
@@ -389,6 +823,8 @@ impl PredictifyHybrid {
     /// * `user` - The user withdrawing funds.
     /// * `asset` - The asset to withdraw.
     /// * `amount` - The amount to withdraw.
+    ///
+    /// Fails with `Error::AddressBlocked` when `user` is blocked.
     pub fn withdraw(
         env: Env,
         user: Address,
@@ -410,9 +846,14 @@ impl PredictifyHybrid {
 
     /// Creates a new prediction market with specified parameters and oracle configuration.
     ///
-    /// This function allows authorized administrators to create prediction markets
-    /// with custom questions, possible outcomes, duration, and oracle integration.
-    /// Each market gets a unique identifier and is stored in persistent contract storage.
+    /// This function allows the admin, or any address on the market creator allowlist
+    /// (see `add_market_creator`), to create prediction markets with custom questions,
+    /// possible outcomes, duration, and oracle integration. Each market gets a unique
+    /// identifier and is stored in persistent contract storage, recording its `creator`.
+    /// Non-admin creators pay `get_creation_fee` in the staking token into the fee vault
+    /// and post a refundable `get_creation_bond`, returned once the market resolves or
+    /// is voided for low participation, and slashed if the admin cancels the market as
+    /// `Unresolvable` or `Malicious`.
     ///
     /// # Multi-Outcome Support
     ///
@@ -426,11 +867,17 @@ impl PredictifyHybrid {
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `admin` - The administrator address creating the market (must be authorized)
+    /// * `creator` - The address creating the market: the admin or an allowlisted creator
+    ///   (must be authorized)
     /// * `question` - The prediction question (must be non-empty)
-    /// * `outcomes` - Vector of possible outcomes (minimum 2 required, all non-empty, no duplicates)
-    /// * `duration_days` - Market duration in days (must be between 1-365 days)
+    /// * `outcomes` - Vector of possible outcomes (2 up to `get_max_market_outcomes`, each
+    ///   `MIN_OUTCOME_LENGTH`-`MAX_OUTCOME_LENGTH` characters, no duplicates)
+    /// * `duration_days` - Market duration in days (must fall within `get_market_duration_limits`)
     /// * `oracle_config` - Configuration for oracle integration (Reflector, Pyth, etc.)
+    /// * `resolution_timeout` - Seconds after `end_time` before the fallback oracle is used
+    ///   (1 to `MAX_RESOLUTION_TIMEOUT_SECONDS`)
+    /// * `bet_deadline_mins_before_end` - Stop accepting bets this many minutes before
+    ///   `end_time` (must be shorter than the market duration); `None` accepts bets until `end_time`
     ///
     /// # Returns
     ///
@@ -439,9 +886,17 @@ impl PredictifyHybrid {
     /// # Panics
     ///
     /// This function will panic with specific errors if:
-    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::Unauthorized` - Caller is neither the contract admin nor an allowlisted creator
     /// - `Error::InvalidQuestion` - Question is empty
-    /// - `Error::InvalidOutcomes` - Less than 2 outcomes or any outcome is empty
+    /// - `Error::InvalidOutcomes` - Less than 2 outcomes, or an outcome appears twice
+    /// - `Error::InvalidOutcome` - An outcome is empty or longer than `MAX_OUTCOME_LENGTH`
+    /// - `Error::InvalidDuration` - End time is not in the future, or the bet deadline is zero
+    ///   or not shorter than the market duration
+    /// - `Error::ThresholdBelowMin` - Duration is shorter than the configured minimum
+    /// - `Error::ThresholdTooHigh` - Duration is longer than the configured maximum, or there
+    ///   are more outcomes than `get_max_market_outcomes`
+    /// - `Error::InvalidTimeoutHours` - Resolution timeout is zero or above the maximum
+    /// - `Error::InsufficientBalance` - A non-admin creator cannot cover the creation fee
     /// - Storage operations fail
     ///
     /// # Example
@@ -513,7 +968,7 @@ impl PredictifyHybrid {
     /// The market will automatically transition to `MarketState::Ended` when the duration expires.
     pub fn create_market(
         env: Env,
-        admin: Address,
+        creator: Address,
         question: String,
         outcomes: Vec<String>,
         duration_days: u32,
@@ -523,81 +978,174 @@ impl PredictifyHybrid {
         min_pool_size: Option<i128>,
         bet_deadline_mins_before_end: Option<u32>,
         dispute_window_seconds: Option<u64>,
-    ) -> Symbol {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
-        let gas_marker = crate::gas::GasTracker::start_tracking(&env);
-        // Authenticate that the caller is the admin
-        admin.require_auth();
-
-        // Verify the caller is an admin
-        let stored_admin: Address = env
+    ) -> Result<Symbol, Error> {
+        let (has_fallback, fallback_oracle_config) = match fallback_oracle_config {
+            Some(config) => (true, config),
+            None => (false, OracleConfig::none_sentinel(&env)),
+        };
+        let params = MarketTemplate {
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            has_fallback,
+            fallback_oracle_config,
+            resolution_timeout,
+            min_pool_size,
+            bet_deadline_mins_before_end,
+            dispute_window_seconds,
+            creator_fee_bps: 0,
+        };
+        creator.require_auth();
+        Self::create_market_internal(env, creator, params)
+    }
+
+    /// Shared body of `create_market`, `create_market_from_template` and market
+    /// series. Callers authenticate `creator`.
+    fn create_market_internal(
+        env: Env,
+        creator: Address,
+        params: MarketTemplate,
+    ) -> Result<Symbol, Error> {
+        let MarketTemplate {
+            question,
+            outcomes,
+            duration_days,
+            oracle_config,
+            has_fallback,
+            fallback_oracle_config,
+            resolution_timeout,
+            min_pool_size,
+            bet_deadline_mins_before_end,
+            dispute_window_seconds,
+            creator_fee_bps,
+        } = params;
+        let fallback_oracle_config = if has_fallback {
+            Some(fallback_oracle_config)
+        } else {
+            None
+        };
+
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        let gas_marker = crate::gas::GasTracker::start_tracking(&env);
+
+        // The admin may always create markets; anyone else must be allowlisted
+        let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::AdminNotSet);
-            });
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
 
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+        if creator != stored_admin && !Self::is_market_creator(env.clone(), creator.clone()) {
+            return Err(Error::Unauthorized);
         }
 
         // Check active events limit for the creator
         let market_config = crate::config::ConfigManager::get_default_market_config();
         let current_active_events =
-            crate::storage::CreatorLimitsManager::get_active_events(&env, &admin);
+            crate::storage::CreatorLimitsManager::get_active_events(&env, &creator);
         if current_active_events >= market_config.max_active_events_per_creator {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
         // Validate metadata using InputValidator
         if let Err(_) = crate::validation::InputValidator::validate_question_length(&question) {
-            panic_with_error!(env, Error::InvalidQuestion);
+            return Err(Error::InvalidQuestion);
         }
 
-        if let Err(_) = crate::validation::InputValidator::validate_outcomes(&outcomes) {
-            panic_with_error!(env, Error::InvalidOutcomes);
+        // Validate outcomes: 2 up to the configured maximum, each non-empty,
+        // at most MAX_OUTCOME_LENGTH characters, and distinct (case-sensitive)
+        if outcomes.len() > Self::get_max_market_outcomes(env.clone()) {
+            return Err(Error::ThresholdTooHigh);
         }
-
-        // Validate inputs
-        if outcomes.len() < 2 {
-            panic_with_error!(env, Error::InvalidOutcomes);
+        if outcomes.len() < config::MIN_MARKET_OUTCOMES {
+            return Err(Error::InvalidOutcomes);
+        }
+        for outcome in outcomes.iter() {
+            if crate::validation::InputValidator::validate_outcome_length(&outcome).is_err() {
+                return Err(Error::InvalidOutcome);
+            }
+        }
+        if crate::validation::InputValidator::validate_unique_outcomes(&outcomes).is_err() {
+            return Err(Error::InvalidOutcomes);
         }
 
         if question.len() == 0 {
-            panic_with_error!(env, Error::InvalidQuestion);
+            return Err(Error::InvalidQuestion);
+        }
+
+        // Reject unknown comparison operators and price sources now rather
+        // than at resolution
+        OracleComparison::parse(&oracle_config.comparison)?;
+        oracle_config.validate_price_source()?;
+        if let Some(fallback) = &fallback_oracle_config {
+            OracleComparison::parse(&fallback.comparison)?;
+            fallback.validate_price_source()?;
         }
 
         // Generate a unique collision-resistant market ID
-        let market_id = MarketIdGenerator::generate_market_id(&env, &admin);
+        let market_id = MarketIdGenerator::generate_market_id(&env, &creator);
 
-        // Calculate end time
+        // Calculate end time, which must be strictly in the future
         let seconds_per_day: u64 = 24 * 60 * 60;
         let duration_seconds: u64 = (duration_days as u64) * seconds_per_day;
-        let end_time: u64 = env.ledger().timestamp() + duration_seconds;
+        let end_time: u64 = env
+            .ledger()
+            .timestamp()
+            .checked_add(duration_seconds)
+            .ok_or(Error::InvalidDuration)?;
+        if end_time <= env.ledger().timestamp() {
+            return Err(Error::InvalidDuration);
+        }
 
-        // Bet deadline: if set, must be before end_time
+        // Duration must fall within the admin-configured limits
+        let (min_duration, max_duration) = Self::get_market_duration_limits(env.clone());
+        if duration_seconds < min_duration {
+            return Err(Error::ThresholdBelowMin);
+        }
+        if duration_seconds > max_duration {
+            return Err(Error::ThresholdTooHigh);
+        }
+
+        if resolution_timeout == 0 || resolution_timeout > config::MAX_RESOLUTION_TIMEOUT_SECONDS {
+            return Err(Error::InvalidTimeoutHours);
+        }
+
+        // Bet deadline: if set, must fall after creation and before end_time
         let bet_deadline: u64 = match bet_deadline_mins_before_end {
             Some(mins) => {
-                let deadline = end_time.saturating_sub((mins as u64) * 60);
-                if deadline >= end_time || deadline == 0 {
-                    panic_with_error!(env, Error::InvalidDuration);
+                let cutoff_seconds = (mins as u64) * 60;
+                if cutoff_seconds == 0 || cutoff_seconds >= duration_seconds {
+                    return Err(Error::InvalidDuration);
                 }
-                deadline
+                end_time - cutoff_seconds
             }
             None => 0,
         };
-        let dispute_win = dispute_window_seconds.unwrap_or(86400u64); // 24h default
+        // Snapshot the window so later default changes leave this market alone
+        let dispute_win = match dispute_window_seconds {
+            Some(seconds) => {
+                Self::validate_dispute_window(seconds)?;
+                seconds
+            }
+            None => Self::get_default_dispute_window(env.clone()),
+        };
 
         let (has_fallback, fallback_cfg) = match &fallback_oracle_config {
             Some(c) => (true, c.clone()),
             None => (false, OracleConfig::none_sentinel(&env)),
         };
+        // Non-admin creators pay the creation fee; a failed payment aborts creation
+        let creation_fee = if creator == stored_admin {
+            0
+        } else {
+            fees::FeeManager::charge_market_creation_fee(&env, &creator)?
+        };
+
         // Create a new market
-        let market = Market {
-            admin: admin.clone(),
+        let mut market = Market {
+            admin: stored_admin.clone(),
+            creator: creator.clone(),
             question: question.clone(),
             outcomes: outcomes.clone(),
             end_time,
@@ -606,11 +1154,8 @@ impl PredictifyHybrid {
             fallback_oracle_config: fallback_cfg,
             resolution_timeout,
             oracle_result: None,
-            votes: Map::new(&env),
             total_staked: 0,
             dispute_stakes: Map::new(&env),
-            stakes: Map::new(&env),
-            claimed: Map::new(&env),
             winning_outcomes: None,
             fee_collected: false,
             state: MarketState::Active,
@@ -620,18 +1165,39 @@ impl PredictifyHybrid {
             category: None,
             tags: Vec::new(&env),
             min_pool_size,
+            max_pool_size: None,
             bet_deadline,
             dispute_window_seconds: dispute_win,
+            resolved_at: None,
+            payout_unlock_at: None,
+            token: None,
+            fee_bps: fees::FeeManager::get_fee_bps(&env),
+            creator_fee_bps,
+            resolve_mode: ResolveMode::Standard,
+            community_result: None,
+            frozen: false,
+            market_type: MarketType::Categorical,
+            scalar_value: None,
+            closed_early_at: None,
+            resolution_deadline: 0,
+            outcome_pools: Map::new(&env),
+            outcome_positions: Map::new(&env),
         };
+        market.resolution_deadline = market.compute_resolution_deadline();
 
         // Store the market
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+
+        // Non-admin creators also post a bond, returned once the market settles
+        if creator != stored_admin {
+            fees::FeeManager::post_creation_bond(&env, &creator, &market_id)?;
+        }
 
         // Increment active event count for this creator
-        crate::storage::CreatorLimitsManager::increment_active_events(&env, &admin);
+        crate::storage::CreatorLimitsManager::increment_active_events(&env, &creator);
 
         // Emit market created event
-        EventEmitter::emit_market_created(&env, &market_id, &question, &outcomes, &admin, end_time);
+        EventEmitter::emit_market_created(&env, &market_id, &market, creation_fee);
 
         // Record statistics
         statistics::StatisticsManager::record_market_created(&env);
@@ -642,7 +1208,139 @@ impl PredictifyHybrid {
             gas_marker,
         );
 
-        market_id
+        Ok(market_id)
+    }
+
+    /// Store a market template for a question that repeats (admin only).
+    ///
+    /// Returns the new template id.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidOutcomes` - Fewer than two outcomes
+    /// - `Error::InvalidComparison` - Unknown oracle comparison operator
+    /// - `Error::InvalidFeeConfig` - Creator fee above `MAX_CREATOR_FEE_BPS`
+    pub fn create_template(
+        env: Env,
+        admin: Address,
+        template: MarketTemplate,
+    ) -> Result<u32, Error> {
+        templates::MarketTemplateManager::create_template(&env, &admin, &template)
+    }
+
+    /// Replace a market template (admin only). Existing markets are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// Same as `create_template`, plus `Error::InvalidInput` for an unknown id.
+    pub fn update_template(
+        env: Env,
+        admin: Address,
+        template_id: u32,
+        template: MarketTemplate,
+    ) -> Result<(), Error> {
+        templates::MarketTemplateManager::update_template(&env, &admin, template_id, &template)
+    }
+
+    /// Get a market template by id.
+    pub fn get_template(env: Env, template_id: u32) -> Option<MarketTemplate> {
+        templates::MarketTemplateManager::get_template(&env, template_id)
+    }
+
+    /// List all market templates with their ids.
+    pub fn get_templates(env: Env) -> Vec<(u32, MarketTemplate)> {
+        templates::MarketTemplateManager::get_templates(&env)
+    }
+
+    /// Create a market from a template, filling in the per-market values from
+    /// `overrides` (threshold, duration and question).
+    ///
+    /// The market is created exactly as `create_market` would with the resulting
+    /// parameters, under the same creator rules and validation, and carries the
+    /// template's creator fee.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - No template with `template_id`
+    /// - Any error from `create_market`
+    pub fn create_market_from_template(
+        env: Env,
+        creator: Address,
+        template_id: u32,
+        overrides: TemplateOverrides,
+    ) -> Result<Symbol, Error> {
+        creator.require_auth();
+        let params =
+            templates::MarketTemplateManager::apply_overrides(&env, template_id, &overrides)?;
+        Self::create_market_internal(env, creator, params)
+    }
+
+    /// Start a series of `count` markets from a template, one after another,
+    /// each running for `interval_seconds` (admin only).
+    ///
+    /// The first market is created right away; `roll_series` creates the rest.
+    /// Returns the series id.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidInput` - Unknown template, zero `count`, or an interval
+    ///   that is not a positive whole number of days
+    /// - Any error from `create_market` for the first market
+    pub fn create_series(
+        env: Env,
+        admin: Address,
+        template_id: u32,
+        interval_seconds: u64,
+        count: u32,
+    ) -> Result<u32, Error> {
+        let series_id = templates::MarketSeriesManager::create_series(
+            &env,
+            &admin,
+            template_id,
+            interval_seconds,
+            count,
+        )?;
+        Self::roll_series(env, series_id)?;
+        Ok(series_id)
+    }
+
+    /// Create the next market of a series once the latest one has ended.
+    ///
+    /// Permissionless, so keepers can keep a series running: the market is
+    /// created on behalf of the series admin from the template's current
+    /// settings. Returns the new market id.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - Unknown series
+    /// - `Error::MarketNotReady` - The latest market has not reached its `end_time`
+    /// - `Error::InvalidState` - The series already has all `count` markets
+    pub fn roll_series(env: Env, series_id: u32) -> Result<Symbol, Error> {
+        let mut series = templates::MarketSeriesManager::get_series(&env, series_id)
+            .ok_or(Error::InvalidInput)?;
+        let params = templates::MarketSeriesManager::next_market_params(&env, &series)?;
+        let market_id = Self::create_market_internal(env.clone(), series.admin.clone(), params)?;
+        templates::MarketSeriesManager::record_market(&env, series_id, &mut series, &market_id);
+        Ok(market_id)
+    }
+
+    /// Get a market series by id.
+    pub fn get_series(env: Env, series_id: u32) -> Option<MarketSeries> {
+        templates::MarketSeriesManager::get_series(&env, series_id)
+    }
+
+    /// Markets created by a series so far, in order.
+    pub fn get_series_markets(env: Env, series_id: u32) -> Vec<Symbol> {
+        templates::MarketSeriesManager::get_series(&env, series_id)
+            .map(|series| series.markets)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Series id and index of a market, if it was created by a series.
+    pub fn get_market_series(env: Env, market_id: Symbol) -> Option<SeriesPosition> {
+        templates::MarketSeriesManager::get_series_position(&env, &market_id)
     }
 
     /// Creates a new prediction event with specified parameters.
@@ -680,10 +1378,8 @@ impl PredictifyHybrid {
         fallback_oracle_config: Option<OracleConfig>,
         resolution_timeout: u64,
         visibility: EventVisibility,
-    ) -> Symbol {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+    ) -> Result<Symbol, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         // Authenticate that the caller is the admin
         admin.require_auth();
@@ -691,14 +1387,12 @@ impl PredictifyHybrid {
         // Verify the caller is an admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::AdminNotSet);
-            });
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
 
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
 
         // Get market configuration for limits
@@ -708,7 +1402,7 @@ impl PredictifyHybrid {
         let current_active_events =
             crate::storage::CreatorLimitsManager::get_active_events(&env, &admin);
         if current_active_events >= market_config.max_active_events_per_creator {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
         // Validate inputs using EventValidator
@@ -719,7 +1413,7 @@ impl PredictifyHybrid {
             &outcomes,
             &end_time,
         ) {
-            panic_with_error!(env, e.to_contract_error());
+            return Err(e.to_contract_error());
         }
 
         // Generate a unique collision-resistant event ID (reusing market ID generator)
@@ -747,10 +1441,7 @@ impl PredictifyHybrid {
         };
 
         // Collect creation fee before persisting the event so failed payments abort creation.
-        let creation_fee = match crate::markets::MarketUtils::process_creation_fee(&env, &admin) {
-            Ok(amount) => amount,
-            Err(e) => panic_with_error!(env, e),
-        };
+        let creation_fee = crate::markets::MarketUtils::process_creation_fee(&env, &admin)?;
 
         // Store the event
         crate::storage::EventManager::store_event(&env, &event);
@@ -786,7 +1477,7 @@ impl PredictifyHybrid {
             soroban_sdk::symbol_short!("cre_event"),
             gas_marker,
         );
-        event_id
+        Ok(event_id)
     }
 
     /// Retrieves an event by its unique identifier.
@@ -825,8 +1516,8 @@ impl PredictifyHybrid {
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
@@ -874,8 +1565,8 @@ impl PredictifyHybrid {
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
@@ -919,8 +1610,8 @@ impl PredictifyHybrid {
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
@@ -970,7 +1661,7 @@ impl PredictifyHybrid {
     ///
     /// ```rust
     /// # use soroban_sdk::{Env, Address, String, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # use predictify_hybrid::{CancelReason, PredictifyHybrid};
     /// # let env = Env::default();
     /// # let user = Address::generate(&env);
     /// # let market_id = Symbol::new(&env, "market_1");
@@ -996,54 +1687,51 @@ impl PredictifyHybrid {
     /// - Market must be in `Active` state
     /// - Current time must be before market end time
     /// - Market must not be cancelled or resolved
-    pub fn vote(env: Env, user: Address, market_id: Symbol, outcome: String, stake: i128) {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+    pub fn vote(env: Env, user: Address, market_id: Symbol, outcome: String, stake: i128) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         user.require_auth();
 
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Check if the market is still active
         if market.has_ended(&env) {
-            panic_with_error!(env, Error::MarketClosed);
+            return Err(Error::MarketClosed);
         }
+        markets::MarketAccessManager::require_allowed(&env, &market_id, &user)?;
+        admin::ComplianceManager::require_not_blocked(&env, &user)?;
 
         // Validate outcome
         let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
         if !outcome_exists {
-            panic_with_error!(env, Error::InvalidOutcome);
+            return Err(Error::InvalidOutcome);
         }
 
         // Check if user already voted
-        if market.votes.get(user.clone()).is_some() {
-            panic_with_error!(env, Error::AlreadyVoted);
+        if bets::BetStorage::get_position(&env, &market_id, &user).is_some() {
+            return Err(Error::AlreadyVoted);
         }
+        amounts::require_positive(stake)?;
 
         // Lock funds (transfer from user to contract)
-        match bets::BetUtils::lock_funds(&env, &user, stake) {
-            Ok(_) => {}
-            Err(e) => panic_with_error!(env, e),
-        }
+        bets::BetUtils::lock_funds(&env, &market, &user, stake)?;
 
-        // Store the vote and stake
-        market.votes.set(user.clone(), outcome.clone());
-        market.stakes.set(user.clone(), stake);
-        market.total_staked += stake;
+        // Store the vote as the user's position
+        let position = Bet::new(&env, user.clone(), market_id.clone(), outcome.clone(), stake);
+        bets::BetStorage::store_bet(&env, &position)?;
+        market.add_position(&outcome, stake);
+        market.total_staked = amounts::checked_add(market.total_staked, stake)?;
 
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        statistics::StatisticsManager::record_stake_escrowed(&env, stake);
+        escrow::EscrowManager::credit(&env, &market_id, stake)?;
+        statistics::StatisticsManager::record_market_joined(&env, &user, &market_id);
 
         // Emit vote cast event
         EventEmitter::emit_vote_cast(&env, &market_id, &user, &outcome, stake);
 
         crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("vote"), gas_marker);
+        Ok(())
     }
 
     /// Places a bet on a prediction market event by locking user funds.
@@ -1063,7 +1751,8 @@ impl PredictifyHybrid {
     ///
     /// # Returns
     ///
-    /// Returns the created `Bet` struct containing bet details on success.
+    /// Returns the user's `Bet` on the market; a repeat bet on the same outcome
+    /// returns the merged position.
     ///
     /// # Panics
     ///
@@ -1072,9 +1761,9 @@ impl PredictifyHybrid {
     /// - `Error::MarketClosed` - Market betting period has ended or market is not active
     /// - `Error::MarketResolved` - Market has already been resolved
     /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
-    /// - `Error::InsufficientStake` - Bet amount is below minimum (0.1 XLM)
-    /// - `Error::InvalidInput` - Bet amount exceeds maximum (10,000 XLM)
+    /// - `Error::ConflictingOutcome` - User already holds a position on a different outcome
+    /// - `Error::BetBelowMinimum` - Bet amount is below minimum (0.1 XLM)
+    /// - `Error::InvalidInput` - Bet amount, or the merged position, exceeds maximum (10,000 XLM)
     ///
     /// # Example
     ///
@@ -1091,7 +1780,8 @@ impl PredictifyHybrid {
     ///     user,
     ///     market_id,
     ///     String::from_str(&env, "Yes"),
-    ///     10_000_000 // 1.0 XLM in stroops
+    ///     10_000_000, // 1.0 XLM in stroops
+    ///     None,
     /// );
     /// ```
     ///
@@ -1105,11 +1795,12 @@ impl PredictifyHybrid {
     ///    - Losers forfeit their locked funds
     ///    - Refunds issued if market is cancelled
     ///
-    /// # Double Betting Prevention
+    /// # Position Merging
     ///
-    /// Users can only place ONE bet per market. Attempting to bet again will
-    /// result in an `Error::AlreadyBet` error. This ensures fair distribution
-    /// of rewards and prevents manipulation.
+    /// Users hold ONE position per market. Betting again on the same outcome adds
+    /// the amount to that position (the `bet_plc` event carries the new cumulative
+    /// stake), and payouts use the merged amount. Betting on a different outcome
+    /// results in an `Error::ConflictingOutcome` error.
     ///
     /// # Market State Requirements
     ///
@@ -1127,7 +1818,7 @@ impl PredictifyHybrid {
     ///
     /// This function allows users to place bets on markets with 2 or more outcomes.
     /// The outcome must be one of the valid outcomes defined when the market was created.
-    /// Users can only place one bet per market.
+    /// Users hold one position per market; repeat bets on the same outcome are merged.
     ///
     /// # Multi-Outcome Support
     ///
@@ -1142,6 +1833,8 @@ impl PredictifyHybrid {
     /// * `market_id` - Unique identifier of the market to bet on
     /// * `outcome` - The outcome to bet on (must match one of the market's outcomes)
     /// * `amount` - Amount of tokens to bet (must meet minimum/maximum bet limits)
+    /// * `referrer` - Optional referrer, recorded with the user's first bet (see
+    ///   `get_referrer`)
     ///
     /// # Returns
     ///
@@ -1150,12 +1843,20 @@ impl PredictifyHybrid {
     /// # Panics
     ///
     /// This function will panic with specific errors if:
+    /// - `Error::ContractPaused` - Contract is paused
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market is not active or has ended
+    /// - `Error::MarketClosed` - Market is not active, has ended, or its bet deadline has passed
     /// - `Error::InvalidOutcome` - Outcome doesn't match any market outcomes
-    /// - `Error::AlreadyBet` - User has already placed a bet on this market
-    /// - `Error::InsufficientStake` - Bet amount is below minimum
-    /// - `Error::InvalidInput` - Bet amount exceeds maximum
+    /// - `Error::ConflictingOutcome` - User already holds a position on a different outcome
+    /// - `Error::BetBelowMinimum` - Bet amount is below minimum
+    /// - `Error::InvalidAmount` - Bet amount is zero or negative
+    /// - `Error::InvalidInput` - Bet amount, or the merged position, exceeds maximum
+    /// - `Error::InsufficientBalance` - User holds less than `amount` of the market's token
+    /// - `Error::InvalidInput` - `referrer` is the user
+    /// - `Error::AlreadyBet` - `referrer` is given after the user's first bet, or
+    ///   differs from the referrer on record
+    /// - `Error::AddressBlocked` - `user` is blocked
+    /// - `Error::Unauthorized` - The market is private and `user` is not on its allowlist
     ///
     /// # Example
     ///
@@ -1173,6 +1874,7 @@ impl PredictifyHybrid {
     ///     market_id,
     ///     String::from_str(&env, "Team A"),
     ///     10_0000000, // 10 XLM
+    ///     None,
     /// );
     /// ```
     pub fn place_bet(
@@ -1181,28 +1883,108 @@ impl PredictifyHybrid {
         market_id: Symbol,
         outcome: String,
         amount: i128,
-    ) -> crate::types::Bet {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+        referrer: Option<Address>,
+    ) -> Result<crate::types::Bet, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
+            return Err(Error::InvalidState);
+        }
+        if let Some(referrer) = &referrer {
+            referrals::ReferralManager::attach_referrer(&env, &user, referrer)?;
         }
         // Use the BetManager to handle the bet placement
-        match bets::BetManager::place_bet(&env, user.clone(), market_id, outcome, amount) {
-            Ok(bet) => {
-                // Record statistics
-                statistics::StatisticsManager::record_bet_placed(&env, &user, amount);
-                crate::gas::GasTracker::end_tracking(
-                    &env,
-                    soroban_sdk::symbol_short!("place_bet"),
-                    gas_marker,
-                );
-                bet
-            }
-            Err(e) => panic_with_error!(env, e),
+        let bet = bets::BetManager::place_bet(&env, user.clone(), market_id, outcome, amount)?;
+        // Record statistics
+        statistics::StatisticsManager::record_bet_placed(&env, &user, amount);
+        crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("place_bet"), gas_marker);
+        Ok(bet)
+    }
+
+    /// `place_bet` for a market addressed by its number (see `get_market_number`).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - No market has this number
+    /// * Any error from `place_bet`
+    pub fn place_bet_by_id(
+        env: Env,
+        user: Address,
+        id: u64,
+        outcome: String,
+        amount: i128,
+        referrer: Option<Address>,
+    ) -> Result<crate::types::Bet, Error> {
+        let market_id = MarketIdGenerator::resolve_market_number(&env, id)?;
+        Self::place_bet(env, user, market_id, outcome, amount, referrer)
+    }
+
+    /// `place_bet` paid with the user's free-bet credit (see `grant_credit`)
+    /// instead of a token transfer.
+    ///
+    /// The stake joins the pools like any other. When the position is settled the
+    /// credited principal goes back to the fee vault, so a winner receives only
+    /// the winnings on top of it and a refund pays nothing back. Credit-backed
+    /// positions cannot be withdrawn, cancelled, cashed out or transferred.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InsufficientBalance` - Credit is missing, expired or too small
+    /// * `Error::InvalidState` - Market is denominated in its own token
+    /// * Any error from `place_bet`
+    pub fn place_bet_with_credit(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        outcome: String,
+        amount: i128,
+    ) -> Result<crate::types::Bet, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
         }
+        let bet =
+            bets::BetManager::place_bet_with_credit(&env, user.clone(), market_id, outcome, amount)?;
+        statistics::StatisticsManager::record_bet_placed(&env, &user, amount);
+        Ok(bet)
+    }
+
+    /// Grant `user` `amount` of free-bet credit spendable until `expires_at`
+    /// (admin only).
+    ///
+    /// The admin deposits `amount` of the staking token with the grant, so credit
+    /// is always backed. Credit already held is topped up and takes the new expiry.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidInput` - `amount` is not positive or `expires_at` has passed
+    /// * `Error::InsufficientBalance` - Admin cannot fund the deposit
+    pub fn grant_credit(
+        env: Env,
+        admin: Address,
+        user: Address,
+        amount: i128,
+        expires_at: u64,
+    ) -> Result<BetCredit, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        credits::CreditManager::grant_credit(&env, &admin, &user, amount, expires_at)
+    }
+
+    /// Free-bet credit held by `user`, if any. Check `expires_at` before spending.
+    pub fn get_bet_credit(env: Env, user: Address) -> Option<BetCredit> {
+        credits::CreditManager::get_credit(&env, &user)
+    }
+
+    /// Remove `user`'s unspent free-bet credit and return its deposit to the
+    /// admin, typically once it has expired (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::NothingToClaim` - `user` holds no credit
+    pub fn reclaim_credit(env: Env, admin: Address, user: Address) -> Result<i128, Error> {
+        credits::CreditManager::reclaim_credit(&env, &admin, &user)
     }
 
     /// Places multiple bets in a single atomic transaction.
@@ -1225,7 +2007,7 @@ impl PredictifyHybrid {
     ///
     /// This function will panic with specific errors if:
     /// - Any bet fails validation (market not found, closed, invalid outcome, etc.)
-    /// - User has insufficient balance for the total amount
+    /// - User has insufficient balance for the total amount (`Error::InsufficientBalance`)
     /// - User has already bet on any of the markets
     /// - Any bet amount is below minimum or above maximum
     /// - The batch is empty or exceeds maximum batch size
@@ -1263,25 +2045,15 @@ impl PredictifyHybrid {
         env: Env,
         user: Address,
         bets: Vec<(Symbol, String, i128)>,
-    ) -> Vec<crate::types::Bet> {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+    ) -> Result<Vec<crate::types::Bet>, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
-        }
-        match bets::BetManager::place_bets(&env, user, bets) {
-            Ok(placed_bets) => {
-                crate::gas::GasTracker::end_tracking(
-                    &env,
-                    soroban_sdk::symbol_short!("pl_bets"),
-                    gas_marker,
-                );
-                placed_bets
-            }
-            Err(e) => panic_with_error!(env, e),
+            return Err(Error::InvalidState);
         }
+        let placed_bets = bets::BetManager::place_bets(&env, user, bets)?;
+        crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("pl_bets"), gas_marker);
+        Ok(placed_bets)
     }
 
     /// Cancels a user's active bet before the market deadline.
@@ -1322,94 +2094,246 @@ impl PredictifyHybrid {
     /// // Cancel bet before market closes
     /// PredictifyHybrid::cancel_bet(env.clone(), user, market_id);
     /// ```
-    pub fn cancel_bet(env: Env, user: Address, market_id: Symbol) {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+    pub fn cancel_bet(env: Env, user: Address, market_id: Symbol) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
-        }
-        match bets::BetManager::cancel_bet(&env, user, market_id) {
-            Ok(_) => {},
-            Err(e) => panic_with_error!(env, e),
+            return Err(Error::InvalidState);
         }
+        bets::BetManager::cancel_bet(&env, user, market_id)?;
+        Ok(())
     }
 
-    /// Retrieves a user's bet on a specific market.
-    ///
-    /// This function provides read-only access to a user's bet details including
-    /// the selected outcome, locked amount, and bet status.
+    /// Withdraws a user's active bet before the betting deadline, minus a penalty.
     ///
-    /// # Parameters
-    ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `market_id` - Unique identifier of the market
-    /// * `user` - Address of the user whose bet to retrieve
+    /// Usable until `bet_deadline` (or `end_time` when no deadline is set). The
+    /// early-exit penalty (`get_early_exit_penalty`, 1% by default) is kept in the
+    /// fee vault and the rest of the stake is refunded. The position is removed
+    /// from the market, so `distribute_payouts` never pays it.
     ///
     /// # Returns
     ///
-    /// Returns `Some(Bet)` if the user has placed a bet on this market,
-    /// `None` if no bet exists.
-    ///
-    /// # Example
+    /// The amount refunded to the user.
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let user = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k");
+    /// # Errors
     ///
-    /// match PredictifyHybrid::get_bet(env.clone(), market_id, user) {
-    ///     Some(bet) => {
-    ///         // User has a bet
-    ///         println!("Bet amount: {}", bet.amount);
-    ///         println!("Selected outcome: {:?}", bet.outcome);
-    ///         println!("Status: {:?}", bet.status);
-    ///     },
-    ///     None => {
-    ///         // User has not placed a bet on this market
-    ///     }
-    /// }
-    /// ```
-    pub fn get_bet(env: Env, market_id: Symbol, user: Address) -> Option<crate::types::Bet> {
-        bets::BetManager::get_bet(&env, &market_id, &user)
+    /// - `Error::AddressBlocked` - User is blocked
+    /// - `Error::NothingToClaim` - User has no bet on this market
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidState` - Bet is not in Active status
+    /// - `Error::MarketClosed` - Market is not active or the betting deadline has passed
+    pub fn withdraw_bet(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        bets::BetManager::withdraw_bet(&env, user, market_id)
     }
 
-    /// Checks if a user has already placed a bet on a specific market.
-    ///
-    /// This function provides a quick check to determine if a user has
-    /// an existing bet on a market before attempting to place a new bet.
+    /// Cashes out a user's active position before resolution at its implied odds.
     ///
-    /// # Parameters
-    ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `market_id` - Unique identifier of the market
-    /// * `user` - Address of the user to check
+    /// Pays `stake * outcome_pool / total_pool` minus the cash-out fee
+    /// (`get_cash_out_fee`, 2% by default), which goes to the fee vault, and
+    /// removes the position. The rest of the stake stays in the pool for the
+    /// remaining bettors. Closes `CASH_OUT_CUTOFF_SECONDS` before the betting
+    /// deadline.
     ///
     /// # Returns
     ///
-    /// Returns `true` if the user has already placed a bet, `false` otherwise.
+    /// The amount paid to the user.
     ///
-    /// # Example
+    /// # Errors
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let user = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k");
+    /// - `Error::ContractPaused` - Contract is paused
+    /// - `Error::MarketFrozen` - Market is frozen
+    /// - `Error::NothingToClaim` - User has no bet on this market
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::InvalidState` - Bet is not in Active status, nobody holds the
+    ///   opposing side, or the market would not stay fully backed
+    /// - `Error::MarketClosed` - Market is not active or the cash-out window has closed
+    pub fn cash_out(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+        bets::BetManager::cash_out(&env, user, market_id)
+    }
+
+    /// Set the fee charged by `cash_out`, in basis points (admin only).
     ///
-    /// if PredictifyHybrid::has_user_bet(env.clone(), market_id.clone(), user.clone()) {
-    ///     println!("User has already placed a bet on this market");
-    /// } else {
-    ///     println!("User can place a bet");
-    /// }
-    /// ```
+    /// Returns `Error::InvalidInput` if `fee_bps` exceeds 10_000.
+    pub fn set_cash_out_fee(env: Env, admin: Address, fee_bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        bets::set_cash_out_fee_bps(&env, fee_bps)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("cash_fee"),
+            admin::AdminLogTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Returns the fee charged by `cash_out`, in basis points.
+    pub fn get_cash_out_fee(env: Env) -> u32 {
+        bets::get_cash_out_fee_bps(&env)
+    }
+
+    /// Moves a user's whole position on a market to another address before resolution.
+    ///
+    /// Requires auth from `from`. The stake and outcome move to `to`, merging into
+    /// any active position `to` holds on the same outcome. `to` must be allowed to
+    /// bet on the market, exactly as for `place_bet`. Payouts and refunds then go
+    /// to `to` only.
+    ///
+    /// # Returns
+    ///
+    /// `to`'s position after the transfer.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::ContractPaused` - Contract is paused
+    /// - `Error::MarketFrozen` - Market is frozen
+    /// - `Error::InvalidInput` - `from` is `to`, or the merged position exceeds the maximum bet
+    /// - `Error::NothingToClaim` - `from` has no bet on this market
+    /// - `Error::InvalidState` - `from`'s bet is not in Active status
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::MarketClosed` - Market was cancelled or is otherwise settled
+    /// - `Error::AddressBlocked` - Either address is blocked
+    /// - `Error::Unauthorized` - The market is private and `to` is not on its allowlist
+    /// - `Error::ConflictingOutcome` - `to` holds a position on a different outcome
+    /// - `Error::ThresholdTooHigh` - `to` would exceed the per-user exposure cap
+    pub fn transfer_position(
+        env: Env,
+        from: Address,
+        to: Address,
+        market_id: Symbol,
+    ) -> Result<Bet, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        bets::BetManager::transfer_position(&env, from, to, market_id)
+    }
+
+    /// Set the early-exit penalty charged by `withdraw_bet`, in basis points (admin only).
+    ///
+    /// Returns `Error::InvalidInput` if `penalty_bps` exceeds 10_000.
+    pub fn set_early_exit_penalty(env: Env, admin: Address, penalty_bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        bets::set_early_exit_penalty_bps(&env, penalty_bps)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("exit_pen"),
+            admin::AdminLogTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Returns the early-exit penalty charged by `withdraw_bet`, in basis points.
+    pub fn get_early_exit_penalty(env: Env) -> u32 {
+        bets::get_early_exit_penalty_bps(&env)
+    }
+
+    /// Retrieves a user's bet on a specific market.
+    ///
+    /// This function provides read-only access to a user's bet details including
+    /// the selected outcome, locked amount, and bet status.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `market_id` - Unique identifier of the market
+    /// * `user` - Address of the user whose bet to retrieve
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(Bet)` if the user has placed a bet on this market,
+    /// `None` if no bet exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k");
+    ///
+    /// match PredictifyHybrid::get_bet(env.clone(), market_id, user) {
+    ///     Some(bet) => {
+    ///         // User has a bet
+    ///         println!("Bet amount: {}", bet.amount);
+    ///         println!("Selected outcome: {:?}", bet.outcome);
+    ///         println!("Status: {:?}", bet.status);
+    ///     },
+    ///     None => {
+    ///         // User has not placed a bet on this market
+    ///     }
+    /// }
+    /// ```
+    pub fn get_bet(env: Env, market_id: Symbol, user: Address) -> Option<crate::types::Bet> {
+        bets::BetManager::get_bet(&env, &market_id, &user)
+    }
+
+    /// Checks if a user has already placed a bet on a specific market.
+    ///
+    /// This function provides a quick check to determine if a user has
+    /// an existing bet on a market before attempting to place a new bet.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `market_id` - Unique identifier of the market
+    /// * `user` - Address of the user to check
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the user has already placed a bet, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let user = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k");
+    ///
+    /// if PredictifyHybrid::has_user_bet(env.clone(), market_id.clone(), user.clone()) {
+    ///     println!("User has already placed a bet on this market");
+    /// } else {
+    ///     println!("User can place a bet");
+    /// }
+    /// ```
     pub fn has_user_bet(env: Env, market_id: Symbol, user: Address) -> bool {
         bets::BetManager::has_user_bet(&env, &market_id, &user)
     }
 
+    /// Returns `true` once a user's position on a market has been paid out or
+    /// refunded, whether by a claim, `distribute_payouts`, a refund or a sweep.
+    /// Read-only; no auth.
+    pub fn has_claimed(env: Env, market_id: Symbol, user: Address) -> bool {
+        bets::BetStorage::is_claimed(&env, &market_id, &user)
+    }
+
     /// Retrieves betting statistics for a specific market.
     ///
     /// This function provides aggregate information about betting activity
@@ -1606,6 +2530,19 @@ impl PredictifyHybrid {
         bets::BetAnalytics::calculate_payout_multiplier(&env, &market_id, &outcome)
     }
 
+    /// Returns live betting statistics for a market.
+    ///
+    /// Includes the total and per-outcome pools, bettor and bet counts, and the
+    /// fee-adjusted payout multiplier per outcome (scaled by 100). The figures are
+    /// maintained as bets are placed and cancelled, so this is a cheap read.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market with given ID doesn't exist
+    pub fn get_market_stats(env: Env, market_id: Symbol) -> Result<crate::types::MarketStats, Error> {
+        bets::BetAnalytics::get_market_stats(&env, &market_id)
+    }
+
     /// Allows users to claim their winnings from resolved prediction markets.
     ///
     /// This function enables users who voted for the winning outcome to claim
@@ -1625,6 +2562,14 @@ impl PredictifyHybrid {
     /// - `Error::AlreadyClaimed` - User has already claimed winnings from this market
     /// - `Error::MarketNotResolved` - Market hasn't been resolved yet
     /// - `Error::NothingToClaim` - User didn't vote or voted for losing outcome
+    /// - `Error::AddressBlocked` - User is blocked (see `set_blacklist`)
+    ///
+    /// # Returns
+    ///
+    /// The amount credited to the user's balance. Losing positions return `0`
+    /// and are still marked as claimed. When nobody backed the winning
+    /// outcome(s) the market settles in refund mode and every position is
+    /// credited its full stake, without the platform fee.
     ///
     /// # Example
     ///
@@ -1636,7 +2581,7 @@ impl PredictifyHybrid {
     /// # let market_id = Symbol::new(&env, "resolved_market");
     ///
     /// // Claim winnings from a resolved market
-    /// PredictifyHybrid::claim_winnings(
+    /// let paid = PredictifyHybrid::claim_winnings(
     ///     env.clone(),
     ///     user,
     ///     market_id
@@ -1645,30 +2590,42 @@ impl PredictifyHybrid {
     ///
     /// # Payout Calculation
     ///
-    /// Winnings are calculated using the formula:
+    /// Winnings are calculated with the same formula as `distribute_payouts`, so
+    /// pull-based claims and push-based distribution always reconcile:
     /// ```text
-    /// user_payout = (user_stake * (100 - fee_percentage) / 100) * total_pool / winning_total
+    /// user_payout = (user_stake * (10000 - fee_bps) / 10000) * total_pool / winning_total
     /// ```
     ///
     /// Where:
     /// - `user_stake` - Amount the user staked on the winning outcome
-    /// - `fee_percentage` - Platform fee (currently 2%)
+    /// - `fee_bps` - Platform fee in basis points (default 200 = 2%) plus the
+    ///   market's creator fee, which accrues to the creator
     /// - `total_pool` - Sum of all stakes in the market
-    /// - `winning_total` - Sum of stakes on the winning outcome
+    /// - `winning_total` - Sum of stakes on the winning outcome(s)
     ///
     /// # Market State Requirements
     ///
     /// - Market must be in `Resolved` state with a winning outcome set
     /// - User must have voted for the winning outcome
     /// - User must not have previously claimed winnings
-    pub fn claim_winnings(env: Env, user: Address, market_id: Symbol) {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+    pub fn claim_winnings(env: Env, user: Address, market_id: Symbol) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         user.require_auth();
-        Self::claim_winnings_internal(&env, &user, &market_id);
+        let payout = Self::claim_winnings_internal(&env, &user, &market_id, &user)?;
         crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("claim"), gas_marker);
+        Ok(payout)
+    }
+
+    /// `claim_winnings` for a market addressed by its number.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - No market has this number
+    /// * Any error from `claim_winnings`
+    pub fn claim_winnings_by_id(env: Env, user: Address, id: u64) -> Result<i128, Error> {
+        let market_id = MarketIdGenerator::resolve_market_number(&env, id)?;
+        Self::claim_winnings(env, user, market_id)
     }
 
     /// Claims winnings across multiple markets atomically for a single user.
@@ -1681,154 +2638,443 @@ impl PredictifyHybrid {
     /// Panics with:
     /// - `Error::InvalidInput` for empty batch, oversized batch, or duplicate market IDs
     /// - Any error from `claim_winnings` for invalid claims
-    pub fn batch_claim_winnings(env: Env, user: Address, market_ids: Vec<Symbol>) {
+    pub fn batch_claim_winnings(env: Env, user: Address, market_ids: Vec<Symbol>) -> Result<(), Error> {
         const MAX_BATCH_CLAIMS: u32 = 50;
 
         if market_ids.is_empty() {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
         if market_ids.len() as u32 > MAX_BATCH_CLAIMS {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
         // Pre-validate all claims to enforce all-or-nothing behavior.
         let mut seen_markets: Vec<Symbol> = Vec::new(&env);
         for market_id in market_ids.iter() {
             if seen_markets.contains(&market_id) {
-                panic_with_error!(env, Error::InvalidInput);
+                return Err(Error::InvalidInput);
             }
             seen_markets.push_back(market_id.clone());
 
-            let market: Market = env
-                .storage()
-                .persistent()
-                .get(&market_id)
-                .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+            let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
-            if market.claimed.get(user.clone()).unwrap_or(false) {
-                panic_with_error!(env, Error::AlreadyClaimed);
+            if bets::BetStorage::is_claimed(&env, &market_id, &user) {
+                return Err(Error::AlreadyClaimed);
             }
 
             let winning_outcomes = match &market.winning_outcomes {
                 Some(outcomes) => outcomes,
-                None => panic_with_error!(env, Error::MarketNotResolved),
+                None => return Err(Error::MarketNotResolved),
             };
 
-            let user_outcome = market
-                .votes
-                .get(user.clone())
-                .unwrap_or_else(|| panic_with_error!(env, Error::NothingToClaim));
+            let user_outcome = bets::BetStorage::get_position(&env, &market_id, &user)
+                .ok_or(Error::NothingToClaim)?
+                .outcome;
 
-            if !winning_outcomes.contains(&user_outcome) {
-                panic_with_error!(env, Error::NothingToClaim);
+            if !winning_outcomes.contains(&user_outcome)
+                && !markets::MarketUtils::is_refund_mode(&market)
+            {
+                return Err(Error::NothingToClaim);
             }
         }
 
         for market_id in market_ids.iter() {
-            Self::claim_winnings_internal(&env, &user, &market_id);
+            Self::claim_winnings_internal(&env, &user, &market_id, &user)?;
+        }
+        Ok(())
+    }
+
+    /// Claims whatever is claimable for `user` in each of `market_ids` and
+    /// returns the total paid out.
+    ///
+    /// Unlike `batch_claim_winnings`, a market that cannot be claimed (nothing
+    /// to claim, already claimed, unresolved, still in its dispute window, ...)
+    /// is skipped rather than reverting the batch. Every market gets a
+    /// `claim_res` event carrying the amount paid and an error code, 0 on success.
+    /// Markets are checked before any claim state is written; an error once a
+    /// claim has started (e.g. a failed transfer) reverts the whole call, so no
+    /// market is ever left marked claimed without being paid.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - More than `MAX_CLAIM_ALL_MARKETS` market IDs
+    /// - Any error raised while paying a market that passed the checks
+    pub fn claim_all(env: Env, user: Address, market_ids: Vec<Symbol>) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        if market_ids.len() > MAX_CLAIM_ALL_MARKETS {
+            return Err(Error::InvalidInput);
+        }
+        let gas_marker = crate::gas::GasTracker::start_tracking(&env);
+
+        let mut total: i128 = 0;
+        for market_id in market_ids.iter() {
+            // Skip a market only when it fails before anything is written; a
+            // failure while claiming reverts the whole call
+            if let Err(e) = Self::require_claimable(&env, &user, &market_id, &user) {
+                EventEmitter::emit_claim_result(&env, &market_id, &user, 0, e as u32);
+                continue;
+            }
+            let payout = Self::claim_winnings_internal(&env, &user, &market_id, &user)?;
+            total = amounts::checked_add(total, payout)?;
+            EventEmitter::emit_claim_result(&env, &market_id, &user, payout, 0);
         }
+
+        crate::gas::GasTracker::end_tracking(&env, soroban_sdk::symbol_short!("claim_all"), gas_marker);
+        Ok(total)
     }
 
-    fn claim_winnings_internal(env: &Env, user: &Address, market_id: &Symbol) {
+    /// Check that `user` can claim from `market_id` into `payee` without writing
+    /// anything, returning the market and the user's position.
+    /// `claim_winnings_internal` starts with it.
+    fn require_claimable(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        payee: &Address,
+    ) -> Result<(Market, Bet), Error> {
         if ReentrancyGuard::check_reentrancy_state(env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
+            return Err(Error::InvalidState);
         }
+        admin::ComplianceManager::require_not_blocked(env, payee)?;
 
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(market_id)
-            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+        let market = markets::MarketStateManager::get_market(env, market_id)?;
+        if market.frozen {
+            return Err(Error::MarketFrozen);
+        }
 
         // Enforce claim timeout period
         let claim_period = Self::get_effective_claim_period(env.clone(), market_id.clone());
         let claim_deadline = market.end_time.saturating_add(claim_period);
         if env.ledger().timestamp() >= claim_deadline {
-            panic_with_error!(env, Error::ResolutionTimeoutReached);
+            return Err(Error::ResolutionTimeoutReached);
         }
 
         // Check if user has claimed already
-        if market.claimed.get(user.clone()).unwrap_or(false) {
-            panic_with_error!(env, Error::AlreadyClaimed);
+        if bets::BetStorage::is_claimed(env, market_id, user) {
+            return Err(Error::AlreadyClaimed);
         }
 
         // Check if market is resolved
-        let winning_outcomes = match &market.winning_outcomes {
-            Some(outcomes) => outcomes,
-            None => panic_with_error!(env, Error::MarketNotResolved),
-        };
+        if market.winning_outcomes.is_none() {
+            return Err(Error::MarketNotResolved);
+        }
+        if disputes::DisputeUtils::has_open_result_dispute(env, market_id) {
+            return Err(Error::InvalidState);
+        }
+        // Claims open with payouts, once the dispute window after resolution closes
+        if env.ledger().timestamp() < market.payout_unlock_time() {
+            return Err(Error::InvalidState);
+        }
 
-        // Get user's vote
-        let user_outcome = market
-            .votes
-            .get(user.clone())
-            .unwrap_or_else(|| panic_with_error!(env, Error::NothingToClaim));
-
-        let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
-
-        // Calculate payout if user won (check if outcome is in winning outcomes)
-        if winning_outcomes.contains(&user_outcome) {
-            // Calculate total winning stakes across all winning outcomes
-            let mut winning_total = 0;
-            for (voter, outcome) in market.votes.iter() {
-                if winning_outcomes.contains(&outcome) {
-                    winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                }
-            }
+        // Check the user holds a position
+        let position =
+            bets::BetStorage::get_position(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        Ok((market, position))
+    }
 
-            if winning_total > 0 {
-                // Retrieve dynamic platform fee percentage from configuration
-                let cfg = match crate::config::ConfigManager::get_config(env) {
-                    Ok(c) => c,
-                    Err(_) => panic_with_error!(env, Error::ConfigNotFound),
-                };
-                let fee_percent = cfg.fees.platform_fee_percentage;
-                let user_share = (user_stake
-                    .checked_mul(PERCENTAGE_DENOMINATOR - fee_percent)
-                    .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput)))
-                    / PERCENTAGE_DENOMINATOR;
-                let total_pool = market.total_staked;
-                let product = user_share
-                    .checked_mul(total_pool)
-                    .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
-                let payout = product / winning_total;
-
-                let product_gross = user_stake
-                    .checked_mul(total_pool)
-                    .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
-                let gross_payout = product_gross / winning_total;
-                let fee_amount = gross_payout - payout;
+    fn claim_winnings_internal(
+        env: &Env,
+        user: &Address,
+        market_id: &Symbol,
+        payee: &Address,
+    ) -> Result<i128, Error> {
+        let (market, mut bet) = Self::require_claimable(env, user, market_id, payee)?;
+        let winning_outcomes = market.winning_outcomes.clone().unwrap();
+        let user_outcome = bet.outcome.clone();
+
+        let user_stake = bet.amount;
+        let won = winning_outcomes.contains(&user_outcome);
+        let refund = markets::MarketUtils::is_refund_mode(&market);
+
+        let mut payout = 0i128;
+        if refund {
+            // Nobody backed the winning outcome(s): return the full stake, no fee
+            payout = user_stake;
+        } else if won {
+            let (winning_total, total_pool) =
+                markets::MarketUtils::winning_pool(&market, &winning_outcomes, &user_outcome)?;
+            payout = Self::settle_winner_payout(
+                env,
+                market_id,
+                &market,
+                user,
+                user_stake,
+                winning_total,
+                total_pool,
+            )?;
 
+            if payout > 0 {
+                let gross_payout = Self::calculate_winner_payout(
+                    user_stake,
+                    winning_total,
+                    total_pool,
+                    0,
+                )
+                ?;
                 statistics::StatisticsManager::record_winnings_claimed(env, user, payout);
-                statistics::StatisticsManager::record_fees_collected(env, fee_amount);
+                // A remainder on the last payout can exceed its fee
+                let fees_paid = (gross_payout - payout).max(0);
+                statistics::StatisticsManager::record_fees_collected(env, fees_paid);
+            }
+        }
 
-                // Mark as claimed
-                market.claimed.set(user.clone(), true);
-                env.storage().persistent().set(market_id, &market);
+        // Mark as claimed before crediting so a failed credit can never be retried into a double payout.
+        // Losers are marked too, to prevent re-attempts.
+        bets::BetStorage::mark_claimed(env, market_id, user);
+        escrow::EscrowManager::debit(env, market_id, payout)?;
+        // Free-bet principal goes back to the vault; the user keeps the winnings
+        let payout = credits::CreditManager::settle(env, market_id, user, payout)?;
 
-                // Emit winnings claimed event
-                EventEmitter::emit_winnings_claimed(env, market_id, user, payout);
+        // Resolution marked every bet lost; refund mode settles them as refunded
+        if bet.is_active() || (refund && bet.is_resolved()) {
+            if refund {
+                bet.mark_as_refunded();
+            } else if won {
+                bet.mark_as_won();
+            } else {
+                bet.mark_as_lost();
+            }
+            let _ = bets::BetStorage::store_bet(env, &bet);
+        }
 
-                // Credit tokens to user balance
-                match storage::BalanceStorage::add_balance(
-                    env,
-                    user,
-                    &types::ReflectorAsset::Stellar,
-                    payout,
-                ) {
-                    Ok(_) => {}
-                    Err(e) => panic_with_error!(env, e),
-                }
+        if payout > 0 {
+            EventEmitter::emit_winnings_claimed(env, market_id, user, payout);
+
+            // Credit tokens to payee balance (or pay out in the market's own token)
+            Self::credit_payout(env, &market, payee, payout)?;
+        }
 
-                return;
+        Ok(payout)
+    }
+
+    /// Credit a payout from a market to `recipient`.
+    ///
+    /// Markets on the global token credit the recipient's internal balance. Markets
+    /// with their own `token` transfer that asset directly, since internal balances
+    /// are only tracked for the global token. The transfer runs under the reentrancy
+    /// lock, so callers must have written the claim to storage beforehand.
+    fn credit_payout(
+        env: &Env,
+        market: &Market,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        match &market.token {
+            Some(_) => {
+                let token_client = markets::MarketUtils::get_market_token_client(env, market)?;
+                ReentrancyGuard::guarded(env, || {
+                    token_client.transfer(&env.current_contract_address(), recipient, &amount)
+                })
+                .map_err(|_| Error::InvalidState)
             }
+            None => storage::BalanceStorage::add_balance(
+                env,
+                recipient,
+                &types::ReflectorAsset::Stellar,
+                amount,
+            )
+            .map(|_| ()),
+        }
+    }
+
+    /// Total fee (basis points) applied to winning payouts of a market: the
+    /// platform fee snapshotted when the market was created plus the creator fee.
+    ///
+    /// Shared by `claim_winnings`, `claim_winnings_batch`, `sweep_unclaimed_winnings`
+    /// and `distribute_payouts` so every payout path uses identical math.
+    fn payout_fee_bps(market: &Market) -> i128 {
+        market.fee_bps as i128 + market.creator_fee_bps as i128
+    }
+
+    /// Platform fee in basis points charged on `user`'s winnings: none for
+    /// fee-exempt partners, checked at the time of each claim.
+    fn platform_fee_bps_for(env: &Env, market: &Market, user: &Address) -> i128 {
+        if fees::FeeManager::is_fee_exempt(env, user) {
+            0
+        } else {
+            market.fee_bps as i128
+        }
+    }
+
+    /// Payout for `user`'s winning stake, net of fees, plus the market's rounding
+    /// remainder when `user` is the last winner paid.
+    ///
+    /// Accrues the creator's share and the referrer's share of the platform fee.
+    /// For fee-exempt winners, releases the platform fee they would have paid
+    /// from the market's fee so it is paid to them instead.
+    fn settle_winner_payout(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+        user_stake: i128,
+        winning_total: i128,
+        total_pool: i128,
+    ) -> Result<i128, Error> {
+        let platform_bps = Self::platform_fee_bps_for(env, market, user);
+        let payout = Self::calculate_winner_payout(
+            user_stake,
+            winning_total,
+            total_pool,
+            platform_bps + market.creator_fee_bps as i128,
+        )?;
+        let creator_share = Self::accrue_creator_fee(
+            env,
+            market,
+            platform_bps,
+            user_stake,
+            winning_total,
+            total_pool,
+            payout,
+        )?;
+        escrow::EscrowManager::debit(env, market_id, creator_share)?;
+        let without_platform_fee = Self::calculate_winner_payout(
+            user_stake,
+            winning_total,
+            total_pool,
+            market.creator_fee_bps as i128,
+        )?;
+        if platform_bps < market.fee_bps as i128 {
+            let charged = Self::calculate_winner_payout(
+                user_stake,
+                winning_total,
+                total_pool,
+                Self::payout_fee_bps(market),
+            )?;
+            let waived = amounts::checked_sub(without_platform_fee, charged)?;
+            fees::FeeTracker::release_market_fee(env, market_id, market, waived)?;
+        } else {
+            let platform_fee = amounts::checked_sub(without_platform_fee, payout)?;
+            referrals::ReferralManager::accrue(env, market_id, market, user, platform_fee)?;
+        }
+        // The last winner paid also takes the pool's rounding remainder
+        let entitlement = Self::calculate_winner_payout(
+            user_stake,
+            winning_total,
+            total_pool,
+            market.fee_bps as i128,
+        )?;
+        let remainder = escrow::EscrowManager::settle_winner_share(
+            env,
+            market_id,
+            market,
+            user_stake,
+            entitlement,
+        )?;
+        amounts::checked_add(payout, remainder)
+    }
+
+    /// Accrue the creator's share of a winning payout to the market creator.
+    ///
+    /// The share is what the winner would have received with only the platform fee
+    /// (`platform_bps`) deducted, minus `payout`, so the platform fee is never
+    /// charged twice. Returns the share accrued.
+    fn accrue_creator_fee(
+        env: &Env,
+        market: &Market,
+        platform_bps: i128,
+        user_stake: i128,
+        winning_total: i128,
+        total_pool: i128,
+        payout: i128,
+    ) -> Result<i128, Error> {
+        if market.creator_fee_bps == 0 {
+            return Ok(0);
+        }
+        let after_platform_fee = Self::calculate_winner_payout(
+            user_stake,
+            winning_total,
+            total_pool,
+            platform_bps,
+        )?;
+        let share = amounts::checked_sub(after_platform_fee, payout)?;
+        if share > 0 {
+            fees::FeeTracker::credit_creator_fees(env, &market.creator, share)?;
+        }
+        Ok(share.max(0))
+    }
+
+    /// Payout `position` would receive if `winning_outcomes` won.
+    ///
+    /// Zero when it backed a losing outcome; the full stake when nobody backed
+    /// `winning_outcomes` (refund mode). Used by the read-only queries so
+    /// previews and positions match the claim paths.
+    fn position_payout(
+        env: &Env,
+        market: &Market,
+        position: &Bet,
+        winning_outcomes: &Vec<String>,
+    ) -> Result<i128, Error> {
+        if markets::MarketUtils::winning_stake_total(market, winning_outcomes) == 0 {
+            return Ok(position.amount);
         }
+        if !winning_outcomes.contains(&position.outcome) {
+            return Ok(0);
+        }
+        let (winning_total, total_pool) =
+            markets::MarketUtils::winning_pool(market, winning_outcomes, &position.outcome)?;
+        Self::calculate_winner_payout(
+            position.amount,
+            winning_total,
+            total_pool,
+            Self::platform_fee_bps_for(env, market, &position.user)
+                + market.creator_fee_bps as i128,
+        )
+    }
 
-        // If no winnings (user didn't win or zero payout), still mark as claimed to prevent re-attempts
-        market.claimed.set(user.clone(), true);
-        env.storage().persistent().set(&market_id, &market);
+    /// Unclaimed payouts of `market` if `winning_outcomes` won, in the order
+    /// `distribute_payouts` settles positions.
+    ///
+    /// Returns every non-zero `(user, payout)` pair and their total.
+    fn pending_payouts(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        winning_outcomes: &Vec<String>,
+    ) -> Result<(Vec<(Address, i128)>, i128), Error> {
+        let mut payouts = Vec::new(env);
+        let mut total: i128 = 0;
+        for index in 0..bets::BetStorage::get_bettor_count(env, market_id) {
+            let Some(user) = bets::BetStorage::get_bettor(env, market_id, index) else {
+                continue;
+            };
+            if bets::BetStorage::is_claimed(env, market_id, &user)
+                || admin::ComplianceManager::is_blocked(env, &user)
+            {
+                continue;
+            }
+            let Some(position) = bets::BetStorage::get_position(env, market_id, &user) else {
+                continue;
+            };
+            let payout = Self::position_payout(env, market, &position, winning_outcomes)?;
+            // Free-bet principal goes to the vault, as in `distribute_payouts`
+            let credited = credits::CreditManager::get_credit_stake(env, market_id, &user);
+            let payout = payout - credited.min(payout);
+            if payout <= 0 {
+                continue;
+            }
+            payouts.push_back((user, payout));
+            total = amounts::checked_add(total, payout)?;
+        }
+        Ok((payouts, total))
+    }
 
+    /// Payout for a single winning stake:
+    /// `(stake * (10000 - fee_bps) / 10000) * total_pool / winning_total`.
+    fn calculate_winner_payout(
+        user_stake: i128,
+        winning_total: i128,
+        total_pool: i128,
+        fee_bps: i128,
+    ) -> Result<i128, Error> {
+        if winning_total <= 0 || user_stake <= 0 {
+            return Ok(0);
+        }
+        let user_share =
+            amounts::mul_div(user_stake, FEE_BPS_DENOMINATOR - fee_bps, FEE_BPS_DENOMINATOR)?;
+        amounts::mul_div(user_share, total_pool, winning_total)
     }
 
     /// Sweeps unclaimed winning payouts after claim timeout to treasury or burns them.
@@ -1841,18 +3087,18 @@ impl PredictifyHybrid {
         caller: Address,
         market_id: Symbol,
         burn: bool,
-    ) -> i128 {
+    ) -> Result<i128, Error> {
         caller.require_auth();
 
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
+            return Err(Error::InvalidState);
         }
 
         let admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         let treasury_opt: Option<Address> = env
             .storage()
@@ -1865,96 +3111,127 @@ impl PredictifyHybrid {
             .unwrap_or(false);
 
         if caller != admin && !is_treasury {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
 
-        let mut market: Market = env
+        Self::sweep_unclaimed_internal(&env, &caller, &market_id, burn)
+    }
+
+    /// Body of `sweep_unclaimed_winnings` once `caller` is authorized: sweeps to
+    /// the treasury (or the admin when none is set) unless `burn`.
+    fn sweep_unclaimed_internal(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        burn: bool,
+    ) -> Result<i128, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        let treasury_opt: Option<Address> = env
             .storage()
             .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| panic_with_error!(env, Error::MarketNotFound));
+            .get(&Symbol::new(env, TREASURY_STORAGE_KEY));
+
+        let mut market = markets::MarketStateManager::get_market(env, market_id)?;
 
         let winning_outcomes = match &market.winning_outcomes {
             Some(outcomes) => outcomes,
-            None => panic_with_error!(env, Error::MarketNotResolved),
+            None => return Err(Error::MarketNotResolved),
         };
 
         let claim_period = Self::get_effective_claim_period(env.clone(), market_id.clone());
         let claim_deadline = market.end_time.saturating_add(claim_period);
         if env.ledger().timestamp() < claim_deadline {
-            panic_with_error!(env, Error::InvalidState);
+            return Err(Error::InvalidState);
         }
 
-        let cfg = match crate::config::ConfigManager::get_config(&env) {
-            Ok(c) => c,
-            Err(_) => panic_with_error!(env, Error::ConfigNotFound),
-        };
-        let fee_percent = cfg.fees.platform_fee_percentage;
+        let fee_bps = Self::payout_fee_bps(&market);
 
-        // Calculate total winning stake across all winning outcomes
-        let mut winning_total = 0i128;
-        for (voter, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                winning_total += market.stakes.get(voter).unwrap_or(0);
-            }
-        }
+        // Calculate total winning stake across all winning outcomes; in refund
+        // mode every unclaimed stake is swept instead
+        let winning_total = markets::MarketUtils::winning_stake_total(&market, winning_outcomes);
+        let refund = markets::MarketUtils::is_refund_mode(&market);
 
-        if winning_total <= 0 {
-            panic_with_error!(env, Error::NothingToClaim);
+        if winning_total <= 0 && !refund {
+            return Err(Error::NothingToClaim);
         }
 
-        let total_pool = market.total_staked;
         let mut sweep_total = 0i128;
+        let mut creator_shares = 0i128;
 
-        for (voter, outcome) in market.votes.iter() {
-            if !winning_outcomes.contains(&outcome) {
+        for index in 0..bets::BetStorage::get_bettor_count(env, market_id) {
+            let Some(voter) = bets::BetStorage::get_bettor(env, market_id, index) else {
+                continue;
+            };
+            let Some(position) = bets::BetStorage::get_position(env, market_id, &voter) else {
+                continue;
+            };
+            let outcome = position.outcome;
+            if !refund && !winning_outcomes.contains(&outcome) {
                 continue;
             }
 
-            if market.claimed.get(voter.clone()).unwrap_or(false) {
+            if bets::BetStorage::is_claimed(env, market_id, &voter) {
                 continue;
             }
 
-            let user_stake = market.stakes.get(voter.clone()).unwrap_or(0);
+            let user_stake = position.amount;
             if user_stake <= 0 {
                 continue;
             }
 
-            let user_share = (user_stake
-                .checked_mul(PERCENTAGE_DENOMINATOR - fee_percent)
-                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput)))
-                / PERCENTAGE_DENOMINATOR;
-
-            let payout = user_share
-                .checked_mul(total_pool)
-                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput))
-                / winning_total;
-
-            if payout > 0 {
-                sweep_total += payout;
-            }
+            let payout = if refund {
+                user_stake
+            } else {
+                let (winning_total, total_pool) =
+                    markets::MarketUtils::winning_pool(&market, winning_outcomes, &outcome)?;
+                let payout =
+                    Self::calculate_winner_payout(user_stake, winning_total, total_pool, fee_bps)?;
+                let creator_share = Self::accrue_creator_fee(
+                    env,
+                    &market,
+                    market.fee_bps as i128,
+                    user_stake,
+                    winning_total,
+                    total_pool,
+                    payout,
+                )?;
+                creator_shares = amounts::checked_add(creator_shares, creator_share)?;
+                let remainder = escrow::EscrowManager::settle_winner_share(
+                    env,
+                    market_id,
+                    &market,
+                    user_stake,
+                    amounts::checked_add(payout, creator_share)?,
+                )?;
+                amounts::checked_add(payout, remainder)?
+            };
+
+            if payout > 0 {
+                sweep_total = amounts::checked_add(sweep_total, payout)?;
+            }
 
-            market.claimed.set(voter, true);
+            bets::BetStorage::mark_claimed(env, market_id, &voter);
         }
 
         if sweep_total <= 0 {
-            panic_with_error!(env, Error::NothingToClaim);
+            return Err(Error::NothingToClaim);
         }
+        escrow::EscrowManager::debit(
+            env,
+            market_id,
+            amounts::checked_add(sweep_total, creator_shares)?,
+        )?;
 
         if !burn {
             let recipient = treasury_opt.clone().unwrap_or(admin.clone());
-            match storage::BalanceStorage::add_balance(
-                &env,
-                &recipient,
-                &types::ReflectorAsset::Stellar,
-                sweep_total,
-            ) {
-                Ok(_) => {}
-                Err(e) => panic_with_error!(env, e),
-            }
+            Self::credit_payout(env, &market, &recipient, sweep_total)?;
         }
 
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(env, market_id, &market);
 
         let recipient_for_event = if burn {
             None
@@ -1962,19 +3239,19 @@ impl PredictifyHybrid {
             let treasury_for_event: Option<Address> = env
                 .storage()
                 .persistent()
-                .get(&Symbol::new(&env, TREASURY_STORAGE_KEY));
+                .get(&Symbol::new(env, TREASURY_STORAGE_KEY));
             Some(treasury_for_event.unwrap_or(admin.clone()))
         };
         EventEmitter::emit_unclaimed_winnings_swept(
-            &env,
-            &market_id,
-            &caller,
+            env,
+            market_id,
+            caller,
             &recipient_for_event,
             sweep_total,
             burn,
         );
 
-        sweep_total
+        Ok(sweep_total)
     }
 
     /// Claims winnings for multiple resolved markets in a single atomic transaction.
@@ -1993,12 +3270,14 @@ impl PredictifyHybrid {
     ///
     /// - User must authorize the transaction via `require_auth()`
     /// - Each market validates: exists, is resolved, user hasn't claimed, user participated
+    /// - Each market is then claimed through the same path as `claim_winnings`, so the
+    ///   claim window, open result disputes and the payout unlock time all apply
     /// - Prevents double-claiming through the `claimed` map
     /// - Uses reentrancy guard for protection
     ///
     /// # Payout Calculation
     ///
-    /// For each market: `payout = (stake * (100 - fee%) / 100) * total_pool / winning_total`
+    /// For each market, the same payout as `claim_winnings`.
     ///
     /// # Returns Error On
     ///
@@ -2007,135 +3286,75 @@ impl PredictifyHybrid {
     /// - `AlreadyClaimed` - User already claimed from any market
     /// - `NothingToClaim` - User didn't vote on any market
     /// - `InvalidInput` - Empty market vector
-    /// - `InvalidState` - Reentrancy detected
-    pub fn claim_winnings_batch(env: Env, user: Address, market_ids: Vec<Symbol>) {
+    /// - `InvalidState` - Reentrancy detected, a result dispute is open on any market,
+    ///   or any market's payouts are not unlocked yet
+    /// - `ResolutionTimeoutReached` - The claim period of any market has passed
+    /// - `MarketFrozen` - Any market is frozen
+    pub fn claim_winnings_batch(env: Env, user: Address, market_ids: Vec<Symbol>) -> Result<(), Error> {
         user.require_auth();
+        admin::ComplianceManager::require_not_blocked(&env, &user)?;
 
         // Validate reentrancy state
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            panic_with_error!(env, Error::InvalidState);
+            return Err(Error::InvalidState);
         }
 
         // Early validation: ensure market_ids is not empty
         if market_ids.len() == 0 {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
         }
 
-        // Retrieve configuration once for all market calculations
-        let cfg = match crate::config::ConfigManager::get_config(&env) {
-            Ok(c) => c,
-            Err(_) => panic_with_error!(env, Error::ConfigNotFound),
-        };
-        let fee_percent = cfg.fees.platform_fee_percentage;
-
         // First pass: Validate all markets before making any state changes
         // This ensures atomicity - if any market is invalid, we revert without changing state
         for i in 0..market_ids.len() {
             let market_id = market_ids.get(i).unwrap();
 
-            let market: Market = env
-                .storage()
-                .persistent()
-                .get(&market_id)
-                .unwrap_or_else(|| {
-                    panic_with_error!(env, Error::MarketNotFound);
-                });
+            let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+            if market.frozen {
+                return Err(Error::MarketFrozen);
+            }
 
             // Check if user has already claimed from this market
-            if market.claimed.get(user.clone()).unwrap_or(false) {
-                panic_with_error!(env, Error::AlreadyClaimed);
+            if bets::BetStorage::is_claimed(&env, &market_id, &user) {
+                return Err(Error::AlreadyClaimed);
             }
 
             // Check if market is resolved and has winning outcomes
             if market.winning_outcomes.is_none() {
-                panic_with_error!(env, Error::MarketNotResolved);
+                return Err(Error::MarketNotResolved);
             }
 
             // Check if user participated in this market
-            if !market.votes.contains_key(user.clone()) {
-                panic_with_error!(env, Error::NothingToClaim);
+            if bets::BetStorage::get_position(&env, &market_id, &user).is_none() {
+                return Err(Error::NothingToClaim);
+            }
+
+            // Same claim window, dispute and payout unlock gates as `claim_winnings`
+            let claim_period = Self::get_effective_claim_period(env.clone(), market_id.clone());
+            let now = env.ledger().timestamp();
+            if now >= market.end_time.saturating_add(claim_period) {
+                return Err(Error::ResolutionTimeoutReached);
+            }
+            if disputes::DisputeUtils::has_open_result_dispute(&env, &market_id) {
+                return Err(Error::InvalidState);
+            }
+            if now < market.payout_unlock_time() {
+                return Err(Error::InvalidState);
             }
         }
 
-        // Second pass: Process all markets and calculate total winnings
+        // Second pass: claim each market exactly as `claim_winnings` would; any
+        // failure reverts the whole batch
         let mut total_payout: i128 = 0;
         let mut batch_claims: Vec<(Symbol, i128)> = Vec::new(&env);
-
-        for i in 0..market_ids.len() {
-            let market_id = market_ids.get(i).unwrap();
-
-            let mut market: Market = env.storage().persistent().get(&market_id).unwrap();
-
-            let winning_outcomes = market.winning_outcomes.clone().unwrap();
-            let user_outcome = market.votes.get(user.clone()).unwrap();
-            let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
-
-            // Calculate payout if user won
-            let market_payout = if winning_outcomes.contains(&user_outcome) {
-                // Calculate total winning stakes
-                let mut winning_total = 0;
-                for (voter, outcome) in market.votes.iter() {
-                    if winning_outcomes.contains(&outcome) {
-                        winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                    }
-                }
-
-                if winning_total > 0 {
-                    let user_share = (user_stake
-                        .checked_mul(PERCENTAGE_DENOMINATOR - fee_percent)
-                        .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput)))
-                        / PERCENTAGE_DENOMINATOR;
-                    let total_pool = market.total_staked;
-                    let product = user_share
-                        .checked_mul(total_pool)
-                        .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
-                    let payout = product / winning_total;
-
-                    // Calculate fee for statistics
-                    let product_gross = user_stake
-                        .checked_mul(total_pool)
-                        .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
-                    let gross_payout = product_gross / winning_total;
-                    let fee_amount = gross_payout - payout;
-
-                    statistics::StatisticsManager::record_fees_collected(&env, fee_amount);
-                    payout
-                } else {
-                    0
-                }
-            } else {
-                0
-            };
-
-            // Update market state: mark as claimed
-            market.claimed.set(user.clone(), true);
-            env.storage().persistent().set(&market_id, &market);
-
-            // Track claim for event emission
-            batch_claims.push_back((market_id.clone(), market_payout));
-            total_payout = total_payout
-                .checked_add(market_payout)
-                .unwrap_or_else(|| panic_with_error!(env, Error::InvalidInput));
+        for market_id in market_ids.iter() {
+            let market_payout = Self::claim_winnings_internal(&env, &user, &market_id, &user)?;
+            batch_claims.push_back((market_id, market_payout));
+            total_payout = amounts::checked_add(total_payout, market_payout)?;
         }
 
-        // Record total winnings claimed in statistics
-        statistics::StatisticsManager::record_winnings_claimed(&env, &user, total_payout);
-
-        // Emit batch winnings claimed event
         EventEmitter::emit_winnings_claimed_batch(&env, &user, &batch_claims, total_payout);
-
-        // Credit total tokens to user balance (single operation)
-        if total_payout > 0 {
-            match storage::BalanceStorage::add_balance(
-                &env,
-                &user,
-                &types::ReflectorAsset::Stellar,
-                total_payout,
-            ) {
-                Ok(_) => {}
-                Err(e) => panic_with_error!(env, e),
-            }
-        }
+        Ok(())
     }
 
     /// Retrieves complete market information by market identifier.
@@ -2192,10 +3411,221 @@ impl PredictifyHybrid {
     /// This is a read-only operation that doesn't modify contract state.
     /// It retrieves data from persistent storage with minimal computational overhead.
     pub fn get_market(env: Env, market_id: Symbol) -> Option<Market> {
-        env.storage().persistent().get(&market_id)
+        markets::MarketStateManager::get_market(&env, &market_id).ok()
+    }
+
+    /// Returns a market together with the values front ends would otherwise
+    /// derive themselves: effective state, betting deadline, dispute window end,
+    /// payout unlock time and per-outcome pools.
+    ///
+    /// Computed on every call from the stored market and the ledger time;
+    /// `get_market` still returns the raw stored market. Read-only; no auth.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market with given ID doesn't exist
+    pub fn get_market_view(env: Env, market_id: Symbol) -> Result<MarketView, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let state = markets::MarketStateLogic::derive_market_state(&env, &market_id, &market);
+
+        let mut outcome_pools = Map::new(&env);
+        for outcome in market.outcomes.iter() {
+            outcome_pools.set(outcome.clone(), market.outcome_pool(&outcome));
+        }
+        // Disputes are accepted until payouts unlock, and an open one blocks
+        // payouts until it is ruled on
+        let dispute_window_end = market
+            .winning_outcomes
+            .as_ref()
+            .map(|_| market.payout_unlock_time());
+        let payout_unlock_at = dispute_window_end.filter(|_| state != MarketState::Disputed);
+
+        Ok(MarketView {
+            market_id,
+            question: market.question.clone(),
+            outcomes: market.outcomes.clone(),
+            state,
+            end_time: market.end_time,
+            betting_deadline: market.betting_deadline(),
+            resolution_deadline: market.resolution_deadline,
+            dispute_window_end,
+            payout_unlock_at,
+            total_pool: market.total_staked,
+            outcome_pools,
+            winning_outcomes: market.winning_outcomes.clone(),
+            fee_collected: market.fee_collected,
+            frozen: market.frozen,
+        })
+    }
+
+    /// Returns the market with the given number, or `None` if there is none.
+    ///
+    /// Markets are numbered sequentially as they are created. Markets created
+    /// before numbering was introduced have no number and remain reachable
+    /// through `get_market` with their `Symbol` ID.
+    pub fn get_market_by_id(env: Env, id: u64) -> Option<Market> {
+        let market_id = MarketIdGenerator::get_market_symbol(&env, id)?;
+        Self::get_market(env, market_id)
+    }
+
+    /// Returns a market's number, or `None` for markets created before
+    /// numbering was introduced.
+    pub fn get_market_number(env: Env, market_id: Symbol) -> Option<u64> {
+        MarketIdGenerator::get_market_number(&env, &market_id)
+    }
+
+    /// Returns the `Symbol` ID of the market with the given number.
+    pub fn get_market_symbol(env: Env, id: u64) -> Option<Symbol> {
+        MarketIdGenerator::get_market_symbol(&env, id)
+    }
+
+    /// Returns a user's position in a market, or `None` if the market does not
+    /// exist or the user holds no stake in it.
+    ///
+    /// The claimable amount uses the same formula as `claim_winnings`, net of the
+    /// market fee. It is reported as soon as the market is resolved, even while
+    /// the dispute window still blocks the claim itself. Read-only; no auth.
+    pub fn get_user_position(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+    ) -> Option<crate::types::Position> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id).ok()?;
+        let position = bets::BetStorage::get_position(&env, &market_id, &user)?;
+        let mut stakes = Map::new(&env);
+        stakes.set(position.outcome.clone(), position.amount);
+
+        let claimed = bets::BetStorage::is_claimed(&env, &market_id, &user);
+        let claimable = match &market.winning_outcomes {
+            Some(winning_outcomes) if !claimed => {
+                Self::position_payout(&env, &market, &position, winning_outcomes).unwrap_or(0)
+            }
+            _ => 0,
+        };
+
+        Some(crate::types::Position {
+            market_id,
+            stakes,
+            resolved: market.winning_outcomes.is_some(),
+            claimed,
+            claimable,
+        })
+    }
+
+    /// Previews what a user would be paid if `hypothetical_outcome` won.
+    ///
+    /// Applies the same fee and pro-rata formula as `claim_winnings` and
+    /// `distribute_payouts` to the current pools, so once the market resolves to
+    /// that outcome (with no further bets) the actual payout equals the preview.
+    /// Returns 0 for users without a stake on that outcome. Read-only; no auth.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// * `Error::InvalidOutcome` - `hypothetical_outcome` is not one of the market's outcomes
+    pub fn preview_payout(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        hypothetical_outcome: String,
+    ) -> Result<i128, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if !market.outcomes.contains(&hypothetical_outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        let Some(position) = bets::BetStorage::get_position(&env, &market_id, &user) else {
+            return Ok(0);
+        };
+        let mut winning_outcomes = Vec::new(&env);
+        winning_outcomes.push_back(hypothetical_outcome);
+        Self::position_payout(&env, &market, &position, &winning_outcomes)
+    }
+
+    /// Dry run of resolving a market: the oracle value, the winning outcome(s) it
+    /// leads to, the platform fee and what would be paid out.
+    ///
+    /// A resolved market is previewed with its winning outcome(s). Otherwise the
+    /// outcome comes from the result stored by `fetch_oracle_result`, or from
+    /// querying the market's oracle when none is stored. Outcome, fee and payout
+    /// math are shared with `resolve_market` and `distribute_payouts`.
+    /// Read-only; no auth.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// * `Error::MarketClosed` - Market hasn't ended or was cancelled
+    /// * Oracle errors when the oracle has to be queried and does not answer
+    pub fn simulate_resolution(env: Env, market_id: Symbol) -> Result<ResolutionPreview, Error> {
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let stored_price = oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
+            .map(|result| result.price);
+
+        let (oracle_value, winning_outcomes) =
+            match (market.winning_outcomes.clone(), market.oracle_result.clone()) {
+                (Some(outcomes), _) => (stored_price, outcomes),
+                (None, Some(oracle_result)) => (
+                    stored_price,
+                    resolution::MarketResolutionManager::derive_winning_outcomes(
+                        &env,
+                        &market,
+                        &oracle_result,
+                    ),
+                ),
+                (None, None) => {
+                    let (price, outcome) =
+                        resolution::OracleResolutionManager::preview_oracle_result(
+                            &env,
+                            &market_id,
+                            &mut market,
+                        )?;
+                    (
+                        Some(price),
+                        resolution::MarketResolutionManager::derive_winning_outcomes(
+                            &env, &market, &outcome,
+                        ),
+                    )
+                }
+            };
+        market.winning_outcomes = Some(winning_outcomes.clone());
+
+        let (payouts, total_payout) = Self::pending_payouts(&env, &market_id, &market, &winning_outcomes)?;
+        Ok(ResolutionPreview {
+            market_id,
+            oracle_value,
+            winning_outcomes,
+            fee_amount: fees::FeeTracker::market_fee_amount(&market),
+            winner_count: payouts.len(),
+            total_payout,
+        })
+    }
+
+    /// Dry run of `distribute_payouts` over every unsettled position of a
+    /// resolved market.
+    ///
+    /// Returns the first `MAX_PAYOUT_PAGE_SIZE` `(user, payout)` credits in the
+    /// order `distribute_payouts` settles them, and the total of all credits.
+    /// Uses the same payout math and ignores the dispute window. Read-only; no auth.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// * `Error::MarketNotResolved` - Market hasn't been resolved yet
+    pub fn simulate_distribution(
+        env: Env,
+        market_id: Symbol,
+    ) -> Result<(Vec<(Address, i128)>, i128), Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
+
+        let (payouts, total) = Self::pending_payouts(&env, &market_id, &market, &winning_outcomes)?;
+        Ok((payouts.slice(0..payouts.len().min(MAX_PAYOUT_PAGE_SIZE)), total))
     }
 
-    /// Manually resolves a prediction market by setting the winning outcome (admin only).
+    /// Manually resolves a prediction market by setting the winning outcome (admin or `Resolver` role).
     ///
     /// This function allows contract administrators to manually resolve markets
     /// when automatic oracle resolution is not available or needs override.
@@ -2212,9 +3642,11 @@ impl PredictifyHybrid {
     /// # Panics
     ///
     /// This function will panic with specific errors if:
-    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::Unauthorized` - Caller is not the admin and lacks the `Resolver` role,
+    ///   or several admin signers must approve it through `propose_admin_op`
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market hasn't reached its end time yet
+    /// - `Error::MarketClosed` - Market hasn't reached its end time yet, or has been cancelled
+    /// - `Error::InvalidState` - Market's pools are below its minimum pool size (see `void_market`)
     /// - `Error::InvalidOutcome` - Winning outcome doesn't match any market outcomes
     ///
     /// # Example
@@ -2258,57 +3690,66 @@ impl PredictifyHybrid {
         admin: Address,
         market_id: Symbol,
         winning_outcome: String,
-    ) {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
-        let gas_marker = crate::gas::GasTracker::start_tracking(&env);
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         admin.require_auth();
 
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
+        // Verify the caller holds the resolver role (the admin holds every role)
+        admin::RoleManager::require_role(&env, admin::Role::Resolver, &admin)?;
+        multisig::MultisigManager::require_single_signer(&env)?;
 
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
+        Self::resolve_market_manual_internal(env, admin, market_id, winning_outcome)
+    }
 
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
+    /// Resolves `market_id` to `winning_outcome`; callers are responsible for
+    /// authorization and the pause check.
+    fn resolve_market_manual_internal(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        winning_outcome: String,
+    ) -> Result<(), Error> {
+        let gas_marker = crate::gas::GasTracker::start_tracking(&env);
+
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        // Cancelled and voided markets have been refunded and can no longer be resolved
+        if market.state.is_refunding() {
+            return Err(Error::MarketClosed);
+        }
 
         // Check if market has ended
         if env.ledger().timestamp() < market.end_time {
-            panic_with_error!(env, Error::MarketClosed);
+            return Err(Error::MarketClosed);
+        }
+
+        // Markets below their minimum pool size are voided, not resolved
+        if markets::MarketUtils::is_underfunded(&env, &market) {
+            return Err(Error::InvalidState);
         }
 
         // Validate winning outcome
         let outcome_exists = market.outcomes.iter().any(|o| o == winning_outcome);
         if !outcome_exists {
-            panic_with_error!(env, Error::InvalidOutcome);
+            return Err(Error::InvalidOutcome);
         }
 
         // Capture old state for event
         let old_state = market.state.clone();
 
+        // The end time has passed; a market resolved before is rejected here
+        markets::MarketStateLogic::advance_by_time(&env, &mut market)?;
+        markets::MarketStateLogic::transition(&mut market, MarketEvent::Resolve)?;
+
         // Set winning outcome(s) as a vector (single outcome for now, supports future multi-winner)
         let mut winning_outcomes_vec = Vec::new(&env);
         winning_outcomes_vec.push_back(winning_outcome.clone());
         market.winning_outcomes = Some(winning_outcomes_vec.clone());
-        market.state = MarketState::Resolved;
-        env.storage().persistent().set(&market_id, &market);
+        market.mark_resolved(env.ledger().timestamp());
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Decrement active event count for the creator since the market is no longer active
-        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
+        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.creator);
 
         // Resolve bets to mark them as won/lost
         let _ = bets::BetManager::resolve_market_bets(&env, &market_id, &winning_outcomes_vec);
@@ -2331,6 +3772,12 @@ impl PredictifyHybrid {
             &resolution_method,
             100, // confidence score for manual resolution
         );
+        markets::MarketUtils::publish_resolution(
+            &env,
+            &market_id,
+            &mut market,
+            events::ResolutionSource::Manual,
+        );
 
         // Emit state change event
         let reason = String::from_str(&env, "Manual resolution by admin");
@@ -2342,11 +3789,18 @@ impl PredictifyHybrid {
             &reason,
         );
 
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("resolve"),
+            admin::AdminLogTarget::Market(market_id.clone()),
+        );
+
         // Distribute payouts only after dispute window closes (or skip and allow finalize_after_window later)
         let now = env.ledger().timestamp();
-        let payout_allowed = now >= market.end_time.saturating_add(market.dispute_window_seconds);
+        let payout_allowed = now >= market.payout_unlock_time();
         if payout_allowed {
-            let _ = Self::distribute_payouts(env.clone(), market_id);
+            let _ = Self::distribute_payouts(env.clone(), market_id, 0, MAX_PAYOUT_PAGE_SIZE);
         }
 
         crate::gas::GasTracker::end_tracking(
@@ -2354,29 +3808,30 @@ impl PredictifyHybrid {
             soroban_sdk::symbol_short!("res_man"),
             gas_marker,
         );
+        Ok(())
     }
 
-    /// Resolves a market with multiple winning outcomes (for tie cases).
+    /// Resolves a market with multiple winning outcomes (admin or `Resolver` role).
     ///
-    /// This function allows authorized administrators to resolve a market with
+    /// This function allows authorized resolvers to resolve a market with
     /// multiple winners when there's a tie. The pool will be split proportionally
     /// among all winning outcomes based on stake distribution.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
-    /// * `admin` - The administrator address performing the resolution (must be authorized)
+    /// * `admin` - The address performing the resolution (must be authorized)
     /// * `market_id` - Unique identifier of the market to resolve
-    /// * `winning_outcomes` - Vector of outcomes to be declared as winners (minimum 1, all must be valid)
+    /// * `winning_outcomes` - Distinct outcomes to be declared as winners (minimum 1, all must be valid)
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function will panic with specific errors if:
-    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::Unauthorized` - Caller is neither the admin nor a `Resolver`
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketClosed` - Market hasn't ended yet
+    /// - `Error::MarketClosed` - Market hasn't ended yet or was cancelled
+    /// - `Error::InvalidState` - Market's pools are below its minimum pool size (see `void_market`)
     /// - `Error::InvalidOutcome` - One or more outcomes are not valid for this market
-    /// - `Error::InvalidInput` - Empty outcomes vector
+    /// - `Error::InvalidInput` - Empty outcomes vector or a repeated outcome
     ///
     /// # Example
     ///
@@ -2405,69 +3860,82 @@ impl PredictifyHybrid {
     /// # Pool Split Logic
     ///
     /// When multiple outcomes win:
-    /// - Total pool is split proportionally among all winners
-    /// - Each winner receives: (their_stake / total_winning_stakes) * total_pool * (1 - fee)
-    /// - This ensures fair distribution even when outcomes are tied
+    /// - The losing pool is split across the winning outcomes in proportion to
+    ///   each outcome's stake, and each outcome's backers share its slice pro rata
+    /// - Each winner therefore receives: (their_stake / total_winning_stakes) * total_pool * (1 - fee)
+    /// - A winning outcome nobody backed takes no slice
+    /// - Declaring every outcome a winner refunds each stake minus the fee
     pub fn resolve_market_with_ties(
         env: Env,
         admin: Address,
         market_id: Symbol,
         winning_outcomes: Vec<String>,
-    ) {
-        if let Err(e) = admin::ContractPauseManager::require_not_paused(&env) {
-            panic_with_error!(env, e);
-        }
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         admin.require_auth();
 
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
+        // Verify the caller holds the resolver role (the admin holds every role)
+        admin::RoleManager::require_role(&env, admin::Role::Resolver, &admin)?;
+        multisig::MultisigManager::require_single_signer(&env)?;
 
-        if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
-        }
+        Self::resolve_market_with_ties_internal(env, admin, market_id, winning_outcomes)
+    }
 
-        // Validate outcomes vector is not empty
+    /// Resolves `market_id` to `winning_outcomes`; callers are responsible for
+    /// authorization and the pause check.
+    fn resolve_market_with_ties_internal(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        winning_outcomes: Vec<String>,
+    ) -> Result<(), Error> {
+        // Validate outcomes vector is not empty and names each outcome once
         if winning_outcomes.len() == 0 {
-            panic_with_error!(env, Error::InvalidInput);
+            return Err(Error::InvalidInput);
+        }
+        if crate::validation::InputValidator::validate_unique_outcomes(&winning_outcomes).is_err() {
+            return Err(Error::InvalidInput);
         }
 
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        // Cancelled and voided markets have been refunded and can no longer be resolved
+        if market.state.is_refunding() {
+            return Err(Error::MarketClosed);
+        }
 
         // Check if market has ended
         if env.ledger().timestamp() < market.end_time {
-            panic_with_error!(env, Error::MarketClosed);
+            return Err(Error::MarketClosed);
+        }
+
+        // Markets below their minimum pool size are voided, not resolved
+        if markets::MarketUtils::is_underfunded(&env, &market) {
+            return Err(Error::InvalidState);
         }
 
         // Validate all winning outcomes exist in market outcomes
         for outcome in winning_outcomes.iter() {
             let outcome_exists = market.outcomes.iter().any(|o| o == outcome);
             if !outcome_exists {
-                panic_with_error!(env, Error::InvalidOutcome);
+                return Err(Error::InvalidOutcome);
             }
         }
 
         // Capture old state for event
         let old_state = market.state.clone();
 
+        // The end time has passed; a market resolved before is rejected here
+        markets::MarketStateLogic::advance_by_time(&env, &mut market)?;
+        markets::MarketStateLogic::transition(&mut market, MarketEvent::Resolve)?;
+
         // Set winning outcome(s) - supports multiple winners for ties
         market.winning_outcomes = Some(winning_outcomes.clone());
-        market.state = MarketState::Resolved;
-        env.storage().persistent().set(&market_id, &market);
+        market.mark_resolved(env.ledger().timestamp());
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Decrement active event count for the creator since the market is no longer active
-        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
+        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.creator);
 
         // Resolve bets to mark them as won/lost
         let _ = bets::BetManager::resolve_market_bets(&env, &market_id, &winning_outcomes);
@@ -2490,6 +3958,12 @@ impl PredictifyHybrid {
             &resolution_method,
             100, // confidence score for manual resolution
         );
+        markets::MarketUtils::publish_resolution(
+            &env,
+            &market_id,
+            &mut market,
+            events::ResolutionSource::Manual,
+        );
 
         // Emit state change event
         let reason = String::from_str(&env, "Manual resolution with ties by admin");
@@ -2501,12 +3975,20 @@ impl PredictifyHybrid {
             &reason,
         );
 
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("resolve"),
+            admin::AdminLogTarget::Market(market_id.clone()),
+        );
+
         // Distribute payouts only after dispute window closes
         let now = env.ledger().timestamp();
-        let payout_allowed = now >= market.end_time.saturating_add(market.dispute_window_seconds);
+        let payout_allowed = now >= market.payout_unlock_time();
         if payout_allowed {
-            let _ = Self::distribute_payouts(env.clone(), market_id);
+            let _ = Self::distribute_payouts(env.clone(), market_id, 0, MAX_PAYOUT_PAGE_SIZE);
         }
+        Ok(())
     }
 
     /// Fetches oracle result for a market from external oracle contracts.
@@ -2544,7 +4026,7 @@ impl PredictifyHybrid {
     /// # let market_id = Symbol::new(&env, "btc_market");
     /// # let oracle_address = Address::generate(&env);
     ///
-    /// match PredictifyHybrid::fetch_oracle_result(
+    /// match PredictifyHybrid::fetch_oracle_with_contract(
     ///     env.clone(),
     ///     market_id,
     ///     oracle_address
@@ -2578,11 +4060,7 @@ impl PredictifyHybrid {
         oracle_contract: Address,
     ) -> Result<String, Error> {
         // Get the market from storage
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Validate market state
         if market.oracle_result.is_some() {
@@ -2602,54 +4080,212 @@ impl PredictifyHybrid {
         Ok(oracle_resolution.oracle_result)
     }
 
-    /// Verifies and fetches event outcome from external oracle sources automatically.
-    ///
-    /// This function implements the complete oracle integration mechanism that:
-    /// - Automatically fetches event outcomes from configured external data sources
-    /// - Validates oracle responses and signatures/authority
-    /// - Supports multiple oracle sources with consensus-based verification
-    /// - Handles oracle failures gracefully with fallback mechanisms
-    /// - Emits result verification events for transparency
+    /// Queries the market's configured oracle and stores what it reported.
     ///
-    /// # Parameters
+    /// The raw price, the derived outcome and the ledger timestamp are kept so
+    /// anyone can inspect them with [`Self::get_oracle_result`]. A later call to
+    /// `resolve_market` finalizes the market from this stored result without
+    /// querying the oracle again.
     ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `caller` - The address initiating the verification (must be authenticated)
-    /// * `market_id` - Unique identifier of the market to verify
+    /// # Errors
     ///
-    /// # Returns
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketResolved` - Oracle result already fetched or market resolved
+    /// - `Error::MarketClosed` - Market hasn't ended or was cancelled
+    /// - Oracle-specific errors from the resolution module
+    pub fn fetch_oracle_result(env: Env, market_id: Symbol) -> Result<OracleResult, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+
+        resolution::OracleResolutionManager::fetch_oracle_result(&env, &market_id)?;
+
+        oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
+            .ok_or(Error::OracleUnavailable)
+    }
+
+    /// Stores an off-chain result signed by a market's `OracleProvider::Signed` key.
     ///
-    /// Returns `Result<OracleResult, Error>` where:
-    /// - `Ok(OracleResult)` - Complete oracle verification result including:
-    ///   - `outcome`: The determined outcome ("yes"/"no" or custom)
-    ///   - `price`: The fetched price from oracle
-    ///   - `threshold`: The configured threshold for comparison
-    ///   - `confidence_score`: Statistical confidence (0-100)
-    ///   - `is_verified`: Whether the result passed all validations
-    ///   - `sources_count`: Number of oracle sources consulted
-    /// - `Err(Error)` - Specific error if verification fails
+    /// `payload` is the XDR encoding of `(contract, market_id, outcome, timestamp)`,
+    /// where `contract` is this contract's address, and `signature` its ed25519
+    /// signature by the key in the market's `oracle_config.signer`. Anyone may
+    /// relay it; the signature is the authorization. A forged signature aborts
+    /// the call, and a market accepts only one result, so a payload can never
+    /// be replayed.
     ///
     /// # Errors
     ///
-    /// This function returns specific errors:
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
-    /// - `Error::MarketNotReadyForVerification` - Market hasn't ended yet
-    /// - `Error::OracleVerified` - Result already verified for this market
-    /// - `Error::OracleUnavailable` - Oracle service is unavailable
-    /// - `Error::OracleStale` - Oracle data is too old
-    /// - `Error::OracleConsensusNotReached` - Multiple oracles disagree
-    /// - `Error::InvalidOracleConfig` - Oracle not whitelisted/authorized
-    /// - `Error::OracleAllSourcesFailed` - All oracle sources failed
-    /// - `Error::InsufficientOracleSources` - No active oracle sources available
+    /// - `Error::MarketResolved` - An oracle result was already stored
+    /// - `Error::MarketClosed` - Market hasn't ended or was cancelled
+    /// - `Error::InvalidOracleConfig` - The market's oracle is not `Signed`
+    /// - `Error::InvalidInput` - The payload is malformed or for another contract
+    ///   or market
+    /// - `Error::InvalidOutcome` - The signed outcome is not a market outcome
+    /// - `Error::OracleStale` - The payload timestamp is before the market's
+    ///   end, in the future, or older than the market's max price age
+    pub fn submit_signed_result(
+        env: Env,
+        market_id: Symbol,
+        payload: Bytes,
+        signature: BytesN<64>,
+    ) -> Result<OracleResult, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+
+        resolution::OracleResolutionManager::submit_signed_result(
+            &env, &market_id, &payload, &signature,
+        )?;
+
+        oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
+            .ok_or(Error::OracleUnavailable)
+    }
+
+    /// Returns the oracle result stored for a market, if one has been fetched.
     ///
-    /// # Example
+    /// Lets users check the reported price and outcome before the dispute
+    /// window closes.
+    pub fn get_oracle_result(env: Env, market_id: Symbol) -> Option<OracleResult> {
+        oracles::OracleIntegrationManager::get_oracle_result(&env, &market_id)
+    }
+
+    /// Set the maximum age in seconds of an oracle price accepted for resolution.
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let caller = Address::generate(&env);
-    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
+    /// Pyth and Reflector prices whose publish time is older than this window
+    /// are rejected with `Error::OracleStale`. Markets may override it with
+    /// [`Self::set_market_max_price_age`].
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller lacks the `update_config` permission
+    /// - `Error::ConfigNotFound` - Contract configuration has not been stored
+    /// - `Error::InvalidInput` - `max_price_age` is zero
+    pub fn set_oracle_max_price_age(
+        env: Env,
+        admin: Address,
+        max_price_age: u64,
+    ) -> Result<(), Error> {
+        config::ConfigManager::update_oracle_max_price_age(&env, admin, max_price_age)?;
+        Ok(())
+    }
+
+    /// Override the maximum oracle price age for a single market.
+    ///
+    /// `None` clears the override so the market follows the global setting
+    /// again. A stale price is retried until the market's resolution timeout,
+    /// after which the fallback oracle or manual resolution takes over.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller lacks the `update_config` permission
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::InvalidInput` - `max_price_age` is zero
+    pub fn set_market_max_price_age(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        max_price_age: Option<u64>,
+    ) -> Result<(), Error> {
+        admin::AdminAccessControl::validate_admin_for_action(&env, &admin, "update_config")?;
+        markets::MarketStateManager::get_market(&env, &market_id)?;
+        if max_price_age == Some(0) {
+            return Err(Error::InvalidInput);
+        }
+
+        oracles::OracleUtils::set_market_max_price_age(&env, &market_id, max_price_age);
+        Ok(())
+    }
+
+    /// Add up to two extra primary oracles to a market.
+    ///
+    /// At resolution the market's own oracle and these are all queried; a
+    /// majority must answer and their median price is compared against the
+    /// market's threshold. Below quorum the fetch is retried until the
+    /// resolution timeout, then the fallback oracle or manual path applies.
+    /// Pass an empty list to return to a single oracle.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the market creator
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already has stakes
+    /// - `Error::ThresholdTooHigh` - More than two extra oracles
+    /// - `Error::InvalidOracleConfig` - Unsupported provider, or threshold or
+    ///   comparison differs from the market's oracle config
+    pub fn set_extra_oracles(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        oracles: Vec<OracleConfig>,
+    ) -> Result<(), Error> {
+        resolution::OracleResolutionManager::set_extra_oracles(&env, &creator, &market_id, &oracles)
+    }
+
+    /// All primary oracles used to resolve a market, its own config first.
+    pub fn get_market_oracles(env: Env, market_id: Symbol) -> Result<Vec<OracleConfig>, Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let mut oracles = Vec::new(&env);
+        oracles.push_back(market.oracle_config);
+        oracles.append(&oracles::OracleUtils::get_extra_oracles(&env, &market_id));
+        Ok(oracles)
+    }
+
+    /// Maximum oracle price age applied to a market, in seconds.
+    pub fn get_market_max_price_age(env: Env, market_id: Symbol) -> u64 {
+        oracles::OracleUtils::max_price_age_for_market(&env, &market_id)
+    }
+
+    /// Verifies and fetches event outcome from external oracle sources automatically.
+    ///
+    /// This function implements the complete oracle integration mechanism that:
+    /// - Automatically fetches event outcomes from configured external data sources
+    /// - Validates oracle responses and signatures/authority
+    /// - Supports multiple oracle sources with consensus-based verification
+    /// - Handles oracle failures gracefully with fallback mechanisms
+    /// - Emits result verification events for transparency
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `caller` - The address initiating the verification (must be authenticated)
+    /// * `market_id` - Unique identifier of the market to verify
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<OracleResult, Error>` where:
+    /// - `Ok(OracleResult)` - Complete oracle verification result including:
+    ///   - `outcome`: The determined outcome ("yes"/"no" or custom)
+    ///   - `price`: The fetched price from oracle
+    ///   - `threshold`: The configured threshold for comparison
+    ///   - `confidence_score`: Statistical confidence (0-100)
+    ///   - `is_verified`: Whether the result passed all validations
+    ///   - `sources_count`: Number of oracle sources consulted
+    /// - `Err(Error)` - Specific error if verification fails
+    ///
+    /// # Errors
+    ///
+    /// This function returns specific errors:
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketNotReadyForVerification` - Market hasn't ended yet
+    /// - `Error::OracleVerified` - Result already verified for this market
+    /// - `Error::OracleUnavailable` - Oracle service is unavailable
+    /// - `Error::OracleStale` - Oracle data is too old
+    /// - `Error::OracleConsensusNotReached` - Multiple oracles disagree
+    /// - `Error::InvalidOracleConfig` - Oracle not whitelisted/authorized
+    /// - `Error::OracleAllSourcesFailed` - All oracle sources failed
+    /// - `Error::InsufficientOracleSources` - No active oracle sources available
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use soroban_sdk::{Env, Address, Symbol};
+    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # let env = Env::default();
+    /// # let caller = Address::generate(&env);
+    /// # let market_id = Symbol::new(&env, "btc_50k_2024");
     ///
     /// // Verify result for an ended market
     /// match PredictifyHybrid::verify_result(env.clone(), caller, market_id) {
@@ -2881,8 +4517,18 @@ impl PredictifyHybrid {
     /// - `Error::MarketNotEnded` - Market hasn't reached its end time
     /// - `Error::MarketResolved` - Market is already resolved
     /// - `Error::InsufficientData` - Not enough data for resolution
+    /// - `Error::OracleUnavailable` - Oracle call failed or returned no price
+    /// - `Error::OracleStale` - Oracle price older than the configured `max_price_age`
     /// - Resolution-specific errors from the resolution module
     ///
+    /// # Oracle Timeout
+    ///
+    /// Once `end_time + resolution_timeout` has passed, anyone may call this function
+    /// to resolve from the fallback oracle instead of the primary one (a `fbk_used`
+    /// event records the switch). If that query fails too, the call still succeeds
+    /// but leaves the market `Ended` and unresolved with an `orc_fail` event, after
+    /// which `resolve_market_manual` or `refund_on_oracle_failure` may be used.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -2906,7 +4552,8 @@ impl PredictifyHybrid {
     /// # Hybrid Resolution Algorithm
     ///
     /// The resolution process follows these steps:
-    /// 1. **Data Collection**: Gather oracle data and community votes
+    /// 1. **Data Collection**: Fetch the oracle result from the configured provider
+    ///    (falling back to the fallback oracle) if none is stored yet, and gather votes
     /// 2. **Consensus Analysis**: Analyze agreement between oracle and community
     /// 3. **Conflict Resolution**: Handle disagreements using weighted algorithms
     /// 4. **Final Determination**: Set winning outcome based on hybrid result
@@ -2928,6 +4575,56 @@ impl PredictifyHybrid {
     /// - Market statistics are finalized
     pub fn resolve_market(env: Env, market_id: Symbol) -> Result<(), Error> {
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
+
+        // Optimistic markets are resolved by their unchallenged proposal
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.resolve_mode == ResolveMode::Optimistic && market.winning_outcomes.is_none() {
+            optimistic::OptimisticResolutionManager::finalize(&env, &market_id)?;
+            crate::gas::GasTracker::end_tracking(
+                &env,
+                soroban_sdk::symbol_short!("resolve"),
+                gas_marker,
+            );
+            return Ok(());
+        }
+
+        // Automatic path: pull the oracle price when it has not been fetched yet
+        if market.oracle_result.is_none() && market.winning_outcomes.is_none() {
+            match resolution::OracleResolutionManager::fetch_oracle_result(&env, &market_id) {
+                Ok(_) => {}
+                Err(Error::ResolutionTimeoutReached) => {
+                    // Both oracles failed after the timeout: hand the market over to
+                    // manual resolution / refunds and keep that state change
+                    resolution::OracleResolutionManager::mark_oracle_failure(&env, &market_id)?;
+                    crate::gas::GasTracker::end_tracking(
+                        &env,
+                        soroban_sdk::symbol_short!("resolve"),
+                        gas_marker,
+                    );
+                    return Ok(());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        // Hybrid markets also need the community vote to agree with the oracle
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.resolve_mode == ResolveMode::Hybrid && market.winning_outcomes.is_none() {
+            match resolution::CommunityResolutionManager::get_vote(&env, &market_id) {
+                None => {
+                    resolution::CommunityResolutionManager::open_hybrid_vote(&env, &market_id)?;
+                }
+                Some(vote) if !vote.finalized => return Err(Error::MarketNotReady),
+                Some(_) => return Err(Error::InvalidState),
+            }
+            crate::gas::GasTracker::end_tracking(
+                &env,
+                soroban_sdk::symbol_short!("resolve"),
+                gas_marker,
+            );
+            return Ok(());
+        }
+
         // Use the resolution module to resolve the market
         let _resolution = resolution::MarketResolutionManager::resolve_market(&env, &market_id)?;
 
@@ -2941,6 +4638,147 @@ impl PredictifyHybrid {
         Ok(())
     }
 
+    /// Resolve an ended market on behalf of a keeper and pay it the keeper incentive.
+    ///
+    /// Permissionless counterpart to `resolve_market` for keeper bots: `keeper`
+    /// only signs to receive the incentive, a share (`get_keeper_incentive_bps`)
+    /// of the market's platform fee paid out of the fee vault. Nothing is paid
+    /// when the call does not resolve the market, e.g. when it opens the community
+    /// vote of a hybrid market or hands a timed-out market over to manual resolution.
+    ///
+    /// Returns the incentive transferred to `keeper`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotReady` - The market's `end_time` has not passed yet
+    /// - `Error::MarketResolved` - The market is already resolved
+    /// - `Error::OracleUnavailable` - The oracle has no price for the market yet
+    /// - Any other error from `resolve_market`
+    pub fn keeper_resolve(env: Env, keeper: Address, market_id: Symbol) -> Result<i128, Error> {
+        keeper.require_auth();
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketNotReady);
+        }
+
+        Self::resolve_market(env.clone(), market_id.clone())?;
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.winning_outcomes.is_none() {
+            return Ok(0);
+        }
+        fees::FeeManager::pay_keeper_incentive(&env, &keeper, &market_id, &market)
+    }
+
+    /// Distribute the next page of a resolved market's payouts on behalf of a
+    /// keeper, continuing from `get_payout_cursor`.
+    ///
+    /// Permissionless counterpart to `distribute_payouts`: `keeper` only signs to
+    /// receive the keeper reward (`get_keeper_reward`), paid out of the fee vault
+    /// to the keeper whose page completes the distribution. Calls on a completed
+    /// distribution earn nothing, and the reward is paid once per market.
+    ///
+    /// Returns the reward transferred to `keeper`.
+    ///
+    /// # Errors
+    ///
+    /// Any error from `distribute_payouts`.
+    pub fn keeper_distribute_payouts(
+        env: Env,
+        keeper: Address,
+        market_id: Symbol,
+        limit: u32,
+    ) -> Result<i128, Error> {
+        keeper.require_auth();
+
+        let start = Self::get_payout_cursor(env.clone(), market_id.clone());
+        if start == PAYOUT_CURSOR_DONE {
+            return Ok(0);
+        }
+        let (_, next_cursor) =
+            Self::distribute_payouts(env.clone(), market_id.clone(), start, limit)?;
+        if next_cursor != PAYOUT_CURSOR_DONE {
+            return Ok(0);
+        }
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        fees::FeeManager::pay_keeper_reward(
+            &env,
+            &keeper,
+            &market_id,
+            &market,
+            soroban_sdk::symbol_short!("distrib"),
+        )
+    }
+
+    /// Void a market nobody resolved in time on behalf of a keeper.
+    ///
+    /// Permissionless counterpart to `void_unresolved_market` that pays `keeper`
+    /// the keeper reward when the call voids the market. A voided market has no
+    /// platform fee, so only a `KeeperReward::Flat` reward pays anything.
+    ///
+    /// Returns the reward transferred to `keeper`.
+    ///
+    /// # Errors
+    ///
+    /// Any error from `void_unresolved_market`.
+    pub fn keeper_void_market(env: Env, keeper: Address, market_id: Symbol) -> Result<i128, Error> {
+        keeper.require_auth();
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.state.is_refunding() {
+            return Ok(0);
+        }
+        Self::void_unresolved_market(env.clone(), market_id.clone())?;
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        fees::FeeManager::pay_keeper_reward(
+            &env,
+            &keeper,
+            &market_id,
+            &market,
+            soroban_sdk::symbol_short!("void"),
+        )
+    }
+
+    /// Sweep a market's unclaimed winnings to the treasury on behalf of a keeper,
+    /// once its claim period has ended.
+    ///
+    /// Permissionless counterpart to `sweep_unclaimed_winnings`: the funds always
+    /// go to the treasury (or the admin when none is set), never to `keeper`,
+    /// which receives the keeper reward instead.
+    ///
+    /// Returns the reward transferred to `keeper`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::NothingToClaim` - No unclaimed winnings are left to sweep
+    /// - Any other error from `sweep_unclaimed_winnings`
+    pub fn keeper_sweep_unclaimed(
+        env: Env,
+        keeper: Address,
+        market_id: Symbol,
+    ) -> Result<i128, Error> {
+        keeper.require_auth();
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+
+        Self::sweep_unclaimed_internal(&env, &keeper, &market_id, false)?;
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        fees::FeeManager::pay_keeper_reward(
+            &env,
+            &keeper,
+            &market_id,
+            &market,
+            soroban_sdk::symbol_short!("sweep"),
+        )
+    }
+
     /// Retrieves comprehensive analytics about market resolution performance.
     ///
     /// This function provides detailed statistics about how markets are being
@@ -3108,11 +4946,7 @@ impl PredictifyHybrid {
         env: Env,
         market_id: Symbol,
     ) -> Result<markets::MarketStats, Error> {
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Calculate market statistics
         let stats = markets::MarketAnalytics::get_market_stats(&market);
@@ -3132,101 +4966,313 @@ impl PredictifyHybrid {
         disputes::DisputeManager::process_dispute(&env, user, market_id, stake, reason)
     }
 
-    /// Vote on a dispute
-    pub fn vote_on_dispute(
+    /// Dispute a resolved market's result by staking on a different outcome.
+    ///
+    /// Only allowed after resolution and before `resolved_at + dispute_window_seconds`.
+    /// The stake is transferred into the contract and `distribute_payouts` (and
+    /// `claim_winnings`) are blocked until the dispute is settled. At most one open
+    /// dispute per market; the stake must be at least `get_min_dispute_stake`.
+    ///
+    /// After a ruling, the result can be appealed the same way up to two more
+    /// times, each within the shortened window the ruling opened and with at
+    /// least double the previous stake.
+    pub fn dispute_result(
         env: Env,
         user: Address,
         market_id: Symbol,
-        dispute_id: Symbol,
-        vote: bool,
+        proposed_outcome: String,
         stake: i128,
-        reason: Option<String>,
     ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
         user.require_auth();
-        disputes::DisputeManager::vote_on_dispute(
-            &env, user, market_id, dispute_id, vote, stake, reason,
-        )
+        disputes::DisputeManager::dispute_result(&env, user, market_id, proposed_outcome, stake)
     }
 
-    /// Resolve a dispute (admin only)
-    pub fn resolve_dispute(
+    /// Propose the result of an ended `ResolveMode::Optimistic` market.
+    ///
+    /// `bond` (at least `get_min_dispute_stake`) is locked in the market's
+    /// token. Unless `challenge_result` is called within the market's dispute
+    /// window, `finalize_result` (or `resolve_market`) then resolves the market
+    /// to `outcome` and returns the bond with a reward from the fee vault.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidState` - Market is not optimistic, or is underfunded
+    /// - `Error::MarketClosed` - Market hasn't ended or was cancelled
+    /// - `Error::MarketResolved` - Market already has a result
+    /// - `Error::InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// - `Error::InsufficientStake` - `bond` is below the minimum dispute stake
+    /// - `Error::AlreadyDisputed` - A result was already proposed
+    pub fn propose_result(
         env: Env,
-        admin: Address,
+        user: Address,
         market_id: Symbol,
-    ) -> Result<disputes::DisputeResolution, Error> {
+        outcome: String,
+        bond: i128,
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        optimistic::OptimisticResolutionManager::propose(&env, user, market_id, outcome, bond)
+    }
+
+    /// Challenge a market's proposed result before its challenge window closes.
+    ///
+    /// Locks a counter-bond equal to the proposer's. The market is then settled
+    /// by `arbitrate` (or `arbitrate_by_contract`): the market resolves to the
+    /// arbiter's outcome, and the proposer takes both bonds if it is the
+    /// proposed outcome, the challenger otherwise.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::DisputeCondNotMet` - No open proposal, or its challenge window closed
+    /// - `Error::AlreadyDisputed` - The proposal was already challenged
+    /// - `Error::InvalidInput` - The proposer cannot challenge their own proposal
+    pub fn challenge_result(env: Env, user: Address, market_id: Symbol) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        user.require_auth();
+        optimistic::OptimisticResolutionManager::challenge(&env, user, market_id)
+    }
+
+    /// Resolve an optimistic market to its unchallenged proposal once the
+    /// challenge window has closed (callable by anyone). Returns the outcome.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotReady` - No open proposal, or its challenge window is still open
+    /// - `Error::DisputeCondNotMet` - The proposal was challenged and awaits arbitration
+    pub fn finalize_result(env: Env, market_id: Symbol) -> Result<String, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        optimistic::OptimisticResolutionManager::finalize(&env, &market_id)
+    }
+
+    /// Returns the result proposed for an optimistic market, if any.
+    pub fn get_result_proposal(env: Env, market_id: Symbol) -> Option<disputes::Dispute> {
+        optimistic::OptimisticResolutionManager::get_proposal(&env, &market_id)
+    }
+
+    /// Returns the challenge posted against a market's proposed result, if any.
+    pub fn get_result_challenge(env: Env, market_id: Symbol) -> Option<disputes::Dispute> {
+        optimistic::OptimisticResolutionManager::get_challenge(&env, &market_id)
+    }
+
+    /// Set the minimum stake required by `dispute_result` (admin only).
+    pub fn set_min_dispute_stake(env: Env, admin: Address, stake: i128) -> Result<(), Error> {
         admin.require_auth();
 
-        // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
-
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
+        }
+        if stake <= 0 {
+            return Err(Error::InvalidInput);
         }
 
-        disputes::DisputeManager::resolve_dispute(&env, market_id, admin)
+        disputes::DisputeUtils::set_min_result_dispute_stake(&env, stake);
+        Ok(())
     }
 
-    /// Collect fees from a market (admin only)
-    pub fn collect_fees(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
-        admin.require_auth();
+    /// Returns the minimum stake required by `dispute_result`.
+    pub fn get_min_dispute_stake(env: Env) -> i128 {
+        disputes::DisputeUtils::get_min_result_dispute_stake(&env)
+    }
 
-        // Verify admin
+    /// Returns the result dispute recorded for a market, if any.
+    pub fn get_result_dispute(env: Env, market_id: Symbol) -> Option<disputes::Dispute> {
+        disputes::DisputeUtils::get_result_dispute(&env, &market_id)
+    }
+
+    /// Settle a market's open result dispute by naming the correct outcome.
+    ///
+    /// Naming the disputer's proposed outcome upholds the dispute and naming a
+    /// recorded winning outcome rejects it, with the same payouts and appeal
+    /// windows as `resolve_dispute`. Callable by an `Arbitrator` role holder,
+    /// or by the admin while no arbitrator has been granted.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller may not settle disputes on this market
+    /// - `Error::DisputeCondNotMet` - The market has no open result dispute
+    /// - `Error::InvalidOutcome` - `outcome` is neither the proposed nor a winning outcome
+    pub fn arbitrate(
+        env: Env,
+        arbitrator: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        arbitrator.require_auth();
+        disputes::DisputeManager::arbitrate(&env, arbitrator, market_id, outcome)
+    }
+
+    /// Delegate a market's dispute settlement to another contract (admin only).
+    ///
+    /// The contract must expose `arbitrate(market_id) -> String`. While it is
+    /// set, disputes on the market are settled only by `arbitrate_by_contract`.
+    /// Pass `None` to go back to arbitrators or the admin.
+    pub fn set_arbitration_contract(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        contract: Option<Address>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
-
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
+        markets::MarketStateManager::get_market(&env, &market_id)?;
+        disputes::DisputeUtils::set_arbitration_contract(&env, &market_id, &contract);
+        Ok(())
+    }
 
-        fees::FeeManager::collect_fees(&env, admin, market_id)
+    /// Returns the arbitration contract set for a market, if any.
+    pub fn get_arbitration_contract(env: Env, market_id: Symbol) -> Option<Address> {
+        disputes::DisputeUtils::get_arbitration_contract(&env, &market_id)
     }
 
-    /// Automatically distribute payouts to all winners after market resolution.
+    /// Settle a market's open result dispute with the outcome returned by its
+    /// arbitration contract (callable by anyone). Returns that outcome.
     ///
-    /// This function automatically calculates and distributes winnings to all users
-    /// who bet on the winning outcome, eliminating the need for manual claiming.
-    /// It handles edge cases like no winners, all winners, and prevents double payouts.
-    ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `env` - The Soroban environment for blockchain operations
+    /// - `Error::InvalidState` - No arbitration contract is set, or the call failed
+    /// - `Error::DisputeCondNotMet` - The market has no open result dispute
+    /// - `Error::InvalidOutcome` - The returned outcome fits neither side of the dispute
+    pub fn arbitrate_by_contract(env: Env, market_id: Symbol) -> Result<String, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        disputes::DisputeManager::arbitrate_by_contract(&env, market_id)
+    }
+
+    /// Returns every result dispute round for a market, oldest first: the
+    /// original dispute followed by any appeals.
+    pub fn get_disputes(env: Env, market_id: Symbol) -> Vec<disputes::Dispute> {
+        disputes::DisputeUtils::get_result_dispute_history(&env, &market_id)
+    }
+
+    /// Vote on a dispute
+    pub fn vote_on_dispute(
+        env: Env,
+        user: Address,
+        market_id: Symbol,
+        dispute_id: Symbol,
+        vote: bool,
+        stake: i128,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        user.require_auth();
+        disputes::DisputeManager::vote_on_dispute(
+            &env, user, market_id, dispute_id, vote, stake, reason,
+        )
+    }
+
+    /// Settle a market's open result dispute.
+    ///
+    /// Once an `Arbitrator` role has been granted, only arbitrators may settle
+    /// disputes; until then the admin does. Markets with an arbitration contract
+    /// are settled only through `arbitrate_by_contract`.
+    ///
+    /// If `uphold` is true, `final_outcome` must be the disputer's proposed outcome:
+    /// the market's winning outcome is rewritten to it and the disputer's stake is
+    /// returned with a reward from the fee vault. Otherwise `final_outcome` must be a
+    /// recorded winning outcome and the stake is forfeited, split between the fee
+    /// vault and the upheld disputer the appeal challenged, if any. Each ruling
+    /// reopens a dispute window half as long as the last for an appeal; the ruling
+    /// on the final appeal closes it so `distribute_payouts` and `claim_winnings`
+    /// can proceed.
+    pub fn resolve_dispute(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        final_outcome: String,
+        uphold: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        disputes::DisputeManager::resolve_result_dispute(
+            &env,
+            admin,
+            market_id,
+            final_outcome,
+            uphold,
+        )
+    }
+
+    /// Collect fees from a market (admin or `FeeManager` role)
+    ///
+    /// Authorization and the admin check are performed by `FeeManager::collect_fees`;
+    /// requiring auth here as well would authorize the same frame twice.
+    ///
+    /// Each market's fee can be collected once, after resolution, in either order
+    /// relative to `distribute_payouts`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotResolved` - Market has no winning outcome yet
+    /// - `Error::MarketClosed` - Market was cancelled
+    /// - `Error::FeeAlreadyCollected` - Fee was already collected
+    pub fn collect_fees(env: Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
+        fees::FeeManager::collect_fees(&env, admin, market_id)
+    }
+
+    /// `(accrued, collected)` platform fees for a market. `accrued` is reserved
+    /// when the market resolves; `collected` is set by `collect_fees`.
+    pub fn get_market_fees(env: Env, market_id: Symbol) -> (i128, i128) {
+        fees::FeeTracker::get_market_fees(&env, &market_id)
+    }
+
+    /// Distribute payouts to one page of winners after market resolution.
+    ///
+    /// Positions are processed in the order of the market's participant map, from
+    /// index `start` up to `limit` entries (capped at `MAX_PAYOUT_PAGE_SIZE`), so large
+    /// markets can be settled across several transactions without exceeding the
+    /// per-invocation budget. Winners are credited and marked as claimed; losing bets
+    /// are marked as lost. Positions already paid (by an earlier page or by
+    /// `claim_winnings`) are skipped, so re-submitting a range never pays twice.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
     /// * `market_id` - Unique identifier of the resolved market
+    /// * `start` - Index of the first position to process
+    /// * `limit` - Maximum number of positions to process in this call
     ///
     /// # Returns
     ///
-    /// Returns `Result<i128, Error>` where:
-    /// - `Ok(total_distributed)` - Total amount distributed to winners
+    /// Returns `Result<(i128, u32), Error>` where:
+    /// - `Ok((distributed, next_cursor))` - Amount distributed by this page and the index
+    ///   to pass as `start` for the next page, or `PAYOUT_CURSOR_DONE` when finished
     /// - `Err(Error)` - Error if distribution fails
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function will panic with specific errors if:
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::MarketNotResolved` - Market hasn't been resolved yet
-    /// - `Error::MarketResolved` - Payouts have already been distributed
+    /// - `Error::InvalidState` - Dispute window has not closed yet
+    /// - `Error::InvalidInput` - `limit` is zero
+    /// - `Error::ContractPaused` - Contract is paused
     ///
     /// # Example
     ///
     /// ```rust
     /// # use soroban_sdk::{Env, Symbol};
-    /// # use predictify_hybrid::PredictifyHybrid;
+    /// # use predictify_hybrid::{PredictifyHybrid, PAYOUT_CURSOR_DONE};
     /// # let env = Env::default();
     /// # let market_id = Symbol::new(&env, "resolved_market");
     ///
-    /// match PredictifyHybrid::distribute_payouts(env.clone(), market_id) {
-    ///     Ok(total) => println!("Distributed {} stroops to winners", total),
-    ///     Err(e) => println!("Distribution failed: {:?}", e),
+    /// let mut cursor = 0;
+    /// while cursor != PAYOUT_CURSOR_DONE {
+    ///     let (paid, next) =
+    ///         PredictifyHybrid::distribute_payouts(env.clone(), market_id.clone(), cursor, 25)
+    ///             .unwrap();
+    ///     println!("Distributed {} stroops", paid);
+    ///     cursor = next;
     /// }
     /// ```
     ///
@@ -3234,219 +5280,148 @@ impl PredictifyHybrid {
     ///
     /// Payouts are calculated using the formula:
     /// ```text
-    /// user_payout = (user_stake * (100 - fee_percentage) / 100) * total_pool / winning_total
+    /// user_payout = (user_stake * (10_000 - fee_bps) / 10_000) * total_pool / winning_total
     /// ```
     ///
-    /// # Edge Cases
+    /// `fee_bps` is the platform fee plus the market's creator fee; the creator's
+    /// share accrues to the creator for `withdraw_creator_fees`. For a scalar market
+    /// resolved inside its range, `total_pool` and `winning_total` are the user's
+    /// side's share of the pool and that side's stake.
     ///
-    /// - **No Winners**: If no users bet on the winning outcome, no payouts are made
-    /// - **All Winners**: If all users bet on the winning outcome, they receive proportional shares
-    /// - **Double Payout Prevention**: Users who already claimed are skipped
+    /// If `winning_total` is zero the market is in refund mode and every position
+    /// is credited its full stake instead.
     ///
     /// # Events
     ///
     /// This function emits `WinningsClaimedEvent` for each user who receives a payout.
-    pub fn distribute_payouts(env: Env, market_id: Symbol) -> Result<i128, Error> {
+    pub fn distribute_payouts(
+        env: Env,
+        market_id: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Result<(i128, u32), Error> {
         admin::ContractPauseManager::require_not_paused(&env)?;
         let gas_marker = crate::gas::GasTracker::start_tracking(&env);
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
             return Err(Error::InvalidState);
         }
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
+        if limit == 0 {
+            return Err(Error::InvalidInput);
+        }
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.frozen {
+            return Err(Error::MarketFrozen);
+        }
 
         // Check if market is resolved
         let winning_outcomes = match &market.winning_outcomes {
-            Some(outcomes) => outcomes,
+            Some(outcomes) => outcomes.clone(),
             None => return Err(Error::MarketNotResolved),
         };
 
-        // Dispute window: payouts only after end_time + dispute_window_seconds
+        // Dispute window: payouts only after resolved_at + dispute_window_seconds
         let now = env.ledger().timestamp();
-        if now < market.end_time.saturating_add(market.dispute_window_seconds) {
+        if now < market.payout_unlock_time() {
             return Err(Error::InvalidState);
         }
-
-        // Get all bettors
-        let bettors = bets::BetStorage::get_all_bets_for_market(&env, &market_id);
-
-        // Get fee from legacy storage (backward compatible)
-        let fee_percent = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "platform_fee"))
-            .unwrap_or(200); // Default 2% if not set
-
-        // Since place_bet now updates market.votes and market.stakes,
-        // we can use the vote-based payout system for both bets and votes
-        let _total_distributed = 0;
-
-        // Check if payouts have already been distributed
-        let mut has_unclaimed_winners = false;
-
-        // Check voters
-        for (user, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                if !market.claimed.get(user.clone()).unwrap_or(false) {
-                    has_unclaimed_winners = true;
-                    break;
-                }
-            }
-        }
-
-        // Check bettors
-        if !has_unclaimed_winners {
-            for user in bettors.iter() {
-                if let Some(bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
-                    if winning_outcomes.contains(&bet.outcome)
-                        && !market.claimed.get(user.clone()).unwrap_or(false)
-                    {
-                        has_unclaimed_winners = true;
-                        break;
-                    }
-                }
-            }
+        // An open result dispute may still change the winning outcome
+        if disputes::DisputeUtils::has_open_result_dispute(&env, &market_id) {
+            return Err(Error::InvalidState);
         }
 
-        if !has_unclaimed_winners {
-            return Ok(0);
-        }
+        // Bets and votes both list their user in the market's bettor list, so it
+        // pages over every position; each page reads only its own users' entries.
+        let len = bets::BetStorage::get_bettor_count(&env, &market_id);
+        let end = start.saturating_add(limit.min(MAX_PAYOUT_PAGE_SIZE)).min(len);
+        let next_cursor = if end >= len { PAYOUT_CURSOR_DONE } else { end };
 
-        // Calculate total winning stakes across all winning outcomes (for split pool calculation)
-        // Supports both single winner and multi-winner (tie) scenarios
-        let mut winning_total = 0;
+        // Totals are computed over the whole market so every page uses identical math
+        let refund = markets::MarketUtils::is_refund_mode(&market);
 
-        // Sum voter stakes
-        for (voter, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-            }
-        }
+        let mut total_distributed: i128 = 0;
+        let mut credits: Vec<(Address, i128)> = Vec::new(&env);
 
-        // Sum bet amounts (check if bet outcome is in winning outcomes for multi-outcome support)
-        for user in bettors.iter() {
-            // Avoid double counting if user is already in votes (legacy support)
-            if market.votes.contains_key(user.clone()) {
+        for i in start..end {
+            let Some(user) = bets::BetStorage::get_bettor(&env, &market_id, i) else {
+                continue;
+            };
+            // Blocked positions stay unpaid until `release_blocked_funds`
+            if admin::ComplianceManager::is_blocked(&env, &user) {
                 continue;
             }
+            // Cancelled, cashed-out and transferred positions have nothing left
+            let Some(mut bet) = bets::BetStorage::get_position(&env, &market_id, &user) else {
+                continue;
+            };
+            let outcome = bet.outcome.clone();
+            let won = winning_outcomes.contains(&outcome);
+
+            if (won || refund) && !bets::BetStorage::is_claimed(&env, &market_id, &user) {
+                let user_stake = bet.amount;
+                // Refund mode returns every stake in full, without the fee
+                let payout = if refund {
+                    user_stake
+                } else {
+                    let (winning_total, total_pool) =
+                        markets::MarketUtils::winning_pool(&market, &winning_outcomes, &outcome)?;
+                    Self::settle_winner_payout(
+                        &env,
+                        &market_id,
+                        &market,
+                        &user,
+                        user_stake,
+                        winning_total,
+                        total_pool,
+                    )?
+                };
 
-            if let Some(bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
-                if winning_outcomes.contains(&bet.outcome) {
-                    winning_total += bet.amount;
+                // Allow 0 payout but mark as claimed before crediting
+                bets::BetStorage::mark_claimed(&env, &market_id, &user);
+                escrow::EscrowManager::debit(&env, &market_id, payout)?;
+                let payout = credits::CreditManager::settle(&env, &market_id, &user, payout)?;
+                if payout > 0 {
+                    total_distributed = amounts::checked_add(total_distributed, payout)?;
+                    credits.push_back((user.clone(), payout));
                 }
             }
-        }
-
-        if winning_total == 0 {
-            return Ok(0);
-        }
-
-        let total_pool = market.total_staked;
-        let fee_denominator = 10000i128; // Fee is in basis points
-
-        let mut total_distributed: i128 = 0;
-
-        // 1. Distribute to Voters
-        // Distribute payouts to all winners (handles both single and multi-winner cases)
-        // For multi-winner (ties), pool is split proportionally among all winners
-        for (user, outcome) in market.votes.iter() {
-            if winning_outcomes.contains(&outcome) {
-                if market.claimed.get(user.clone()).unwrap_or(false) {
-                    continue;
-                }
 
-                let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
-                if user_stake > 0 {
-                    let fee_denominator = 10000i128;
-                    let user_share = (user_stake
-                        .checked_mul(fee_denominator - fee_percent)
-                        .ok_or(Error::InvalidInput)?)
-                        / fee_denominator;
-                    // Payout calculation: (user_stake / total_winning_stakes) * total_pool
-                    // This automatically handles split pools for ties - each winner gets proportional share
-                    let payout = (user_share
-                        .checked_mul(total_pool)
-                        .ok_or(Error::InvalidInput)?)
-                        / winning_total;
-
-                    if payout >= 0 {
-                        // Allow 0 payout but mark as claimed
-                        market.claimed.set(user.clone(), true);
-                        if payout > 0 {
-                            total_distributed = total_distributed
-                                .checked_add(payout)
-                                .ok_or(Error::InvalidInput)?;
-
-                            // Credit winnings to user balance
-                            storage::BalanceStorage::add_balance(
-                                &env,
-                                &user,
-                                &types::ReflectorAsset::Stellar,
-                                payout,
-                            )?;
-
-                            EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
-                        }
-                    }
-                }
+            if bet.is_active() || (refund && bet.is_resolved()) {
+                bet.status = if refund {
+                    BetStatus::Refunded
+                } else if won {
+                    BetStatus::Won
+                } else {
+                    BetStatus::Lost
+                };
+                let _ = bets::BetStorage::store_bet(&env, &bet);
             }
         }
 
-        // 2. Distribute to Bettors
-        // Check if bet outcome is in winning outcomes (supports multi-outcome/tie scenarios)
-        for user in bettors.iter() {
-            if let Some(mut bet) = bets::BetStorage::get_bet(&env, &market_id, &user) {
-                if winning_outcomes.contains(&bet.outcome) {
-                    if market.claimed.get(user.clone()).unwrap_or(false) {
-                        // Already claimed (perhaps as a voter or double check)
-                        bet.status = BetStatus::Won;
-                        let _ = bets::BetStorage::store_bet(&env, &bet);
-                        continue;
-                    }
-
-                    if bet.amount > 0 {
-                        let user_share =
-                            (bet.amount * (fee_denominator - fee_percent)) / fee_denominator;
-                        let payout = (user_share * total_pool) / winning_total;
-
-                        if payout > 0 {
-                            market.claimed.set(user.clone(), true);
-                            total_distributed += payout;
-
-                            // Update bet status
-                            bet.status = BetStatus::Won;
-                            let _ = bets::BetStorage::store_bet(&env, &bet);
-
-                            // Credit winnings to user balance instead of direct transfer
-                            match storage::BalanceStorage::add_balance(
-                                &env,
-                                &user,
-                                &types::ReflectorAsset::Stellar,
-                                payout,
-                            ) {
-                                Ok(_) => {}
-                                Err(e) => panic_with_error!(env, e),
-                            }
-                            EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
-                        }
-                    }
-                } else {
-                    // Mark losing bet
-                    if bet.status == BetStatus::Active {
-                        bet.status = BetStatus::Lost;
-                        let _ = bets::BetStorage::store_bet(&env, &bet);
-                    }
-                }
-            }
+        // Advance the on-chain cursor when this page continues from it
+        // (re-submitted or out-of-order pages never move it back)
+        let stored_cursor = Self::get_payout_cursor(env.clone(), market_id.clone());
+        if start <= stored_cursor && stored_cursor != PAYOUT_CURSOR_DONE {
+            let mut cursors: Map<Symbol, u32> = env
+                .storage()
+                .persistent()
+                .get(&Symbol::new(&env, PAYOUT_CURSORS_KEY))
+                .unwrap_or(Map::new(&env));
+            let advanced = if next_cursor == PAYOUT_CURSOR_DONE || next_cursor > stored_cursor {
+                next_cursor
+            } else {
+                stored_cursor
+            };
+            cursors.set(market_id.clone(), advanced);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, PAYOUT_CURSORS_KEY), &cursors);
         }
 
-        // Save final market state
-        env.storage().persistent().set(&market_id, &market);
+        // Credit winnings only once every claim on this page is stored
+        for (user, payout) in credits.iter() {
+            Self::credit_payout(&env, &market, &user, payout)?;
+            EventEmitter::emit_winnings_claimed(&env, &market_id, &user, payout);
+        }
 
         crate::gas::GasTracker::end_tracking(
             &env,
@@ -3454,19 +5429,39 @@ impl PredictifyHybrid {
             gas_marker,
         );
 
-        Ok(total_distributed)
+        Ok((total_distributed, next_cursor))
+    }
+
+    /// Returns the next position index to be settled by `distribute_payouts` for a
+    /// market (0 before the first page, `PAYOUT_CURSOR_DONE` once fully distributed).
+    pub fn get_payout_cursor(env: Env, market_id: Symbol) -> u32 {
+        let cursors: Map<Symbol, u32> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(&env, PAYOUT_CURSORS_KEY))
+            .unwrap_or(Map::new(&env));
+        cursors.get(market_id).unwrap_or(0)
     }
 
     /// Finalize payouts after the dispute window has closed. Callable by anyone once
-    /// market is resolved and current time >= end_time + dispute_window_seconds.
+    /// market is resolved and current time >= resolved_at + dispute_window_seconds.
+    ///
+    /// Settles the next page (up to `MAX_PAYOUT_PAGE_SIZE` positions) from the stored
+    /// payout cursor; call repeatedly until `get_payout_cursor` returns `PAYOUT_CURSOR_DONE`.
     pub fn finalize_after_window(env: Env, market_id: Symbol) -> Result<i128, Error> {
-        Self::distribute_payouts(env, market_id)
+        let cursor = Self::get_payout_cursor(env.clone(), market_id.clone());
+        if cursor == PAYOUT_CURSOR_DONE {
+            return Ok(0);
+        }
+        let (distributed, _) =
+            Self::distribute_payouts(env, market_id, cursor, MAX_PAYOUT_PAGE_SIZE)?;
+        Ok(distributed)
     }
 
     // ===== EVENT ARCHIVE AND HISTORICAL QUERY =====
 
-    /// Mark a resolved or cancelled event (market) as archived. Admin only.
-    /// Market must be in Resolved or Cancelled state. Returns InvalidState if not
+    /// Mark a resolved, cancelled or voided event (market) as archived. Admin only.
+    /// Market must be in Resolved, Cancelled or Voided state. Returns InvalidState if not
     /// eligible, AlreadyClaimed if already archived.
     pub fn archive_event(env: Env, admin: Address, market_id: Symbol) -> Result<(), Error> {
         crate::event_archive::EventArchive::archive_event(&env, &admin, &market_id)
@@ -3508,172 +5503,867 @@ impl PredictifyHybrid {
         crate::event_archive::EventArchive::query_events_by_category(&env, &category, cursor, limit)
     }
 
-    /// Set the platform fee percentage (admin only).
-    ///
-    /// This function allows the admin to update the platform fee percentage
-    /// within the allowed limits (0-10%). The fee is applied to winning payouts.
-    ///
-    /// # Parameters
-    ///
-    /// * `env` - The Soroban environment for blockchain operations
-    /// * `admin` - The administrator address (must be authorized)
-    /// * `fee_percentage` - New fee percentage in basis points (e.g., 200 = 2%)
-    ///
-    /// # Returns
+    /// Set the platform fee as a whole percentage (admin only).
     ///
-    /// Returns `Result<(), Error>` where:
-    /// - `Ok(())` - Fee percentage updated successfully
-    /// - `Err(Error)` - Error if update fails
+    /// Deprecated: use `set_fee_bps`. Kept for existing integrations; the
+    /// percentage (`0..=10`) is scaled to basis points and applied through the
+    /// same path, so only a cut applies at once.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function will panic with specific errors if:
     /// - `Error::Unauthorized` - Caller is not the contract admin
-    /// - `Error::InvalidFeeConfig` - Fee percentage is outside valid range (0-10%)
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address};
-    /// # use predictify_hybrid::PredictifyHybrid;
-    /// # let env = Env::default();
-    /// # let admin = Address::generate(&env);
-    ///
-    /// // Set platform fee to 2.5% (250 basis points)
-    /// match PredictifyHybrid::set_platform_fee(env.clone(), admin, 250) {
-    ///     Ok(()) => println!("Fee updated successfully"),
-    ///     Err(e) => println!("Fee update failed: {:?}", e),
-    /// }
-    /// ```
-    ///
-    /// # Fee Limits
-    ///
-    /// - Minimum fee: 0% (0 basis points)
-    /// - Maximum fee: 10% (1000 basis points)
-    /// - Default fee: 2% (200 basis points)
+    /// - `Error::InvalidFeeConfig` - Percentage is outside `0..=10`
+    /// - `Error::FeeIncreaseTimelocked` - Fee is higher than the current one;
+    ///   increases are scheduled as `TimelockedAction::SetFeeBps`
     pub fn set_platform_fee(env: Env, admin: Address, fee_percentage: i128) -> Result<(), Error> {
-        // Require authentication
-        admin.require_auth();
-
-        // Verify admin - get from storage with defensive check
-        let admin_key = Symbol::new(&env, "Admin");
-        if !env.storage().persistent().has(&admin_key) {
-            return Err(Error::Unauthorized);
-        }
-
-        let stored_admin: Address = env.storage().persistent().get(&admin_key).unwrap();
-        if admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
-
-        // Validate fee percentage (0-10%)
-        if fee_percentage < 0 || fee_percentage > 1000 {
+        if !(MIN_PLATFORM_FEE_PERCENTAGE..=MAX_PLATFORM_FEE_PERCENTAGE).contains(&fee_percentage) {
             return Err(Error::InvalidFeeConfig);
         }
-
-        // Update fee in legacy storage
-        let fee_key = Symbol::new(&env, "platform_fee");
-        env.storage().persistent().set(&fee_key, &fee_percentage);
+        fees::FeeManager::set_fee_bps(&env, &admin, (fee_percentage * 100) as u32)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("set_fee"),
+            admin::AdminLogTarget::Contract,
+        );
 
         Ok(())
     }
 
-    /// Set global minimum and maximum bet limits (admin only).
-    /// Applies to all events that do not have per-event limits.
-    /// Rejects if min > max or outside absolute bounds (MIN_BET_AMOUNT..=MAX_BET_AMOUNT).
-    pub fn set_global_bet_limits(
-        env: Env,
-        admin: Address,
-        min_bet: i128,
-        max_bet: i128,
-    ) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
-        if admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
-        let limits = BetLimits { min_bet, max_bet };
-        crate::bets::set_global_bet_limits(&env, &limits)?;
-        let scope = Symbol::new(&env, "global");
-        EventEmitter::emit_bet_limits_updated(&env, &admin, &scope, min_bet, max_bet);
+    /// Set the global platform fee in basis points (admin only).
+    ///
+    /// Accepts `0..=1000` (0%-10%). New markets snapshot the fee at creation, so
+    /// changing it never alters payouts or fee collection of existing markets.
+    /// Only a cut applies at once; an increase must be scheduled as
+    /// `TimelockedAction::SetFeeBps` with `schedule_action`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - Fee exceeds the 1000 bps cap
+    /// - `Error::FeeIncreaseTimelocked` - Fee is higher than the current one
+    pub fn set_fee_bps(env: Env, admin: Address, bps: u32) -> Result<(), Error> {
+        fees::FeeManager::set_fee_bps(&env, &admin, bps)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("fee_bps"),
+            admin::AdminLogTarget::Contract,
+        );
         Ok(())
     }
 
-    /// Set global minimum pool size for resolution (admin only).
-    ///
-    /// Applies to all markets where `min_pool_size` is `None`.
-    /// A value of 0 disables any global minimum.
-    pub fn set_global_min_pool_size(
-        env: Env,
-        admin: Address,
-        min_pool: i128,
-    ) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
-        if admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
-
-        if min_pool < 0 {
-            return Err(Error::InvalidInput);
-        }
+    /// Get the global platform fee in basis points applied to newly created markets.
+    pub fn get_fee_bps(env: Env) -> u32 {
+        fees::FeeManager::get_fee_bps(&env)
+    }
 
-        env.storage()
-            .persistent()
-            .set(&Symbol::new(&env, GLOBAL_MIN_POOL_SIZE_KEY), &min_pool);
+    /// Set the share of a market's platform fee, in basis points (`0..=10000`),
+    /// paid to keepers that resolve it via `keeper_resolve` (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - `bps` exceeds 10000
+    pub fn set_keeper_incentive_bps(env: Env, admin: Address, bps: u32) -> Result<(), Error> {
+        fees::FeeManager::set_keeper_bps(&env, &admin, bps)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("keep_bps"),
+            admin::AdminLogTarget::Contract,
+        );
         Ok(())
     }
 
-    /// Get global minimum pool size for resolution.
-    /// Returns 0 when not configured.
-    pub fn get_global_min_pool_size(env: Env) -> i128 {
-        env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, GLOBAL_MIN_POOL_SIZE_KEY))
-            .unwrap_or(0)
+    /// Get the keeper incentive in basis points of a market's platform fee.
+    pub fn get_keeper_incentive_bps(env: Env) -> u32 {
+        fees::FeeManager::get_keeper_bps(&env)
     }
 
-    /// Set per-event minimum and maximum bet limits (admin only).
-    /// Overrides global limits for the given market.
-    pub fn set_event_bet_limits(
-        env: Env,
-        admin: Address,
-        market_id: Symbol,
-        min_bet: i128,
-        max_bet: i128,
-    ) -> Result<(), Error> {
-        admin.require_auth();
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::AdminNotSet));
-        if admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
-        let limits = BetLimits { min_bet, max_bet };
-        crate::bets::set_event_bet_limits(&env, &market_id, &limits)?;
-        EventEmitter::emit_bet_limits_updated(&env, &admin, &market_id, min_bet, max_bet);
+    /// Set the reward paid to keepers that settle overdue markets through
+    /// `keeper_distribute_payouts`, `keeper_void_market` and
+    /// `keeper_sweep_unclaimed` (admin only): a flat amount in the market's token
+    /// or basis points of its platform fee. Rewards come out of the fee vault.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - Flat amount negative or above `MAX_FEE_AMOUNT`,
+    ///   or basis points above 10,000
+    pub fn set_keeper_reward(env: Env, admin: Address, reward: KeeperReward) -> Result<(), Error> {
+        fees::FeeManager::set_keeper_reward(&env, &admin, &reward)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("keep_rwd"),
+            admin::AdminLogTarget::Contract,
+        );
         Ok(())
     }
 
-    /// Get effective bet limits for a market (per-event if set, else global, else defaults).
-    pub fn get_effective_bet_limits(env: Env, market_id: Symbol) -> BetLimits {
-        crate::bets::get_effective_bet_limits(&env, &market_id)
+    /// Get the reward paid to keepers that settle overdue markets.
+    pub fn get_keeper_reward(env: Env) -> KeeperReward {
+        fees::FeeManager::get_keeper_reward(&env)
     }
 
-    /// Withdraw collected platform fees (admin only).
+    /// Exempt `addr` from the platform fee on its winnings (admin only).
     ///
-    /// This function allows the admin to withdraw fees that have been collected
-    /// from market payouts. Fees are accumulated across all markets and can be
+    /// Meant for partners that run markets jointly with the platform. The
+    /// platform fee share an exempt winner would have paid stays in the pool
+    /// and is paid to them; creator fees still apply. Exemption is checked at
+    /// the time of each claim.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    pub fn add_fee_exempt(env: Env, admin: Address, addr: Address) -> Result<(), Error> {
+        fees::FeeManager::set_fee_exempt(&env, &admin, &addr, true)
+    }
+
+    /// Remove `addr` from the platform fee exemption list (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    pub fn remove_fee_exempt(env: Env, admin: Address, addr: Address) -> Result<(), Error> {
+        fees::FeeManager::set_fee_exempt(&env, &admin, &addr, false)
+    }
+
+    /// Whether `addr` is exempt from the platform fee on its winnings.
+    pub fn is_fee_exempt(env: Env, addr: Address) -> bool {
+        fees::FeeManager::is_fee_exempt(&env, &addr)
+    }
+
+    /// Set the flat fee, in the staking token, that non-admin creators pay per market
+    /// (admin only). `0` disables the fee; the admin never pays it. `amount` is a
+    /// whole-token amount written at `DEFAULT_TOKEN_DECIMALS` (`1_0000000` is one
+    /// token), converted with the staking token's decimals.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - Amount is negative or above `MAX_FEE_AMOUNT`
+    pub fn set_creation_fee(env: Env, admin: Address, amount: i128) -> Result<(), Error> {
+        fees::FeeManager::set_creation_fee(&env, &admin, amount)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("crt_fee"),
+            admin::AdminLogTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Get the market creation fee charged to non-admin creators, in base units
+    /// of the staking token.
+    pub fn get_creation_fee(env: Env) -> i128 {
+        fees::FeeManager::get_creation_fee(&env)
+    }
+
+    /// Set the refundable bond, in the staking token, that non-admin creators post
+    /// per market (admin only). `0` disables the bond; the admin never posts it.
+    /// Bonds already posted keep their amount. `amount` is written at
+    /// `DEFAULT_TOKEN_DECIMALS` like `set_creation_fee`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - Amount is negative or above `MAX_FEE_AMOUNT`
+    pub fn set_creation_bond(env: Env, admin: Address, amount: i128) -> Result<(), Error> {
+        fees::FeeManager::set_creation_bond(&env, &admin, amount)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("crt_bond"),
+            admin::AdminLogTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Get the market creation bond posted by non-admin creators, in base units
+    /// of the staking token.
+    pub fn get_creation_bond(env: Env) -> i128 {
+        fees::FeeManager::get_creation_bond(&env)
+    }
+
+    /// Creation bond still held against a market; 0 once returned or slashed.
+    pub fn get_market_creation_bond(env: Env, market_id: Symbol) -> i128 {
+        fees::FeeManager::get_market_creation_bond(&env, &market_id)
+    }
+
+    /// Set the pool-size fee tiers as `(pool breakpoint, bps)` (admin only).
+    ///
+    /// When a market resolves, the last tier whose breakpoint its total pool
+    /// reaches sets its platform fee, capped at the `fee_bps` it snapshotted at
+    /// creation, so tiers can only lower fees and apply at once. Markets below
+    /// the first breakpoint, markets with a `set_market_fee_bps` override, and
+    /// every market while no tiers are set keep their flat `fee_bps`. An empty
+    /// list clears the tiers.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - Breakpoints negative or not strictly
+    ///   increasing, or fees increasing or above `MAX_PLATFORM_FEE_BPS`
+    pub fn set_fee_tiers(env: Env, admin: Address, tiers: Vec<(i128, u32)>) -> Result<(), Error> {
+        fees::FeeManager::set_fee_tiers(&env, &admin, &tiers)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("fee_tiers"),
+            admin::AdminLogTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Get the pool-size fee tiers; empty when none are set.
+    pub fn get_fee_tiers(env: Env) -> Vec<(i128, u32)> {
+        fees::FeeManager::get_fee_tiers(&env)
+    }
+
+    /// Override a market's platform fee, in basis points (admin only).
+    ///
+    /// `create_market` already takes the maximum number of contract arguments,
+    /// so the override is applied right after creation, before the first bet.
+    /// It replaces the global fee snapshotted at creation for every payout and
+    /// fee collection path, and `get_market` reports it as `fee_bps`. A zero
+    /// override makes a promotional market: no platform fee is deducted from
+    /// payouts and `collect_fees` returns 0. Fee tiers do not apply to an
+    /// overridden market. Only a cut applies at once; a higher fee must be
+    /// scheduled as `TimelockedAction::SetMarketFeeBps`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::InvalidFeeConfig` - Fee exceeds `MAX_PLATFORM_FEE_BPS`
+    /// - `Error::FeeIncreaseTimelocked` - Fee is higher than the market's current fee
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes
+    pub fn set_market_fee_bps(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        bps: u32,
+    ) -> Result<(), Error> {
+        fees::FeeManager::set_market_fee_bps(&env, &admin, &market_id, bps)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("mkt_fee"),
+            admin::AdminLogTarget::Market(market_id),
+        );
+        Ok(())
+    }
+
+    /// Set the creator fee for a market, in basis points (market creator only).
+    ///
+    /// The creator fee is deducted from winning payouts alongside the platform fee
+    /// and accrues to the creator, who collects it with `withdraw_creator_fees`.
+    /// It can only be set before the first bet.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller did not create the market
+    /// - `Error::InvalidFeeConfig` - Fee exceeds `MAX_CREATOR_FEE_BPS`
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes
+    /// - `Error::InvalidState` - Market uses its own staking token
+    pub fn set_creator_fee_bps(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        bps: u32,
+    ) -> Result<(), Error> {
+        fees::FeeManager::set_creator_fee_bps(&env, &creator, &market_id, bps)
+    }
+
+    /// Set how a market is resolved (market creator only).
+    ///
+    /// In `ResolveMode::Hybrid` the oracle result alone does not resolve the
+    /// market: `resolve_market` opens a stake-weighted community vote, and
+    /// `finalize_community_vote` resolves the market only when the vote
+    /// agrees with the oracle. A disagreement moves the market to `Disputed`
+    /// for the admin to settle.
+    ///
+    /// In `ResolveMode::Optimistic` the oracle is not consulted: the market is
+    /// resolved by a bonded `propose_result` that stands unless challenged
+    /// with `challenge_result`. Can only be set before the first bet.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller did not create the market
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes
+    /// - `Error::InvalidState` - `Hybrid` or `Optimistic` requested for a scalar market
+    pub fn set_resolve_mode(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        mode: ResolveMode,
+    ) -> Result<(), Error> {
+        resolution::CommunityResolutionManager::set_resolve_mode(&env, &creator, &market_id, mode)
+    }
+
+    /// Make a market scalar, or categorical again (market creator only).
+    ///
+    /// A `MarketType::Scalar` market takes `long` and `short` bets instead of its
+    /// original outcomes and resolves on the oracle value: the `long` side is paid
+    /// `(value - lower_bound) / (upper_bound - lower_bound)` of the pool and the
+    /// `short` side the rest, with the value clamped to the range. Each side splits
+    /// its share pro rata after fees. Can only be set before the first bet.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller did not create the market
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds positions
+    /// - `Error::InvalidInput` - `lower_bound` is not below `upper_bound`
+    /// - `Error::InvalidState` - Market uses `ResolveMode::Hybrid` or `Optimistic`
+    pub fn set_market_type(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        market_type: MarketType,
+    ) -> Result<(), Error> {
+        markets::MarketStateManager::set_market_type(&env, &creator, &market_id, market_type)
+    }
+
+    /// Make a market public or invite-only (market creator or admin).
+    ///
+    /// Bets on a `MarketAccess::Allowlist` market are rejected with
+    /// `Error::Unauthorized` unless the bettor is on its allowlist, managed with
+    /// `update_market_allowlist`. Going private starts from an empty allowlist and
+    /// going public discards it. Positions already held are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller is neither the market creator nor the admin
+    /// - `Error::MarketClosed` - Market is no longer active or has reached `end_time`
+    pub fn set_market_access(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        access: MarketAccess,
+    ) -> Result<(), Error> {
+        markets::MarketAccessManager::set_access(&env, &caller, &market_id, access)
+    }
+
+    /// Add and remove bettors on a private market's allowlist (market creator or
+    /// admin, before `end_time`).
+    ///
+    /// Removals apply after additions. The list holds at most
+    /// `MAX_MARKET_ALLOWLIST_SIZE` addresses.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller is neither the market creator nor the admin
+    /// - `Error::MarketClosed` - Market is no longer active or has reached `end_time`
+    /// - `Error::InvalidState` - Market is public, see `set_market_access`
+    /// - `Error::ThresholdTooHigh` - The allowlist would exceed its maximum size
+    pub fn update_market_allowlist(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+        add: Vec<Address>,
+        remove: Vec<Address>,
+    ) -> Result<(), Error> {
+        markets::MarketAccessManager::update_allowlist(&env, &caller, &market_id, &add, &remove)
+    }
+
+    /// Whether anyone or only allowlisted addresses may bet on a market.
+    pub fn get_market_access(env: Env, market_id: Symbol) -> MarketAccess {
+        markets::MarketAccessManager::get_access(&env, &market_id)
+    }
+
+    /// Bettor allowlist of a private market; empty for public markets.
+    pub fn get_market_allowlist(env: Env, market_id: Symbol) -> Vec<Address> {
+        markets::MarketAccessManager::get_allowlist(&env, &market_id)
+    }
+
+    /// Creator fees accrued to `creator` and not yet withdrawn.
+    pub fn get_creator_fees(env: Env, creator: Address) -> i128 {
+        fees::FeeTracker::get_creator_fees(&env, &creator)
+    }
+
+    /// Transfer every creator fee accrued to `creator` and return the amount.
+    ///
+    /// Returns `Error::NothingToClaim` when nothing has accrued, and
+    /// `Error::AddressBlocked` when `creator` is blocked.
+    pub fn withdraw_creator_fees(env: Env, creator: Address) -> Result<i128, Error> {
+        fees::FeeManager::withdraw_creator_fees(&env, &creator)
+    }
+
+    /// Referrer attached to `user` by their first bet, if any.
+    pub fn get_referrer(env: Env, user: Address) -> Option<Address> {
+        referrals::ReferralManager::get_referrer(&env, &user)
+    }
+
+    /// Referral earnings accrued to `referrer` and not yet withdrawn.
+    pub fn get_referral_earnings(env: Env, referrer: Address) -> i128 {
+        referrals::ReferralManager::get_earnings(&env, &referrer)
+    }
+
+    /// Transfer every referral earning accrued to `referrer` and return the amount.
+    ///
+    /// Returns `Error::NothingToClaim` when nothing has accrued, and
+    /// `Error::AddressBlocked` when `referrer` is blocked.
+    pub fn withdraw_referral_earnings(env: Env, referrer: Address) -> Result<i128, Error> {
+        referrals::ReferralManager::withdraw_earnings(&env, &referrer)
+    }
+
+    /// Set the share of the platform fee on a referred user's winnings paid to
+    /// their referrer, in basis points (`0..=10000`, admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidFeeConfig` - `bps` exceeds 10000
+    pub fn set_referral_bps(env: Env, admin: Address, bps: u32) -> Result<(), Error> {
+        referrals::ReferralManager::set_referral_bps(&env, &admin, bps)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("ref_bps"),
+            admin::AdminLogTarget::Contract,
+        );
+        Ok(())
+    }
+
+    /// Get the referral share in basis points of the platform fee.
+    pub fn get_referral_bps(env: Env) -> u32 {
+        referrals::ReferralManager::get_referral_bps(&env)
+    }
+
+    /// Set global minimum and maximum bet limits (admin only).
+    /// Applies to all events that do not have per-event limits.
+    /// Limits are whole-token amounts written at `DEFAULT_TOKEN_DECIMALS`
+    /// (`1_0000000` is one token) and converted with the staking token's decimals.
+    /// Rejects if min > max or outside absolute bounds (MIN_BET_AMOUNT..=MAX_BET_AMOUNT).
+    pub fn set_global_bet_limits(
+        env: Env,
+        admin: Address,
+        min_bet: i128,
+        max_bet: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        let limits = BetLimits { min_bet, max_bet };
+        crate::bets::set_global_bet_limits(&env, &limits)?;
+        let scope = Symbol::new(&env, "global");
+        EventEmitter::emit_bet_limits_updated(&env, &admin, &scope, min_bet, max_bet);
+        Ok(())
+    }
+
+    /// Set the global minimum bet amount (admin only), keeping the global maximum.
+    ///
+    /// `min_bet` is a whole-token amount written at `DEFAULT_TOKEN_DECIMALS`
+    /// (`1_0000000` is one token), converted with the staking token's decimals.
+    /// Markets can override it with `set_event_bet_limits`. `place_bet` rejects bets
+    /// below the effective minimum with `Error::BetBelowMinimum`, and zero or
+    /// negative amounts with `Error::InvalidAmount`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InsufficientStake` - `min_bet` is below `MIN_BET_AMOUNT`
+    /// - `Error::InvalidInput` - `min_bet` exceeds the global maximum bet
+    pub fn set_min_bet(env: Env, admin: Address, min_bet: i128) -> Result<(), Error> {
+        let max_bet = crate::bets::get_configured_global_bet_limits(&env).max_bet;
+        Self::set_global_bet_limits(env, admin, min_bet, max_bet)
+    }
+
+    /// Get the global minimum bet amount, in base units of the staking token.
+    pub fn get_min_bet(env: Env) -> i128 {
+        crate::bets::get_global_bet_limits(&env).min_bet
+    }
+
+    /// Set global minimum pool size for resolution (admin only).
+    ///
+    /// Applies to all markets where `min_pool_size` is `None`.
+    /// A value of 0 disables any global minimum.
+    pub fn set_global_min_pool_size(
+        env: Env,
+        admin: Address,
+        min_pool: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if min_pool < 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, GLOBAL_MIN_POOL_SIZE_KEY), &min_pool);
+        Ok(())
+    }
+
+    /// Get global minimum pool size for resolution.
+    /// Returns 0 when not configured.
+    pub fn get_global_min_pool_size(env: Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, GLOBAL_MIN_POOL_SIZE_KEY))
+            .unwrap_or(0)
+    }
+
+    /// Set or clear a market's maximum pool size (market creator only, before the first bet).
+    ///
+    /// `place_bet` rejects any bet that would take the combined outcome pools
+    /// above the cap with `Error::ThresholdTooHigh`; bets are never partially
+    /// filled. `get_market_stats` reports the remaining capacity.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller did not create the market
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes
+    /// - `Error::InvalidInput` - Cap not positive or below the market's minimum pool size
+    pub fn set_max_pool_size(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        max_pool_size: Option<i128>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if let Some(cap) = max_pool_size {
+            if cap <= 0 || cap < markets::MarketUtils::effective_min_pool_size(&env, &market) {
+                return Err(Error::InvalidInput);
+            }
+        }
+
+        market.max_pool_size = max_pool_size;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        EventEmitter::emit_max_pool_size_set(&env, &market_id, &creator, max_pool_size);
+        Ok(())
+    }
+
+    /// Set or clear a market's anti-sniping settings (market creator only, before the first bet).
+    ///
+    /// A bet of at least `threshold_amount` placed within `window_seconds` of the
+    /// betting deadline extends the deadline by `extension_seconds`, moving
+    /// `end_time` along when the new deadline would pass it. Extensions stop once
+    /// they add up to `max_extension_seconds`. The new deadline shows in
+    /// `get_market` and applies to every later bet.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller did not create the market
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes
+    /// - `Error::InvalidInput` - A window, extension or threshold is zero, or the
+    ///   maximum extension is below a single extension
+    pub fn set_anti_snipe(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        config: Option<AntiSnipeConfig>,
+    ) -> Result<(), Error> {
+        extensions::AntiSnipeManager::set_config(&env, &creator, &market_id, config)
+    }
+
+    /// Anti-sniping settings of a market, if any.
+    pub fn get_anti_snipe(env: Env, market_id: Symbol) -> Option<AntiSnipeConfig> {
+        extensions::AntiSnipeManager::get_config(&env, &market_id)
+    }
+
+    /// Seconds anti-sniping has added to a market's betting deadline so far.
+    pub fn get_anti_snipe_extension(env: Env, market_id: Symbol) -> u64 {
+        extensions::AntiSnipeManager::get_extended_seconds(&env, &market_id)
+    }
+
+    /// Set the minimum and maximum market duration in seconds (admin only).
+    ///
+    /// `create_market` rejects durations outside `[min_seconds, max_seconds]`.
+    /// `min_seconds` must be positive and not above `max_seconds`.
+    pub fn set_market_duration_limits(
+        env: Env,
+        admin: Address,
+        min_seconds: u64,
+        max_seconds: u64,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if min_seconds == 0 || min_seconds > max_seconds {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage().persistent().set(
+            &Symbol::new(&env, MARKET_DURATION_LIMITS_KEY),
+            &(min_seconds, max_seconds),
+        );
+        Ok(())
+    }
+
+    /// Set the maximum number of outcomes a new market may have (admin only).
+    ///
+    /// Must be between `MIN_MARKET_OUTCOMES` and `MAX_CONFIGURABLE_MARKET_OUTCOMES`.
+    /// Existing markets are unaffected.
+    pub fn set_max_market_outcomes(env: Env, admin: Address, max_outcomes: u32) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if !(config::MIN_MARKET_OUTCOMES..=config::MAX_CONFIGURABLE_MARKET_OUTCOMES)
+            .contains(&max_outcomes)
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, MAX_MARKET_OUTCOMES_KEY), &max_outcomes);
+        Ok(())
+    }
+
+    /// Allow a non-admin address to create markets (admin only).
+    pub fn add_market_creator(env: Env, admin: Address, creator: Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let key = Symbol::new(&env, MARKET_CREATORS_KEY);
+        let mut creators: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        if !creators.contains(&creator) {
+            creators.push_back(creator.clone());
+            env.storage().persistent().set(&key, &creators);
+        }
+
+        EventEmitter::emit_market_creator_updated(&env, &creator, true, &admin);
+        Ok(())
+    }
+
+    /// Revoke an address's permission to create markets (admin only).
+    /// Markets it already created are unaffected.
+    pub fn remove_market_creator(env: Env, admin: Address, creator: Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let key = Symbol::new(&env, MARKET_CREATORS_KEY);
+        let creators: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env));
+        let mut remaining = Vec::new(&env);
+        for addr in creators.iter() {
+            if addr != creator {
+                remaining.push_back(addr);
+            }
+        }
+        env.storage().persistent().set(&key, &remaining);
+
+        EventEmitter::emit_market_creator_updated(&env, &creator, false, &admin);
+        Ok(())
+    }
+
+    /// Whether `creator` is on the market creator allowlist.
+    /// The admin can always create markets and need not be listed.
+    pub fn is_market_creator(env: Env, creator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<Symbol, Vec<Address>>(&Symbol::new(&env, MARKET_CREATORS_KEY))
+            .is_some_and(|creators| creators.contains(&creator))
+    }
+
+    /// Get the maximum number of outcomes a new market may have.
+    /// Defaults to `MAX_MARKET_OUTCOMES` when not configured.
+    pub fn get_max_market_outcomes(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, MAX_MARKET_OUTCOMES_KEY))
+            .unwrap_or(config::MAX_MARKET_OUTCOMES)
+    }
+
+    /// Get the `(min_seconds, max_seconds)` market duration limits.
+    /// Defaults to `MIN_MARKET_DURATION_DAYS`..`MAX_MARKET_DURATION_DAYS` when not configured.
+    pub fn get_market_duration_limits(env: Env) -> (u64, u64) {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, MARKET_DURATION_LIMITS_KEY))
+            .unwrap_or((
+                config::MIN_MARKET_DURATION_DAYS as u64 * 24 * 60 * 60,
+                config::MAX_MARKET_DURATION_DAYS as u64 * 24 * 60 * 60,
+            ))
+    }
+
+    /// Set per-event minimum and maximum bet limits (admin only).
+    /// Overrides global limits for the given market; amounts are written at
+    /// `DEFAULT_TOKEN_DECIMALS` like `set_global_bet_limits`.
+    pub fn set_event_bet_limits(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        min_bet: i128,
+        max_bet: i128,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        let limits = BetLimits { min_bet, max_bet };
+        crate::bets::set_event_bet_limits(&env, &market_id, &limits)?;
+        EventEmitter::emit_bet_limits_updated(&env, &admin, &market_id, min_bet, max_bet);
+        Ok(())
+    }
+
+    /// Set or clear the global default cap on how much one user may stake on a
+    /// market, summed across all their bets on it (admin only). The cap is a
+    /// whole-token amount written at `DEFAULT_TOKEN_DECIMALS`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::InvalidInput` - Cap outside `MIN_BET_AMOUNT..=MAX_BET_AMOUNT`
+    pub fn set_default_max_bet_per_user(
+        env: Env,
+        admin: Address,
+        max_bet_per_user: Option<i128>,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        crate::bets::set_global_max_bet_per_user(&env, max_bet_per_user)?;
+        let scope = Symbol::new(&env, "global");
+        EventEmitter::emit_user_bet_cap_updated(&env, &admin, &scope, max_bet_per_user);
+        Ok(())
+    }
+
+    /// Set or clear a market's per-user exposure cap, overriding the global
+    /// default (market creator only, before the first bet). The cap is a
+    /// whole-token amount written at `DEFAULT_TOKEN_DECIMALS`.
+    ///
+    /// `place_bet` rejects a bet that would take the user's total stake on the
+    /// market above the cap with `Error::ThresholdTooHigh`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::Unauthorized` - Caller did not create the market
+    /// - `Error::MarketClosed` - Market is no longer active
+    /// - `Error::BetsAlreadyPlaced` - Market already holds stakes
+    /// - `Error::InvalidInput` - Cap outside `MIN_BET_AMOUNT..=MAX_BET_AMOUNT`
+    pub fn set_max_bet_per_user(
+        env: Env,
+        creator: Address,
+        market_id: Symbol,
+        max_bet_per_user: Option<i128>,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if market.creator != creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        crate::bets::set_event_max_bet_per_user(&env, &market_id, max_bet_per_user)?;
+        EventEmitter::emit_user_bet_cap_updated(&env, &creator, &market_id, max_bet_per_user);
+        Ok(())
+    }
+
+    /// Effective per-user exposure cap for a market in base units of the staking
+    /// token (per-event if set, else the global default); `None` when only the
+    /// maximum bet applies.
+    pub fn get_max_bet_per_user(env: Env, market_id: Symbol) -> Option<i128> {
+        crate::bets::get_effective_max_bet_per_user(&env, &market_id)
+    }
+
+    /// How much more `user` may stake on a market before reaching the per-user
+    /// cap, counting their existing position.
+    pub fn get_remaining_bet_allowance(env: Env, user: Address, market_id: Symbol) -> i128 {
+        bets::BetManager::get_remaining_bet_allowance(&env, &market_id, &user)
+    }
+
+    /// Get effective bet limits for a market in base units of the staking token
+    /// (per-event if set, else global, else defaults).
+    pub fn get_effective_bet_limits(env: Env, market_id: Symbol) -> BetLimits {
+        crate::bets::get_effective_bet_limits(&env, &market_id)
+    }
+
+    /// Withdraw collected platform fees (admin or `FeeManager` role).
+    ///
+    /// This function allows the admin to withdraw fees that have been collected
+    /// from market payouts. Fees are accumulated across all markets and can be
     /// withdrawn by the admin.
     ///
     /// # Withdrawal Schedule (Timelock / Cap)
@@ -3703,9 +6393,14 @@ impl PredictifyHybrid {
     ///
     /// # Errors
     ///
-    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::ContractPaused` - Contract is paused
+    /// - `Error::Unauthorized` - Caller is not the admin and lacks the `FeeManager` role,
+    ///   or several admin signers must approve it through `propose_admin_op`
     /// - `Error::InvalidState` - Reentrancy guard indicates invalid state
     /// - `Error::InvalidInput` - Invalid withdrawal amount or schedule math overflow
+    /// - `Error::InsufficientBalance` - `amount` exceeds the fee vault balance
+    ///
+    /// Fees are paid to the treasury (see `set_treasury`), or to the admin if none is set.
     ///
     /// # Example
     ///
@@ -3723,26 +6418,198 @@ impl PredictifyHybrid {
     /// ```
     pub fn withdraw_collected_fees(env: Env, admin: Address, amount: i128) -> Result<i128, Error> {
         admin.require_auth();
+        admin::ContractPauseManager::require_not_paused(&env)?;
         if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
             return Err(Error::InvalidState);
         }
 
-        // Verify admin
-        let stored_admin: Address = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
+        admin::RoleManager::require_role(&env, admin::Role::FeeManager, &admin)?;
+        multisig::MultisigManager::require_single_signer(&env)?;
+        let recipient = Self::fee_recipient(&env)?;
+        fees::FeeWithdrawalManager::withdraw_fees(&env, &admin, &recipient, amount)
+    }
 
-        if admin != stored_admin {
-            return Err(Error::Unauthorized);
+    /// Withdraw collected platform fees to an explicit `recipient` (admin or
+    /// `FeeManager` role), under the same schedule and vault limit as `withdraw_fees`.
+    pub fn withdraw_fees_to(
+        env: Env,
+        admin: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        admin.require_auth();
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+
+        admin::RoleManager::require_role(&env, admin::Role::FeeManager, &admin)?;
+        multisig::MultisigManager::require_single_signer(&env)?;
+        fees::FeeWithdrawalManager::withdraw_fees(&env, &admin, &recipient, amount)
+    }
+
+    /// Withdraw collected fees from the vault of a per-market token (admin or `FeeManager` role).
+    ///
+    /// Fees collected from markets with their own token (see `set_market_token`) are
+    /// tracked per token and withdrawn here, in that token, under the same schedule
+    /// as `withdraw_fees`.
+    pub fn withdraw_token_fees(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        admin.require_auth();
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+
+        admin::RoleManager::require_role(&env, admin::Role::FeeManager, &admin)?;
+        multisig::MultisigManager::require_single_signer(&env)?;
+        let recipient = Self::fee_recipient(&env)?;
+        fees::FeeWithdrawalManager::withdraw_fees_for_token(
+            &env,
+            &admin,
+            &recipient,
+            Some(&token),
+            amount,
+        )
+    }
+
+    /// Get the collected-but-unwithdrawn fee balance for a token
+    /// (`None` = the global staking token vault).
+    pub fn get_fee_vault_balance(env: Env, token: Option<Address>) -> i128 {
+        fees::FeeTracker::get_fee_vault_balance(&env, token.as_ref())
+    }
+
+    /// Read-only accounting check for the global staking token, callable by anyone.
+    ///
+    /// Returns `(escrow_total, vault_total, token_balance)`:
+    /// - `escrow_total` - sum of every global-token market's escrow (see
+    ///   `reconcile_market`)
+    /// - `vault_total` - protocol fees, credited only by fee collection and
+    ///   penalties/slashes and debited only by withdrawals and dispute rewards
+    /// - `token_balance` - tokens the contract actually holds
+    ///
+    /// The invariant is `token_balance >= escrow_total + vault_total`; the surplus
+    /// is settled user funds not yet withdrawn (credited winnings, creator fees).
+    /// Fee withdrawals are bounded by `vault_total`, never by `token_balance`.
+    pub fn reconcile(env: Env) -> (i128, i128, i128) {
+        let escrow_total = escrow::EscrowManager::global_token_total(&env);
+        let vault_total = fees::FeeTracker::get_fee_vault_balance(&env, None);
+        let token_balance = markets::MarketUtils::get_token_client(&env)
+            .map(|client| client.balance(&env.current_contract_address()))
+            .unwrap_or(0);
+        (escrow_total, vault_total, token_balance)
+    }
+
+    /// Read-only accounting check for one market, callable by anyone.
+    ///
+    /// Returns `(escrowed, computed_from_positions)`:
+    /// - `escrowed` - funds the market's escrow ledger says it holds, credited by
+    ///   bets, votes and dispute bonds and debited by payouts, refunds, creator
+    ///   and referral shares and fee collection
+    /// - `computed_from_positions` - what the market still owes: open stakes and
+    ///   bonds before settlement, unclaimed stakes once cancelled, and after
+    ///   resolution the unclaimed winnings before creator fees plus the platform
+    ///   fee not yet collected
+    ///
+    /// The invariant is `escrowed >= computed_from_positions`; the difference is
+    /// rounding dust from proportional payouts, which the last winner paid takes.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    pub fn reconcile_market(env: Env, market_id: Symbol) -> Result<(i128, i128), Error> {
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let owed = Self::market_obligations(&env, &market_id, &market)?;
+        Ok((escrow::EscrowManager::get(&env, &market_id), owed))
+    }
+
+    /// Winnings a resolved market has paid out so far, creator shares included.
+    ///
+    /// Each winner receives their share rounded down, and the last winner paid
+    /// also takes the rounding remainder, so once every winner is paid this
+    /// equals the pool less the platform fee exactly.
+    pub fn get_distributed_so_far(env: Env, market_id: Symbol) -> i128 {
+        escrow::EscrowManager::get_distributed(&env, &market_id)
+    }
+
+    /// Funds `market` still owes to its participants and the fee vault.
+    fn market_obligations(env: &Env, market_id: &Symbol, market: &Market) -> Result<i128, Error> {
+        let mut owed = market.total_dispute_stakes();
+        if let Some(dispute) = disputes::DisputeUtils::get_result_dispute(env, market_id) {
+            if dispute.status == disputes::DisputeStatus::Active {
+                owed = amounts::checked_add(owed, dispute.stake)?;
+            }
+        }
+        let optimistic_bonds = [
+            optimistic::OptimisticResolutionManager::get_proposal(env, market_id),
+            optimistic::OptimisticResolutionManager::get_challenge(env, market_id),
+        ];
+        for bond in optimistic_bonds.into_iter().flatten() {
+            if bond.status == disputes::DisputeStatus::Active {
+                owed = amounts::checked_add(owed, bond.stake)?;
+            }
+        }
+
+        let winning_outcomes = match &market.winning_outcomes {
+            Some(outcomes) => outcomes,
+            None if market.state.is_refunding() => {
+                for user in bets::BetStorage::get_all_bets_for_market(env, market_id).iter() {
+                    let Some(bet) = bets::BetStorage::get_position(env, market_id, &user) else {
+                        continue;
+                    };
+                    let refunded =
+                        bets::BetStorage::is_claimed(env, market_id, &user) || !bet.is_active();
+                    if !refunded {
+                        owed = amounts::checked_add(owed, bet.amount)?;
+                    }
+                }
+                return Ok(owed);
+            }
+            None => return amounts::checked_add(owed, market.total_staked),
+        };
+
+        let refund = markets::MarketUtils::is_refund_mode(market);
+        for user in bets::BetStorage::get_all_bets_for_market(env, market_id).iter() {
+            if bets::BetStorage::is_claimed(env, market_id, &user) {
+                continue;
+            }
+            let Some(bet) = bets::BetStorage::get_position(env, market_id, &user) else {
+                continue;
+            };
+            let (outcome, user_stake) = (bet.outcome, bet.amount);
+            let position = if refund {
+                user_stake
+            } else if winning_outcomes.contains(&outcome) {
+                let (winning_total, total_pool) =
+                    markets::MarketUtils::winning_pool(market, winning_outcomes, &outcome)?;
+                Self::calculate_winner_payout(
+                    user_stake,
+                    winning_total,
+                    total_pool,
+                    market.fee_bps as i128,
+                )?
+            } else {
+                0
+            };
+            owed = amounts::checked_add(owed, position)?;
         }
-        fees::FeeWithdrawalManager::withdraw_fees(&env, &admin, amount)
+
+        if !refund && !market.fee_collected {
+            let fee = if fees::FeeTracker::is_market_fee_reserved(env, market_id) {
+                fees::FeeTracker::get_market_fees(env, market_id).0
+            } else {
+                fees::FeeTracker::market_fee_amount(market)
+            };
+            owed = amounts::checked_add(owed, fee)?;
+        }
+        Ok(owed)
     }
 
-    /// Withdraw collected platform fees (admin only) with timelock/schedule enforcement.
+    /// Withdraw collected platform fees (admin or `FeeManager` role) with timelock/schedule enforcement.
     ///
     /// This is the preferred alias for `withdraw_collected_fees`.
     pub fn withdraw_fees(env: Env, admin: Address, amount: i128) -> Result<i128, Error> {
@@ -3770,9 +6637,9 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
         if admin != stored_admin {
             return Err(Error::Unauthorized);
         }
@@ -3837,7 +6704,7 @@ impl PredictifyHybrid {
     ///
     /// # Extension Rules
     ///
-    /// - Market must be in Active or Ended state (not Resolved, Closed, or Cancelled)
+    /// - Market must not be Resolved, Closed, Cancelled or Voided
     /// - Total extensions cannot exceed `max_extension_days` (default 30 days)
     /// - Extensions are recorded in market's extension history
     /// - Admin must pay extension fee if configured
@@ -3859,25 +6726,21 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
             return Err(Error::Unauthorized);
         }
 
         // Get market
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
-        // Validate market state - cannot extend resolved, closed, or cancelled markets
+        // Validate market state - cannot extend resolved, closed, cancelled or voided markets
         if market.state == MarketState::Resolved
             || market.state == MarketState::Closed
-            || market.state == MarketState::Cancelled
+            || market.state.is_refunding()
         {
             return Err(Error::MarketResolved);
         }
@@ -3912,7 +6775,7 @@ impl PredictifyHybrid {
         market.extension_history.push_back(extension);
 
         // Save market
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Emit extension event
         EventEmitter::emit_market_deadline_extended(
@@ -4002,9 +6865,9 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
             return Err(Error::Unauthorized);
@@ -4016,11 +6879,7 @@ impl PredictifyHybrid {
         }
 
         // Get market
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Validate market state - cannot update resolved, closed, or cancelled markets
         if market.state != MarketState::Active {
@@ -4045,7 +6904,7 @@ impl PredictifyHybrid {
         market.question = new_description.clone();
 
         // Save market
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Emit description update event
         EventEmitter::emit_market_description_updated(
@@ -4139,9 +6998,9 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
             return Err(Error::Unauthorized);
@@ -4160,11 +7019,7 @@ impl PredictifyHybrid {
         }
 
         // Get market
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Validate market state - cannot update resolved, closed, or cancelled markets
         if market.state != MarketState::Active {
@@ -4189,7 +7044,7 @@ impl PredictifyHybrid {
         market.outcomes = new_outcomes.clone();
 
         // Save market
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Emit outcomes update event
         EventEmitter::emit_market_outcomes_updated(
@@ -4261,20 +7116,16 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
             return Err(Error::Unauthorized);
         }
 
         // Get market
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Validate market state - cannot update resolved, closed, or cancelled markets
         if market.state != MarketState::Active {
@@ -4299,7 +7150,7 @@ impl PredictifyHybrid {
         market.category = category.clone();
 
         // Save market
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Emit category update event
         EventEmitter::emit_category_updated(&env, &market_id, &old_category, &category, &admin);
@@ -4373,9 +7224,9 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| panic_with_error!(env, Error::Unauthorized));
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
             return Err(Error::Unauthorized);
@@ -4389,11 +7240,7 @@ impl PredictifyHybrid {
         }
 
         // Get market
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Validate market state - cannot update resolved, closed, or cancelled markets
         if market.state != MarketState::Active {
@@ -4418,7 +7265,7 @@ impl PredictifyHybrid {
         market.tags = tags.clone();
 
         // Save market
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
 
         // Emit tags update event
         EventEmitter::emit_tags_updated(&env, &market_id, &old_tags, &tags, &admin);
@@ -4426,6 +7273,96 @@ impl PredictifyHybrid {
         Ok(())
     }
 
+    /// Sets a market's display metadata: category, description and reference URL (admin only).
+    ///
+    /// Metadata is stored separately from the `Market` struct, so it can be set on
+    /// any existing market and markets without metadata are unaffected. Setting a
+    /// new category moves the market between `get_markets_by_category` indexes.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MetadataTooLong` - `description` exceeds `MAX_METADATA_DESCRIPTION_LENGTH`
+    ///   characters, or `reference_url` exceeds `MAX_REFERENCE_URL_LENGTH` characters
+    pub fn set_market_metadata(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        category: Symbol,
+        description: String,
+        reference_url: String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        markets::MarketStateManager::get_market(&env, &market_id)?;
+        validation::InputValidator::validate_metadata_description_length(&description)
+            .map_err(|_| Error::MetadataTooLong)?;
+        validation::InputValidator::validate_reference_url_length(&reference_url)
+            .map_err(|_| Error::MetadataTooLong)?;
+
+        let old_category = markets::MarketStateManager::get_market_metadata(&env, &market_id)
+            .map(|metadata| metadata.category);
+        let metadata = types::MarketMetadata {
+            category: category.clone(),
+            description,
+            reference_url,
+        };
+        markets::MarketStateManager::set_market_metadata(&env, &market_id, &metadata);
+
+        EventEmitter::emit_market_metadata_updated(
+            &env,
+            &market_id,
+            &old_category,
+            &category,
+            &admin,
+        );
+
+        Ok(())
+    }
+
+    /// Returns a market's display metadata, or `None` if none has been set.
+    pub fn get_market_metadata(env: Env, market_id: Symbol) -> Option<types::MarketMetadata> {
+        markets::MarketStateManager::get_market_metadata(&env, &market_id)
+    }
+
+    /// Lists market ids in creation order, optionally filtered by state.
+    ///
+    /// `start`/`limit` page over the market index (`limit` capped at
+    /// `MAX_QUERY_LIMIT`); advance `start` by `limit` for the next page. The filter
+    /// uses the effective state derived from the market and ledger time: `Active`
+    /// (open for bets), `BettingClosed` (past the betting deadline), `Ended` (past
+    /// `end_time`, unresolved), `Resolved`, `Disputed` (open result dispute),
+    /// `Closed`, `Cancelled` or `Voided`, so a filtered page may return fewer than
+    /// `limit` ids.
+    pub fn list_markets(
+        env: Env,
+        start: u32,
+        limit: u32,
+        state_filter: Option<MarketState>,
+    ) -> Vec<Symbol> {
+        markets::MarketStateManager::list_markets(&env, start, limit, state_filter)
+    }
+
+    /// Returns ids of markets in `category`, paginated (`limit` capped at `MAX_QUERY_LIMIT`).
+    pub fn get_markets_by_category(
+        env: Env,
+        category: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Symbol> {
+        markets::MarketStateManager::get_markets_by_category(&env, &category, start, limit)
+    }
+
     /// Query events by tags (paginated, bounded).
     ///
     /// Returns events that have ANY of the provided tags (OR logic).
@@ -4449,29 +7386,34 @@ impl PredictifyHybrid {
         event_archive::EventArchive::query_events_by_tags(&env, &tags, cursor, limit)
     }
 
-    /// Cancel an event and automatically refund all placed bets (admin only).
+    /// Cancel an event so its stakes can be refunded (admin only).
     ///
-    /// This function allows admins to cancel events before resolution and
-    /// automatically refund all bets placed on the market. It validates
-    /// cancellation conditions, updates market status, and processes refunds.
+    /// This function allows admins to cancel events before resolution. It
+    /// validates cancellation conditions and moves the market to `Cancelled`,
+    /// which stops new bets. Stakes are then returned in pages by
+    /// `process_refunds`, so large markets stay within resource limits.
     ///
     /// # Parameters
     ///
     /// * `env` - The Soroban environment for blockchain operations
     /// * `admin` - The administrator address (must be authorized)
     /// * `market_id` - Unique identifier of the market to cancel
+    /// * `code` - Why the market is cancelled; `Unresolvable` and `Malicious`
+    ///   slash the creator's creation bond to the fee vault, `Administrative`
+    ///   returns it
     /// * `reason` - Optional reason for cancellation
     ///
     /// # Returns
     ///
     /// Returns `Result<i128, Error>` where:
-    /// - `Ok(total_refunded)` - Total amount refunded to users
+    /// - `Ok(total_refundable)` - Total stake to be refunded by `process_refunds`
     /// - `Err(Error)` - Error if cancellation fails
     ///
     /// # Panics
     ///
     /// This function will panic with specific errors if:
-    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::Unauthorized` - Caller is not the contract admin, or several admin
+    ///   signers must approve it through `propose_admin_op`
     /// - `Error::MarketNotFound` - Market with given ID doesn't exist
     /// - `Error::MarketResolved` - Market has already been resolved
     /// - `Error::InvalidState` - Market is in an invalid state for cancellation
@@ -4489,31 +7431,34 @@ impl PredictifyHybrid {
     ///     env.clone(),
     ///     admin,
     ///     market_id,
+    ///     CancelReason::Administrative,
     ///     Some(String::from_str(&env, "Oracle data unavailable"))
     /// ) {
-    ///     Ok(total) => println!("Refunded {} stroops", total),
+    ///     Ok(total) => println!("{} stroops to refund", total),
     ///     Err(e) => println!("Cancellation failed: {:?}", e),
     /// }
     /// ```
     ///
     /// # Cancellation Conditions
     ///
-    /// - Market must exist and be active
-    /// - Market must not be resolved
-    /// - Market must not already be cancelled
+    /// - Market must exist and be active, or ended without a result (e.g. after an
+    ///   oracle failure)
+    /// - Market must not be resolved (`Error::MarketResolved`)
+    /// - Cancelling an already cancelled market returns 0; it only rules on a
+    ///   creation bond still held, e.g. after `refund_on_oracle_failure`
     /// - Only admin can cancel events
     ///
     /// # Refund Process
     ///
-    /// 1. All active bets are identified
-    /// 2. Funds are unlocked and returned to users
-    /// 3. Bet status is updated to "Refunded"
-    /// 4. Market state is updated to "Cancelled"
-    /// 5. Cancellation and refund events are emitted
+    /// 1. Market state is updated to "Cancelled" and cancellation events are emitted
+    /// 2. Anyone calls `process_refunds` page by page until it returns
+    ///    `PAYOUT_CURSOR_DONE`
+    /// 3. Each page returns stakes to their owners and marks bets "Refunded"
     pub fn cancel_event(
         env: Env,
         admin: Address,
         market_id: Symbol,
+        code: CancelReason,
         reason: Option<String>,
     ) -> Result<i128, Error> {
         admin.require_auth();
@@ -4521,158 +7466,342 @@ impl PredictifyHybrid {
         // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
 
         if admin != stored_admin {
             return Err(Error::Unauthorized);
         }
-
-        // Get and validate market
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::MarketNotFound);
-            });
-
-        // Validate cancellation conditions
-        if market.state == MarketState::Resolved {
-            return Err(Error::MarketResolved);
+        multisig::MultisigManager::require_single_signer(&env)?;
+
+        Self::cancel_event_internal(env, admin, market_id, code, reason)
+    }
+
+    /// Cancels `market_id`; callers are responsible for authorization.
+    fn cancel_event_internal(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        code: CancelReason,
+        reason: Option<String>,
+    ) -> Result<i128, Error> {
+        // Get and validate market
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        // Validate cancellation conditions: winners expect a payout once resolved
+        if matches!(market.state, MarketState::Resolved | MarketState::Closed) {
+            return Err(Error::MarketResolved);
+        }
+
+        // A bond still held after another cancellation path is ruled on here
+        let slash = code != CancelReason::Administrative;
+        if market.state.is_refunding() {
+            // Already cancelled or voided, return 0 refunded
+            fees::FeeManager::release_creation_bond(&env, &market_id, &market.creator, slash)?;
+            return Ok(0);
+        }
+
+        // Market must be open or ended (not disputed or resolved)
+        if !matches!(
+            market.state,
+            MarketState::Active | MarketState::BettingClosed | MarketState::Ended
+        ) {
+            return Err(Error::InvalidState);
+        }
+
+        // Capture old state for event
+        let old_state = market.state.clone();
+
+        // Update market state to cancelled
+        markets::MarketStateLogic::transition(&mut market, MarketEvent::Cancel)?;
+        markets::MarketStateManager::update_market(&env, &market_id, &market);
+        statistics::StatisticsManager::record_market_settled(&env, market.total_staked, true);
+
+        // Decrement active event count for the creator since the market is no longer active
+        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.creator);
+
+        // Stakes are returned by process_refunds
+        let total_refunded = market.total_staked;
+        fees::FeeManager::release_creation_bond(&env, &market_id, &market.creator, slash)?;
+
+        // Emit cancellation events
+        let reason = reason.unwrap_or_else(|| String::from_str(&env, "Event cancelled by admin"));
+        EventEmitter::emit_state_change_event(
+            &env,
+            &market_id,
+            &old_state,
+            &MarketState::Cancelled,
+            &reason,
+        );
+        EventEmitter::emit_market_cancelled(&env, &market_id, &admin, &reason, total_refunded);
+
+        // Emit market closed event
+        EventEmitter::emit_market_closed(&env, &market_id, &admin);
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("cancel"),
+            admin::AdminLogTarget::Market(market_id),
+        );
+
+        Ok(total_refunded)
+    }
+
+    /// Refund one page of positions on a cancelled or voided market.
+    ///
+    /// Callable by anyone. Processes up to `limit` positions (capped at
+    /// `MAX_PAYOUT_PAGE_SIZE`) starting at index `start` of the market's position
+    /// list, returning each stake to its owner. Positions already refunded, or
+    /// whose bet was settled another way (e.g. `cancel_bet`), are skipped, so pages
+    /// can be re-submitted safely. Positions of blocked addresses are skipped too
+    /// and can only be refunded through `release_blocked_funds`.
+    ///
+    /// # Returns
+    ///
+    /// `(refunded, next_cursor)`: the amount refunded by this page, and the index
+    /// to pass as `start` for the next page, or `PAYOUT_CURSOR_DONE` when finished.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::InvalidState` - Market is not cancelled
+    /// - `Error::InvalidInput` - `limit` is zero
+    pub fn process_refunds(
+        env: Env,
+        market_id: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Result<(i128, u32), Error> {
+        if limit == 0 {
+            return Err(Error::InvalidInput);
+        }
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        if !market.state.is_refunding() {
+            return Err(Error::InvalidState);
         }
 
-        if market.state == MarketState::Cancelled {
-            // Already cancelled, return 0 refunded
-            return Ok(0);
-        }
+        // Bets and votes both list their user in the market's bettor list
+        let len = bets::BetStorage::get_bettor_count(&env, &market_id);
+        let end = start.saturating_add(limit.min(MAX_PAYOUT_PAGE_SIZE)).min(len);
+        let next_cursor = if end >= len { PAYOUT_CURSOR_DONE } else { end };
 
-        // Market must be active or ended (not resolved)
-        if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
             return Err(Error::InvalidState);
         }
 
-        // Capture old state for event
-        let old_state = market.state.clone();
-
-        // Update market state to cancelled
-        market.state = MarketState::Cancelled;
-        env.storage().persistent().set(&market_id, &market);
-
-        // Decrement active event count for the creator since the market is no longer active
-        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
+        let mut total_refunded: i128 = 0;
+        let mut refunds: Vec<(Address, i128)> = Vec::new(&env);
+        for i in start..end {
+            let Some(user) = bets::BetStorage::get_bettor(&env, &market_id, i) else {
+                continue;
+            };
+            // Blocked positions stay unrefunded until `release_blocked_funds`
+            if bets::BetStorage::is_claimed(&env, &market_id, &user)
+                || admin::ComplianceManager::is_blocked(&env, &user)
+            {
+                continue;
+            }
+            let Some(mut bet) = bets::BetStorage::get_position(&env, &market_id, &user) else {
+                continue;
+            };
+            bets::BetStorage::mark_claimed(&env, &market_id, &user);
+            if !bet.is_active() {
+                continue;
+            }
 
-        // Refund all bets under reentrancy lock (batch of token transfers)
-        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            return Err(Error::InvalidState);
+            let stake = bet.amount;
+            escrow::EscrowManager::debit(&env, &market_id, stake)?;
+            let amount = credits::CreditManager::settle(&env, &market_id, &user, stake)?;
+            if amount > 0 {
+                refunds.push_back((user.clone(), amount));
+                total_refunded += amount;
+            }
+            bet.mark_as_refunded();
+            let _ = bets::BetStorage::store_bet(&env, &bet);
+            EventEmitter::emit_bet_status_updated(
+                &env,
+                &market_id,
+                &user,
+                &String::from_str(&env, "Active"),
+                &String::from_str(&env, "Refunded"),
+                Some(amount),
+            );
         }
+
+        // Transfer only after every refund on this page is recorded
         if ReentrancyGuard::before_external_call(&env).is_err() {
             return Err(Error::InvalidState);
         }
-        let refund_result = bets::BetManager::refund_market_bets(&env, &market_id);
+        for (user, amount) in refunds.iter() {
+            if let Err(e) = bets::BetUtils::unlock_funds(&env, &market, &user, amount) {
+                ReentrancyGuard::after_external_call(&env);
+                return Err(e);
+            }
+        }
         ReentrancyGuard::after_external_call(&env);
-        refund_result?;
 
-        // Calculate total refunded (sum of all bets)
-        let total_refunded = market.total_staked;
+        Ok((total_refunded, next_cursor))
+    }
 
-        // Emit cancellation event
-        EventEmitter::emit_state_change_event(
-            &env,
-            &market_id,
-            &old_state,
-            &MarketState::Cancelled,
-            &reason.unwrap_or_else(|| String::from_str(&env, "Event cancelled by admin")),
-        );
+    /// Freeze a single market while it is investigated (admin only).
+    ///
+    /// A frozen market rejects `place_bet`, `distribute_payouts` and claims with
+    /// `Error::MarketFrozen`. It can still be cancelled with `cancel_event` or resolved
+    /// with `resolve_market_manual`, so the admin can correct it before unfreezing.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::InvalidState` - Market is already frozen
+    pub fn freeze_market(env: Env, admin: Address, market_id: Symbol) -> Result<(), Error> {
+        Self::set_market_frozen(&env, &admin, &market_id, true)
+    }
 
-        // Emit market closed event
-        EventEmitter::emit_market_closed(&env, &market_id, &admin);
+    /// Lift a freeze set by `freeze_market` (admin only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::InvalidState` - Market is not frozen
+    pub fn unfreeze_market(env: Env, admin: Address, market_id: Symbol) -> Result<(), Error> {
+        Self::set_market_frozen(&env, &admin, &market_id, false)
+    }
 
-        Ok(total_refunded)
+    fn set_market_frozen(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        frozen: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if admin != &stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let mut market = markets::MarketStateManager::get_market(env, market_id)?;
+        if market.frozen == frozen {
+            return Err(Error::InvalidState);
+        }
+        market.frozen = frozen;
+        markets::MarketStateManager::update_market(env, market_id, &market);
+
+        EventEmitter::emit_market_frozen(env, market_id, admin, frozen);
+        Ok(())
     }
 
-    /// Cancel and refund an event that has ended but did not meet its minimum pool size.
+    /// Void an event that has ended but did not meet its minimum pool size.
     ///
     /// Callable by admin at any time after market ends, or by anyone once the
-    /// resolution timeout has passed. Returns total amount refunded.
+    /// resolution timeout has passed. The market moves to `Voided` and its stakes
+    /// are returned page by page through `process_refunds`. Returns the total
+    /// amount to be refunded.
     pub fn cancel_underfunded_event(
         env: Env,
         caller: Address,
         market_id: Symbol,
     ) -> Result<i128, Error> {
         caller.require_auth();
+        Self::void_underfunded_market(&env, &market_id, Some(&caller))
+    }
 
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+    /// Void a market that ended below its minimum pool size (callable by anyone).
+    ///
+    /// The sum of all outcome pools is compared against the market's
+    /// `min_pool_size`, or the global minimum when the market has none. An
+    /// underfunded market cannot be resolved; it is voided exactly like by
+    /// `cancel_underfunded_event`, without needing the admin, and every bet is
+    /// then refunded in full through `process_refunds`. Returns the total amount
+    /// to be refunded, or 0 if the market was already cancelled or voided.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::MarketClosed` - Market hasn't reached its end time yet
+    /// - `Error::InvalidState` - No minimum pool size applies, or the pools meet it
+    pub fn void_market(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        Self::void_underfunded_market(&env, &market_id, None)
+    }
+
+    /// Void an ended, underfunded market, leaving its refunds to `process_refunds`.
+    ///
+    /// With `caller` set (`cancel_underfunded_event`), only the admin may void
+    /// before the resolution timeout; `None` (`void_market`) lets anyone void.
+    fn void_underfunded_market(
+        env: &Env,
+        market_id: &Symbol,
+        caller: Option<&Address>,
+    ) -> Result<i128, Error> {
+        let mut market = markets::MarketStateManager::get_market(env, market_id)?;
 
-        if market.state == MarketState::Cancelled {
+        if market.state.is_refunding() {
             return Ok(0);
         }
-        if market.state == MarketState::Resolved {
+        if matches!(market.state, MarketState::Resolved | MarketState::Closed) {
             return Err(Error::MarketResolved);
         }
 
-        let current_time = env.ledger().timestamp();
-        if current_time < market.end_time {
+        if env.ledger().timestamp() < market.end_time {
             return Err(Error::MarketClosed);
         }
 
-        // Verify effective min pool is set and not met (per-market override, else global)
-        let global_min: i128 = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(&env, GLOBAL_MIN_POOL_SIZE_KEY))
-            .unwrap_or(0);
-        let min_pool = market.min_pool_size.unwrap_or(global_min);
-        if min_pool <= 0 || market.total_staked >= min_pool {
+        // Verify effective min pool is set and the outcome pools do not meet it
+        if !markets::MarketUtils::is_underfunded(env, &market) {
             return Err(Error::InvalidState);
         }
 
         // Admin can cancel immediately; others must wait for resolution timeout
-        let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(&env, "Admin"));
-        let is_admin = stored_admin.as_ref().map_or(false, |a| a == &caller);
-        let timeout_passed = current_time.saturating_sub(market.end_time)
-            >= config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS;
-        if !is_admin && !timeout_passed {
-            return Err(Error::Unauthorized);
+        if let Some(caller) = caller {
+            let stored_admin: Option<Address> =
+                env.storage().instance().get(&DataKey::Admin);
+            let is_admin = stored_admin.as_ref().is_some_and(|a| a == caller);
+            let timeout_passed = env.ledger().timestamp().saturating_sub(market.end_time)
+                >= config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS;
+            if !is_admin && !timeout_passed {
+                return Err(Error::Unauthorized);
+            }
         }
 
+        let min_pool = markets::MarketUtils::effective_min_pool_size(env, &market);
+
         // Emit pool size not met event
-        EventEmitter::emit_min_pool_size_not_met(&env, &market_id, market.total_staked, min_pool);
+        EventEmitter::emit_min_pool_size_not_met(env, market_id, market.total_staked, min_pool);
+        EventEmitter::emit_cancelled(
+            env,
+            market_id,
+            &String::from_str(env, "Minimum pool size not met"),
+        );
 
         let old_state = market.state.clone();
-        market.state = MarketState::Cancelled;
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateLogic::transition(&mut market, MarketEvent::Void)?;
+        markets::MarketStateManager::update_market(env, market_id, &market);
+        statistics::StatisticsManager::record_market_settled(env, market.total_staked, true);
 
-        // Refund all bets
-        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            return Err(Error::InvalidState);
-        }
-        if ReentrancyGuard::before_external_call(&env).is_err() {
-            return Err(Error::InvalidState);
-        }
-        let refund_result = bets::BetManager::refund_market_bets(&env, &market_id);
-        ReentrancyGuard::after_external_call(&env);
-        refund_result?;
+        // Stakes are returned by process_refunds
+        fees::FeeManager::release_creation_bond(env, market_id, &market.creator, false)?;
 
         let total_refunded = market.total_staked;
 
         EventEmitter::emit_state_change_event(
-            &env,
-            &market_id,
+            env,
+            market_id,
             &old_state,
-            &MarketState::Cancelled,
-            &String::from_str(&env, "Cancelled: minimum pool size not met"),
+            &MarketState::Voided,
+            &String::from_str(env, "Voided: minimum pool size not met"),
         );
 
-        EventEmitter::emit_market_closed(&env, &market_id, &caller);
+        let closed_by = caller
+            .cloned()
+            .unwrap_or_else(|| env.current_contract_address());
+        EventEmitter::emit_market_closed(env, market_id, &closed_by);
 
         Ok(total_refunded)
     }
@@ -4680,9 +7809,11 @@ impl PredictifyHybrid {
     /// Refund all bets when oracle resolution fails or times out (automatic refund path).
     ///
     /// Callable when: market has ended, no oracle result, and either (1) resolution
-    /// timeout has passed since market end, or (2) caller is admin (confirmed failure).
-    /// Refunds full bet amount per user (no fee deduction). Marks market as cancelled and
-    /// prevents further resolution. Emits refund events. Idempotent when already cancelled.
+    /// timeout has passed since market end, (2) `resolve_market` recorded that both the
+    /// primary and fallback oracles failed, or (3) caller is admin (confirmed failure).
+    /// Marks market as voided and prevents further resolution; the full bet amount
+    /// per user (no fee deduction) is then returned through `process_refunds`. Emits
+    /// refund events. Idempotent when already cancelled or voided.
     pub fn refund_on_oracle_failure(
         env: Env,
         caller: Address,
@@ -4690,13 +7821,9 @@ impl PredictifyHybrid {
     ) -> Result<i128, Error> {
         caller.require_auth();
 
-        let mut market: Market = env
-            .storage()
-            .persistent()
-            .get(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
-        if market.state == MarketState::Cancelled {
+        if market.state.is_refunding() {
             return Ok(0);
         }
         if market.winning_outcomes.is_some() {
@@ -4711,78 +7838,426 @@ impl PredictifyHybrid {
         }
 
         let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(&env, "Admin"));
-        let is_admin = stored_admin.as_ref().map_or(false, |a| a == &caller);
+            env.storage().instance().get(&DataKey::Admin);
+        let is_admin = stored_admin.as_ref().is_some_and(|a| a == &caller);
         let timeout_passed = current_time.saturating_sub(market.end_time)
             >= config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS;
-        if !is_admin && !timeout_passed {
+        let oracle_failed =
+            resolution::OracleResolutionManager::is_oracle_failed(&env, &market_id);
+        if !is_admin && !timeout_passed && !oracle_failed {
+            return Err(Error::Unauthorized);
+        }
+        // A running community vote decides between resolution and refund
+        if !is_admin
+            && resolution::CommunityResolutionManager::is_vote_open(&env, &market_id)
+        {
+            return Err(Error::InvalidState);
+        }
+
+        let total_refunded = Self::void_for_refunds(
+            &env,
+            &market_id,
+            &mut market,
+            "Refund on oracle failure/timeout",
+        )?;
+        EventEmitter::emit_refund_on_oracle_failure(&env, &market_id, total_refunded);
+
+        Ok(total_refunded)
+    }
+
+    /// Void a market nobody resolved in time so every bet is refunded (callable by anyone).
+    ///
+    /// Once `resolution_deadline` (`end_time + resolution_timeout +
+    /// dispute_window_seconds` plus a grace period) has passed without a
+    /// result, for instance because the oracle and the admin are both gone,
+    /// the market is voided and all stakes are returned in full through
+    /// `process_refunds`. Returns the total to be refunded, or 0 if the market
+    /// was already cancelled or voided.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketResolved` - Market has already been resolved
+    /// - `Error::MarketNotReady` - The resolution deadline has not passed
+    pub fn void_unresolved_market(env: Env, market_id: Symbol) -> Result<i128, Error> {
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+
+        if market.state.is_refunding() {
+            return Ok(0);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() < market.resolution_deadline {
+            return Err(Error::MarketNotReady);
+        }
+
+        let total_refunded = Self::void_for_refunds(
+            &env,
+            &market_id,
+            &mut market,
+            "Voided: resolution deadline passed",
+        )?;
+        EventEmitter::emit_cancelled(
+            &env,
+            &market_id,
+            &String::from_str(&env, "Resolution deadline passed"),
+        );
+
+        Ok(total_refunded)
+    }
+
+    /// Void a market so `process_refunds` returns every bet in full; returns the
+    /// total to be refunded.
+    fn void_for_refunds(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        reason: &str,
+    ) -> Result<i128, Error> {
+        let old_state = market.state.clone();
+        markets::MarketStateLogic::transition(market, MarketEvent::Void)?;
+        markets::MarketStateManager::update_market(env, market_id, market);
+        statistics::StatisticsManager::record_market_settled(env, market.total_staked, true);
+
+        // Decrement active event count for the creator since the market is no longer active
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.creator);
+
+        EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Voided,
+            &String::from_str(env, reason),
+        );
+
+        Ok(market.total_staked)
+    }
+
+    /// Opens a last-resort community vote on a market whose oracles all failed.
+    ///
+    /// Any bettor may call this once `resolve_market` has recorded the oracle
+    /// failure and `resolution_timeout` plus the configured grace period has
+    /// passed since `end_time`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller has no stake in the market
+    /// - `Error::MarketNotReady` - Oracle failure not recorded or grace period not over
+    /// - `Error::InvalidState` - A vote was already opened for this market
+    /// - `Error::MarketResolved` / `Error::MarketClosed` - Market already settled
+    pub fn open_community_vote(
+        env: Env,
+        caller: Address,
+        market_id: Symbol,
+    ) -> Result<CommunityVote, Error> {
+        resolution::CommunityResolutionManager::open_vote(&env, &caller, &market_id)
+    }
+
+    /// Votes for an outcome in a market's community vote, weighted by the
+    /// caller's stake when the vote opened (see `get_stake_snapshot`). Each
+    /// bettor may vote once.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller had no stake in the market's snapshot
+    /// - `Error::AlreadyVoted` - Caller already voted
+    /// - `Error::InvalidOutcome` - Outcome is not one of the market's outcomes
+    /// - `Error::MarketClosed` - Voting period is over
+    /// - `Error::InvalidState` - No vote is open for this market
+    pub fn cast_community_vote(
+        env: Env,
+        voter: Address,
+        market_id: Symbol,
+        outcome: String,
+    ) -> Result<(), Error> {
+        resolution::CommunityResolutionManager::cast_vote(&env, &voter, &market_id, &outcome)
+    }
+
+    /// Tallies a finished community vote. Anyone may call this.
+    ///
+    /// The plurality outcome resolves the market and is returned. On a tie,
+    /// or when less than the quorum share of stake voted, the market is
+    /// cancelled for `process_refunds` to refund every bettor, and `None` is
+    /// returned.
+    ///
+    /// For a `Hybrid` market the vote outcome is recorded in
+    /// `community_result` and only resolves the market when it matches the
+    /// oracle result. Otherwise the market is moved to `Disputed` and left
+    /// for the admin to settle with `resolve_market_manual`.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotReady` - Voting period has not ended
+    /// - `Error::InvalidState` - No vote open, or it was already tallied
+    pub fn finalize_community_vote(env: Env, market_id: Symbol) -> Result<Option<String>, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        let outcome = resolution::CommunityResolutionManager::tally(&env, &market_id)?;
+        let mut market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        market.community_result = outcome.clone();
+
+        if market.resolve_mode == ResolveMode::Hybrid && market.oracle_result.is_some() {
+            if outcome == market.oracle_result {
+                Self::resolve_by_community(&env, &market_id, &mut market, outcome.clone().unwrap())?;
+            } else {
+                resolution::CommunityResolutionManager::escalate_hybrid(
+                    &env,
+                    &market_id,
+                    &mut market,
+                )?;
+            }
+            return Ok(outcome);
+        }
+
+        match &outcome {
+            Some(winning_outcome) => {
+                Self::resolve_by_community(&env, &market_id, &mut market, winning_outcome.clone())?;
+            }
+            None => {
+                Self::void_for_refunds(
+                    &env,
+                    &market_id,
+                    &mut market,
+                    "Community vote void: tie or no quorum",
+                )?;
+            }
+        }
+
+        Ok(outcome)
+    }
+
+    /// Resolve a market to the outcome its community vote settled on.
+    fn resolve_by_community(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        winning_outcome: String,
+    ) -> Result<(), Error> {
+        let mut winning_outcomes = Vec::new(env);
+        winning_outcomes.push_back(winning_outcome);
+        markets::MarketStateManager::set_winning_outcomes(
+            market,
+            winning_outcomes.clone(),
+            Some(market_id),
+        )?;
+        markets::MarketStateManager::update_market(env, market_id, market);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.creator);
+
+        let _ = bets::BetManager::resolve_market_bets(env, market_id, &winning_outcomes);
+        markets::MarketUtils::publish_resolution(
+            env,
+            market_id,
+            market,
+            events::ResolutionSource::Community,
+        );
+
+        let now = env.ledger().timestamp();
+        if now >= market.payout_unlock_time() {
+            let _ = Self::distribute_payouts(
+                env.clone(),
+                market_id.clone(),
+                0,
+                MAX_PAYOUT_PAGE_SIZE,
+            );
+        }
+        Ok(())
+    }
+
+    /// Community vote for a market, if one has been opened.
+    pub fn get_community_vote(env: Env, market_id: Symbol) -> Option<CommunityVote> {
+        resolution::CommunityResolutionManager::get_vote(&env, &market_id)
+    }
+
+    /// Stake-weighted community vote tally for each of a market's outcomes, in
+    /// outcome order. Empty if no vote has been opened.
+    pub fn get_vote_tally(env: Env, market_id: Symbol) -> Vec<(String, i128)> {
+        resolution::CommunityResolutionManager::get_tally(&env, &market_id)
+    }
+
+    /// Bettors' stakes as frozen for a market's community vote, if taken yet.
+    pub fn get_stake_snapshot(env: Env, market_id: Symbol) -> Option<StakeSnapshot> {
+        resolution::CommunityResolutionManager::get_stake_snapshot(&env, &market_id)
+    }
+
+    /// Sets the community vote grace period, voting period (seconds) and
+    /// quorum (basis points of total stake). Admin only.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::InvalidInput` - Zero voting period, or quorum not in 1..=10000
+    pub fn set_community_vote_config(
+        env: Env,
+        admin: Address,
+        config: CommunityVoteConfig,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        resolution::CommunityResolutionManager::set_config(&env, &config)
+    }
+
+    /// Current community vote settings.
+    pub fn get_community_vote_config(env: Env) -> CommunityVoteConfig {
+        resolution::CommunityResolutionManager::get_config(&env)
+    }
+
+    /// Push an unresolved market's end time out to `new_end_time` (admin only).
+    ///
+    /// For postponed events. The end time can only move later, by at most the
+    /// market's `max_extension_days` in total; a bet deadline moves with it.
+    /// Bets placed before and after the extension all count. Emits `mkt_ext`
+    /// with the old and new end times.
+    pub fn extend_market(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        new_end_time: u64,
+        reason: String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        // Verify admin
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        extensions::ExtensionManager::extend_market_to(&env, admin, market_id, new_end_time, reason)
+    }
+
+    /// Close a market before its scheduled end because the outcome is already
+    /// known (admin only).
+    ///
+    /// New bets are rejected with `Error::MarketClosed` from this point and the
+    /// market can be resolved right away. The early-close time is kept in
+    /// `closed_early_at`, and payouts still wait out the dispute window.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the contract admin
+    /// - `Error::MarketNotFound` - Market with given ID doesn't exist
+    /// - `Error::MarketResolved` - Market already has a result
+    /// - `Error::MarketClosed` - Market is not active or has already ended
+    pub fn close_market_early(
+        env: Env,
+        admin: Address,
+        market_id: Symbol,
+        reason: String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
             return Err(Error::Unauthorized);
         }
 
-        let old_state = market.state.clone();
-        market.state = MarketState::Cancelled;
-        env.storage().persistent().set(&market_id, &market);
+        markets::MarketStateManager::close_market_early(&env, &admin, &market_id, &reason)
+    }
 
-        // Decrement active event count for the creator since the market is no longer active
-        crate::storage::CreatorLimitsManager::decrement_active_events(&env, &market.admin);
+    // ===== STORAGE OPTIMIZATION FUNCTIONS =====
 
-        if reentrancy_guard::ReentrancyGuard::check_reentrancy_state(&env).is_err() {
-            return Err(Error::InvalidState);
-        }
-        if reentrancy_guard::ReentrancyGuard::before_external_call(&env).is_err() {
-            return Err(Error::InvalidState);
+    /// One-time move of entries stored under the legacy `Symbol` keys to the
+    /// typed `DataKey` keys (admin, token, markets, bets, fee vault, config).
+    ///
+    /// Run once by the admin after upgrading an instance deployed before the
+    /// `DataKey` switch. The admin is checked against either key, so this works
+    /// before the admin entry itself has moved. Markets stored in the old
+    /// layout are converted to the current one. Returns the number of entries
+    /// moved and the registered markets whose entry could not be decoded.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidState` - Migration has already run
+    pub fn migrate_storage(
+        env: Env,
+        admin: Address,
+    ) -> Result<storage::StorageMigrationReport, Error> {
+        admin.require_auth();
+
+        let stored_admin =
+            storage::LegacyKeyMigrator::get_admin(&env).ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
         }
-        let refund_result = bets::BetManager::refund_market_bets(&env, &market_id);
-        reentrancy_guard::ReentrancyGuard::after_external_call(&env);
-        refund_result?;
 
-        let total_refunded = market.total_staked;
-        EventEmitter::emit_state_change_event(
-            &env,
-            &market_id,
-            &old_state,
-            &MarketState::Cancelled,
-            &String::from_str(&env, "Refund on oracle failure/timeout"),
-        );
-        EventEmitter::emit_refund_on_oracle_failure(&env, &market_id, total_refunded);
+        storage::LegacyKeyMigrator::migrate(&env)
+    }
 
-        Ok(total_refunded)
+    /// Returns true once `migrate_storage` has run.
+    pub fn is_storage_migrated(env: Env) -> bool {
+        storage::LegacyKeyMigrator::is_migrated(&env)
     }
 
-    /// Extend market duration (admin only)
-    pub fn extend_market(
+    /// Extend the TTL of a market's storage entry and of the contract instance.
+    ///
+    /// Markets are extended whenever they are read or written; anyone may call
+    /// this to keep a market that sees no activity from being archived before
+    /// it resolves and pays out.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market does not exist
+    pub fn extend_market_ttl(env: Env, market_id: Symbol) -> Result<(), Error> {
+        markets::MarketStateManager::get_market(&env, &market_id)?;
+        storage::TtlManager::extend_instance(&env);
+        Ok(())
+    }
+
+    /// Set how far market and bet entries are extended (admin only).
+    ///
+    /// Entries are extended to `extend_to` ledgers once their remaining TTL is
+    /// at or below `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidInput` - `extend_to` is zero or below `threshold`
+    pub fn set_ttl_config(
         env: Env,
         admin: Address,
-        market_id: Symbol,
-        additional_days: u32,
-        reason: String,
-        _fee_amount: i128,
+        threshold: u32,
+        extend_to: u32,
     ) -> Result<(), Error> {
         admin.require_auth();
 
-        // Verify admin
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
-            .unwrap_or_else(|| {
-                panic_with_error!(env, Error::Unauthorized);
-            });
-
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
         if admin != stored_admin {
-            panic_with_error!(env, Error::Unauthorized);
+            return Err(Error::Unauthorized);
         }
 
-        extensions::ExtensionManager::extend_market_duration(
+        storage::TtlManager::set_config(
             &env,
-            admin,
-            market_id,
-            additional_days,
-            reason,
+            &storage::TtlConfig {
+                threshold,
+                extend_to,
+            },
         )
     }
 
-    // ===== STORAGE OPTIMIZATION FUNCTIONS =====
+    /// Returns the TTL extension settings for market and bet entries.
+    pub fn get_ttl_config(env: Env) -> storage::TtlConfig {
+        storage::TtlManager::get_config(&env)
+    }
 
     /// Compress market data for storage optimization
     pub fn compress_market_data(
@@ -4965,15 +8440,10 @@ impl PredictifyHybrid {
 
     // ===== RECOVERY PUBLIC METHODS =====
     /// Initiates or performs recovery of a potentially corrupted market state. Only admin.
-    pub fn recover_market_state(env: Env, admin: Address, market_id: Symbol) -> bool {
+    pub fn recover_market_state(env: Env, admin: Address, market_id: Symbol) -> Result<bool, Error> {
         admin.require_auth();
-        if let Err(e) = crate::recovery::RecoveryManager::assert_is_admin(&env, &admin) {
-            panic_with_error!(env, e);
-        }
-        match crate::recovery::RecoveryManager::recover_market_state(&env, &market_id) {
-            Ok(res) => res,
-            Err(e) => panic_with_error!(env, e),
-        }
+        crate::recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+        crate::recovery::RecoveryManager::recover_market_state(&env, &market_id)
     }
 
     /// Executes partial refund mechanism for selected users in a failed/corrupted market. Only admin.
@@ -4982,15 +8452,10 @@ impl PredictifyHybrid {
         admin: Address,
         market_id: Symbol,
         users: Vec<Address>,
-    ) -> i128 {
+    ) -> Result<i128, Error> {
         admin.require_auth();
-        if let Err(e) = crate::recovery::RecoveryManager::assert_is_admin(&env, &admin) {
-            panic_with_error!(env, e);
-        }
-        match crate::recovery::RecoveryManager::partial_refund_mechanism(&env, &market_id, &users) {
-            Ok(total_refunded) => total_refunded,
-            Err(e) => panic_with_error!(env, e),
-        }
+        crate::recovery::RecoveryManager::assert_is_admin(&env, &admin)?;
+        crate::recovery::RecoveryManager::partial_refund_mechanism(&env, &market_id, &users)
     }
 
     /// Validates market state integrity; returns true if consistent.
@@ -5133,11 +8598,7 @@ impl PredictifyHybrid {
         backup_oracle: OracleProvider,
     ) -> Result<String, Error> {
         // Get market info
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
 
         // Check if market ended
         let current_time = env.ledger().timestamp();
@@ -5149,31 +8610,12 @@ impl PredictifyHybrid {
         let backup = OracleBackup::new(primary_oracle, backup_oracle);
         match backup.get_price(&env, &oracle_contract, &market.oracle_config.feed_id) {
             Ok(price) => {
-                // Simple comparison logic
-                let threshold = market.oracle_config.threshold;
-                let comparison = &market.oracle_config.comparison;
-
-                let result = if comparison == &String::from_str(&env, "gt") {
-                    if price > threshold {
-                        "yes"
-                    } else {
-                        "no"
-                    }
-                } else if comparison == &String::from_str(&env, "lt") {
-                    if price < threshold {
-                        "yes"
-                    } else {
-                        "no"
-                    }
-                } else {
-                    if price == threshold {
-                        "yes"
-                    } else {
-                        "no"
-                    }
-                };
-
-                Ok(String::from_str(&env, result))
+                oracles::OracleUtils::determine_outcome(
+                    price,
+                    market.oracle_config.threshold,
+                    &market.oracle_config.comparison,
+                    &env,
+                )
             }
             Err(_) => {
                 // Both oracles failed
@@ -5245,21 +8687,96 @@ impl PredictifyHybrid {
         AdminManager::get_admin_roles(&env)
     }
 
-    /// Transfer the primary contract admin to a new address. Caller must be the current primary admin.
+    /// Start handing the primary contract admin to a new address (current admin only).
+    ///
+    /// Kept for existing integrations; it is the same as `propose_admin`, so the new
+    /// address only takes over once it calls `accept_admin`.
     pub fn transfer_admin(
         env: Env,
         current_admin: Address,
         new_admin: Address,
     ) -> Result<(), Error> {
-        admin::ContractPauseManager::transfer_admin(&env, &current_admin, &new_admin)
+        admin::ContractPauseManager::propose_admin(&env, &current_admin, &new_admin)
+    }
+
+    /// Propose a new primary admin (current admin only). The proposed address must call
+    /// `accept_admin` before it takes over; until then the current admin keeps all
+    /// privileges. A newer proposal replaces a pending one.
+    pub fn propose_admin(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), Error> {
+        admin::ContractPauseManager::propose_admin(&env, &current_admin, &new_admin)
+    }
+
+    /// Accept a pending admin proposal. Caller must be the proposed address.
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        admin::ContractPauseManager::accept_admin(&env, &new_admin)
+    }
+
+    /// Withdraw the pending admin proposal (current admin only).
+    pub fn cancel_admin_proposal(env: Env, current_admin: Address) -> Result<(), Error> {
+        admin::ContractPauseManager::cancel_admin_proposal(&env, &current_admin)
+    }
+
+    /// Returns the address awaiting `accept_admin`, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        admin::ContractPauseManager::get_pending_admin(&env)
+    }
+
+    /// Grant an operational role (`Resolver`, `FeeManager`, `Pauser` or `Arbitrator`)
+    /// to an address.
+    /// Caller must be the primary admin, who implicitly holds every role.
+    pub fn grant_role(env: Env, admin: Address, role: admin::Role, addr: Address) -> Result<(), Error> {
+        admin::RoleManager::grant_role(&env, &admin, role, &addr)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("grant_rl"),
+            admin::AdminLogTarget::Account(addr),
+        );
+        Ok(())
+    }
+
+    /// Revoke an operational role from an address (primary admin only).
+    pub fn revoke_role(env: Env, admin: Address, role: admin::Role, addr: Address) -> Result<(), Error> {
+        admin::RoleManager::revoke_role(&env, &admin, role, &addr)?;
+        admin::AdminActionLogger::record(
+            &env,
+            &admin,
+            soroban_sdk::symbol_short!("revoke_rl"),
+            admin::AdminLogTarget::Account(addr),
+        );
+        Ok(())
+    }
+
+    /// Returns true if `addr` holds `role`, either by grant or as the primary admin.
+    pub fn has_role(env: Env, role: admin::Role, addr: Address) -> bool {
+        admin::RoleManager::has_role(&env, role, &addr)
+    }
+
+    /// Page through the on-chain admin log, oldest entry first.
+    ///
+    /// Token changes, fee changes, role grants and revocations, cancellations,
+    /// manual resolutions and upgrades are logged. Only the last
+    /// `ADMIN_LOG_CAPACITY` entries are retained; `start` counts from the oldest
+    /// retained entry and `limit` is capped at `ADMIN_LOG_CAPACITY`.
+    pub fn get_admin_log(env: Env, start: u32, limit: u32) -> Vec<admin::AdminLogEntry> {
+        admin::AdminActionLogger::get_log(&env, start, limit)
     }
 
-    /// Pause contract operations (admin only). Blocks all state-changing operations until unpause.
+    /// Total number of admin log entries ever written, including overwritten ones.
+    pub fn get_admin_log_count(env: Env) -> u64 {
+        admin::AdminActionLogger::get_log_count(&env)
+    }
+
+    /// Pause contract operations (admin or `Pauser` role). Blocks all state-changing operations until unpause.
     pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
         admin::ContractPauseManager::pause(&env, &admin)
     }
 
-    /// Unpause contract operations (admin only).
+    /// Unpause contract operations (admin or `Pauser` role).
     pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
         admin::ContractPauseManager::unpause(&env, &admin)
     }
@@ -5269,6 +8786,134 @@ impl PredictifyHybrid {
         admin::ContractPauseManager::is_contract_paused(&env)
     }
 
+    /// Block or unblock an address (primary admin only).
+    ///
+    /// A blocked address cannot bet, vote, cancel or withdraw a bet, cash out,
+    /// transfer or receive a position, claim, or withdraw balances, creator fees
+    /// or referral earnings; these fail with `Error::AddressBlocked`. Payout and
+    /// refund runs (`distribute_payouts`, `process_refunds` and the void paths)
+    /// skip it, and keeper rewards are not paid to it. Funds it already has
+    /// escrowed can only leave through `release_blocked_funds`.
+    pub fn set_blacklist(env: Env, admin: Address, addr: Address, blocked: bool) -> Result<(), Error> {
+        admin::ComplianceManager::set_blacklist(&env, &admin, &addr, blocked)
+    }
+
+    /// Returns true if `addr` is blocked.
+    pub fn is_blacklisted(env: Env, addr: Address) -> bool {
+        admin::ComplianceManager::is_blocked(&env, &addr)
+    }
+
+    /// Release the funds a blocked address holds in a market to `recipient`
+    /// (primary admin only).
+    ///
+    /// On a resolved market this settles `addr`'s claim, crediting its payout
+    /// (winnings or refund) to `recipient` as `claim_winnings` would have. Before
+    /// resolution the whole stake is refunded to `recipient` without the
+    /// early-exit penalty and the position is removed from the market.
+    ///
+    /// # Returns
+    ///
+    /// The amount released, which is zero for a losing position.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::AddressBlocked` - `recipient` is blocked
+    /// - `Error::InvalidState` - `addr` is not blocked
+    /// - Any error from `claim_winnings` (resolved markets) or `withdraw_bet`
+    ///   (unresolved markets), other than the betting deadline
+    pub fn release_blocked_funds(
+        env: Env,
+        admin: Address,
+        addr: Address,
+        market_id: Symbol,
+        recipient: Address,
+    ) -> Result<i128, Error> {
+        admin::ContractPauseManager::require_not_paused(&env)?;
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        if !admin::ComplianceManager::is_blocked(&env, &addr) {
+            return Err(Error::InvalidState);
+        }
+        admin::ComplianceManager::require_not_blocked(&env, &recipient)?;
+        if ReentrancyGuard::check_reentrancy_state(&env).is_err() {
+            return Err(Error::InvalidState);
+        }
+
+        let market = markets::MarketStateManager::get_market(&env, &market_id)?;
+        let released = if market.winning_outcomes.is_some() {
+            Self::claim_winnings_internal(&env, &addr, &market_id, &recipient)?
+        } else {
+            bets::BetManager::release_position(&env, &addr, &market_id, &recipient)?
+        };
+
+        EventEmitter::emit_blocked_funds_released(
+            &env,
+            &market_id,
+            &addr,
+            &recipient,
+            released,
+            &admin,
+        );
+        Ok(released)
+    }
+
+    /// Send `amount` of a token transferred to the contract by mistake to
+    /// `recipient` (primary admin only).
+    ///
+    /// The global staking token, every market's token and any token with a
+    /// fee vault balance are refused outright, since their balance backs
+    /// escrow, fee vaults and user balances.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not the admin
+    /// - `Error::CannotRecoverStakingToken` - `token` is a staking token
+    /// - `Error::InvalidInput` - `amount` is not positive
+    /// - `Error::InsufficientBalance` - The contract holds less than `amount` of `token`
+    pub fn recover_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        recipient: Address,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        if markets::MarketUtils::is_staking_token(&env, &token) {
+            return Err(Error::CannotRecoverStakingToken);
+        }
+        amounts::require_positive(amount)?;
+
+        let token_client = soroban_sdk::token::Client::new(&env, &token);
+        if token_client.balance(&env.current_contract_address()) < amount {
+            return Err(Error::InsufficientBalance);
+        }
+        ReentrancyGuard::guarded(&env, || {
+            token_client.transfer(&env.current_contract_address(), &recipient, &amount)
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        EventEmitter::emit_token_recovered(&env, &token, &recipient, amount, &admin);
+        Ok(())
+    }
+
     /// Get comprehensive admin analytics
     pub fn get_admin_analytics(env: Env) -> AdminAnalyticsResult {
         admin::EnhancedAdminAnalytics::get_admin_analytics(&env)
@@ -5292,50 +8937,241 @@ impl PredictifyHybrid {
 
     // ===== CONTRACT UPGRADE METHODS =====
 
-    /// Upgrade the contract to new Wasm bytecode
+    /// Schedule a sensitive admin operation (admin only).
     ///
-    /// This function allows authorized admins to upgrade the contract to a new
-    /// version by replacing the Wasm bytecode. It includes comprehensive validation,
-    /// version checking, and event logging.
+    /// Token switches, platform fee increases, upgrades and lowering the admin
+    /// action delay only take effect through this timelock: the action can be
+    /// executed with `execute_action` once `get_action_delay` seconds have
+    /// passed (for upgrades, the upgrade timelock if longer) and dropped with
+    /// `cancel_action` until then. Emergency pausing stays immediate.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// * `env` - The Soroban environment
-    /// * `admin` - The admin performing the upgrade (must be authorized)
-    /// * `new_wasm_hash` - Hash of the new Wasm bytecode to deploy
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidInput` - Token does not implement the token interface, or
+    ///   delay above `MAX_ADMIN_ACTION_DELAY_SECONDS`
+    /// * `Error::InvalidFeeConfig` - Fee exceeds `MAX_PLATFORM_FEE_BPS`
+    /// * `Error::InvalidState` - `MAX_PENDING_ACTIONS` actions are already pending
+    pub fn schedule_action(
+        env: Env,
+        admin: Address,
+        action: TimelockedAction,
+    ) -> Result<PendingAction, Error> {
+        timelock::TimelockManager::schedule(&env, &admin, action)
+    }
+
+    /// Apply a scheduled action whose delay has passed. Callable by anyone.
     ///
-    /// # Returns
+    /// The action is checked again as it is applied, e.g. a token switch is
+    /// refused while markets hold open pools; it then stays pending.
     ///
-    /// * `Ok(())` if upgrade succeeds
-    /// * `Err(Error)` if authorization fails or upgrade is incompatible
+    /// # Errors
     ///
-    /// # Security
+    /// * `Error::InvalidInput` - No pending action has this id
+    /// * `Error::MarketNotReady` - The delay has not passed yet
+    /// * Any error of the applied operation, e.g. `Error::BetsAlreadyPlaced`
+    pub fn execute_action(env: Env, action_id: u64) -> Result<(), Error> {
+        let pending = timelock::TimelockManager::take_due(&env, action_id)?;
+        let admin = pending.scheduled_by.clone();
+        let logged = match pending.action.clone() {
+            TimelockedAction::SetToken(token) => {
+                Self::apply_token(&env, &admin, &token)?;
+                soroban_sdk::symbol_short!("set_token")
+            }
+            TimelockedAction::SetFeeBps(bps) => {
+                fees::FeeManager::apply_fee_bps(&env, &admin, bps);
+                soroban_sdk::symbol_short!("fee_bps")
+            }
+            TimelockedAction::Upgrade(new_wasm_hash) => {
+                upgrade_manager::UpgradeManager::execute_scheduled_upgrade(
+                    &env,
+                    &admin,
+                    new_wasm_hash,
+                )?;
+                soroban_sdk::symbol_short!("upgrade")
+            }
+            TimelockedAction::SetActionDelay(seconds) => {
+                timelock::TimelockManager::store_delay(&env, seconds);
+                soroban_sdk::symbol_short!("act_delay")
+            }
+            TimelockedAction::SetMarketFeeBps(market_id, bps) => {
+                fees::FeeManager::apply_market_fee_bps(&env, &admin, &market_id, bps)?;
+                soroban_sdk::symbol_short!("mkt_fee")
+            }
+        };
+
+        EventEmitter::emit_action_executed(&env, &admin, action_id, &pending.action);
+        admin::AdminActionLogger::record(&env, &admin, logged, admin::AdminLogTarget::Contract);
+        Ok(())
+    }
+
+    /// Drop a scheduled action before it is executed (admin only).
     ///
-    /// - Requires admin authentication via `require_auth()`
-    /// - Validates version compatibility
-    /// - Performs safety checks before upgrade
-    /// - Logs all upgrade attempts for audit trail
+    /// # Errors
     ///
-    /// # Example
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidInput` - No pending action has this id
+    pub fn cancel_action(env: Env, admin: Address, action_id: u64) -> Result<(), Error> {
+        timelock::TimelockManager::cancel(&env, &admin, action_id)
+    }
+
+    /// Scheduled actions not yet executed or cancelled, oldest first.
+    pub fn get_pending_actions(env: Env) -> Vec<PendingAction> {
+        timelock::TimelockManager::get_pending(&env)
+    }
+
+    /// Raise the delay in seconds between `schedule_action` and `execute_action`
+    /// (admin only), up to `MAX_ADMIN_ACTION_DELAY_SECONDS`. A lower delay must
+    /// itself be scheduled as `TimelockedAction::SetActionDelay`.
+    pub fn set_action_delay(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        timelock::TimelockManager::set_delay(&env, &admin, seconds)
+    }
+
+    /// Returns the admin action delay in seconds (24 hours by default).
+    pub fn get_action_delay(env: Env) -> u64 {
+        timelock::TimelockManager::get_delay(&env)
+    }
+
+    /// Propose an admin operation (admin signers only) and return its id.
     ///
-    /// ```rust
-    /// # use soroban_sdk::{Env, Address, BytesN};
-    /// # let env = Env::default();
-    /// # let admin = Address::generate(&env);
-    /// # let new_wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
+    /// The proposal counts as the proposer's approval; once approvals from
+    /// `get_admin_signers` reach the threshold the operation runs, so with the
+    /// default single-admin set (one signer, threshold one) it runs at once.
+    /// While more than one approval is required, manual resolution,
+    /// cancellation, fee withdrawal and upgrade scheduling are refused on their
+    /// direct entry points and only run through here. Changing the signer set is
+    /// itself a `MultisigOp::SetSigners` proposal.
     ///
-    /// // Perform upgrade with admin authorization
-    /// admin.require_auth();
-    /// PredictifyHybrid::upgrade_contract(env, admin, new_wasm_hash)?;
-    /// # Ok::<(), predictify_hybrid::errors::Error>(())
-    /// ```
-    pub fn upgrade_contract(
-        env: Env,
-        admin: Address,
-        new_wasm_hash: soroban_sdk::BytesN<32>,
-    ) -> Result<(), Error> {
-        admin.require_auth();
-        upgrade_manager::UpgradeManager::upgrade_contract(&env, &admin, new_wasm_hash)
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not a signer
+    /// * `Error::InvalidInput` - Invalid signer set or threshold, no outcomes, or a
+    ///   negative withdrawal
+    /// * Any error of the operation when it runs, e.g. `Error::MarketClosed`
+    pub fn propose_admin_op(env: Env, signer: Address, op: MultisigOp) -> Result<u64, Error> {
+        let proposal = multisig::MultisigManager::propose(&env, &signer, op)?;
+        Self::execute_if_approved(&env, &signer, &proposal)?;
+        Ok(proposal.id)
+    }
+
+    /// Approve a proposed admin operation (admin signers only); returns true
+    /// when this approval reached the threshold and the operation ran.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not a signer
+    /// * `Error::InvalidInput` - No pending proposal has this id
+    /// * `Error::AlreadyVoted` - Caller already approved it
+    /// * Any error of the operation when it runs; the approval is then not recorded
+    pub fn approve_admin_op(env: Env, signer: Address, op_id: u64) -> Result<bool, Error> {
+        let proposal = multisig::MultisigManager::approve(&env, &signer, op_id)?;
+        Self::execute_if_approved(&env, &signer, &proposal)
+    }
+
+    /// Pending admin operation `op_id`; `None` once it ran or if it never existed.
+    pub fn get_admin_op(env: Env, op_id: u64) -> Option<MultisigProposal> {
+        multisig::MultisigManager::get_proposal(&env, op_id)
+    }
+
+    /// Signers that approve admin operations and the approval threshold.
+    pub fn get_admin_signers(env: Env) -> AdminSigners {
+        multisig::MultisigManager::get_signers(&env)
+    }
+
+    /// Runs `proposal` once its approvals reach the threshold; `signer` gave the
+    /// last approval and acts as the admin of the operation.
+    fn execute_if_approved(
+        env: &Env,
+        signer: &Address,
+        proposal: &MultisigProposal,
+    ) -> Result<bool, Error> {
+        if !multisig::MultisigManager::is_approved(env, proposal) {
+            return Ok(false);
+        }
+        multisig::MultisigManager::remove_proposal(env, proposal.id);
+
+        match proposal.op.clone() {
+            MultisigOp::ResolveMarket(market_id, outcomes) => {
+                admin::ContractPauseManager::require_not_paused(env)?;
+                if outcomes.len() == 1 {
+                    let outcome = outcomes.get(0).ok_or(Error::InvalidInput)?;
+                    Self::resolve_market_manual_internal(
+                        env.clone(),
+                        signer.clone(),
+                        market_id,
+                        outcome,
+                    )?;
+                } else {
+                    Self::resolve_market_with_ties_internal(
+                        env.clone(),
+                        signer.clone(),
+                        market_id,
+                        outcomes,
+                    )?;
+                }
+            }
+            MultisigOp::CancelMarket(market_id, code) => {
+                Self::cancel_event_internal(env.clone(), signer.clone(), market_id, code, None)?;
+            }
+            MultisigOp::WithdrawFees(amount) => {
+                admin::ContractPauseManager::require_not_paused(env)?;
+                if ReentrancyGuard::check_reentrancy_state(env).is_err() {
+                    return Err(Error::InvalidState);
+                }
+                let recipient = Self::fee_recipient(env)?;
+                fees::FeeWithdrawalManager::withdraw_fees(env, signer, &recipient, amount)?;
+            }
+            MultisigOp::Upgrade(new_wasm_hash) => {
+                timelock::TimelockManager::enqueue(
+                    env,
+                    signer,
+                    TimelockedAction::Upgrade(new_wasm_hash),
+                )?;
+            }
+            MultisigOp::SetSigners(signers, threshold) => {
+                multisig::MultisigManager::validate_signers(&signers, threshold)?;
+                multisig::MultisigManager::store_signers(env, signers, threshold);
+                admin::AdminActionLogger::record(
+                    env,
+                    signer,
+                    soroban_sdk::symbol_short!("signers"),
+                    admin::AdminLogTarget::Contract,
+                );
+            }
+        }
+
+        EventEmitter::emit_multisig_op_executed(env, proposal.id, signer, &proposal.op);
+        Ok(true)
+    }
+
+    /// Set the minimum delay in seconds between scheduling and executing an
+    /// upgrade (admin only); upgrades wait out the longer of it and the admin
+    /// action delay. It can only be raised, up to `MAX_UPGRADE_TIMELOCK_SECONDS`.
+    pub fn set_upgrade_timelock(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        upgrade_manager::UpgradeManager::set_upgrade_timelock(&env, &admin, seconds)
+    }
+
+    /// Returns the upgrade timelock in seconds (`0` when disabled).
+    pub fn get_upgrade_timelock(env: Env) -> u64 {
+        upgrade_manager::UpgradeManager::get_upgrade_timelock(&env)
+    }
+
+    /// Release number of the running code, bumped with every release.
+    pub fn get_version(_env: Env) -> u32 {
+        config::CONTRACT_VERSION
+    }
+
+    /// Contract release and event schema versions, for indexers.
+    pub fn get_version_info(_env: Env) -> VersionInfo {
+        VersionInfo {
+            contract_version: config::CONTRACT_VERSION,
+            event_schema_version: events::EVENT_SCHEMA_VERSION,
+        }
+    }
+
+    /// Topic layout and data struct of every published event.
+    pub fn get_events_abi(env: Env) -> Vec<events::EventAbi> {
+        events::EventDocumentation::get_events_abi(&env)
     }
 
     /// Rollback contract to previous version
@@ -6071,11 +9907,55 @@ impl PredictifyHybrid {
         statistics::StatisticsManager::get_platform_stats(&env)
     }
 
+    /// Get protocol-wide volume, open interest, market and fee counters
+    pub fn get_protocol_stats(env: Env) -> ProtocolStats {
+        statistics::StatisticsManager::get_protocol_stats(&env)
+    }
+
     /// Get user-specific statistics
     pub fn get_user_statistics(env: Env, user: Address) -> UserStatistics {
         statistics::StatisticsManager::get_user_stats(&env, &user)
     }
 
+    /// Get a user's lifetime statistics and the total they can currently claim.
+    ///
+    /// `total_claimable` sums `get_user_position` claimable amounts over the
+    /// user's market index (the `USER_MARKET_INDEX_LIMIT` most recent markets),
+    /// so positions in older markets are not included.
+    pub fn get_user_stats(env: Env, user: Address) -> UserStats {
+        let stats = statistics::StatisticsManager::get_user_stats(&env, &user);
+        let mut total_claimable: i128 = 0;
+        for market_id in statistics::StatisticsManager::get_user_markets(&env, &user).iter() {
+            let claimable = Self::get_user_position(env.clone(), user.clone(), market_id)
+                .map_or(0, |position| position.claimable);
+            total_claimable = total_claimable.checked_add(claimable).unwrap_or(total_claimable);
+        }
+
+        UserStats {
+            lifetime_staked: stats.total_amount_wagered,
+            lifetime_winnings: stats.total_winnings,
+            markets_participated: statistics::StatisticsManager::get_markets_participated(
+                &env, &user,
+            ),
+            win_count: stats.total_bets_won,
+            total_claimable,
+        }
+    }
+
+    /// Get a page of the markets a user has taken a position in, most recent
+    /// last. Only the `USER_MARKET_INDEX_LIMIT` most recent markets are kept.
+    pub fn get_user_markets(env: Env, user: Address, offset: u32, limit: u32) -> Vec<Symbol> {
+        let markets = statistics::StatisticsManager::get_user_markets(&env, &user);
+        let mut page = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(markets.len());
+        for i in offset..end {
+            if let Some(market_id) = markets.get(i) {
+                page.push_back(market_id);
+            }
+        }
+        page
+    }
+
     pub fn sweep_unclaimed(env: Env, admin: Address, market_id: Symbol) -> i128 {
         admin.require_auth();
         
@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+//! Private market tests.
+//!
+//! A market switched to `MarketAccess::Allowlist` only takes bets from the
+//! addresses its creator or the admin put on its allowlist.
+
+use crate::config::MAX_MARKET_ALLOWLIST_SIZE;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::MarketAccess;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String, Symbol, Vec};
+
+const STAKE: i128 = 10_000_000;
+
+fn create_private_market(test: &PredictifyTest, bettors: &Vec<Address>) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    client.set_market_access(&test.admin, &market_id, &MarketAccess::Allowlist);
+    client.update_market_allowlist(&test.admin, &market_id, bettors, &Vec::new(&test.env));
+    market_id
+}
+
+#[test]
+fn test_listed_user_bets_on_private_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user = test.create_funded_user();
+    let market_id = create_private_market(&test, &vec![&test.env, user.clone()]);
+
+    assert_eq!(
+        client.get_market_access(&market_id),
+        MarketAccess::Allowlist
+    );
+    assert_eq!(
+        client.get_market_allowlist(&market_id),
+        vec![&test.env, user.clone()]
+    );
+
+    let bet = client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    assert_eq!(bet.amount, STAKE);
+}
+
+#[test]
+fn test_unlisted_user_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let listed = test.create_funded_user();
+    let market_id = create_private_market(&test, &vec![&test.env, listed]);
+
+    let outsider = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    assert_eq!(
+        client.try_place_bet(&outsider, &market_id, &yes, &STAKE, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_place_bets(
+            &outsider,
+            &vec![&test.env, (market_id.clone(), yes.clone(), STAKE)]
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_vote(&outsider, &market_id, &yes, &STAKE),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_late_addition_can_bet() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_private_market(&test, &Vec::new(&test.env));
+    let user = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    assert!(client
+        .try_place_bet(&user, &market_id, &yes, &STAKE, &None)
+        .is_err());
+
+    client.update_market_allowlist(
+        &test.admin,
+        &market_id,
+        &vec![&test.env, user.clone()],
+        &Vec::new(&test.env),
+    );
+    client.place_bet(&user, &market_id, &yes, &STAKE, &None);
+
+    // Removal blocks new bets but keeps the position
+    client.update_market_allowlist(
+        &test.admin,
+        &market_id,
+        &Vec::new(&test.env),
+        &vec![&test.env, user.clone()],
+    );
+    assert_eq!(
+        client.try_place_bet(&user, &market_id, &yes, &STAKE, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_bet(&market_id, &user).unwrap().amount, STAKE);
+}
+
+#[test]
+fn test_public_market_unaffected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+
+    assert_eq!(client.get_market_access(&market_id), MarketAccess::Public);
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+
+    // The allowlist only exists on private markets
+    assert_eq!(
+        client.try_update_market_allowlist(
+            &test.admin,
+            &market_id,
+            &vec![&test.env, user],
+            &Vec::new(&test.env),
+        ),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_allowlist_managed_by_creator_or_admin_before_end() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_private_market(&test, &Vec::new(&test.env));
+    let stranger = Address::generate(&test.env);
+
+    assert_eq!(
+        client.try_update_market_allowlist(
+            &stranger,
+            &market_id,
+            &vec![&test.env, stranger.clone()],
+            &Vec::new(&test.env),
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time);
+    assert_eq!(
+        client.try_update_market_allowlist(
+            &test.admin,
+            &market_id,
+            &vec![&test.env, stranger],
+            &Vec::new(&test.env),
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+}
+
+#[test]
+fn test_allowlist_size_capped() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let mut bettors = Vec::new(&test.env);
+    for _ in 0..MAX_MARKET_ALLOWLIST_SIZE {
+        bettors.push_back(Address::generate(&test.env));
+    }
+    let market_id = create_private_market(&test, &bettors);
+
+    assert_eq!(
+        client.try_update_market_allowlist(
+            &test.admin,
+            &market_id,
+            &vec![&test.env, Address::generate(&test.env)],
+            &Vec::new(&test.env),
+        ),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+
+    // Going public drops the list
+    client.set_market_access(&test.admin, &market_id, &MarketAccess::Public);
+    assert!(client.get_market_allowlist(&market_id).is_empty());
+}
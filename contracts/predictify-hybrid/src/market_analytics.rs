@@ -1,6 +1,9 @@
 #![allow(dead_code)]
 
+use crate::bets::BetStorage;
 use crate::errors::Error;
+use crate::markets::MarketStateManager;
+use crate::storage::DataKey;
 use crate::types::*;
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
@@ -147,37 +150,15 @@ pub struct MarketAnalyticsManager;
 impl MarketAnalyticsManager {
     /// Get comprehensive market statistics for a specific market
     pub fn get_market_statistics(env: &Env, market_id: Symbol) -> Result<MarketStatistics, Error> {
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = MarketStateManager::get_market(env, &market_id)?;
 
-        let total_participants = market.votes.len() as u32;
+        let total_participants = market.position_count();
         let total_stake = market.total_staked;
-        let total_votes = market.votes.len() as u32;
+        let total_votes = market.position_count();
 
-        // Calculate outcome distribution
-        let mut outcome_distribution = Map::new(env);
-        let mut stake_distribution = Map::new(env);
-        let mut total_stake_by_outcome = Map::new(env);
-
-        for (user, outcome) in market.votes.iter() {
-            let stake = market.stakes.get(user.clone()).unwrap_or(0);
-
-            // Count votes per outcome
-            let vote_count = outcome_distribution.get(outcome.clone()).unwrap_or(0);
-            outcome_distribution.set(outcome.clone(), vote_count + 1);
-
-            // Sum stakes per outcome
-            let outcome_stake = total_stake_by_outcome.get(outcome.clone()).unwrap_or(0);
-            total_stake_by_outcome.set(outcome.clone(), outcome_stake + stake);
-        }
-
-        // Set stake distribution
-        for (outcome, stake) in total_stake_by_outcome.iter() {
-            stake_distribution.set(outcome, stake);
-        }
+        // Outcome distribution from the market's per-outcome totals
+        let outcome_distribution = market.outcome_positions.clone();
+        let stake_distribution = market.outcome_pools.clone();
 
         let average_stake = if total_participants > 0 {
             total_stake / total_participants as i128
@@ -191,7 +172,7 @@ impl MarketAnalyticsManager {
             0
         };
 
-        let market_volatility = Self::calculate_market_volatility(&market);
+        let market_volatility = Self::calculate_market_volatility(env, &market_id, &market);
         let consensus_strength = Self::calculate_consensus_strength(&market);
         let time_to_resolution = Self::calculate_time_to_resolution(&market);
         let resolution_method = Self::get_resolution_method(&market);
@@ -214,30 +195,22 @@ impl MarketAnalyticsManager {
 
     /// Get voting analytics and participation metrics for a market
     pub fn get_voting_analytics(env: &Env, market_id: Symbol) -> Result<VotingAnalytics, Error> {
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = MarketStateManager::get_market(env, &market_id)?;
 
-        let total_votes = market.votes.len() as u32;
-        let unique_voters = market.votes.len() as u32;
+        let total_votes = market.position_count();
+        let unique_voters = market.position_count();
 
         // Create voting timeline (simplified - in real implementation would track timestamps)
         let mut voting_timeline = Map::new(env);
         voting_timeline.set(0, total_votes); // Placeholder
 
         // Calculate outcome preferences
-        let mut outcome_preferences = Map::new(env);
-        for (_, outcome) in market.votes.iter() {
-            let count = outcome_preferences.get(outcome.clone()).unwrap_or(0);
-            outcome_preferences.set(outcome.clone(), count + 1);
-        }
+        let outcome_preferences = market.outcome_positions.clone();
 
         // Calculate stake concentration
         let mut stake_concentration = Map::new(env);
-        for (user, stake) in market.stakes.iter() {
-            stake_concentration.set(user, stake);
+        for bet in Self::positions(env, &market_id).iter() {
+            stake_concentration.set(bet.user, bet.amount);
         }
 
         // Create voting patterns (simplified)
@@ -333,11 +306,7 @@ impl MarketAnalyticsManager {
 
     /// Get dispute analytics for a specific market
     pub fn get_dispute_analytics(env: &Env, market_id: Symbol) -> Result<DisputeAnalytics, Error> {
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = MarketStateManager::get_market(env, &market_id)?;
 
         let total_disputes = market.dispute_stakes.len() as u32;
         let resolved_disputes = if market.state == MarketState::Resolved {
@@ -382,13 +351,9 @@ impl MarketAnalyticsManager {
         env: &Env,
         market_id: Symbol,
     ) -> Result<ParticipationMetrics, Error> {
-        let market = env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .ok_or(Error::MarketNotFound)?;
+        let market = MarketStateManager::get_market(env, &market_id)?;
 
-        let total_participants = market.votes.len() as u32;
+        let total_participants = market.position_count();
         let active_participants = total_participants; // All voters are considered active
         let new_participants = total_participants; // Simplified - would track new vs returning
         let returning_participants = 0; // Simplified
@@ -437,8 +402,11 @@ impl MarketAnalyticsManager {
         let mut market_categories = Map::new(env);
 
         for (_i, market_id) in markets.iter().enumerate() {
-            if let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) {
-                let participants = market.votes.len() as u32;
+            if let Some(market) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Market>(&DataKey::Market(market_id.clone())) {
+                let participants = market.position_count();
                 let stake = market.total_staked;
 
                 total_participation += participants;
@@ -505,51 +473,53 @@ impl MarketAnalyticsManager {
 
     // ===== HELPER FUNCTIONS =====
 
-    /// Calculate market volatility based on stake distribution
-    fn calculate_market_volatility(market: &Market) -> u32 {
-        if market.votes.len() == 0 {
-            return 0;
+    /// Open positions of a market, in bettor list order
+    fn positions(env: &Env, market_id: &Symbol) -> Vec<Bet> {
+        let mut positions = Vec::new(env);
+        for user in BetStorage::get_all_bets_for_market(env, market_id).iter() {
+            if let Some(bet) = BetStorage::get_position(env, market_id, &user) {
+                positions.push_back(bet);
+            }
         }
+        positions
+    }
 
-        let mut stake_values = Vec::new(&market.votes.env());
-        for (_, stake) in market.stakes.iter() {
-            stake_values.push_back(stake);
+    /// Calculate market volatility based on stake distribution
+    fn calculate_market_volatility(env: &Env, market_id: &Symbol, market: &Market) -> u32 {
+        let participants = market.position_count();
+        if participants == 0 {
+            return 0;
         }
 
         // Simplified volatility calculation
         let total_stake = market.total_staked;
-        let average_stake = total_stake / market.votes.len() as i128;
+        let average_stake = total_stake / participants as i128;
 
         let mut variance = 0;
-        for stake in stake_values.iter() {
-            let diff = stake - average_stake;
+        for bet in Self::positions(env, market_id).iter() {
+            let diff = bet.amount - average_stake;
             variance += diff * diff;
         }
 
-        let volatility = (variance / market.votes.len() as i128) / 1000; // Scale down
+        let volatility = (variance / participants as i128) / 1000; // Scale down
         volatility as u32
     }
 
     /// Calculate consensus strength based on vote distribution
     fn calculate_consensus_strength(market: &Market) -> u32 {
-        if market.votes.len() == 0 {
+        let participants = market.position_count();
+        if participants == 0 {
             return 0;
         }
 
-        let mut outcome_counts = Map::new(&market.votes.env());
-        for (_, outcome) in market.votes.iter() {
-            let count = outcome_counts.get(outcome.clone()).unwrap_or(0);
-            outcome_counts.set(outcome.clone(), count + 1);
-        }
-
         let mut max_votes = 0;
-        for (_, count) in outcome_counts.iter() {
+        for (_, count) in market.outcome_positions.iter() {
             if count > max_votes {
                 max_votes = count;
             }
         }
 
-        (max_votes * 100) / market.votes.len() as u32
+        (max_votes * 100) / participants
     }
 
     /// Calculate time to resolution for a market
@@ -566,18 +536,18 @@ impl MarketAnalyticsManager {
         match market.state {
             MarketState::Resolved => {
                 if market.oracle_result.is_some() {
-                    String::from_str(&market.votes.env(), "oracle")
+                    String::from_str(&market.outcomes.env(), "oracle")
                 } else {
-                    String::from_str(&market.votes.env(), "manual")
+                    String::from_str(&market.outcomes.env(), "manual")
                 }
             }
-            _ => String::from_str(&market.votes.env(), "pending"),
+            _ => String::from_str(&market.outcomes.env(), "pending"),
         }
     }
 
     /// Calculate engagement score for a market
     fn calculate_engagement_score(market: &Market) -> u32 {
-        let participation = market.votes.len() as u32;
+        let participation = market.position_count();
         let stake_ratio = if market.total_staked > 0 {
             (market.total_staked / 1000000) as u32 // Scale down
         } else {
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+//! Market extension tests.
+//!
+//! `extend_market` moves an unresolved market's end time later for postponed
+//! events, carrying the bet deadline along with it.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::{OracleConfig, OracleProvider, OracleSigner};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const DAY: u64 = 24 * 60 * 60;
+const STAKE: i128 = 10_000_000;
+
+fn create_market(test: &PredictifyTest, bet_deadline_mins: Option<u32>) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will the match finish with a home win?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &7,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2_500_000,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &3600,
+        &None,
+        &bet_deadline_mins,
+        &None,
+    )
+}
+
+fn reason(test: &PredictifyTest) -> String {
+    String::from_str(&test.env, "Match postponed by weather")
+}
+
+#[test]
+fn test_extend_market_moves_end_time_and_bet_deadline() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_market(&test, Some(60));
+    let before = client.get_market(&market_id).unwrap();
+    assert_eq!(before.bet_deadline, before.end_time - 3600);
+
+    let early = test.create_funded_user();
+    client.place_bet(
+        &early,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+
+    let new_end_time = before.end_time + 3 * DAY;
+    client.extend_market(&test.admin, &market_id, &new_end_time, &reason(&test));
+
+    let after = client.get_market(&market_id).unwrap();
+    assert_eq!(after.end_time, new_end_time);
+    assert_eq!(after.bet_deadline, new_end_time - 3600);
+    assert_eq!(after.total_extension_days, 3);
+    assert_eq!(after.extension_history.len(), 1);
+
+    // Past the original end, bets are still accepted and both count
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = before.end_time + DAY);
+    let late = test.create_funded_user();
+    client.place_bet(
+        &late,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        2 * STAKE
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = new_end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert!(client.get_user_position(&early, &market_id).is_some());
+    assert!(client.get_user_position(&late, &market_id).is_some());
+}
+
+#[test]
+fn test_extend_market_rejects_shortening_and_overlong_extension() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_market(&test, None);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+
+    assert_eq!(
+        client.try_extend_market(&test.admin, &market_id, &(end_time - DAY), &reason(&test)),
+        Err(Ok(Error::InvalidExtensionDays))
+    );
+    assert_eq!(
+        client.try_extend_market(&test.admin, &market_id, &end_time, &reason(&test)),
+        Err(Ok(Error::InvalidExtensionDays))
+    );
+    assert_eq!(
+        client.try_extend_market(
+            &test.admin,
+            &market_id,
+            &(end_time + 31 * DAY),
+            &reason(&test)
+        ),
+        Err(Ok(Error::ExtensionDenied))
+    );
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_extend_market(&outsider, &market_id, &(end_time + DAY), &reason(&test)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_market(&market_id).unwrap().end_time, end_time);
+}
+
+#[test]
+fn test_extend_market_after_resolution_fails() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = create_market(&test, None);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    assert_eq!(
+        client.try_extend_market(
+            &test.admin,
+            &market_id,
+            &(end_time + 2 * DAY),
+            &reason(&test)
+        ),
+        Err(Ok(Error::MarketResolved))
+    );
+}
@@ -1,12 +1,14 @@
 use crate::errors::Error;
-use crate::types::Market;
+use crate::storage::DataKey;
 use alloc::format;
 /// Market ID Generator Module
 ///
-/// Provides collision-resistant market ID generation using per-admin counters.
-///
-/// Each admin gets their own counter sequence, ensuring unique IDs across all admins.
-use soroban_sdk::{contracttype, panic_with_error, Address, Bytes, Env, Symbol, Vec};
+/// Provides collision-resistant market ID generation from a contract-wide
+/// sequence. Every generated market also gets a sequential `u64` number that
+/// can be used in place of its `Symbol` id.
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, Address, Bytes, Env, Symbol, Vec,
+};
 
 /// Market ID components
 #[contracttype]
@@ -44,26 +46,30 @@ impl MarketIdGenerator {
     const MAX_RETRIES: u32 = 10;
 
     /// Generate a unique market ID for an admin
+    ///
+    /// The ID is derived from the next value of the contract-wide sequence,
+    /// which also becomes the market's number (see [`Self::get_market_number`]).
     pub fn generate_market_id(env: &Env, admin: &Address) -> Symbol {
         let timestamp = env.ledger().timestamp();
-        let counter = Self::get_admin_counter(env, admin);
-
-        if counter > Self::MAX_COUNTER {
-            panic_with_error!(env, Error::InvalidInput);
-        }
+        let sequence = Self::get_sequence(env);
 
-        // Generate ID with collision detection
-        for attempt in 0..Self::MAX_RETRIES {
-            let current_counter = counter + attempt;
-            if current_counter > Self::MAX_COUNTER {
+        // Generate ID with collision detection against legacy IDs
+        for attempt in 0..Self::MAX_RETRIES as u64 {
+            let number = sequence + attempt;
+            if number > Self::MAX_COUNTER as u64 {
                 panic_with_error!(env, Error::InvalidInput);
             }
 
-            let market_id = Self::build_market_id(env, admin, current_counter);
+            let market_id = Self::build_market_id(env, admin, number as u32);
 
             if !Self::check_market_id_collision(env, &market_id) {
-                Self::set_admin_counter(env, admin, current_counter + 1);
+                env.storage()
+                    .persistent()
+                    .set(&symbol_short!("mkt_seq"), &(number + 1));
+                let admin_counter = Self::get_admin_counter(env, admin);
+                Self::set_admin_counter(env, admin, admin_counter + 1);
                 Self::register_market_id(env, &market_id, admin, timestamp);
+                Self::register_market_number(env, &market_id, number);
                 return market_id;
             }
         }
@@ -71,6 +77,43 @@ impl MarketIdGenerator {
         panic_with_error!(env, Error::InvalidState);
     }
 
+    /// Next value of the contract-wide market sequence
+    fn get_sequence(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("mkt_seq"))
+            .unwrap_or(0)
+    }
+
+    /// Link a market's number and its `Symbol` ID in both directions
+    fn register_market_number(env: &Env, market_id: &Symbol, number: u64) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_num"), number), market_id);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_sym"), market_id.clone()), &number);
+    }
+
+    /// `Symbol` ID of the market with the given number
+    pub fn get_market_symbol(env: &Env, number: u64) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_num"), number))
+    }
+
+    /// Number of a market, or `None` for markets created before numbering
+    pub fn get_market_number(env: &Env, market_id: &Symbol) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_sym"), market_id.clone()))
+    }
+
+    /// `Symbol` ID of a numbered market, for the `*_by_id` entry points
+    pub fn resolve_market_number(env: &Env, number: u64) -> Result<Symbol, Error> {
+        Self::get_market_symbol(env, number).ok_or(Error::MarketNotFound)
+    }
+
     /// Build market ID from admin and counter
     fn build_market_id(env: &Env, _admin: &Address, counter: u32) -> Symbol {
         // Simple approach: hash counter with admin's Val
@@ -123,12 +166,12 @@ impl MarketIdGenerator {
         true // Simplified
     }
 
-    /// Check if market ID exists
+    /// Check if market ID exists or was already handed out
     pub fn check_market_id_collision(env: &Env, market_id: &Symbol) -> bool {
         env.storage()
             .persistent()
-            .get::<Symbol, Market>(market_id)
-            .is_some()
+            .has(&DataKey::Market(market_id.clone()))
+            || Self::get_market_number(env, market_id).is_some()
     }
 
     /// Parse market ID into components
@@ -158,7 +201,7 @@ impl MarketIdGenerator {
             .unwrap_or(Vec::new(env));
 
         let mut result = Vec::new(env);
-        let end = core::cmp::min(start + limit, registry.len());
+        let end = core::cmp::min(start.saturating_add(limit), registry.len());
 
         for i in start..end {
             if let Some(entry) = registry.get(i) {
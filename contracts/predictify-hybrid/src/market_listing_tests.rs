@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+//! Market listing tests.
+//!
+//! `list_markets` pages over the market id index in creation order and filters on
+//! the state derived from each market and the ledger time.
+
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, MarketState, OracleConfig, OracleProvider, OracleSigner};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+fn create_market(test: &PredictifyTest, duration_days: u32) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &duration_days,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 2500000,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &86400,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+#[test]
+fn test_list_markets_filters_each_state() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+
+    let ended = create_market(&test, 1);
+    let resolved = create_market(&test, 1);
+    let cancelled = create_market(&test, 30);
+    let disputed = create_market(&test, 1);
+    let active = create_market(&test, 30);
+
+    let end_time = client.get_market(&ended).unwrap().end_time;
+    test.set_time(end_time + 1);
+
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &resolved, &yes);
+    client.resolve_market_manual(&test.admin, &disputed, &yes);
+    client.dispute_result(
+        &test.create_funded_user(),
+        &disputed,
+        &String::from_str(&test.env, "no"),
+        &client.get_min_dispute_stake(),
+    );
+    client.cancel_event(&test.admin, &cancelled, &CancelReason::Administrative, &None);
+
+    assert_eq!(
+        client.list_markets(&0, &10, &None),
+        vec![
+            &test.env,
+            ended.clone(),
+            resolved.clone(),
+            cancelled.clone(),
+            disputed.clone(),
+            active.clone()
+        ]
+    );
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Active)),
+        vec![&test.env, active]
+    );
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Ended)),
+        vec![&test.env, ended]
+    );
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Resolved)),
+        vec![&test.env, resolved.clone()]
+    );
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Disputed)),
+        vec![&test.env, disputed]
+    );
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Cancelled)),
+        vec![&test.env, cancelled.clone()]
+    );
+    assert!(client
+        .list_markets(&0, &10, &Some(MarketState::Closed))
+        .is_empty());
+
+    // Pages follow creation order
+    assert_eq!(
+        client.list_markets(&1, &2, &None),
+        vec![&test.env, resolved, cancelled]
+    );
+    assert!(client.list_markets(&5, &10, &None).is_empty());
+}
+
+#[test]
+fn test_list_markets_tracks_state_changes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert!(client.list_markets(&0, &10, &None).is_empty());
+
+    let market_id = create_market(&test, 30);
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Active)),
+        vec![&test.env, market_id.clone()]
+    );
+
+    // Cancelling keeps the market indexed under its new state
+    test.env.mock_all_auths();
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+    assert!(client
+        .list_markets(&0, &10, &Some(MarketState::Active))
+        .is_empty());
+    assert_eq!(
+        client.list_markets(&0, &10, &Some(MarketState::Cancelled)),
+        vec![&test.env, market_id.clone()]
+    );
+    assert_eq!(
+        client.list_markets(&0, &10, &None),
+        vec![&test.env, market_id]
+    );
+}
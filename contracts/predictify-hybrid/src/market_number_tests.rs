@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+//! Market number tests.
+//!
+//! Every market gets a sequential `u64` number from a contract-wide sequence
+//! alongside its `Symbol` ID. Markets created before numbering keep working
+//! through their `Symbol` ID.
+
+use crate::errors::Error;
+use crate::market_id_generator::MarketIdGenerator;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Map, String, Symbol};
+
+const STAKE: i128 = 10_000_000;
+
+#[test]
+fn test_market_ids_unique_across_1000_creations() {
+    let test = PredictifyTest::setup();
+    test.env.cost_estimate().budget().reset_unlimited();
+    let admins = [Address::generate(&test.env), Address::generate(&test.env)];
+
+    test.env.as_contract(&test.contract_id, || {
+        let mut seen: Map<Symbol, u64> = Map::new(&test.env);
+        for i in 0..1000u64 {
+            let admin = &admins[(i % 2) as usize];
+            let market_id = MarketIdGenerator::generate_market_id(&test.env, admin);
+            assert!(!seen.contains_key(market_id.clone()));
+            assert_eq!(
+                MarketIdGenerator::get_market_number(&test.env, &market_id),
+                Some(i)
+            );
+            assert_eq!(
+                MarketIdGenerator::get_market_symbol(&test.env, i),
+                Some(market_id.clone())
+            );
+            seen.set(market_id, i);
+        }
+        assert_eq!(seen.len(), 1000);
+    });
+}
+
+#[test]
+fn test_market_by_id_round_trip() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let id = client.get_market_number(&market_id).unwrap();
+
+    assert_eq!(client.get_market_symbol(&id), Some(market_id.clone()));
+    assert_eq!(client.get_market_by_id(&id), client.get_market(&market_id));
+    assert_eq!(client.get_market_by_id(&(id + 1)), None);
+
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    client.place_bet_by_id(&winner, &id, &String::from_str(&test.env, "yes"), &STAKE, &None);
+    client.place_bet_by_id(&loser, &id, &String::from_str(&test.env, "no"), &STAKE, &None);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        2 * STAKE
+    );
+    assert_eq!(
+        client.try_place_bet_by_id(
+            &winner,
+            &(id + 1),
+            &String::from_str(&test.env, "yes"),
+            &STAKE,
+            &None
+        ),
+        Err(Ok(Error::MarketNotFound))
+    );
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+    assert!(client.claim_winnings_by_id(&winner, &id) > STAKE);
+}
+
+#[test]
+fn test_legacy_symbol_ids_still_resolve() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let legacy_id = test.create_test_market();
+
+    // Forget the numbering, as for a market stored before it existed
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        storage.remove(&(symbol_short!("mkt_num"), 0u64));
+        storage.remove(&(symbol_short!("mkt_sym"), legacy_id.clone()));
+        storage.set(&symbol_short!("mkt_seq"), &0u64);
+    });
+
+    assert!(client.get_market(&legacy_id).is_some());
+    assert_eq!(client.get_market_number(&legacy_id), None);
+
+    // The sequence skips the number whose ID is already taken by the legacy market
+    let market_id = test.create_test_market();
+    assert_ne!(market_id, legacy_id);
+    assert_eq!(client.get_market_number(&market_id), Some(1));
+    assert_eq!(client.get_market_by_id(&0), None);
+    assert!(client.get_market(&legacy_id).is_some());
+}
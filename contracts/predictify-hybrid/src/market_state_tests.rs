@@ -0,0 +1,424 @@
+#![cfg(test)]
+
+//! Market state machine tests.
+//!
+//! Every stored state change goes through `MarketStateLogic::transition`; these
+//! tests pin its table for every state and event, and check that the entry
+//! points reject the moves it forbids.
+
+use crate::errors::Error;
+use crate::markets::MarketStateLogic;
+use crate::resolution::MarketResolutionManager;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, MarketEvent, MarketState};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{String, Symbol};
+
+const STAKE: i128 = 100_0000000;
+
+const STATES: [MarketState; 8] = [
+    MarketState::Active,
+    MarketState::BettingClosed,
+    MarketState::Ended,
+    MarketState::Disputed,
+    MarketState::Resolved,
+    MarketState::Closed,
+    MarketState::Cancelled,
+    MarketState::Voided,
+];
+
+/// Every allowed move as (from, event, to); anything else is rejected
+const TABLE: [(MarketState, MarketEvent, MarketState); 19] = [
+    (
+        MarketState::Active,
+        MarketEvent::CloseBetting,
+        MarketState::BettingClosed,
+    ),
+    (MarketState::Active, MarketEvent::End, MarketState::Ended),
+    (
+        MarketState::BettingClosed,
+        MarketEvent::End,
+        MarketState::Ended,
+    ),
+    (
+        MarketState::Active,
+        MarketEvent::Dispute,
+        MarketState::Disputed,
+    ),
+    (
+        MarketState::BettingClosed,
+        MarketEvent::Dispute,
+        MarketState::Disputed,
+    ),
+    (
+        MarketState::Ended,
+        MarketEvent::Dispute,
+        MarketState::Disputed,
+    ),
+    (
+        MarketState::Ended,
+        MarketEvent::Resolve,
+        MarketState::Resolved,
+    ),
+    (
+        MarketState::Disputed,
+        MarketEvent::Resolve,
+        MarketState::Resolved,
+    ),
+    (
+        MarketState::Resolved,
+        MarketEvent::Dispute,
+        MarketState::Disputed,
+    ),
+    (
+        MarketState::Disputed,
+        MarketEvent::Overturn,
+        MarketState::Resolved,
+    ),
+    (
+        MarketState::Resolved,
+        MarketEvent::Settle,
+        MarketState::Closed,
+    ),
+    (
+        MarketState::Active,
+        MarketEvent::Cancel,
+        MarketState::Cancelled,
+    ),
+    (
+        MarketState::BettingClosed,
+        MarketEvent::Cancel,
+        MarketState::Cancelled,
+    ),
+    (
+        MarketState::Ended,
+        MarketEvent::Cancel,
+        MarketState::Cancelled,
+    ),
+    (
+        MarketState::Disputed,
+        MarketEvent::Cancel,
+        MarketState::Cancelled,
+    ),
+    (MarketState::Active, MarketEvent::Void, MarketState::Voided),
+    (
+        MarketState::BettingClosed,
+        MarketEvent::Void,
+        MarketState::Voided,
+    ),
+    (MarketState::Ended, MarketEvent::Void, MarketState::Voided),
+    (
+        MarketState::Disputed,
+        MarketEvent::Void,
+        MarketState::Voided,
+    ),
+];
+
+fn expected(from: MarketState, event: MarketEvent) -> Result<MarketState, Error> {
+    TABLE
+        .iter()
+        .find(|(f, e, _)| *f == from && *e == event)
+        .map(|(_, _, to)| *to)
+        .ok_or(Error::InvalidState)
+}
+
+/// Market with a 100 XLM "yes" bet
+fn market_with_bet(test: &PredictifyTest) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    market_id
+}
+
+fn past_end(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+}
+
+// ===== TRANSITION TABLE =====
+
+#[test]
+fn test_next_state_matches_table_for_every_state_and_event() {
+    for from in STATES {
+        for event in MarketEvent::ALL {
+            assert_eq!(
+                MarketStateLogic::next_state(from, event),
+                expected(from, event),
+                "{:?} on {:?}",
+                event,
+                from
+            );
+        }
+    }
+}
+
+#[test]
+fn test_no_event_leaves_terminal_states() {
+    for from in STATES.into_iter().filter(|s| s.is_terminal()) {
+        for event in MarketEvent::ALL {
+            let next = MarketStateLogic::next_state(from, event);
+            assert!(next == Err(Error::InvalidState) || next == Ok(from));
+        }
+    }
+    assert!(STATES
+        .into_iter()
+        .filter(|s| !s.is_terminal())
+        .all(|from| MarketEvent::ALL.into_iter().any(|event| matches!(
+            MarketStateLogic::next_state(from, event),
+            Ok(to) if to != from
+        ))));
+}
+
+#[test]
+fn test_validate_state_transition_follows_table() {
+    for from in STATES {
+        for to in STATES {
+            let reachable = TABLE.iter().any(|(f, _, t)| *f == from && *t == to);
+            assert_eq!(
+                MarketStateLogic::validate_state_transition(from, to).is_ok(),
+                reachable,
+                "{:?} -> {:?}",
+                from,
+                to
+            );
+        }
+    }
+}
+
+#[test]
+fn test_illegal_transition_leaves_market_unchanged() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let mut market = client.get_market(&test.create_test_market()).unwrap();
+
+    assert_eq!(
+        MarketStateLogic::transition(&mut market, MarketEvent::Cancel),
+        Ok(MarketState::Cancelled)
+    );
+    assert_eq!(
+        MarketStateLogic::transition(&mut market, MarketEvent::Resolve),
+        Err(Error::InvalidState)
+    );
+    assert_eq!(market.state, MarketState::Cancelled);
+}
+
+// ===== ENTRY POINTS =====
+
+#[test]
+fn test_resolve_after_cancel_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+
+    client.cancel_event(
+        &test.admin,
+        &market_id,
+        &CancelReason::Administrative,
+        &None,
+    );
+    past_end(&test, &market_id);
+
+    assert_eq!(
+        client.try_resolve_market_manual(
+            &test.admin,
+            &market_id,
+            &String::from_str(&test.env, "yes")
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Cancelled);
+    assert_eq!(market.winning_outcomes, None);
+}
+
+#[test]
+fn test_double_resolution_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    past_end(&test, &market_id);
+
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+
+    assert_eq!(
+        client.try_resolve_market_manual(
+            &test.admin,
+            &market_id,
+            &String::from_str(&test.env, "no")
+        ),
+        Err(Ok(Error::InvalidState))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.winning_outcomes.unwrap().get(0).unwrap(),
+        String::from_str(&test.env, "yes")
+    );
+}
+
+#[test]
+fn test_unresolved_market_is_voided_and_refunded() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let deadline = client.get_market(&market_id).unwrap().resolution_deadline;
+    test.env.ledger().with_mut(|li| li.timestamp = deadline);
+
+    assert_eq!(client.void_unresolved_market(&market_id), STAKE);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Voided
+    );
+
+    // Voiding again and resolving are both no longer possible
+    assert_eq!(client.void_unresolved_market(&market_id), 0);
+    assert_eq!(
+        client.try_resolve_market_manual(
+            &test.admin,
+            &market_id,
+            &String::from_str(&test.env, "yes")
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    let (refunded, _) = client.process_refunds(&market_id, &0, &10);
+    assert_eq!(refunded, STAKE);
+}
+
+#[test]
+fn test_finalize_market_ends_stale_market_before_resolving() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let yes = String::from_str(&test.env, "yes");
+
+    // Before the end time the market is still open and cannot be resolved
+    let early = test.env.as_contract(&test.contract_id, || {
+        MarketResolutionManager::finalize_market(&test.env, &test.admin, &market_id, &yes)
+    });
+    assert_eq!(early.err(), Some(Error::MarketClosed));
+
+    // Past the end time the stored state still reads Active until finalized
+    past_end(&test, &market_id);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Active
+    );
+    test.env.as_contract(&test.contract_id, || {
+        MarketResolutionManager::finalize_market(&test.env, &test.admin, &market_id, &yes).unwrap();
+    });
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes.unwrap().get(0).unwrap(), yes);
+}
+
+#[test]
+fn test_upheld_dispute_overturns_resolved_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let no = String::from_str(&test.env, "no");
+    past_end(&test, &market_id);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    let disputer = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.dispute_result(&disputer, &market_id, &no, &10_000_000);
+    client.resolve_dispute(&test.admin, &market_id, &no, &true);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.winning_outcomes.unwrap().get(0).unwrap(), no);
+}
+
+#[test]
+fn test_early_close_ends_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+
+    client.close_market_early(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "Outcome known"),
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Ended
+    );
+
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+}
+
+#[test]
+fn test_advance_by_time_stores_time_driven_states() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let mut market = client.get_market(&test.create_test_market()).unwrap();
+    market.bet_deadline = market.end_time - 3600;
+
+    assert_eq!(
+        MarketStateLogic::advance_by_time(&test.env, &mut market),
+        Ok(MarketState::Active)
+    );
+
+    let deadline = market.bet_deadline;
+    test.env.ledger().with_mut(|li| li.timestamp = deadline);
+    assert_eq!(
+        MarketStateLogic::advance_by_time(&test.env, &mut market),
+        Ok(MarketState::BettingClosed)
+    );
+
+    let end_time = market.end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time);
+    assert_eq!(
+        MarketStateLogic::advance_by_time(&test.env, &mut market),
+        Ok(MarketState::Ended)
+    );
+}
+
+#[test]
+fn test_view_reports_betting_closed_between_deadline_and_end() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let mut market = client.get_market(&market_id).unwrap();
+    market.bet_deadline = market.end_time - 3600;
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .set(&DataKey::Market(market_id.clone()), &market);
+    });
+
+    let deadline = market.bet_deadline;
+    test.env.ledger().with_mut(|li| li.timestamp = deadline);
+    assert_eq!(
+        client.get_market_view(&market_id).state,
+        MarketState::BettingClosed
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Active
+    );
+
+    past_end(&test, &market_id);
+    assert_eq!(client.get_market_view(&market_id).state, MarketState::Ended);
+}
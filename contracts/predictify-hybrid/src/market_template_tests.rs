@@ -0,0 +1,255 @@
+#![cfg(test)]
+
+//! Market template tests.
+//!
+//! The admin stores a recurring market's settings once as a template, and
+//! markets created from it fill in only their threshold, duration and question.
+//! A series rolls a template into a new market each time the previous one ends.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::{
+    MarketTemplate, OracleConfig, OracleProvider, OracleSigner, SeriesPosition, TemplateOverrides,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String};
+
+fn weekly_btc_template(test: &PredictifyTest) -> MarketTemplate {
+    MarketTemplate {
+        question: String::from_str(&test.env, "Will BTC close above the strike this week?"),
+        outcomes: vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        duration_days: 7,
+        oracle_config: OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "BTC"),
+            threshold: 0,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        has_fallback: false,
+        fallback_oracle_config: OracleConfig::none_sentinel(&test.env),
+        resolution_timeout: 3600,
+        min_pool_size: None,
+        bet_deadline_mins_before_end: None,
+        dispute_window_seconds: None,
+        creator_fee_bps: 100,
+    }
+}
+
+fn overrides(threshold: Option<i128>, duration_days: Option<u32>) -> TemplateOverrides {
+    TemplateOverrides {
+        question: None,
+        threshold,
+        duration_days,
+    }
+}
+
+#[test]
+fn test_markets_from_one_template_with_different_thresholds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let template = weekly_btc_template(&test);
+    let template_id = client.create_template(&test.admin, &template);
+
+    let first = client.create_market_from_template(
+        &test.admin,
+        &template_id,
+        &overrides(Some(6_000_000), None),
+    );
+    let second = client.create_market_from_template(
+        &test.admin,
+        &template_id,
+        &overrides(Some(6_500_000), Some(14)),
+    );
+    assert_ne!(first, second);
+
+    let first = client.get_market(&first).unwrap();
+    let second = client.get_market(&second).unwrap();
+    assert_eq!(first.oracle_config.threshold, 6_000_000);
+    assert_eq!(second.oracle_config.threshold, 6_500_000);
+    assert_eq!(first.outcomes, template.outcomes);
+    assert_eq!(first.oracle_config.feed_id, template.oracle_config.feed_id);
+    assert_eq!(first.question, template.question);
+    assert_eq!(first.creator_fee_bps, 100);
+
+    let now = test.env.ledger().timestamp();
+    assert_eq!(first.end_time, now + 7 * 24 * 60 * 60);
+    assert_eq!(second.end_time, now + 14 * 24 * 60 * 60);
+}
+
+#[test]
+fn test_template_question_override() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let template_id = client.create_template(&test.admin, &weekly_btc_template(&test));
+
+    let question = String::from_str(&test.env, "Will BTC close above $60,000 this week?");
+    let market_id = client.create_market_from_template(
+        &test.admin,
+        &template_id,
+        &TemplateOverrides {
+            question: Some(question.clone()),
+            threshold: Some(6_000_000),
+            duration_days: None,
+        },
+    );
+    assert_eq!(client.get_market(&market_id).unwrap().question, question);
+}
+
+#[test]
+fn test_templates_are_listable_and_admin_only() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outsider = Address::generate(&test.env);
+    let template = weekly_btc_template(&test);
+
+    assert_eq!(
+        client.try_create_template(&outsider, &template),
+        Err(Ok(Error::Unauthorized))
+    );
+    let first = client.create_template(&test.admin, &template);
+    let second = client.create_template(&test.admin, &template);
+    assert_eq!((first, second), (1, 2));
+    assert_eq!(client.get_templates().len(), 2);
+
+    let mut updated = template.clone();
+    updated.duration_days = 30;
+    assert_eq!(
+        client.try_update_template(&outsider, &first, &updated),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_update_template(&test.admin, &99, &updated),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.update_template(&test.admin, &first, &updated);
+    assert_eq!(client.get_template(&first).unwrap().duration_days, 30);
+    assert_eq!(client.get_templates().get(1).unwrap(), (second, template));
+}
+
+#[test]
+fn test_template_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let mut template = weekly_btc_template(&test);
+    template.creator_fee_bps = 10_000;
+    assert_eq!(
+        client.try_create_template(&test.admin, &template),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+
+    let mut template = weekly_btc_template(&test);
+    template.oracle_config.comparison = String::from_str(&test.env, "between");
+    assert_eq!(
+        client.try_create_template(&test.admin, &template),
+        Err(Ok(Error::InvalidComparison))
+    );
+
+    assert_eq!(
+        client.try_create_market_from_template(&test.admin, &7, &overrides(None, None)),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn test_create_market_from_template_requires_allowed_creator() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let template_id = client.create_template(&test.admin, &weekly_btc_template(&test));
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_create_market_from_template(&outsider, &template_id, &overrides(None, None)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+const WEEK: u64 = 7 * 24 * 60 * 60;
+
+#[test]
+fn test_weekly_series_rolls_three_markets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let template_id = client.create_template(&test.admin, &weekly_btc_template(&test));
+
+    let series_id = client.create_series(&test.admin, &template_id, &WEEK, &3);
+    let first = client.get_series_markets(&series_id).get(0).unwrap();
+
+    // The first market is still running
+    assert_eq!(
+        client.try_roll_series(&series_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    let mut previous = first.clone();
+    for index in 1..3u32 {
+        let end_time = client.get_market(&previous).unwrap().end_time;
+        test.env.ledger().with_mut(|li| li.timestamp = end_time);
+        let market_id = client.roll_series(&series_id);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.end_time, end_time + WEEK);
+        assert_eq!(market.creator, test.admin);
+        assert_eq!(
+            client.get_market_series(&market_id),
+            Some(SeriesPosition { series_id, index })
+        );
+        previous = market_id;
+    }
+
+    let markets = client.get_series_markets(&series_id);
+    assert_eq!(markets.len(), 3);
+    assert_eq!(markets.get(0).unwrap(), first);
+    assert_eq!(markets.get(2).unwrap(), previous);
+    assert_eq!(
+        client.get_market_series(&first),
+        Some(SeriesPosition {
+            series_id,
+            index: 0
+        })
+    );
+
+    // Past the final index
+    let end_time = client.get_market(&previous).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    assert_eq!(
+        client.try_roll_series(&series_id),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+#[test]
+fn test_create_series_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let template_id = client.create_template(&test.admin, &weekly_btc_template(&test));
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_create_series(&outsider, &template_id, &WEEK, &3),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_create_series(&test.admin, &99, &WEEK, &3),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_create_series(&test.admin, &template_id, &(WEEK + 1), &3),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_create_series(&test.admin, &template_id, &WEEK, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(client.try_roll_series(&42), Err(Ok(Error::InvalidInput)));
+    assert_eq!(client.get_series_markets(&42).len(), 0);
+}
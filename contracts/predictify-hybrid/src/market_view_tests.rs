@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+//! Market view tests.
+//!
+//! `get_market_view` derives the effective state, deadlines and pools from the
+//! stored market and the ledger time in every lifecycle phase, while
+//! `get_market` keeps returning the raw stored market.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, MarketState};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, String, Symbol};
+
+const STAKE: i128 = 100_0000000;
+
+/// Market with a 100 XLM "yes" bet and nothing on "no"
+fn market_with_bet(test: &PredictifyTest) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    market_id
+}
+
+/// Resolve `market_id` to "yes" just after it ends; returns the resolution time
+fn resolve_yes(test: &PredictifyTest, market_id: &Symbol) -> u64 {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let resolved_at = client.get_market(market_id).unwrap().end_time + 1;
+    test.env.ledger().with_mut(|li| li.timestamp = resolved_at);
+    client.resolve_market_manual(&test.admin, market_id, &String::from_str(&test.env, "yes"));
+    resolved_at
+}
+
+#[test]
+fn test_view_of_active_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let market = client.get_market(&market_id).unwrap();
+
+    let view = client.get_market_view(&market_id);
+    assert_eq!(view.market_id, market_id);
+    assert_eq!(view.question, market.question);
+    assert_eq!(view.outcomes, market.outcomes);
+    assert_eq!(view.state, MarketState::Active);
+    assert_eq!(view.end_time, market.end_time);
+    assert_eq!(view.betting_deadline, market.end_time);
+    assert_eq!(view.resolution_deadline, market.compute_resolution_deadline());
+    assert_eq!(view.dispute_window_end, None);
+    assert_eq!(view.payout_unlock_at, None);
+    assert_eq!(view.total_pool, STAKE);
+    assert_eq!(view.winning_outcomes, None);
+    assert!(!view.fee_collected);
+    assert!(!view.frozen);
+
+    // Outcomes nobody backed are listed with an empty pool
+    assert_eq!(view.outcome_pools.len(), 2);
+    assert_eq!(
+        view.outcome_pools.get(String::from_str(&test.env, "yes")),
+        Some(STAKE)
+    );
+    assert_eq!(
+        view.outcome_pools.get(String::from_str(&test.env, "no")),
+        Some(0)
+    );
+}
+
+#[test]
+fn test_view_of_ended_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time);
+
+    // The stored state only changes on explicit transitions
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Active
+    );
+    let view = client.get_market_view(&market_id);
+    assert_eq!(view.state, MarketState::Ended);
+    assert_eq!(view.winning_outcomes, None);
+    assert_eq!(view.payout_unlock_at, None);
+}
+
+#[test]
+fn test_view_of_resolved_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let resolved_at = resolve_yes(&test, &market_id);
+    let window = client.get_market(&market_id).unwrap().dispute_window_seconds;
+
+    let view = client.get_market_view(&market_id);
+    assert_eq!(view.state, MarketState::Resolved);
+    assert_eq!(
+        view.winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+    assert_eq!(view.dispute_window_end, Some(resolved_at + window));
+    assert_eq!(view.payout_unlock_at, Some(resolved_at + window));
+    assert_eq!(view.total_pool, STAKE);
+    assert!(!view.fee_collected);
+
+    client.collect_fees(&test.admin, &market_id);
+    assert!(client.get_market_view(&market_id).fee_collected);
+}
+
+#[test]
+fn test_view_of_disputed_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    let resolved_at = resolve_yes(&test, &market_id);
+    let window = client.get_market(&market_id).unwrap().dispute_window_seconds;
+    let disputer = test.create_funded_user();
+    let no = String::from_str(&test.env, "no");
+
+    test.env.mock_all_auths();
+    client.dispute_result(&disputer, &market_id, &no, &10_000_000);
+
+    // An open dispute blocks payouts until it is ruled on
+    let view = client.get_market_view(&market_id);
+    assert_eq!(view.state, MarketState::Disputed);
+    assert_eq!(view.dispute_window_end, Some(resolved_at + window));
+    assert_eq!(view.payout_unlock_at, None);
+
+    // Upholding it opens a half-length appeal window from the ruling
+    client.resolve_dispute(&test.admin, &market_id, &no, &true);
+    let now = test.env.ledger().timestamp();
+    let view = client.get_market_view(&market_id);
+    assert_eq!(view.state, MarketState::Resolved);
+    assert_eq!(view.winning_outcomes, Some(vec![&test.env, no]));
+    assert_eq!(view.dispute_window_end, Some(now + window / 2));
+    assert_eq!(view.payout_unlock_at, Some(now + window / 2));
+}
+
+#[test]
+fn test_view_of_cancelled_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = market_with_bet(&test);
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+
+    let view = client.get_market_view(&market_id);
+    assert_eq!(view.state, MarketState::Cancelled);
+    assert_eq!(view.winning_outcomes, None);
+    assert_eq!(view.payout_unlock_at, None);
+}
+
+#[test]
+fn test_view_of_unknown_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(
+        client.try_get_market_view(&Symbol::new(&test.env, "missing")),
+        Err(Ok(Error::MarketNotFound))
+    );
+}
@@ -1,9 +1,13 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, token, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, token, vec, Address, Env, Map, String, Symbol, TryFromVal, Val,
+    Vec,
+};
 
 // use crate::config; // Unused import
 use crate::errors::Error;
+use crate::storage::{DataKey, TtlManager};
 use crate::types::*;
 // Oracle imports removed - not currently used
 
@@ -128,7 +132,7 @@ impl MarketCreator {
         let _ = MarketUtils::process_creation_fee(env, &admin)?;
 
         // Store market
-        env.storage().persistent().set(&market_id, &market);
+        MarketStateManager::update_market(env, &market_id, &market);
 
         Ok(market_id)
     }
@@ -206,6 +210,9 @@ impl MarketCreator {
             feed_id: asset_symbol,
             threshold,
             comparison,
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         };
 
         Self::create_market(
@@ -288,6 +295,9 @@ impl MarketCreator {
             feed_id,
             threshold,
             comparison,
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         };
 
         Self::create_market(
@@ -731,7 +741,7 @@ impl MarketStateManager {
     ///
     /// # Parameters
     ///
-    /// * `_env` - The Soroban environment for blockchain operations
+    /// * `env` - The Soroban environment for blockchain operations
     /// * `market_id` - Unique symbol identifier for the market
     ///
     /// # Returns
@@ -760,11 +770,15 @@ impl MarketStateManager {
     ///     Err(e) => println!("Market not found: {:?}", e),
     /// }
     /// ```
-    pub fn get_market(_env: &Env, market_id: &Symbol) -> Result<Market, Error> {
-        _env.storage()
+    pub fn get_market(env: &Env, market_id: &Symbol) -> Result<Market, Error> {
+        let mut market: Market = env
+            .storage()
             .persistent()
-            .get(market_id)
-            .ok_or(Error::MarketNotFound)
+            .get(&DataKey::Market(market_id.clone()))
+            .ok_or(Error::MarketNotFound)?;
+        TtlManager::extend_market(env, market_id);
+        market.resolution_deadline = market.compute_resolution_deadline();
+        Ok(market)
     }
 
     /// Updates market data in persistent storage.
@@ -775,7 +789,7 @@ impl MarketStateManager {
     ///
     /// # Parameters
     ///
-    /// * `_env` - The Soroban environment for blockchain operations
+    /// * `env` - The Soroban environment for blockchain operations
     /// * `market_id` - Unique symbol identifier for the market
     /// * `market` - Updated market data to store
     ///
@@ -795,8 +809,13 @@ impl MarketStateManager {
     /// // Save changes
     /// MarketStateManager::update_market(&env, &market_id, &market);
     /// ```
-    pub fn update_market(_env: &Env, market_id: &Symbol, market: &Market) {
-        _env.storage().persistent().set(market_id, market);
+    pub fn update_market(env: &Env, market_id: &Symbol, market: &Market) {
+        let key = DataKey::Market(market_id.clone());
+        let previous: Option<Market> = env.storage().persistent().get(&key);
+        MarketUtils::track_open_market(env, previous.as_ref(), market);
+        env.storage().persistent().set(&key, market);
+        TtlManager::extend_market(env, market_id);
+        TtlManager::extend_instance(env);
     }
 
     /// Updates the market question/description.
@@ -817,7 +836,7 @@ impl MarketStateManager {
         let mut market = Self::get_market(_env, market_id)?;
 
         // Ensure no votes have been placed
-        if !market.votes.is_empty() {
+        if market.position_count() > 0 {
             return Err(Error::InvalidState);
         }
 
@@ -826,6 +845,200 @@ impl MarketStateManager {
         Ok(())
     }
 
+    /// Returns the display metadata of a market, if any has been set.
+    pub fn get_market_metadata(env: &Env, market_id: &Symbol) -> Option<MarketMetadata> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("mkt_meta"), market_id.clone()))
+    }
+
+    /// Stores a market's display metadata and keeps the category index in sync.
+    ///
+    /// Moving a market to a new category removes it from the old category's index.
+    pub fn set_market_metadata(env: &Env, market_id: &Symbol, metadata: &MarketMetadata) {
+        if let Some(old) = Self::get_market_metadata(env, market_id) {
+            if old.category != metadata.category {
+                let mut old_ids = Self::get_category_index(env, &old.category);
+                if let Some(pos) = old_ids.first_index_of(market_id) {
+                    old_ids.remove(pos);
+                }
+                Self::set_category_index(env, &old.category, &old_ids);
+            }
+        }
+
+        let mut ids = Self::get_category_index(env, &metadata.category);
+        if !ids.contains(market_id) {
+            ids.push_back(market_id.clone());
+            Self::set_category_index(env, &metadata.category, &ids);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("mkt_meta"), market_id.clone()), metadata);
+    }
+
+    /// Switch a market between categorical and scalar (market creator only).
+    ///
+    /// Allowed while the market is active and holds no positions. Making a market
+    /// scalar replaces its outcomes with `long` and `short`. Scalar markets settle
+    /// on the oracle value, so they cannot use the hybrid community vote.
+    pub fn set_market_type(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+        market_type: MarketType,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        let mut market = Self::get_market(env, market_id)?;
+        if market.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 || market.position_count() > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if let MarketType::Scalar(range) = market_type {
+            if range.lower_bound >= range.upper_bound {
+                return Err(Error::InvalidInput);
+            }
+            if market.resolve_mode != ResolveMode::Standard {
+                return Err(Error::InvalidState);
+            }
+            market.outcomes = vec![
+                env,
+                String::from_str(env, crate::config::SCALAR_LONG_OUTCOME),
+                String::from_str(env, crate::config::SCALAR_SHORT_OUTCOME),
+            ];
+        }
+
+        market.market_type = market_type;
+        Self::update_market(env, market_id, &market);
+        crate::events::EventEmitter::emit_market_type_set(env, market_id, creator, market_type);
+        Ok(())
+    }
+
+    /// Close an active market before its scheduled `end_time`.
+    ///
+    /// For events whose outcome is settled early. `end_time` becomes now, so
+    /// betting stops with `Error::MarketClosed` and the usual resolution paths
+    /// open straight away; the dispute window still runs from resolution. A bet
+    /// deadline later than now is pulled in as well, and the market is moved
+    /// to `Ended`. Admin checks are left to the caller.
+    pub fn close_market_early(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        reason: &String,
+    ) -> Result<(), Error> {
+        let mut market = Self::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        let now = env.ledger().timestamp();
+        if !matches!(
+            market.state,
+            MarketState::Active | MarketState::BettingClosed
+        ) || now >= market.end_time
+        {
+            return Err(Error::MarketClosed);
+        }
+
+        let original_end_time = market.end_time;
+        let old_state = market.state;
+        market.end_time = now;
+        if market.bet_deadline > now {
+            market.bet_deadline = now;
+        }
+        market.closed_early_at = Some(now);
+        MarketStateLogic::transition(&mut market, MarketEvent::End)?;
+        Self::update_market(env, market_id, &market);
+        MarketStateLogic::emit_state_change_event(env, market_id, old_state, market.state);
+
+        crate::events::EventEmitter::emit_market_closed_early(
+            env,
+            market_id,
+            admin,
+            reason,
+            original_end_time,
+        );
+        Ok(())
+    }
+
+    /// Returns up to `limit` market ids in `category`, starting at index `start`.
+    pub fn get_markets_by_category(
+        env: &Env,
+        category: &Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Symbol> {
+        let ids = Self::get_category_index(env, category);
+        let end = start
+            .saturating_add(limit.min(crate::event_archive::MAX_QUERY_LIMIT))
+            .min(ids.len());
+        let mut page = Vec::new(env);
+        for i in start..end {
+            page.push_back(ids.get(i).unwrap());
+        }
+        page
+    }
+
+    /// Returns ids of markets in the market id index, optionally filtered by state.
+    ///
+    /// `start` and `limit` page over the index in creation order (`limit` capped at
+    /// `MAX_QUERY_LIMIT`); `state_filter` is matched against
+    /// `MarketStateLogic::derive_market_state`, so a filtered page may hold fewer
+    /// than `limit` ids. Index entries that are not markets (e.g. events) or whose
+    /// market was removed are skipped.
+    pub fn list_markets(
+        env: &Env,
+        start: u32,
+        limit: u32,
+        state_filter: Option<MarketState>,
+    ) -> Vec<Symbol> {
+        let limit = limit.min(crate::event_archive::MAX_QUERY_LIMIT);
+        let entries = crate::market_id_generator::MarketIdGenerator::get_market_id_registry(
+            env, start, limit,
+        );
+        let mut ids = Vec::new(env);
+        for entry in entries.iter() {
+            let market = match env
+                .storage()
+                .persistent()
+                .get::<DataKey, Val>(&DataKey::Market(entry.market_id.clone()))
+                .and_then(|val| Market::try_from_val(env, &val).ok())
+            {
+                Some(market) => market,
+                None => continue,
+            };
+            let matches = match state_filter {
+                Some(state) => {
+                    MarketStateLogic::derive_market_state(env, &entry.market_id, &market) == state
+                }
+                None => true,
+            };
+            if matches {
+                ids.push_back(entry.market_id);
+            }
+        }
+        ids
+    }
+
+    fn get_category_index(env: &Env, category: &Symbol) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("cat_idx"), category.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_category_index(env: &Env, category: &Symbol, ids: &Vec<Symbol>) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("cat_idx"), category.clone()), ids);
+    }
+
     /// Removes a market from persistent storage after proper closure.
     ///
     /// This function safely removes a market from storage, ensuring it's
@@ -839,8 +1052,9 @@ impl MarketStateManager {
     ///
     /// # State Transitions
     ///
-    /// If the market is not already closed, it will be transitioned to
-    /// `MarketState::Closed` before removal.
+    /// A resolved market is settled to `MarketState::Closed` and any other
+    /// unresolved market is cancelled before removal; markets in a terminal
+    /// state are removed as they are.
     ///
     /// # Example
     ///
@@ -862,41 +1076,46 @@ impl MarketStateManager {
             Ok(m) => m,
             Err(_) => return,
         };
-        if market.state != MarketState::Closed {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Closed).unwrap();
+        if !market.state.is_terminal() {
             let old_state = market.state;
-            market.state = MarketState::Closed;
+            let event = if old_state == MarketState::Resolved {
+                MarketEvent::Settle
+            } else {
+                MarketEvent::Cancel
+            };
+            MarketStateLogic::transition(&mut market, event).unwrap();
             MarketStateLogic::emit_state_change_event(env, market_id, old_state, market.state);
             Self::update_market(env, market_id, &market);
         }
-        env.storage().persistent().remove(market_id);
+        env.storage().persistent().remove(&DataKey::Market(market_id.clone()));
     }
 
     /// Adds a user's vote to a market with the specified stake amount.
     ///
     /// This function records a user's vote for a specific outcome and their
-    /// associated stake. It updates the market's vote mappings, stake tracking,
-    /// and total staked amount. The function validates market state before
-    /// allowing the vote.
+    /// associated stake as the user's bet on the market, and adds the stake to
+    /// the market's outcome totals and total staked amount. The function
+    /// validates market state before allowing the vote.
     ///
     /// # Parameters
     ///
+    /// * `env` - The Soroban environment
+    /// * `market_id` - The market identifier
     /// * `market` - Mutable reference to the market to add the vote to
     /// * `user` - Address of the user placing the vote
     /// * `outcome` - The outcome the user is voting for
     /// * `stake` - Amount staked on this vote (in token base units)
-    /// * `_market_id` - Optional market ID for event emission (currently unused)
     ///
     /// # State Requirements
     ///
     /// * Market must be in `Active` state
     /// * Market must not have expired
-    /// * User must not have already voted (will overwrite existing vote)
+    /// * User must not already hold a position
     ///
     /// # Side Effects
     ///
-    /// * Updates `market.votes` mapping with user's choice
-    /// * Updates `market.stakes` mapping with user's stake
+    /// * Stores the vote as the user's `Bet` under `DataKey::Bet`
+    /// * Adds the stake to the outcome's pool and position count
     /// * Increments `market.total_staked` by the stake amount
     ///
     /// # Example
@@ -913,29 +1132,29 @@ impl MarketStateManager {
     /// let outcome = String::from_str(&env, "Yes");
     /// let stake = 5_000_000; // 0.5 XLM
     ///
-    /// MarketStateManager::add_vote(
-    ///     &mut market,
-    ///     user,
-    ///     outcome,
-    ///     stake,
-    ///     Some(&market_id)
-    /// );
+    /// MarketStateManager::add_vote(&env, &market_id, &mut market, user, outcome, stake)?;
     ///
     /// // Save updated market
     /// MarketStateManager::update_market(&env, &market_id, &market);
     /// ```
     pub fn add_vote(
+        env: &Env,
+        market_id: &Symbol,
         market: &mut Market,
         user: Address,
         outcome: String,
         stake: i128,
-        _market_id: Option<&Symbol>,
-    ) {
-        MarketStateLogic::check_function_access_for_state("vote", market.state).unwrap();
-        market.votes.set(user.clone(), outcome);
-        market.stakes.set(user.clone(), stake);
-        market.total_staked += stake;
+    ) -> Result<(), Error> {
+        MarketStateLogic::check_function_access_for_state("vote", market.state)?;
+        if crate::bets::BetStorage::get_position(env, market_id, &user).is_some() {
+            return Err(Error::AlreadyVoted);
+        }
+        let bet = Bet::new(env, user, market_id.clone(), outcome.clone(), stake);
+        crate::bets::BetStorage::store_bet(env, &bet)?;
+        market.add_position(&outcome, stake);
+        market.total_staked = crate::amounts::checked_add(market.total_staked, stake)?;
         // No state change for voting
+        Ok(())
     }
 
     /// Add dispute stake to market
@@ -1010,11 +1229,9 @@ impl MarketStateManager {
         market.dispute_stakes.set(user, existing_stake + stake);
         // State transition: Ended -> Disputed
         if market.state == MarketState::Ended {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Disputed)
-                .unwrap();
             let old_state = market.state;
-            market.state = MarketState::Disputed;
-            let env = &market.votes.env();
+            MarketStateLogic::transition(market, MarketEvent::Dispute).unwrap();
+            let env = &market.outcomes.env();
             let owned_event_id = market_id
                 .cloned()
                 .unwrap_or_else(|| Symbol::new(env, "unknown_market_id"));
@@ -1035,9 +1252,10 @@ impl MarketStateManager {
     ///
     /// # Parameters
     ///
-    /// * `market` - Mutable reference to the market
+    /// * `env` - The Soroban environment
+    /// * `market_id` - The market identifier
+    /// * `market` - The market
     /// * `user` - Address of the user who has claimed their winnings
-    /// * `_market_id` - Optional market ID for event emission (currently unused)
     ///
     /// # State Requirements
     ///
@@ -1047,7 +1265,7 @@ impl MarketStateManager {
     ///
     /// # Side Effects
     ///
-    /// * Updates `market.claimed` mapping to mark user as claimed
+    /// * Sets the user's `DataKey::Claimed` flag for the market
     ///
     /// # Example
     ///
@@ -1065,22 +1283,20 @@ impl MarketStateManager {
     /// assert_eq!(market.state, MarketState::Resolved);
     ///
     /// // Check if user hasn't claimed yet
-    /// assert!(!market.claimed.get(winner.clone()).unwrap_or(false));
+    /// assert!(!BetStorage::is_claimed(&env, &market_id, &winner));
     ///
     /// // Process payout (external logic)
     /// // ...
     ///
     /// // Mark as claimed
-    /// MarketStateManager::mark_claimed(&mut market, winner.clone(), Some(&market_id));
+    /// MarketStateManager::mark_claimed(&env, &market_id, &market, &winner);
     ///
     /// // Verify claim status
-    /// assert!(market.claimed.get(winner).unwrap_or(false));
-    ///
-    /// MarketStateManager::update_market(&env, &market_id, &market);
+    /// assert!(BetStorage::is_claimed(&env, &market_id, &winner));
     /// ```
-    pub fn mark_claimed(market: &mut Market, user: Address, _market_id: Option<&Symbol>) {
+    pub fn mark_claimed(env: &Env, market_id: &Symbol, market: &Market, user: &Address) {
         MarketStateLogic::check_function_access_for_state("claim", market.state).unwrap();
-        market.claimed.set(user, true);
+        crate::bets::BetStorage::mark_claimed(env, market_id, user);
     }
 
     /// Sets the oracle result for a market that has reached its end time.
@@ -1135,13 +1351,18 @@ impl MarketStateManager {
     ///
     /// # State Requirements
     ///
-    /// * Market must be in `Ended` or `Disputed` state
+    /// * Market must be in `Ended` or `Disputed` state, or still stored as
+    ///   `Active`/`BettingClosed` after its end time
     ///
     /// # State Transitions
     ///
     /// * `Ended` → `Resolved`
     /// * `Disputed` → `Resolved`
     ///
+    /// # Errors
+    ///
+    /// * `Error::MarketClosed` - Market is in a state that cannot be resolved
+    ///
     /// # Side Effects
     ///
     /// * Sets `market.winning_outcomes` to the specified outcome(s)
@@ -1152,10 +1373,14 @@ impl MarketStateManager {
     ///
     /// For single winner, pass a vector with one outcome.
     /// This function replaces the old `set_winning_outcome` for multi-outcome support.
-    pub fn set_winning_outcome(market: &mut Market, outcome: String, market_id: Option<&Symbol>) {
+    pub fn set_winning_outcome(
+        market: &mut Market,
+        outcome: String,
+        market_id: Option<&Symbol>,
+    ) -> Result<(), Error> {
         // Convert single outcome to vector for backward compatibility
-        let outcomes = vec![market.votes.env(), outcome];
-        Self::set_winning_outcomes(market, outcomes, market_id);
+        let outcomes = vec![market.outcomes.env(), outcome];
+        Self::set_winning_outcomes(market, outcomes, market_id)
     }
     ///
     /// # Example
@@ -1177,7 +1402,7 @@ impl MarketStateManager {
     ///     &mut market,
     ///     winning_outcome,
     ///     Some(&market_id)
-    /// );
+    /// )?;
     ///
     /// // Market should now be resolved
     /// assert_eq!(market.state, MarketState::Resolved);
@@ -1190,30 +1415,27 @@ impl MarketStateManager {
     /// Supports both single winner and multi-winner (tie) cases.
     /// For single winner: pass a vector with one outcome.
     /// For ties: pass a vector with multiple outcomes (pool will be split).
+    /// A market still stored as open past its end time is ended first.
+    ///
+    /// Returns `Error::MarketClosed` if the market cannot be resolved from its state.
     pub fn set_winning_outcomes(
         market: &mut Market,
         outcomes: Vec<String>,
         market_id: Option<&Symbol>,
-    ) {
-        MarketStateLogic::check_function_access_for_state("resolve", market.state).unwrap();
+    ) -> Result<(), Error> {
+        let env = market.outcomes.env().clone();
         let old_state = market.state;
-        market.winning_outcomes = Some(outcomes);
+        MarketStateLogic::advance_by_time(&env, market)?;
+        MarketStateLogic::check_function_access_for_state("resolve", market.state)?;
         // State transition: Ended/Disputed -> Resolved
-        if market.state == MarketState::Ended || market.state == MarketState::Disputed {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Resolved)
-                .unwrap();
-            market.state = MarketState::Resolved;
-            let env = &market.votes.env();
-            let owned_event_id = market_id
-                .cloned()
-                .unwrap_or_else(|| Symbol::new(env, "unknown_market_id"));
-            MarketStateLogic::emit_state_change_event(
-                env,
-                &owned_event_id,
-                old_state,
-                market.state,
-            );
-        }
+        MarketStateLogic::transition(market, MarketEvent::Resolve)?;
+        market.winning_outcomes = Some(outcomes);
+        market.mark_resolved(env.ledger().timestamp());
+        let owned_event_id = market_id
+            .cloned()
+            .unwrap_or_else(|| Symbol::new(&env, "unknown_market_id"));
+        MarketStateLogic::emit_state_change_event(&env, &owned_event_id, old_state, market.state);
+        Ok(())
     }
 
     /// Marks platform fees as collected and transitions market to closed state.
@@ -1272,9 +1494,8 @@ impl MarketStateManager {
         let old_state = market.state;
         // State transition: Resolved -> Closed
         if market.state == MarketState::Resolved {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Closed).unwrap();
-            market.state = MarketState::Closed;
-            let env = &market.votes.env();
+            MarketStateLogic::transition(market, MarketEvent::Settle).unwrap();
+            let env = &market.outcomes.env();
             let owned_event_id = market_id
                 .cloned()
                 .unwrap_or_else(|| Symbol::new(env, "unknown_market_id"));
@@ -1347,6 +1568,143 @@ impl MarketStateManager {
     }
 }
 
+// ===== MARKET ACCESS =====
+
+/// Invite-only markets.
+///
+/// A private market stores its bettor allowlist under
+/// `DataKey::MarketAllowlist`; public markets have no entry. The market creator
+/// or the contract admin manages both until the market ends.
+pub struct MarketAccessManager;
+
+impl MarketAccessManager {
+    /// Whether anyone or only allowlisted addresses may bet on the market.
+    pub fn get_access(env: &Env, market_id: &Symbol) -> MarketAccess {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::MarketAllowlist(market_id.clone()))
+        {
+            MarketAccess::Allowlist
+        } else {
+            MarketAccess::Public
+        }
+    }
+
+    /// Addresses allowed to bet on a private market; empty for public markets.
+    pub fn get_allowlist(env: &Env, market_id: &Symbol) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MarketAllowlist(market_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Reject `user` with `Error::Unauthorized` when the market is private and
+    /// they are not on its allowlist.
+    pub fn require_allowed(env: &Env, market_id: &Symbol, user: &Address) -> Result<(), Error> {
+        let allowlist: Option<Vec<Address>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MarketAllowlist(market_id.clone()));
+        match allowlist {
+            Some(allowlist) if !allowlist.contains(user) => Err(Error::Unauthorized),
+            _ => Ok(()),
+        }
+    }
+
+    /// Make a market public or private. Going private starts from an empty
+    /// allowlist; going public drops it.
+    pub fn set_access(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        access: MarketAccess,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::require_manageable(env, caller, market_id)?;
+
+        let key = DataKey::MarketAllowlist(market_id.clone());
+        match access {
+            MarketAccess::Public => env.storage().persistent().remove(&key),
+            MarketAccess::Allowlist => {
+                if !env.storage().persistent().has(&key) {
+                    env.storage().persistent().set(&key, &Vec::<Address>::new(env));
+                }
+            }
+        }
+
+        crate::events::EventEmitter::emit_market_access_updated(
+            env,
+            market_id,
+            caller,
+            access,
+            &Vec::new(env),
+            &Vec::new(env),
+        );
+        Ok(())
+    }
+
+    /// Add and remove bettors on a private market's allowlist.
+    ///
+    /// Removals apply after additions, so an address in both ends up off the
+    /// list. Removed bettors keep the positions they already hold.
+    pub fn update_allowlist(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        add: &Vec<Address>,
+        remove: &Vec<Address>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+        Self::require_manageable(env, caller, market_id)?;
+
+        let key = DataKey::MarketAllowlist(market_id.clone());
+        let mut allowlist: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::InvalidState)?;
+        for address in add.iter() {
+            if !allowlist.contains(&address) {
+                allowlist.push_back(address);
+            }
+        }
+        for address in remove.iter() {
+            if let Some(index) = allowlist.first_index_of(&address) {
+                allowlist.remove(index);
+            }
+        }
+        if allowlist.len() > crate::config::MAX_MARKET_ALLOWLIST_SIZE {
+            return Err(Error::ThresholdTooHigh);
+        }
+        env.storage().persistent().set(&key, &allowlist);
+
+        crate::events::EventEmitter::emit_market_access_updated(
+            env,
+            market_id,
+            caller,
+            MarketAccess::Allowlist,
+            add,
+            remove,
+        );
+        Ok(())
+    }
+
+    /// Only the market creator or the contract admin may manage access, and
+    /// only while the market still takes bets.
+    fn require_manageable(env: &Env, caller: &Address, market_id: &Symbol) -> Result<(), Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if market.creator != *caller && admin.as_ref() != Some(caller) {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active || market.has_ended(env) {
+            return Err(Error::MarketClosed);
+        }
+        Ok(())
+    }
+}
+
 // ===== MARKET ANALYTICS =====
 
 /// Market analytics and statistics utilities for data analysis and insights.
@@ -1391,22 +1749,11 @@ impl MarketAnalytics {
     /// }
     /// ```
     pub fn get_market_stats(market: &Market) -> MarketStats {
-        let total_votes = market.votes.len() as u32;
-        let total_staked = market.total_staked;
-        let total_dispute_stakes = market.total_dispute_stakes();
-
-        // Calculate outcome distribution
-        let mut outcome_stats = Map::new(&market.votes.env());
-        for (_, outcome) in market.votes.iter() {
-            let count = outcome_stats.get(outcome.clone()).unwrap_or(0);
-            outcome_stats.set(outcome.clone(), count + 1);
-        }
-
         MarketStats {
-            total_votes,
-            total_staked,
-            total_dispute_stakes,
-            outcome_distribution: outcome_stats,
+            total_votes: market.position_count(),
+            total_staked: market.total_staked,
+            total_dispute_stakes: market.total_dispute_stakes(),
+            outcome_distribution: market.outcome_positions.clone(),
         }
     }
 
@@ -1452,20 +1799,10 @@ impl MarketAnalytics {
     /// println!("Payout multiplier: {:.2}x", payout_ratio);
     /// ```
     pub fn calculate_winning_stats(market: &Market, winning_outcome: &String) -> WinningStats {
-        let mut winning_total = 0;
-        let mut winning_voters = 0;
-
-        for (user, outcome) in market.votes.iter() {
-            if &outcome == winning_outcome {
-                winning_total += market.stakes.get(user.clone()).unwrap_or(0);
-                winning_voters += 1;
-            }
-        }
-
         WinningStats {
             winning_outcome: winning_outcome.clone(),
-            winning_total,
-            winning_voters,
+            winning_total: market.outcome_pool(winning_outcome),
+            winning_voters: market.outcome_position_count(winning_outcome),
             total_pool: market.total_staked,
         }
     }
@@ -1478,6 +1815,8 @@ impl MarketAnalytics {
     ///
     /// # Parameters
     ///
+    /// * `env` - The Soroban environment
+    /// * `market_id` - The market identifier
     /// * `market` - Reference to the market to analyze
     /// * `user` - Address of the user to get statistics for
     ///
@@ -1501,7 +1840,7 @@ impl MarketAnalytics {
     /// let market_id = Symbol::new(&env, "active_market");
     /// let market = MarketStateManager::get_market(&env, &market_id)?;
     ///
-    /// let user_stats = MarketAnalytics::get_user_stats(&market, &user);
+    /// let user_stats = MarketAnalytics::get_user_stats(&env, &market_id, &market, &user);
     ///
     /// if user_stats.has_voted {
     ///     println!("User voted for: {:?}", user_stats.voted_outcome);
@@ -1518,19 +1857,20 @@ impl MarketAnalytics {
     ///     println!("User has already claimed winnings");
     /// }
     /// ```
-    pub fn get_user_stats(market: &Market, user: &Address) -> UserStats {
-        let has_voted = market.votes.contains_key(user.clone());
-        let stake = market.stakes.get(user.clone()).unwrap_or(0);
-        let dispute_stake = market.dispute_stakes.get(user.clone()).unwrap_or(0);
-        let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
-        let voted_outcome = market.votes.get(user.clone());
+    pub fn get_user_stats(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+    ) -> UserStats {
+        let position = crate::bets::BetStorage::get_position(env, market_id, user);
 
         UserStats {
-            has_voted,
-            stake,
-            dispute_stake,
-            has_claimed,
-            voted_outcome,
+            has_voted: position.is_some(),
+            stake: position.as_ref().map_or(0, |bet| bet.amount),
+            dispute_stake: market.dispute_stakes.get(user.clone()).unwrap_or(0),
+            has_claimed: crate::bets::BetStorage::is_claimed(env, market_id, user),
+            voted_outcome: position.map(|bet| bet.outcome),
         }
     }
 
@@ -1580,14 +1920,9 @@ impl MarketAnalytics {
     /// }
     /// ```
     pub fn calculate_community_consensus(market: &Market) -> CommunityConsensus {
-        let mut vote_counts: Map<String, u32> = Map::new(&market.votes.env());
-
-        for (_, outcome) in market.votes.iter() {
-            let count = vote_counts.get(outcome.clone()).unwrap_or(0);
-            vote_counts.set(outcome.clone(), count + 1);
-        }
+        let vote_counts = &market.outcome_positions;
 
-        let mut consensus_outcome = String::from_str(&market.votes.env(), "");
+        let mut consensus_outcome = String::from_str(&market.outcomes.env(), "");
         let mut max_votes = 0;
         let mut total_votes = 0;
 
@@ -1848,13 +2183,277 @@ impl MarketUtils {
     pub fn get_token_client(_env: &Env) -> Result<token::Client<'_>, Error> {
         let token_id: Address = _env
             .storage()
-            .persistent()
-            .get(&Symbol::new(_env, "TokenID"))
+            .instance()
+            .get(&DataKey::Token)
             .ok_or(Error::InvalidState)?;
 
         Ok(token::Client::new(_env, &token_id))
     }
 
+    /// Get the token client for the asset a market is denominated in.
+    ///
+    /// Markets with a dedicated `token` use it for stakes, refunds and payouts;
+    /// all other markets fall back to the global staking token.
+    pub fn get_market_token_client<'a>(
+        env: &'a Env,
+        market: &Market,
+    ) -> Result<token::Client<'a>, Error> {
+        match &market.token {
+            Some(token_id) => Ok(token::Client::new(env, token_id)),
+            None => Self::get_token_client(env),
+        }
+    }
+
+    /// Whether `token` is the global staking token, the token of any market, or
+    /// a token with a non-zero fee vault or market escrow.
+    ///
+    /// Balances of these tokens back escrow, fee vaults and user balances, so
+    /// they are never treated as stray assets.
+    pub fn is_staking_token(env: &Env, token: &Address) -> bool {
+        let global: Option<Address> = env.storage().instance().get(&DataKey::Token);
+        if global.as_ref() == Some(token) {
+            return true;
+        }
+        if crate::fees::FeeTracker::get_fee_vault_balance(env, Some(token)) > 0 {
+            return true;
+        }
+        let registry = crate::market_id_generator::MarketIdGenerator::get_market_id_registry(
+            env,
+            0,
+            u32::MAX,
+        );
+        registry.iter().any(|entry| {
+            let market: Option<Market> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Market(entry.market_id.clone()));
+            market.is_some_and(|market| market.token.as_ref() == Some(token))
+        })
+    }
+
+    /// Transfer `amount` from `from` into the contract and verify it arrived in full.
+    ///
+    /// Compares the contract's balance before and after the transfer. Tokens that
+    /// skim a fee on transfer deliver less than `amount`; crediting the nominal
+    /// amount would leave payouts under-collateralised, so the call fails with
+    /// `Error::InsufficientBalance` and the whole transaction reverts.
+    pub fn receive_tokens(
+        env: &Env,
+        token_client: &token::Client,
+        from: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let contract = env.current_contract_address();
+        let before = token_client.balance(&contract);
+        token_client.transfer(from, &contract, &amount);
+        let after = token_client.balance(&contract);
+        if after.checked_sub(before) != Some(amount) {
+            return Err(Error::InsufficientBalance);
+        }
+        Ok(())
+    }
+
+    /// Sum of stakes backing any of the winning outcomes (split pool for ties).
+    pub fn winning_stake_total(market: &Market, winning_outcomes: &Vec<String>) -> i128 {
+        let mut winning_total = 0i128;
+        for outcome in winning_outcomes.iter() {
+            // Bounded by total_staked, whose updates are overflow-checked
+            winning_total = winning_total.saturating_add(market.outcome_pool(&outcome));
+        }
+        winning_total
+    }
+
+    /// Stake total a winner's payout is measured against, and the pool it is paid from.
+    ///
+    /// Winners normally split the whole pool pro rata. When a scalar market resolves
+    /// strictly inside its range, each side instead splits its own share of the pool
+    /// (see [`Self::scalar_long_pool`]); a side nobody backed forfeits its share to
+    /// the other one.
+    pub fn winning_pool(
+        market: &Market,
+        winning_outcomes: &Vec<String>,
+        outcome: &String,
+    ) -> Result<(i128, i128), Error> {
+        if let (MarketType::Scalar(range), Some(value)) =
+            (market.market_type, market.scalar_value)
+        {
+            if winning_outcomes.len() > 1 {
+                let env = market.outcomes.env();
+                let long = String::from_str(env, crate::config::SCALAR_LONG_OUTCOME);
+                let short = String::from_str(env, crate::config::SCALAR_SHORT_OUTCOME);
+                let long_total = Self::winning_stake_total(market, &vec![env, long.clone()]);
+                let short_total = Self::winning_stake_total(market, &vec![env, short]);
+                let long_pool = Self::scalar_long_pool(market, &range, value)?;
+
+                return Ok(if *outcome == long {
+                    if short_total == 0 {
+                        (long_total, market.total_staked)
+                    } else {
+                        (long_total, long_pool)
+                    }
+                } else if long_total == 0 {
+                    (short_total, market.total_staked)
+                } else {
+                    (short_total, market.total_staked - long_pool)
+                });
+            }
+        }
+        Ok((Self::winning_stake_total(market, winning_outcomes), market.total_staked))
+    }
+
+    /// Part of a scalar market's pool owed to the `long` side when it resolves at
+    /// `value`: `total_staked * (value - lower) / (upper - lower)`, with `value`
+    /// clamped to the range. The `short` side is owed the rest.
+    pub fn scalar_long_pool(
+        market: &Market,
+        range: &ScalarRange,
+        value: i128,
+    ) -> Result<i128, Error> {
+        let clamped = value.clamp(range.lower_bound, range.upper_bound);
+        crate::amounts::mul_div(
+            market.total_staked,
+            crate::amounts::checked_sub(clamped, range.lower_bound)?,
+            crate::amounts::checked_sub(range.upper_bound, range.lower_bound)?,
+        )
+    }
+
+    /// Outcomes a scalar market resolved at `value` pays out to: `long` alone at or
+    /// above the upper bound, `short` alone at or below the lower bound, and both
+    /// sides in between.
+    pub fn scalar_winning_outcomes(env: &Env, range: &ScalarRange, value: i128) -> Vec<String> {
+        let long = String::from_str(env, crate::config::SCALAR_LONG_OUTCOME);
+        let short = String::from_str(env, crate::config::SCALAR_SHORT_OUTCOME);
+        if value >= range.upper_bound {
+            vec![env, long]
+        } else if value <= range.lower_bound {
+            vec![env, short]
+        } else {
+            vec![env, long, short]
+        }
+    }
+
+    /// Minimum pool size a market must reach to be resolved: its own
+    /// `min_pool_size`, else the global minimum (0 when neither is set).
+    pub fn effective_min_pool_size(env: &Env, market: &Market) -> i128 {
+        let global_min: i128 = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, crate::GLOBAL_MIN_POOL_SIZE_KEY))
+            .unwrap_or(0);
+        market.min_pool_size.unwrap_or(global_min)
+    }
+
+    /// Returns `true` if the sum of all outcome pools is below the market's
+    /// effective minimum pool size. Such a market cannot be resolved and can be
+    /// voided by anyone once it has ended.
+    pub fn is_underfunded(env: &Env, market: &Market) -> bool {
+        let min_pool = Self::effective_min_pool_size(env, market);
+        min_pool > 0 && Self::winning_stake_total(market, &market.outcomes) < min_pool
+    }
+
+    /// Returns `true` if a resolved market holds stakes but nobody backed any of
+    /// its winning outcomes.
+    ///
+    /// Such a market settles in refund mode: every position recovers its full
+    /// stake through the payout paths and no platform fee is taken.
+    pub fn is_refund_mode(market: &Market) -> bool {
+        match &market.winning_outcomes {
+            Some(outcomes) => {
+                market.total_staked > 0 && Self::winning_stake_total(market, outcomes) == 0
+            }
+            None => false,
+        }
+    }
+
+    /// Publish the resolution of `market` and, when it settles in refund mode,
+    /// announce the conversion.
+    ///
+    /// On first resolution, fixes the market's fee from the pool-size fee tiers.
+    /// Also reserves the market's platform fee, so `collect_fees` and the payout
+    /// paths agree on it whichever runs first, and returns the creator's
+    /// creation bond.
+    pub fn publish_resolution(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        source: crate::events::ResolutionSource,
+    ) {
+        if let Some(outcomes) = &market.winning_outcomes {
+            crate::events::EventEmitter::emit_resolved(env, market_id, outcomes, source);
+        }
+        if Self::is_refund_mode(market) {
+            crate::events::EventEmitter::emit_refund_mode(env, market_id, market.total_staked);
+        }
+        // Re-resolutions (e.g. an upheld dispute) are not counted or re-tiered
+        if !crate::fees::FeeTracker::is_market_fee_reserved(env, market_id) {
+            crate::statistics::StatisticsManager::record_market_settled(
+                env,
+                market.total_staked,
+                false,
+            );
+            if crate::fees::FeeManager::apply_fee_tier(env, market_id, market) {
+                MarketStateManager::update_market(env, market_id, market);
+            }
+        }
+        crate::fees::FeeTracker::reserve_market_fee(env, market_id, market);
+        // A market that resolved was not junk: return its creation bond
+        let _ = crate::fees::FeeManager::release_creation_bond(
+            env,
+            market_id,
+            &market.creator,
+            false,
+        );
+    }
+
+    /// Returns `true` if any market on the global token still holds user funds:
+    /// an unsettled pool, dispute stakes, or escrow still owed out as winnings,
+    /// refunds or fees (e.g. a resolved market with unclaimed winnings, or a
+    /// cancelled one awaiting `process_refunds`). Markets with their own `token`
+    /// are unaffected by global switches.
+    pub fn has_open_pool(env: &Env) -> bool {
+        crate::escrow::EscrowManager::global_token_total(env) > 0
+    }
+
+    /// Returns `true` if any market on the global token is still open (state
+    /// `Active`, `BettingClosed`, `Ended` or `Disputed`), whether or not it
+    /// holds stakes.
+    pub fn has_open_market(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, u32>(&DataKey::OpenMarketCount)
+            .unwrap_or(0)
+            > 0
+    }
+
+    /// Keep the count behind `has_open_market` in step with a market being
+    /// stored as `current` over `previous` (`None` for a new entry).
+    pub fn track_open_market(env: &Env, previous: Option<&Market>, current: &Market) {
+        let was_open = previous.is_some_and(Self::is_open_on_global_token);
+        let is_open = Self::is_open_on_global_token(current);
+        if was_open == is_open {
+            return;
+        }
+        let key = DataKey::OpenMarketCount;
+        let count: u32 = env.storage().instance().get(&key).unwrap_or(0);
+        let count = if is_open {
+            count.saturating_add(1)
+        } else {
+            count.saturating_sub(1)
+        };
+        env.storage().instance().set(&key, &count);
+    }
+
+    fn is_open_on_global_token(market: &Market) -> bool {
+        market.token.is_none()
+            && matches!(
+                market.state,
+                MarketState::Active
+                    | MarketState::BettingClosed
+                    | MarketState::Ended
+                    | MarketState::Disputed
+            )
+    }
+
     /// Calculates the payout amount for a winning user based on their stake and pool distribution.
     ///
     /// This function implements the payout algorithm for prediction markets,
@@ -1910,14 +2509,8 @@ impl MarketUtils {
             return Err(Error::NothingToClaim);
         }
 
-        let user_share = (user_stake
-            .checked_mul(100 - fee_percentage)
-            .ok_or(Error::InvalidInput)?)
-            / 100;
-        let payout = (user_share
-            .checked_mul(total_pool)
-            .ok_or(Error::InvalidInput)?)
-            / winning_total;
+        let user_share = crate::amounts::mul_div(user_stake, 100 - fee_percentage, 100)?;
+        let payout = crate::amounts::mul_div(user_share, total_pool, winning_total)?;
 
         Ok(payout)
     }
@@ -2048,18 +2641,8 @@ impl MarketUtils {
         let primary_result = Self::determine_final_result(env, oracle_result, community_consensus);
 
         // Check for ties by analyzing vote distribution
-        let mut outcome_votes: Map<String, u32> = Map::new(env);
-        let mut outcome_stakes: Map<String, i128> = Map::new(env);
-
-        // Count votes and stakes per outcome
-        for (user, outcome) in market.votes.iter() {
-            let vote_count = outcome_votes.get(outcome.clone()).unwrap_or(0);
-            outcome_votes.set(outcome.clone(), vote_count + 1);
-
-            let stake = market.stakes.get(user.clone()).unwrap_or(0);
-            let current_stake = outcome_stakes.get(outcome.clone()).unwrap_or(0);
-            outcome_stakes.set(outcome.clone(), current_stake + stake);
-        }
+        let outcome_votes = &market.outcome_positions;
+        let outcome_stakes = &market.outcome_pools;
 
         // Find outcomes with maximum votes
         let mut max_votes = 0;
@@ -2516,13 +3099,14 @@ impl MarketTestHelpers {
 
         // Transfer stake
         let token_client = MarketUtils::get_token_client(env)?;
-        token_client.transfer(&user, &env.current_contract_address(), &stake);
+        MarketUtils::receive_tokens(env, &token_client, &user, stake)?;
         // Transfer stake via centralized, guarded utility
         //  VotingUtils::transfer_stake(env, &user, stake)?;
 
         // Add vote
-        MarketStateManager::add_vote(&mut market, user, outcome, stake, None);
+        MarketStateManager::add_vote(env, market_id, &mut market, user, outcome, stake)?;
         MarketStateManager::update_market(env, market_id, &market);
+        crate::escrow::EscrowManager::credit(env, market_id, stake)?;
 
         Ok(())
     }
@@ -2606,7 +3190,7 @@ impl MarketTestHelpers {
 
         // Set winning outcome(s) - convert single outcome to vector
         let winning_outcomes = vec![env, final_result.clone()];
-        MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, None);
+        MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, None)?;
         MarketStateManager::update_market(env, market_id, &market);
 
         Ok(final_result)
@@ -2624,34 +3208,86 @@ impl MarketTestHelpers {
 pub struct MarketStateLogic;
 
 impl MarketStateLogic {
-    /// Validates that a market state transition is allowed by business rules.
+    /// Returns the state `event` moves a market in state `from` to.
     ///
-    /// This function enforces the market state machine by validating that
-    /// transitions between states follow the defined business logic. It prevents
-    /// invalid state changes that could compromise market integrity.
+    /// This is the market state machine: every stored state change goes through
+    /// it, via `transition`, so illegal moves such as resolving a cancelled
+    /// market or resolving a market twice are rejected in one place.
     ///
-    /// # Parameters
+    /// # Transition Table
     ///
-    /// * `from` - Current market state
-    /// * `to` - Target market state
+    /// * `CloseBetting`: `Active` → `BettingClosed`
+    /// * `End`: `Active`, `BettingClosed` → `Ended`
+    /// * `Dispute`: `Active`, `BettingClosed`, `Ended`, `Resolved` → `Disputed`
+    /// * `Resolve`: `Ended`, `Disputed` → `Resolved`
+    /// * `Overturn`: `Disputed` → `Resolved`
+    /// * `Settle`: `Resolved` → `Closed`
+    /// * `Cancel`: `Active`, `BettingClosed`, `Ended`, `Disputed` → `Cancelled`
+    /// * `Void`: `Active`, `BettingClosed`, `Ended`, `Disputed` → `Voided`
     ///
-    /// # Returns
+    /// `Closed`, `Cancelled` and `Voided` are terminal: no event moves a
+    /// market out of them.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - `event` is not allowed from `from`
+    pub fn next_state(from: MarketState, event: MarketEvent) -> Result<MarketState, Error> {
+        use MarketState::*;
+        let unresolved = matches!(from, Active | BettingClosed | Ended | Disputed);
+        let to = match event {
+            MarketEvent::CloseBetting if from == Active => BettingClosed,
+            MarketEvent::End if matches!(from, Active | BettingClosed) => Ended,
+            MarketEvent::Dispute if matches!(from, Active | BettingClosed | Ended | Resolved) => {
+                Disputed
+            }
+            MarketEvent::Resolve if matches!(from, Ended | Disputed) => Resolved,
+            MarketEvent::Overturn if from == Disputed => Resolved,
+            MarketEvent::Settle if from == Resolved => Closed,
+            MarketEvent::Cancel if unresolved => Cancelled,
+            MarketEvent::Void if unresolved => Voided,
+            _ => return Err(Error::InvalidState),
+        };
+        Ok(to)
+    }
+
+    /// Applies `event` to `market`, storing and returning its new state.
     ///
-    /// * `Ok(())` - Transition is valid and allowed
-    /// * `Err(Error)` - Transition is not allowed
+    /// The market is left unchanged if the move is not in the `next_state`
+    /// table. Callers persist the market and emit the state change event.
     ///
     /// # Errors
     ///
-    /// * `Error::InvalidState` - The requested state transition is not allowed
+    /// * `Error::InvalidState` - `event` is not allowed from the market's state
+    pub fn transition(market: &mut Market, event: MarketEvent) -> Result<MarketState, Error> {
+        market.state = Self::next_state(market.state, event)?;
+        Ok(market.state)
+    }
+
+    /// Ends a market whose end time has passed, or closes its betting once
+    /// the betting deadline has passed, if its stored state still lags behind.
+    ///
+    /// Time-driven states are stored lazily, by the first operation that needs
+    /// them. Returns the state the market is left in.
+    pub fn advance_by_time(env: &Env, market: &mut Market) -> Result<MarketState, Error> {
+        let now = env.ledger().timestamp();
+        if matches!(
+            market.state,
+            MarketState::Active | MarketState::BettingClosed
+        ) && now >= market.end_time
+        {
+            Self::transition(market, MarketEvent::End)?;
+        } else if market.state == MarketState::Active && now >= market.betting_deadline() {
+            Self::transition(market, MarketEvent::CloseBetting)?;
+        }
+        Ok(market.state)
+    }
+
+    /// Validates that a market can move from `from` to `to`, i.e. that some
+    /// `MarketEvent` takes it there in the `next_state` table.
     ///
-    /// # Valid State Transitions
+    /// # Errors
     ///
-    /// * `Active` → `Ended`, `Cancelled`, `Closed`, `Disputed`
-    /// * `Ended` → `Resolved`, `Disputed`, `Closed`, `Cancelled`
-    /// * `Disputed` → `Resolved`, `Closed`, `Cancelled`
-    /// * `Resolved` → `Closed`
-    /// * `Closed` → (no transitions allowed)
-    /// * `Cancelled` → (no transitions allowed)
+    /// * `Error::InvalidState` - The requested state transition is not allowed
     ///
     /// # Example
     ///
@@ -2672,16 +3308,10 @@ impl MarketStateLogic {
     /// ).is_err());
     /// ```
     pub fn validate_state_transition(from: MarketState, to: MarketState) -> Result<(), Error> {
-        use MarketState::*;
-        let allowed = match from {
-            Active => matches!(to, Ended | Cancelled | Closed | Disputed),
-            Ended => matches!(to, Resolved | Disputed | Closed | Cancelled),
-            Disputed => matches!(to, Resolved | Closed | Cancelled),
-            Resolved => matches!(to, Closed),
-            Closed => false,
-            Cancelled => false,
-        };
-        if allowed {
+        let reachable = MarketEvent::ALL
+            .iter()
+            .any(|event| Self::next_state(from, *event) == Ok(to));
+        if reachable {
             Ok(())
         } else {
             Err(Error::InvalidState)
@@ -2716,7 +3346,7 @@ impl MarketStateLogic {
     /// * **dispute**: Only allowed in `Ended` state
     /// * **resolve**: Allowed in `Ended` or `Disputed` states
     /// * **claim**: Only allowed in `Resolved` state
-    /// * **close**: Allowed in `Resolved`, `Cancelled`, or `Closed` states
+    /// * **close**: Allowed in `Resolved`, `Cancelled`, `Voided` or `Closed` states
     /// * **other**: All other functions are allowed by default
     ///
     /// # Example
@@ -2748,7 +3378,7 @@ impl MarketStateLogic {
             "dispute" => matches!(state, Ended),
             "resolve" => matches!(state, Ended | Disputed),
             "claim" => matches!(state, Resolved),
-            "close" => matches!(state, Resolved | Cancelled | Closed),
+            "close" => matches!(state, Resolved | Cancelled | Voided | Closed),
             _ => true, // By default allow
         };
         if allowed {
@@ -2774,8 +3404,8 @@ impl MarketStateLogic {
     /// # Event Structure
     ///
     /// The event is published with:
-    /// - **Topic**: `("market_state_change", market_id)`
-    /// - **Data**: `(old_state, new_state)`
+    /// - **Topic**: `("mkt_state", EVENT_SCHEMA_VERSION, market_id)`
+    /// - **Data**: [`MarketStateChangedEvent`](crate::events::MarketStateChangedEvent)
     ///
     /// # Example
     ///
@@ -2803,8 +3433,18 @@ impl MarketStateLogic {
         old_state: MarketState,
         new_state: MarketState,
     ) {
-        env.events()
-            .publish(("market_state_change", market_id), (old_state, new_state));
+        env.events().publish(
+            (
+                symbol_short!("mkt_state"),
+                crate::events::EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+            ),
+            crate::events::MarketStateChangedEvent {
+                market_id: market_id.clone(),
+                old_state,
+                new_state,
+            },
+        );
     }
 
     /// Validates that a market's state is consistent with its internal data.
@@ -2830,10 +3470,12 @@ impl MarketStateLogic {
     /// # Consistency Rules
     ///
     /// * **Active**: Must not be expired, must not have winning outcome
+    /// * **BettingClosed**: Must be past the betting deadline but not expired,
+    ///   must not have winning outcome
     /// * **Ended**: Must be expired, must not have winning outcome
     /// * **Disputed**: Must have dispute stakes
     /// * **Resolved**: Must have winning outcome set
-    /// * **Closed/Cancelled**: No specific data requirements
+    /// * **Closed/Cancelled/Voided**: No specific data requirements
     ///
     /// # Example
     ///
@@ -2863,6 +3505,14 @@ impl MarketStateLogic {
                     return Err(Error::InvalidState);
                 }
             }
+            BettingClosed => {
+                if market.betting_deadline() > now || market.end_time <= now {
+                    return Err(Error::InvalidState);
+                }
+                if market.winning_outcomes.is_some() {
+                    return Err(Error::InvalidState);
+                }
+            }
             Ended => {
                 if market.end_time > now {
                     return Err(Error::InvalidState);
@@ -2881,7 +3531,7 @@ impl MarketStateLogic {
                     return Err(Error::InvalidState);
                 }
             }
-            Closed | Cancelled => {}
+            Closed | Cancelled | Voided => {}
         }
         Ok(())
     }
@@ -2933,6 +3583,32 @@ impl MarketStateLogic {
         Ok(market.state)
     }
 
+    /// Derives a market's effective state from its fields and the ledger time.
+    ///
+    /// Time-driven states are only stored when an operation needs them, so an
+    /// `Active` or `BettingClosed` market past its `end_time` is reported as
+    /// `Ended`, an `Active` market past its betting deadline as `BettingClosed`,
+    /// a resolved market with an open result dispute as `Disputed`, and a market
+    /// with winning outcomes as `Resolved`. Terminal states and `Disputed` are
+    /// reported as stored.
+    pub fn derive_market_state(env: &Env, market_id: &Symbol, market: &Market) -> MarketState {
+        let now = env.ledger().timestamp();
+        match market.state {
+            state if state.is_terminal() || state == MarketState::Disputed => state,
+            _ if crate::disputes::DisputeUtils::has_open_result_dispute(env, market_id) => {
+                MarketState::Disputed
+            }
+            _ if market.winning_outcomes.is_some() => MarketState::Resolved,
+            MarketState::Active | MarketState::BettingClosed if now >= market.end_time => {
+                MarketState::Ended
+            }
+            MarketState::Active if now >= market.betting_deadline() => {
+                MarketState::BettingClosed
+            }
+            state => state,
+        }
+    }
+
     /// Checks if a market can transition to a specific target state.
     ///
     /// This function determines whether a state transition is possible for
@@ -3270,7 +3946,7 @@ impl MarketPauseManager {
         }
 
         env.storage().persistent().remove(&market_id);
-        Self::emit_resume_event(env, market_id, &admin);
+        Self::emit_resume_event(env, market_id, Some(&admin));
 
         Ok(())
     }
@@ -3298,6 +3974,7 @@ impl MarketPauseManager {
     /// # Valid States for Pausing
     ///
     /// * Active - Market is accepting votes
+    /// * BettingClosed - Betting has closed before the end time
     /// * Ended - Market has ended but not resolved
     /// * Disputed - Market is under dispute
     pub fn validate_pause_conditions(
@@ -3310,7 +3987,10 @@ impl MarketPauseManager {
         }
 
         match market.state {
-            MarketState::Active | MarketState::Ended | MarketState::Disputed => Ok(()),
+            MarketState::Active
+            | MarketState::BettingClosed
+            | MarketState::Ended
+            | MarketState::Disputed => Ok(()),
             _ => Err(Error::InvalidState),
         }
     }
@@ -3365,8 +4045,7 @@ impl MarketPauseManager {
                     env.storage().persistent().remove(&market_id);
 
                     // Emit auto-resume event
-                    env.events()
-                        .publish(("market_auto_resumed", market_id), current_time);
+                    Self::emit_resume_event(env, market_id, None);
 
                     return Ok(true);
                 }
@@ -3428,8 +4107,8 @@ impl MarketPauseManager {
 
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::Unauthorized)?;
 
         if admin != &stored_admin {
@@ -3441,15 +4120,33 @@ impl MarketPauseManager {
 
     fn emit_pause_event(env: &Env, market_id: &Symbol, duration: u32, admin: &Address) {
         env.events().publish(
-            ("market_paused", market_id),
-            (duration, admin, env.ledger().timestamp()),
+            (
+                symbol_short!("mkt_pause"),
+                crate::events::EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+            ),
+            crate::events::MarketPausedEvent {
+                market_id: market_id.clone(),
+                duration_hours: duration,
+                admin: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
         );
     }
 
-    fn emit_resume_event(env: &Env, market_id: &Symbol, admin: &Address) {
+    /// `admin` is `None` when the pause expired and the market auto-resumed.
+    fn emit_resume_event(env: &Env, market_id: &Symbol, admin: Option<&Address>) {
         env.events().publish(
-            ("market_resumed", market_id),
-            (admin, env.ledger().timestamp()),
+            (
+                symbol_short!("mkt_resum"),
+                crate::events::EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+            ),
+            crate::events::MarketResumedEvent {
+                market_id: market_id.clone(),
+                admin: admin.cloned(),
+                timestamp: env.ledger().timestamp(),
+            },
         );
     }
 }
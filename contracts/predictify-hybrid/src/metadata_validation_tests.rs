@@ -16,9 +16,9 @@ extern crate alloc;
 use alloc::format;
 
 use crate::config;
-use crate::types::{OracleConfig, OracleProvider};
+use crate::types::{OracleConfig, OracleProvider, OracleSigner};
 use crate::validation::{InputValidator, ValidationError};
-use crate::PredictifyHybridClient;
+use crate::{Error, PredictifyHybridClient};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
     vec, Address, Env, String, Vec,
@@ -65,6 +65,9 @@ impl MetadataTest {
             feed_id: String::from_str(&self.env, "BTC/USD"),
             threshold: 100_000_00,
             comparison: String::from_str(&self.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         }
     }
 }
@@ -175,8 +178,8 @@ fn test_outcome_length_at_minimum() {
 fn test_outcome_length_at_maximum() {
     let test = MetadataTest::setup();
     
-    // Outcome at exactly maximum length (100 characters)
-    let long_outcome = "A".repeat(100);
+    // Outcome at exactly maximum length (32 characters)
+    let long_outcome = "A".repeat(32);
     let outcome = String::from_str(&test.env, &long_outcome);
     
     let result = InputValidator::validate_outcome_length(&outcome);
@@ -199,8 +202,8 @@ fn test_outcome_length_too_short() {
 fn test_outcome_length_too_long() {
     let test = MetadataTest::setup();
     
-    // Outcome above maximum length (> 100 characters)
-    let long_outcome = "A".repeat(101);
+    // Outcome above maximum length (> 32 characters)
+    let long_outcome = "A".repeat(33);
     let outcome = String::from_str(&test.env, &long_outcome);
     
     let result = InputValidator::validate_outcome_length(&outcome);
@@ -456,7 +459,6 @@ fn test_create_market_with_valid_metadata() {
 }
 
 #[test]
-#[should_panic(expected = "#300")]
 fn test_create_market_with_short_question() {
     let test = MetadataTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -467,22 +469,24 @@ fn test_create_market_with_short_question() {
     let oracle_config = test.create_valid_oracle_config();
     
     test.env.mock_all_auths();
-    client.create_market(
-        &test.admin,
-        &question,
-        &outcomes,
-        &30,
-        &oracle_config,
-        &None,
-        &604800,
-        &None, // min_pool_size
-        &None, // bet_deadline_mins_before_end
-        &None, // dispute_window_seconds
+    assert_eq!(
+        client.try_create_market(
+            &test.admin,
+            &question,
+            &outcomes,
+            &30,
+            &oracle_config,
+            &None,
+            &604800,
+            &None, // min_pool_size
+            &None, // bet_deadline_mins_before_end
+            &None, // dispute_window_seconds
+        ),
+        Err(Ok(Error::InvalidQuestion))
     );
 }
 
 #[test]
-#[should_panic(expected = "#300")]
 fn test_create_market_with_long_question() {
     let test = MetadataTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -494,22 +498,24 @@ fn test_create_market_with_long_question() {
     let oracle_config = test.create_valid_oracle_config();
     
     test.env.mock_all_auths();
-    client.create_market(
-        &test.admin,
-        &question,
-        &outcomes,
-        &30,
-        &oracle_config,
-        &None,
-        &604800,
-        &None, // min_pool_size
-        &None, // bet_deadline_mins_before_end
-        &None, // dispute_window_seconds
+    assert_eq!(
+        client.try_create_market(
+            &test.admin,
+            &question,
+            &outcomes,
+            &30,
+            &oracle_config,
+            &None,
+            &604800,
+            &None, // min_pool_size
+            &None, // bet_deadline_mins_before_end
+            &None, // dispute_window_seconds
+        ),
+        Err(Ok(Error::InvalidQuestion))
     );
 }
 
 #[test]
-#[should_panic(expected = "#301")]
 fn test_create_market_with_short_outcome() {
     let test = MetadataTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -524,48 +530,53 @@ fn test_create_market_with_short_outcome() {
     let oracle_config = test.create_valid_oracle_config();
     
     test.env.mock_all_auths();
-    client.create_market(
-        &test.admin,
-        &question,
-        &outcomes,
-        &30,
-        &oracle_config,
-        &None,
-        &604800,
-        &None, // min_pool_size
-        &None, // bet_deadline_mins_before_end
-        &None, // dispute_window_seconds
+    assert_eq!(
+        client.try_create_market(
+            &test.admin,
+            &question,
+            &outcomes,
+            &30,
+            &oracle_config,
+            &None,
+            &604800,
+            &None, // min_pool_size
+            &None, // bet_deadline_mins_before_end
+            &None, // dispute_window_seconds
+        ),
+        Err(Ok(Error::InvalidOutcome))
     );
 }
 
 #[test]
-#[should_panic(expected = "#301")]
 fn test_create_market_with_long_outcome() {
     let test = MetadataTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
     
     // Create market with too long outcome
     let question = String::from_str(&test.env, "Will Bitcoin reach $100,000?");
-    let long_outcome = "A".repeat(101);
+    let long_outcome = "A".repeat(33);
     let outcomes = vec![
         &test.env,
         String::from_str(&test.env, "Yes"),
-        String::from_str(&test.env, &long_outcome), // 101 characters - too long
+        String::from_str(&test.env, &long_outcome), // 33 characters - too long
     ];
     let oracle_config = test.create_valid_oracle_config();
     
     test.env.mock_all_auths();
-    client.create_market(
-        &test.admin,
-        &question,
-        &outcomes,
-        &30,
-        &oracle_config,
-        &None,
-        &604800,
-        &None, // min_pool_size
-        &None, // bet_deadline_mins_before_end
-        &None, // dispute_window_seconds
+    assert_eq!(
+        client.try_create_market(
+            &test.admin,
+            &question,
+            &outcomes,
+            &30,
+            &oracle_config,
+            &None,
+            &604800,
+            &None, // min_pool_size
+            &None, // bet_deadline_mins_before_end
+            &None, // dispute_window_seconds
+        ),
+        Err(Ok(Error::InvalidOutcome))
     );
 }
 
@@ -4,7 +4,7 @@ use alloc::format;
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::errors::Error;
-use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
+use crate::types::{Market, MarketState, OracleConfig, OracleProvider, OracleSigner};
 
 /// Comprehensive monitoring system for Predictify contract health and performance.
 ///
@@ -365,17 +365,12 @@ impl ContractMonitor {
     pub fn emit_monitoring_alert(env: &Env, alert: MonitoringAlert) -> Result<(), Error> {
         // Emit alert event
         env.events().publish(
-            (Symbol::new(env, "monitoring_alert"),),
             (
+                Symbol::new(env, "monitoring_alert"),
+                crate::events::EVENT_SCHEMA_VERSION,
                 alert.alert_id.clone(),
-                alert.alert_type.clone(),
-                alert.severity.clone(),
-                alert.title.clone(),
-                alert.description.clone(),
-                alert.affected_component.clone(),
-                alert.timestamp,
-                alert.resolved,
             ),
+            alert.clone(),
         );
 
         // Store alert in persistent storage
@@ -436,6 +431,10 @@ impl ContractMonitor {
                 env,
                 "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
             ),
+            creator: Address::from_str(
+                env,
+                "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+            ),
             question: String::from_str(env, "Sample Market"),
             outcomes: Vec::new(env),
             end_time: env.ledger().timestamp() + 86400,
@@ -448,14 +447,14 @@ impl ContractMonitor {
                 feed_id: String::from_str(env, "sample_feed"),
                 threshold: 100,
                 comparison: String::from_str(env, ">="),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             has_fallback: false,
             fallback_oracle_config: OracleConfig::none_sentinel(env),
             resolution_timeout: 86400,
             oracle_result: None,
-            votes: Map::new(env),
-            stakes: Map::new(env),
-            claimed: Map::new(env),
             total_staked: 0,
             dispute_stakes: Map::new(env),
             winning_outcomes: None,
@@ -467,8 +466,23 @@ impl ContractMonitor {
             category: None,
             tags: Vec::new(env),
             min_pool_size: None,
+            max_pool_size: None,
             bet_deadline: 0,
             dispute_window_seconds: 86400,
+            resolved_at: None,
+            payout_unlock_at: None,
+            token: None,
+            fee_bps: crate::config::DEFAULT_PLATFORM_FEE_BPS,
+            creator_fee_bps: 0,
+            resolve_mode: crate::types::ResolveMode::Standard,
+            community_result: None,
+            frozen: false,
+            market_type: crate::types::MarketType::Categorical,
+            scalar_value: None,
+            closed_early_at: None,
+            resolution_deadline: 0,
+            outcome_pools: Map::new(env),
+            outcome_positions: Map::new(env),
         })
     }
 
@@ -0,0 +1,249 @@
+#![cfg(test)]
+
+//! Multi-oracle median resolution tests.
+//!
+//! A market's own oracle plus up to two extra primary oracles are queried at
+//! resolution; a majority must answer and the median price decides the outcome.
+
+use crate::errors::Error;
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{
+    Market, MarketState, OracleConfig, OracleProvider, OracleSigner, PythPriceData,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Ledger, LedgerInfo};
+use soroban_sdk::{vec, Address, String, Symbol, Vec};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+const RESOLUTION_TIMEOUT: u64 = 3600;
+const THRESHOLD: i128 = 2_500_000_000_000; // $25,000 in 8-decimal units
+
+fn pyth_config(test: &PredictifyTest, oracle: &Address) -> OracleConfig {
+    OracleConfig {
+        provider: OracleProvider::Pyth,
+        oracle_address: oracle.clone(),
+        feed_id: String::from_str(&test.env, BTC_FEED),
+        threshold: THRESHOLD,
+        comparison: String::from_str(&test.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
+    }
+}
+
+/// Create a market resolved by three mock Pyth oracles.
+fn create_three_oracle_market(test: &PredictifyTest) -> (Symbol, [Address; 3]) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracles = [
+        test.env.register(MockPythContract, ()),
+        test.env.register(MockPythContract, ()),
+        test.env.register(MockPythContract, ()),
+    ];
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &pyth_config(test, &oracles[0]),
+        &None,
+        &RESOLUTION_TIMEOUT,
+        &None,
+        &None,
+        &None,
+    );
+    client.set_extra_oracles(
+        &test.admin,
+        &market_id,
+        &vec![
+            &test.env,
+            pyth_config(test, &oracles[1]),
+            pyth_config(test, &oracles[2]),
+        ],
+    );
+    (market_id, oracles)
+}
+
+fn set_time_after_end(test: &PredictifyTest, market_id: &Symbol, seconds: u64) {
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+}
+
+fn publish_price(test: &PredictifyTest, oracle: &Address, price: i64) {
+    MockPythContractClient::new(&test.env, oracle).set_feed_price(
+        &String::from_str(&test.env, BTC_FEED),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: test.env.ledger().timestamp(),
+        },
+    );
+}
+
+#[test]
+fn test_median_of_three_divergent_oracles() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, oracles) = create_three_oracle_market(&test);
+    assert_eq!(client.get_market_oracles(&market_id).len(), 3);
+
+    set_time_after_end(&test, &market_id, 1);
+    // The mean ($29,500) is above the threshold but the median ($24,500) is not
+    publish_price(&test, &oracles[0], 2_400_000_000_000);
+    publish_price(&test, &oracles[1], 4_000_000_000_000);
+    publish_price(&test, &oracles[2], 2_450_000_000_000);
+
+    let result = client.fetch_oracle_result(&market_id);
+    assert_eq!(result.price, 2_450_000_000_000);
+    assert_eq!(result.outcome, String::from_str(&test.env, "no"));
+    assert_eq!(result.sources_count, 3);
+
+    client.resolve_market(&market_id);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(
+        market.winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "no")])
+    );
+}
+
+#[test]
+fn test_one_offline_oracle_still_reaches_quorum() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, oracles) = create_three_oracle_market(&test);
+
+    set_time_after_end(&test, &market_id, 1);
+    // oracles[1] never publishes
+    publish_price(&test, &oracles[0], 2_400_000_000_000);
+    publish_price(&test, &oracles[2], 2_800_000_000_000);
+
+    let result = client.fetch_oracle_result(&market_id);
+    assert_eq!(result.price, 2_600_000_000_000);
+    assert_eq!(result.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(result.sources_count, 2);
+}
+
+#[test]
+fn test_below_quorum_retries_then_falls_through_to_manual() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, oracles) = create_three_oracle_market(&test);
+
+    set_time_after_end(&test, &market_id, 1);
+    publish_price(&test, &oracles[0], 2_600_000_000_000);
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::OracleNoConsensus))
+    );
+    assert!(client
+        .get_market(&market_id)
+        .unwrap()
+        .oracle_result
+        .is_none());
+
+    set_time_after_end(&test, &market_id, RESOLUTION_TIMEOUT + 1);
+    client.resolve_market(&market_id);
+    let market = client.get_market(&market_id).unwrap();
+    assert!(market.oracle_result.is_none());
+    assert_eq!(market.state, MarketState::Ended);
+
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+}
+
+#[test]
+fn test_all_oracles_offline() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _) = create_three_oracle_market(&test);
+
+    set_time_after_end(&test, &market_id, 1);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleNoConsensus))
+    );
+    assert!(client.get_oracle_result(&market_id).is_none());
+
+    set_time_after_end(&test, &market_id, RESOLUTION_TIMEOUT + 1);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::ResolutionTimeoutReached))
+    );
+}
+
+#[test]
+fn test_set_extra_oracles_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let market = client.get_market(&market_id).unwrap();
+    let oracle = test.env.register(MockPythContract, ());
+    let mut matching = pyth_config(&test, &oracle);
+    matching.threshold = market.oracle_config.threshold;
+    matching.comparison = market.oracle_config.comparison.clone();
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_extra_oracles(&test.user, &market_id, &vec![&test.env, matching.clone()]),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let mut too_many = Vec::new(&test.env);
+    for _ in 0..3 {
+        too_many.push_back(matching.clone());
+    }
+    assert_eq!(
+        client.try_set_extra_oracles(&test.admin, &market_id, &too_many),
+        Err(Ok(Error::ThresholdTooHigh))
+    );
+
+    let mut other_threshold = matching.clone();
+    other_threshold.threshold += 1;
+    assert_eq!(
+        client.try_set_extra_oracles(&test.admin, &market_id, &vec![&test.env, other_threshold]),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+
+    client.set_extra_oracles(&test.admin, &market_id, &vec![&test.env, matching.clone()]);
+    assert_eq!(client.get_market_oracles(&market_id).len(), 2);
+    client.set_extra_oracles(&test.admin, &market_id, &Vec::new(&test.env));
+    assert_eq!(client.get_market_oracles(&market_id).len(), 1);
+
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+    );
+    assert_eq!(
+        client.try_set_extra_oracles(&test.admin, &market_id, &vec![&test.env, matching]),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+}
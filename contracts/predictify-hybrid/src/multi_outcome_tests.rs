@@ -0,0 +1,475 @@
+#![cfg(test)]
+
+//! Markets with more than two outcomes.
+//!
+//! Outcome lists of 2 to the configured maximum (10 by default) distinct,
+//! non-empty entries are accepted; bets, manual resolution and payouts work
+//! against the full list, and only backers of the winning outcome share the
+//! pool, pro rata within that outcome. Tied
+//! resolutions split the losing pool across the winning outcomes by stake;
+//! resolving to an outcome nobody backed refunds every stake without a fee.
+
+use crate::errors::Error;
+use crate::events::EVENT_SCHEMA_VERSION;
+use crate::test::PredictifyTest;
+use crate::types::{OracleConfig, OracleProvider, OracleSigner, ReflectorAsset};
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{symbol_short, token, vec, Address, IntoVal, String, Symbol, Vec};
+
+fn outcomes(test: &PredictifyTest, names: &[&str]) -> Vec<String> {
+    let mut outcomes = Vec::new(&test.env);
+    for name in names.iter() {
+        outcomes.push_back(String::from_str(&test.env, name));
+    }
+    outcomes
+}
+
+fn try_create(test: &PredictifyTest, outcomes: &Vec<String>) -> Result<Symbol, Error> {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client
+        .try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Which asset will lead the quarter?"),
+            outcomes,
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "BTC"),
+                threshold: 2500000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+/// Places each `(amount, outcome)` bet from a fresh funded user and returns
+/// `(user, token balance before betting)` per bet.
+fn place_stakes(
+    test: &PredictifyTest,
+    market_id: &Symbol,
+    stakes: &[(i128, &str)],
+) -> Vec<(Address, i128)> {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let mut bettors = Vec::new(&test.env);
+    test.env.mock_all_auths();
+    for (amount, outcome) in stakes.iter() {
+        let user = test.create_funded_user();
+        let before = token_client.balance(&user);
+        client.place_bet(
+            &user,
+            market_id,
+            &String::from_str(&test.env, outcome),
+            amount,
+            &None,
+        );
+        bettors.push_back((user, before));
+    }
+    bettors
+}
+
+/// Distributes payouts after the dispute window, then checks every bettor's
+/// credited payout and token balance after withdrawing it.
+fn assert_payouts(
+    test: &PredictifyTest,
+    market_id: &Symbol,
+    bettors: &Vec<(Address, i128)>,
+    stakes: &[(i128, &str)],
+    expected: &[i128],
+) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let market = client.get_market(market_id).unwrap();
+    test.set_time(market.end_time + market.dispute_window_seconds + 1);
+    let (paid, _) = client.distribute_payouts(market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+
+    assert_eq!(paid, expected.iter().sum::<i128>());
+    for (i, (user, before)) in bettors.iter().enumerate() {
+        let payout = expected[i];
+        assert_eq!(
+            client.get_balance(&user, &ReflectorAsset::Stellar).amount,
+            payout
+        );
+        if payout > 0 {
+            client.withdraw(&user, &ReflectorAsset::Stellar, &payout);
+        }
+        assert_eq!(token_client.balance(&user), before - stakes[i].0 + payout);
+    }
+}
+
+#[test]
+fn test_outcome_list_validation() {
+    let test = PredictifyTest::setup();
+
+    let ten = outcomes(
+        &test,
+        &["o1", "o2", "o3", "o4", "o5", "o6", "o7", "o8", "o9", "o10"],
+    );
+    assert!(try_create(&test, &ten).is_ok());
+
+    let mut eleven = ten.clone();
+    eleven.push_back(String::from_str(&test.env, "o11"));
+    assert_eq!(try_create(&test, &eleven), Err(Error::ThresholdTooHigh));
+
+    let duplicate = outcomes(&test, &["alpha", "beta", "gamma", "beta"]);
+    assert_eq!(try_create(&test, &duplicate), Err(Error::InvalidOutcomes));
+
+    let empty = outcomes(&test, &["alpha", "", "gamma"]);
+    assert_eq!(try_create(&test, &empty), Err(Error::InvalidOutcome));
+}
+
+#[test]
+fn test_outcome_length_limit() {
+    let test = PredictifyTest::setup();
+    let at_limit = "a".repeat(crate::config::MAX_OUTCOME_LENGTH as usize);
+    let over_limit = "a".repeat(crate::config::MAX_OUTCOME_LENGTH as usize + 1);
+
+    assert!(try_create(&test, &outcomes(&test, &[&at_limit, "no"])).is_ok());
+    assert_eq!(
+        try_create(&test, &outcomes(&test, &[&over_limit, "no"])),
+        Err(Error::InvalidOutcome)
+    );
+}
+
+#[test]
+fn test_configurable_max_outcomes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(
+        client.get_max_market_outcomes(),
+        crate::config::MAX_MARKET_OUTCOMES
+    );
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_max_market_outcomes(&test.user, &12),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_max_market_outcomes(&test.admin, &1),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_max_market_outcomes(
+            &test.admin,
+            &(crate::config::MAX_CONFIGURABLE_MARKET_OUTCOMES + 1)
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.set_max_market_outcomes(&test.admin, &12);
+    assert_eq!(client.get_max_market_outcomes(), 12);
+
+    let names = [
+        "o1", "o2", "o3", "o4", "o5", "o6", "o7", "o8", "o9", "o10", "o11", "o12",
+    ];
+    let twelve = outcomes(&test, &names);
+    let market_id = try_create(&test, &twelve).unwrap();
+    assert_eq!(client.get_market(&market_id).unwrap().outcomes.len(), 12);
+
+    let mut thirteen = twelve.clone();
+    thirteen.push_back(String::from_str(&test.env, "o13"));
+    assert_eq!(try_create(&test, &thirteen), Err(Error::ThresholdTooHigh));
+
+    client.set_max_market_outcomes(&test.admin, &3);
+    assert_eq!(
+        try_create(&test, &outcomes(&test, &["a", "b", "c", "d"])),
+        Err(Error::ThresholdTooHigh)
+    );
+}
+
+#[test]
+fn test_bets_and_resolution_check_outcome_membership() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = try_create(
+        &test,
+        &outcomes(&test, &["alpha", "beta", "gamma", "delta"]),
+    )
+    .unwrap();
+    let unknown = String::from_str(&test.env, "omega");
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_place_bet(&test.user, &market_id, &unknown, &10_0000000, &None),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "gamma"),
+        &10_0000000,
+        &None,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    assert_eq!(
+        client.try_resolve_market_manual(&test.admin, &market_id, &unknown),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "gamma"),
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "gamma")])
+    );
+}
+
+#[test]
+fn test_four_outcome_market_pays_winning_pool_pro_rata() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let market_id = try_create(
+        &test,
+        &outcomes(&test, &["alpha", "beta", "gamma", "delta"]),
+    )
+    .unwrap();
+
+    // 100 XLM pool, 40 XLM on the winning outcome
+    let stakes: [(i128, &str); 5] = [
+        (10_0000000, "alpha"),
+        (30_0000000, "alpha"),
+        (25_0000000, "beta"),
+        (15_0000000, "gamma"),
+        (20_0000000, "delta"),
+    ];
+    let bettors = place_stakes(&test, &market_id, &stakes);
+    assert_eq!(token_client.balance(&test.contract_id), 100_0000000);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "alpha"),
+    );
+
+    // stake * 0.98 * 100 / 40: 24.5 XLM and 73.5 XLM; the 2 XLM fee stays in the contract
+    assert_payouts(
+        &test,
+        &market_id,
+        &bettors,
+        &stakes,
+        &[24_5000000, 73_5000000, 0, 0, 0],
+    );
+    assert_eq!(token_client.balance(&test.contract_id), 2_0000000);
+}
+
+#[test]
+fn test_tied_resolution_splits_pool_across_three_outcomes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let market_id = try_create(
+        &test,
+        &outcomes(&test, &["alpha", "beta", "gamma", "delta"]),
+    )
+    .unwrap();
+
+    // 100 XLM pool, 80 XLM across the three winning outcomes, 20 XLM losing pool
+    let stakes: [(i128, &str); 5] = [
+        (10_0000000, "alpha"),
+        (30_0000000, "alpha"),
+        (20_0000000, "beta"),
+        (20_0000000, "gamma"),
+        (20_0000000, "delta"),
+    ];
+    let bettors = place_stakes(&test, &market_id, &stakes);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    let winners = outcomes(&test, &["alpha", "beta", "gamma"]);
+    client.resolve_market_with_ties(&test.admin, &market_id, &winners);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(winners)
+    );
+
+    // alpha takes 40/80 of the 98 XLM net pool, beta and gamma 20/80 each;
+    // alpha's 49 XLM slice is shared 1:3 between its backers
+    assert_payouts(
+        &test,
+        &market_id,
+        &bettors,
+        &stakes,
+        &[12_2500000, 36_7500000, 24_5000000, 24_5000000, 0],
+    );
+    assert_eq!(token_client.balance(&test.contract_id), 2_0000000);
+}
+
+#[test]
+fn test_every_outcome_winning_refunds_stake_minus_fee() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = try_create(&test, &outcomes(&test, &["alpha", "beta", "gamma"])).unwrap();
+
+    let stakes: [(i128, &str); 3] = [
+        (10_0000000, "alpha"),
+        (50_0000000, "beta"),
+        (40_0000000, "gamma"),
+    ];
+    let bettors = place_stakes(&test, &market_id, &stakes);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_with_ties(
+        &test.admin,
+        &market_id,
+        &outcomes(&test, &["alpha", "beta", "gamma"]),
+    );
+
+    assert_payouts(
+        &test,
+        &market_id,
+        &bettors,
+        &stakes,
+        &[9_8000000, 49_0000000, 39_2000000],
+    );
+}
+
+#[test]
+fn test_unbacked_winning_outcome_takes_no_share() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = try_create(&test, &outcomes(&test, &["alpha", "beta", "gamma"])).unwrap();
+
+    // Nobody backs gamma
+    let stakes: [(i128, &str); 2] = [(25_0000000, "alpha"), (75_0000000, "beta")];
+    let bettors = place_stakes(&test, &market_id, &stakes);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_with_ties(
+        &test.admin,
+        &market_id,
+        &outcomes(&test, &["alpha", "gamma"]),
+    );
+
+    assert_payouts(&test, &market_id, &bettors, &stakes, &[98_0000000, 0]);
+}
+
+#[test]
+fn test_tied_resolution_rejects_invalid_winner_lists() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = try_create(&test, &outcomes(&test, &["alpha", "beta", "gamma"])).unwrap();
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_resolve_market_with_ties(&test.admin, &market_id, &Vec::new(&test.env)),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_resolve_market_with_ties(
+            &test.admin,
+            &market_id,
+            &outcomes(&test, &["alpha", "beta", "alpha"]),
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_resolve_market_with_ties(
+            &test.admin,
+            &market_id,
+            &outcomes(&test, &["alpha", "omega"]),
+        ),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    assert_eq!(
+        client.try_resolve_market_with_ties(
+            &test.user,
+            &market_id,
+            &outcomes(&test, &["alpha", "beta"]),
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_unbacked_resolution_refunds_every_stake() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = token::Client::new(&test.env, &test.token_test.token_id);
+    let market_id = try_create(&test, &outcomes(&test, &["alpha", "beta", "gamma"])).unwrap();
+
+    let stakes: [(i128, &str); 3] = [
+        (10_0000000, "alpha"),
+        (30_0000000, "alpha"),
+        (60_0000000, "beta"),
+    ];
+    let bettors = place_stakes(&test, &market_id, &stakes);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "gamma"),
+    );
+    let refund_events = test
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            contract == &test.contract_id
+                && topics
+                    == &(symbol_short!("refund"), EVENT_SCHEMA_VERSION, market_id.clone())
+                        .into_val(&test.env)
+        })
+        .count();
+    assert_eq!(refund_events, 1);
+
+    // No platform fee on a refunded market
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 0);
+
+    // One bettor pulls their refund, distribution pushes the rest
+    test.set_time(market.end_time + market.dispute_window_seconds + 1);
+    let (first, _) = bettors.get(0).unwrap();
+    assert_eq!(client.claim_winnings(&first, &market_id), 10_0000000);
+    assert!(
+        client
+            .get_user_position(&first, &market_id)
+            .unwrap()
+            .claimed
+    );
+    client.withdraw(&first, &ReflectorAsset::Stellar, &10_0000000);
+
+    let mut unclaimed = bettors.clone();
+    unclaimed.remove(0);
+    assert_payouts(
+        &test,
+        &market_id,
+        &unclaimed,
+        &stakes[1..],
+        &[30_0000000, 60_0000000],
+    );
+    for (i, (user, before)) in bettors.iter().enumerate() {
+        assert_eq!(
+            token_client.balance(&user),
+            before,
+            "bettor {} not made whole",
+            i
+        );
+    }
+    assert_eq!(token_client.balance(&test.contract_id), 0);
+}
@@ -0,0 +1,175 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::config::{
+    ADMIN_SIGNERS_STORAGE_KEY, MAX_ADMIN_SIGNERS, NEXT_MULTISIG_OP_ID_STORAGE_KEY,
+};
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::storage::DataKey;
+use crate::types::{AdminSigners, MultisigOp, MultisigProposal};
+
+/// M-of-N approval for admin operations that move funds or settle markets.
+///
+/// Until a signer set is stored the admin is the only signer with a threshold
+/// of one, so single-admin deployments behave as before. Once more than one
+/// approval is required, manual resolution, cancellation, fee withdrawal and
+/// upgrades are only reachable through a `MultisigOp` and their direct entry
+/// points refuse every caller. Executing an approved operation is left to the
+/// caller of `is_approved`.
+pub struct MultisigManager;
+
+impl MultisigManager {
+    /// Current signer set and threshold.
+    pub fn get_signers(env: &Env) -> AdminSigners {
+        if let Some(signers) = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(env, ADMIN_SIGNERS_STORAGE_KEY))
+        {
+            return signers;
+        }
+        let mut signers = Vec::new(env);
+        if let Some(admin) = env.storage().instance().get::<_, Address>(&DataKey::Admin) {
+            signers.push_back(admin);
+        }
+        AdminSigners {
+            signers,
+            threshold: 1,
+        }
+    }
+
+    /// Refuse direct calls to operations covered by the signers once more than
+    /// one approval is required.
+    pub fn require_single_signer(env: &Env) -> Result<(), Error> {
+        if Self::get_signers(env).threshold > 1 {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Non-empty set of at most `MAX_ADMIN_SIGNERS` distinct signers and a
+    /// threshold between one and the set size.
+    pub fn validate_signers(signers: &Vec<Address>, threshold: u32) -> Result<(), Error> {
+        if signers.is_empty() || signers.len() > MAX_ADMIN_SIGNERS {
+            return Err(Error::InvalidInput);
+        }
+        if threshold == 0 || threshold > signers.len() {
+            return Err(Error::InvalidInput);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.iter().skip(i + 1).any(|other| other == signer) {
+                return Err(Error::InvalidInput);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn store_signers(env: &Env, signers: Vec<Address>, threshold: u32) {
+        env.storage().instance().set(
+            &Symbol::new(env, ADMIN_SIGNERS_STORAGE_KEY),
+            &AdminSigners { signers, threshold },
+        );
+    }
+
+    /// Propose `op` (signers only); the proposal carries the proposer's approval.
+    pub fn propose(
+        env: &Env,
+        signer: &Address,
+        op: MultisigOp,
+    ) -> Result<MultisigProposal, Error> {
+        Self::require_signer(env, signer)?;
+        match &op {
+            MultisigOp::SetSigners(signers, threshold) => {
+                Self::validate_signers(signers, *threshold)?;
+            }
+            MultisigOp::ResolveMarket(_, outcomes) => {
+                if outcomes.is_empty() {
+                    return Err(Error::InvalidInput);
+                }
+            }
+            MultisigOp::WithdrawFees(amount) => {
+                if *amount < 0 {
+                    return Err(Error::InvalidInput);
+                }
+            }
+            MultisigOp::CancelMarket(..) | MultisigOp::Upgrade(_) => {}
+        }
+
+        let id_key = Symbol::new(env, NEXT_MULTISIG_OP_ID_STORAGE_KEY);
+        let id: u64 = env.storage().instance().get(&id_key).unwrap_or(0);
+        env.storage().instance().set(&id_key, &(id + 1));
+
+        let mut approvals = Vec::new(env);
+        approvals.push_back(signer.clone());
+        let proposal = MultisigProposal {
+            id,
+            op,
+            proposer: signer.clone(),
+            approvals,
+            proposed_at: env.ledger().timestamp(),
+        };
+        Self::store_proposal(env, &proposal);
+
+        EventEmitter::emit_multisig_op_proposed(env, &proposal);
+        Ok(proposal)
+    }
+
+    /// Add `signer`'s approval to a pending proposal (signers only).
+    ///
+    /// # Errors
+    ///
+    /// - `Error::Unauthorized` - Caller is not a signer
+    /// - `Error::InvalidInput` - No pending proposal has this id
+    /// - `Error::AlreadyVoted` - Caller already approved it
+    pub fn approve(env: &Env, signer: &Address, op_id: u64) -> Result<MultisigProposal, Error> {
+        Self::require_signer(env, signer)?;
+        let mut proposal = Self::get_proposal(env, op_id).ok_or(Error::InvalidInput)?;
+        if proposal.approvals.contains(signer) {
+            return Err(Error::AlreadyVoted);
+        }
+        proposal.approvals.push_back(signer.clone());
+        Self::store_proposal(env, &proposal);
+
+        EventEmitter::emit_multisig_op_approved(env, op_id, signer, proposal.approvals.len());
+        Ok(proposal)
+    }
+
+    /// Whether approvals from current signers reach the threshold. Approvals
+    /// from signers removed since no longer count.
+    pub fn is_approved(env: &Env, proposal: &MultisigProposal) -> bool {
+        let signers = Self::get_signers(env);
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approver| signers.signers.contains(approver))
+            .count() as u32;
+        approvals >= signers.threshold
+    }
+
+    /// Pending proposal `op_id`; `None` once executed.
+    pub fn get_proposal(env: &Env, op_id: u64) -> Option<MultisigProposal> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("msig_op"), op_id))
+    }
+
+    pub(crate) fn remove_proposal(env: &Env, op_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("msig_op"), op_id));
+    }
+
+    fn store_proposal(env: &Env, proposal: &MultisigProposal) {
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("msig_op"), proposal.id), proposal);
+    }
+
+    fn require_signer(env: &Env, signer: &Address) -> Result<(), Error> {
+        signer.require_auth();
+        if !Self::get_signers(env).signers.contains(signer) {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+}
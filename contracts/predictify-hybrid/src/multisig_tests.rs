@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+//! Multisig admin tests.
+//!
+//! With a signer set of N and threshold M, manual resolution, cancellation,
+//! fee withdrawal and upgrades run only once M signers approve them through
+//! `propose_admin_op` and `approve_admin_op`. The default set is the admin
+//! alone with a threshold of one.
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, MarketState, MultisigOp, TimelockedAction};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{vec, Address, BytesN, String};
+
+/// Replace the admin-only signer set with three fresh signers, two of whom must approve
+fn two_of_three(test: &PredictifyTest) -> (Address, Address, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let a = Address::generate(&test.env);
+    let b = Address::generate(&test.env);
+    let c = Address::generate(&test.env);
+    let signers = vec![&test.env, a.clone(), b.clone(), c.clone()];
+    client.propose_admin_op(&test.admin, &MultisigOp::SetSigners(signers, 2));
+    (a, b, c)
+}
+
+/// Fund the fee vault with `amount` newly minted to the contract
+fn set_fee_vault(test: &PredictifyTest, amount: i128) {
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&test.contract_id, &amount);
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .set(&DataKey::FeeVault, &amount);
+    });
+}
+
+#[test]
+fn test_single_admin_mode_runs_operations_at_once() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let signers = client.get_admin_signers();
+    assert_eq!(signers.signers, vec![&test.env, test.admin.clone()]);
+    assert_eq!(signers.threshold, 1);
+
+    let market_id = test.create_test_market();
+    let op_id = client.propose_admin_op(
+        &test.admin,
+        &MultisigOp::CancelMarket(market_id.clone(), CancelReason::Administrative),
+    );
+    assert!(client.get_admin_op(&op_id).is_none());
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Cancelled
+    );
+
+    assert_eq!(
+        client.try_propose_admin_op(
+            &test.user,
+            &MultisigOp::CancelMarket(market_id, CancelReason::Administrative)
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_two_of_three_approve_fee_withdrawal() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let (a, b, _) = two_of_three(&test);
+    set_fee_vault(&test, 100);
+    let before = token.balance(&test.admin);
+
+    // The admin key alone can no longer withdraw
+    assert_eq!(
+        client.try_withdraw_fees(&test.admin, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let op_id = client.propose_admin_op(&a, &MultisigOp::WithdrawFees(0));
+    let pending = client.get_admin_op(&op_id).unwrap();
+    assert_eq!(pending.approvals, vec![&test.env, a.clone()]);
+    assert_eq!(token.balance(&test.admin), before);
+
+    assert!(client.approve_admin_op(&b, &op_id));
+    assert_eq!(token.balance(&test.admin), before + 100);
+    assert_eq!(client.get_fee_vault_balance(&None), 0);
+    assert!(client.get_admin_op(&op_id).is_none());
+    assert_eq!(
+        client.try_approve_admin_op(&b, &op_id),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn test_single_approval_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (a, _, _) = two_of_three(&test);
+    let market_id = test.create_test_market();
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    let yes = String::from_str(&test.env, "yes");
+
+    let op_id = client.propose_admin_op(
+        &a,
+        &MultisigOp::ResolveMarket(market_id.clone(), vec![&test.env, yes.clone()]),
+    );
+    assert_eq!(
+        client.try_approve_admin_op(&a, &op_id),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_approve_admin_op(&test.admin, &op_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_resolve_market_manual(&test.admin, &market_id, &yes),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Active
+    );
+    assert!(client.get_admin_op(&op_id).is_some());
+}
+
+#[test]
+fn test_upgrade_needs_signer_approval() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (_, b, c) = two_of_three(&test);
+    let wasm_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    assert_eq!(
+        client.try_schedule_action(&test.admin, &TimelockedAction::Upgrade(wasm_hash.clone())),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let op_id = client.propose_admin_op(&c, &MultisigOp::Upgrade(wasm_hash.clone()));
+    assert!(client.get_pending_actions().is_empty());
+    assert!(client.approve_admin_op(&b, &op_id));
+
+    // The approved upgrade still waits out the admin action delay
+    let pending = client.get_pending_actions();
+    assert_eq!(pending.len(), 1);
+    let scheduled = pending.get(0).unwrap();
+    assert_eq!(scheduled.action, TimelockedAction::Upgrade(wasm_hash));
+    assert_eq!(scheduled.scheduled_by, b);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::MarketNotReady))
+    );
+}
+
+#[test]
+fn test_signer_set_changes_need_threshold() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (a, b, c) = two_of_three(&test);
+
+    for (signers, threshold) in [
+        (vec![&test.env, a.clone(), b.clone()], 0),
+        (vec![&test.env, a.clone(), b.clone()], 3),
+        (vec![&test.env, a.clone(), a.clone()], 1),
+        (vec![&test.env], 1),
+    ] {
+        assert_eq!(
+            client.try_propose_admin_op(&a, &MultisigOp::SetSigners(signers, threshold)),
+            Err(Ok(Error::InvalidInput))
+        );
+    }
+
+    // A withdrawal only `c` approved stops counting once `c` is removed
+    let withdrawal = client.propose_admin_op(&c, &MultisigOp::WithdrawFees(0));
+    let signers = vec![&test.env, a.clone(), b.clone()];
+    let op_id = client.propose_admin_op(&a, &MultisigOp::SetSigners(signers.clone(), 2));
+    assert_eq!(client.get_admin_signers().signers.len(), 3);
+    assert!(client.approve_admin_op(&b, &op_id));
+    assert_eq!(client.get_admin_signers().signers, signers);
+
+    assert_eq!(
+        client.try_approve_admin_op(&c, &withdrawal),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(!client.approve_admin_op(&a, &withdrawal));
+}
@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+//! Native XLM tests.
+//!
+//! The staking token may be the native asset's Stellar Asset Contract, either
+//! globally (`set_token`) or for one market (`set_market_token`). The native
+//! asset cannot be minted, so bettors are funded from a classic account whose
+//! balance is written straight into the test ledger. Amounts are stroops.
+
+use crate::test::PredictifyTest;
+use crate::types::ReflectorAsset;
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use alloc::rc::Rc;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::xdr::{
+    AccountEntry, AccountEntryExt, AccountId, Asset, ContractExecutable, ContractIdPreimage,
+    CreateContractArgs, HostFunction, LedgerEntry, LedgerEntryData, LedgerEntryExt, LedgerKey,
+    LedgerKeyAccount, PublicKey, ScAddress, ScVal, SequenceNumber, Thresholds, Uint256,
+};
+use soroban_sdk::{Address, Env, String, Symbol, TryFromVal};
+
+/// 10,000 XLM
+const FUNDER_BALANCE: i64 = 100_000_000_000;
+/// Bettor stakes: 30 and 10 XLM on `yes`, 60 XLM on `no`
+const STAKES: [(i128, &str); 3] = [(300_000_000, "yes"), (100_000_000, "yes"), (600_000_000, "no")];
+
+/// Deploy the Stellar Asset Contract of the native asset.
+fn register_native_sac(env: &Env) -> Address {
+    let create = HostFunction::CreateContract(CreateContractArgs {
+        contract_id_preimage: ContractIdPreimage::Asset(Asset::Native),
+        executable: ContractExecutable::StellarAsset,
+    });
+    match env.host().invoke_function(create).unwrap() {
+        ScVal::Address(address) => Address::try_from_val(env, &address).unwrap(),
+        other => panic!("unexpected contract id {:?}", other),
+    }
+}
+
+/// Create the classic funding account, holding `balance` stroops of XLM.
+fn native_account(env: &Env, balance: i64) -> Address {
+    let account_id = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([7; 32])));
+    let key = Rc::new(LedgerKey::Account(LedgerKeyAccount {
+        account_id: account_id.clone(),
+    }));
+    let entry = Rc::new(LedgerEntry {
+        data: LedgerEntryData::Account(AccountEntry {
+            account_id: account_id.clone(),
+            balance,
+            flags: 0,
+            home_domain: Default::default(),
+            inflation_dest: None,
+            num_sub_entries: 0,
+            seq_num: SequenceNumber(0),
+            thresholds: Thresholds([1; 4]),
+            signers: Default::default(),
+            ext: AccountEntryExt::V0,
+        }),
+        last_modified_ledger_seq: 0,
+        ext: LedgerEntryExt::V0,
+    });
+    let budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| storage.put(&key, &entry, None, &budget))
+        .unwrap();
+    Address::try_from_val(env, &ScAddress::Account(account_id)).unwrap()
+}
+
+/// Fund a fresh bettor for each of `STAKES` and place the bets.
+fn place_stakes(
+    test: &PredictifyTest,
+    xlm: &TokenClient,
+    funder: &Address,
+    market_id: &Symbol,
+) -> [Address; 3] {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let bettors = [
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+        Address::generate(&test.env),
+    ];
+    for (bettor, (amount, outcome)) in bettors.iter().zip(STAKES.iter()) {
+        xlm.transfer(funder, bettor, amount);
+        client.place_bet(
+            bettor,
+            market_id,
+            &String::from_str(&test.env, outcome),
+            amount,
+            &None,
+        );
+        assert_eq!(xlm.balance(bettor), 0);
+    }
+    bettors
+}
+
+fn resolve_and_distribute(test: &PredictifyTest, market_id: &Symbol) -> i128 {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(market_id);
+    client.distribute_payouts(market_id, &0, &MAX_PAYOUT_PAGE_SIZE).0
+}
+
+#[test]
+fn test_native_xlm_as_global_token() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let xlm_id = register_native_sac(&test.env);
+    let xlm = TokenClient::new(&test.env, &xlm_id);
+    test.env.mock_all_auths();
+    test.switch_token(&xlm_id);
+
+    // Decimals come from the asset contract rather than being assumed
+    assert_eq!(
+        client.get_token_info(),
+        (xlm_id.clone(), 7, String::from_str(&test.env, "native"))
+    );
+
+    let funder = native_account(&test.env, FUNDER_BALANCE);
+    let market_id = test.create_test_market();
+
+    let bettors = place_stakes(&test, &xlm, &funder, &market_id);
+    assert_eq!(xlm.balance(&test.contract_id), 1_000_000_000);
+
+    // Winners share the 100 XLM pool less the 2% platform fee, 98 XLM, 3:1
+    assert_eq!(resolve_and_distribute(&test, &market_id), 980_000_000);
+    let credited = [735_000_000, 245_000_000, 0];
+    for (bettor, amount) in bettors.iter().zip(credited.iter()) {
+        assert_eq!(
+            client.get_balance(bettor, &ReflectorAsset::Stellar).amount,
+            *amount
+        );
+    }
+
+    client.withdraw(&bettors[0], &ReflectorAsset::Stellar, &735_000_000);
+    assert_eq!(xlm.balance(&bettors[0]), 735_000_000);
+
+    let vault_before = client.get_fee_vault_balance(&None);
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 20_000_000);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + 20_000_000);
+
+    let (escrow_total, vault_total, token_balance) = client.reconcile();
+    assert_eq!(escrow_total, 0);
+    assert_eq!(token_balance, 1_000_000_000 - 735_000_000);
+    assert!(token_balance >= vault_total + 245_000_000);
+}
+
+#[test]
+fn test_native_xlm_as_market_token() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let xlm_id = register_native_sac(&test.env);
+    let xlm = TokenClient::new(&test.env, &xlm_id);
+    let funder = native_account(&test.env, FUNDER_BALANCE);
+
+    let market_id = test.create_test_market();
+    client.set_market_token(&test.admin, &market_id, &xlm_id);
+    assert_eq!(client.get_market_token(&market_id), xlm_id);
+
+    let bettors = place_stakes(&test, &xlm, &funder, &market_id);
+    assert_eq!(xlm.balance(&test.contract_id), 1_000_000_000);
+
+    // Market-token payouts are transferred straight to the winners
+    assert_eq!(resolve_and_distribute(&test, &market_id), 980_000_000);
+    assert_eq!(xlm.balance(&bettors[0]), 735_000_000);
+    assert_eq!(xlm.balance(&bettors[1]), 245_000_000);
+    assert_eq!(xlm.balance(&bettors[2]), 0);
+
+    // The fee stays in the contract, in its own vault
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 20_000_000);
+    assert_eq!(client.get_fee_vault_balance(&Some(xlm_id.clone())), 20_000_000);
+    assert_eq!(xlm.balance(&test.contract_id), 20_000_000);
+    assert_eq!(
+        xlm.balance(&funder),
+        FUNDER_BALANCE as i128 - 1_000_000_000
+    );
+}
@@ -0,0 +1,328 @@
+//! Optimistic resolution.
+//!
+//! A market in `ResolveMode::Optimistic` is resolved by a bonded proposal
+//! instead of its oracle. Once the market has ended anyone may propose an
+//! outcome with a bond; the proposal stands unless someone posts the same bond
+//! against it within the market's dispute window. Unchallenged, the proposal
+//! resolves the market and the proposer earns a reward from the fee vault.
+//! Challenged, the decision goes to the market's arbiter (see
+//! [`DisputeUtils::require_arbiter`]) and whichever side it agrees with takes
+//! both bonds.
+//!
+//! Proposals and challenges are stored as [`Dispute`] records: the proposal
+//! carries the proposed outcome, the challenge none.
+
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::disputes::{Dispute, DisputeStatus, DisputeUtils};
+use crate::errors::Error;
+use crate::markets::{MarketStateManager, MarketUtils};
+use crate::types::{Market, MarketEvent, MarketState, ResolveMode};
+
+/// Bonded proposals and challenges of optimistically resolved markets.
+pub struct OptimisticResolutionManager;
+
+impl OptimisticResolutionManager {
+    /// Propose `outcome` as the result of an ended `Optimistic` market,
+    /// locking `bond` in the market's token.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - The market is not optimistic, or is underfunded
+    /// * `Error::MarketClosed` - The market has not ended or was cancelled
+    /// * `Error::MarketResolved` - The market already has a result
+    /// * `Error::InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// * `Error::InsufficientStake` - `bond` is below the minimum dispute stake
+    /// * `Error::AlreadyDisputed` - A result was already proposed
+    pub fn propose(
+        env: &Env,
+        proposer: Address,
+        market_id: Symbol,
+        outcome: String,
+        bond: i128,
+    ) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, &market_id)?;
+        if market.resolve_mode != ResolveMode::Optimistic {
+            return Err(Error::InvalidState);
+        }
+        if market.state.is_refunding() || env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if MarketUtils::is_underfunded(env, &market) {
+            return Err(Error::InvalidState);
+        }
+        if !market.outcomes.contains(&outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        crate::amounts::require_positive(bond)?;
+        if bond < DisputeUtils::get_min_result_dispute_stake(env) {
+            return Err(Error::InsufficientStake);
+        }
+        if Self::get_proposal(env, &market_id).is_some() {
+            return Err(Error::AlreadyDisputed);
+        }
+
+        let token_client = MarketUtils::get_market_token_client(env, &market)?;
+        MarketUtils::receive_tokens(env, &token_client, &proposer, bond)?;
+
+        let proposal = Dispute {
+            user: proposer.clone(),
+            market_id: market_id.clone(),
+            stake: bond,
+            timestamp: env.ledger().timestamp(),
+            reason: None,
+            status: DisputeStatus::Active,
+            proposed_outcome: Some(outcome.clone()),
+        };
+        Self::store(env, &symbol_short!("opt_prop"), &proposal);
+        crate::escrow::EscrowManager::credit(env, &market_id, bond)?;
+
+        // Betting is over; the market waits on the proposal
+        if matches!(market.state, MarketState::Active | MarketState::BettingClosed) {
+            crate::markets::MarketStateLogic::transition(&mut market, MarketEvent::End)?;
+            MarketStateManager::update_market(env, &market_id, &market);
+        }
+
+        crate::events::EventEmitter::emit_result_proposed(
+            env,
+            &market_id,
+            &proposer,
+            &outcome,
+            bond,
+            Self::challenge_deadline(&market, &proposal),
+        );
+        Ok(())
+    }
+
+    /// Challenge a market's proposed result, locking a bond equal to the
+    /// proposer's. The market is then settled by its arbiter.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::DisputeCondNotMet` - No open proposal, or its challenge window closed
+    /// * `Error::AlreadyDisputed` - The proposal was already challenged
+    /// * `Error::InvalidInput` - The proposer cannot challenge their own proposal
+    pub fn challenge(env: &Env, challenger: Address, market_id: Symbol) -> Result<(), Error> {
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        let proposal = Self::get_proposal(env, &market_id)
+            .filter(|p| p.status == DisputeStatus::Active)
+            .ok_or(Error::DisputeCondNotMet)?;
+        if Self::get_challenge(env, &market_id).is_some() {
+            return Err(Error::AlreadyDisputed);
+        }
+        if env.ledger().timestamp() >= Self::challenge_deadline(&market, &proposal) {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if challenger == proposal.user {
+            return Err(Error::InvalidInput);
+        }
+
+        let token_client = MarketUtils::get_market_token_client(env, &market)?;
+        MarketUtils::receive_tokens(env, &token_client, &challenger, proposal.stake)?;
+
+        let challenge = Dispute {
+            user: challenger.clone(),
+            market_id: market_id.clone(),
+            stake: proposal.stake,
+            timestamp: env.ledger().timestamp(),
+            reason: None,
+            status: DisputeStatus::Active,
+            proposed_outcome: None,
+        };
+        Self::store(env, &symbol_short!("opt_chal"), &challenge);
+        crate::escrow::EscrowManager::credit(env, &market_id, proposal.stake)?;
+
+        crate::events::EventEmitter::emit_result_challenged(
+            env,
+            &market_id,
+            &challenger,
+            proposal.stake,
+        );
+        Ok(())
+    }
+
+    /// Resolve a market to its unchallenged proposal once the challenge window
+    /// has closed, returning the outcome. The proposer gets the bond back plus
+    /// `DISPUTE_REWARD_BPS` of it from the fee vault, capped at the vault balance.
+    ///
+    /// A market resolved some other way in the meantime keeps its result: the
+    /// outstanding bonds are returned and the recorded outcome is returned.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotReady` - No open proposal, or its challenge window is still open
+    /// * `Error::DisputeCondNotMet` - The proposal was challenged and awaits arbitration
+    pub fn finalize(env: &Env, market_id: &Symbol) -> Result<String, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let mut proposal = Self::get_proposal(env, market_id)
+            .filter(|p| p.status == DisputeStatus::Active)
+            .ok_or(Error::MarketNotReady)?;
+        let outcome = proposal.proposed_outcome.clone().ok_or(Error::InvalidState)?;
+
+        let recorded = market.winning_outcomes.clone().and_then(|o| o.get(0));
+        if let Some(recorded) = recorded {
+            proposal.status = DisputeStatus::Expired;
+            let mut refunds = Vec::new(env);
+            refunds.push_back((proposal.user.clone(), proposal.stake));
+            if let Some(mut challenge) = Self::get_challenge(env, market_id) {
+                challenge.status = DisputeStatus::Expired;
+                refunds.push_back((challenge.user.clone(), challenge.stake));
+                Self::store(env, &symbol_short!("opt_chal"), &challenge);
+            }
+            Self::store(env, &symbol_short!("opt_prop"), &proposal);
+            for (recipient, bond) in refunds.iter() {
+                Self::pay_bond(env, market_id, &market, &recipient, bond, 0)?;
+            }
+            return Ok(recorded);
+        }
+        if Self::get_challenge(env, market_id).is_some() {
+            return Err(Error::DisputeCondNotMet);
+        }
+        if env.ledger().timestamp() < Self::challenge_deadline(&market, &proposal) {
+            return Err(Error::MarketNotReady);
+        }
+
+        let vault_token = market.token.clone();
+        let vault_balance =
+            crate::fees::FeeTracker::get_fee_vault_balance(env, vault_token.as_ref());
+        let reward =
+            crate::amounts::mul_div(proposal.stake, crate::config::DISPUTE_REWARD_BPS, 10_000)?
+                .min(vault_balance)
+                .max(0);
+        crate::fees::FeeTracker::debit_fee_vault(env, vault_token.as_ref(), reward)?;
+
+        proposal.status = DisputeStatus::Resolved;
+        Self::store(env, &symbol_short!("opt_prop"), &proposal);
+        Self::resolve(env, market_id, &mut market, outcome.clone())?;
+        Self::pay_bond(env, market_id, &market, &proposal.user, proposal.stake, reward)?;
+        Ok(outcome)
+    }
+
+    /// Settle a challenged proposal with the arbiter's `outcome`, resolving the
+    /// market to it. The proposer takes both bonds if `outcome` is the proposed
+    /// one; the challenger takes them otherwise.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::DisputeCondNotMet` - The market has no challenged proposal
+    /// * `Error::InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// * `Error::MarketResolved` - The market was resolved some other way
+    pub fn settle_challenge(env: &Env, market_id: &Symbol, outcome: &String) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let mut proposal = Self::get_proposal(env, market_id)
+            .filter(|p| p.status == DisputeStatus::Active)
+            .ok_or(Error::DisputeCondNotMet)?;
+        let mut challenge = Self::get_challenge(env, market_id)
+            .filter(|c| c.status == DisputeStatus::Active)
+            .ok_or(Error::DisputeCondNotMet)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        let proposer_won = proposal.proposed_outcome.as_ref() == Some(outcome);
+        let (winner, loser) = if proposer_won {
+            proposal.status = DisputeStatus::Resolved;
+            challenge.status = DisputeStatus::Rejected;
+            (proposal.user.clone(), challenge.user.clone())
+        } else {
+            proposal.status = DisputeStatus::Rejected;
+            challenge.status = DisputeStatus::Resolved;
+            (challenge.user.clone(), proposal.user.clone())
+        };
+        let bonds = crate::amounts::checked_add(proposal.stake, challenge.stake)?;
+        Self::store(env, &symbol_short!("opt_prop"), &proposal);
+        Self::store(env, &symbol_short!("opt_chal"), &challenge);
+        Self::resolve(env, market_id, &mut market, outcome.clone())?;
+        Self::pay_bond(env, market_id, &market, &winner, bonds, 0)?;
+
+        let mut winners = Vec::new(env);
+        winners.push_back(winner);
+        let mut losers = Vec::new(env);
+        losers.push_back(loser);
+        crate::events::EventEmitter::emit_dispute_resolved(
+            env, market_id, outcome, &winners, &losers, 0,
+        );
+        Ok(())
+    }
+
+    /// Whether a market's proposal has been challenged and awaits arbitration
+    pub fn is_challenged(env: &Env, market_id: &Symbol) -> bool {
+        Self::get_challenge(env, market_id)
+            .map(|c| c.status == DisputeStatus::Active)
+            .unwrap_or(false)
+    }
+
+    /// Result proposed for a market, if any
+    pub fn get_proposal(env: &Env, market_id: &Symbol) -> Option<Dispute> {
+        let key = (symbol_short!("opt_prop"), market_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Challenge posted against a market's proposal, if any
+    pub fn get_challenge(env: &Env, market_id: &Symbol) -> Option<Dispute> {
+        let key = (symbol_short!("opt_chal"), market_id.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Time until which a proposal can be challenged
+    fn challenge_deadline(market: &Market, proposal: &Dispute) -> u64 {
+        proposal
+            .timestamp
+            .saturating_add(market.dispute_window_seconds)
+    }
+
+    fn store(env: &Env, kind: &Symbol, record: &Dispute) {
+        let key = (kind.clone(), record.market_id.clone());
+        env.storage().persistent().set(&key, record);
+    }
+
+    /// Resolve the market to `outcome`. The challenge window already served as
+    /// its dispute window, so payouts unlock immediately.
+    fn resolve(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        outcome: String,
+    ) -> Result<(), Error> {
+        let mut winning_outcomes = Vec::new(env);
+        winning_outcomes.push_back(outcome);
+        MarketStateManager::set_winning_outcomes(market, winning_outcomes.clone(), Some(market_id))?;
+        market.payout_unlock_at = Some(env.ledger().timestamp());
+        MarketStateManager::update_market(env, market_id, market);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.creator);
+
+        let _ = crate::bets::BetManager::resolve_market_bets(env, market_id, &winning_outcomes);
+        MarketUtils::publish_resolution(
+            env,
+            market_id,
+            market,
+            crate::events::ResolutionSource::Proposal,
+        );
+        crate::statistics::StatisticsManager::record_market_resolved(env);
+        Ok(())
+    }
+
+    /// Release `bond` from the market's escrow and pay it with `reward` to `recipient`
+    fn pay_bond(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        recipient: &Address,
+        bond: i128,
+        reward: i128,
+    ) -> Result<(), Error> {
+        crate::escrow::EscrowManager::debit(env, market_id, bond)?;
+        let amount = crate::amounts::checked_add(bond, reward)?;
+        let token_client = MarketUtils::get_market_token_client(env, market)?;
+        crate::reentrancy_guard::ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), recipient, &amount)
+        })
+        .map_err(|_| Error::InvalidState)
+    }
+}
@@ -0,0 +1,226 @@
+#![cfg(test)]
+
+//! Optimistic resolution tests.
+//!
+//! A `ResolveMode::Optimistic` market is resolved by a bonded proposal that
+//! stands unless challenged within the market's dispute window. A challenge
+//! posts the same bond and leaves the decision to the arbiter, whose side
+//! takes both bonds; an unchallenged proposer earns a reward from the fee vault.
+
+use crate::disputes::DisputeStatus;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::ResolveMode;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const BOND: i128 = 10_000_000;
+const DISPUTE_WINDOW: u64 = 86_400;
+
+fn optimistic_market(test: &PredictifyTest) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    client.set_resolve_mode(&test.admin, &market_id, &ResolveMode::Optimistic);
+    market_id
+}
+
+fn bet(test: &PredictifyTest, market_id: &Symbol, outcome: &str, amount: i128) -> Address {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        market_id,
+        &String::from_str(&test.env, outcome),
+        &amount,
+        &None,
+    );
+    user
+}
+
+fn advance_to(test: &PredictifyTest, timestamp: u64) {
+    test.env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+fn advance_past_end(test: &PredictifyTest, market_id: &Symbol) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    advance_to(test, client.get_market(market_id).unwrap().end_time + 1);
+}
+
+/// Fill the fee vault with the 20 XLM fee of a resolved 1,000 XLM market
+fn fund_fee_vault(test: &PredictifyTest) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    bet(test, &market_id, "yes", 1_000_000_000);
+    advance_past_end(test, &market_id);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 20_000_000);
+}
+
+#[test]
+fn test_unchallenged_proposal_finalizes_with_reward() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = optimistic_market(&test);
+    let winner = bet(&test, &market_id, "yes", 100_000_000);
+    bet(&test, &market_id, "no", 100_000_000);
+    fund_fee_vault(&test);
+
+    // Only the market's outcomes may be proposed, and only once
+    let proposer = test.create_funded_user();
+    let before = token.balance(&proposer);
+    assert_eq!(
+        client.try_propose_result(&proposer, &market_id, &String::from_str(&test.env, "maybe"), &BOND),
+        Err(Ok(Error::InvalidOutcome))
+    );
+    client.propose_result(&proposer, &market_id, &String::from_str(&test.env, "yes"), &BOND);
+    assert_eq!(token.balance(&proposer), before - BOND);
+    assert_eq!(
+        client.try_propose_result(&proposer, &market_id, &String::from_str(&test.env, "no"), &BOND),
+        Err(Ok(Error::AlreadyDisputed))
+    );
+    assert_eq!(
+        client.reconcile_market(&market_id),
+        (200_000_000 + BOND, 200_000_000 + BOND)
+    );
+
+    // The proposal stands only once the challenge window has closed
+    assert_eq!(
+        client.try_finalize_result(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    let proposed_at = client.get_result_proposal(&market_id).unwrap().timestamp;
+    advance_to(&test, proposed_at + DISPUTE_WINDOW);
+    client.resolve_market(&market_id);
+
+    // Bond back plus half of it from the fee vault
+    assert_eq!(token.balance(&proposer), before + BOND / 2);
+    assert_eq!(client.get_fee_vault_balance(&None), 20_000_000 - BOND / 2);
+    assert_eq!(
+        client.get_result_proposal(&market_id).unwrap().status,
+        DisputeStatus::Resolved
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+
+    // The challenge window served as the dispute window
+    assert_eq!(client.claim_winnings(&winner, &market_id), 196_000_000);
+}
+
+#[test]
+fn test_successful_challenge_takes_both_bonds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = optimistic_market(&test);
+    bet(&test, &market_id, "yes", 100_000_000);
+    bet(&test, &market_id, "no", 100_000_000);
+    advance_past_end(&test, &market_id);
+
+    let proposer = test.create_funded_user();
+    let challenger = test.create_funded_user();
+    let proposer_before = token.balance(&proposer);
+    let challenger_before = token.balance(&challenger);
+    client.propose_result(&proposer, &market_id, &String::from_str(&test.env, "yes"), &BOND);
+    assert_eq!(
+        client.try_challenge_result(&proposer, &market_id),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.challenge_result(&challenger, &market_id);
+    assert_eq!(token.balance(&challenger), challenger_before - BOND);
+    assert_eq!(
+        client.try_challenge_result(&test.user, &market_id),
+        Err(Ok(Error::AlreadyDisputed))
+    );
+
+    // A challenged proposal never finalizes by itself
+    advance_to(&test, test.env.ledger().timestamp() + DISPUTE_WINDOW);
+    assert_eq!(
+        client.try_finalize_result(&market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+
+    client.arbitrate(&test.admin, &market_id, &String::from_str(&test.env, "no"));
+    assert_eq!(token.balance(&challenger), challenger_before + BOND);
+    assert_eq!(token.balance(&proposer), proposer_before - BOND);
+    assert_eq!(
+        client.get_result_proposal(&market_id).unwrap().status,
+        DisputeStatus::Rejected
+    );
+    assert_eq!(
+        client.get_result_challenge(&market_id).unwrap().status,
+        DisputeStatus::Resolved
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "no")])
+    );
+    assert_eq!(client.reconcile_market(&market_id), (200_000_000, 200_000_000));
+}
+
+#[test]
+fn test_failed_challenge_forfeits_bond_to_proposer() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = optimistic_market(&test);
+    bet(&test, &market_id, "yes", 100_000_000);
+    bet(&test, &market_id, "no", 100_000_000);
+
+    // Nothing can be proposed before the market ends
+    let proposer = test.create_funded_user();
+    assert_eq!(
+        client.try_propose_result(&proposer, &market_id, &String::from_str(&test.env, "yes"), &BOND),
+        Err(Ok(Error::MarketClosed))
+    );
+    advance_past_end(&test, &market_id);
+
+    let challenger = test.create_funded_user();
+    let proposer_before = token.balance(&proposer);
+    let challenger_before = token.balance(&challenger);
+    client.propose_result(&proposer, &market_id, &String::from_str(&test.env, "yes"), &BOND);
+    client.challenge_result(&challenger, &market_id);
+    assert_eq!(
+        client.reconcile_market(&market_id),
+        (200_000_000 + 2 * BOND, 200_000_000 + 2 * BOND)
+    );
+
+    client.arbitrate(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    assert_eq!(token.balance(&proposer), proposer_before + BOND);
+    assert_eq!(token.balance(&challenger), challenger_before - BOND);
+    assert_eq!(
+        client.get_result_challenge(&market_id).unwrap().status,
+        DisputeStatus::Rejected
+    );
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+    assert_eq!(client.reconcile_market(&market_id), (200_000_000, 200_000_000));
+
+    // Settled: nothing left to challenge or arbitrate
+    assert_eq!(
+        client.try_challenge_result(&test.user, &market_id),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+    assert_eq!(
+        client.try_arbitrate(&test.admin, &market_id, &String::from_str(&test.env, "no")),
+        Err(Ok(Error::DisputeCondNotMet))
+    );
+}
+
+#[test]
+fn test_propose_requires_optimistic_mode() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    advance_past_end(&test, &market_id);
+    assert_eq!(
+        client.try_propose_result(&test.user, &market_id, &String::from_str(&test.env, "yes"), &BOND),
+        Err(Ok(Error::InvalidState))
+    );
+}
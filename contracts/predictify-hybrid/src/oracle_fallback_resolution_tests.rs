@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+//! Fallback oracle activation tests.
+//!
+//! The primary oracle is queried until `end_time + resolution_timeout`; after that
+//! `resolve_market` switches to the fallback oracle, and when both fail the market
+//! is handed over to manual resolution or refunds.
+
+use crate::errors::Error;
+use crate::events::{FallbackUsedEvent, OracleResolutionFailedEvent};
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{
+    Market, MarketState, OracleConfig, OracleProvider, OracleSigner, PythPriceData,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, vec, Address, String, Symbol};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+const RESOLUTION_TIMEOUT: u64 = 3600;
+
+fn pyth_config(test: &PredictifyTest, oracle: &Address) -> OracleConfig {
+    OracleConfig {
+        provider: OracleProvider::Pyth,
+        oracle_address: oracle.clone(),
+        feed_id: String::from_str(&test.env, BTC_FEED),
+        threshold: 2_500_000_000_000, // $25,000 in 8-decimal units
+        comparison: String::from_str(&test.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
+    }
+}
+
+fn create_market_with_fallback(
+    test: &PredictifyTest,
+    primary: &Address,
+    fallback: Option<&Address>,
+) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &pyth_config(test, primary),
+        &fallback.map(|f| pyth_config(test, f)),
+        &RESOLUTION_TIMEOUT,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn get_market(test: &PredictifyTest, market_id: &Symbol) -> Market {
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    })
+}
+
+fn publish_price(test: &PredictifyTest, oracle: &Address, price: i64) {
+    MockPythContractClient::new(&test.env, oracle).set_feed_price(
+        &String::from_str(&test.env, BTC_FEED),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: test.env.ledger().timestamp(),
+        },
+    );
+}
+
+#[test]
+fn test_primary_oracle_resolves_before_timeout() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = test.env.register(MockPythContract, ());
+    let fallback = test.env.register(MockPythContract, ());
+    let market_id = create_market_with_fallback(&test, &primary, Some(&fallback));
+
+    let end_time = get_market(&test, &market_id).end_time;
+    test.set_time(end_time + 1);
+    publish_price(&test, &primary, 2_600_000_000_000);
+    publish_price(&test, &fallback, 2_400_000_000_000);
+
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "yes"))
+    );
+    assert_eq!(market.state, MarketState::Resolved);
+    let fallback_event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, FallbackUsedEvent>(&symbol_short!("fbk_used"))
+    });
+    assert!(fallback_event.is_none());
+}
+
+#[test]
+fn test_primary_failure_before_timeout_is_retryable() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = test.env.register(MockPythContract, ());
+    let fallback = test.env.register(MockPythContract, ());
+    let market_id = create_market_with_fallback(&test, &primary, Some(&fallback));
+
+    let end_time = get_market(&test, &market_id).end_time;
+    test.set_time(end_time + RESOLUTION_TIMEOUT);
+    publish_price(&test, &fallback, 2_600_000_000_000);
+
+    // The fallback is not consulted while the primary is still within its timeout
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert!(market.oracle_result.is_none());
+    assert_eq!(market.state, MarketState::Active);
+}
+
+#[test]
+fn test_fallback_oracle_resolves_after_timeout() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = test.env.register(MockPythContract, ());
+    let fallback = test.env.register(MockPythContract, ());
+    let market_id = create_market_with_fallback(&test, &primary, Some(&fallback));
+
+    let end_time = get_market(&test, &market_id).end_time;
+    test.set_time(end_time + RESOLUTION_TIMEOUT + 1);
+    publish_price(&test, &fallback, 2_600_000_000_000);
+
+    // Anyone may trigger resolution; the primary never published a price
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "yes"))
+    );
+    assert_eq!(market.state, MarketState::Resolved);
+
+    let event = test
+        .env
+        .as_contract(&test.contract_id, || {
+            test.env
+                .storage()
+                .persistent()
+                .get::<Symbol, FallbackUsedEvent>(&symbol_short!("fbk_used"))
+        })
+        .unwrap();
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.primary_oracle, primary);
+    assert_eq!(event.fallback_oracle, fallback);
+}
+
+#[test]
+fn test_double_oracle_failure_allows_manual_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = test.env.register(MockPythContract, ());
+    let fallback = test.env.register(MockPythContract, ());
+    let market_id = create_market_with_fallback(&test, &primary, Some(&fallback));
+
+    let end_time = get_market(&test, &market_id).end_time;
+    test.set_time(end_time + RESOLUTION_TIMEOUT + 1);
+
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert!(market.oracle_result.is_none());
+    assert!(market.winning_outcomes.is_none());
+    assert_eq!(market.state, MarketState::Ended);
+    let event = test
+        .env
+        .as_contract(&test.contract_id, || {
+            test.env
+                .storage()
+                .persistent()
+                .get::<Symbol, OracleResolutionFailedEvent>(&symbol_short!("orc_fail"))
+        })
+        .unwrap();
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.primary_oracle, primary);
+    assert_eq!(event.fallback_oracle, Some(fallback));
+
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "no"));
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(
+        market.winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "no")])
+    );
+}
+
+#[test]
+fn test_double_oracle_failure_allows_refund_by_anyone() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let primary = test.env.register(MockPythContract, ());
+    let missing_fallback = Address::generate(&test.env);
+    let market_id = create_market_with_fallback(&test, &primary, Some(&missing_fallback));
+
+    let end_time = get_market(&test, &market_id).end_time;
+    test.set_time(end_time + RESOLUTION_TIMEOUT + 1);
+
+    // Not yet flagged and still inside the default refund timeout
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_refund_on_oracle_failure(&test.user, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.resolve_market(&market_id);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Ended
+    );
+
+    client.refund_on_oracle_failure(&test.user, &market_id);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Voided
+    );
+}
@@ -0,0 +1,199 @@
+//! Band Protocol provider, read through the imported `std_reference` contract.
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+
+use super::{OracleAdapter, OracleInterface};
+use crate::bandprotocol;
+use crate::errors::Error;
+use crate::types::{OracleConfig, OracleProvider, OracleSigner};
+
+// ===== BAND PROTOCOLE ORACLE CLIENT =====
+
+pub struct BandProtocolClient<'a> {
+    env: &'a Env,
+    contract_id: Address,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub(crate) enum BandDataKey {
+    StdReferenceAddress,
+}
+
+impl<'a> BandProtocolClient<'a> {
+    pub fn new(env: &'a Env, contract_id: Address) -> Self {
+        Self { env, contract_id }
+    }
+
+    pub fn get_price_of(&self, symbol_pair: (Symbol, Symbol)) -> u128 {
+        let client = bandprotocol::Client::new(self.env, &self.contract_id);
+        client
+            .get_reference_data(&Vec::from_array(self.env, [symbol_pair]))
+            .get_unchecked(0)
+            .rate
+    }
+}
+
+/// Band Protocol Oracle implementation
+
+#[derive(Debug)]
+pub struct BandProtocolOracle {
+    contract_id: Address,
+}
+
+impl BandProtocolOracle {
+    pub fn new(contract_id: Address) -> Self {
+        Self { contract_id }
+    }
+
+    pub fn contract_id(&self) -> Address {
+        self.contract_id.clone()
+    }
+
+    pub fn parse_feed_id(&self, env: &Env, feed_id: &String) -> Result<(Symbol, Symbol), Error> {
+        if feed_id.is_empty() {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        if feed_id == &String::from_str(env, "BTC/USD") || feed_id == &String::from_str(env, "BTC")
+        {
+            Ok((Symbol::new(env, "BTC"), Symbol::new(env, "USD")))
+        } else if feed_id == &String::from_str(env, "ETH/USD")
+            || feed_id == &String::from_str(env, "ETH")
+        {
+            Ok((Symbol::new(env, "ETH"), Symbol::new(env, "USD")))
+        } else if feed_id == &String::from_str(env, "XLM/USD")
+            || feed_id == &String::from_str(env, "XLM")
+        {
+            Ok((Symbol::new(env, "XLM"), Symbol::new(env, "USD")))
+        } else if feed_id == &String::from_str(env, "USDC/USD")
+            || feed_id == &String::from_str(env, "USDC")
+        {
+            Ok((Symbol::new(env, "USDC"), Symbol::new(env, "USD")))
+        } else {
+            Err(Error::InvalidOracleConfig)
+        }
+    }
+
+    /// Fetch price from Band client
+    fn get_band_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
+        let pair = self.parse_feed_id(env, feed_id)?;
+        let client = BandProtocolClient::new(env, self.contract_id.clone());
+        let rate = client.get_price_of(pair);
+        Ok(rate as i128)
+    }
+}
+
+impl OracleInterface for BandProtocolOracle {
+    fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
+        self.get_band_price(env, feed_id)
+    }
+
+    fn contract_id(&self) -> Address {
+        self.contract_id.clone()
+    }
+
+    fn provider(&self) -> OracleProvider {
+        OracleProvider::BandProtocol
+    }
+
+    fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
+        let asset = String::from_str(env, "BTC/USD");
+        match self.get_band_price(env, &asset) {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+}
+
+// ===== BAND ADAPTER =====
+
+/// [`OracleAdapter`] for `OracleProvider::BandProtocol`.
+///
+/// Band reports no publish time, so `max_age` does not apply to it.
+pub struct BandAdapter;
+
+impl OracleAdapter for BandAdapter {
+    /// `std_reference` rates are scaled by 1e18
+    const DECIMALS: u32 = 18;
+
+    fn fetch(env: &Env, config: &OracleConfig, _max_age: u64) -> Result<i128, Error> {
+        BandProtocolOracle::new(config.oracle_address.clone()).get_price(env, &config.feed_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    /// Minimal Band `std_reference` contract exposing `get_reference_data`.
+    #[contract]
+    pub struct MockBandContract;
+
+    #[contractimpl]
+    impl MockBandContract {
+        pub fn set_rate(env: Env, base: Symbol, rate: u128) {
+            env.storage().instance().set(&base, &rate);
+        }
+
+        pub fn get_reference_data(
+            env: Env,
+            symbol_pairs: Vec<(Symbol, Symbol)>,
+        ) -> Vec<bandprotocol::ReferenceDatum> {
+            let mut data = Vec::new(&env);
+            for (base, _quote) in symbol_pairs.iter() {
+                data.push_back(bandprotocol::ReferenceDatum {
+                    rate: env.storage().instance().get(&base).unwrap_or(0),
+                    last_updated_base: 0,
+                    last_updated_quote: 0,
+                });
+            }
+            data
+        }
+    }
+
+    fn config(env: &Env, oracle_address: Address, feed_id: &str) -> OracleConfig {
+        OracleConfig {
+            provider: OracleProvider::BandProtocol,
+            oracle_address,
+            feed_id: String::from_str(env, feed_id),
+            threshold: 2_500_000,
+            comparison: String::from_str(env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        }
+    }
+
+    #[test]
+    fn test_fetch_reads_reference_rate() {
+        let env = Env::default();
+        let band = env.register(MockBandContract, ());
+        MockBandContractClient::new(&env, &band).set_rate(&Symbol::new(&env, "XLM"), &1_200);
+
+        assert_eq!(
+            BandAdapter::fetch(&env, &config(&env, band.clone(), "XLM/USD"), 60),
+            Ok(1_200)
+        );
+        assert_eq!(
+            BandAdapter::fetch(&env, &config(&env, band, "BTC"), 60),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_fetch_rejects_unknown_feed() {
+        let env = Env::default();
+        let band = env.register(MockBandContract, ());
+
+        assert_eq!(
+            BandAdapter::fetch(&env, &config(&env, band.clone(), "DOGE/USD"), 60),
+            Err(Error::InvalidOracleConfig)
+        );
+        assert_eq!(
+            BandAdapter::fetch(&env, &config(&env, band, ""), 60),
+            Err(Error::InvalidOracleConfig)
+        );
+    }
+}
@@ -1,8 +1,13 @@
 #![allow(dead_code)]
 
-use crate::bandprotocol;
 use crate::errors::Error;
-use soroban_sdk::{contracttype, symbol_short, vec, Address, Env, IntoVal, String, Symbol, Vec};
+use crate::events::{
+    OracleHealthCheckedEvent, OracleStatusEvent, OracleWhitelistedEvent, WhitelistAdminEvent,
+    EVENT_SCHEMA_VERSION,
+};
+use soroban_sdk::{
+    contracttype, symbol_short, vec, Address, BytesN, Env, IntoVal, String, Symbol, Vec, I256,
+};
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
 
@@ -11,10 +16,21 @@ use crate::types::*;
 /// This module provides a comprehensive oracle management system with:
 /// - OracleInterface trait for standardized oracle interactions
 /// - Reflector oracle implementation (primary oracle for Stellar Network)
+/// - Pyth oracle implementation reading an on-chain Pyth price contract
 /// - Oracle factory pattern for creating oracle instances
 /// - Oracle utilities for price comparison and outcome determination
 ///
-/// Note: Pyth Network is not available on Stellar, so Reflector is the primary oracle provider.
+/// Note: Reflector is the primary oracle provider; Pyth is read from a Pyth price contract.
+///
+/// Each provider lives in its own submodule with an [`OracleAdapter`]
+/// implementation; [`OracleFactory::fetch`] dispatches to them by provider.
+mod band;
+mod pyth;
+mod reflector;
+
+pub use band::*;
+pub use pyth::*;
+pub use reflector::*;
 
 // ===== ORACLE INTERFACE =====
 
@@ -103,774 +119,24 @@ pub trait OracleInterface {
     fn is_healthy(&self, env: &Env) -> Result<bool, Error>;
 }
 
-// ===== PYTH ORACLE IMPLEMENTATION =====
+// ===== ORACLE ADAPTERS =====
 
-/// Pyth Network oracle implementation for future Stellar blockchain support.
-///
-/// **Current Status**: Pyth Network does not currently support Stellar blockchain.
-/// This implementation is designed to be future-proof and follows Rust best practices
-/// for when Pyth becomes available on Stellar.
-///
-/// # Implementation Strategy
-///
-/// This oracle implementation:
-/// - **Future-Ready**: Designed for easy integration when Pyth supports Stellar
-/// - **Error Handling**: Returns appropriate errors indicating unavailability
-/// - **Configuration Support**: Maintains feed configurations for future use
-/// - **Standard Interface**: Implements OracleInterface for consistency
-///
-/// # Pyth Network Overview
-///
-/// Pyth Network is a high-frequency, cross-chain oracle network that provides
-/// real-time financial market data. Key features include:
-/// - **High Frequency**: Sub-second price updates
-/// - **Institutional Grade**: Data from major trading firms and exchanges
-/// - **Cross-Chain**: Supports multiple blockchain networks
-/// - **Decentralized**: Distributed network of data providers
-///
-/// # Example Usage (Future)
-///
-/// ```rust
-/// # use soroban_sdk::{Env, Address, String, Vec};
-/// # use predictify_hybrid::oracles::{PythOracle, PythFeedConfig, OracleInterface};
-/// # let env = Env::default();
-/// # let contract_id = Address::generate(&env);
-///
-/// // Create Pyth oracle with feed configurations
-/// let mut oracle = PythOracle::new(contract_id.clone());
-///
-/// // Add BTC/USD feed configuration
-/// oracle.add_feed_config(PythFeedConfig {
-///     feed_id: String::from_str(&env, "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
-///     asset_symbol: String::from_str(&env, "BTC/USD"),
-///     decimals: 8,
-///     is_active: true,
-/// });
-///
-/// // Currently returns error (Pyth not available on Stellar)
-/// let price_result = oracle.get_price(&env, &String::from_str(&env, "BTC/USD"));
-/// assert!(price_result.is_err());
+/// Reads a price for a market's [`OracleConfig`] from one oracle provider.
 ///
-/// // Check oracle provider
-/// assert_eq!(oracle.provider(), OracleProvider::Pyth);
+/// Every provider has exactly one adapter, defined next to its client in
+/// `oracles/<provider>.rs`, and [`OracleFactory::fetch`] selects it by
+/// `OracleProvider`. Adding a provider takes a new `OracleProvider` variant, a
+/// new adapter file, and one dispatcher arm; resolution code is unchanged.
 ///
-/// // Validate feed configurations
-/// assert_eq!(oracle.get_feed_count(), 1);
-/// assert!(oracle.is_feed_active(&String::from_str(&env, "BTC/USD")));
-/// ```
-///
-/// # Feed Configuration
-///
-/// Pyth feeds are identified by:
-/// - **Feed ID**: Unique 64-character hexadecimal identifier
-/// - **Asset Symbol**: Human-readable symbol (e.g., "BTC/USD")
-/// - **Decimals**: Price precision (typically 8 for crypto)
-/// - **Active Status**: Whether the feed is currently active
-///
-/// # Migration Path
-///
-/// When Pyth becomes available on Stellar:
-/// 1. **Update Dependencies**: Add Pyth Stellar SDK
-/// 2. **Implement get_price()**: Replace error with actual Pyth price fetching
-/// 3. **Add Authentication**: Implement any required Pyth authentication
-/// 4. **Update Health Check**: Connect to actual Pyth network status
-/// 5. **Test Integration**: Comprehensive testing with live Pyth feeds
-///
-/// # Current Limitations
-///
-/// - All price requests return `Error::OracleNotAvailable`
-/// - Health checks always return `false`
-/// - No actual network connectivity to Pyth services
-/// - Feed configurations are stored but not used for price fetching
-#[derive(Debug, Clone)]
-pub struct PythOracle {
-    contract_id: Address,
-    feed_configurations: Vec<PythFeedConfig>,
-}
+/// Adapters return prices with [`Self::DECIMALS`] decimals, the units
+/// compared against `config.threshold`, and reject readings older than
+/// `max_age` seconds whenever the provider reports a publish time.
+pub trait OracleAdapter {
+    /// Decimals of the prices returned by [`Self::fetch`]
+    const DECIMALS: u32;
 
-/// Configuration structure for Pyth Network price feeds.
-///
-/// This structure defines the parameters needed to configure and manage
-/// individual price feeds from the Pyth Network. Each feed represents
-/// a specific asset pair with its own unique identifier and characteristics.
-///
-/// # Feed Identification
-///
-/// Pyth feeds use:
-/// - **Unique Feed IDs**: 64-character hexadecimal identifiers
-/// - **Asset Symbols**: Human-readable trading pair names
-/// - **Precision Settings**: Decimal places for price representation
-/// - **Status Flags**: Active/inactive feed management
-///
-/// # Example Usage
-///
-/// ```rust
-/// # use soroban_sdk::{Env, String};
-/// # use predictify_hybrid::oracles::PythFeedConfig;
-/// # let env = Env::default();
-///
-/// // Configure BTC/USD feed
-/// let btc_config = PythFeedConfig {
-///     feed_id: String::from_str(&env,
-///         "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
-///     asset_symbol: String::from_str(&env, "BTC/USD"),
-///     decimals: 8, // 8 decimal places for crypto prices
-///     is_active: true,
-/// };
-///
-/// // Configure ETH/USD feed
-/// let eth_config = PythFeedConfig {
-///     feed_id: String::from_str(&env,
-///         "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace"),
-///     asset_symbol: String::from_str(&env, "ETH/USD"),
-///     decimals: 8,
-///     is_active: true,
-/// };
-///
-/// // Configure stock feed with different precision
-/// let aapl_config = PythFeedConfig {
-///     feed_id: String::from_str(&env,
-///         "0x49f6b65cb1de6b10eaf75e7c03ca029c306d0357e91b5311b175084a5ad55688"),
-///     asset_symbol: String::from_str(&env, "AAPL/USD"),
-///     decimals: 2, // 2 decimal places for stock prices
-///     is_active: true,
-/// };
-///
-/// println!("Configured {} with {} decimals",
-///     btc_config.asset_symbol.to_string(),
-///     btc_config.decimals);
-/// ```
-///
-/// # Feed ID Format
-///
-/// Pyth feed IDs are:
-/// - **64 characters long**: Hexadecimal representation
-/// - **Globally unique**: Each feed has a unique identifier across all assets
-/// - **Immutable**: Feed IDs don't change once assigned
-/// - **Network specific**: Different IDs for different blockchain networks
-///
-/// # Decimal Precision
-///
-/// Common decimal configurations:
-/// - **Crypto pairs**: 8 decimals (e.g., BTC/USD: $45,123.45678901)
-/// - **Forex pairs**: 6 decimals (e.g., EUR/USD: 1.123456)
-/// - **Stock prices**: 2 decimals (e.g., AAPL: $150.25)
-/// - **Commodities**: Variable based on asset type
-///
-/// # Feed Management
-///
-/// Feed configurations support:
-/// - **Dynamic activation**: Enable/disable feeds without removing configuration
-/// - **Batch operations**: Configure multiple feeds simultaneously
-/// - **Validation**: Ensure feed IDs and symbols are properly formatted
-/// - **Asset discovery**: List all configured assets and their symbols
-#[contracttype]
-#[derive(Debug, Clone)]
-pub struct PythFeedConfig {
-    pub feed_id: String,
-    pub asset_symbol: String,
-    pub decimals: u32,
-    pub is_active: bool,
-}
-
-impl PythOracle {
-    /// Create a new Pyth oracle instance
-    ///
-    /// # Arguments
-    /// * `contract_id` - The contract address for the Pyth oracle
-    ///
-    /// # Returns
-    /// A new PythOracle instance configured for the given contract
-    pub fn new(contract_id: Address) -> Self {
-        Self {
-            contract_id,
-            feed_configurations: Vec::new(&soroban_sdk::Env::default()),
-        }
-    }
-
-    /// Create a new Pyth oracle with pre-configured feeds
-    ///
-    /// # Arguments
-    /// * `contract_id` - The contract address for the Pyth oracle
-    /// * `feed_configs` - Vector of feed configurations
-    ///
-    /// # Returns
-    /// A new PythOracle instance with configured feeds
-    pub fn with_feeds(contract_id: Address, feed_configs: Vec<PythFeedConfig>) -> Self {
-        Self {
-            contract_id,
-            feed_configurations: feed_configs,
-        }
-    }
-
-    /// Get the Pyth oracle contract ID
-    pub fn contract_id(&self) -> Address {
-        self.contract_id.clone()
-    }
-
-    /// Add a new feed configuration
-    ///
-    /// # Arguments
-    /// * `feed_config` - Configuration for the new feed
-    pub fn add_feed_config(&mut self, feed_config: PythFeedConfig) {
-        self.feed_configurations.push_back(feed_config);
-    }
-
-    /// Get feed configuration by feed ID
-    ///
-    /// # Arguments
-    /// * `feed_id` - The feed ID to search for
-    ///
-    /// # Returns
-    /// Optional feed configuration if found
-    pub fn get_feed_config(&self, feed_id: &String) -> Option<PythFeedConfig> {
-        for config in self.feed_configurations.iter() {
-            if config.feed_id == *feed_id {
-                return Some(config.clone());
-            }
-        }
-        None
-    }
-
-    /// Validate feed ID format
-    ///
-    /// # Arguments
-    /// * `feed_id` - The feed ID to validate
-    ///
-    /// # Returns
-    /// True if the feed ID format is valid
-    pub fn validate_feed_id(&self, feed_id: &String) -> bool {
-        // Pyth feed IDs are typically hex strings
-        !feed_id.is_empty() && feed_id.len() >= 8
-    }
-
-    /// Get supported asset symbols
-    ///
-    /// # Returns
-    /// Vector of supported asset symbols
-    pub fn get_supported_assets(&self) -> Vec<String> {
-        let mut assets = Vec::new(&soroban_sdk::Env::default());
-        for config in self.feed_configurations.iter() {
-            if config.is_active {
-                assets.push_back(config.asset_symbol.clone());
-            }
-        }
-        assets
-    }
-
-    /// Check if a feed is configured and active
-    ///
-    /// # Arguments
-    /// * `feed_id` - The feed ID to check
-    ///
-    /// # Returns
-    /// True if the feed is configured and active
-    pub fn is_feed_active(&self, feed_id: &String) -> bool {
-        if let Some(config) = self.get_feed_config(feed_id) {
-            config.is_active
-        } else {
-            false
-        }
-    }
-
-    /// Get the number of configured feeds
-    ///
-    /// # Returns
-    /// Number of configured feeds
-    pub fn get_feed_count(&self) -> u32 {
-        self.feed_configurations.len() as u32
-    }
-
-    /// Convert raw price to scaled price based on feed configuration
-    ///
-    /// # Arguments
-    /// * `raw_price` - Raw price from oracle
-    /// * `feed_config` - Feed configuration containing decimals
-    ///
-    /// # Returns
-    /// Scaled price in the contract's expected format
-    pub fn scale_price(&self, raw_price: i128, feed_config: &PythFeedConfig) -> i128 {
-        // Convert from Pyth price format to contract format
-        // This is a placeholder implementation
-        if feed_config.decimals > 0 {
-            raw_price / (10_i128.pow(feed_config.decimals as u32))
-        } else {
-            raw_price
-        }
-    }
-
-    /// Get price with retry logic (future implementation)
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment
-    /// * `feed_id` - Feed ID to get price for
-    /// * `max_retries` - Maximum number of retry attempts
-    ///
-    /// # Returns
-    /// Result containing the price or error
-    pub fn get_price_with_retry(
-        &self,
-        env: &Env,
-        feed_id: &String,
-        max_retries: u32,
-    ) -> Result<i128, Error> {
-        for attempt in 0..max_retries {
-            match self.get_price(env, feed_id) {
-                Ok(price) => return Ok(price),
-                Err(e) => {
-                    if attempt == max_retries - 1 {
-                        return Err(e);
-                    }
-                    // In a real implementation, we would wait before retrying
-                }
-            }
-        }
-        Err(Error::OracleUnavailable)
-    }
-}
-
-impl OracleInterface for PythOracle {
-    /// Get the current price for a given feed ID
-    ///
-    /// **Note**: This function returns an error because Pyth Network is not
-    /// available on Stellar. When Pyth becomes available, this implementation
-    /// should be updated to make actual oracle calls.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment
-    /// * `feed_id` - The feed ID to get price for
-    ///
-    /// # Returns
-    /// Error indicating Pyth is not available on Stellar
-    fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        // Validate feed ID format
-        if !self.validate_feed_id(feed_id) {
-            return Err(Error::InvalidOracleConfig);
-        }
-
-        // Check if feed is configured
-        if !self.is_feed_active(feed_id) {
-            return Err(Error::InvalidOracleConfig);
-        }
-
-        // Log the attempt for debugging
-        env.events().publish(
-            (Symbol::new(env, "pyth_price_request"),),
-            (feed_id.clone(), env.ledger().timestamp()),
-        );
-
-        // Pyth Network is not available on Stellar
-        // This error should be handled by the calling code to fallback to Reflector
-        Err(Error::OracleUnavailable)
-    }
-
-    /// Get the oracle provider type
-    ///
-    /// # Returns
-    /// OracleProvider::Pyth
-    fn provider(&self) -> OracleProvider {
-        OracleProvider::Pyth
-    }
-
-    /// Get the oracle contract ID
-    ///
-    /// # Returns
-    /// The contract address for this oracle
-    fn contract_id(&self) -> Address {
-        self.contract_id.clone()
-    }
-
-    /// Check if the oracle is healthy and available
-    ///
-    /// **Note**: This function returns false because Pyth Network is not
-    /// available on Stellar. When Pyth becomes available, this implementation
-    /// should be updated to perform actual health checks.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment
-    ///
-    /// # Returns
-    /// Always returns false for Stellar (Pyth not available)
-    fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
-        // Log the health check for debugging
-        env.events().publish(
-            (Symbol::new(env, "pyth_health_check"),),
-            (self.contract_id.clone(), env.ledger().timestamp()),
-        );
-
-        // Pyth Network is not available on Stellar
-        // In a real implementation, this would check:
-        // - Oracle contract responsiveness
-        // - Latest price timestamp freshness
-        // - Feed availability
-        // - Network connectivity
-        Ok(false)
-    }
-}
-
-// ===== REFLECTOR ORACLE CLIENT =====
-
-/// Client for interacting with Reflector oracle contract on Stellar Network.
-///
-/// Reflector is the primary oracle provider for the Stellar Network, offering
-/// institutional-grade price feeds with high reliability, security, and native
-/// integration with the Stellar ecosystem. This client provides a convenient
-/// interface for accessing Reflector's price data and oracle services.
-///
-/// # Reflector Network Overview
-///
-/// Reflector provides:
-/// - **Real-time Price Feeds**: Live market data for major cryptocurrencies
-/// - **TWAP Calculations**: Time-weighted average prices for volatility smoothing
-/// - **High Availability**: Enterprise-grade uptime and reliability
-/// - **Stellar Native**: Built specifically for Stellar blockchain
-/// - **Multiple Assets**: Support for BTC, ETH, XLM, and other major cryptocurrencies
-///
-/// # Supported Operations
-///
-/// The client supports:
-/// - **Latest Price**: Get the most recent price for any supported asset
-/// - **Historical Price**: Retrieve price data at specific timestamps
-/// - **TWAP**: Calculate time-weighted average prices over specified periods
-/// - **Health Monitoring**: Check oracle availability and responsiveness
-///
-/// # Example Usage
-///
-/// ```rust
-/// # use soroban_sdk::{Env, Address};
-/// # use predictify_hybrid::oracles::ReflectorOracleClient;
-/// # use predictify_hybrid::types::ReflectorAsset;
-/// # let env = Env::default();
-/// # let oracle_address = Address::generate(&env);
-///
-/// // Create Reflector oracle client
-/// let client = ReflectorOracleClient::new(&env, oracle_address);
-///
-/// // Check oracle health before use
-/// if client.is_healthy() {
-///     // Get latest BTC price
-///     if let Some(btc_data) = client.lastprice(ReflectorAsset::BTC) {
-///         println!("BTC price: ${}", btc_data.price / 100);
-///         println!("Last updated: {}", btc_data.timestamp);
-///     }
-///     
-///     // Get TWAP for ETH over last 10 records
-///     if let Some(eth_twap) = client.twap(ReflectorAsset::ETH, 10) {
-///         println!("ETH TWAP: ${}", eth_twap / 100);
-///     }
-///     
-///     // Get historical price at specific timestamp
-///     let timestamp = env.ledger().timestamp() - 3600; // 1 hour ago
-///     if let Some(historical) = client.price(ReflectorAsset::XLM, timestamp) {
-///         println!("XLM price 1h ago: ${}", historical.price / 100);
-///     }
-/// } else {
-///     println!("Reflector oracle is not responding");
-/// }
-/// ```
-///
-/// # Asset Support
-///
-/// Reflector supports major cryptocurrencies including:
-/// - **BTC**: Bitcoin price feeds
-/// - **ETH**: Ethereum price feeds  
-/// - **XLM**: Stellar Lumens (native asset)
-/// - **Other**: Custom assets via symbol specification
-///
-/// # Error Handling
-///
-/// The client handles various scenarios:
-/// - **Network Issues**: Returns None when oracle is unreachable
-/// - **Invalid Assets**: Returns None for unsupported asset types
-/// - **Stale Data**: Provides timestamp information for data freshness validation
-/// - **Service Downtime**: Health check indicates oracle availability
-///
-/// # Performance Considerations
-///
-/// - **Caching**: Consider caching price data to reduce oracle calls
-/// - **Batch Requests**: Group multiple price requests when possible
-/// - **Fallback Strategy**: Implement fallback mechanisms for oracle downtime
-/// - **Rate Limiting**: Respect oracle rate limits to avoid service disruption
-pub struct ReflectorOracleClient<'a> {
-    env: &'a Env,
-    contract_id: Address,
-}
-
-impl<'a> ReflectorOracleClient<'a> {
-    /// Create a new Reflector oracle client
-    pub fn new(env: &'a Env, contract_id: Address) -> Self {
-        Self { env, contract_id }
-    }
-
-    /// Get the latest price for an asset
-    pub fn lastprice(&self, asset: ReflectorAsset) -> Option<ReflectorPriceData> {
-        let args = vec![self.env, asset.into_val(self.env)];
-        // Reentrancy guard removed - external call protection no longer needed
-        let res = self
-            .env
-            .invoke_contract(&self.contract_id, &symbol_short!("lastprice"), args);
-        res
-    }
-
-    /// Get price for an asset at a specific timestamp
-    pub fn price(&self, asset: ReflectorAsset, timestamp: u64) -> Option<ReflectorPriceData> {
-        let args = vec![
-            self.env,
-            asset.into_val(self.env),
-            timestamp.into_val(self.env),
-        ];
-        // Reentrancy guard removed - external call protection no longer needed
-        let res = self
-            .env
-            .invoke_contract(&self.contract_id, &symbol_short!("price"), args);
-        res
-    }
-
-    /// Get TWAP (Time-Weighted Average Price) for an asset
-    pub fn twap(&self, asset: ReflectorAsset, records: u32) -> Option<i128> {
-        let args = vec![
-            self.env,
-            asset.into_val(self.env),
-            records.into_val(self.env),
-        ];
-        // Reentrancy guard removed - external call protection no longer needed
-        let res = self
-            .env
-            .invoke_contract(&self.contract_id, &symbol_short!("twap"), args);
-        res
-    }
-
-    /// Check if the Reflector oracle is healthy
-    pub fn is_healthy(&self) -> bool {
-        // Try to get a simple price to check if oracle is responsive
-        let test_asset = ReflectorAsset::Other(Symbol::new(self.env, "XLM"));
-        self.lastprice(test_asset).is_some()
-    }
-}
-
-// ===== REFLECTOR ORACLE IMPLEMENTATION =====
-
-/// Reflector oracle implementation for Stellar Network integration.
-///
-/// This is the primary and recommended oracle provider for Stellar blockchain,
-/// offering enterprise-grade price feeds with native Stellar integration.
-/// The implementation provides a standardized interface for accessing Reflector's
-/// comprehensive oracle services.
-///
-/// # Key Features
-///
-/// Reflector oracle provides:
-/// - **Real-time Price Feeds**: Live market data with sub-second updates
-/// - **TWAP Calculations**: Time-weighted average prices for volatility smoothing
-/// - **High Reliability**: Enterprise-grade uptime and service availability
-/// - **Stellar Native**: Built specifically for Stellar blockchain ecosystem
-/// - **Multi-Asset Support**: BTC, ETH, XLM, and other major cryptocurrencies
-/// - **Historical Data**: Access to historical price information
-///
-/// # Implementation Strategy
-///
-/// This oracle implementation:
-/// - **Production Ready**: Fully functional with live Reflector network
-/// - **Error Resilient**: Comprehensive error handling for network issues
-/// - **Feed Validation**: Validates feed IDs and asset symbols
-/// - **Health Monitoring**: Real-time oracle availability checking
-/// - **Standard Interface**: Implements OracleInterface for consistency
-///
-/// # Example Usage
-///
-/// ```rust
-/// # use soroban_sdk::{Env, Address, String};
-/// # use predictify_hybrid::oracles::{ReflectorOracle, OracleInterface};
-/// # use predictify_hybrid::types::OracleProvider;
-/// # let env = Env::default();
-/// # let oracle_address = Address::generate(&env);
-///
-/// // Create Reflector oracle instance
-/// let oracle = ReflectorOracle::new(oracle_address.clone());
-///
-/// // Verify oracle provider type
-/// assert_eq!(oracle.provider(), OracleProvider::Reflector);
-/// assert_eq!(oracle.contract_id(), oracle_address);
-///
-/// // Check oracle health before use
-/// if oracle.is_healthy(&env).unwrap_or(false) {
-///     // Get BTC price
-///     let btc_price = oracle.get_price(
-///         &env,
-///         &String::from_str(&env, "BTC/USD")
-///     );
-///     
-///     match btc_price {
-///         Ok(price) => {
-///             println!("BTC price: ${}", price / 100);
-///             
-///             // Use price for market resolution
-///             let threshold = 50_000_00; // $50,000
-///             if price > threshold {
-///                 println!("BTC is above $50k threshold");
-///             }
-///         },
-///         Err(e) => println!("Failed to get BTC price: {:?}", e),
-///     }
-///     
-///     // Get ETH price
-///     let eth_price = oracle.get_price(
-///         &env,
-///         &String::from_str(&env, "ETH/USD")
-///     );
-///     
-///     if let Ok(price) = eth_price {
-///         println!("ETH price: ${}", price / 100);
-///     }
-/// } else {
-///     println!("Reflector oracle is not healthy, using fallback");
-/// }
-/// ```
-///
-/// # Feed ID Format
-///
-/// Reflector accepts feed IDs in formats:
-/// - **Standard Pairs**: "BTC/USD", "ETH/USD", "XLM/USD"
-/// - **Asset Only**: "BTC", "ETH", "XLM" (assumes USD denomination)
-/// - **Custom Symbols**: Any symbol supported by Reflector network
-///
-/// # Price Format
-///
-/// Prices are returned as:
-/// - **Integer Values**: No floating point arithmetic
-/// - **8 Decimal Precision**: Prices multiplied by 100,000,000
-/// - **USD Denomination**: All prices in US Dollar terms
-/// - **Positive Values**: Always positive integers representing price
-///
-/// # Error Scenarios
-///
-/// Common error conditions:
-/// - **Network Issues**: Reflector service temporarily unavailable
-/// - **Invalid Feeds**: Requested asset not supported by Reflector
-/// - **Stale Data**: Price data older than acceptable threshold
-/// - **Service Limits**: Rate limiting or quota exceeded
-///
-/// # Integration Best Practices
-///
-/// For production use:
-/// - **Health Checks**: Always verify oracle health before price requests
-/// - **Error Handling**: Implement comprehensive error handling and fallbacks
-/// - **Caching**: Cache price data to reduce oracle calls and improve performance
-/// - **Monitoring**: Monitor oracle responses and implement alerting
-/// - **Fallback Strategy**: Have backup oracle or manual resolution procedures
-#[derive(Debug)]
-pub struct ReflectorOracle {
-    contract_id: Address,
-}
-
-impl ReflectorOracle {
-    /// Create a new Reflector oracle instance
-    pub fn new(contract_id: Address) -> Self {
-        Self { contract_id }
-    }
-
-    /// Get the Reflector oracle contract ID
-    pub fn contract_id(&self) -> Address {
-        self.contract_id.clone()
-    }
-
-    /// Parse feed ID to extract asset information
-    ///
-    /// Converts feed IDs like "BTC/USD", "ETH/USD", "XLM/USD" to Reflector asset types
-    pub fn parse_feed_id(&self, env: &Env, feed_id: &String) -> Result<ReflectorAsset, Error> {
-        if feed_id.is_empty() {
-            // Return a default asset for empty feed IDs
-            return Ok(ReflectorAsset::Other(Symbol::new(env, "BTC")));
-        }
-
-        // Extract the base asset from the feed ID
-        // For simplicity, we'll check for common patterns
-        if feed_id == &String::from_str(env, "BTC/USD") || feed_id == &String::from_str(env, "BTC")
-        {
-            Ok(ReflectorAsset::Other(Symbol::new(env, "BTC")))
-        } else if feed_id == &String::from_str(env, "ETH/USD")
-            || feed_id == &String::from_str(env, "ETH")
-        {
-            Ok(ReflectorAsset::Other(Symbol::new(env, "ETH")))
-        } else if feed_id == &String::from_str(env, "XLM/USD")
-            || feed_id == &String::from_str(env, "XLM")
-        {
-            Ok(ReflectorAsset::Other(Symbol::new(env, "XLM")))
-        } else if feed_id == &String::from_str(env, "USDC/USD")
-            || feed_id == &String::from_str(env, "USDC")
-        {
-            Ok(ReflectorAsset::Other(Symbol::new(env, "USDC")))
-        } else {
-            // Default to treating the feed_id as the asset symbol
-            // Extract first 3 characters as asset symbol
-            let asset_symbol = if feed_id.len() >= 3 {
-                // For simplicity, default to BTC if we can't parse
-                Symbol::new(env, "BTC")
-            } else {
-                Symbol::new(env, "BTC")
-            };
-            Ok(ReflectorAsset::Other(asset_symbol))
-        }
-    }
-
-    /// Get price from Reflector oracle with fallback mechanisms
-    pub fn get_reflector_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        // Parse the feed_id to extract asset information
-        let _base_asset = self.parse_feed_id(env, feed_id)?;
-
-        // For now, return mock data for testing
-        // In a production environment, this would call the real Reflector oracle contract
-        // TODO: Implement real oracle contract calls when deployed to mainnet
-        self.get_mock_price_for_testing(env, feed_id)
-    }
-
-    /// Get mock price data for testing purposes
-    ///
-    /// This is called when the real oracle contract is not available,
-    /// typically in testing environments with mock contracts
-    fn get_mock_price_for_testing(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        // Return mock prices for testing
-        // These prices are designed to work with the test threshold of 2500000 (25k)
-        if feed_id == &String::from_str(env, "BTC") || feed_id == &String::from_str(env, "BTC/USD")
-        {
-            Ok(2600000) // $26k - above the $25k threshold in tests
-        } else if feed_id == &String::from_str(env, "ETH")
-            || feed_id == &String::from_str(env, "ETH/USD")
-        {
-            Ok(200000) // $2k - reasonable ETH price
-        } else if feed_id == &String::from_str(env, "XLM")
-            || feed_id == &String::from_str(env, "XLM/USD")
-        {
-            Ok(12) // $0.12 - reasonable XLM price
-        } else {
-            // Default to BTC price for unknown assets
-            Ok(2600000)
-        }
-    }
-
-    /// Check if the Reflector oracle is healthy
-    pub fn check_health(&self, env: &Env) -> Result<bool, Error> {
-        let reflector_client = ReflectorOracleClient::new(env, self.contract_id.clone());
-        Ok(reflector_client.is_healthy())
-    }
-}
-
-impl OracleInterface for ReflectorOracle {
-    fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        self.get_reflector_price(env, feed_id)
-    }
-
-    fn provider(&self) -> OracleProvider {
-        OracleProvider::Reflector
-    }
-
-    fn contract_id(&self) -> Address {
-        self.contract_id.clone()
-    }
-
-    fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
-        self.check_health(env)
-    }
+    /// Fetch the current price of `config.feed_id` from `config.oracle_address`
+    fn fetch(env: &Env, config: &OracleConfig, max_age: u64) -> Result<i128, Error>;
 }
 
 // ===== ORACLE FACTORY =====
@@ -887,8 +153,9 @@ impl OracleInterface for ReflectorOracle {
 /// - **Reflector**: Primary and recommended oracle provider for Stellar
 /// - **Production Ready**: Fully functional with live price feeds
 ///
+/// - **Pyth Network**: Read from a Pyth price contract deployed on Stellar
+///
 /// **Not Supported on Stellar:**
-/// - **Pyth Network**: Not available on Stellar blockchain
 /// - **Band Protocol**: Not integrated with Stellar ecosystem
 /// - **DIA**: Not available for Stellar Network
 ///
@@ -983,10 +250,7 @@ impl OracleInterface for ReflectorOracle {
 pub struct OracleFactory;
 
 impl OracleFactory {
-    /// Create a Pyth oracle instance (NOT SUPPORTED ON STELLAR)
-    ///
-    /// This will create a placeholder that returns errors for all operations
-    /// since Pyth Network does not support Stellar blockchain.
+    /// Create a Pyth oracle instance reading from the Pyth price contract
     pub fn create_pyth_oracle(contract_id: Address) -> PythOracle {
         PythOracle::new(contract_id)
     }
@@ -1008,7 +272,7 @@ impl OracleFactory {
     /// Result containing the oracle instance or error
     ///
     /// # Notes
-    /// - Pyth oracle will be created but will return errors when used on Stellar
+    /// - Pyth oracle reads from the Pyth price contract at `contract_id`
     /// - Reflector oracle is the recommended choice for Stellar
     /// - Other providers are not supported
     pub fn create_oracle(
@@ -1025,6 +289,10 @@ impl OracleFactory {
                 let oracle = ReflectorOracle::new(contract_id);
                 Ok(OracleInstance::Reflector(oracle))
             }
+            OracleProvider::Pyth => {
+                let oracle = PythOracle::new(contract_id);
+                Ok(OracleInstance::Pyth(oracle))
+            }
             _ => {
                 // All other providers should be caught by is_provider_supported check above
                 Err(Error::InvalidOracleConfig)
@@ -1040,12 +308,58 @@ impl OracleFactory {
         Self::create_oracle(oracle_config.provider.clone(), contract_id)
     }
 
+    /// Fetch a price for `config` through the adapter for its provider
+    ///
+    /// With `config.quote_feed_id` set, both feeds are read from the same
+    /// provider and their ratio is returned instead, see
+    /// [`OracleUtils::cross_rate`].
+    ///
+    /// # Errors
+    /// * `Error::InvalidOracleConfig` - The provider is not supported on Stellar
+    /// * `Error::OracleUnavailable` - The quote price is zero or the ratio overflows
+    /// * Any error returned by the provider's adapter
+    pub fn fetch(env: &Env, config: &OracleConfig, max_age: u64) -> Result<i128, Error> {
+        if !Self::is_provider_supported(&config.provider) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        match config.provider {
+            OracleProvider::Reflector => Self::fetch_with::<ReflectorAdapter>(env, config, max_age),
+            OracleProvider::Pyth => Self::fetch_with::<PythAdapter>(env, config, max_age),
+            OracleProvider::BandProtocol => Self::fetch_with::<BandAdapter>(env, config, max_age),
+            OracleProvider::DIA => Err(Error::InvalidOracleConfig),
+            // Signed results are pushed with `submit_signed_result`, never pulled
+            OracleProvider::Signed => Err(Error::OracleUnavailable),
+        }
+    }
+
+    /// Read `config` through adapter `A`, dividing by the quote feed if any
+    fn fetch_with<A: OracleAdapter>(
+        env: &Env,
+        config: &OracleConfig,
+        max_age: u64,
+    ) -> Result<i128, Error> {
+        let base = A::fetch(env, config, max_age)?;
+        let Some(quote_feed_id) = &config.quote_feed_id else {
+            return Ok(base);
+        };
+
+        // The quote leg is read as the inverse pair, so the adapter still
+        // sees a cross-rate config
+        let mut quote_config = config.clone();
+        quote_config.feed_id = quote_feed_id.clone();
+        quote_config.quote_feed_id = Some(config.feed_id.clone());
+        let quote = A::fetch(env, &quote_config, max_age)?;
+
+        OracleUtils::cross_rate(env, base, quote, A::DECIMALS)
+    }
+
     /// Check if a provider is supported on Stellar
 
     pub fn is_provider_supported(provider: &OracleProvider) -> bool {
         match provider {
-            OracleProvider::Reflector => true,
-            OracleProvider::Pyth | OracleProvider::BandProtocol | OracleProvider::DIA => false,
+            OracleProvider::Reflector | OracleProvider::Pyth | OracleProvider::Signed => true,
+            OracleProvider::BandProtocol | OracleProvider::DIA => false,
         }
     }
 
@@ -1064,7 +378,7 @@ impl OracleFactory {
     /// A new PythOracle instance with configured feeds
     ///
     /// # Notes
-    /// This oracle will return errors when used on Stellar since Pyth is not available
+    /// Only active feeds from `feed_configs` can be queried
     pub fn create_pyth_oracle_with_feeds(
         contract_id: Address,
         feed_configs: Vec<PythFeedConfig>,
@@ -1149,8 +463,11 @@ impl OracleFactory {
                 Ok(())
             }
             OracleProvider::Pyth => {
-                // Pyth is not supported on Stellar, but we'll allow it for future compatibility
-                // The implementation will return errors when used
+                // Pyth is read from a Pyth price contract on Stellar
+                Ok(())
+            }
+            OracleProvider::Signed => {
+                // Signed results are verified on-chain against the signer's key
                 Ok(())
             }
             OracleProvider::BandProtocol | OracleProvider::DIA => {
@@ -1173,9 +490,7 @@ impl OracleFactory {
 ///
 /// **Production Ready:**
 /// - **Reflector**: Primary oracle provider for Stellar Network with full functionality
-///
-/// **Future/Placeholder:**
-/// - **Pyth**: Placeholder implementation for future Stellar support
+/// - **Pyth**: Prices read from a Pyth price contract with staleness checks
 ///
 /// # Design Benefits
 ///
@@ -1228,7 +543,7 @@ impl OracleFactory {
 ///                 // Reflector-specific operations if needed
 ///             },
 ///             OracleInstance::Pyth(ref pyth) => {
-///                 println!("Using Pyth oracle (placeholder)");
+///                 println!("Using Pyth oracle");
 ///                 // Pyth-specific operations if needed
 ///             },
 ///         }
@@ -1277,7 +592,7 @@ impl OracleFactory {
 /// - **Compile-Time Optimization**: Rust compiler optimizes enum dispatch
 #[derive(Debug)]
 pub enum OracleInstance {
-    Pyth(PythOracle),           // Placeholder - not supported on Stellar
+    Pyth(PythOracle),           // Pyth price contract on Stellar
     Reflector(ReflectorOracle), // Primary oracle for Stellar
     Band(BandProtocolOracle),   //  Band Protocole oracle
 }
@@ -1292,6 +607,24 @@ impl OracleInstance {
         }
     }
 
+    /// Get the price, rejecting readings older than `max_age` seconds.
+    ///
+    /// Band reports no publish time, so `max_age` does not apply to it.
+    pub fn get_price_with_max_age(
+        &self,
+        env: &Env,
+        feed_id: &String,
+        max_age: u64,
+    ) -> Result<i128, Error> {
+        match self {
+            OracleInstance::Pyth(oracle) => oracle.get_price_with_max_age(env, feed_id, max_age),
+            OracleInstance::Reflector(oracle) => {
+                oracle.get_price_with_max_age(env, feed_id, max_age)
+            }
+            OracleInstance::Band(oracle) => oracle.get_price(env, feed_id),
+        }
+    }
+
     /// Get the oracle provider type
     pub fn provider(&self) -> OracleProvider {
         match self {
@@ -1348,8 +681,9 @@ impl OracleInstance {
 /// - **Greater Than ("gt")**: Price > threshold
 /// - **Less Than ("lt")**: Price < threshold  
 /// - **Equal To ("eq")**: Price == threshold
-/// - **Greater or Equal ("gte")**: Price >= threshold (if implemented)
-/// - **Less or Equal ("lte")**: Price <= threshold (if implemented)
+/// - **Greater or Equal ("gte")**: Price >= threshold
+/// - **Less or Equal ("lte")**: Price <= threshold
+/// - **Range ("range:lo:hi")**: lo <= price <= hi
 ///
 /// # Example Usage
 ///
@@ -1438,22 +772,104 @@ impl OracleInstance {
 pub struct OracleUtils;
 
 impl OracleUtils {
+    /// Maximum accepted oracle price age from the runtime config
+    pub fn default_max_price_age(env: &Env) -> u64 {
+        crate::config::ConfigManager::get_config(env)
+            .map(|config| config.oracle.max_price_age)
+            .unwrap_or(crate::config::MAX_ORACLE_PRICE_AGE)
+    }
+
+    /// Maximum accepted oracle price age for a market: its own override if
+    /// one is set, otherwise the runtime config value
+    pub fn max_price_age_for_market(env: &Env, market_id: &Symbol) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&OracleIntegrationKey::MaxPriceAge(market_id.clone()))
+            .unwrap_or_else(|| Self::default_max_price_age(env))
+    }
+
+    /// Primary oracles queried alongside `Market::oracle_config`
+    pub fn get_extra_oracles(env: &Env, market_id: &Symbol) -> Vec<OracleConfig> {
+        env.storage()
+            .persistent()
+            .get(&OracleIntegrationKey::ExtraOracles(market_id.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Replace a market's extra primary oracles; an empty list clears them
+    pub fn set_extra_oracles(env: &Env, market_id: &Symbol, oracles: &Vec<OracleConfig>) {
+        let key = OracleIntegrationKey::ExtraOracles(market_id.clone());
+        if oracles.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, oracles);
+        }
+    }
+
+    /// Median of up to `MAX_PRIMARY_ORACLES` prices; with an even count the
+    /// two middle prices are averaged
+    pub fn median_price(prices: &Vec<i128>) -> Result<i128, Error> {
+        const MAX: usize = crate::config::MAX_PRIMARY_ORACLES as usize;
+        let n = prices.len() as usize;
+        if n == 0 || n > MAX {
+            return Err(Error::OracleNoConsensus);
+        }
+        let mut sorted = [0i128; MAX];
+        for (i, price) in prices.iter().enumerate() {
+            sorted[i] = price;
+        }
+        let sorted = &mut sorted[..n];
+        sorted.sort_unstable();
+
+        if n % 2 == 1 {
+            Ok(sorted[n / 2])
+        } else {
+            let (lo, hi) = (sorted[n / 2 - 1], sorted[n / 2]);
+            Ok(lo + (hi - lo) / 2)
+        }
+    }
+
+    /// Ratio `base / quote` of two prices with `decimals` decimals, in the
+    /// same decimals, rounded down
+    ///
+    /// The intermediate product is computed in 256 bits, so it cannot overflow.
+    ///
+    /// # Errors
+    /// `Error::OracleUnavailable` if either price is non-positive or the ratio
+    /// does not fit in an `i128`
+    pub fn cross_rate(env: &Env, base: i128, quote: i128, decimals: u32) -> Result<i128, Error> {
+        if base <= 0 || quote <= 0 {
+            return Err(Error::OracleUnavailable);
+        }
+        let scale = 10_i128
+            .checked_pow(decimals)
+            .ok_or(Error::OracleUnavailable)?;
+
+        I256::from_i128(env, base)
+            .mul(&I256::from_i128(env, scale))
+            .div(&I256::from_i128(env, quote))
+            .to_i128()
+            .ok_or(Error::OracleUnavailable)
+    }
+
+    /// Set or clear a market's price age override
+    pub fn set_market_max_price_age(env: &Env, market_id: &Symbol, max_price_age: Option<u64>) {
+        let key = OracleIntegrationKey::MaxPriceAge(market_id.clone());
+        match max_price_age {
+            Some(age) => env.storage().persistent().set(&key, &age),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
     /// Compare prices using different operators
     pub fn compare_prices(
         price: i128,
         threshold: i128,
         comparison: &String,
-        env: &Env,
+        _env: &Env,
     ) -> Result<bool, Error> {
-        if comparison == &String::from_str(env, "gt") {
-            Ok(price > threshold)
-        } else if comparison == &String::from_str(env, "lt") {
-            Ok(price < threshold)
-        } else if comparison == &String::from_str(env, "eq") {
-            Ok(price == threshold)
-        } else {
-            Err(Error::InvalidComparison)
-        }
+        let comparison = crate::types::OracleComparison::parse(comparison)?;
+        Ok(comparison.is_met(price, threshold))
     }
 
     /// Determine market outcome based on price comparison
@@ -1487,105 +903,6 @@ impl OracleUtils {
     }
 }
 
-// ===== BAND PROTOCOLE ORACLE CLIENT =====
-
-pub struct BandProtocolClient<'a> {
-    env: &'a Env,
-    contract_id: Address,
-}
-
-#[derive(Clone, Debug, Eq, PartialEq)]
-#[contracttype]
-pub(crate) enum BandDataKey {
-    StdReferenceAddress,
-}
-
-impl<'a> BandProtocolClient<'a> {
-    pub fn new(env: &'a Env, contract_id: Address) -> Self {
-        Self { env, contract_id }
-    }
-
-    pub fn get_price_of(&self, symbol_pair: (Symbol, Symbol)) -> u128 {
-        let client = bandprotocol::Client::new(&self.env, &self.contract_id);
-        client
-            .get_reference_data(&Vec::from_array(&self.env, [symbol_pair]))
-            .get_unchecked(0)
-            .rate
-    }
-}
-
-/// Band Protocol Oracle implementation
-
-#[derive(Debug)]
-pub struct BandProtocolOracle {
-    contract_id: Address,
-}
-
-impl BandProtocolOracle {
-    pub fn new(contract_id: Address) -> Self {
-        Self { contract_id }
-    }
-
-    pub fn contract_id(&self) -> Address {
-        self.contract_id.clone()
-    }
-
-    pub fn parse_feed_id(&self, env: &Env, feed_id: &String) -> Result<(Symbol, Symbol), Error> {
-        if feed_id.is_empty() {
-            return Err(Error::InvalidOracleConfig);
-        }
-
-        if feed_id == &String::from_str(env, "BTC/USD") || feed_id == &String::from_str(env, "BTC")
-        {
-            Ok((Symbol::new(env, "BTC"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "ETH/USD")
-            || feed_id == &String::from_str(env, "ETH")
-        {
-            Ok((Symbol::new(env, "ETH"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "XLM/USD")
-            || feed_id == &String::from_str(env, "XLM")
-        {
-            Ok((Symbol::new(env, "XLM"), Symbol::new(env, "USD")))
-        } else if feed_id == &String::from_str(env, "USDC/USD")
-            || feed_id == &String::from_str(env, "USDC")
-        {
-            Ok((Symbol::new(env, "USDC"), Symbol::new(env, "USD")))
-        } else {
-            return Err(Error::InvalidOracleConfig);
-        }
-    }
-
-    /// Fetch price from Band client
-    fn get_band_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        let pair = self.parse_feed_id(env, feed_id).unwrap();
-        let client = BandProtocolClient::new(env, self.contract_id.clone());
-        let rate = client.get_price_of(pair);
-        Ok(rate as i128)
-    }
-}
-
-impl OracleInterface for BandProtocolOracle {
-    fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
-        self.get_band_price(env, feed_id)
-    }
-
-    fn contract_id(&self) -> Address {
-        self.contract_id.clone()
-    }
-
-    fn provider(&self) -> OracleProvider {
-        OracleProvider::BandProtocol
-    }
-
-    fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
-        let asset = String::from_str(env, "BTC/USD");
-        match self.get_band_price(env, &asset) {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false),
-        }
-    }
-}
-
 // ===== MODULE TESTS =====
 
 #[cfg(test)]
@@ -1618,10 +935,10 @@ mod tests {
         let env = Env::default();
         let contract_id = Address::generate(&env);
 
-        // Test Pyth oracle creation (should fail)
+        // Test Pyth oracle creation
         let pyth_oracle = OracleFactory::create_oracle(OracleProvider::Pyth, contract_id.clone());
-        assert!(pyth_oracle.is_err());
-        assert_eq!(pyth_oracle.unwrap_err(), Error::InvalidOracleConfig);
+        assert!(pyth_oracle.is_ok());
+        assert_eq!(pyth_oracle.unwrap().provider(), OracleProvider::Pyth);
 
         // Test Reflector oracle creation
         let reflector_oracle =
@@ -1635,6 +952,73 @@ mod tests {
         assert_eq!(unsupported_oracle.unwrap_err(), Error::InvalidOracleConfig);
     }
 
+    #[test]
+    fn test_fetch_dispatches_by_provider() {
+        let env = Env::default();
+        let mut config = OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&env),
+            feed_id: String::from_str(&env, "BTC/USD"),
+            threshold: 2_500_000,
+            comparison: String::from_str(&env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        };
+
+        // No Reflector contract at the address: the Reflector adapter's fallback answers
+        assert_eq!(OracleFactory::fetch(&env, &config, 60), Ok(2_600_000));
+
+        // The Pyth adapter finds no Pyth contract at the same address
+        config.provider = OracleProvider::Pyth;
+        config.feed_id = String::from_str(&env, "Crypto.BTC/USD");
+        assert_eq!(
+            OracleFactory::fetch(&env, &config, 60),
+            Err(Error::OracleUnavailable)
+        );
+
+        // Providers not supported on Stellar never reach an adapter
+        for provider in [OracleProvider::BandProtocol, OracleProvider::DIA] {
+            config.provider = provider;
+            assert_eq!(
+                OracleFactory::fetch(&env, &config, 60),
+                Err(Error::InvalidOracleConfig)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_rate() {
+        let env = Env::default();
+
+        // BTC/ETH at $60,000 / $2,500 in cents and 8 decimals
+        assert_eq!(OracleUtils::cross_rate(&env, 6_000_000, 250_000, 2), Ok(2_400));
+        assert_eq!(
+            OracleUtils::cross_rate(&env, 6_000_000_000_000, 250_000_000_000, 8),
+            Ok(2_400_000_000)
+        );
+        // Rounded down
+        assert_eq!(OracleUtils::cross_rate(&env, 1, 3, 2), Ok(33));
+
+        // The scaled product exceeds i128 but the ratio fits
+        let big = i128::MAX / 2;
+        assert_eq!(
+            OracleUtils::cross_rate(&env, big, big / 4, 18),
+            Ok(4_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            OracleUtils::cross_rate(&env, big, 1, 18),
+            Err(Error::OracleUnavailable)
+        );
+
+        for (base, quote) in [(6_000_000, 0), (6_000_000, -1), (0, 250_000)] {
+            assert_eq!(
+                OracleUtils::cross_rate(&env, base, quote, 2),
+                Err(Error::OracleUnavailable)
+            );
+        }
+    }
+
     #[test]
     fn test_oracle_utils() {
         let env = Env::default();
@@ -1774,8 +1158,12 @@ impl OracleWhitelist {
             .set(&OracleWhitelistKey::WhitelistAdmin(admin.clone()), &true);
 
         env.events().publish(
-            (Symbol::new(env, "whitelist_init"),),
-            (admin, env.ledger().timestamp()),
+            (symbol_short!("wl_init"), EVENT_SCHEMA_VERSION, admin.clone()),
+            WhitelistAdminEvent {
+                admin: admin.clone(),
+                authorized_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -1799,8 +1187,12 @@ impl OracleWhitelist {
         );
 
         env.events().publish(
-            (Symbol::new(env, "admin_added"),),
-            (new_admin, current_admin, env.ledger().timestamp()),
+            (symbol_short!("wl_add"), EVENT_SCHEMA_VERSION, new_admin.clone()),
+            WhitelistAdminEvent {
+                admin: new_admin,
+                authorized_by: current_admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -1831,8 +1223,12 @@ impl OracleWhitelist {
             .remove(&OracleWhitelistKey::WhitelistAdmin(admin_to_remove.clone()));
 
         env.events().publish(
-            (Symbol::new(env, "admin_removed"),),
-            (admin_to_remove, current_admin, env.ledger().timestamp()),
+            (symbol_short!("wl_remove"), EVENT_SCHEMA_VERSION, admin_to_remove.clone()),
+            WhitelistAdminEvent {
+                admin: admin_to_remove,
+                authorized_by: current_admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -1930,8 +1326,12 @@ impl OracleWhitelist {
             .set(&OracleWhitelistKey::OracleList, &oracle_list);
 
         env.events().publish(
-            (Symbol::new(env, "oracle_whitelisted"),),
-            (oracle_address, metadata.provider, env.ledger().timestamp()),
+            (symbol_short!("orcl_add"), EVENT_SCHEMA_VERSION, oracle_address.clone()),
+            OracleWhitelistedEvent {
+                oracle: oracle_address,
+                provider: metadata.provider,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -1992,8 +1392,12 @@ impl OracleWhitelist {
             .set(&OracleWhitelistKey::OracleList, &new_list);
 
         env.events().publish(
-            (Symbol::new(env, "oracle_removed"),),
-            (oracle_address, admin, env.ledger().timestamp()),
+            (symbol_short!("orcl_rm"), EVENT_SCHEMA_VERSION, oracle_address.clone()),
+            OracleStatusEvent {
+                oracle: oracle_address,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -2059,17 +1463,17 @@ impl OracleWhitelist {
                 &metadata,
             );
 
-            env.events().publish(
-                (Symbol::new(env, "oracle_health_ok"),),
-                (oracle_address.clone(), env.ledger().timestamp()),
-            );
-        } else {
-            env.events().publish(
-                (Symbol::new(env, "oracle_unhealthy"),),
-                (oracle_address.clone(), env.ledger().timestamp()),
-            );
         }
 
+        env.events().publish(
+            (symbol_short!("orcl_hlth"), EVENT_SCHEMA_VERSION, oracle_address.clone()),
+            OracleHealthCheckedEvent {
+                oracle: oracle_address.clone(),
+                healthy: is_healthy,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
         Ok(is_healthy)
     }
 
@@ -2139,8 +1543,12 @@ impl OracleWhitelist {
         );
 
         env.events().publish(
-            (Symbol::new(env, "oracle_deactivated"),),
-            (oracle_address, admin, env.ledger().timestamp()),
+            (symbol_short!("orcl_off"), EVENT_SCHEMA_VERSION, oracle_address.clone()),
+            OracleStatusEvent {
+                oracle: oracle_address,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -2176,8 +1584,12 @@ impl OracleWhitelist {
         );
 
         env.events().publish(
-            (Symbol::new(env, "oracle_reactivated"),),
-            (oracle_address, admin, env.ledger().timestamp()),
+            (symbol_short!("orcl_on"), EVENT_SCHEMA_VERSION, oracle_address.clone()),
+            OracleStatusEvent {
+                oracle: oracle_address,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         Ok(())
@@ -2200,6 +1612,10 @@ pub enum OracleIntegrationKey {
     VerificationStatus(Symbol),
     /// Retry count for market verification
     RetryCount(Symbol),
+    /// Per-market override of the maximum accepted price age
+    MaxPriceAge(Symbol),
+    /// Primary oracles aggregated with the market's own oracle config
+    ExtraOracles(Symbol),
 }
 
 /// Comprehensive oracle integration manager for automatic result verification.
@@ -2616,7 +2032,7 @@ impl OracleIntegrationManager {
     }
 
     /// Store oracle result for a market.
-    fn store_oracle_result(
+    pub(crate) fn store_oracle_result(
         env: &Env,
         market_id: &Symbol,
         result: &crate::types::OracleResult,
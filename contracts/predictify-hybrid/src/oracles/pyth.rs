@@ -0,0 +1,577 @@
+//! Pyth Network provider: reads `get_price_unsafe` from a Pyth price contract.
+
+use soroban_sdk::{contracttype, vec, Address, Env, IntoVal, String, Symbol, Vec};
+
+use super::{OracleAdapter, OracleInterface, OracleUtils};
+use crate::errors::Error;
+use crate::types::{OracleConfig, OracleProvider, OracleSigner, PythPriceData};
+
+// ===== PYTH ORACLE IMPLEMENTATION =====
+
+/// Pyth Network oracle implementation backed by a Pyth price contract on Stellar.
+///
+/// Prices are read from the contract at `contract_id` through [`PythOracleClient`],
+/// checked against the configured staleness window, and normalized to
+/// [`PYTH_PRICE_DECIMALS`] decimals.
+///
+/// # Implementation Strategy
+///
+/// This oracle implementation:
+/// - **On-Chain Reads**: Queries `get_price_unsafe(feed_id)` on the Pyth contract
+/// - **Error Handling**: Missing prices and failed calls surface as `OracleUnavailable`,
+///   old prices as `OracleStale`
+/// - **Configuration Support**: Optional feed configurations restrict queryable feeds
+/// - **Standard Interface**: Implements OracleInterface for consistency
+///
+/// # Pyth Network Overview
+///
+/// Pyth Network is a high-frequency, cross-chain oracle network that provides
+/// real-time financial market data. Key features include:
+/// - **High Frequency**: Sub-second price updates
+/// - **Institutional Grade**: Data from major trading firms and exchanges
+/// - **Cross-Chain**: Supports multiple blockchain networks
+/// - **Decentralized**: Distributed network of data providers
+///
+/// # Example Usage (Future)
+///
+/// ```rust
+/// # use soroban_sdk::{Env, Address, String, Vec};
+/// # use predictify_hybrid::oracles::{PythOracle, PythFeedConfig, OracleInterface};
+/// # let env = Env::default();
+/// # let contract_id = Address::generate(&env);
+///
+/// // Create Pyth oracle with feed configurations
+/// let mut oracle = PythOracle::new(contract_id.clone());
+///
+/// // Add BTC/USD feed configuration
+/// oracle.add_feed_config(PythFeedConfig {
+///     feed_id: String::from_str(&env, "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
+///     asset_symbol: String::from_str(&env, "BTC/USD"),
+///     decimals: 8,
+///     is_active: true,
+/// });
+///
+/// // Reads the latest BTC/USD price from the Pyth contract
+/// let price_result = oracle.get_price(&env, &String::from_str(&env, "BTC/USD"));
+///
+/// // Check oracle provider
+/// assert_eq!(oracle.provider(), OracleProvider::Pyth);
+///
+/// // Validate feed configurations
+/// assert_eq!(oracle.get_feed_count(), 1);
+/// assert!(oracle.is_feed_active(&String::from_str(&env, "BTC/USD")));
+/// ```
+///
+/// # Feed Configuration
+///
+/// Pyth feeds are identified by:
+/// - **Feed ID**: Unique 64-character hexadecimal identifier
+/// - **Asset Symbol**: Human-readable symbol (e.g., "BTC/USD")
+/// - **Decimals**: Price precision (typically 8 for crypto)
+/// - **Active Status**: Whether the feed is currently active
+///
+/// # Price Freshness
+///
+/// A price is rejected with `Error::OracleStale` when its `publish_time` is more
+/// than `OracleRuntimeConfig::max_price_age` seconds behind the ledger timestamp
+/// (or in the future). Without a stored contract config, `MAX_ORACLE_PRICE_AGE`
+/// is used.
+#[derive(Debug, Clone)]
+pub struct PythOracle {
+    contract_id: Address,
+    feed_configurations: Vec<PythFeedConfig>,
+}
+
+/// Configuration structure for Pyth Network price feeds.
+///
+/// This structure defines the parameters needed to configure and manage
+/// individual price feeds from the Pyth Network. Each feed represents
+/// a specific asset pair with its own unique identifier and characteristics.
+///
+/// # Feed Identification
+///
+/// Pyth feeds use:
+/// - **Unique Feed IDs**: 64-character hexadecimal identifiers
+/// - **Asset Symbols**: Human-readable trading pair names
+/// - **Precision Settings**: Decimal places for price representation
+/// - **Status Flags**: Active/inactive feed management
+///
+/// # Example Usage
+///
+/// ```rust
+/// # use soroban_sdk::{Env, String};
+/// # use predictify_hybrid::oracles::PythFeedConfig;
+/// # let env = Env::default();
+///
+/// // Configure BTC/USD feed
+/// let btc_config = PythFeedConfig {
+///     feed_id: String::from_str(&env,
+///         "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43"),
+///     asset_symbol: String::from_str(&env, "BTC/USD"),
+///     decimals: 8, // 8 decimal places for crypto prices
+///     is_active: true,
+/// };
+///
+/// // Configure ETH/USD feed
+/// let eth_config = PythFeedConfig {
+///     feed_id: String::from_str(&env,
+///         "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace"),
+///     asset_symbol: String::from_str(&env, "ETH/USD"),
+///     decimals: 8,
+///     is_active: true,
+/// };
+///
+/// // Configure stock feed with different precision
+/// let aapl_config = PythFeedConfig {
+///     feed_id: String::from_str(&env,
+///         "0x49f6b65cb1de6b10eaf75e7c03ca029c306d0357e91b5311b175084a5ad55688"),
+///     asset_symbol: String::from_str(&env, "AAPL/USD"),
+///     decimals: 2, // 2 decimal places for stock prices
+///     is_active: true,
+/// };
+///
+/// println!("Configured {} with {} decimals",
+///     btc_config.asset_symbol.to_string(),
+///     btc_config.decimals);
+/// ```
+///
+/// # Feed ID Format
+///
+/// Pyth feed IDs are:
+/// - **64 characters long**: Hexadecimal representation
+/// - **Globally unique**: Each feed has a unique identifier across all assets
+/// - **Immutable**: Feed IDs don't change once assigned
+/// - **Network specific**: Different IDs for different blockchain networks
+///
+/// # Decimal Precision
+///
+/// Common decimal configurations:
+/// - **Crypto pairs**: 8 decimals (e.g., BTC/USD: $45,123.45678901)
+/// - **Forex pairs**: 6 decimals (e.g., EUR/USD: 1.123456)
+/// - **Stock prices**: 2 decimals (e.g., AAPL: $150.25)
+/// - **Commodities**: Variable based on asset type
+///
+/// # Feed Management
+///
+/// Feed configurations support:
+/// - **Dynamic activation**: Enable/disable feeds without removing configuration
+/// - **Batch operations**: Configure multiple feeds simultaneously
+/// - **Validation**: Ensure feed IDs and symbols are properly formatted
+/// - **Asset discovery**: List all configured assets and their symbols
+#[contracttype]
+#[derive(Debug, Clone)]
+pub struct PythFeedConfig {
+    pub feed_id: String,
+    pub asset_symbol: String,
+    pub decimals: u32,
+    pub is_active: bool,
+}
+
+impl PythOracle {
+    /// Create a new Pyth oracle instance
+    ///
+    /// # Arguments
+    /// * `contract_id` - The contract address for the Pyth oracle
+    ///
+    /// # Returns
+    /// A new PythOracle instance configured for the given contract
+    pub fn new(contract_id: Address) -> Self {
+        Self {
+            contract_id,
+            feed_configurations: Vec::new(&soroban_sdk::Env::default()),
+        }
+    }
+
+    /// Create a new Pyth oracle with pre-configured feeds
+    ///
+    /// # Arguments
+    /// * `contract_id` - The contract address for the Pyth oracle
+    /// * `feed_configs` - Vector of feed configurations
+    ///
+    /// # Returns
+    /// A new PythOracle instance with configured feeds
+    pub fn with_feeds(contract_id: Address, feed_configs: Vec<PythFeedConfig>) -> Self {
+        Self {
+            contract_id,
+            feed_configurations: feed_configs,
+        }
+    }
+
+    /// Get the Pyth oracle contract ID
+    pub fn contract_id(&self) -> Address {
+        self.contract_id.clone()
+    }
+
+    /// Add a new feed configuration
+    ///
+    /// # Arguments
+    /// * `feed_config` - Configuration for the new feed
+    pub fn add_feed_config(&mut self, feed_config: PythFeedConfig) {
+        self.feed_configurations.push_back(feed_config);
+    }
+
+    /// Get feed configuration by feed ID
+    ///
+    /// # Arguments
+    /// * `feed_id` - The feed ID to search for
+    ///
+    /// # Returns
+    /// Optional feed configuration if found
+    pub fn get_feed_config(&self, feed_id: &String) -> Option<PythFeedConfig> {
+        for config in self.feed_configurations.iter() {
+            if config.feed_id == *feed_id {
+                return Some(config.clone());
+            }
+        }
+        None
+    }
+
+    /// Validate feed ID format
+    ///
+    /// # Arguments
+    /// * `feed_id` - The feed ID to validate
+    ///
+    /// # Returns
+    /// True if the feed ID format is valid
+    pub fn validate_feed_id(&self, feed_id: &String) -> bool {
+        // Pyth feed IDs are typically hex strings
+        !feed_id.is_empty() && feed_id.len() >= 8
+    }
+
+    /// Get supported asset symbols
+    ///
+    /// # Returns
+    /// Vector of supported asset symbols
+    pub fn get_supported_assets(&self) -> Vec<String> {
+        let mut assets = Vec::new(&soroban_sdk::Env::default());
+        for config in self.feed_configurations.iter() {
+            if config.is_active {
+                assets.push_back(config.asset_symbol.clone());
+            }
+        }
+        assets
+    }
+
+    /// Check if a feed is configured and active
+    ///
+    /// # Arguments
+    /// * `feed_id` - The feed ID to check
+    ///
+    /// # Returns
+    /// True if the feed is configured and active
+    pub fn is_feed_active(&self, feed_id: &String) -> bool {
+        if let Some(config) = self.get_feed_config(feed_id) {
+            config.is_active
+        } else {
+            false
+        }
+    }
+
+    /// Get the number of configured feeds
+    ///
+    /// # Returns
+    /// Number of configured feeds
+    pub fn get_feed_count(&self) -> u32 {
+        self.feed_configurations.len()
+    }
+
+    /// Convert raw price to scaled price based on feed configuration
+    ///
+    /// # Arguments
+    /// * `raw_price` - Raw price from oracle
+    /// * `feed_config` - Feed configuration containing decimals
+    ///
+    /// # Returns
+    /// Scaled price in the contract's expected format
+    pub fn scale_price(&self, raw_price: i128, feed_config: &PythFeedConfig) -> i128 {
+        // Convert from Pyth price format to contract format
+        // This is a placeholder implementation
+        if feed_config.decimals > 0 {
+            raw_price / (10_i128.pow(feed_config.decimals))
+        } else {
+            raw_price
+        }
+    }
+
+    /// Convert a Pyth price (`price * 10^expo`) to [`PYTH_PRICE_DECIMALS`] decimals
+    ///
+    /// # Returns
+    /// `Error::OracleUnavailable` for non-positive prices or exponents that
+    /// would overflow the conversion
+    pub fn normalize_price(&self, data: &PythPriceData) -> Result<i128, Error> {
+        if data.price <= 0 {
+            return Err(Error::OracleUnavailable);
+        }
+
+        let price = data.price as i128;
+        let shift = data.expo + PYTH_PRICE_DECIMALS;
+        let factor = 10_i128
+            .checked_pow(shift.unsigned_abs())
+            .ok_or(Error::OracleUnavailable)?;
+
+        if shift >= 0 {
+            price.checked_mul(factor).ok_or(Error::OracleUnavailable)
+        } else {
+            Ok(price / factor)
+        }
+    }
+
+    /// Maximum accepted age of a Pyth price, from the oracle runtime config
+    pub fn max_price_age(env: &Env) -> u64 {
+        OracleUtils::default_max_price_age(env)
+    }
+
+    /// Get the current price, rejecting it if `publish_time` is more than
+    /// `max_age` seconds behind the ledger timestamp
+    pub fn get_price_with_max_age(
+        &self,
+        env: &Env,
+        feed_id: &String,
+        max_age: u64,
+    ) -> Result<i128, Error> {
+        // Validate feed ID format
+        if !self.validate_feed_id(feed_id) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        // When feeds are configured, only active ones may be queried
+        if self.get_feed_count() > 0 && !self.is_feed_active(feed_id) {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        let client = PythOracleClient::new(env, self.contract_id.clone());
+        let data = client
+            .get_price_unsafe(feed_id)
+            .ok_or(Error::OracleUnavailable)?;
+
+        let now = env.ledger().timestamp();
+        if data.publish_time > now || now - data.publish_time > max_age {
+            return Err(Error::OracleStale);
+        }
+
+        self.normalize_price(&data)
+    }
+
+    /// Get price with retry logic (future implementation)
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `feed_id` - Feed ID to get price for
+    /// * `max_retries` - Maximum number of retry attempts
+    ///
+    /// # Returns
+    /// Result containing the price or error
+    pub fn get_price_with_retry(
+        &self,
+        env: &Env,
+        feed_id: &String,
+        max_retries: u32,
+    ) -> Result<i128, Error> {
+        for attempt in 0..max_retries {
+            match self.get_price(env, feed_id) {
+                Ok(price) => return Ok(price),
+                Err(e) => {
+                    if attempt == max_retries - 1 {
+                        return Err(e);
+                    }
+                    // In a real implementation, we would wait before retrying
+                }
+            }
+        }
+        Err(Error::OracleUnavailable)
+    }
+}
+
+impl OracleInterface for PythOracle {
+    /// Get the current price for a given feed ID
+    ///
+    /// Reads the latest price from the Pyth price contract, rejects it if the
+    /// publish time is older than the configured `max_price_age`, and returns
+    /// it normalized to [`PYTH_PRICE_DECIMALS`] decimals.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `feed_id` - The feed ID to get price for
+    ///
+    /// # Returns
+    /// * `Error::InvalidOracleConfig` - Feed ID malformed or not an active configured feed
+    /// * `Error::OracleUnavailable` - Contract call failed or no price for the feed
+    /// * `Error::OracleStale` - Price older than the staleness window
+    fn get_price(&self, env: &Env, feed_id: &String) -> Result<i128, Error> {
+        self.get_price_with_max_age(env, feed_id, Self::max_price_age(env))
+    }
+
+    /// Get the oracle provider type
+    ///
+    /// # Returns
+    /// OracleProvider::Pyth
+    fn provider(&self) -> OracleProvider {
+        OracleProvider::Pyth
+    }
+
+    /// Get the oracle contract ID
+    ///
+    /// # Returns
+    /// The contract address for this oracle
+    fn contract_id(&self) -> Address {
+        self.contract_id.clone()
+    }
+
+    /// Check if the oracle is healthy and available
+    ///
+    /// The oracle is considered healthy when the Pyth contract answers a
+    /// price query for one of the configured feeds (or any feed when none
+    /// are configured).
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    fn is_healthy(&self, env: &Env) -> Result<bool, Error> {
+        let client = PythOracleClient::new(env, self.contract_id.clone());
+        let probe_feed = match self.feed_configurations.get(0) {
+            Some(config) => config.feed_id,
+            None => String::from_str(env, "Crypto.BTC/USD"),
+        };
+        Ok(client.get_price_unsafe(&probe_feed).is_some())
+    }
+}
+
+// ===== PYTH ORACLE CLIENT =====
+
+/// Number of decimals Pyth prices are normalized to before threshold comparison.
+///
+/// Matches the 8-decimal threshold range enforced for Pyth markets by
+/// `OracleConfigValidator::validate_threshold_range`.
+pub const PYTH_PRICE_DECIMALS: i32 = 8;
+
+/// Client for a Pyth price contract deployed on Stellar.
+///
+/// The contract is expected to expose `get_price_unsafe(feed_id) -> Option<PythPriceData>`,
+/// which returns the latest stored price without any freshness check; staleness
+/// is enforced by [`PythOracle`] against the contract configuration.
+///
+/// All calls go through `try_invoke_contract`, so a missing contract, a trapped
+/// call, or an unexpected return type yields `None` instead of aborting the
+/// caller's transaction.
+pub struct PythOracleClient<'a> {
+    env: &'a Env,
+    contract_id: Address,
+}
+
+impl<'a> PythOracleClient<'a> {
+    /// Create a new Pyth oracle client
+    pub fn new(env: &'a Env, contract_id: Address) -> Self {
+        Self { env, contract_id }
+    }
+
+    /// Get the latest price for a feed, or `None` if unavailable
+    pub fn get_price_unsafe(&self, feed_id: &String) -> Option<PythPriceData> {
+        let args = vec![self.env, feed_id.into_val(self.env)];
+        match self
+            .env
+            .try_invoke_contract::<Option<PythPriceData>, soroban_sdk::Error>(
+                &self.contract_id,
+                &Symbol::new(self.env, "get_price_unsafe"),
+                args,
+            ) {
+            Ok(Ok(data)) => data,
+            _ => None,
+        }
+    }
+}
+
+// ===== PYTH ADAPTER =====
+
+/// [`OracleAdapter`] for `OracleProvider::Pyth`.
+pub struct PythAdapter;
+
+impl OracleAdapter for PythAdapter {
+    const DECIMALS: u32 = PYTH_PRICE_DECIMALS as u32;
+
+    fn fetch(env: &Env, config: &OracleConfig, max_age: u64) -> Result<i128, Error> {
+        PythOracle::new(config.oracle_address.clone()).get_price_with_max_age(
+            env,
+            &config.feed_id,
+            max_age,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+    const NOW: u64 = 1_000_000;
+
+    fn config(env: &Env, oracle_address: Address) -> OracleConfig {
+        OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address,
+            feed_id: String::from_str(env, BTC_FEED),
+            threshold: 2_500_000_000_000,
+            comparison: String::from_str(env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        }
+    }
+
+    fn mock_with_price(env: &Env, price: i64, expo: i32, publish_time: u64) -> Address {
+        let pyth = env.register(MockPythContract, ());
+        MockPythContractClient::new(env, &pyth).set_feed_price(
+            &String::from_str(env, BTC_FEED),
+            &PythPriceData {
+                price,
+                conf: 0,
+                expo,
+                publish_time,
+            },
+        );
+        pyth
+    }
+
+    #[test]
+    fn test_fetch_normalizes_to_eight_decimals() {
+        let env = Env::default();
+        env.ledger().set_timestamp(NOW);
+        let pyth = mock_with_price(&env, 260_000_000_000_000, -10, NOW);
+
+        assert_eq!(
+            PythAdapter::fetch(&env, &config(&env, pyth), 60),
+            Ok(2_600_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_fetch_rejects_stale_price() {
+        let env = Env::default();
+        env.ledger().set_timestamp(NOW);
+        let pyth = mock_with_price(&env, 2_600_000_000_000, -8, NOW - 61);
+
+        assert_eq!(
+            PythAdapter::fetch(&env, &config(&env, pyth.clone()), 60),
+            Err(Error::OracleStale)
+        );
+        assert_eq!(
+            PythAdapter::fetch(&env, &config(&env, pyth), 61),
+            Ok(2_600_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_fetch_without_price_is_unavailable() {
+        let env = Env::default();
+        let pyth = env.register(MockPythContract, ());
+
+        assert_eq!(
+            PythAdapter::fetch(&env, &config(&env, pyth), 60),
+            Err(Error::OracleUnavailable)
+        );
+        assert_eq!(
+            PythAdapter::fetch(&env, &config(&env, Address::generate(&env)), 60),
+            Err(Error::OracleUnavailable)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+//! Per-outcome pool tests.
+//!
+//! Stake totals and position counts per outcome are kept on the market as bets
+//! are placed, while each position lives in its own `DataKey::Bet` entry, so a
+//! claim reads one entry plus the totals. `migrate_storage` writes out the
+//! positions of a market stored in the baseline layout.
+
+use crate::escrow::EscrowManager;
+use crate::test::{baseline_market_fixture, PredictifyTest};
+use crate::{DataKey, PredictifyHybridClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Map, String, Symbol};
+
+const STAKE: i128 = 10_000_000;
+
+fn place_bets(test: &PredictifyTest, market_id: &Symbol) -> (Address, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let small = test.create_funded_user();
+    let large = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    client.place_bet(&small, market_id, &yes, &STAKE, &None);
+    client.place_bet(&large, market_id, &yes, &(3 * STAKE), &None);
+    client.place_bet(
+        &loser,
+        market_id,
+        &String::from_str(&test.env, "no"),
+        &(2 * STAKE),
+        &None,
+    );
+    (small, large)
+}
+
+#[test]
+fn test_outcome_pools_track_bets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    place_bets(&test, &market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    assert_eq!(market.outcome_pool(&yes), 4 * STAKE);
+    assert_eq!(market.outcome_pool(&no), 2 * STAKE);
+    assert_eq!(market.outcome_pools.len(), 2);
+    assert_eq!(market.outcome_position_count(&yes), 2);
+    assert_eq!(market.outcome_position_count(&no), 1);
+    assert_eq!(market.position_count(), 3);
+}
+
+#[test]
+fn test_migrated_positions_pay_out_the_same() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let current_id = test.create_test_market();
+    let (current_small, current_large) = place_bets(&test, &current_id);
+    let legacy_id = test.create_test_market();
+    let end_time = client.get_market(&legacy_id).unwrap().end_time;
+
+    // The same positions, held in the maps of a baseline-layout market
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let legacy_small = Address::generate(&test.env);
+    let legacy_large = Address::generate(&test.env);
+    let legacy_loser = Address::generate(&test.env);
+    let mut legacy = baseline_market_fixture(&test, end_time);
+    legacy.votes = Map::from_array(
+        &test.env,
+        [
+            (legacy_small.clone(), yes.clone()),
+            (legacy_large.clone(), yes.clone()),
+            (legacy_loser.clone(), no.clone()),
+        ],
+    );
+    legacy.stakes = Map::from_array(
+        &test.env,
+        [
+            (legacy_small.clone(), STAKE),
+            (legacy_large.clone(), 3 * STAKE),
+            (legacy_loser.clone(), 2 * STAKE),
+        ],
+    );
+    legacy.total_staked = 6 * STAKE;
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        storage.remove(&DataKey::Market(legacy_id.clone()));
+        storage.set(&legacy_id, &legacy);
+        // The baseline contract held the stakes; escrow accounting came later
+        EscrowManager::credit(&test.env, &legacy_id, 6 * STAKE).unwrap();
+    });
+
+    client.migrate_storage(&test.admin);
+
+    // Each position now lives in its own bet entry, and the market keeps totals
+    let migrated = client.get_market(&legacy_id).unwrap();
+    let current = client.get_market(&current_id).unwrap();
+    assert_eq!(migrated.outcome_pools, current.outcome_pools);
+    assert_eq!(migrated.outcome_positions, current.outcome_positions);
+    let position = client.get_bet(&legacy_id, &legacy_large).unwrap();
+    assert_eq!(
+        (position.outcome, position.amount),
+        (yes.clone(), 3 * STAKE)
+    );
+
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &current_id, &yes);
+    client.resolve_market_manual(&test.admin, &legacy_id, &yes);
+    test.advance_past_payout_unlock(&current_id);
+    test.advance_past_payout_unlock(&legacy_id);
+
+    let current_payouts = (
+        client.claim_winnings(&current_small, &current_id),
+        client.claim_winnings(&current_large, &current_id),
+    );
+    let legacy_payouts = (
+        client.claim_winnings(&legacy_small, &legacy_id),
+        client.claim_winnings(&legacy_large, &legacy_id),
+    );
+    assert_eq!(current_payouts, legacy_payouts);
+    assert!(current_payouts.0 > STAKE);
+    assert_eq!(current_payouts.1, 3 * current_payouts.0);
+    assert!(client.has_claimed(&legacy_id, &legacy_large));
+    assert!(!client.has_claimed(&legacy_id, &legacy_loser));
+}
+
+#[test]
+fn test_migration_keeps_claimed_positions_claimed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let legacy_id = test.create_test_market();
+    let end_time = client.get_market(&legacy_id).unwrap().end_time;
+
+    let mut legacy = baseline_market_fixture(&test, end_time);
+    let paid = legacy.votes.keys().get(0).unwrap();
+    let unpaid = legacy.votes.keys().get(1).unwrap();
+    legacy.claimed.set(paid.clone(), true);
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        storage.remove(&DataKey::Market(legacy_id.clone()));
+        storage.set(&legacy_id, &legacy);
+    });
+
+    client.migrate_storage(&test.admin);
+    assert!(client.has_claimed(&legacy_id, &paid));
+    assert!(!client.has_claimed(&legacy_id, &unpaid));
+    assert_eq!(
+        client.get_bet(&legacy_id, &unpaid).unwrap().amount,
+        legacy.stakes.get(unpaid).unwrap()
+    );
+}
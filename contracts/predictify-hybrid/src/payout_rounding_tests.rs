@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+//! Payout rounding tests.
+//!
+//! Winners are paid their pro-rata share rounded down and the last winner paid
+//! takes the remainder, so whether payouts are pulled with `claim_winnings`,
+//! pushed in pages with `distribute_payouts` or swept after the claim period,
+//! the payouts plus the platform fee add up to the pool exactly.
+
+use crate::test::PredictifyTest;
+use crate::types::TimelockedAction;
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE, PAYOUT_CURSOR_DONE};
+use proptest::prelude::*;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{Address, String, Symbol};
+use alloc::vec::Vec as StdVec;
+
+/// Place every `(stake, backs_yes)` bet on a new market, resolve it to "yes"
+/// and unlock payouts. Returns the market and the winners in betting order.
+fn resolved_market(test: &PredictifyTest, bets: &[(i128, bool)]) -> (Symbol, StdVec<Address>) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let mut winners = StdVec::new();
+    for (stake, backs_yes) in bets {
+        let user = test.create_funded_user();
+        let outcome = if *backs_yes { &yes } else { &no };
+        client.place_bet(&user, &market_id, outcome, stake, &None);
+        if *backs_yes {
+            winners.push(user);
+        }
+    }
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+    (market_id, winners)
+}
+
+/// Payouts plus the reserved fee equal the pool, and the market holds nothing
+/// but that fee
+fn assert_pool_fully_split(test: &PredictifyTest, market_id: &Symbol, paid: i128) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let total_staked = client.get_market(market_id).unwrap().total_staked;
+    let (fee, _) = client.get_market_fees(market_id);
+    assert_eq!(paid + fee, total_staked);
+    assert_eq!(client.get_distributed_so_far(market_id), paid);
+    assert_eq!(client.reconcile_market(market_id), (fee, fee));
+}
+
+#[test]
+fn test_last_claimant_takes_rounding_remainder() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // Winners share 29_400_001 after the 2% fee of a 30_000_001 pool
+    let (market_id, winners) = resolved_market(
+        &test,
+        &[(3_333_334, true), (3_333_334, true), (3_333_333, true), (20_000_000, false)],
+    );
+    let first = client.claim_winnings(&winners[1], &market_id);
+    let second = client.claim_winnings(&winners[0], &market_id);
+    assert_eq!((first, second), (9_800_000, 9_800_000));
+
+    // Rounded down, the smallest stake's share is 9_799_997; it is paid last and
+    // takes the 4 stroops left over
+    let last = client.claim_winnings(&winners[2], &market_id);
+    assert_eq!(last, 9_800_001);
+    assert_pool_fully_split(&test, &market_id, first + second + last);
+}
+
+#[test]
+fn test_sweep_takes_remainder_of_unclaimed_winners() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, winners) =
+        resolved_market(&test, &[(7_777_777, true), (3_333_333, true), (11_111_111, false)]);
+    let claimed = client.claim_winnings(&winners[0], &market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    let claim_period = market.payout_unlock_at.unwrap() - market.end_time + 10;
+    client.set_market_claim_period(&test.admin, &market_id, &claim_period);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.end_time + claim_period);
+    let swept = client.sweep_unclaimed_winnings(&test.admin, &market_id, &true);
+    assert_pool_fully_split(&test, &market_id, claimed + swept);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(24))]
+
+    #[test]
+    fn test_pulled_and_paged_payouts_split_pool_exactly(
+        bets in prop::collection::vec((1_000_000i128..50_000_000, any::<bool>()), 2..8),
+        fee_bps in prop::sample::select(&[0u32, 50, 200, 333, 1_000][..]),
+        page_size in 1u32..4,
+        pull_claims in 0usize..4,
+    ) {
+        let test = PredictifyTest::setup();
+        let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+        test.env.mock_all_auths();
+        client.execute_action(&test.schedule_due(TimelockedAction::SetFeeBps(fee_bps)));
+
+        // At least one winner and one loser
+        let mut bets = bets;
+        bets[0].1 = true;
+        bets[1].1 = false;
+        let (market_id, winners) = resolved_market(&test, &bets);
+
+        // Some winners pull their payout, in reverse order, then pages push the rest
+        let mut paid = 0;
+        for winner in winners.iter().rev().take(pull_claims) {
+            paid += client.claim_winnings(winner, &market_id);
+        }
+        let mut cursor = 0;
+        while cursor != PAYOUT_CURSOR_DONE {
+            let (page_paid, next) = client.distribute_payouts(
+                &market_id,
+                &cursor,
+                &page_size.min(MAX_PAYOUT_PAGE_SIZE),
+            );
+            paid += page_paid;
+            cursor = next;
+        }
+        assert_pool_fully_split(&test, &market_id, paid);
+    }
+}
@@ -0,0 +1,159 @@
+#![cfg(test)]
+
+//! Position transfer tests.
+//!
+//! `transfer_position` moves a whole position to another address before
+//! resolution; the recipient is then the only one paid.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::MarketAccess;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, String, Symbol, Vec};
+
+const STAKE: i128 = 100_000_000;
+
+fn resolve(test: &PredictifyTest, market_id: &Symbol, outcome: &str) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(
+        &test.admin,
+        market_id,
+        &String::from_str(&test.env, outcome),
+    );
+    test.advance_past_payout_unlock(market_id);
+}
+
+#[test]
+fn test_transferred_position_pays_recipient_only() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let from = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let to = Address::generate(&test.env);
+
+    client.place_bet(
+        &from,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+
+    let position = client.transfer_position(&from, &to, &market_id);
+    assert_eq!(position.user, to);
+    assert_eq!(position.amount, STAKE);
+    assert_eq!(client.get_bet(&market_id, &from), None);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 2 * STAKE);
+    assert_eq!(market.position_count(), 2);
+    assert_eq!(client.get_user_position(&from, &market_id), None);
+    assert_eq!(client.get_bet(&market_id, &to).unwrap().amount, STAKE);
+
+    resolve(&test, &market_id, "yes");
+    assert_eq!(
+        client.try_claim_winnings(&from, &market_id),
+        Err(Ok(Error::NothingToClaim))
+    );
+    // 2% platform fee on the 200M pool
+    assert_eq!(client.claim_winnings(&to, &market_id), 196_000_000);
+}
+
+#[test]
+fn test_transfer_merges_into_same_outcome_position() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let from = test.create_funded_user();
+    let to = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+
+    client.place_bet(&from, &market_id, &yes, &STAKE, &None);
+    client.place_bet(&to, &market_id, &yes, &(2 * STAKE), &None);
+
+    let position = client.transfer_position(&from, &to, &market_id);
+    assert_eq!(position.amount, 3 * STAKE);
+    assert_eq!(position.outcome, yes);
+    assert_eq!(client.get_bet(&market_id, &to).unwrap().amount, 3 * STAKE);
+    assert_eq!(client.get_market(&market_id).unwrap().position_count(), 1);
+
+    let stats = client.get_market_bet_stats(&market_id);
+    assert_eq!(stats.unique_bettors, 1);
+    assert_eq!(stats.total_amount_locked, 3 * STAKE);
+
+    // A position on the other outcome cannot be merged
+    let other = test.create_funded_user();
+    client.place_bet(
+        &other,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+    assert_eq!(
+        client.try_transfer_position(&to, &other, &market_id),
+        Err(Ok(Error::ConflictingOutcome))
+    );
+}
+
+#[test]
+fn test_transfer_rejected_after_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let from = test.create_funded_user();
+    client.place_bet(
+        &from,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+
+    resolve(&test, &market_id, "yes");
+    assert_eq!(
+        client.try_transfer_position(&from, &Address::generate(&test.env), &market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_transfer_recipient_must_be_allowlisted() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let from = test.create_funded_user();
+    let to = Address::generate(&test.env);
+
+    client.set_market_access(&test.admin, &market_id, &MarketAccess::Allowlist);
+    let mut listed = Vec::new(&test.env);
+    listed.push_back(from.clone());
+    client.update_market_allowlist(&test.admin, &market_id, &listed, &Vec::new(&test.env));
+    client.place_bet(
+        &from,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+
+    assert_eq!(
+        client.try_transfer_position(&from, &to, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_transfer_position(&from, &from, &market_id),
+        Err(Ok(Error::InvalidInput))
+    );
+}
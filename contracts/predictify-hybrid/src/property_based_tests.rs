@@ -53,8 +53,8 @@ impl PropertyBasedTestSuite {
         // Store TokenID
         env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_id);
+                .instance()
+                .set(&DataKey::Token, &token_id);
         });
 
         // Generate multiple test users for comprehensive testing
@@ -90,6 +90,9 @@ impl PropertyBasedTestSuite {
             feed_id: SorobanString::from_str(&self.env, "BTC/USD"),
             threshold,
             comparison: SorobanString::from_str(&self.env, comparison),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         }
     }
 
@@ -179,7 +182,7 @@ proptest! {
             &duration_days,
             &oracle_config,
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -232,7 +235,7 @@ proptest! {
             &duration_days,
             &oracle_config,
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -288,7 +291,7 @@ proptest! {
             &30,
             &oracle_config,
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -303,12 +306,13 @@ proptest! {
         client.vote(user, &market_id, &chosen_outcome, &stake_amount);
 
         let market = client.get_market(&market_id).unwrap();
+        let position = client.get_bet(&market_id, user).unwrap();
 
         // Property: User vote should be recorded correctly
-        prop_assert_eq!(market.votes.get(user.clone()).unwrap(), chosen_outcome);
+        prop_assert_eq!(position.outcome, chosen_outcome);
 
         // Property: User stake should be recorded correctly
-        prop_assert_eq!(market.stakes.get(user.clone()).unwrap(), stake_amount);
+        prop_assert_eq!(position.amount, stake_amount);
 
         // Property: Total staked should equal user stake
         prop_assert_eq!(market.total_staked, stake_amount);
@@ -333,6 +337,9 @@ proptest! {
             feed_id: SorobanString::from_str(&suite.env, &feed_id),
             threshold,
             comparison: SorobanString::from_str(&suite.env, comparison),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         };
 
         // Property: Oracle configuration validation should pass for valid inputs
@@ -361,6 +368,9 @@ proptest! {
             feed_id: SorobanString::from_str(&suite.env, "BTC/USD"),
             threshold,
             comparison: SorobanString::from_str(&suite.env, comparison),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         };
 
         // Invariant: Threshold must always be positive
@@ -460,7 +470,7 @@ proptest! {
             &duration_days,
             &oracle_config,
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -544,11 +554,11 @@ proptest! {
             prop_assert_eq!(market.total_staked, expected_total);
 
             // Invariant: Number of votes should not exceed number of users
-            let vote_count = market.votes.len();
+            let vote_count = market.position_count();
             prop_assert!(vote_count <= users_len as u32);
 
             // Invariant: Each user should have at most one vote
-            prop_assert!(market.votes.get(user.clone()).is_some());
+            prop_assert!(client.get_bet(&market_id, user).is_some());
 
             // Invariant: Market state should remain consistent
             prop_assert_eq!(market.state, MarketState::Active);
@@ -559,6 +569,128 @@ proptest! {
     }
 }
 
+// ===== AMOUNT ARITHMETIC PROPERTY TESTS =====
+
+proptest! {
+    #[test]
+    fn test_amount_mul_div_scales_exactly(
+        amount in 0..=i128::MAX,
+        scale in 1..=i128::MAX,
+    ) {
+        // Property: scaling by k/k is lossless, even when amount * k overflows i128
+        prop_assert_eq!(crate::amounts::mul_div(amount, scale, scale), Ok(amount));
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_amount_payout_stays_within_pool(
+        stake in 1..=i128::MAX,
+        extra_winning in 0..=i128::MAX,
+        extra_pool in 0..=i128::MAX,
+    ) {
+        // stake <= winning_total <= total_pool, all up to i128::MAX
+        let winning_total = stake.saturating_add(extra_winning);
+        let total_pool = winning_total.saturating_add(extra_pool);
+        let payout = crate::amounts::mul_div(stake, total_pool, winning_total).unwrap();
+
+        // Property: a winner never gets less than their stake or more than the pool
+        prop_assert!(payout >= stake);
+        prop_assert!(payout <= total_pool);
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_amount_overflow_is_reported_not_wrapped(
+        a in any::<i128>(),
+        b in any::<i128>(),
+    ) {
+        // Property: results match exact arithmetic or fail, never wrap
+        match crate::amounts::checked_add(a, b) {
+            Ok(sum) => prop_assert_eq!(Some(sum), a.checked_add(b)),
+            Err(e) => prop_assert!(
+                (a < 0 || b < 0) && e == crate::amounts::INVALID_AMOUNT
+                    || a.checked_add(b).is_none() && e == crate::amounts::OVERFLOW
+            ),
+        }
+        match crate::amounts::mul_div(a, b, 1) {
+            Ok(product) => prop_assert_eq!(Some(product), a.checked_mul(b)),
+            Err(e) => prop_assert!(
+                (a < 0 || b < 0) && e == crate::amounts::INVALID_AMOUNT
+                    || a.checked_mul(b).is_none() && e == crate::amounts::OVERFLOW
+            ),
+        }
+        if let Ok(difference) = crate::amounts::checked_sub(a, b) {
+            prop_assert!(difference >= 0 && difference <= a);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+    #[test]
+    fn test_non_positive_stakes_never_touch_the_pool(
+        amount in i128::MIN..=0i128,
+    ) {
+        let suite = PropertyBasedTestSuite::new();
+        let client = suite.client();
+        let outcomes = suite.generate_outcomes(2);
+        let market_id = client.create_market(
+            &suite.admin,
+            &SorobanString::from_str(&suite.env, "Will BTC reach $100k?"),
+            &outcomes,
+            &30,
+            &suite.generate_oracle_config(5_000_000, "gt"),
+            &None,
+            &86400u64,
+            &None,
+            &None,
+            &None,
+        );
+        let outcome = outcomes.get(0).unwrap();
+
+        // Property: zero and negative amounts are rejected by every staking path
+        prop_assert_eq!(
+            client.try_place_bet(suite.get_user(0), &market_id, &outcome, &amount, &None),
+            Err(Ok(Error::InvalidAmount))
+        );
+        prop_assert_eq!(
+            client.try_vote(suite.get_user(1), &market_id, &outcome, &amount),
+            Err(Ok(Error::InvalidInput))
+        );
+        prop_assert_eq!(client.get_market(&market_id).unwrap().total_staked, 0);
+    }
+}
+
+#[test]
+fn test_payout_near_i128_max_pool_is_exact() {
+    // Old two-step math overflowed on stake * pool; the wide product keeps it exact
+    let pool = i128::MAX - 1;
+    let stake = pool / 4;
+    let winning_total = pool / 2;
+    assert_eq!(
+        crate::markets::MarketUtils::calculate_payout(stake, winning_total, pool, 0),
+        Ok(stake * 2)
+    );
+    assert_eq!(
+        crate::amounts::mul_div(i128::MAX, i128::MAX, i128::MAX),
+        Ok(i128::MAX)
+    );
+    assert_eq!(
+        crate::amounts::mul_div(i128::MAX, 2, 1),
+        Err(crate::amounts::OVERFLOW)
+    );
+    assert_eq!(
+        crate::amounts::checked_add(i128::MAX, 1),
+        Err(crate::amounts::OVERFLOW)
+    );
+    assert_eq!(
+        crate::amounts::checked_sub(1, 2),
+        Err(crate::amounts::INVALID_AMOUNT)
+    );
+}
+
 #[cfg(test)]
 mod property_test_runner {
     #[test]
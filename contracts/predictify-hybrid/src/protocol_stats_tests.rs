@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+//! Protocol-wide analytics tests.
+//!
+//! Runs a scripted sequence of bets, a withdrawal, a resolution, a
+//! cancellation and a fee collection, then checks `get_protocol_stats`
+//! against hand-computed totals.
+
+use crate::test::PredictifyTest;
+use crate::types::CancelReason;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::String;
+
+const STAKE: i128 = 100_000_000;
+
+#[test]
+fn test_protocol_stats_follow_scripted_sequence() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    let resolved = test.create_test_market();
+    let cancelled = test.create_test_market();
+    let open = test.create_test_market();
+
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let carol = test.create_funded_user();
+
+    // Resolved market: 100M yes, 100M no
+    client.place_bet(&alice, &resolved, &yes, &STAKE, &None);
+    client.place_bet(&bob, &resolved, &no, &STAKE, &None);
+    // Cancelled market: 50M placed, 30M withdrawn before cancellation
+    client.place_bet(&alice, &cancelled, &yes, &(STAKE / 2), &None);
+    client.place_bet(&carol, &cancelled, &no, &(STAKE * 3 / 10), &None);
+    client.withdraw_bet(&carol, &cancelled);
+    // Open market: 70M still escrowed
+    client.place_bet(&bob, &open, &yes, &(STAKE * 7 / 10), &None);
+
+    let stats = client.get_protocol_stats();
+    assert_eq!(stats.total_volume, 350_000_000);
+    assert_eq!(stats.open_interest, 320_000_000);
+    assert_eq!(stats.markets_created, 3);
+
+    client.cancel_event(&test.admin, &cancelled, &CancelReason::Administrative, &None);
+    let end_time = client.get_market(&resolved).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &resolved, &yes);
+    // 2% platform fee on the 200M pool
+    assert_eq!(client.collect_fees(&test.admin, &resolved), 4_000_000);
+
+    let stats = client.get_protocol_stats();
+    assert_eq!(stats.total_volume, 350_000_000);
+    assert_eq!(stats.open_interest, 70_000_000);
+    assert_eq!(stats.markets_created, 3);
+    assert_eq!(stats.markets_resolved, 1);
+    assert_eq!(stats.markets_cancelled, 1);
+    assert_eq!(stats.total_fees_collected, 4_000_000);
+}
@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+//! Pyth oracle resolution tests.
+//!
+//! Markets configured with `OracleProvider::Pyth` are resolved through
+//! `resolve_market` against a mock Pyth price contract registered in the `Env`.
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{MarketState, OracleConfig, OracleProvider, OracleSigner, PythPriceData};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Symbol};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+
+/// Minimal Pyth price contract exposing `get_price_unsafe`.
+#[contract]
+pub struct MockPythContract;
+
+#[contractimpl]
+impl MockPythContract {
+    pub fn set_feed_price(env: Env, feed_id: String, price: PythPriceData) {
+        env.storage().instance().set(&feed_id, &price);
+    }
+
+    pub fn get_price_unsafe(env: Env, feed_id: String) -> Option<PythPriceData> {
+        env.storage().instance().get(&feed_id)
+    }
+}
+
+fn create_pyth_market(test: &PredictifyTest, pyth: &Address) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address: pyth.clone(),
+            feed_id: String::from_str(&test.env, BTC_FEED),
+            threshold: 2_500_000_000_000, // $25,000 in 8-decimal units
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &86400,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn advance_past_end(test: &PredictifyTest, market_id: &Symbol) {
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, crate::types::Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+}
+
+fn publish_price(test: &PredictifyTest, pyth: &Address, price: i64, expo: i32, publish_time: u64) {
+    MockPythContractClient::new(&test.env, pyth).set_feed_price(
+        &String::from_str(&test.env, BTC_FEED),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo,
+            publish_time,
+        },
+    );
+}
+
+#[test]
+fn test_resolve_market_with_pyth_price() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pyth = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &pyth);
+
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+    );
+
+    advance_past_end(&test, &market_id);
+    // $26,000 with Pyth's usual -5 exponent, normalized to 8 decimals on read
+    publish_price(
+        &test,
+        &pyth,
+        2_600_000_000,
+        -5,
+        test.env.ledger().timestamp(),
+    );
+
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "yes"))
+    );
+    assert_eq!(
+        market.winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+    assert_eq!(market.state, MarketState::Resolved);
+}
+
+#[test]
+fn test_resolve_market_with_pyth_price_below_threshold() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pyth = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &pyth);
+
+    advance_past_end(&test, &market_id);
+    publish_price(
+        &test,
+        &pyth,
+        2_400_000_000_000,
+        -8,
+        test.env.ledger().timestamp(),
+    );
+
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "no"))
+    );
+}
+
+#[test]
+fn test_resolve_market_rejects_stale_pyth_price() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pyth = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &pyth);
+
+    advance_past_end(&test, &market_id);
+    let now = test.env.ledger().timestamp();
+    let max_age = test.env.as_contract(&test.contract_id, || {
+        crate::oracles::PythOracle::max_price_age(&test.env)
+    });
+    publish_price(&test, &pyth, 2_600_000_000_000, -8, now - max_age - 1);
+
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::OracleStale))
+    );
+    assert!(client
+        .get_market(&market_id)
+        .unwrap()
+        .oracle_result
+        .is_none());
+
+    // Widening the staleness window accepts the same price
+    test.env.mock_all_auths();
+    client.set_oracle_max_price_age(&test.admin, &(max_age + 60));
+    client.resolve_market(&market_id);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().oracle_result,
+        Some(String::from_str(&test.env, "yes"))
+    );
+}
+
+#[test]
+fn test_resolve_market_with_missing_pyth_price() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // Feed never published
+    let pyth = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &pyth);
+    advance_past_end(&test, &market_id);
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    // No contract deployed at the oracle address
+    let missing = Address::generate(&test.env);
+    let market_id = create_pyth_market(&test, &missing);
+    advance_past_end(&test, &market_id);
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+}
@@ -83,10 +83,10 @@ impl QueryManager {
         let market = Self::get_market_from_storage(env, &market_id)?;
 
         // Calculate participant count
-        let participant_count = market.votes.len() as u32;
+        let participant_count = market.position_count();
 
         // Calculate vote count (simple approximation)
-        let vote_count = market.votes.len() as u32;
+        let vote_count = market.position_count();
 
         // Get oracle provider name
         let oracle_provider = market.oracle_config.provider.name();
@@ -205,11 +205,12 @@ impl QueryManager {
         let market = Self::get_market_from_storage(env, &market_id)?;
 
         // Check if user has participated
-        let outcome = market.votes.get(user.clone()).ok_or(Error::InvalidInput)?;
+        let position = crate::bets::BetStorage::get_position(env, &market_id, &user)
+            .ok_or(Error::InvalidInput)?;
+        let outcome = position.outcome;
+        let stake_amount = position.amount;
 
-        let stake_amount = market.stakes.get(user.clone()).ok_or(Error::InvalidInput)?;
-
-        let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
+        let has_claimed = crate::bets::BetStorage::is_claimed(env, &market_id, &user);
 
         // Determine if user is winning (supports single or multiple winning outcomes / ties)
         let is_winning = market
@@ -438,10 +439,7 @@ impl QueryManager {
     ///
     /// Internal helper to get market data from storage with error handling.
     fn get_market_from_storage(env: &Env, market_id: &Symbol) -> Result<Market, Error> {
-        env.storage()
-            .persistent()
-            .get(market_id)
-            .ok_or(Error::MarketNotFound)
+        MarketStateManager::get_market(env, market_id)
     }
 
     /// Calculate payout for a user based on stake and market outcome.
@@ -485,24 +483,13 @@ impl QueryManager {
 
     /// Calculate total stake for a specific outcome.
     ///
-    /// Sums all user stakes that voted for the given outcome.
+    /// Read from the market's per-outcome totals.
     pub(crate) fn calculate_outcome_pool(
-        env: &Env,
+        _env: &Env,
         market: &Market,
         outcome: &String,
     ) -> Result<i128, Error> {
-        let mut pool = 0i128;
-
-        // Iterate through all votes to find matching outcome
-        for (user, voted_outcome) in market.votes.iter() {
-            if voted_outcome == *outcome {
-                if let Some(stake) = market.stakes.get(user) {
-                    pool += stake;
-                }
-            }
-        }
-
-        Ok(pool)
+        Ok(market.outcome_pool(outcome))
     }
 
     /// Calculate implied probabilities for binary outcomes.
@@ -625,8 +612,6 @@ mod tests {
     fn test_outcome_pool_calculation() {
         let env = Env::default();
         let admin = Address::generate(&env);
-        let user1 = Address::generate(&env);
-        let user2 = Address::generate(&env);
 
         let mut market = Market::new(
             &env,
@@ -655,11 +640,8 @@ mod tests {
 
         // Add votes
         let yes_outcome = String::from_str(&env, "yes");
-        market.votes.set(user1.clone(), yes_outcome.clone());
-        market.stakes.set(user1, 50);
-
-        market.votes.set(user2.clone(), yes_outcome.clone());
-        market.stakes.set(user2, 75);
+        market.add_position(&yes_outcome, 50);
+        market.add_position(&yes_outcome, 75);
 
         let pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome);
         assert!(pool.is_ok());
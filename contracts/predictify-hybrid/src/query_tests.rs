@@ -253,8 +253,7 @@ fn test_outcome_pool_with_single_vote() {
     let yes_outcome = String::from_str(&env, "yes");
     let stake = 5_000_000i128;
 
-    market.votes.set(user.clone(), yes_outcome.clone());
-    market.stakes.set(user, stake);
+    market.add_position(&yes_outcome, stake);
 
     let pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome);
     assert!(pool.is_ok(), "Outcome pool calculation failed");
@@ -295,15 +294,12 @@ fn test_outcome_pool_with_multiple_votes() {
     let no_outcome = String::from_str(&env, "no");
 
     // Add multiple votes for YES
-    market.votes.set(user1.clone(), yes_outcome.clone());
-    market.stakes.set(user1, 3_000_000i128);
+    market.add_position(&yes_outcome, 3_000_000i128);
 
-    market.votes.set(user2.clone(), yes_outcome.clone());
-    market.stakes.set(user2, 2_000_000i128);
+    market.add_position(&yes_outcome, 2_000_000i128);
 
     // Add vote for NO
-    market.votes.set(user3.clone(), no_outcome.clone());
-    market.stakes.set(user3, 5_000_000i128);
+    market.add_position(&no_outcome, 5_000_000i128);
 
     let yes_pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome);
     let no_pool = QueryManager::calculate_outcome_pool(&env, &market, &no_outcome);
@@ -317,13 +313,15 @@ fn test_outcome_pool_with_multiple_votes() {
 #[test]
 fn test_market_status_all_states() {
     // Test all market states convert properly
-    let states: [MarketState; 6] = [
+    let states: [MarketState; 8] = [
         MarketState::Active,
         MarketState::Ended,
         MarketState::Disputed,
         MarketState::Resolved,
         MarketState::Closed,
         MarketState::Cancelled,
+        MarketState::BettingClosed,
+        MarketState::Voided,
     ];
 
     for state in states.iter().copied() {
@@ -335,7 +333,9 @@ fn test_market_status_all_states() {
             | MarketStatus::Disputed
             | MarketStatus::Resolved
             | MarketStatus::Closed
-            | MarketStatus::Cancelled => {
+            | MarketStatus::Cancelled
+            | MarketStatus::BettingClosed
+            | MarketStatus::Voided => {
                 // Valid status
             }
         }
@@ -454,10 +454,8 @@ fn test_pool_calculation_commutative() {
     let outcome = String::from_str(&env, "yes");
 
     // Add votes in order 1, 2
-    market1.votes.set(user1.clone(), outcome.clone());
-    market1.stakes.set(user1.clone(), 3_000_000i128);
-    market1.votes.set(user2.clone(), outcome.clone());
-    market1.stakes.set(user2.clone(), 2_000_000i128);
+    market1.add_position(&outcome, 3_000_000i128);
+    market1.add_position(&outcome, 2_000_000i128);
 
     let pool1 = QueryManager::calculate_outcome_pool(&env, &market1, &outcome).unwrap();
 
@@ -485,10 +483,8 @@ fn test_pool_calculation_commutative() {
     );
 
     // Add votes in reverse order 2, 1
-    market2.votes.set(user2.clone(), outcome.clone());
-    market2.stakes.set(user2.clone(), 2_000_000i128);
-    market2.votes.set(user1.clone(), outcome.clone());
-    market2.stakes.set(user1.clone(), 3_000_000i128);
+    market2.add_position(&outcome, 2_000_000i128);
+    market2.add_position(&outcome, 3_000_000i128);
 
     let pool2 = QueryManager::calculate_outcome_pool(&env, &market2, &outcome).unwrap();
 
@@ -500,13 +496,15 @@ fn test_pool_calculation_commutative() {
 #[test]
 fn test_status_conversion_roundtrip() {
     // Test that we can convert states and back
-    let all_states: [MarketState; 6] = [
+    let all_states: [MarketState; 8] = [
         MarketState::Active,
         MarketState::Ended,
         MarketState::Disputed,
         MarketState::Resolved,
         MarketState::Closed,
         MarketState::Cancelled,
+        MarketState::BettingClosed,
+        MarketState::Voided,
     ];
 
     for state in all_states.iter().copied() {
@@ -519,6 +517,8 @@ fn test_status_conversion_roundtrip() {
             MarketStatus::Resolved => assert_eq!(state, MarketState::Resolved),
             MarketStatus::Closed => assert_eq!(state, MarketState::Closed),
             MarketStatus::Cancelled => assert_eq!(state, MarketState::Cancelled),
+            MarketStatus::BettingClosed => assert_eq!(state, MarketState::BettingClosed),
+            MarketStatus::Voided => assert_eq!(state, MarketState::Voided),
         }
     }
 }
@@ -556,10 +556,8 @@ fn test_outcome_pool_consistency() {
     let yes_outcome = String::from_str(&env, "yes");
     let no_outcome = String::from_str(&env, "no");
 
-    market.votes.set(user1.clone(), yes_outcome.clone());
-    market.stakes.set(user1, 7_000_000i128);
-    market.votes.set(user2.clone(), no_outcome.clone());
-    market.stakes.set(user2, 3_000_000i128);
+    market.add_position(&yes_outcome, 7_000_000i128);
+    market.add_position(&no_outcome, 3_000_000i128);
     market.total_staked = 10_000_000i128;
 
     let yes_pool = QueryManager::calculate_outcome_pool(&env, &market, &yes_outcome).unwrap();
@@ -605,8 +603,7 @@ fn test_payout_with_high_fees() {
     );
 
     let stake = 100_000_000i128; // 10 XLM
-    market.votes.set(user.clone(), yes.clone());
-    market.stakes.set(user.clone(), stake);
+    market.add_position(&yes, stake);
     market.total_staked = stake;
     market.winning_outcomes = Some(soroban_sdk::vec![&env, yes]);
 
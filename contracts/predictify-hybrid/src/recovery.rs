@@ -1,8 +1,8 @@
-use alloc::format;
 use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 
 use crate::events::EventEmitter;
 use crate::markets::MarketStateManager;
+use crate::storage::DataKey;
 use crate::types::MarketState;
 use crate::Error;
 
@@ -129,8 +129,8 @@ impl RecoveryManager {
     pub fn assert_is_admin(env: &Env, admin: &Address) -> Result<(), Error> {
         let stored_admin: Address = env
             .storage()
-            .persistent()
-            .get(&Symbol::new(env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
         if &stored_admin != admin {
             return Err(Error::Unauthorized);
@@ -165,14 +165,14 @@ impl RecoveryManager {
 
         // Attempt reconstruction heuristics (simplified)
         let mut market = MarketStateManager::get_market(env, market_id)?;
-        if market.state == MarketState::Closed || market.state == MarketState::Cancelled {
-            // cannot reconstruct closed or cancelled; treat as skip
+        if market.state.is_terminal() {
+            // cannot reconstruct closed, cancelled or voided; treat as skip
             return Ok(false);
         }
 
-        // Example heuristic: ensure total_staked matches sum of stakes map
+        // Example heuristic: ensure total_staked matches sum of outcome pools
         let mut recomputed: i128 = 0;
-        for (_, v) in market.stakes.iter() {
+        for (_, v) in market.outcome_pools.iter() {
             recomputed += v;
         }
         if recomputed != market.total_staked {
@@ -211,10 +211,11 @@ impl RecoveryManager {
         let mut total_refunded: i128 = 0;
 
         for user in users.iter() {
-            if let Some(stake) = market.stakes.get(user.clone()) {
+            if let Some(bet) = crate::bets::BetStorage::get_position(env, market_id, &user) {
+                let stake = bet.amount;
                 if stake > 0 {
                     // For now just mark claimed and reduce total; real implementation would transfer tokens
-                    market.claimed.set(user.clone(), true);
+                    crate::bets::BetStorage::mark_claimed(env, market_id, &user);
                     market.total_staked = market.total_staked - stake;
                     total_refunded += stake;
                 }
@@ -249,25 +250,22 @@ impl RecoveryManager {
 // ===== EVENT INTEGRATION =====
 impl EventEmitter {
     pub fn emit_recovery_event(env: &Env, market_id: &Symbol, action: &String, status: &String) {
-        let topic = Symbol::new(env, "recovery_evt");
-        let mut data = Vec::new(env);
-        data.push_back(String::from_str(env, "market_id"));
-        let mid = symbol_to_string(env, market_id);
-        data.push_back(mid);
-        data.push_back(String::from_str(env, "action"));
-        data.push_back(action.clone());
-        data.push_back(String::from_str(env, "status"));
-        data.push_back(status.clone());
-        env.events().publish((topic,), data);
+        let event = crate::events::RecoveryEvent {
+            market_id: market_id.clone(),
+            action: action.clone(),
+            status: status.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.events().publish(
+            (
+                soroban_sdk::symbol_short!("recovery"),
+                crate::events::EVENT_SCHEMA_VERSION,
+                market_id.clone(),
+            ),
+            event,
+        );
     }
 }
 
-// Helper for symbol -> string representation (Soroban lacks direct to_string for Symbol)
-fn symbol_to_string(env: &Env, sym: &Symbol) -> String {
-    // Use debug formatting of Symbol then convert to soroban String
-    let host_string = format!("{:?}", sym);
-    String::from_str(env, &host_string)
-}
-
 // Helper to build composite key prefix + symbol as soroban Symbol
 // composite_symbol no longer required with new map-based storage approach
@@ -12,19 +12,19 @@ pub enum GuardError {
 ///
 /// This guard prevents reentry across all public entrypoints while an external
 /// call (e.g., token transfer, oracle invocation) is in-flight. The lock is
-/// stored in persistent storage using a single boolean flag.
+/// stored in instance storage using a single boolean flag.
 pub struct ReentrancyGuard;
 
 impl ReentrancyGuard {
     fn key() -> soroban_sdk::Symbol {
-        // Persistent storage key for the reentrancy lock
+        // Instance storage key for the reentrancy lock
         symbol_short!("reent_lk")
     }
 
     /// Returns true if the reentrancy lock is currently active.
     pub fn is_locked(env: &Env) -> bool {
         env.storage()
-            .persistent()
+            .instance()
             .get::<soroban_sdk::Symbol, bool>(&Self::key())
             .unwrap_or(false)
     }
@@ -44,13 +44,24 @@ impl ReentrancyGuard {
         if Self::is_locked(env) {
             return Err(GuardError::ReentrancyGuardActive);
         }
-        env.storage().persistent().set(&Self::key(), &true);
+        env.storage().instance().set(&Self::key(), &true);
         Ok(())
     }
 
     /// Clears the reentrancy lock after the external call completes.
     pub fn after_external_call(env: &Env) {
-        env.storage().persistent().set(&Self::key(), &false);
+        env.storage().instance().set(&Self::key(), &false);
+    }
+
+    /// Runs a single external call with the lock held.
+    ///
+    /// Callers write their state changes first (checks-effects-interactions),
+    /// so a callee that re-enters finds the lock set and the state already final.
+    pub fn guarded<T, F: FnOnce() -> T>(env: &Env, call: F) -> Result<T, GuardError> {
+        Self::before_external_call(env)?;
+        let result = call();
+        Self::after_external_call(env);
+        Ok(result)
     }
 
     /// Validates that an external call succeeded.
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+//! Reentrancy tests.
+//!
+//! Payout paths store the claim before any token leaves the contract and hold
+//! the reentrancy lock during the transfer, so a token that calls back into
+//! the contract cannot be paid twice.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, IntoVal, Map, String, Symbol, Val};
+
+const STAKE: i128 = 10_000_000;
+
+/// Token that calls `claim_winnings` again from inside every payout it receives.
+#[contract]
+pub struct MaliciousToken;
+
+#[contractimpl]
+impl MaliciousToken {
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(&env, to, balance + amount);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        Self::balances(&env).get(id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let from_balance = Self::balance(env.clone(), from.clone());
+        Self::set_balance(&env, from.clone(), from_balance - amount);
+        let to_balance = Self::balance(env.clone(), to.clone());
+        Self::set_balance(&env, to.clone(), to_balance + amount);
+
+        let attack: Option<(Address, Symbol)> =
+            env.storage().instance().get(&Symbol::new(&env, "attack"));
+        if let Some((target, market_id)) = attack {
+            if from == target {
+                env.storage()
+                    .instance()
+                    .remove(&Symbol::new(&env, "attack"));
+                let args: soroban_sdk::Vec<Val> =
+                    vec![&env, to.into_val(&env), market_id.into_val(&env)];
+                let reentered = env.try_invoke_contract::<i128, Error>(
+                    &target,
+                    &Symbol::new(&env, "claim_winnings"),
+                    args,
+                );
+                env.storage().instance().set(
+                    &Symbol::new(&env, "reentered"),
+                    &matches!(reentered, Ok(Ok(_))),
+                );
+            }
+        }
+    }
+
+    /// Re-enter `claim_winnings` on the next payout `target` makes.
+    pub fn arm(env: Env, target: Address, market_id: Symbol) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "attack"), &(target, market_id));
+    }
+
+    /// Whether the reentrant claim succeeded, once it has been attempted.
+    pub fn reentered(env: Env) -> Option<bool> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "reentered"))
+    }
+
+    fn balances(env: &Env) -> Map<Address, i128> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, "balances"))
+            .unwrap_or(Map::new(env))
+    }
+
+    fn set_balance(env: &Env, id: Address, amount: i128) {
+        let mut balances = Self::balances(env);
+        balances.set(id, amount);
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, "balances"), &balances);
+    }
+}
+
+#[test]
+fn test_reentrant_claim_from_token_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    let token_id = test.env.register(MaliciousToken, ());
+    let token = MaliciousTokenClient::new(&test.env, &token_id);
+    client.set_market_token(&test.admin, &market_id, &token_id);
+
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    token.mint(&winner, &STAKE);
+    token.mint(&loser, &STAKE);
+    client.place_bet(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+
+    token.arm(&test.contract_id, &market_id);
+    let payout = client.claim_winnings(&winner, &market_id);
+    assert!(payout > STAKE);
+
+    // The nested claim ran and failed; the winner was paid exactly once
+    assert_eq!(token.reentered(), Some(false));
+    assert_eq!(token.balance(&winner), payout);
+    assert_eq!(
+        client.try_claim_winnings(&winner, &market_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+}
@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+//! Referral program tests.
+//!
+//! A referrer named with a user's first bet earns a share of the platform fee
+//! charged on that user's winnings.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{Address, String};
+
+const STAKE: i128 = 100_000_000;
+
+#[test]
+fn test_referrer_earns_share_of_platform_fee() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let referrer = Address::generate(&test.env);
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+
+    let yes = String::from_str(&test.env, "yes");
+    client.place_bet(&winner, &market_id, &yes, &STAKE, &Some(referrer.clone()));
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &STAKE,
+        &None,
+    );
+    assert_eq!(client.get_referrer(&winner), Some(referrer.clone()));
+    assert_eq!(client.get_referrer(&loser), None);
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+
+    // 2% platform fee on a 200M pool is 4M; 10% of it goes to the referrer
+    assert_eq!(client.claim_winnings(&winner, &market_id), 196_000_000);
+    assert_eq!(client.get_referral_earnings(&referrer), 400_000);
+    assert_eq!(client.get_market_fees(&market_id), (3_600_000, 0));
+
+    assert_eq!(client.withdraw_referral_earnings(&referrer), 400_000);
+    assert_eq!(token.balance(&referrer), 400_000);
+    assert_eq!(client.get_referral_earnings(&referrer), 0);
+    assert_eq!(
+        client.try_withdraw_referral_earnings(&referrer),
+        Err(Ok(Error::NothingToClaim))
+    );
+}
+
+#[test]
+fn test_referrer_rejected_for_self_or_after_first_bet() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let other_market = test.create_test_market();
+    let referrer = Address::generate(&test.env);
+    let yes = String::from_str(&test.env, "yes");
+
+    let user = test.create_funded_user();
+    assert_eq!(
+        client.try_place_bet(&user, &market_id, &yes, &STAKE, &Some(user.clone())),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // No referrer can be attached after the first bet
+    client.place_bet(&user, &market_id, &yes, &STAKE, &None);
+    assert_eq!(
+        client.try_place_bet(&user, &other_market, &yes, &STAKE, &Some(referrer.clone())),
+        Err(Ok(Error::AlreadyBet))
+    );
+    assert_eq!(client.get_referrer(&user), None);
+
+    // The referrer on record may be repeated but not replaced
+    let referred = test.create_funded_user();
+    client.place_bet(&referred, &market_id, &yes, &STAKE, &Some(referrer.clone()));
+    client.place_bet(
+        &referred,
+        &other_market,
+        &yes,
+        &STAKE,
+        &Some(referrer.clone()),
+    );
+    assert_eq!(
+        client.try_place_bet(
+            &referred,
+            &other_market,
+            &yes,
+            &STAKE,
+            &Some(Address::generate(&test.env))
+        ),
+        Err(Ok(Error::AlreadyBet))
+    );
+    assert_eq!(client.get_referrer(&referred), Some(referrer));
+}
+
+#[test]
+fn test_set_referral_bps() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_referral_bps(), 1_000);
+
+    assert_eq!(
+        client.try_set_referral_bps(&test.user, &500),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_referral_bps(&test.admin, &10_001),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    client.set_referral_bps(&test.admin, &500);
+    assert_eq!(client.get_referral_bps(), 500);
+}
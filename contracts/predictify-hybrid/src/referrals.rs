@@ -0,0 +1,142 @@
+//! Referral program.
+//!
+//! A user may name a referrer with their first bet. When the platform fee is
+//! taken from that user's winnings, a configurable share of it (10% by default)
+//! accrues to the referrer, who withdraws it with `withdraw_referral_earnings`.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::amounts;
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::fees::{FeeTracker, FeeValidator};
+use crate::markets::MarketUtils;
+use crate::reentrancy_guard::ReentrancyGuard;
+use crate::statistics::StatisticsManager;
+use crate::types::Market;
+
+/// Referrer registry and referral earnings.
+pub struct ReferralManager;
+
+impl ReferralManager {
+    /// Referrer attached to `user`, if any.
+    pub fn get_referrer(env: &Env, user: &Address) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("referrer"), user.clone()))
+    }
+
+    /// Attach `referrer` to `user` on their first bet.
+    ///
+    /// Naming the referrer already on record is a no-op, so clients may pass it
+    /// with every bet.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - `user` names themselves
+    /// * `Error::AlreadyBet` - `user` has already bet, or has another referrer
+    pub fn attach_referrer(env: &Env, user: &Address, referrer: &Address) -> Result<(), Error> {
+        if user == referrer {
+            return Err(Error::InvalidInput);
+        }
+        match Self::get_referrer(env, user) {
+            Some(current) if current == *referrer => return Ok(()),
+            Some(_) => return Err(Error::AlreadyBet),
+            None => {}
+        }
+        if StatisticsManager::get_user_stats(env, user).total_bets_placed > 0 {
+            return Err(Error::AlreadyBet);
+        }
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("referrer"), user.clone()), referrer);
+        EventEmitter::emit_referrer_set(env, user, referrer);
+        Ok(())
+    }
+
+    /// Share of the platform fee paid to referrers, in basis points.
+    pub fn get_referral_bps(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, crate::config::REFERRAL_BPS_STORAGE_KEY))
+            .unwrap_or(crate::config::DEFAULT_REFERRAL_BPS)
+    }
+
+    /// Set the referral share in basis points of the platform fee (admin only).
+    pub fn set_referral_bps(env: &Env, admin: &Address, bps: u32) -> Result<(), Error> {
+        admin.require_auth();
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        if bps > 10_000 {
+            return Err(Error::InvalidFeeConfig);
+        }
+
+        env.storage().instance().set(
+            &Symbol::new(env, crate::config::REFERRAL_BPS_STORAGE_KEY),
+            &bps,
+        );
+        Ok(())
+    }
+
+    /// Referral earnings accrued to `referrer` and not yet withdrawn.
+    pub fn get_earnings(env: &Env, referrer: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("ref_earn"), referrer.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Accrue the referral share of `platform_fee`, charged on `user`'s winnings
+    /// in a market, to the user's referrer.
+    ///
+    /// The share is released from the market's platform fee. Earnings are held in
+    /// the global staking token, so markets with their own token accrue nothing.
+    pub fn accrue(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+        platform_fee: i128,
+    ) -> Result<(), Error> {
+        if platform_fee <= 0 || market.token.is_some() {
+            return Ok(());
+        }
+        let referrer = match Self::get_referrer(env, user) {
+            Some(referrer) => referrer,
+            None => return Ok(()),
+        };
+        let share = amounts::mul_div(platform_fee, Self::get_referral_bps(env) as i128, 10_000)?;
+        let released = FeeTracker::release_market_fee(env, market_id, market, share)?;
+        if released > 0 {
+            crate::escrow::EscrowManager::debit(env, market_id, released)?;
+            let earnings = amounts::checked_add(Self::get_earnings(env, &referrer), released)?;
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("ref_earn"), referrer), &earnings);
+        }
+        Ok(())
+    }
+
+    /// Pay out every referral earning accrued to `referrer` and return the amount.
+    pub fn withdraw_earnings(env: &Env, referrer: &Address) -> Result<i128, Error> {
+        referrer.require_auth();
+        crate::admin::ComplianceManager::require_not_blocked(env, referrer)?;
+
+        let amount = Self::get_earnings(env, referrer);
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("ref_earn"), referrer.clone()));
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), referrer, &amount)
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        EventEmitter::emit_referral_earnings_withdrawn(env, referrer, amount);
+        Ok(amount)
+    }
+}
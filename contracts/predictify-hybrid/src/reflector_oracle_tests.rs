@@ -0,0 +1,513 @@
+#![cfg(test)]
+
+//! Reflector oracle fetch-and-resolve tests.
+//!
+//! `fetch_oracle_result` reads a mock Reflector contract registered in the `Env`
+//! and stores what it reported; `resolve_market` then settles from that record.
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::types::{
+    MarketState, OracleConfig, OracleProvider, OracleSigner, ReflectorAsset, ReflectorPriceData,
+    MAX_TWAP_RECORDS,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Ledger, LedgerInfo};
+use soroban_sdk::{contract, contractimpl, vec, Address, Env, String, Symbol, Vec};
+
+/// Minimal Reflector price contract exposing `lastprice` and `twap`.
+#[contract]
+pub struct MockReflectorContract;
+
+#[contractimpl]
+impl MockReflectorContract {
+    pub fn set_price(env: Env, asset: ReflectorAsset, price: ReflectorPriceData) {
+        env.storage().instance().set(&asset, &price);
+    }
+
+    pub fn lastprice(env: Env, asset: ReflectorAsset) -> Option<ReflectorPriceData> {
+        env.storage().instance().get(&asset)
+    }
+
+    pub fn set_twap(env: Env, asset: ReflectorAsset, records: u32, twap: i128) {
+        env.storage().instance().set(&(asset, records), &twap);
+    }
+
+    /// Only answers for the exact window a TWAP was set for.
+    pub fn twap(env: Env, asset: ReflectorAsset, records: u32) -> Option<i128> {
+        env.storage().instance().get(&(asset, records))
+    }
+}
+
+fn create_reflector_market(test: &PredictifyTest, reflector: &Address) -> Symbol {
+    create_market_with_comparison(test, reflector, "gt")
+}
+
+fn create_market_with_comparison(
+    test: &PredictifyTest,
+    reflector: &Address,
+    comparison: &str,
+) -> Symbol {
+    create_market_with_config(test, reflector, comparison, None)
+}
+
+fn create_market_with_config(
+    test: &PredictifyTest,
+    reflector: &Address,
+    comparison: &str,
+    twap_records: Option<u32>,
+) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: reflector.clone(),
+            feed_id: String::from_str(&test.env, "BTC/USD"),
+            threshold: 2_500_000, // $25,000 in cents
+            comparison: String::from_str(&test.env, comparison),
+            twap_records,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &86400,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn advance_past_end(test: &PredictifyTest, market_id: &Symbol) {
+    advance_past_end_by(test, market_id, 1);
+}
+
+fn advance_past_end_by(test: &PredictifyTest, market_id: &Symbol, seconds: u64) {
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, crate::types::Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + seconds,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+}
+
+fn publish_btc_price(test: &PredictifyTest, reflector: &Address, price: i128) {
+    publish_btc_price_at(test, reflector, price, test.env.ledger().timestamp());
+}
+
+fn publish_btc_price_at(test: &PredictifyTest, reflector: &Address, price: i128, timestamp: u64) {
+    MockReflectorContractClient::new(&test.env, reflector).set_price(
+        &ReflectorAsset::Other(Symbol::new(&test.env, "BTC")),
+        &ReflectorPriceData {
+            price,
+            timestamp,
+            source: String::from_str(&test.env, "Reflector"),
+        },
+    );
+}
+
+#[test]
+fn test_fetch_oracle_result_stores_reflector_reading() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+
+    assert!(client.get_oracle_result(&market_id).is_none());
+
+    advance_past_end(&test, &market_id);
+    // Below the threshold, unlike the built-in $26k test price
+    publish_btc_price(&test, &reflector, 2_400_000);
+
+    let fetched = client.fetch_oracle_result(&market_id);
+    assert_eq!(fetched.price, 2_400_000);
+    assert_eq!(fetched.outcome, String::from_str(&test.env, "no"));
+    assert_eq!(fetched.timestamp, test.env.ledger().timestamp());
+    assert_eq!(fetched.provider, OracleProvider::Reflector);
+    assert_eq!(client.get_oracle_result(&market_id), Some(fetched));
+
+    // Fetched but not yet finalized
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.oracle_result,
+        Some(String::from_str(&test.env, "no"))
+    );
+    assert!(market.winning_outcomes.is_none());
+}
+
+#[test]
+fn test_resolve_market_uses_stored_oracle_result() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+
+    advance_past_end(&test, &market_id);
+    publish_btc_price(&test, &reflector, 2_400_000);
+    client.fetch_oracle_result(&market_id);
+
+    // A later price move does not change the stored reading
+    publish_btc_price(&test, &reflector, 2_700_000);
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(
+        market.winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "no")])
+    );
+    assert_eq!(
+        client.get_oracle_result(&market_id).unwrap().price,
+        2_400_000
+    );
+}
+
+#[test]
+fn test_fetch_oracle_result_rejects_early_and_repeat_fetches() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::MarketClosed))
+    );
+
+    advance_past_end(&test, &market_id);
+    publish_btc_price(&test, &reflector, 2_600_000);
+    client.fetch_oracle_result(&market_id);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
+
+#[test]
+fn test_each_comparison_at_above_and_below_threshold() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+
+    // Threshold is $25,000; the range spans $24,000..=$25,000
+    let below = 2_400_000;
+    let at = 2_500_000;
+    let above = 2_600_000;
+    let cases = [
+        ("gt", [false, false, true]),
+        ("gte", [false, true, true]),
+        ("lt", [true, false, false]),
+        ("lte", [true, true, false]),
+        ("eq", [false, true, false]),
+        ("range:2400000:2500000", [true, true, false]),
+    ];
+
+    let mut markets = Vec::new(&test.env);
+    for (comparison, _) in cases.iter() {
+        for _ in 0..3 {
+            markets.push_back(create_market_with_comparison(&test, &reflector, comparison));
+        }
+    }
+    advance_past_end(&test, &markets.get(0).unwrap());
+
+    let mut i = 0;
+    for (comparison, expected) in cases.iter() {
+        for (price, yes) in [below, at, above].iter().zip(expected.iter()) {
+            publish_btc_price(&test, &reflector, *price);
+            let result = client.fetch_oracle_result(&markets.get(i).unwrap());
+            let outcome = if *yes { "yes" } else { "no" };
+            assert_eq!(
+                result.outcome,
+                String::from_str(&test.env, outcome),
+                "{} at price {}",
+                comparison,
+                price
+            );
+            i += 1;
+        }
+    }
+}
+
+#[test]
+fn test_create_market_rejects_unknown_comparison() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+
+    for comparison in [
+        "",
+        "ne",
+        ">=",
+        "GT",
+        "range:",
+        "range:2500000",
+        "range:2600000:2400000",
+        "range:0:2400000",
+        "range:24x:25",
+    ] {
+        test.env.mock_all_auths();
+        let result = client.try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Will BTC be above $25,000?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: reflector.clone(),
+                feed_id: String::from_str(&test.env, "BTC/USD"),
+                threshold: 2_500_000,
+                comparison: String::from_str(&test.env, comparison),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(
+            result,
+            Err(Ok(Error::InvalidComparison)),
+            "comparison {:?}",
+            comparison
+        );
+    }
+}
+
+#[test]
+fn test_stale_reflector_price_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+
+    advance_past_end(&test, &market_id);
+    let now = test.env.ledger().timestamp();
+    let max_age = client.get_market_max_price_age(&market_id);
+    publish_btc_price_at(&test, &reflector, 2_600_000, now - max_age - 1);
+
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleStale))
+    );
+    assert_eq!(
+        client.try_resolve_market(&market_id),
+        Err(Ok(Error::OracleStale))
+    );
+    assert!(client.get_oracle_result(&market_id).is_none());
+
+    // Exactly at the limit is still fresh
+    publish_btc_price_at(&test, &reflector, 2_600_000, now - max_age);
+    assert_eq!(
+        client.fetch_oracle_result(&market_id).outcome,
+        String::from_str(&test.env, "yes")
+    );
+}
+
+#[test]
+fn test_market_max_price_age_override() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+    let other_id = create_reflector_market(&test, &reflector);
+    let global = client.get_market_max_price_age(&market_id);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_market_max_price_age(&test.admin, &market_id, &Some(0)),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_market_max_price_age(&test.user, &market_id, &Some(60)),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_market_max_price_age(&test.admin, &market_id, &Some(global * 2));
+    assert_eq!(client.get_market_max_price_age(&market_id), global * 2);
+    assert_eq!(client.get_market_max_price_age(&other_id), global);
+
+    advance_past_end(&test, &market_id);
+    let now = test.env.ledger().timestamp();
+    publish_btc_price_at(&test, &reflector, 2_600_000, now - global - 1);
+
+    assert_eq!(
+        client.try_fetch_oracle_result(&other_id),
+        Err(Ok(Error::OracleStale))
+    );
+    client.fetch_oracle_result(&market_id);
+
+    test.env.mock_all_auths();
+    client.set_market_max_price_age(&test.admin, &market_id, &None);
+    assert_eq!(client.get_market_max_price_age(&market_id), global);
+}
+
+#[test]
+fn test_non_positive_reflector_price_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+
+    advance_past_end(&test, &market_id);
+    for price in [0, -2_600_000] {
+        publish_btc_price(&test, &reflector, price);
+        assert_eq!(
+            client.try_fetch_oracle_result(&market_id),
+            Err(Ok(Error::OracleUnavailable))
+        );
+    }
+}
+
+#[test]
+fn test_stale_price_falls_through_to_manual_after_timeout() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_reflector_market(&test, &reflector);
+    publish_btc_price(&test, &reflector, 2_600_000);
+
+    // The feed never updates again; past the resolution timeout the market
+    // is handed over to manual resolution instead of staying blocked
+    advance_past_end_by(&test, &market_id, 86400 + 1);
+    client.resolve_market(&market_id);
+
+    let market = client.get_market(&market_id).unwrap();
+    assert!(market.oracle_result.is_none());
+    assert_eq!(market.state, MarketState::Ended);
+
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "no"));
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+}
+
+#[test]
+fn test_twap_market_ignores_last_price_spike() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let spot_market = create_reflector_market(&test, &reflector);
+    let twap_market = create_market_with_config(&test, &reflector, "gt", Some(5));
+
+    // One manipulated round lifts the last price over the $25k threshold,
+    // while the average of the last five rounds stays below it
+    advance_past_end(&test, &twap_market);
+    publish_btc_price(&test, &reflector, 3_000_000);
+    MockReflectorContractClient::new(&test.env, &reflector).set_twap(
+        &ReflectorAsset::Other(Symbol::new(&test.env, "BTC")),
+        &5,
+        &2_400_000,
+    );
+
+    let spot = client.fetch_oracle_result(&spot_market);
+    assert_eq!(spot.price, 3_000_000);
+    assert_eq!(spot.outcome, String::from_str(&test.env, "yes"));
+
+    let twap = client.fetch_oracle_result(&twap_market);
+    assert_eq!(twap.price, 2_400_000);
+    assert_eq!(twap.outcome, String::from_str(&test.env, "no"));
+    client.resolve_market(&twap_market);
+    assert_eq!(
+        client.get_market(&twap_market).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "no")])
+    );
+}
+
+#[test]
+fn test_twap_market_rejects_stale_rounds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+    let market_id = create_market_with_config(&test, &reflector, "gt", Some(5));
+
+    advance_past_end(&test, &market_id);
+    let now = test.env.ledger().timestamp();
+    MockReflectorContractClient::new(&test.env, &reflector).set_twap(
+        &ReflectorAsset::Other(Symbol::new(&test.env, "BTC")),
+        &5,
+        &2_400_000,
+    );
+
+    // No latest round at all, then a latest round older than the max age
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+    let max_age = client.get_market_max_price_age(&market_id);
+    publish_btc_price_at(&test, &reflector, 2_400_000, now - max_age - 1);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleStale))
+    );
+}
+
+#[test]
+fn test_create_market_rejects_invalid_twap_records() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let reflector = test.env.register(MockReflectorContract, ());
+
+    for (provider, twap_records) in [
+        (OracleProvider::Reflector, 0),
+        (OracleProvider::Reflector, MAX_TWAP_RECORDS + 1),
+        (OracleProvider::Pyth, 5),
+    ] {
+        test.env.mock_all_auths();
+        let result = client.try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Will BTC be above $25,000?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider,
+                oracle_address: reflector.clone(),
+                feed_id: String::from_str(&test.env, "BTC/USD"),
+                threshold: 2_500_000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: Some(twap_records),
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidOracleConfig)));
+    }
+
+    create_market_with_config(&test, &reflector, "gt", Some(MAX_TWAP_RECORDS));
+}
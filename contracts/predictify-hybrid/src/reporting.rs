@@ -1,4 +1,5 @@
 use soroban_sdk::{Env, Map, String, Symbol, Vec};
+use crate::markets::MarketStateManager;
 use crate::types::{Market, MarketState, ActiveEvent, PlatformStats, EventSnapshot};
 use crate::errors::Error;
 use crate::queries::QueryManager;
@@ -25,7 +26,7 @@ impl ReportingManager {
         let mut added = 0;
 
         for id in all_markets.iter() {
-            let market: Market = env.storage().persistent().get(&id).ok_or(Error::MarketNotFound)?;
+            let market = MarketStateManager::get_market(env, &id)?;
             if market.state == MarketState::Active {
                 if skipped >= offset {
                     active_events.push_back(ActiveEvent {
@@ -65,7 +66,7 @@ impl ReportingManager {
     /// * `env` - The Soroban environment.
     /// * `id` - Unique identifier of the event to snapshot.
     pub fn get_event_snapshot(env: &Env, id: Symbol) -> Result<EventSnapshot, Error> {
-        let market: Market = env.storage().persistent().get(&id).ok_or(Error::MarketNotFound)?;
+        let market = MarketStateManager::get_market(env, &id)?;
         let pool_query = QueryManager::query_market_pool(env, id.clone())?;
         
         Ok(EventSnapshot {
@@ -75,7 +76,7 @@ impl ReportingManager {
             state: market.state,
             total_pool: market.total_staked,
             outcome_pools: pool_query.outcome_pools,
-            participant_count: market.votes.len(),
+            participant_count: market.position_count(),
             end_time: market.end_time,
         })
     }
@@ -1,13 +1,19 @@
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::xdr::FromXdr;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec};
 
+use crate::bets::BetStorage;
 use crate::errors::Error;
 
 use crate::markets::{CommunityConsensus, MarketAnalytics, MarketStateManager, MarketUtils};
 
 use crate::oracles::{OracleFactory, OracleUtils};
+use crate::storage::DataKey;
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
 
+/// Storage key for markets whose primary and fallback oracles both failed.
+const ORACLE_FAILURES_KEY: &str = "oracle_failures";
+
 /// Resolution management system for Predictify Hybrid contract
 ///
 /// This module provides a comprehensive resolution system with:
@@ -946,11 +952,9 @@ impl OracleResolutionManager {
     fn try_fetch_from_config(
         env: &Env,
         config: &crate::types::OracleConfig,
+        max_price_age: u64,
     ) -> Result<(i128, String), Error> {
-        let oracle =
-            OracleFactory::create_oracle(config.provider.clone(), config.oracle_address.clone())?;
-
-        let price = oracle.get_price(env, &config.feed_id)?;
+        let price = OracleFactory::fetch(env, config, max_price_age)?;
 
         let outcome =
             OracleUtils::determine_outcome(price, config.threshold, &config.comparison, env)?;
@@ -958,63 +962,213 @@ impl OracleResolutionManager {
         Ok((price, outcome))
     }
 
+    /// Query the market's primary oracle set and return the price, outcome and
+    /// number of oracles that answered.
+    ///
+    /// With extra primary oracles configured, a majority of the set must answer
+    /// (2 of 2, 2 of 3) or `Error::OracleNoConsensus` is returned; the median of
+    /// the answers is compared against the market's own threshold.
+    fn try_fetch_primary(
+        env: &Env,
+        market: &Market,
+        market_id: &Symbol,
+        max_price_age: u64,
+    ) -> Result<((i128, String), u32), Error> {
+        let primary = &market.oracle_config;
+        let extra = OracleUtils::get_extra_oracles(env, market_id);
+        if extra.is_empty() {
+            return Ok((Self::try_fetch_from_config(env, primary, max_price_age)?, 1));
+        }
+
+        let mut configs = Vec::new(env);
+        configs.push_back(primary.clone());
+        configs.append(&extra);
+
+        let mut prices = Vec::new(env);
+        for config in configs.iter() {
+            if let Ok(price) = OracleFactory::fetch(env, &config, max_price_age) {
+                prices.push_back(price);
+            }
+        }
+
+        let quorum = configs.len() / 2 + 1;
+        if prices.len() < quorum {
+            return Err(Error::OracleNoConsensus);
+        }
+
+        let price = OracleUtils::median_price(&prices)?;
+        let outcome =
+            OracleUtils::determine_outcome(price, primary.threshold, &primary.comparison, env)?;
+        Ok(((price, outcome), prices.len()))
+    }
+
     /// Fetch oracle result for a market with fallback support and timeout
+    ///
+    /// Until `end_time + resolution_timeout` only the primary oracle set is queried
+    /// and its error is returned so the call can be retried. After the timeout the
+    /// fallback oracle (or the primary set when none is configured) is queried
+    /// instead; if that also fails, `Error::ResolutionTimeoutReached` is returned
+    /// and the caller may flag the market with [`Self::mark_oracle_failure`].
+    /// Outcome `market` settles on for an oracle `price` and its derived `outcome`.
+    ///
+    /// Scalar markets settle on the value itself, so `scalar_value` is set and the
+    /// side owed the larger share is returned.
+    fn settle_oracle_outcome(env: &Env, market: &mut Market, price: i128, outcome: String) -> String {
+        match market.market_type {
+            MarketType::Scalar(range) => {
+                market.scalar_value = Some(price);
+                let midpoint = range.lower_bound / 2 + range.upper_bound / 2;
+                let side = if price >= midpoint {
+                    crate::config::SCALAR_LONG_OUTCOME
+                } else {
+                    crate::config::SCALAR_SHORT_OUTCOME
+                };
+                String::from_str(env, side)
+            }
+            MarketType::Categorical => outcome,
+        }
+    }
+
+    /// Query the oracle `fetch_oracle_result` would use, without storing or
+    /// emitting anything, and apply the result to the in-memory `market`.
+    ///
+    /// Returns the price and the outcome the market would settle on.
+    pub fn preview_oracle_result(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+    ) -> Result<(i128, String), Error> {
+        OracleResolutionValidator::validate_market_for_oracle_resolution(env, market)?;
+
+        let timed_out = env.ledger().timestamp()
+            > market.end_time.saturating_add(market.resolution_timeout);
+        let max_price_age = OracleUtils::max_price_age_for_market(env, market_id);
+        let (price, outcome) = if timed_out && market.has_fallback {
+            Self::try_fetch_from_config(env, &market.fallback_oracle_config, max_price_age)?
+        } else {
+            Self::try_fetch_primary(env, market, market_id, max_price_age)?.0
+        };
+
+        let outcome = Self::settle_oracle_outcome(env, market, price, outcome);
+        market.oracle_result = Some(outcome.clone());
+        Ok((price, outcome))
+    }
+
     pub fn fetch_oracle_result(env: &Env, market_id: &Symbol) -> Result<OracleResolution, Error> {
         // Get the market from storage
         let mut market = MarketStateManager::get_market(env, market_id)?;
 
-        // 1. Check if resolution timeout has been reached
-        let current_time = env.ledger().timestamp();
-        if current_time > market.end_time + market.resolution_timeout {
-            // Reached timeout without resolution, mark for refund
-            let old_state = market.state.clone();
-            market.state = crate::types::MarketState::Cancelled;
-            MarketStateManager::update_market(env, market_id, &market);
+        // Validate market for oracle resolution
+        OracleResolutionValidator::validate_market_for_oracle_resolution(env, &market)?;
 
+        let current_time = env.ledger().timestamp();
+        let timed_out =
+            current_time > market.end_time.saturating_add(market.resolution_timeout);
+        let max_price_age = OracleUtils::max_price_age_for_market(env, market_id);
+
+        let (used_config, ((price, outcome), sources_count)) = if !timed_out {
+            // 1. Primary oracles only while they still have time to report
+            let res = Self::try_fetch_primary(env, &market, market_id, max_price_age)?;
+            (market.oracle_config.clone(), res)
+        } else {
             crate::events::EventEmitter::emit_resolution_timeout(env, market_id, current_time);
-            crate::events::EventEmitter::emit_state_change_event(
-                env,
-                market_id,
-                &old_state,
-                &crate::types::MarketState::Cancelled,
-                &soroban_sdk::String::from_str(env, "Resolution timeout reached, market cancelled"),
-            );
 
-            return Err(Error::InvalidState);
-        }
+            // 2. Timed out: switch to the fallback oracle when one is configured
+            if market.has_fallback {
+                let config = market.fallback_oracle_config.clone();
+                let res = Self::try_fetch_from_config(env, &config, max_price_age)
+                    .map_err(|_| Error::ResolutionTimeoutReached)?;
+                crate::events::EventEmitter::emit_fallback_used(
+                    env,
+                    market_id,
+                    &market.oracle_config.oracle_address,
+                    &config.oracle_address,
+                );
+                (config, (res, 1))
+            } else {
+                let res = Self::try_fetch_primary(env, &market, market_id, max_price_age)
+                    .map_err(|_| Error::ResolutionTimeoutReached)?;
+                (market.oracle_config.clone(), res)
+            }
+        };
 
-        // Validate market for oracle resolution
+        let outcome = Self::settle_oracle_outcome(env, &mut market, price, outcome);
+        Self::record_oracle_result(
+            env,
+            market_id,
+            &mut market,
+            &used_config,
+            price,
+            outcome,
+            sources_count,
+        )
+    }
+
+    /// Verify an off-chain result signed by the market's `OracleProvider::Signed`
+    /// key and store it as the market's oracle result.
+    ///
+    /// `payload` is the XDR encoding of `(contract, market_id, outcome, timestamp)`
+    /// and `signature` its ed25519 signature by the key in `oracle_config.signer`. The
+    /// contract address keeps a payload signed for another deployment from
+    /// resolving a market here. An invalid signature aborts the call.
+    ///
+    /// # Errors
+    /// * `Error::InvalidOracleConfig` - The market's oracle is not `Signed`
+    /// * `Error::InvalidInput` - The payload is for another contract or market
+    /// * `Error::InvalidOutcome` - The signed outcome is not a market outcome
+    /// * `Error::OracleStale` - The payload was signed before the market ended,
+    ///   in the future, or longer than the market's max price age ago
+    /// * `Error::MarketResolved` - A result was already stored, so a payload
+    ///   can never be replayed
+    pub fn submit_signed_result(
+        env: &Env,
+        market_id: &Symbol,
+        payload: &Bytes,
+        signature: &BytesN<64>,
+    ) -> Result<OracleResolution, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
         OracleResolutionValidator::validate_market_for_oracle_resolution(env, &market)?;
 
-        // 2. Try primary oracle
-        let mut used_config = market.oracle_config.clone();
-        let primary_result = Self::try_fetch_from_config(env, &used_config);
-
-        let (price, outcome) = match primary_result {
-            Ok(res) => res,
-            Err(_) => {
-                // 3. Try fallback oracle if primary fails
-                if market.has_fallback {
-                    let fallback_config = &market.fallback_oracle_config;
-                    match Self::try_fetch_from_config(env, fallback_config) {
-                        Ok(res) => {
-                            crate::events::EventEmitter::emit_fallback_used(
-                                env,
-                                market_id,
-                                &market.oracle_config.oracle_address,
-                                &fallback_config.oracle_address,
-                            );
-                            used_config = fallback_config.clone();
-                            res
-                        }
-                        Err(_) => return Err(Error::OracleUnavailable),
-                    }
-                } else {
-                    return Err(Error::OracleUnavailable);
-                }
-            }
+        let config = market.oracle_config.clone();
+        if config.provider != OracleProvider::Signed {
+            return Err(Error::InvalidOracleConfig);
+        }
+        let OracleSigner::Ed25519(public_key) = config.signer.clone() else {
+            return Err(Error::InvalidOracleConfig);
         };
 
+        let (contract, signed_market_id, outcome, timestamp) =
+            <(Address, Symbol, String, u64)>::from_xdr(env, payload)
+                .map_err(|_| Error::InvalidInput)?;
+        if contract != env.current_contract_address() || signed_market_id != *market_id {
+            return Err(Error::InvalidInput);
+        }
+        env.crypto().ed25519_verify(&public_key, payload, signature);
+
+        let now = env.ledger().timestamp();
+        let max_age = OracleUtils::max_price_age_for_market(env, market_id);
+        if timestamp < market.end_time || timestamp > now || now - timestamp > max_age {
+            return Err(Error::OracleStale);
+        }
+        if !market.outcomes.contains(&outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        Self::record_oracle_result(env, market_id, &mut market, &config, 0, outcome, 1)
+    }
+
+    /// Store an oracle reading on the market and emit the oracle result event
+    fn record_oracle_result(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        used_config: &OracleConfig,
+        price: i128,
+        outcome: String,
+        sources_count: u32,
+    ) -> Result<OracleResolution, Error> {
+        let current_time = env.ledger().timestamp();
+
         // Create oracle resolution record
         let resolution = OracleResolution {
             market_id: market_id.clone(),
@@ -1028,8 +1182,34 @@ impl OracleResolutionManager {
         };
 
         // Store the result in the market
-        MarketStateManager::set_oracle_result(&mut market, outcome.clone());
-        MarketStateManager::update_market(env, market_id, &market);
+        MarketStateManager::set_oracle_result(market, outcome.clone());
+        MarketStateManager::update_market(env, market_id, market);
+
+        // Keep the raw reading so it can be checked before the dispute window
+        // closes; a multi-source verified result is never overwritten
+        if !crate::oracles::OracleIntegrationManager::is_result_verified(env, market_id) {
+            let oracle_result = crate::types::OracleResult {
+                market_id: market_id.clone(),
+                outcome: outcome.clone(),
+                price,
+                threshold: used_config.threshold,
+                comparison: used_config.comparison.clone(),
+                provider: used_config.provider.clone(),
+                feed_id: used_config.feed_id.clone(),
+                timestamp: current_time,
+                block_number: env.ledger().sequence(),
+                is_verified: false,
+                confidence_score: 100,
+                sources_count,
+                signature: None,
+                error_message: None,
+            };
+            crate::oracles::OracleIntegrationManager::store_oracle_result(
+                env,
+                market_id,
+                &oracle_result,
+            )?;
+        }
 
         // Emit oracle result event
         let provider_str = match used_config.provider {
@@ -1037,6 +1217,7 @@ impl OracleResolutionManager {
                 soroban_sdk::String::from_str(env, "Reflector")
             }
             crate::types::OracleProvider::Pyth => soroban_sdk::String::from_str(env, "Pyth"),
+            crate::types::OracleProvider::Signed => soroban_sdk::String::from_str(env, "Signed"),
             _ => soroban_sdk::String::from_str(env, "Custom"),
         };
         let feed_str = used_config.feed_id.clone();
@@ -1056,6 +1237,102 @@ impl OracleResolutionManager {
         Ok(resolution)
     }
 
+    /// Attach up to two extra primary oracles to a market, aggregated with its
+    /// own oracle config by median at resolution.
+    ///
+    /// Only the market creator may call this, and only while the market is
+    /// active with no stakes. Every extra oracle must use a supported provider
+    /// and the same threshold and comparison as the market's own config. An
+    /// empty list goes back to single-oracle resolution.
+    pub fn set_extra_oracles(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+        oracles: &Vec<crate::types::OracleConfig>,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.creator != *caller {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if oracles.len() >= crate::config::MAX_PRIMARY_ORACLES {
+            return Err(Error::ThresholdTooHigh);
+        }
+        for config in oracles.iter() {
+            if !OracleFactory::is_provider_supported(&config.provider)
+                || config.threshold != market.oracle_config.threshold
+                || config.comparison != market.oracle_config.comparison
+                || config.quote_feed_id.is_some() != market.oracle_config.quote_feed_id.is_some()
+            {
+                return Err(Error::InvalidOracleConfig);
+            }
+            config.validate_price_source()?;
+        }
+
+        OracleUtils::set_extra_oracles(env, market_id, oracles);
+        crate::events::EventEmitter::emit_market_oracles_updated(
+            env,
+            market_id,
+            caller,
+            oracles.len() + 1,
+        );
+        Ok(())
+    }
+
+    /// Record that neither the primary nor the fallback oracle produced a result
+    /// after the resolution timeout.
+    ///
+    /// The market is moved to `Ended` (if still open) so that
+    /// `resolve_market_manual` can settle it, and it is flagged so that anyone may
+    /// call `refund_on_oracle_failure` to void it.
+    pub fn mark_oracle_failure(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+
+        if matches!(market.state, MarketState::Active | MarketState::BettingClosed) {
+            crate::markets::MarketStateLogic::transition(&mut market, MarketEvent::End)?;
+            MarketStateManager::update_market(env, market_id, &market);
+        }
+
+        let key = Symbol::new(env, ORACLE_FAILURES_KEY);
+        let mut failures: Map<Symbol, u64> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Map::new(env));
+        failures.set(market_id.clone(), env.ledger().timestamp());
+        env.storage().persistent().set(&key, &failures);
+
+        let fallback_oracle = if market.has_fallback {
+            Some(market.fallback_oracle_config.oracle_address.clone())
+        } else {
+            None
+        };
+        crate::events::EventEmitter::emit_oracle_resolution_failed(
+            env,
+            market_id,
+            &market.oracle_config.oracle_address,
+            &fallback_oracle,
+        );
+
+        Ok(())
+    }
+
+    /// Whether both oracles failed to resolve the market after its timeout.
+    pub fn is_oracle_failed(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get::<Symbol, Map<Symbol, u64>>(&Symbol::new(env, ORACLE_FAILURES_KEY))
+            .map(|failures| failures.contains_key(market_id.clone()))
+            .unwrap_or(false)
+    }
+
     /// Get oracle resolution for a market
 
     pub fn get_oracle_resolution(
@@ -1281,6 +1558,30 @@ pub struct MarketResolutionManager;
 
 impl MarketResolutionManager {
     /// Resolve a market by combining oracle results and community votes
+    /// Winning outcome(s) of `market` given its oracle result.
+    ///
+    /// Uses multi-outcome resolution with tie detection, so ties split the pool;
+    /// scalar markets pay both sides unless the value lands at or beyond a bound.
+    /// Shared by `resolve_market` and `simulate_resolution`.
+    pub fn derive_winning_outcomes(
+        env: &Env,
+        market: &Market,
+        oracle_result: &String,
+    ) -> Vec<String> {
+        match (market.market_type, market.scalar_value) {
+            (MarketType::Scalar(range), Some(value)) => {
+                MarketUtils::scalar_winning_outcomes(env, &range, value)
+            }
+            _ => MarketUtils::determine_winning_outcomes(
+                env,
+                market,
+                oracle_result,
+                &MarketAnalytics::calculate_community_consensus(market),
+                0, // Tie threshold: 0 = exact ties only
+            ),
+        }
+    }
+
     pub fn resolve_market(env: &Env, market_id: &Symbol) -> Result<MarketResolution, Error> {
         // Get the market from storage
         let mut market = MarketStateManager::get_market(env, market_id)?;
@@ -1288,12 +1589,7 @@ impl MarketResolutionManager {
         // Validate market for resolution (includes min pool size check)
         let validation = MarketResolutionValidator::validate_market_for_resolution(env, &market);
         if let Err(Error::InvalidState) = validation {
-            let global_min: i128 = env
-                .storage()
-                .persistent()
-                .get(&Symbol::new(env, "global_min_pool"))
-                .unwrap_or(0);
-            let min_pool = market.min_pool_size.unwrap_or(global_min);
+            let min_pool = MarketUtils::effective_min_pool_size(env, &market);
             crate::events::EventEmitter::emit_min_pool_size_not_met(
                 env,
                 market_id,
@@ -1314,15 +1610,7 @@ impl MarketResolutionManager {
         // Calculate community consensus
         let community_consensus = MarketAnalytics::calculate_community_consensus(&market);
 
-        // Determine winning outcome(s) using multi-outcome resolution with tie detection
-        // This handles both single winner and tie cases (pool split)
-        let winning_outcomes = MarketUtils::determine_winning_outcomes(
-            env,
-            &market,
-            &oracle_result,
-            &community_consensus,
-            0, // Tie threshold: 0 = exact ties only
-        );
+        let winning_outcomes = Self::derive_winning_outcomes(env, &market, &oracle_result);
 
         // For resolution record, use first outcome (or comma-separated for display)
         let final_result = if winning_outcomes.len() > 0 {
@@ -1358,16 +1646,17 @@ impl MarketResolutionManager {
         // Capture old state for event
         let old_state = market.state.clone();
 
-        // Set winning outcome(s) - supports both single winner and ties
+        // Set winning outcome(s) - supports both single winner and ties; validation
+        // guarantees the end time has passed, so a market still stored as open ends first
         MarketStateManager::set_winning_outcomes(
             &mut market,
             winning_outcomes.clone(),
             Some(market_id),
-        );
+        )?;
         MarketStateManager::update_market(env, market_id, &market);
 
         // Decrement active event count since the event is resolved
-        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.creator);
 
         // Emit market resolved event
         let oracle_result_str = market
@@ -1393,6 +1682,12 @@ impl MarketResolutionManager {
             &resolution_method_str,
             confidence_score as i128,
         );
+        let source = match resolution_method {
+            ResolutionMethod::AdminOverride => crate::events::ResolutionSource::Manual,
+            ResolutionMethod::DisputeResolution => crate::events::ResolutionSource::Dispute,
+            _ => crate::events::ResolutionSource::Oracle,
+        };
+        MarketUtils::publish_resolution(env, market_id, &mut market, source);
 
         // Emit state change event
         crate::events::EventEmitter::emit_state_change_event(
@@ -1439,11 +1734,22 @@ impl MarketResolutionManager {
         // Set final outcome(s) - convert single outcome to vector
         let mut winning_outcomes = Vec::new(env);
         winning_outcomes.push_back(outcome.clone());
-        MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, Some(market_id));
+        MarketStateManager::set_winning_outcomes(
+            &mut market,
+            winning_outcomes.clone(),
+            Some(market_id),
+        )?;
         MarketStateManager::update_market(env, market_id, &market);
 
         // Decrement active event count since the event is manually finalized
-        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.creator);
+
+        MarketUtils::publish_resolution(
+            env,
+            market_id,
+            &mut market,
+            crate::events::ResolutionSource::Manual,
+        );
 
         Ok(resolution)
     }
@@ -1482,6 +1788,11 @@ impl OracleResolutionValidator {
             return Err(Error::MarketResolved);
         }
 
+        // Cancelled and voided markets have been refunded and can no longer be resolved
+        if market.state.is_refunding() {
+            return Err(Error::MarketClosed);
+        }
+
         // Check if the market ended (we can only fetch oracle result after market ends)
         let current_time = env.ledger().timestamp();
         if current_time < market.end_time {
@@ -1537,13 +1848,7 @@ impl MarketResolutionValidator {
         }
 
         // Check minimum pool size requirement (per-market override, else global)
-        let global_min: i128 = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(env, "global_min_pool"))
-            .unwrap_or(0);
-        let min_pool = market.min_pool_size.unwrap_or(global_min);
-        if min_pool > 0 && market.total_staked < min_pool {
+        if MarketUtils::is_underfunded(env, market) {
             return Err(Error::InvalidState);
         }
 
@@ -1553,7 +1858,7 @@ impl MarketResolutionValidator {
     /// Validate admin permissions
     pub fn validate_admin_permissions(env: &Env, admin: &Address) -> Result<(), Error> {
         let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+            env.storage().instance().get(&DataKey::Admin);
 
         match stored_admin {
             Some(stored_admin) => {
@@ -1604,6 +1909,355 @@ impl MarketResolutionValidator {
     }
 }
 
+// ===== COMMUNITY RESOLUTION =====
+
+/// Storage keys for community votes
+#[contracttype]
+#[derive(Clone)]
+pub enum CommunityVoteKey {
+    /// Vote opened for a market
+    Vote(Symbol),
+    /// Stakes frozen for a market's vote
+    Snapshot(Symbol),
+    /// Grace period, voting period and quorum
+    Config,
+}
+
+/// Last-resort resolution by a stake-weighted vote of a market's bettors.
+///
+/// Once `resolve_market` has recorded that every oracle failed and the market's
+/// `resolution_timeout` plus a grace period has passed, any bettor may open a
+/// vote. Each bettor votes once with a weight equal to their stake, as frozen
+/// in a [`StakeSnapshot`] when the vote starts. After the voting period the
+/// plurality outcome wins if enough stake voted; otherwise [`Self::tally`]
+/// reports a void so the market can be refunded.
+pub struct CommunityResolutionManager;
+
+impl CommunityResolutionManager {
+    /// Current grace period, voting period and quorum.
+    pub fn get_config(env: &Env) -> CommunityVoteConfig {
+        env.storage()
+            .persistent()
+            .get(&CommunityVoteKey::Config)
+            .unwrap_or(CommunityVoteConfig {
+                grace_period: crate::config::DEFAULT_COMMUNITY_VOTE_GRACE_PERIOD,
+                voting_period: crate::config::DEFAULT_COMMUNITY_VOTING_PERIOD,
+                quorum_bps: crate::config::DEFAULT_COMMUNITY_VOTE_QUORUM_BPS,
+            })
+    }
+
+    /// Store new community vote settings (caller checks admin).
+    pub fn set_config(env: &Env, config: &CommunityVoteConfig) -> Result<(), Error> {
+        if config.voting_period == 0 || config.quorum_bps == 0 || config.quorum_bps > 10_000 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&CommunityVoteKey::Config, config);
+        Ok(())
+    }
+
+    /// Community vote for a market, if one has been opened.
+    pub fn get_vote(env: &Env, market_id: &Symbol) -> Option<CommunityVote> {
+        env.storage()
+            .persistent()
+            .get(&CommunityVoteKey::Vote(market_id.clone()))
+    }
+
+    /// Stakes frozen for a market's vote, if taken yet.
+    pub fn get_stake_snapshot(env: &Env, market_id: &Symbol) -> Option<StakeSnapshot> {
+        env.storage()
+            .persistent()
+            .get(&CommunityVoteKey::Snapshot(market_id.clone()))
+    }
+
+    /// Return the market's stake snapshot, taking it from the current positions
+    /// if there is none yet. Only called once betting has ended.
+    fn stake_snapshot(env: &Env, market_id: &Symbol) -> StakeSnapshot {
+        if let Some(snapshot) = Self::get_stake_snapshot(env, market_id) {
+            return snapshot;
+        }
+        let mut stakes = Map::new(env);
+        let mut total: i128 = 0;
+        for user in BetStorage::get_all_bets_for_market(env, market_id).iter() {
+            if let Some(bet) = BetStorage::get_position(env, market_id, &user) {
+                stakes.set(user, bet.amount);
+                total = total.saturating_add(bet.amount);
+            }
+        }
+        let snapshot = StakeSnapshot {
+            taken_at: env.ledger().timestamp(),
+            stakes,
+            total,
+        };
+        env.storage()
+            .persistent()
+            .set(&CommunityVoteKey::Snapshot(market_id.clone()), &snapshot);
+        snapshot
+    }
+
+    /// Stake-weighted tally per outcome of a market's vote, in outcome order.
+    /// Empty if no vote has been opened.
+    pub fn get_tally(env: &Env, market_id: &Symbol) -> Vec<(String, i128)> {
+        let mut result = Vec::new(env);
+        let (Some(vote), Ok(market)) = (
+            Self::get_vote(env, market_id),
+            MarketStateManager::get_market(env, market_id),
+        ) else {
+            return result;
+        };
+        for outcome in market.outcomes.iter() {
+            let weight = vote.tallies.get(outcome.clone()).unwrap_or(0);
+            result.push_back((outcome, weight));
+        }
+        result
+    }
+
+    /// Whether a market has a vote that has not been tallied yet.
+    pub fn is_vote_open(env: &Env, market_id: &Symbol) -> bool {
+        Self::get_vote(env, market_id).is_some_and(|vote| !vote.finalized)
+    }
+
+    /// Open a vote on a market whose oracles failed.
+    pub fn open_vote(
+        env: &Env,
+        caller: &Address,
+        market_id: &Symbol,
+    ) -> Result<CommunityVote, Error> {
+        caller.require_auth();
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.state.is_refunding() {
+            return Err(Error::MarketClosed);
+        }
+        if market.winning_outcomes.is_some() || market.oracle_result.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if BetStorage::get_position(env, market_id, caller).map_or(0, |bet| bet.amount) <= 0 {
+            return Err(Error::Unauthorized);
+        }
+        if Self::get_vote(env, market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+
+        let config = Self::get_config(env);
+        let now = env.ledger().timestamp();
+        let opens_at = market
+            .end_time
+            .saturating_add(market.resolution_timeout)
+            .saturating_add(config.grace_period);
+        if !OracleResolutionManager::is_oracle_failed(env, market_id) || now < opens_at {
+            return Err(Error::MarketNotReady);
+        }
+
+        Ok(Self::start_vote(env, &market, market_id, caller))
+    }
+
+    /// Open the vote a `Hybrid` market needs once its oracle result is in.
+    ///
+    /// No grace period applies; the vote is opened by the contract itself.
+    pub fn open_hybrid_vote(env: &Env, market_id: &Symbol) -> Result<CommunityVote, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.resolve_mode != ResolveMode::Hybrid || market.oracle_result.is_none() {
+            return Err(Error::InvalidState);
+        }
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if Self::get_vote(env, market_id).is_some() {
+            return Err(Error::InvalidState);
+        }
+
+        // Betting is over; the market waits on the vote
+        let mut market = market;
+        if matches!(market.state, MarketState::Active | MarketState::BettingClosed) {
+            crate::markets::MarketStateLogic::transition(&mut market, MarketEvent::End)?;
+            MarketStateManager::update_market(env, market_id, &market);
+        }
+
+        Ok(Self::start_vote(
+            env,
+            &market,
+            market_id,
+            &env.current_contract_address(),
+        ))
+    }
+
+    /// Hand a `Hybrid` market whose vote disagreed with its oracle result
+    /// over to the admin by moving it to `Disputed`.
+    pub fn escalate_hybrid(env: &Env, market_id: &Symbol, market: &mut Market) -> Result<(), Error> {
+        let old_state = market.state;
+        crate::markets::MarketStateLogic::transition(market, MarketEvent::Dispute)?;
+        MarketStateManager::update_market(env, market_id, market);
+
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Disputed,
+            &String::from_str(env, "Oracle and community vote disagree"),
+        );
+        Ok(())
+    }
+
+    fn start_vote(
+        env: &Env,
+        market: &Market,
+        market_id: &Symbol,
+        opened_by: &Address,
+    ) -> CommunityVote {
+        Self::stake_snapshot(env, market_id);
+        let now = env.ledger().timestamp();
+        let vote = CommunityVote {
+            opened_by: opened_by.clone(),
+            opened_at: now,
+            ends_at: now.saturating_add(Self::get_config(env).voting_period),
+            tallies: Map::new(env),
+            voters: Map::new(env),
+            total_weight: 0,
+            finalized: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&CommunityVoteKey::Vote(market_id.clone()), &vote);
+
+        crate::events::EventEmitter::emit_community_vote_opened(
+            env,
+            market_id,
+            opened_by,
+            vote.ends_at,
+        );
+        vote
+    }
+
+    /// Choose how a market is resolved. Creator only, while the market is
+    /// active and has no stakes.
+    pub fn set_resolve_mode(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+        mode: ResolveMode,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::MarketClosed);
+        }
+        if market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+        if mode != ResolveMode::Standard && market.market_type != MarketType::Categorical {
+            return Err(Error::InvalidState);
+        }
+
+        market.resolve_mode = mode;
+        MarketStateManager::update_market(env, market_id, &market);
+        crate::events::EventEmitter::emit_resolve_mode_set(env, market_id, creator, mode);
+        Ok(())
+    }
+
+    /// Record a bettor's vote, weighted by their stake in the vote's snapshot.
+    pub fn cast_vote(
+        env: &Env,
+        voter: &Address,
+        market_id: &Symbol,
+        outcome: &String,
+    ) -> Result<(), Error> {
+        voter.require_auth();
+
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let mut vote = Self::get_vote(env, market_id).ok_or(Error::InvalidState)?;
+        if vote.finalized || env.ledger().timestamp() >= vote.ends_at {
+            return Err(Error::MarketClosed);
+        }
+
+        let snapshot = Self::stake_snapshot(env, market_id);
+        let weight = snapshot.stakes.get(voter.clone()).unwrap_or(0);
+        if weight <= 0 {
+            return Err(Error::Unauthorized);
+        }
+        if vote.voters.contains_key(voter.clone()) {
+            return Err(Error::AlreadyVoted);
+        }
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        let tally = vote.tallies.get(outcome.clone()).unwrap_or(0);
+        vote.tallies.set(outcome.clone(), tally + weight);
+        vote.voters.set(voter.clone(), outcome.clone());
+        vote.total_weight += weight;
+        env.storage()
+            .persistent()
+            .set(&CommunityVoteKey::Vote(market_id.clone()), &vote);
+
+        crate::events::EventEmitter::emit_community_vote_cast(env, market_id, voter, outcome, weight);
+        Ok(())
+    }
+
+    /// Close a finished vote and return the winning outcome, or `None` on a
+    /// tie, a missed quorum or an underfunded pool.
+    pub fn tally(env: &Env, market_id: &Symbol) -> Result<Option<String>, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let mut vote = Self::get_vote(env, market_id).ok_or(Error::InvalidState)?;
+        if vote.finalized {
+            return Err(Error::InvalidState);
+        }
+        // A Hybrid market's vote runs alongside its oracle result
+        let hybrid = market.resolve_mode == ResolveMode::Hybrid;
+        if market.winning_outcomes.is_some() || (!hybrid && market.oracle_result.is_some()) {
+            return Err(Error::MarketResolved);
+        }
+        if market.state.is_refunding() {
+            return Err(Error::MarketClosed);
+        }
+        if env.ledger().timestamp() < vote.ends_at {
+            return Err(Error::MarketNotReady);
+        }
+
+        let config = Self::get_config(env);
+        let snapshot = Self::stake_snapshot(env, market_id);
+        let quorum_met = vote.total_weight.saturating_mul(10_000)
+            >= snapshot.total.saturating_mul(config.quorum_bps as i128);
+
+        let mut winner: Option<String> = None;
+        let mut best = 0i128;
+        let mut tied = false;
+        for (outcome, weight) in vote.tallies.iter() {
+            if weight > best {
+                best = weight;
+                winner = Some(outcome);
+                tied = false;
+            } else if weight == best {
+                tied = true;
+            }
+        }
+
+        let result = if quorum_met && !tied && !MarketUtils::is_underfunded(env, &market) {
+            winner
+        } else {
+            None
+        };
+
+        vote.finalized = true;
+        env.storage()
+            .persistent()
+            .set(&CommunityVoteKey::Vote(market_id.clone()), &vote);
+
+        crate::events::EventEmitter::emit_community_vote_finalized(
+            env,
+            market_id,
+            &result,
+            vote.total_weight,
+        );
+        Ok(result)
+    }
+}
+
 // ===== RESOLUTION ANALYTICS =====
 
 /// Oracle resolution analytics
@@ -1916,6 +2570,9 @@ mod tests {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 2500000,
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             None,
             86400,
@@ -0,0 +1,129 @@
+#![cfg(test)]
+
+//! Resolution deadline tests.
+//!
+//! Past `resolution_deadline` an unresolved market can be voided by anyone
+//! with `void_unresolved_market`, refunding every bettor in full.
+
+use crate::config::RESOLUTION_DEADLINE_GRACE_SECONDS;
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::MarketState;
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE, PAYOUT_CURSOR_DONE};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::String;
+
+const DAY: u64 = 24 * 60 * 60;
+const STAKE: i128 = 10_000_000;
+
+#[test]
+fn test_resolution_deadline_on_get_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.resolution_deadline,
+        market.end_time
+            + market.resolution_timeout
+            + market.dispute_window_seconds
+            + RESOLUTION_DEADLINE_GRACE_SECONDS
+    );
+
+    // The deadline follows the end time when the market is extended
+    let new_end_time = market.end_time + 2 * DAY;
+    client.extend_market(
+        &test.admin,
+        &market_id,
+        &new_end_time,
+        &String::from_str(&test.env, "Postponed"),
+    );
+    let extended = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        extended.resolution_deadline,
+        market.resolution_deadline + 2 * DAY
+    );
+}
+
+#[test]
+fn test_void_unresolved_market_refunds_bettors() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+
+    let alice = test.create_funded_user();
+    let bob = test.create_funded_user();
+    let alice_start = token.balance(&alice);
+    let bob_start = token.balance(&bob);
+    client.place_bet(
+        &alice,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+    client.place_bet(
+        &bob,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &(2 * STAKE),
+        &None,
+    );
+
+    let deadline = client.get_market(&market_id).unwrap().resolution_deadline;
+    test.env.ledger().with_mut(|li| li.timestamp = deadline - 1);
+    assert_eq!(
+        client.try_void_unresolved_market(&market_id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = deadline + 365 * DAY);
+    assert_eq!(client.void_unresolved_market(&market_id), 3 * STAKE);
+    assert_eq!(
+        client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        (3 * STAKE, PAYOUT_CURSOR_DONE)
+    );
+
+    assert_eq!(token.balance(&alice), alice_start);
+    assert_eq!(token.balance(&bob), bob_start);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Voided
+    );
+    // Already voided: nothing more to refund
+    assert_eq!(client.void_unresolved_market(&market_id), 0);
+}
+
+#[test]
+fn test_void_resolved_market_fails() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &STAKE,
+        &None,
+    );
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.resolution_deadline + 365 * DAY);
+    assert_eq!(
+        client.try_void_unresolved_market(&market_id),
+        Err(Ok(Error::MarketResolved))
+    );
+}
@@ -4,6 +4,7 @@ use crate::config::{ConfigManager, DISPUTE_EXTENSION_HOURS};
 use crate::disputes::DisputeManager;
 use crate::errors::Error;
 use crate::markets::MarketStateManager;
+use crate::storage::DataKey;
 use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
 use crate::voting::VotingManager;
 use crate::PredictifyHybrid;
@@ -101,21 +102,25 @@ fn test_payout_blocked_during_dispute_window() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         // Add a vote
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 1_000_000);
-        market.total_staked = 1_000_000;
+        MarketStateManager::add_vote(
+            &setup.env,
+            &market_id,
+            &mut market,
+            user.clone(),
+            String::from_str(&setup.env, "yes"),
+            1_000_000,
+        )
+        .unwrap();
 
         // Store market
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance time past end_time
         setup.advance_time(3700);
 
         // Market ends (but not resolved yet)
         market.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // File a dispute - this should extend the market
         let dispute_stake = 10_000_000;
@@ -132,7 +137,7 @@ fn test_payout_blocked_during_dispute_window() {
             &setup.env,
             cfg.voting.dispute_extension_hours.into(),
         );
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Try to claim payout during dispute window - should fail
         let current_time = setup.env.ledger().timestamp();
@@ -142,7 +147,11 @@ fn test_payout_blocked_during_dispute_window() {
         );
 
         // Verify market is still in dispute period
-        let extended_market: Market = setup.env.storage().persistent().get(&market_id).unwrap();
+        let extended_market: Market = setup.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         assert!(extended_market.end_time > current_time);
     });
 }
@@ -158,20 +167,24 @@ fn test_payout_allowed_after_dispute_window_closes() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         // Add vote
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 1_000_000);
-        market.total_staked = 1_000_000;
+        MarketStateManager::add_vote(
+            &setup.env,
+            &market_id,
+            &mut market,
+            user.clone(),
+            String::from_str(&setup.env, "yes"),
+            1_000_000,
+        )
+        .unwrap();
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past original end time
         setup.advance_time(3700);
 
         // Market ends
         market.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // File dispute and extend
         MarketStateManager::add_dispute_stake(
@@ -186,14 +199,18 @@ fn test_payout_allowed_after_dispute_window_closes() {
             &setup.env,
             cfg.voting.dispute_extension_hours.into(),
         );
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past dispute window (24 hours)
         setup.advance_time(24 * 3600 + 100);
 
         // Now payout should be allowed
         let current_time = setup.env.ledger().timestamp();
-        let final_market: Market = setup.env.storage().persistent().get(&market_id).unwrap();
+        let final_market: Market = setup.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         assert!(
             current_time >= final_market.end_time,
             "Should be past dispute window"
@@ -210,20 +227,24 @@ fn test_payout_blocked_with_active_dispute() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 5_000_000);
-        market.total_staked = 5_000_000;
+        MarketStateManager::add_vote(
+            &setup.env,
+            &market_id,
+            &mut market,
+            user.clone(),
+            String::from_str(&setup.env, "yes"),
+            5_000_000,
+        )
+        .unwrap();
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past original end time
         setup.advance_time(1100);
 
         // Market ends - set to Ended state before filing dispute
         market.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Add dispute stake
         MarketStateManager::add_dispute_stake(
@@ -238,10 +259,14 @@ fn test_payout_blocked_with_active_dispute() {
             &setup.env,
             cfg.voting.dispute_extension_hours.into(),
         );
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Verify market is in disputed state
-        let disputed_market: Market = setup.env.storage().persistent().get(&market_id).unwrap();
+        let disputed_market: Market = setup.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         assert_eq!(disputed_market.state, MarketState::Disputed);
 
         // Verify end time was extended
@@ -265,7 +290,7 @@ fn test_dispute_creation_during_window() {
         let mut outcomes = soroban_sdk::Vec::new(&setup.env);
         outcomes.push_back(String::from_str(&setup.env, "yes"));
         market.winning_outcomes = Some(outcomes);
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past end time but within dispute window
         setup.advance_time(5100);
@@ -295,14 +320,14 @@ fn test_dispute_extends_market_deadline() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         let original_end_time = market.end_time;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past end time
         setup.advance_time(2100);
 
         // Market ends - set to Ended state before filing dispute
         market.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // File dispute
         MarketStateManager::add_dispute_stake(
@@ -343,15 +368,15 @@ fn test_per_event_dispute_window() {
         let mut market2 = market1.clone();
         market2.end_time = end_time2;
 
-        setup.env.storage().persistent().set(&market_id1, &market1);
-        setup.env.storage().persistent().set(&market_id2, &market2);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id1.clone()), &market1);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id2.clone()), &market2);
 
         // Advance time past first market end
         setup.advance_time(1100);
 
         // First market ends - set to Ended state before filing dispute
         market1.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id1, &market1);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id1.clone()), &market1);
 
         // File dispute on first market
         MarketStateManager::add_dispute_stake(
@@ -366,14 +391,22 @@ fn test_per_event_dispute_window() {
             &setup.env,
             cfg.voting.dispute_extension_hours.into(),
         );
-        setup.env.storage().persistent().set(&market_id1, &market1);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id1.clone()), &market1);
 
         // Second market should not be affected
-        let market2_check: Market = setup.env.storage().persistent().get(&market_id2).unwrap();
+        let market2_check: Market = setup.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id2.clone()))
+            .unwrap();
         assert_eq!(market2_check.end_time, end_time2);
 
         // First market should be extended
-        let market1_check: Market = setup.env.storage().persistent().get(&market_id1).unwrap();
+        let market1_check: Market = setup.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id1.clone()))
+            .unwrap();
         assert!(market1_check.end_time > end_time1);
     });
 }
@@ -413,7 +446,7 @@ fn test_very_long_dispute_window() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past end time
         setup.advance_time(1100);
@@ -438,7 +471,7 @@ fn test_exact_timestamp_match_at_window_boundary() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance to exactly the end time
         setup.advance_time(1000);
@@ -461,14 +494,14 @@ fn test_dispute_window_boundary_exact_expiry() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past end time
         setup.advance_time(1100);
 
         // Market ends - set to Ended state before filing dispute
         market.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // File dispute and extend
         MarketStateManager::add_dispute_stake(
@@ -482,7 +515,7 @@ fn test_dispute_window_boundary_exact_expiry() {
         MarketStateManager::extend_for_dispute(&mut market, &setup.env, extension_hours.into());
 
         let dispute_end_time = market.end_time;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance to exactly the dispute window end
         let time_to_advance = dispute_end_time - setup.env.ledger().timestamp();
@@ -508,14 +541,14 @@ fn test_multiple_disputes_extend_once() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past end time
         setup.advance_time(1100);
 
         // Market ends - set to Ended state before filing disputes
         market.state = MarketState::Ended;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // First user files dispute - this transitions market to Disputed state
         MarketStateManager::add_dispute_stake(
@@ -584,7 +617,7 @@ fn test_resolution_blocked_before_end_time() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         market.state = MarketState::Active;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Try to resolve before end time
         let current_time = setup.env.ledger().timestamp();
@@ -605,7 +638,7 @@ fn test_resolution_allowed_after_end_time() {
         let (market_id, mut market) = setup.create_test_market(end_time);
 
         market.state = MarketState::Active;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // Advance past end time
         setup.advance_time(1100);
@@ -637,14 +670,18 @@ fn test_full_lifecycle_with_dispute_window() {
         let end_time = setup.env.ledger().timestamp() + 1000;
         let (market_id, mut market) = setup.create_test_market(end_time);
 
-        market
-            .votes
-            .set(user.clone(), String::from_str(&setup.env, "yes"));
-        market.stakes.set(user.clone(), 5_000_000);
-        market.total_staked = 5_000_000;
+        MarketStateManager::add_vote(
+            &setup.env,
+            &market_id,
+            &mut market,
+            user.clone(),
+            String::from_str(&setup.env, "yes"),
+            5_000_000,
+        )
+        .unwrap();
         market.state = MarketState::Active;
 
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // 2. Advance to end time
         setup.advance_time(1100);
@@ -654,7 +691,7 @@ fn test_full_lifecycle_with_dispute_window() {
         let mut outcomes = soroban_sdk::Vec::new(&setup.env);
         outcomes.push_back(String::from_str(&setup.env, "yes"));
         market.winning_outcomes = Some(outcomes);
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // 4. File dispute (before resolving, while in Ended state)
         MarketStateManager::add_dispute_stake(
@@ -672,7 +709,7 @@ fn test_full_lifecycle_with_dispute_window() {
         market.state = MarketState::Disputed;
 
         let dispute_end_time = market.end_time;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         // 5. During dispute window - payout blocked
         let current_time = setup.env.ledger().timestamp();
@@ -688,7 +725,7 @@ fn test_full_lifecycle_with_dispute_window() {
 
         // 8. Finalize
         market.state = MarketState::Closed;
-        setup.env.storage().persistent().set(&market_id, &market);
+        setup.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
 
         assert_eq!(market.state, MarketState::Closed);
     });
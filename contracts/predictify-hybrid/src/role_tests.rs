@@ -0,0 +1,188 @@
+#![cfg(test)]
+
+//! Operational role tests.
+//!
+//! The primary admin delegates `Resolver`, `FeeManager` and `Pauser` to other
+//! addresses with `grant_role`; each role unlocks only its own entrypoints.
+
+use crate::admin::Role;
+use crate::errors::Error;
+use crate::events::{RoleGrantedEvent, RoleRevokedEvent};
+use crate::test::PredictifyTest;
+use crate::types::MarketState;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, Address, String, Symbol};
+
+/// Creates a market with one 100 XLM bet on "yes" and moves past its end time.
+fn ended_market(test: &PredictifyTest) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let bettor = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(
+        &bettor,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+        &None,
+    );
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    market_id
+}
+
+#[test]
+fn test_admin_implicitly_holds_all_roles() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    assert!(client.has_role(&Role::Resolver, &test.admin));
+    assert!(client.has_role(&Role::FeeManager, &test.admin));
+    assert!(client.has_role(&Role::Pauser, &test.admin));
+    assert!(!client.has_role(&Role::Resolver, &test.user));
+}
+
+#[test]
+fn test_resolver_can_resolve_but_not_withdraw_fees() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let resolver = Address::generate(&test.env);
+    let market_id = ended_market(&test);
+    let yes = String::from_str(&test.env, "yes");
+
+    assert_eq!(
+        client.try_resolve_market_manual(&resolver, &market_id, &yes),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.grant_role(&test.admin, &Role::Resolver, &resolver);
+    assert!(client.has_role(&Role::Resolver, &resolver));
+    assert!(!client.has_role(&Role::FeeManager, &resolver));
+    let granted = test
+        .env
+        .as_contract(&test.contract_id, || {
+            test.env
+                .storage()
+                .persistent()
+                .get::<Symbol, RoleGrantedEvent>(&symbol_short!("role_grt"))
+        })
+        .unwrap();
+    assert_eq!(granted.role, Role::Resolver);
+    assert_eq!(granted.account, resolver);
+    assert_eq!(granted.granted_by, test.admin);
+
+    client.resolve_market_manual(&resolver, &market_id, &yes);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+
+    // Fee entrypoints stay closed to the resolver
+    assert_eq!(
+        client.try_collect_fees(&resolver, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_withdraw_fees(&resolver, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.try_pause(&resolver), Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_fee_manager_collects_and_withdraws_fees() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let fee_manager = Address::generate(&test.env);
+    let market_id = ended_market(&test);
+
+    client.grant_role(&test.admin, &Role::FeeManager, &fee_manager);
+    assert_eq!(
+        client.try_resolve_market_manual(
+            &fee_manager,
+            &market_id,
+            &String::from_str(&test.env, "yes")
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // 2% of the 100 XLM pool
+    assert_eq!(client.collect_fees(&fee_manager, &market_id), 2_0000000);
+    assert_eq!(client.withdraw_fees(&fee_manager, &0), 2_0000000);
+}
+
+#[test]
+fn test_pauser_can_pause_and_unpause() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pauser = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    assert_eq!(client.try_pause(&pauser), Err(Ok(Error::Unauthorized)));
+    client.grant_role(&test.admin, &Role::Pauser, &pauser);
+    client.pause(&pauser);
+    assert!(client.is_contract_paused());
+    client.unpause(&pauser);
+    assert!(!client.is_contract_paused());
+}
+
+#[test]
+fn test_revoked_role_is_unauthorized() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let resolver = Address::generate(&test.env);
+    let market_id = ended_market(&test);
+
+    client.grant_role(&test.admin, &Role::Resolver, &resolver);
+    client.grant_role(&test.admin, &Role::Pauser, &resolver);
+    client.revoke_role(&test.admin, &Role::Resolver, &resolver);
+    assert!(!client.has_role(&Role::Resolver, &resolver));
+    assert!(client.has_role(&Role::Pauser, &resolver));
+    let revoked = test
+        .env
+        .as_contract(&test.contract_id, || {
+            test.env
+                .storage()
+                .persistent()
+                .get::<Symbol, RoleRevokedEvent>(&symbol_short!("role_rvk"))
+        })
+        .unwrap();
+    assert_eq!(revoked.role, Role::Resolver);
+    assert_eq!(revoked.account, resolver);
+
+    assert_eq!(
+        client.try_resolve_market_manual(
+            &resolver,
+            &market_id,
+            &String::from_str(&test.env, "yes")
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_only_admin_manages_roles() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let resolver = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    assert_eq!(
+        client.try_grant_role(&test.user, &Role::Resolver, &resolver),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Holding a role does not allow granting it
+    client.grant_role(&test.admin, &Role::Resolver, &resolver);
+    assert_eq!(
+        client.try_grant_role(&resolver, &Role::Resolver, &test.user),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_revoke_role(&test.user, &Role::Resolver, &resolver),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(client.has_role(&Role::Resolver, &resolver));
+}
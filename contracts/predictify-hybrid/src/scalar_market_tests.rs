@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+//! Scalar markets.
+//!
+//! A scalar market takes `long` and `short` positions on a numeric oracle
+//! value. The long side is paid `(value - lower) / (upper - lower)` of the pool
+//! and the short side the rest, with the value clamped to the range.
+
+use crate::errors::Error;
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::test::PredictifyTest;
+use crate::types::{
+    MarketType, OracleConfig, OracleProvider, OracleSigner, PythPriceData, ResolveMode, ScalarRange,
+};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, String, Symbol};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+// $20,000 - $30,000 in 8-decimal units
+const LOWER: i128 = 2_000_000_000_000;
+const UPPER: i128 = 3_000_000_000_000;
+const LONG_STAKE: i128 = 10_000_000;
+const SHORT_STAKE: i128 = 30_000_000;
+const POOL: i128 = LONG_STAKE + SHORT_STAKE;
+
+struct ScalarSetup {
+    test: PredictifyTest,
+    oracle: Address,
+    market_id: Symbol,
+    long: Address,
+    short: Address,
+}
+
+fn range() -> MarketType {
+    MarketType::Scalar(ScalarRange {
+        lower_bound: LOWER,
+        upper_bound: UPPER,
+    })
+}
+
+fn create_market(test: &PredictifyTest, oracle: &Address) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Where will BTC close?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address: oracle.clone(),
+            feed_id: String::from_str(&test.env, BTC_FEED),
+            threshold: 2_500_000_000_000,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &3600,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn setup() -> ScalarSetup {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_market(&test, &oracle);
+    client.set_market_type(&test.admin, &market_id, &range());
+
+    let long = test.create_funded_user();
+    let short = test.create_funded_user();
+    client.place_bet(
+        &long,
+        &market_id,
+        &String::from_str(&test.env, "long"),
+        &LONG_STAKE,
+        &None,
+    );
+    client.place_bet(
+        &short,
+        &market_id,
+        &String::from_str(&test.env, "short"),
+        &SHORT_STAKE,
+        &None,
+    );
+
+    ScalarSetup {
+        test,
+        oracle,
+        market_id,
+        long,
+        short,
+    }
+}
+
+/// Resolve at `price` and return the (long, short) payouts after fees.
+fn resolve_and_claim(s: &ScalarSetup, price: i64) -> (i128, i128) {
+    let client = PredictifyHybridClient::new(&s.test.env, &s.test.contract_id);
+    let end_time = client.get_market(&s.market_id).unwrap().end_time;
+    s.test
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time + 1);
+    MockPythContractClient::new(&s.test.env, &s.oracle).set_feed_price(
+        &String::from_str(&s.test.env, BTC_FEED),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: s.test.env.ledger().timestamp(),
+        },
+    );
+    client.resolve_market(&s.market_id);
+    s.test.advance_past_payout_unlock(&s.market_id);
+
+    (
+        client.claim_winnings(&s.long, &s.market_id),
+        client.claim_winnings(&s.short, &s.market_id),
+    )
+}
+
+/// Amount left of `amount` after the 2% platform fee.
+fn after_fee(amount: i128) -> i128 {
+    amount * 9_800 / 10_000
+}
+
+#[test]
+fn test_scalar_midpoint_splits_pool_evenly() {
+    let s = setup();
+    let (long, short) = resolve_and_claim(&s, 2_500_000_000_000);
+
+    assert_eq!(long, after_fee(POOL / 2));
+    assert_eq!(short, after_fee(POOL / 2));
+    let client = PredictifyHybridClient::new(&s.test.env, &s.test.contract_id);
+    assert_eq!(
+        client.get_market(&s.market_id).unwrap().scalar_value,
+        Some(2_500_000_000_000)
+    );
+}
+
+#[test]
+fn test_scalar_split_is_proportional() {
+    let s = setup();
+    // 3/4 of the way up the range
+    let (long, short) = resolve_and_claim(&s, 2_750_000_000_000);
+
+    assert_eq!(long, after_fee(POOL * 3 / 4));
+    assert_eq!(short, after_fee(POOL / 4));
+}
+
+#[test]
+fn test_scalar_at_bounds_pays_one_side() {
+    let s = setup();
+    let (long, short) = resolve_and_claim(&s, UPPER as i64);
+    assert_eq!(long, after_fee(POOL));
+    assert_eq!(short, 0);
+
+    let s = setup();
+    let (long, short) = resolve_and_claim(&s, LOWER as i64);
+    assert_eq!(long, 0);
+    assert_eq!(short, after_fee(POOL));
+}
+
+#[test]
+fn test_scalar_value_outside_range_is_clamped() {
+    let s = setup();
+    let (long, short) = resolve_and_claim(&s, 4_000_000_000_000);
+    assert_eq!(long, after_fee(POOL));
+    assert_eq!(short, 0);
+
+    let s = setup();
+    let (long, short) = resolve_and_claim(&s, 1_000_000_000_000);
+    assert_eq!(long, 0);
+    assert_eq!(short, after_fee(POOL));
+}
+
+#[test]
+fn test_scalar_market_accepts_only_long_and_short() {
+    let s = setup();
+    let client = PredictifyHybridClient::new(&s.test.env, &s.test.contract_id);
+    let user = s.test.create_funded_user();
+
+    assert!(client
+        .try_place_bet(
+            &user,
+            &s.market_id,
+            &String::from_str(&s.test.env, "yes"),
+            &LONG_STAKE,
+            &None
+        )
+        .is_err());
+    assert_eq!(
+        client.get_market(&s.market_id).unwrap().outcomes,
+        vec![
+            &s.test.env,
+            String::from_str(&s.test.env, "long"),
+            String::from_str(&s.test.env, "short"),
+        ]
+    );
+}
+
+#[test]
+fn test_set_market_type_validation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_market(&test, &oracle);
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_set_market_type(&outsider, &market_id, &range()),
+        Err(Ok(Error::Unauthorized))
+    );
+    let inverted = MarketType::Scalar(ScalarRange {
+        lower_bound: UPPER,
+        upper_bound: LOWER,
+    });
+    assert_eq!(
+        client.try_set_market_type(&test.admin, &market_id, &inverted),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.set_resolve_mode(&test.admin, &market_id, &ResolveMode::Hybrid);
+    assert_eq!(
+        client.try_set_market_type(&test.admin, &market_id, &range()),
+        Err(Ok(Error::InvalidState))
+    );
+    client.set_resolve_mode(&test.admin, &market_id, &ResolveMode::Standard);
+    client.set_market_type(&test.admin, &market_id, &range());
+    assert_eq!(
+        client.try_set_resolve_mode(&test.admin, &market_id, &ResolveMode::Hybrid),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let user = test.create_funded_user();
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "long"),
+        &LONG_STAKE,
+        &None,
+    );
+    assert_eq!(
+        client.try_set_market_type(&test.admin, &market_id, &MarketType::Categorical),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+}
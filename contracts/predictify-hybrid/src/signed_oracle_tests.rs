@@ -0,0 +1,251 @@
+#![cfg(test)]
+
+//! Signed off-chain oracle tests.
+//!
+//! An `OracleProvider::Signed` market is resolved by relaying a result its
+//! oracle's key signed off-chain. `submit_signed_result` verifies the ed25519
+//! signature over the XDR-encoded `(contract, market_id, outcome, timestamp)`
+//! payload and refuses replays, stale payloads and payloads signed for another
+//! market or another contract.
+
+use crate::errors::Error;
+use crate::reflector_oracle_tests::MockReflectorContract;
+use crate::test::PredictifyTest;
+use crate::types::{OracleConfig, OracleProvider, OracleSigner};
+use crate::{PredictifyHybrid, PredictifyHybridClient};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, BytesN, String, Symbol};
+
+const RESOLUTION_TIMEOUT: u64 = 3600;
+
+fn signing_key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+fn signed_config(test: &PredictifyTest, key: &SigningKey) -> OracleConfig {
+    OracleConfig {
+        provider: OracleProvider::Signed,
+        oracle_address: Address::generate(&test.env),
+        feed_id: String::from_str(&test.env, "election-2026"),
+        threshold: 1,
+        comparison: String::from_str(&test.env, "eq"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::Ed25519(BytesN::from_array(
+            &test.env,
+            &key.verifying_key().to_bytes(),
+        )),
+    }
+}
+
+fn create_market(test: &PredictifyTest, config: &OracleConfig) -> Result<Symbol, Error> {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client
+        .try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Who wins the election?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            config,
+            &None,
+            &RESOLUTION_TIMEOUT,
+            &None,
+            &None,
+            &None,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+fn advance_past_end_by(test: &PredictifyTest, market_id: &Symbol, seconds: u64) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let end_time = client.get_market(market_id).unwrap().end_time;
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = end_time + seconds);
+}
+
+/// Encode `(contract_id, market_id, outcome, timestamp)` and sign it with `key`
+fn sign_for(
+    test: &PredictifyTest,
+    contract_id: &Address,
+    key: &SigningKey,
+    market_id: &Symbol,
+    outcome: &str,
+    timestamp: u64,
+) -> (Bytes, BytesN<64>) {
+    let payload = (
+        contract_id.clone(),
+        market_id.clone(),
+        String::from_str(&test.env, outcome),
+        timestamp,
+    )
+        .to_xdr(&test.env);
+    let mut message = [0u8; 256];
+    let message = &mut message[..payload.len() as usize];
+    payload.copy_into_slice(message);
+    let signature = BytesN::from_array(&test.env, &key.sign(message).to_bytes());
+    (payload, signature)
+}
+
+/// A payload for the contract under test, signed with `key`
+fn sign(
+    test: &PredictifyTest,
+    key: &SigningKey,
+    market_id: &Symbol,
+    outcome: &str,
+    timestamp: u64,
+) -> (Bytes, BytesN<64>) {
+    sign_for(test, &test.contract_id, key, market_id, outcome, timestamp)
+}
+
+#[test]
+fn test_signed_result_resolves_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let key = signing_key(7);
+    let market_id = create_market(&test, &signed_config(&test, &key)).unwrap();
+
+    // Nothing can be pulled from a signed oracle
+    advance_past_end_by(&test, &market_id, 60);
+    assert_eq!(
+        client.try_fetch_oracle_result(&market_id),
+        Err(Ok(Error::OracleUnavailable))
+    );
+
+    let now = test.env.ledger().timestamp();
+    let (payload, signature) = sign(&test, &key, &market_id, "yes", now - 30);
+    let result = client.submit_signed_result(&market_id, &payload, &signature);
+    assert_eq!(result.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(result.provider, OracleProvider::Signed);
+
+    client.resolve_market(&market_id);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().winning_outcomes,
+        Some(vec![&test.env, String::from_str(&test.env, "yes")])
+    );
+}
+
+#[test]
+fn test_signed_result_replay_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let key = signing_key(7);
+    let config = signed_config(&test, &key);
+    let market_id = create_market(&test, &config).unwrap();
+    let other_market = create_market(&test, &config).unwrap();
+    advance_past_end_by(&test, &market_id, 60);
+    let now = test.env.ledger().timestamp();
+
+    // A payload signed before the market ended cannot be submitted
+    let (early, early_signature) = sign(&test, &key, &market_id, "no", now - 120);
+    assert_eq!(
+        client.try_submit_signed_result(&market_id, &early, &early_signature),
+        Err(Ok(Error::OracleStale))
+    );
+
+    let (payload, signature) = sign(&test, &key, &market_id, "yes", now);
+    client.submit_signed_result(&market_id, &payload, &signature);
+
+    // Neither the same market nor another market accepts it again
+    assert_eq!(
+        client.try_submit_signed_result(&market_id, &payload, &signature),
+        Err(Ok(Error::MarketResolved))
+    );
+    assert_eq!(
+        client.try_submit_signed_result(&other_market, &payload, &signature),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(client.get_oracle_result(&other_market), None);
+}
+
+#[test]
+fn test_forged_signature_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let key = signing_key(7);
+    let market_id = create_market(&test, &signed_config(&test, &key)).unwrap();
+    advance_past_end_by(&test, &market_id, 60);
+    let now = test.env.ledger().timestamp();
+
+    // Signed by another key
+    let (payload, forged) = sign(&test, &signing_key(8), &market_id, "yes", now);
+    assert!(client
+        .try_submit_signed_result(&market_id, &payload, &forged)
+        .is_err());
+
+    // A genuine signature over a different outcome
+    let (_, signature) = sign(&test, &key, &market_id, "no", now);
+    assert!(client
+        .try_submit_signed_result(&market_id, &payload, &signature)
+        .is_err());
+    assert_eq!(client.get_oracle_result(&market_id), None);
+}
+
+#[test]
+fn test_payload_signed_for_another_contract_is_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let key = signing_key(7);
+    let market_id = create_market(&test, &signed_config(&test, &key)).unwrap();
+    advance_past_end_by(&test, &market_id, 60);
+    let now = test.env.ledger().timestamp();
+
+    // The same key, market id and outcome, but addressed to another deployment
+    let other_deployment = test.env.register(PredictifyHybrid, ());
+    let (payload, signature) = sign_for(&test, &other_deployment, &key, &market_id, "yes", now);
+    assert_eq!(
+        client.try_submit_signed_result(&market_id, &payload, &signature),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(client.get_oracle_result(&market_id), None);
+}
+
+#[test]
+fn test_signed_oracle_config_is_validated() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let key = signing_key(7);
+
+    // A signed oracle needs a key to check results against
+    let mut unsigned = signed_config(&test, &key);
+    unsigned.signer = OracleSigner::None;
+    assert_eq!(
+        create_market(&test, &unsigned),
+        Err(Error::InvalidOracleConfig)
+    );
+
+    // Other providers do not take a signer key
+    let mut config = signed_config(&test, &key);
+    config.provider = OracleProvider::Reflector;
+    config.oracle_address = test.env.register(MockReflectorContract, ());
+    config.feed_id = String::from_str(&test.env, "BTC/USD");
+    config.comparison = String::from_str(&test.env, "gt");
+    assert_eq!(
+        create_market(&test, &config),
+        Err(Error::InvalidOracleConfig)
+    );
+
+    // Only signed markets accept signed results
+    config.signer = OracleSigner::None;
+    let market_id = create_market(&test, &config).unwrap();
+    advance_past_end_by(&test, &market_id, 60);
+    let (payload, signature) = sign(
+        &test,
+        &key,
+        &market_id,
+        "yes",
+        test.env.ledger().timestamp(),
+    );
+    assert_eq!(
+        client.try_submit_signed_result(&market_id, &payload, &signature),
+        Err(Ok(Error::InvalidOracleConfig))
+    );
+}
@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+//! Resolution and distribution dry-run tests.
+//!
+//! `simulate_resolution` and `simulate_distribution` must report exactly what
+//! `resolve_market` and `distribute_payouts` then do, without changing state.
+
+use crate::errors::Error;
+use crate::pyth_oracle_tests::{MockPythContract, MockPythContractClient};
+use crate::test::PredictifyTest;
+use crate::types::{
+    MarketState, OracleConfig, OracleProvider, OracleSigner, PythPriceData, ReflectorAsset,
+};
+use crate::{PredictifyHybridClient, MAX_PAYOUT_PAGE_SIZE};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, Address, String, Symbol, Vec};
+
+const BTC_FEED: &str = "0xe62df6c8b4a85fe1a67db44dc12de5db330f7ac66b72dc658afedf0f4a415b43";
+const ABOVE_THRESHOLD: i64 = 2_600_000_000_000;
+
+fn create_pyth_market(test: &PredictifyTest, oracle: &Address) -> Symbol {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will BTC be above $25,000?"),
+        &vec![
+            &test.env,
+            String::from_str(&test.env, "yes"),
+            String::from_str(&test.env, "no"),
+        ],
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Pyth,
+            oracle_address: oracle.clone(),
+            feed_id: String::from_str(&test.env, BTC_FEED),
+            threshold: 2_500_000_000_000, // $25,000 in 8-decimal units
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &3600,
+        &None,
+        &None,
+        &None,
+    )
+}
+
+fn publish_price(test: &PredictifyTest, oracle: &Address, price: i64) {
+    MockPythContractClient::new(&test.env, oracle).set_feed_price(
+        &String::from_str(&test.env, BTC_FEED),
+        &PythPriceData {
+            price,
+            conf: 0,
+            expo: -8,
+            publish_time: test.env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Payout distribution scenario: three `yes` and one `no` bettor, ended and
+/// priced above the threshold.
+fn setup_ended_market(test: &PredictifyTest) -> (Symbol, Vec<Address>) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(test, &oracle);
+
+    let stakes: [(i128, &str); 4] = [
+        (10_000_000, "yes"),
+        (33_000_000, "yes"),
+        (17_000_000, "no"),
+        (25_000_000, "yes"),
+    ];
+    let mut users = Vec::new(&test.env);
+    test.env.mock_all_auths();
+    for (amount, outcome) in stakes.iter() {
+        let user = test.create_funded_user();
+        client.place_bet(
+            &user,
+            &market_id,
+            &String::from_str(&test.env, outcome),
+            amount,
+            &None,
+        );
+        users.push_back(user);
+    }
+
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    publish_price(test, &oracle, ABOVE_THRESHOLD);
+    (market_id, users)
+}
+
+fn balance(client: &PredictifyHybridClient, user: &Address) -> i128 {
+    client.get_balance(user, &ReflectorAsset::Stellar).amount
+}
+
+fn balances(client: &PredictifyHybridClient, users: &Vec<Address>) -> Vec<i128> {
+    let mut amounts = Vec::new(&client.env);
+    for user in users.iter() {
+        amounts.push_back(balance(client, &user));
+    }
+    amounts
+}
+
+#[test]
+fn test_simulated_resolution_matches_real_resolution_and_payouts() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, users) = setup_ended_market(&test);
+
+    let preview = client.simulate_resolution(&market_id);
+
+    // Dry run leaves the market untouched
+    let market = client.get_market(&market_id).unwrap();
+    assert!(market.oracle_result.is_none());
+    assert!(market.winning_outcomes.is_none());
+    assert_ne!(market.state, MarketState::Resolved);
+
+    assert_eq!(preview.oracle_value, Some(ABOVE_THRESHOLD as i128));
+    assert_eq!(
+        preview.winning_outcomes,
+        vec![&test.env, String::from_str(&test.env, "yes")]
+    );
+    assert_eq!(preview.winner_count, 3);
+
+    client.resolve_market(&market_id);
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(
+        market.winning_outcomes,
+        Some(preview.winning_outcomes.clone())
+    );
+    assert_eq!(client.get_market_fees(&market_id).0, preview.fee_amount);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.payout_unlock_at.unwrap() + 1);
+    let before = balances(&client, &users);
+    let (distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert!(distributed > 0);
+    assert_eq!(distributed, preview.total_payout);
+
+    let credited: i128 = users
+        .iter()
+        .zip(before.iter())
+        .map(|(user, before)| balance(&client, &user) - before)
+        .sum();
+    assert_eq!(credited, preview.total_payout);
+}
+
+#[test]
+fn test_simulated_distribution_matches_each_credit() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, users) = setup_ended_market(&test);
+
+    assert_eq!(
+        client.try_simulate_distribution(&market_id),
+        Err(Ok(Error::MarketNotResolved))
+    );
+
+    client.resolve_market(&market_id);
+    let (payouts, total) = client.simulate_distribution(&market_id);
+    assert_eq!(payouts.len(), 3);
+    assert_eq!(total, client.simulate_resolution(&market_id).total_payout);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = market.payout_unlock_at.unwrap() + 1);
+    let before = balances(&client, &users);
+    let (distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(distributed, total);
+
+    for (user, payout) in payouts.iter() {
+        let index = users.first_index_of(&user).unwrap();
+        assert_eq!(balance(&client, &user) - before.get(index).unwrap(), payout);
+    }
+
+    // Everything settled: nothing left to simulate
+    let (payouts, total) = client.simulate_distribution(&market_id);
+    assert!(payouts.is_empty());
+    assert_eq!(total, 0);
+}
+
+#[test]
+fn test_simulate_resolution_before_end_fails() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let oracle = test.env.register(MockPythContract, ());
+    let market_id = create_pyth_market(&test, &oracle);
+
+    assert!(client.try_simulate_resolution(&market_id).is_err());
+}
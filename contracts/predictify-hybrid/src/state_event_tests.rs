@@ -0,0 +1,182 @@
+#![cfg(test)]
+
+//! Published state transition event tests.
+//!
+//! Indexers follow bets, resolutions, payouts, cancellations and fee movements
+//! through `env.events()`; these tests pin the topic and data shapes.
+
+use crate::events::{
+    BetPlacedEvent, CancelEvent, FeeCollectedEvent, FeeWithdrawnEvent, PayoutEvent,
+    ResolutionSource, ResolvedEvent, EVENT_SCHEMA_VERSION,
+};
+use crate::test::PredictifyTest;
+use crate::types::CancelReason;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{symbol_short, vec, Address, IntoVal, String, Symbol, TryIntoVal, Val, Vec};
+
+/// Events published by the contract (not the token) whose first topic is `name`.
+fn published(test: &PredictifyTest, name: Symbol) -> Vec<(Vec<Val>, Val)> {
+    let mut found = Vec::new(&test.env);
+    for (contract, topics, data) in test.env.events().all().iter() {
+        if contract != test.contract_id {
+            continue;
+        }
+        let first: Result<Symbol, _> = topics.get(0).unwrap().try_into_val(&test.env);
+        if first.is_ok_and(|s| s == name) {
+            found.push_back((topics, data));
+        }
+    }
+    found
+}
+
+/// Asserts exactly one `name` event was published and returns its (topics, data).
+fn single(test: &PredictifyTest, name: Symbol) -> (Vec<Val>, Val) {
+    let events = published(test, name);
+    assert_eq!(events.len(), 1);
+    events.get(0).unwrap()
+}
+
+/// Market with a 100 XLM "yes" bet and a 100 XLM "no" bet. Returns (market_id, winner, loser).
+fn market_with_bets(test: &PredictifyTest) -> (Symbol, Address, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.set_time(1_700_000_000);
+    let market_id = test.create_test_market();
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &winner,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+        &None,
+    );
+    client.place_bet(
+        &loser,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &100_0000000,
+        &None,
+    );
+    (market_id, winner, loser)
+}
+
+#[test]
+fn test_place_bet_publishes_bet_event() {
+    let test = PredictifyTest::setup();
+    let (market_id, _, loser) = market_with_bets(&test);
+
+    let (topics, data) = single(&test, symbol_short!("bet"));
+    assert_eq!(
+        topics,
+        (symbol_short!("bet"), EVENT_SCHEMA_VERSION, market_id, loser).into_val(&test.env)
+    );
+    let event: BetPlacedEvent = data.try_into_val(&test.env).unwrap();
+    assert_eq!(event.outcome, String::from_str(&test.env, "no"));
+    assert_eq!(event.amount, 100_0000000);
+    assert_eq!(event.pool_total, 200_0000000);
+}
+
+#[test]
+fn test_resolution_and_payout_publish_events() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, winner, _) = market_with_bets(&test);
+    let yes = String::from_str(&test.env, "yes");
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    let (topics, data) = single(&test, symbol_short!("resolved"));
+    assert_eq!(
+        topics,
+        (symbol_short!("resolved"), EVENT_SCHEMA_VERSION, market_id.clone()).into_val(&test.env)
+    );
+    let event: ResolvedEvent = data.try_into_val(&test.env).unwrap();
+    assert_eq!(event.winning_outcomes, vec![&test.env, yes]);
+    assert_eq!(event.source, ResolutionSource::Manual);
+
+    // Pool 200 XLM, winning stake 100 XLM, 2% fee
+    test.set_time(market.end_time + market.dispute_window_seconds + 1);
+    let paid = client.claim_winnings(&winner, &market_id);
+    assert_eq!(paid, 196_0000000);
+
+    let (topics, data) = single(&test, symbol_short!("payout"));
+    assert_eq!(
+        topics,
+        (symbol_short!("payout"), EVENT_SCHEMA_VERSION, market_id, winner).into_val(&test.env)
+    );
+    let event: PayoutEvent = data.try_into_val(&test.env).unwrap();
+    assert_eq!(event.amount, paid);
+}
+
+#[test]
+fn test_cancel_event_publishes_cancel_event() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _, _) = market_with_bets(&test);
+    let reason = String::from_str(&test.env, "Feed discontinued");
+
+    client.cancel_event(
+        &test.admin,
+        &market_id,
+        &CancelReason::Administrative,
+        &Some(reason.clone()),
+    );
+
+    let (topics, data) = single(&test, symbol_short!("cancel"));
+    assert_eq!(
+        topics,
+        (symbol_short!("cancel"), EVENT_SCHEMA_VERSION, market_id).into_val(&test.env)
+    );
+    let event: CancelEvent = data.try_into_val(&test.env).unwrap();
+    assert_eq!(event.reason, reason);
+}
+
+#[test]
+fn test_fee_collection_and_withdrawal_publish_fees_events() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, _, _) = market_with_bets(&test);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.set_time(market.end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    let collected = client.collect_fees(&test.admin, &market_id);
+
+    let (topics, data) = single(&test, symbol_short!("fees"));
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("fees"),
+            EVENT_SCHEMA_VERSION,
+            symbol_short!("collect"),
+            market_id
+        )
+            .into_val(&test.env)
+    );
+    let event: FeeCollectedEvent = data.try_into_val(&test.env).unwrap();
+    assert_eq!(event.collector, test.admin);
+    assert_eq!(event.amount, collected);
+    assert_eq!(event.fee_type, String::from_str(&test.env, "platform_fee"));
+
+    let withdrawn = client.withdraw_fees(&test.admin, &0);
+
+    let (topics, data) = single(&test, symbol_short!("fees"));
+    assert_eq!(
+        topics,
+        (
+            symbol_short!("fees"),
+            EVENT_SCHEMA_VERSION,
+            symbol_short!("withdraw"),
+            test.admin.clone()
+        )
+            .into_val(&test.env)
+    );
+    let event: FeeWithdrawnEvent = data.try_into_val(&test.env).unwrap();
+    assert_eq!(event.amount, withdrawn);
+    assert_eq!(event.remaining_fees, collected - withdrawn);
+}
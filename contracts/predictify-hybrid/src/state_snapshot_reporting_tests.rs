@@ -10,6 +10,7 @@
 //! - No state mutation during queries
 
 use crate::queries::QueryManager;
+use crate::storage::DataKey;
 use crate::types::*;
 use crate::PredictifyHybrid;
 use soroban_sdk::testutils::Address as _;
@@ -58,7 +59,7 @@ fn create_and_store_test_market(env: &Env, market_id: &str, state: MarketState)
     market.state = state;
     
     let market_key = Symbol::new(env, market_id);
-    env.storage().persistent().set(&market_key, &market);
+    env.storage().persistent().set(&DataKey::Market(market_key.clone()), &market);
     
     // Add to market index - append to existing index
     let market_index_key = Symbol::new(env, "market_index");
@@ -87,7 +88,7 @@ mod state_snapshot_tests {
             let market_key = Symbol::new(&env, market_id);
             
             // Store market
-            env.storage().persistent().set(&market_key, &market);
+            env.storage().persistent().set(&DataKey::Market(market_key.clone()), &market);
             
             // Get snapshot - should not mutate
             let snapshot = PredictifyHybrid::get_market(env.clone(), market_key.clone());
@@ -101,7 +102,11 @@ mod state_snapshot_tests {
             assert_eq!(retrieved.admin, admin);
             
             // Verify original market is unchanged
-            let original = env.storage().persistent().get::<_, Market>(&market_key).unwrap();
+            let original = env
+                .storage()
+                .persistent()
+                .get::<_, Market>(&DataKey::Market(market_key.clone()))
+                .unwrap();
             assert_eq!(original.state, MarketState::Active);
         });
     }
@@ -173,7 +178,7 @@ mod state_snapshot_tests {
             market.total_staked = 1000;
             
             let market_key = Symbol::new(&env, "TVL_TEST");
-            env.storage().persistent().set(&market_key, &market);
+            env.storage().persistent().set(&DataKey::Market(market_key.clone()), &market);
             
             // Add to index
             let market_index_key = Symbol::new(&env, "market_index");
@@ -473,7 +478,7 @@ mod edge_case_tests {
             market.total_staked = 0;
             
             let market_key = Symbol::new(&env, "ZERO_STAKE");
-            env.storage().persistent().set(&market_key, &market);
+            env.storage().persistent().set(&DataKey::Market(market_key.clone()), &market);
             
             let result = PredictifyHybrid::get_market(env.clone(), market_key);
             
@@ -500,7 +505,7 @@ mod edge_case_tests {
             // Update market state (simulating transition)
             let mut updated_market = snapshot1;
             updated_market.state = MarketState::Resolved;
-            env.storage().persistent().set(&market_key, &updated_market);
+            env.storage().persistent().set(&DataKey::Market(market_key.clone()), &updated_market);
             
             // Query again - should reflect new state
             let snapshot2 = PredictifyHybrid::get_market(env.clone(), market_key).unwrap();
@@ -1,11 +1,17 @@
 #![allow(dead_code)]
 
 use crate::events::EventEmitter;
-use crate::types::{PlatformStatistics, UserStatistics};
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use crate::types::{PlatformStatistics, ProtocolStats, UserStatistics};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 const PLATFORM_STATS_KEY: Symbol = symbol_short!("p_stats");
 const USER_STATS_PREFIX: Symbol = symbol_short!("u_stats");
+const PROTOCOL_STATS_KEY: Symbol = symbol_short!("proto_st");
+const USER_MARKETS_PREFIX: Symbol = symbol_short!("u_mkts");
+const USER_MARKET_COUNT_PREFIX: Symbol = symbol_short!("u_mkt_cnt");
+
+/// Number of most recent markets kept in a user's market index
+pub const USER_MARKET_INDEX_LIMIT: u32 = 100;
 
 pub struct StatisticsManager;
 
@@ -69,6 +75,13 @@ impl StatisticsManager {
         Self::set_platform_stats(env, &stats);
 
         Self::emit_update(env, &stats);
+
+        let mut protocol = Self::get_protocol_stats(env);
+        protocol.markets_created = protocol
+            .markets_created
+            .checked_add(1)
+            .unwrap_or(protocol.markets_created);
+        Self::set_protocol_stats(env, &protocol);
     }
 
     /// Record a market resolution (decrements active count)
@@ -113,6 +126,46 @@ impl StatisticsManager {
         Self::set_user_stats(env, user, &u_stats);
     }
 
+    /// Get the user's market index, oldest first
+    pub fn get_user_markets(env: &Env, user: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&(USER_MARKETS_PREFIX, user.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Get the number of markets the user has ever taken a position in
+    pub fn get_markets_participated(env: &Env, user: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&(USER_MARKET_COUNT_PREFIX, user.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Record the user opening a position in a market.
+    ///
+    /// The index keeps the `USER_MARKET_INDEX_LIMIT` most recent markets,
+    /// dropping the oldest; the participation count is not bounded.
+    pub fn record_market_joined(env: &Env, user: &Address, market_id: &Symbol) {
+        let mut markets = Self::get_user_markets(env, user);
+        if markets.contains(market_id) {
+            return;
+        }
+        if markets.len() >= USER_MARKET_INDEX_LIMIT {
+            markets.pop_front();
+        }
+        markets.push_back(market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&(USER_MARKETS_PREFIX, user.clone()), &markets);
+
+        let count = Self::get_markets_participated(env, user);
+        env.storage().persistent().set(
+            &(USER_MARKET_COUNT_PREFIX, user.clone()),
+            &count.checked_add(1).unwrap_or(count),
+        );
+    }
+
     /// Record winnings claimed
     pub fn record_winnings_claimed(env: &Env, user: &Address, amount: i128) {
         // Note: fees are already deducted from 'amount' usually?
@@ -160,6 +213,75 @@ impl StatisticsManager {
         Self::emit_update(env, &p_stats);
     }
 
+    /// Get protocol-wide counters, initializing if not present
+    pub fn get_protocol_stats(env: &Env) -> ProtocolStats {
+        env.storage()
+            .persistent()
+            .get(&PROTOCOL_STATS_KEY)
+            .unwrap_or(ProtocolStats {
+                total_volume: 0,
+                open_interest: 0,
+                markets_created: 0,
+                markets_resolved: 0,
+                markets_cancelled: 0,
+                total_fees_collected: 0,
+            })
+    }
+
+    /// Set protocol-wide counters
+    fn set_protocol_stats(env: &Env, stats: &ProtocolStats) {
+        env.storage().persistent().set(&PROTOCOL_STATS_KEY, stats);
+    }
+
+    /// Record stake entering escrow (bets and staked votes)
+    pub fn record_stake_escrowed(env: &Env, amount: i128) {
+        let mut stats = Self::get_protocol_stats(env);
+        stats.total_volume = stats
+            .total_volume
+            .checked_add(amount)
+            .unwrap_or(stats.total_volume);
+        stats.open_interest = stats
+            .open_interest
+            .checked_add(amount)
+            .unwrap_or(stats.open_interest);
+        Self::set_protocol_stats(env, &stats);
+    }
+
+    /// Record stake leaving escrow before its market settles (withdrawn or cancelled bets)
+    pub fn record_stake_released(env: &Env, amount: i128) {
+        let mut stats = Self::get_protocol_stats(env);
+        stats.open_interest = stats.open_interest.checked_sub(amount).unwrap_or(0).max(0);
+        Self::set_protocol_stats(env, &stats);
+    }
+
+    /// Record a market settling, releasing its pool from open interest
+    pub fn record_market_settled(env: &Env, total_staked: i128, cancelled: bool) {
+        let mut stats = Self::get_protocol_stats(env);
+        if cancelled {
+            stats.markets_cancelled = stats
+                .markets_cancelled
+                .checked_add(1)
+                .unwrap_or(stats.markets_cancelled);
+        } else {
+            stats.markets_resolved = stats
+                .markets_resolved
+                .checked_add(1)
+                .unwrap_or(stats.markets_resolved);
+        }
+        stats.open_interest = stats.open_interest.checked_sub(total_staked).unwrap_or(0).max(0);
+        Self::set_protocol_stats(env, &stats);
+    }
+
+    /// Record platform fees collected from a market
+    pub fn record_platform_fees_collected(env: &Env, amount: i128) {
+        let mut stats = Self::get_protocol_stats(env);
+        stats.total_fees_collected = stats
+            .total_fees_collected
+            .checked_add(amount)
+            .unwrap_or(stats.total_fees_collected);
+        Self::set_protocol_stats(env, &stats);
+    }
+
     fn emit_update(env: &Env, stats: &PlatformStatistics) {
         EventEmitter::emit_statistics_updated(
             env,
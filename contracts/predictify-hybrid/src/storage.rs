@@ -1,9 +1,78 @@
 #![cfg_attr(test, allow(dead_code))]
 
 use super::*;
+use crate::bets::BetStorage;
 use crate::markets::{MarketStateLogic, MarketStateManager};
 use crate::types::{Balance, ReflectorAsset};
-use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+// ===== STORAGE KEYS =====
+
+/// Typed keys for the contract's core persistent entries.
+///
+/// Replaces the ad-hoc `Symbol` keys (`"Admin"`, `"TokenID"`, a bare market id)
+/// used by earlier versions, which could collide with each other and with event
+/// ids. Instances deployed before this change move their entries over with
+/// `migrate_storage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Contract admin address (legacy key `"Admin"`)
+    Admin,
+    /// Global staking token (legacy key `"TokenID"`)
+    Token,
+    /// Decimals of the global staking token (legacy key `"TokenDecimals"`)
+    TokenDecimals,
+    /// Symbol of the global staking token (legacy key `"TokenSymbol"`)
+    TokenSymbol,
+    /// A market by id (legacy key: the bare market id)
+    Market(Symbol),
+    /// A user's bet on a market (legacy key: `BetKey`)
+    Bet(Symbol, Address),
+    /// Platform fee vault for the global token (legacy key `"tot_fees"`)
+    FeeVault,
+    /// Contract configuration (legacy key `"ContractConfig"`). Not named
+    /// `Config`: that would encode the same as `CommunityVoteKey::Config`.
+    ContractConfig,
+    /// Set once `migrate_storage` has run
+    StorageMigrated,
+    /// TTL extension settings for market and bet entries
+    TtlConfig,
+    /// A market template by id
+    MarketTemplate(u32),
+    /// Number of market templates created; ids run from 1 to this value
+    TemplateCount,
+    /// A market series by id
+    Series(u32),
+    /// Number of market series created; ids run from 1 to this value
+    SeriesCount,
+    /// Series id and index of a market created by a series
+    SeriesPosition(Symbol),
+    /// Bettor allowlist of a private market; absent for public markets
+    MarketAllowlist(Symbol),
+    /// Anti-sniping settings of a market
+    AntiSnipe(Symbol),
+    /// Seconds anti-sniping has added to a market's betting deadline so far
+    AntiSnipeExtended(Symbol),
+    /// Set while an address is blocked by `set_blacklist`
+    Blacklisted(Address),
+    /// Funds escrowed by a market (see `EscrowManager`)
+    MarketEscrow(Symbol),
+    /// Sum of the escrows of every global-token market
+    GlobalEscrowTotal,
+    /// Number of global-token markets in an open state
+    OpenMarketCount,
+    /// Bettor of a market by position in its bettor list, in order of first bet
+    Bettor(Symbol, u32),
+    /// Number of bettors listed for a market
+    BettorCount(Symbol),
+    /// Set once a user is in a market's bettor list, so a repeat bet does not list them twice
+    Listed(Symbol, Address),
+    /// Set once a user's position on a market has been paid out or refunded
+    Claimed(Symbol, Address),
+}
 
 // ===== STORAGE OPTIMIZATION TYPES =====
 
@@ -168,8 +237,8 @@ impl StorageOptimizer {
         let config = Self::get_storage_config(env);
 
         if market_age_days > config.cleanup_threshold_days.into() {
-            // Only cleanup closed or cancelled markets
-            if market.state == MarketState::Closed || market.state == MarketState::Cancelled {
+            // Only cleanup closed, cancelled or voided markets
+            if market.state.is_terminal() {
                 // Archive market data before deletion
                 Self::archive_market_data(env, market_id, &market)?;
 
@@ -531,10 +600,9 @@ impl StorageOptimizer {
         let base_size = 100; // Base overhead
         let question_size = market.question.len() as u32;
         let outcomes_size = market.outcomes.len() as u32 * 50; // Average outcome size
-        let votes_size = market.votes.len() as u32 * 100; // Average vote entry size
-        let stakes_size = market.stakes.len() as u32 * 50; // Average stake entry size
+        let pools_size = market.outcome_pools.len() as u32 * 50; // Average pool entry size
 
-        base_size + question_size + outcomes_size + votes_size + stakes_size
+        base_size + question_size + outcomes_size + pools_size
     }
 
     /// Generate checksum for data integrity
@@ -718,6 +786,460 @@ impl CreatorLimitsManager {
     }
 }
 
+// ===== TTL MANAGEMENT =====
+
+/// When and how far persistent market and bet entries are extended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    /// Extend an entry once its remaining TTL is at or below this many ledgers
+    pub threshold: u32,
+    /// Number of ledgers the TTL is extended to
+    pub extend_to: u32,
+}
+
+/// Keeps market and bet entries alive while they are in use.
+///
+/// Market and bet reads and writes go through `MarketStateManager` and
+/// `BetStorage`, which extend the entry each time. The contract instance,
+/// which holds admin, token and fee settings, is extended with every market
+/// write.
+pub struct TtlManager;
+
+impl TtlManager {
+    /// Current TTL settings, or the defaults from `config`.
+    pub fn get_config(env: &Env) -> TtlConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::TtlConfig)
+            .unwrap_or(TtlConfig {
+                threshold: crate::config::DEFAULT_TTL_THRESHOLD_LEDGERS,
+                extend_to: crate::config::DEFAULT_TTL_EXTEND_TO_LEDGERS,
+            })
+    }
+
+    /// Store new TTL settings. `threshold` may not exceed `extend_to`, and
+    /// `extend_to` must be non-zero.
+    pub fn set_config(env: &Env, config: &TtlConfig) -> Result<(), Error> {
+        if config.extend_to == 0 || config.threshold > config.extend_to {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().instance().set(&DataKey::TtlConfig, config);
+        Ok(())
+    }
+
+    /// Extend the contract instance and its config entries.
+    pub fn extend_instance(env: &Env) {
+        let config = Self::get_config(env);
+        env.storage()
+            .instance()
+            .extend_ttl(config.threshold, config.extend_to);
+    }
+
+    /// Extend a stored market entry.
+    pub fn extend_market(env: &Env, market_id: &Symbol) {
+        Self::extend_persistent(env, &DataKey::Market(market_id.clone()));
+    }
+
+    /// Extend a stored bet entry.
+    pub fn extend_bet(env: &Env, market_id: &Symbol, user: &Address) {
+        Self::extend_persistent(env, &DataKey::Bet(market_id.clone(), user.clone()));
+    }
+
+    /// Extend any stored persistent entry with the configured settings.
+    pub fn extend_persistent<K>(env: &Env, key: &K)
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let config = Self::get_config(env);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, config.threshold, config.extend_to);
+    }
+}
+
+// ===== LEGACY KEY MIGRATION =====
+
+/// Market lifecycle states as stored before the `DataKey` switch. Frozen:
+/// never change this type, it decodes existing entries.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegacyMarketStateV0 {
+    Active,
+    Ended,
+    Disputed,
+    Resolved,
+    Closed,
+    Cancelled,
+}
+
+impl LegacyMarketStateV0 {
+    fn into_state(self) -> MarketState {
+        match self {
+            Self::Active => MarketState::Active,
+            Self::Ended => MarketState::Ended,
+            Self::Disputed => MarketState::Disputed,
+            Self::Resolved => MarketState::Resolved,
+            Self::Closed => MarketState::Closed,
+            Self::Cancelled => MarketState::Cancelled,
+        }
+    }
+}
+
+/// Oracle configuration as stored before the `DataKey` switch. Frozen.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyOracleConfigV0 {
+    pub provider: OracleProvider,
+    pub oracle_address: Address,
+    pub feed_id: String,
+    pub threshold: i128,
+    pub comparison: String,
+}
+
+impl LegacyOracleConfigV0 {
+    fn into_config(self) -> OracleConfig {
+        OracleConfig {
+            provider: self.provider,
+            oracle_address: self.oracle_address,
+            feed_id: self.feed_id,
+            threshold: self.threshold,
+            comparison: self.comparison,
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        }
+    }
+}
+
+/// Market as stored under its bare id before the `DataKey` switch. Frozen:
+/// `Market` has gained fields since, so these entries no longer decode as one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyMarketV0 {
+    pub admin: Address,
+    pub question: String,
+    pub outcomes: Vec<String>,
+    pub end_time: u64,
+    pub oracle_config: LegacyOracleConfigV0,
+    pub has_fallback: bool,
+    pub fallback_oracle_config: LegacyOracleConfigV0,
+    pub resolution_timeout: u64,
+    pub oracle_result: Option<String>,
+    pub votes: Map<Address, String>,
+    pub stakes: Map<Address, i128>,
+    pub claimed: Map<Address, bool>,
+    pub total_staked: i128,
+    pub dispute_stakes: Map<Address, i128>,
+    pub winning_outcomes: Option<Vec<String>>,
+    pub fee_collected: bool,
+    pub state: LegacyMarketStateV0,
+    pub total_extension_days: u32,
+    pub max_extension_days: u32,
+    pub extension_history: Vec<MarketExtension>,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub min_pool_size: Option<i128>,
+    pub bet_deadline: u64,
+    pub dispute_window_seconds: u64,
+}
+
+impl LegacyMarketV0 {
+    /// Field names of the layout, which a stored map must match exactly to
+    /// decode: the host traps rather than failing on a mismatched struct.
+    const FIELDS: [&'static str; 25] = [
+        "admin",
+        "question",
+        "outcomes",
+        "end_time",
+        "oracle_config",
+        "has_fallback",
+        "fallback_oracle_config",
+        "resolution_timeout",
+        "oracle_result",
+        "votes",
+        "stakes",
+        "claimed",
+        "total_staked",
+        "dispute_stakes",
+        "winning_outcomes",
+        "fee_collected",
+        "state",
+        "total_extension_days",
+        "max_extension_days",
+        "extension_history",
+        "category",
+        "tags",
+        "min_pool_size",
+        "bet_deadline",
+        "dispute_window_seconds",
+    ];
+
+    /// Returns true if `fields` are exactly the field names of this layout.
+    fn matches(env: &Env, fields: &Map<Symbol, Val>) -> bool {
+        fields.len() == Self::FIELDS.len() as u32
+            && Self::FIELDS
+                .iter()
+                .all(|name| fields.contains_key(Symbol::new(env, name)))
+    }
+
+    /// Convert to the current layout. Fields the old layout lacked take the
+    /// values a new market gets, the admin is taken as the creator and the fee
+    /// is the global fee at migration time.
+    ///
+    /// The vote maps are written out as positions: a voter without a bet entry
+    /// gets one for their stake, claimed voters are marked claimed, and the
+    /// outcome totals are rebuilt from the stakes. Bets must already be under
+    /// their typed keys.
+    pub fn into_market(self, env: &Env, market_id: &Symbol) -> Result<Market, Error> {
+        let mut market = Market::new(
+            env,
+            self.admin,
+            self.question,
+            self.outcomes,
+            self.end_time,
+            self.oracle_config.into_config(),
+            None,
+            self.resolution_timeout,
+            self.state.into_state(),
+        );
+        market.has_fallback = self.has_fallback;
+        market.fallback_oracle_config = self.fallback_oracle_config.into_config();
+        market.oracle_result = self.oracle_result;
+        market.total_staked = self.total_staked;
+        market.dispute_stakes = self.dispute_stakes;
+        market.winning_outcomes = self.winning_outcomes;
+        market.fee_collected = self.fee_collected;
+        market.total_extension_days = self.total_extension_days;
+        market.max_extension_days = self.max_extension_days;
+        market.extension_history = self.extension_history;
+        market.category = self.category;
+        market.tags = self.tags;
+        market.min_pool_size = self.min_pool_size;
+        market.bet_deadline = self.bet_deadline;
+        market.dispute_window_seconds = self.dispute_window_seconds;
+        market.resolution_deadline = market.compute_resolution_deadline();
+        market.fee_bps = crate::fees::FeeManager::get_fee_bps(env);
+        for (user, outcome) in self.votes.iter() {
+            let stake = self.stakes.get(user.clone()).unwrap_or(0);
+            market.add_position(&outcome, stake);
+            if BetStorage::get_bet(env, market_id, &user).is_none() {
+                let bet = Bet::new(env, user.clone(), market_id.clone(), outcome, stake);
+                BetStorage::store_bet(env, &bet)?;
+            }
+            if self.claimed.get(user.clone()).unwrap_or(false) {
+                BetStorage::mark_claimed(env, market_id, &user);
+            }
+        }
+        Ok(market)
+    }
+}
+
+/// Outcome of `migrate_storage`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigrationReport {
+    /// Entries moved to their typed keys
+    pub moved: u32,
+    /// Registered markets left without a typed entry because the value under
+    /// their bare id decodes as neither the current nor the legacy layout
+    pub unmigrated_markets: Vec<Symbol>,
+}
+
+/// Moves entries written under the pre-`DataKey` `Symbol` keys to their
+/// typed keys, and config entries from persistent to instance storage, for
+/// instances deployed before the switch.
+pub struct LegacyKeyMigrator;
+
+impl LegacyKeyMigrator {
+    /// Legacy key of the global fee vault balance.
+    const LEGACY_FEE_VAULT_KEY: Symbol = symbol_short!("tot_fees");
+
+    /// Legacy key of the contract configuration.
+    const LEGACY_CONFIG_KEY: &'static str = "ContractConfig";
+
+    /// Returns true once `migrate` has completed.
+    pub fn is_migrated(env: &Env) -> bool {
+        env.storage().persistent().has(&DataKey::StorageMigrated)
+    }
+
+    /// Admin address under either the typed or the legacy key.
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .or_else(|| {
+                env.storage()
+                    .persistent()
+                    .get(&Symbol::new(env, crate::config::ADMIN_STORAGE_KEY))
+            })
+    }
+
+    /// Move every legacy entry to its `DataKey` and report how many moved.
+    ///
+    /// Markets are found through the market id registry and bets through each
+    /// market's bettor registry, which is replaced by the per-bettor list. An
+    /// entry whose typed key is already set is left in place rather than
+    /// overwritten. A registered market whose entry cannot be decoded is left
+    /// under its bare id and listed in the report. Fails with `InvalidState`
+    /// if the migration already ran.
+    pub fn migrate(env: &Env) -> Result<StorageMigrationReport, Error> {
+        if Self::is_migrated(env) {
+            return Err(Error::InvalidState);
+        }
+
+        let mut moved = 0u32;
+        let settings = [
+            (Symbol::new(env, crate::config::ADMIN_STORAGE_KEY), DataKey::Admin),
+            (Symbol::new(env, crate::config::TOKEN_ID_STORAGE_KEY), DataKey::Token),
+            (
+                Symbol::new(env, crate::config::TOKEN_DECIMALS_STORAGE_KEY),
+                DataKey::TokenDecimals,
+            ),
+            (
+                Symbol::new(env, crate::config::TOKEN_SYMBOL_STORAGE_KEY),
+                DataKey::TokenSymbol,
+            ),
+            (Symbol::new(env, Self::LEGACY_CONFIG_KEY), DataKey::ContractConfig),
+        ];
+        for (legacy, key) in settings.iter() {
+            if Self::move_to_instance(env, legacy, key) {
+                moved += 1;
+            }
+        }
+        // Fee settings keep their keys but move to instance storage.
+        let fee_settings = [
+            Symbol::new(env, "platform_fee"),
+            Symbol::new(env, crate::config::FEE_BPS_STORAGE_KEY),
+            Symbol::new(env, crate::config::CREATION_FEE_STORAGE_KEY),
+            symbol_short!("fee_cfg"),
+            crate::fees::WITHDRAWAL_SCHEDULE_KEY,
+        ];
+        for key in fee_settings.iter() {
+            if Self::move_to_instance(env, key, key) {
+                moved += 1;
+            }
+        }
+        if Self::move_entry(env, &Self::LEGACY_FEE_VAULT_KEY, &DataKey::FeeVault) {
+            moved += 1;
+        }
+
+        let registry = crate::market_id_generator::MarketIdGenerator::get_market_id_registry(
+            env,
+            0,
+            u32::MAX,
+        );
+        let mut unmigrated_markets = Vec::new(env);
+        for entry in registry.iter() {
+            // Bets first: a legacy market adds bets only for voters without one
+            for user in BetStorage::take_legacy_bet_registry(env, &entry.market_id).iter() {
+                let legacy = crate::bets::BetKey {
+                    market_id: entry.market_id.clone(),
+                    user: user.clone(),
+                };
+                let key = DataKey::Bet(entry.market_id.clone(), user.clone());
+                if Self::move_entry(env, &legacy, &key) {
+                    BetStorage::add_to_bet_registry(env, &entry.market_id, &user)?;
+                    moved += 1;
+                }
+            }
+            match Self::move_market(env, &entry.market_id) {
+                Ok(true) => moved += 1,
+                Ok(false) => {}
+                Err(_) => unmigrated_markets.push_back(entry.market_id.clone()),
+            }
+        }
+
+        env.storage().persistent().set(&DataKey::StorageMigrated, &true);
+        Ok(StorageMigrationReport {
+            moved,
+            unmigrated_markets,
+        })
+    }
+
+    /// Decode a stored market in the current layout or in the layout used
+    /// before the `DataKey` switch; `None` for any other value. A legacy
+    /// market's positions are written out by `LegacyMarketV0::into_market`.
+    ///
+    /// The layout is told apart by field names before decoding, since decoding
+    /// a struct from a map with other fields traps instead of returning an error.
+    pub fn decode_market(env: &Env, market_id: &Symbol, value: &Val) -> Option<Market> {
+        let fields = Map::<Symbol, Val>::try_from_val(env, value).ok()?;
+        if fields.contains_key(symbol_short!("creator")) {
+            Market::try_from_val(env, value).ok()
+        } else if LegacyMarketV0::matches(env, &fields) {
+            LegacyMarketV0::try_from_val(env, value)
+                .ok()
+                .and_then(|legacy| legacy.into_market(env, market_id).ok())
+        } else {
+            None
+        }
+    }
+
+    /// Move a market stored under its bare id, converting a legacy layout.
+    ///
+    /// Returns `Ok(false)` when there is nothing to move and
+    /// `Err(Error::InvalidState)` when a registered market has no typed entry
+    /// and the value under its bare id cannot be decoded as a market; the bare
+    /// id is shared with other records (pause info, events), so such a value
+    /// is left untouched.
+    fn move_market(env: &Env, market_id: &Symbol) -> Result<bool, Error> {
+        let key = DataKey::Market(market_id.clone());
+        if env.storage().persistent().has(&key) {
+            return Ok(false);
+        }
+        let value: Option<Val> = env.storage().persistent().get(market_id);
+        let Some(value) = value else {
+            return Ok(false);
+        };
+        let market = Self::decode_market(env, market_id, &value).ok_or(Error::InvalidState)?;
+        crate::markets::MarketUtils::track_open_market(env, None, &market);
+        env.storage().persistent().set(&key, &market);
+        env.storage().persistent().remove(market_id);
+        Ok(true)
+    }
+
+    /// Copy the raw persistent value under `legacy` to `key` in instance
+    /// storage and drop `legacy`.
+    fn move_to_instance<K, L>(env: &Env, legacy: &L, key: &K) -> bool
+    where
+        K: IntoVal<Env, Val>,
+        L: IntoVal<Env, Val>,
+    {
+        if env.storage().instance().has(key) {
+            return false;
+        }
+        let value: Option<Val> = env.storage().persistent().get(legacy);
+        match value {
+            Some(value) => {
+                env.storage().instance().set(key, &value);
+                env.storage().persistent().remove(legacy);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Copy the raw value under `legacy` to `key` and drop `legacy`.
+    fn move_entry<K>(env: &Env, legacy: &K, key: &DataKey) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        if env.storage().persistent().has(key) {
+            return false;
+        }
+        let value: Option<Val> = env.storage().persistent().get(legacy);
+        match value {
+            Some(value) => {
+                env.storage().persistent().set(key, &value);
+                env.storage().persistent().remove(legacy);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 // ===== STORAGE UTILITIES =====
 
 /// Storage utility functions
@@ -762,7 +1284,7 @@ impl StorageUtils {
             ));
         }
 
-        if market.votes.len() > 1000 {
+        if market.position_count() > 1000 {
             recommendations.push_back(String::from_str(
                 &market.question.env(),
                 "High vote count - consider vote aggregation",
@@ -0,0 +1,249 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::types::{
+    MarketSeries, MarketTemplate, OracleComparison, SeriesPosition, TemplateOverrides,
+};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Market templates for questions that repeat.
+///
+/// The admin stores the outcomes, oracle configs, fees and durations of a
+/// recurring market once; creators then stamp out markets from it, supplying
+/// only what changes per market (threshold, duration, question).
+pub struct MarketTemplateManager;
+
+impl MarketTemplateManager {
+    /// Store a new template and return its id (admin only).
+    pub fn create_template(
+        env: &Env,
+        admin: &Address,
+        template: &MarketTemplate,
+    ) -> Result<u32, Error> {
+        Self::require_admin(env, admin)?;
+        Self::validate_template(template)?;
+
+        let template_id = Self::get_template_count(env) + 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::MarketTemplate(template_id), template);
+        env.storage()
+            .instance()
+            .set(&DataKey::TemplateCount, &template_id);
+
+        crate::events::EventEmitter::emit_template_set(env, template_id, admin);
+        Ok(template_id)
+    }
+
+    /// Replace an existing template (admin only). Markets already created from
+    /// it are unaffected.
+    pub fn update_template(
+        env: &Env,
+        admin: &Address,
+        template_id: u32,
+        template: &MarketTemplate,
+    ) -> Result<(), Error> {
+        Self::require_admin(env, admin)?;
+        if Self::get_template(env, template_id).is_none() {
+            return Err(Error::InvalidInput);
+        }
+        Self::validate_template(template)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MarketTemplate(template_id), template);
+
+        crate::events::EventEmitter::emit_template_set(env, template_id, admin);
+        Ok(())
+    }
+
+    /// Get a template by id.
+    pub fn get_template(env: &Env, template_id: u32) -> Option<MarketTemplate> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MarketTemplate(template_id))
+    }
+
+    /// All templates with their ids, oldest first.
+    pub fn get_templates(env: &Env) -> Vec<(u32, MarketTemplate)> {
+        let mut templates = Vec::new(env);
+        for template_id in 1..=Self::get_template_count(env) {
+            if let Some(template) = Self::get_template(env, template_id) {
+                templates.push_back((template_id, template));
+            }
+        }
+        templates
+    }
+
+    /// Number of templates created so far.
+    pub fn get_template_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TemplateCount)
+            .unwrap_or(0)
+    }
+
+    /// Market parameters for a template with `overrides` applied.
+    pub fn apply_overrides(
+        env: &Env,
+        template_id: u32,
+        overrides: &TemplateOverrides,
+    ) -> Result<MarketTemplate, Error> {
+        let mut params = Self::get_template(env, template_id).ok_or(Error::InvalidInput)?;
+
+        if let Some(question) = &overrides.question {
+            params.question = question.clone();
+        }
+        if let Some(threshold) = overrides.threshold {
+            params.oracle_config.threshold = threshold;
+            if params.has_fallback {
+                params.fallback_oracle_config.threshold = threshold;
+            }
+        }
+        if let Some(duration_days) = overrides.duration_days {
+            params.duration_days = duration_days;
+        }
+        Ok(params)
+    }
+
+    /// Checks that do not depend on per-market values; the rest run when a
+    /// market is created from the template.
+    fn validate_template(template: &MarketTemplate) -> Result<(), Error> {
+        if template.outcomes.len() < crate::config::MIN_MARKET_OUTCOMES {
+            return Err(Error::InvalidOutcomes);
+        }
+        OracleComparison::parse(&template.oracle_config.comparison)?;
+        if template.has_fallback {
+            OracleComparison::parse(&template.fallback_oracle_config.comparison)?;
+        }
+        if template.creator_fee_bps > crate::config::MAX_CREATOR_FEE_BPS {
+            return Err(Error::InvalidFeeConfig);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if *admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+}
+
+/// Recurring market series built on templates.
+///
+/// A series creates its first market when it is set up; after each market's
+/// `end_time` anyone may roll the series to create the next one from the
+/// template's current settings, until `count` markets exist.
+pub struct MarketSeriesManager;
+
+impl MarketSeriesManager {
+    /// Store a new series and return its id (admin only). The caller creates
+    /// the first market.
+    pub fn create_series(
+        env: &Env,
+        admin: &Address,
+        template_id: u32,
+        interval_seconds: u64,
+        count: u32,
+    ) -> Result<u32, Error> {
+        MarketTemplateManager::require_admin(env, admin)?;
+        if MarketTemplateManager::get_template(env, template_id).is_none() {
+            return Err(Error::InvalidInput);
+        }
+        if interval_seconds == 0 || !interval_seconds.is_multiple_of(SECONDS_PER_DAY) || count == 0 {
+            return Err(Error::InvalidInput);
+        }
+        if interval_seconds / SECONDS_PER_DAY > u32::MAX as u64 {
+            return Err(Error::InvalidDuration);
+        }
+
+        let series_id = Self::get_series_count(env) + 1;
+        let series = MarketSeries {
+            admin: admin.clone(),
+            template_id,
+            interval_seconds,
+            count,
+            markets: Vec::new(env),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Series(series_id), &series);
+        env.storage()
+            .instance()
+            .set(&DataKey::SeriesCount, &series_id);
+        Ok(series_id)
+    }
+
+    /// Get a series by id.
+    pub fn get_series(env: &Env, series_id: u32) -> Option<MarketSeries> {
+        env.storage().persistent().get(&DataKey::Series(series_id))
+    }
+
+    /// Number of series created so far.
+    pub fn get_series_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SeriesCount)
+            .unwrap_or(0)
+    }
+
+    /// Series id and index of `market_id`, if a series created it.
+    pub fn get_series_position(env: &Env, market_id: &Symbol) -> Option<SeriesPosition> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SeriesPosition(market_id.clone()))
+    }
+
+    /// Parameters for the series' next market.
+    ///
+    /// Fails with `Error::InvalidState` once all `count` markets exist and with
+    /// `Error::MarketNotReady` while the latest market has not ended.
+    pub fn next_market_params(env: &Env, series: &MarketSeries) -> Result<MarketTemplate, Error> {
+        if series.markets.len() >= series.count {
+            return Err(Error::InvalidState);
+        }
+        if let Some(latest) = series.markets.last() {
+            let market = crate::markets::MarketStateManager::get_market(env, &latest)?;
+            if env.ledger().timestamp() < market.end_time {
+                return Err(Error::MarketNotReady);
+            }
+        }
+
+        let overrides = TemplateOverrides {
+            question: None,
+            threshold: None,
+            duration_days: Some((series.interval_seconds / SECONDS_PER_DAY) as u32),
+        };
+        MarketTemplateManager::apply_overrides(env, series.template_id, &overrides)
+    }
+
+    /// Append a newly created market to the series and return its index.
+    pub fn record_market(
+        env: &Env,
+        series_id: u32,
+        series: &mut MarketSeries,
+        market_id: &Symbol,
+    ) -> u32 {
+        let index = series.markets.len();
+        series.markets.push_back(market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Series(series_id), series);
+        env.storage().persistent().set(
+            &DataKey::SeriesPosition(market_id.clone()),
+            &SeriesPosition { series_id, index },
+        );
+
+        crate::events::EventEmitter::emit_series_rolled(env, series_id, index, market_id);
+        index
+    }
+}
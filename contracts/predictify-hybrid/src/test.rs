@@ -18,7 +18,7 @@
 #![cfg(test)]
 
 use crate::events::{
-    BetPlacedEvent, ContractPausedEvent, ContractUnpausedEvent, EventLogger,
+    BetPlacedEvent, ClaimResultEvent, ContractPausedEvent, ContractUnpausedEvent, EventLogger,
     FeeCollectedEvent, FeeWithdrawalAttemptEvent, FeeWithdrawnEvent, PlatformFeeSetEvent,
 };
 
@@ -90,19 +90,8 @@ impl PredictifyTest {
             crate::config::ConfigManager::store_config(&env, &cfg).unwrap();
         });
 
-        // Set platform fee to 200 basis points (2%) for consistent payout calculations
-        env.as_contract(&contract_id, || {
-            env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "platform_fee"), &200i128);
-        });
-
         // Set token for staking
-        env.as_contract(&contract_id, || {
-            env.storage()
-                .persistent()
-                .set(&Symbol::new(&env, "TokenID"), &token_test.token_id);
-        });
+        client.set_token(&admin, &token_test.token_id);
 
         // Fund admin and user with tokens
         let stellar_client = StellarAssetClient::new(&env, &token_test.token_id);
@@ -136,6 +125,56 @@ impl PredictifyTest {
         user
     }
 
+    /// Schedule `action` with the admin action delay dropped to zero, so it can
+    /// be executed at once; returns its id.
+    pub fn schedule_due(&self, action: TimelockedAction) -> u64 {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        self.env.as_contract(&self.contract_id, || {
+            crate::timelock::TimelockManager::store_delay(&self.env, 0);
+        });
+        self.env.mock_all_auths();
+        client.schedule_action(&self.admin, &action).id
+    }
+
+    /// Switch the staking token through the admin action timelock.
+    pub fn switch_token(&self, token: &Address) {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        client.execute_action(&self.schedule_due(TimelockedAction::SetToken(token.clone())));
+    }
+
+    /// Set the ledger timestamp, keeping the sequence number.
+    pub fn set_time(&self, timestamp: u64) {
+        self.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: self.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    }
+
+    /// Move the ledger just past a resolved market's payout unlock time.
+    pub fn advance_past_payout_unlock(&self, market_id: &Symbol) {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let market = client.get_market(market_id).unwrap();
+        self.set_time(market.payout_unlock_at.unwrap() + 1);
+    }
+
+    /// Assert the contract holds enough tokens to cover all escrow and the fee vault.
+    pub fn assert_solvent(&self) {
+        let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
+        let (escrow_total, vault_total, token_balance) = client.reconcile();
+        assert!(token_balance >= escrow_total + vault_total);
+    }
+
+    /// Whether `user`'s position on `market_id` has been paid out or refunded.
+    pub fn is_claimed(&self, market_id: &Symbol, user: &Address) -> bool {
+        PredictifyHybridClient::new(&self.env, &self.contract_id).has_claimed(market_id, user)
+    }
+
     pub fn create_test_market(&self) -> Symbol {
         let client = PredictifyHybridClient::new(&self.env, &self.contract_id);
 
@@ -159,9 +198,12 @@ impl PredictifyTest {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2500000,
                 comparison: String::from_str(&self.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             &None,
-            &0,
+            &86400,
             &None,
             &None,
             &None,
@@ -193,9 +235,12 @@ fn test_create_market_successful() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 2500000,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -205,7 +250,7 @@ fn test_create_market_successful() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -218,6 +263,201 @@ fn test_create_market_successful() {
         market.end_time,
         test.env.ledger().timestamp() + 30 * 24 * 60 * 60
     );
+    assert_eq!(market.creator, test.admin);
+}
+
+fn try_create_market_as(
+    test: &PredictifyTest,
+    creator: &Address,
+) -> Result<Symbol, crate::errors::Error> {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client
+        .try_create_market(
+            creator,
+            &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "BTC"),
+                threshold: 2500000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_allowlisted_creator_creates_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let creator = test.create_funded_user();
+
+    assert!(!client.is_market_creator(&creator));
+    test.env.mock_all_auths();
+    client.add_market_creator(&test.admin, &creator);
+    assert!(client.is_market_creator(&creator));
+
+    let market_id = try_create_market_as(&test, &creator).unwrap();
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.creator, creator);
+    assert_eq!(market.admin, test.admin);
+
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::MarketCreatedEvent>(&symbol_short!("mkt_crt"))
+            .unwrap()
+    });
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.creator, creator);
+
+    // The admin never needs to be allowlisted
+    assert!(try_create_market_as(&test, &test.admin).is_ok());
+}
+
+#[test]
+fn test_unlisted_creator_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let stranger = Address::generate(&test.env);
+
+    assert_eq!(
+        try_create_market_as(&test, &stranger),
+        Err(crate::errors::Error::Unauthorized)
+    );
+
+    // Only the admin manages the allowlist
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_add_market_creator(&stranger, &stranger),
+        Err(Ok(crate::errors::Error::Unauthorized))
+    );
+    assert!(!client.is_market_creator(&stranger));
+}
+
+#[test]
+fn test_removing_creator_revokes_market_creation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let creator = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.add_market_creator(&test.admin, &creator);
+    let market_id = try_create_market_as(&test, &creator).unwrap();
+
+    client.remove_market_creator(&test.admin, &creator);
+    assert!(!client.is_market_creator(&creator));
+    assert_eq!(
+        try_create_market_as(&test, &creator),
+        Err(crate::errors::Error::Unauthorized)
+    );
+
+    // Markets created before removal keep their creator
+    assert_eq!(client.get_market(&market_id).unwrap().creator, creator);
+}
+
+#[test]
+fn test_creation_fee_charged_to_allowlisted_creator() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = test.create_funded_user();
+    let fee = 10_0000000;
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.get_creation_fee(),
+        crate::config::DEFAULT_MARKET_CREATION_FEE
+    );
+    client.set_creation_fee(&test.admin, &fee);
+    assert_eq!(client.get_creation_fee(), fee);
+    client.add_market_creator(&test.admin, &creator);
+
+    let balance_before = token.balance(&creator);
+    let vault_before = client.get_fee_vault_balance(&None);
+    let market_id = try_create_market_as(&test, &creator).unwrap();
+
+    // The creation bond is held by the contract, not the fee vault
+    let bond = client.get_creation_bond();
+    assert_eq!(token.balance(&creator), balance_before - fee - bond);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before + fee);
+    assert_eq!(client.get_market_creation_bond(&market_id), bond);
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::MarketCreatedEvent>(&symbol_short!("mkt_crt"))
+            .unwrap()
+    });
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.creation_fee, fee);
+}
+
+#[test]
+fn test_admin_pays_no_creation_fee() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+
+    test.env.mock_all_auths();
+    client.set_creation_fee(&test.admin, &10_0000000);
+    let balance_before = token.balance(&test.admin);
+    let vault_before = client.get_fee_vault_balance(&None);
+
+    try_create_market_as(&test, &test.admin).unwrap();
+
+    assert_eq!(token.balance(&test.admin), balance_before);
+    assert_eq!(client.get_fee_vault_balance(&None), vault_before);
+}
+
+#[test]
+fn test_creation_fee_shortfall_aborts_creation() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = Address::generate(&test.env);
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&creator, &5_0000000);
+
+    test.env.mock_all_auths();
+    client.set_creation_fee(&test.admin, &10_0000000);
+    client.add_market_creator(&test.admin, &creator);
+
+    assert_eq!(
+        try_create_market_as(&test, &creator),
+        Err(crate::errors::Error::InsufficientBalance)
+    );
+    assert_eq!(token.balance(&creator), 5_0000000);
+    let active = test.env.as_contract(&test.contract_id, || {
+        crate::storage::CreatorLimitsManager::get_active_events(&test.env, &creator)
+    });
+    assert_eq!(active, 0);
+
+    // Out-of-range fees and non-admin callers are rejected
+    assert_eq!(
+        client.try_set_creation_fee(&test.admin, &-1),
+        Err(Ok(crate::errors::Error::InvalidFeeConfig))
+    );
+    assert_eq!(
+        client.try_set_creation_fee(&creator, &0),
+        Err(Ok(crate::errors::Error::Unauthorized))
+    );
 }
 
 #[test]
@@ -264,11 +504,11 @@ fn test_successful_vote() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
-    assert!(market.votes.contains_key(test.user.clone()));
+    assert_eq!(market.position_count(), 1);
     assert_eq!(market.total_staked, 1_0000000);
 }
 
@@ -282,7 +522,7 @@ fn test_vote_on_closed_market() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -314,7 +554,7 @@ fn test_vote_with_invalid_outcome() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert!(!market.outcomes.is_empty());
@@ -364,7 +604,7 @@ fn test_fee_calculation() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -434,7 +674,7 @@ fn test_outcome_validation() {
     let _test = PredictifyTest::setup();
 
     // Test outcome length limits
-    assert_eq!(crate::config::MAX_OUTCOME_LENGTH, 100);
+    assert_eq!(crate::config::MAX_OUTCOME_LENGTH, 32);
 
     // Test minimum and maximum outcomes
     assert_eq!(crate::config::MIN_MARKET_OUTCOMES, 2);
@@ -471,7 +711,7 @@ fn test_time_calculations() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -490,7 +730,7 @@ fn test_market_creation_data() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -519,18 +759,17 @@ fn test_voting_data_integrity() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
     // Verify voting data integrity
-    assert!(market.votes.contains_key(test.user.clone()));
-    let user_vote = market.votes.get(test.user.clone()).unwrap();
-    assert_eq!(user_vote, String::from_str(&test.env, "yes"));
-
-    assert!(market.stakes.contains_key(test.user.clone()));
-    let user_stake = market.stakes.get(test.user.clone()).unwrap();
-    assert_eq!(user_stake, 1_0000000);
+    let position = PredictifyHybridClient::new(&test.env, &test.contract_id)
+        .get_bet(&market_id, &test.user)
+        .unwrap();
+    assert_eq!(position.outcome, String::from_str(&test.env, "yes"));
+    assert_eq!(position.amount, 1_0000000);
+    assert_eq!(market.outcome_pool(&position.outcome), 1_0000000);
     assert_eq!(market.total_staked, 1_0000000);
 }
 
@@ -546,7 +785,7 @@ fn test_oracle_configuration() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -882,11 +1121,11 @@ fn test_oracle_factory_supported_providers() {
     assert!(crate::oracles::OracleFactory::is_provider_supported(
         &OracleProvider::Reflector
     ));
-
-    // Test unsupported providers
-    assert!(!crate::oracles::OracleFactory::is_provider_supported(
+    assert!(crate::oracles::OracleFactory::is_provider_supported(
         &OracleProvider::Pyth
     ));
+
+    // Test unsupported providers
     assert!(!crate::oracles::OracleFactory::is_provider_supported(
         &OracleProvider::BandProtocol
     ));
@@ -908,7 +1147,8 @@ fn test_oracle_factory_creation() {
     assert!(result.is_ok());
 
     // Test failed creation
-    let result = crate::oracles::OracleFactory::create_oracle(OracleProvider::Pyth, contract_id);
+    let result =
+        crate::oracles::OracleFactory::create_oracle(OracleProvider::BandProtocol, contract_id);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err(), Error::InvalidOracleConfig);
 }
@@ -936,7 +1176,7 @@ fn test_contract_pause_blocks_operations() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .is_some()
     });
 }
@@ -963,15 +1203,16 @@ fn test_unpause_restores_operations() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &10_000_000,
+        &None,
     );
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
-    assert!(market.votes.contains_key(user.clone()));
+    assert_eq!(market.position_count(), 1);
 }
 
 #[test]
@@ -1022,91 +1263,443 @@ fn test_payout_distribution_blocked_when_paused() {
     test.env.mock_all_auths();
     client.pause(&test.admin);
     let result = test.env.as_contract(&test.contract_id, || {
-        PredictifyHybrid::distribute_payouts(test.env.clone(), market_id.clone())
+        PredictifyHybrid::distribute_payouts(test.env.clone(), market_id.clone(), 0, 10)
     });
-    assert!(matches!(result, Err(Error::InvalidState)));
+    assert!(matches!(result, Err(Error::ContractPaused)));
 }
 
-// ===== ERROR RECOVERY TESTS =====
-
 #[test]
-fn test_error_recovery_mechanisms() {
-    let env = Env::default();
-    let contract_id = env.register(PredictifyHybrid, ());
-    env.mock_all_auths();
-
-    let admin = Address::from_string(&String::from_str(
-        &env,
-        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
-    ));
-
-    env.as_contract(&contract_id, || {
-        // Initialize admin system first
-        crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
+fn test_pause_mid_market_rejects_bets_until_unpaused() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+    client.place_bet(&user, &market_id, &yes, &10_000_000, &None);
 
-        // Test error recovery for different error types
-        let context = errors::ErrorContext {
-            operation: String::from_str(&env, "test_operation"),
-            user_address: Some(admin.clone()),
-            market_id: Some(Symbol::new(&env, "test_market")),
-            context_data: Map::new(&env),
-            timestamp: env.ledger().timestamp(),
-            call_chain: {
-                let mut chain = Vec::new(&env);
-                chain.push_back(String::from_str(&env, "test"));
-                chain
+    client.pause(&test.admin);
+    assert_eq!(
+        client.try_place_bet(&test.user, &market_id, &yes, &10_000_000, &None),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Will ETH go above $5,000?"),
+            &vec![&test.env, yes.clone(), String::from_str(&test.env, "no")],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "ETH"),
+                threshold: 5000000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
-        };
-
-        // Test basic error recovery functions exist (simplified to avoid object reference issues)
-        // Skip complex error recovery test that causes "mis-tagged object reference" errors
+            &None,
+            &86400,
+            &None,
+            &None,
+            &None,
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_distribute_payouts(&market_id, &0, &10),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_withdraw_fees(&test.admin, &0),
+        Err(Ok(Error::ContractPaused))
+    );
 
-        // Test that error recovery functions are callable
-        let status = errors::ErrorHandler::get_error_recovery_status(&env).unwrap();
-        assert_eq!(status.total_attempts, 0); // No persistent storage in test
+    // Reads keep working while paused
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 10_000_000);
 
-        // Test that resilience patterns can be validated
-        let patterns = Vec::new(&env);
-        let validation_result =
-            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
-        assert!(validation_result);
-    });
+    client.unpause(&test.admin);
+    client.place_bet(&test.user, &market_id, &yes, &10_000_000, &None);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().total_staked,
+        20_000_000
+    );
 }
 
 #[test]
-fn test_resilience_patterns_validation() {
-    let env = Env::default();
-    let contract_id = env.register(PredictifyHybrid, ());
-
-    env.as_contract(&contract_id, || {
-        let mut patterns = Vec::new(&env);
-        let mut pattern_config = Map::new(&env);
-        pattern_config.set(
-            String::from_str(&env, "max_attempts"),
-            String::from_str(&env, "3"),
-        );
-        pattern_config.set(
-            String::from_str(&env, "delay_ms"),
-            String::from_str(&env, "1000"),
-        );
+fn test_freeze_market_blocks_bets_and_payouts() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let other_market = test.create_test_market();
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    test.env.mock_all_auths();
+    client.place_bet(&winner, &market_id, &yes, &100_0000000, &None);
+    client.place_bet(&loser, &market_id, &String::from_str(&test.env, "no"), &100_0000000, &None);
 
-        let pattern = errors::ResiliencePattern {
-            pattern_name: String::from_str(&env, "retry_pattern"),
-            pattern_type: errors::ResiliencePatternType::RetryWithBackoff,
-            pattern_config,
-            enabled: true,
-            priority: 50,
-            last_used: None,
-            success_rate: 8500, // 85%
-        };
+    assert_eq!(
+        client.try_freeze_market(&test.user, &market_id),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.freeze_market(&test.admin, &market_id);
+    assert!(client.get_market(&market_id).unwrap().frozen);
+    assert_eq!(
+        client.try_freeze_market(&test.admin, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    let event = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::MarketFrozenEvent>(&symbol_short!("mkt_frz"))
+            .unwrap()
+    });
+    assert_eq!(event.market_id, market_id);
+    assert!(event.frozen);
 
-        patterns.push_back(pattern);
+    // Only the frozen market rejects bets
+    assert_eq!(
+        client.try_place_bet(&test.user, &market_id, &yes, &10_000_000, &None),
+        Err(Ok(Error::MarketFrozen))
+    );
+    client.place_bet(&test.user, &other_market, &yes, &10_000_000, &None);
 
-        let validation_result =
-            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
-        assert!(validation_result);
+    // Manual resolution still works while frozen, payouts do not
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
     });
-}
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+    assert_eq!(
+        client.try_distribute_payouts(&market_id, &0, &10),
+        Err(Ok(Error::MarketFrozen))
+    );
+    assert_eq!(
+        client.try_claim_winnings(&winner, &market_id),
+        Err(Ok(Error::MarketFrozen))
+    );
+
+    client.unfreeze_market(&test.admin, &market_id);
+    assert!(!client.get_market(&market_id).unwrap().frozen);
+    assert_eq!(
+        client.try_unfreeze_market(&test.admin, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    // Pool 200 XLM, winning stake 100 XLM, 2% fee
+    let (paid, _) = client.distribute_payouts(&market_id, &0, &10);
+    assert_eq!(paid, 196_0000000);
+}
+
+#[test]
+fn test_frozen_market_can_be_cancelled() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(&user, &market_id, &String::from_str(&test.env, "yes"), &10_000_000, &None);
+
+    client.freeze_market(&test.admin, &market_id);
+    assert_eq!(
+        client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None),
+        10_000_000
+    );
+    let (refunded, _) = client.process_refunds(&market_id, &0, &10);
+    assert_eq!(refunded, 10_000_000);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Cancelled
+    );
+}
+
+// ===== ADMIN TRANSFER TESTS =====
+
+#[test]
+fn test_propose_and_accept_admin() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let new_admin = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    client.propose_admin(&test.admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    let proposed = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::AdminProposedEvent>(&symbol_short!("adm_prop"))
+            .unwrap()
+    });
+    assert_eq!(proposed.current_admin, test.admin);
+    assert_eq!(proposed.proposed_admin, new_admin);
+
+    // Until acceptance the old admin keeps its privileges and the new one has none
+    client.pause(&test.admin);
+    assert_eq!(client.try_unpause(&new_admin), Err(Ok(Error::Unauthorized)));
+    client.unpause(&test.admin);
+
+    // Only the proposed address can accept
+    assert_eq!(
+        client.try_accept_admin(&test.user),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    let transferred = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::AdminTransferredEvent>(&symbol_short!("adm_xfer"))
+            .unwrap()
+    });
+    assert_eq!(transferred.previous_admin, test.admin);
+    assert_eq!(transferred.new_admin, new_admin);
+
+    // The old admin is locked out of admin-only entrypoints
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    let yes = String::from_str(&test.env, "yes");
+    assert_eq!(
+        client.try_resolve_market_manual(&test.admin, &market_id, &yes),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_withdraw_fees(&test.admin, &0),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.resolve_market_manual(&new_admin, &market_id, &yes);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Resolved
+    );
+
+    // Nothing left to accept
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(Error::ConfigNotFound))
+    );
+}
+
+#[test]
+fn test_transfer_admin_only_proposes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let new_admin = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    assert_eq!(
+        client.try_transfer_admin(&test.user, &new_admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.transfer_admin(&test.admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.try_unpause(&new_admin), Err(Ok(Error::Unauthorized)));
+    client.pause(&test.admin);
+
+    client.accept_admin(&new_admin);
+    client.unpause(&new_admin);
+    assert_eq!(client.try_pause(&test.admin), Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_accepted_admin_takes_over_role_checked_entrypoints() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let new_admin = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    client.propose_admin(&test.admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    client.set_market_max_price_age(&new_admin, &market_id, &Some(600));
+    assert_eq!(
+        client.try_set_market_max_price_age(&test.admin, &market_id, &Some(600)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_accept_admin_moves_multi_admin_entry() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let new_admin = Address::generate(&test.env);
+    test.env.mock_all_auths();
+    test.env.as_contract(&test.contract_id, || {
+        crate::admin::AdminSystemIntegration::migrate_to_multi_admin(&test.env).unwrap();
+    });
+
+    client.propose_admin(&test.admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    client.set_market_max_price_age(&new_admin, &market_id, &Some(600));
+    test.env.as_contract(&test.contract_id, || {
+        assert_eq!(
+            crate::admin::AdminManager::get_admin_role_for_address(&test.env, &new_admin),
+            Some(crate::admin::AdminRole::SuperAdmin)
+        );
+        assert_eq!(
+            crate::admin::AdminManager::get_admin_role_for_address(&test.env, &test.admin),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_admin_proposal_overwrite_and_cancel() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let typo = Address::generate(&test.env);
+    let intended = Address::generate(&test.env);
+    test.env.mock_all_auths();
+
+    assert_eq!(
+        client.try_propose_admin(&test.user, &intended),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_propose_admin(&test.admin, &test.admin),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    // A newer proposal replaces the mistyped one
+    client.propose_admin(&test.admin, &typo);
+    client.propose_admin(&test.admin, &intended);
+    assert_eq!(client.get_pending_admin(), Some(intended.clone()));
+    assert_eq!(client.try_accept_admin(&typo), Err(Ok(Error::Unauthorized)));
+
+    assert_eq!(
+        client.try_cancel_admin_proposal(&intended),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.cancel_admin_proposal(&test.admin);
+    assert_eq!(client.get_pending_admin(), None);
+    let cancelled = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<Symbol, crate::events::AdminProposalCancelledEvent>(&symbol_short!(
+                "adm_pcnl"
+            ))
+            .unwrap()
+    });
+    assert_eq!(cancelled.current_admin, test.admin);
+    assert_eq!(cancelled.proposed_admin, intended);
+
+    assert_eq!(
+        client.try_accept_admin(&intended),
+        Err(Ok(Error::ConfigNotFound))
+    );
+    assert_eq!(
+        client.try_cancel_admin_proposal(&test.admin),
+        Err(Ok(Error::ConfigNotFound))
+    );
+    client.pause(&test.admin);
+}
+
+// ===== ERROR RECOVERY TESTS =====
+
+#[test]
+fn test_error_recovery_mechanisms() {
+    let env = Env::default();
+    let contract_id = env.register(PredictifyHybrid, ());
+    env.mock_all_auths();
+
+    let admin = Address::from_string(&String::from_str(
+        &env,
+        "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    ));
+
+    env.as_contract(&contract_id, || {
+        // Initialize admin system first
+        crate::admin::AdminInitializer::initialize(&env, &admin).unwrap();
+
+        // Test error recovery for different error types
+        let context = errors::ErrorContext {
+            operation: String::from_str(&env, "test_operation"),
+            user_address: Some(admin.clone()),
+            market_id: Some(Symbol::new(&env, "test_market")),
+            context_data: Map::new(&env),
+            timestamp: env.ledger().timestamp(),
+            call_chain: {
+                let mut chain = Vec::new(&env);
+                chain.push_back(String::from_str(&env, "test"));
+                chain
+            },
+        };
+
+        // Test basic error recovery functions exist (simplified to avoid object reference issues)
+        // Skip complex error recovery test that causes "mis-tagged object reference" errors
+
+        // Test that error recovery functions are callable
+        let status = errors::ErrorHandler::get_error_recovery_status(&env).unwrap();
+        assert_eq!(status.total_attempts, 0); // No persistent storage in test
+
+        // Test that resilience patterns can be validated
+        let patterns = Vec::new(&env);
+        let validation_result =
+            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
+        assert!(validation_result);
+    });
+}
+
+#[test]
+fn test_resilience_patterns_validation() {
+    let env = Env::default();
+    let contract_id = env.register(PredictifyHybrid, ());
+
+    env.as_contract(&contract_id, || {
+        let mut patterns = Vec::new(&env);
+        let mut pattern_config = Map::new(&env);
+        pattern_config.set(
+            String::from_str(&env, "max_attempts"),
+            String::from_str(&env, "3"),
+        );
+        pattern_config.set(
+            String::from_str(&env, "delay_ms"),
+            String::from_str(&env, "1000"),
+        );
+
+        let pattern = errors::ResiliencePattern {
+            pattern_name: String::from_str(&env, "retry_pattern"),
+            pattern_type: errors::ResiliencePatternType::RetryWithBackoff,
+            pattern_config,
+            enabled: true,
+            priority: 50,
+            last_used: None,
+            success_rate: 8500, // 85%
+        };
+
+        patterns.push_back(pattern);
+
+        let validation_result =
+            errors::ErrorHandler::validate_resilience_patterns(&env, &patterns).unwrap();
+        assert!(validation_result);
+    });
+}
 
 #[test]
 fn test_error_recovery_procedures_documentation() {
@@ -1193,8 +1786,8 @@ fn test_initialize_with_default_fee() {
     // Verify admin is set
     let stored_admin: Address = env.as_contract(&contract_id, || {
         env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .unwrap()
     });
     assert_eq!(stored_admin, admin);
@@ -1202,7 +1795,7 @@ fn test_initialize_with_default_fee() {
     // Verify platform fee is default 2%
     let stored_fee: i128 = env.as_contract(&contract_id, || {
         env.storage()
-            .persistent()
+            .instance()
             .get(&Symbol::new(&env, "platform_fee"))
             .unwrap()
     });
@@ -1224,7 +1817,7 @@ fn test_initialize_with_custom_fee() {
     // Verify platform fee is 5%
     let stored_fee: i128 = env.as_contract(&contract_id, || {
         env.storage()
-            .persistent()
+            .instance()
             .get(&Symbol::new(&env, "platform_fee"))
             .unwrap()
     });
@@ -1246,20 +1839,70 @@ fn test_reinitialize_prevention() {
     // Verify admin is set (proves initialization succeeded)
     let stored_admin: Address = env.as_contract(&contract_id, || {
         env.storage()
-            .persistent()
-            .get(&Symbol::new(&env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .unwrap()
     });
     assert_eq!(stored_admin, admin);
 
     // Verify the contract is initialized
-    let has_admin = env.as_contract(&contract_id, || {
-        env.storage().persistent().has(&Symbol::new(&env, "Admin"))
+    assert!(client.is_initialized());
+
+    // A second call, even by a different address, is rejected and leaves the admin alone.
+    let attacker = Address::generate(&env);
+    assert_eq!(
+        client.try_initialize(&attacker, &None),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_initialize(&admin, &Some(5)),
+        Err(Ok(Error::InvalidState))
+    );
+    let stored_admin: Address = env.as_contract(&contract_id, || {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap()
     });
-    assert!(has_admin);
+    assert_eq!(stored_admin, admin);
+}
+
+#[test]
+fn test_initialize_requires_admin_auth() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(PredictifyHybrid, ());
+    let client = PredictifyHybridClient::new(&env, &contract_id);
+    assert!(!client.is_initialized());
+
+    // Nobody signed for `admin`, so a third party cannot claim the contract in its name.
+    assert!(client.try_initialize(&admin, &None).is_err());
+    assert!(!client.is_initialized());
+
+    env.mock_all_auths();
+    client.initialize(&admin, &None);
+    assert!(client.is_initialized());
+}
+
+#[test]
+fn test_initialize_rejects_out_of_range_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(PredictifyHybrid, ());
+    let client = PredictifyHybridClient::new(&env, &contract_id);
 
-    // The initialize function checks if already initialized.
-    // Second call would return AlreadyInitialized (#504).
+    assert_eq!(
+        client.try_initialize(&admin, &Some(-1)),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert_eq!(
+        client.try_initialize(&admin, &Some(11)),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert!(!client.is_initialized());
 }
 
 #[test]
@@ -1290,7 +1933,7 @@ fn test_initialize_valid_fee_bounds() {
 
         let stored_fee: i128 = env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
+                .instance()
                 .get(&Symbol::new(&env, "platform_fee"))
                 .unwrap()
         });
@@ -1309,7 +1952,7 @@ fn test_initialize_valid_fee_bounds() {
 
         let stored_fee: i128 = env.as_contract(&contract_id, || {
             env.storage()
-                .persistent()
+                .instance()
                 .get(&Symbol::new(&env, "platform_fee"))
                 .unwrap()
         });
@@ -1330,7 +1973,7 @@ fn test_initialize_storage_verification() {
 
     // Verify admin address is in persistent storage
     env.as_contract(&contract_id, || {
-        let has_admin = env.storage().persistent().has(&Symbol::new(&env, "Admin"));
+        let has_admin = env.storage().instance().has(&DataKey::Admin);
         assert!(has_admin);
     });
 
@@ -1338,7 +1981,7 @@ fn test_initialize_storage_verification() {
     env.as_contract(&contract_id, || {
         let has_fee = env
             .storage()
-            .persistent()
+            .instance()
             .has(&Symbol::new(&env, "platform_fee"));
         assert!(has_fee);
     });
@@ -1346,7 +1989,7 @@ fn test_initialize_storage_verification() {
     // Verify initialization flag (admin existence serves as initialization flag)
     env.as_contract(&contract_id, || {
         let admin_result: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(&env, "Admin"));
+            env.storage().instance().get(&DataKey::Admin);
         assert!(admin_result.is_some());
     });
 }
@@ -1396,7 +2039,7 @@ fn test_automatic_payout_distribution() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -1410,23 +2053,184 @@ fn test_automatic_payout_distribution() {
         max_entry_ttl: 10000,
     });
 
-    // Resolve market manually (resolve_market_manual internally calls distribute_payouts)
+    // Resolve market manually; the dispute window starts now, so nothing is paid yet
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
-
-    // distribute_payouts (called inside resolve_market_manual) already marked winners as claimed
-    // Verify market state and that winners were marked as claimed
-    let market_after = test.env.as_contract(&test.contract_id, || {
+    let resolved = client.get_market(&market_id).unwrap();
+    let resolved_at = test.env.ledger().timestamp();
+    assert_eq!(resolved.resolved_at, Some(resolved_at));
+    assert_eq!(
+        resolved.payout_unlock_at,
+        Some(resolved_at + resolved.dispute_window_seconds)
+    );
+    assert!(!test.is_claimed(&market_id, &user1));
+    assert_eq!(
+        client.try_distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Once the window closes distribute_payouts marks winners as claimed
+    test.advance_past_payout_unlock(&market_id);
+    client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
+    let market_after = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert_eq!(market_after.state, MarketState::Resolved);
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
-    assert!(!market_after.claimed.get(user3.clone()).unwrap_or(false)); // Loser not claimed
+    assert!(test.is_claimed(&market_id, &user1));
+    assert!(test.is_claimed(&market_id, &user2));
+    assert!(!test.is_claimed(&market_id, &user3)); // Loser not claimed
+}
+
+#[test]
+fn test_dispute_window_starts_at_resolution() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+
+    test.env.mock_all_auths();
+    client.place_bet(&winner, &market_id, &yes, &10_000_000, &None);
+    client.place_bet(&loser, &market_id, &String::from_str(&test.env, "no"), &10_000_000, &None);
+
+    // Resolve 23 hours after the market ends
+    let market = client.get_market(&market_id).unwrap();
+    let set_time = |timestamp: u64| {
+        test.env.ledger().set(LedgerInfo {
+            timestamp,
+            protocol_version: 22,
+            sequence_number: test.env.ledger().sequence(),
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1,
+            min_persistent_entry_ttl: 1,
+            max_entry_ttl: 10000,
+        });
+    };
+    let resolved_at = market.end_time + 23 * 3600;
+    set_time(resolved_at);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    let market = client.get_market(&market_id).unwrap();
+    let unlock_at = resolved_at + market.dispute_window_seconds;
+    assert_eq!(market.resolved_at, Some(resolved_at));
+    assert_eq!(market.payout_unlock_at, Some(unlock_at));
+
+    // The full window still applies after end_time + dispute_window_seconds
+    set_time(market.end_time + market.dispute_window_seconds + 1);
+    assert_eq!(
+        client.try_distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_claim_winnings(&winner, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+
+    set_time(unlock_at);
+    // Pool 2 XLM, winning stake 1 XLM, 2% fee
+    assert_eq!(client.claim_winnings(&winner, &market_id), 19_600_000);
+}
+
+fn try_create_market_with_dispute_window(
+    test: &PredictifyTest,
+    dispute_window_seconds: Option<u64>,
+) -> Result<Symbol, Result<Error, soroban_sdk::InvokeError>> {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.mock_all_auths();
+    client
+        .try_create_market(
+            &test.admin,
+            &String::from_str(&test.env, "Will BTC go above $25,000 by December 31?"),
+            &vec![
+                &test.env,
+                String::from_str(&test.env, "yes"),
+                String::from_str(&test.env, "no"),
+            ],
+            &30,
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "BTC"),
+                threshold: 2500000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &86400,
+            &None,
+            &None,
+            &dispute_window_seconds,
+        )
+        .map(|id| id.unwrap())
+}
+
+#[test]
+fn test_default_dispute_window_override_precedence() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_default_dispute_window(), 86_400);
+
+    let before = try_create_market_with_dispute_window(&test, None).unwrap();
+    assert_eq!(
+        client.get_market(&before).unwrap().dispute_window_seconds,
+        86_400
+    );
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_default_dispute_window(&test.user, &7_200),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_default_dispute_window(&test.admin, &7_200);
+    assert_eq!(client.get_default_dispute_window(), 7_200);
+
+    // New markets take the default unless they set their own window
+    let defaulted = try_create_market_with_dispute_window(&test, None).unwrap();
+    let overridden = try_create_market_with_dispute_window(&test, Some(172_800)).unwrap();
+    assert_eq!(
+        client.get_market(&defaulted).unwrap().dispute_window_seconds,
+        7_200
+    );
+    assert_eq!(
+        client.get_market(&overridden).unwrap().dispute_window_seconds,
+        172_800
+    );
+    // Existing markets keep the window they were created with
+    assert_eq!(
+        client.get_market(&before).unwrap().dispute_window_seconds,
+        86_400
+    );
+}
+
+#[test]
+fn test_dispute_window_out_of_bounds_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    for seconds in [0, 3_599, 604_801] {
+        assert_eq!(
+            client.try_set_default_dispute_window(&test.admin, &seconds),
+            Err(Ok(Error::InvalidInput))
+        );
+        assert_eq!(
+            try_create_market_with_dispute_window(&test, Some(seconds)),
+            Err(Ok(Error::InvalidInput))
+        );
+    }
+    assert_eq!(client.get_default_dispute_window(), 86_400);
+
+    // Both bounds are inclusive
+    client.set_default_dispute_window(&test.admin, &3_600);
+    assert!(try_create_market_with_dispute_window(&test, Some(604_800)).is_ok());
 }
 
 #[test]
@@ -1440,7 +2244,7 @@ fn test_automatic_payout_distribution_unresolved_market() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert!(market.winning_outcomes.is_none());
@@ -1463,7 +2267,7 @@ fn test_automatic_payout_distribution_no_winners() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -1479,9 +2283,10 @@ fn test_automatic_payout_distribution_no_winners() {
 
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
 
     // Distribute payouts (should return 0 with no winners)
-    let total = client.distribute_payouts(&market_id);
+    let (total, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert_eq!(total, 0);
 }
 
@@ -1492,12 +2297,14 @@ fn test_set_platform_fee() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
 
-    // Set fee to 3% (300 basis points)
+    // Cut the fee to 1% (100 basis points); increases go through the timelock
     test.env.mock_all_auths();
-    client.set_platform_fee(&test.admin, &300);
-
-    // Test passes if no panic occurs - fee is set in legacy storage
-    // Verification can be done separately if needed
+    client.set_platform_fee(&test.admin, &1);
+    assert_eq!(client.get_fee_bps(), 100);
+    assert_eq!(
+        client.try_set_platform_fee(&test.admin, &3),
+        Err(Ok(Error::FeeIncreaseTimelocked))
+    );
 }
 
 #[test]
@@ -1508,8 +2315,8 @@ fn test_set_platform_fee_unauthorized() {
     let stored_admin: Address = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&test.env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .unwrap()
     });
     assert_eq!(stored_admin, test.admin);
@@ -1525,22 +2332,211 @@ fn test_set_platform_fee_invalid_range() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
 
-    // Test that valid fee ranges work
+    // Percentages outside 0-10% are rejected
     test.env.mock_all_auths();
-    client.set_platform_fee(&test.admin, &500); // 5% - valid
+    assert_eq!(
+        client.try_set_platform_fee(&test.admin, &11),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert_eq!(
+        client.try_set_platform_fee(&test.admin, &-1),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    client.set_platform_fee(&test.admin, &0);
+    assert_eq!(client.get_fee_bps(), 0);
 
-    // Verify the fee was set
+    // Basis points are never written into the legacy percentage entry
     let stored_fee: i128 = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
-            .persistent()
+            .instance()
             .get(&Symbol::new(&test.env, "platform_fee"))
             .unwrap()
     });
-    assert_eq!(stored_fee, 500);
+    assert_eq!(stored_fee, 2);
+}
+
+#[test]
+fn test_legacy_fee_percentage_is_read_as_percentage() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // An instance initialized before the basis point key only has `platform_fee`
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .instance()
+            .remove(&Symbol::new(&test.env, crate::config::FEE_BPS_STORAGE_KEY));
+    });
+    assert_eq!(client.get_fee_bps(), 200);
+
+    let market_id = test.create_test_market();
+    assert_eq!(client.get_market(&market_id).unwrap().fee_bps, 200);
+}
+
+#[test]
+fn test_set_fee_bps_and_get_fee_bps() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_fee_bps(), 200);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_fee_bps(&test.admin, &350),
+        Err(Ok(Error::FeeIncreaseTimelocked))
+    );
+    client.execute_action(&test.schedule_due(TimelockedAction::SetFeeBps(350)));
+    assert_eq!(client.get_fee_bps(), 350);
+
+    client.set_fee_bps(&test.admin, &0);
+    assert_eq!(client.get_fee_bps(), 0);
+
+    assert_eq!(
+        client.try_set_fee_bps(&test.admin, &1001),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert_eq!(
+        client.try_set_fee_bps(&test.user, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_fee_bps(), 0);
+}
+
+#[test]
+fn test_fee_bps_snapshotted_per_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // Market A is created at the default 200 bps, market B after raising it to 500 bps
+    let market_a = test.create_test_market();
+    client.execute_action(&test.schedule_due(TimelockedAction::SetFeeBps(500)));
+    let market_b = test.create_test_market();
+    assert_eq!(client.get_market(&market_a).unwrap().fee_bps, 200);
+    assert_eq!(client.get_market(&market_b).unwrap().fee_bps, 500);
+
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    for market_id in [&market_a, &market_b] {
+        client.place_bet(&winner, market_id, &yes, &100_0000000, &None);
+        client.place_bet(&loser, market_id, &no, &100_0000000, &None);
+        resolve_market_without_distribution(&test, market_id, "yes");
+    }
+
+    let market = client.get_market(&market_b).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // Pool 200 XLM, winning stake 100 XLM
+    let (paid_a, _) = client.distribute_payouts(&market_a, &0, &MAX_PAYOUT_PAGE_SIZE);
+    let (paid_b, _) = client.distribute_payouts(&market_b, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(paid_a, 196_0000000);
+    assert_eq!(paid_b, 190_0000000);
+
+    assert_eq!(client.collect_fees(&test.admin, &market_a), 4_0000000);
+    assert_eq!(client.collect_fees(&test.admin, &market_b), 10_0000000);
+}
+
+fn resolved_fee_market(test: &PredictifyTest) -> (Symbol, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.place_bet(&winner, &market_id, &String::from_str(&test.env, "yes"), &60_0000000, &None);
+    client.place_bet(&loser, &market_id, &String::from_str(&test.env, "no"), &40_0000000, &None);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    (market_id, winner)
+}
+
+#[test]
+fn test_collect_fees_only_once() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // Nothing accrues before resolution, and collection is rejected
+    let unresolved = test.create_test_market();
+    assert_eq!(client.get_market_fees(&unresolved), (0, 0));
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &unresolved),
+        Err(Ok(crate::errors::Error::MarketNotResolved))
+    );
+    client.cancel_event(&test.admin, &unresolved, &CancelReason::Administrative, &None);
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &unresolved),
+        Err(Ok(crate::errors::Error::MarketClosed))
+    );
+
+    // 2% of the 100 XLM pool is reserved at resolution
+    let (market_id, _) = resolved_fee_market(&test);
+    assert_eq!(client.get_market_fees(&market_id), (2_0000000, 0));
+
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 2_0000000);
+    assert_eq!(client.get_market_fees(&market_id), (2_0000000, 2_0000000));
+    assert_eq!(
+        client.try_collect_fees(&test.admin, &market_id),
+        Err(Ok(crate::errors::Error::FeeAlreadyCollected))
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), 2_0000000);
+}
+
+#[test]
+fn test_collect_fees_and_distribution_are_order_independent() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (collect_first, first_winner) = resolved_fee_market(&test);
+    let (distribute_first, second_winner) = resolved_fee_market(&test);
+    test.env.mock_all_auths();
+
+    let first_fee = client.collect_fees(&test.admin, &collect_first);
+    let (first_paid, _) = client.distribute_payouts(&collect_first, &0, &MAX_PAYOUT_PAGE_SIZE);
+
+    let (second_paid, _) =
+        client.distribute_payouts(&distribute_first, &0, &MAX_PAYOUT_PAGE_SIZE);
+    let second_fee = client.collect_fees(&test.admin, &distribute_first);
 
-    // The function validates fee_percentage is 0-1000 (0-10%).
-    // Values > 1000 return InvalidFeeConfig (#402).
+    // Winner takes the pool less the 2% fee either way, and nothing is charged twice
+    assert_eq!(first_fee, 2_0000000);
+    assert_eq!(second_fee, first_fee);
+    assert_eq!(first_paid, 98_0000000);
+    assert_eq!(second_paid, first_paid);
+    assert_eq!(
+        client.get_balance(&first_winner, &ReflectorAsset::Stellar).amount,
+        client.get_balance(&second_winner, &ReflectorAsset::Stellar).amount
+    );
+    assert_eq!(client.get_fee_vault_balance(&None), 4_0000000);
 }
 
 #[test]
@@ -1562,7 +2558,7 @@ fn test_withdraw_collected_fees() {
 
     // First, collect some fees (simulate by setting collected fees in storage)
     test.env.as_contract(&test.contract_id, || {
-        let fees_key = Symbol::new(&test.env, "tot_fees");
+        let fees_key = DataKey::FeeVault;
         test.env
             .storage()
             .persistent()
@@ -1581,11 +2577,11 @@ fn test_withdraw_collected_fees() {
 
     // Verify fees were withdrawn
     let remaining = test.env.as_contract(&test.contract_id, || {
-        let fees_key = Symbol::new(&test.env, "tot_fees");
+        let fees_key = DataKey::FeeVault;
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, i128>(&fees_key)
+            .get::<DataKey, i128>(&fees_key)
             .unwrap_or(0)
     });
     assert_eq!(remaining, 0);
@@ -1620,11 +2616,11 @@ fn test_withdraw_collected_fees_no_fees() {
 
     // Verify no fees are collected initially
     let fees = test.env.as_contract(&test.contract_id, || {
-        let fees_key = Symbol::new(&test.env, "tot_fees");
+        let fees_key = DataKey::FeeVault;
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, i128>(&fees_key)
+            .get::<DataKey, i128>(&fees_key)
             .unwrap_or(0)
     });
     assert_eq!(fees, 0);
@@ -1648,6 +2644,120 @@ fn test_withdraw_collected_fees_no_fees() {
     );
 }
 
+fn seed_fee_vault(test: &PredictifyTest, amount: i128) {
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .set(&DataKey::FeeVault, &amount);
+    });
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &test.token_test.token_id).mint(&test.contract_id, &amount);
+}
+
+#[test]
+fn test_withdraw_fees_pays_treasury() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let treasury = Address::generate(&test.env);
+    seed_fee_vault(&test, 100);
+
+    test.env.mock_all_auths();
+    client.set_treasury(&test.admin, &treasury);
+    assert_eq!(client.get_treasury(), Some(treasury.clone()));
+
+    let admin_before = token.balance(&test.admin);
+    assert_eq!(client.withdraw_fees(&test.admin, &0), 100);
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&test.admin), admin_before);
+}
+
+#[test]
+fn test_withdraw_fees_to_recipient() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let recipient = Address::generate(&test.env);
+    seed_fee_vault(&test, 100);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_withdraw_fees_to(&test.user, &test.user, &0),
+        Err(Ok(crate::errors::Error::Unauthorized))
+    );
+    assert_eq!(client.withdraw_fees_to(&test.admin, &recipient, &60), 60);
+    assert_eq!(token.balance(&recipient), 60);
+    assert_eq!(client.get_fee_vault_balance(&None), 40);
+}
+
+#[test]
+fn test_fee_withdrawal_never_touches_market_pools() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+        &None,
+    );
+    seed_fee_vault(&test, 100);
+    let held = token.balance(&test.contract_id);
+
+    // The contract holds the pool too, but only the vault may be withdrawn
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_withdraw_fees(&test.admin, &101),
+        Err(Ok(crate::errors::Error::InsufficientBalance))
+    );
+    assert_eq!(token.balance(&test.contract_id), held);
+
+    assert_eq!(client.withdraw_fees(&test.admin, &0), 100);
+    assert_eq!(token.balance(&test.contract_id), held - 100);
+    assert_eq!(
+        token.balance(&test.contract_id),
+        client.get_market(&market_id).unwrap().total_staked
+    );
+    assert_eq!(
+        client.try_withdraw_fees(&test.admin, &1),
+        Err(Ok(crate::errors::Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_reconcile_separates_escrow_from_fee_vault() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let pool = 500_0000000;
+    test.env.mock_all_auths();
+    client.place_bet(&test.user, &market_id, &String::from_str(&test.env, "yes"), &pool, &None);
+    seed_fee_vault(&test, 2_0000000);
+
+    let (escrow, vault, held) = client.reconcile();
+    assert_eq!((escrow, vault, held), (pool, 2_0000000, pool + 2_0000000));
+
+    // The open pool dwarfs the vault, yet only the vault can be withdrawn
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_withdraw_fees(&test.admin, &(2_0000000 + 1)),
+        Err(Ok(crate::errors::Error::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_withdraw_fees_to(&test.admin, &test.admin, &pool),
+        Err(Ok(crate::errors::Error::InsufficientBalance))
+    );
+    assert_eq!(client.withdraw_fees(&test.admin, &0), 2_0000000);
+
+    let (escrow, vault, held) = client.reconcile();
+    assert_eq!((escrow, vault, held), (pool, 0, pool));
+    assert!(held >= escrow + vault);
+}
+
 #[test]
 fn test_fee_withdrawal_timelock_enforced_and_then_allows_withdrawal() {
     let test = PredictifyTest::setup();
@@ -1661,7 +2771,7 @@ fn test_fee_withdrawal_timelock_enforced_and_then_allows_withdrawal() {
         test.env
             .storage()
             .persistent()
-            .set(&Symbol::new(&test.env, "tot_fees"), &100i128);
+            .set(&DataKey::FeeVault, &100i128);
     });
     let stellar_client = StellarAssetClient::new(&test.env, &test.token_test.token_id);
     test.env.mock_all_auths();
@@ -1686,7 +2796,7 @@ fn test_fee_withdrawal_timelock_enforced_and_then_allows_withdrawal() {
         test.env
             .storage()
             .persistent()
-            .set(&Symbol::new(&test.env, "tot_fees"), &50i128);
+            .set(&DataKey::FeeVault, &50i128);
     });
     test.env.mock_all_auths();
     stellar_client.mint(&test.contract_id, &50i128);
@@ -1750,7 +2860,7 @@ fn test_fee_withdrawal_cap_applied_per_window() {
         test.env
             .storage()
             .persistent()
-            .set(&Symbol::new(&test.env, "tot_fees"), &100i128);
+            .set(&DataKey::FeeVault, &100i128);
     });
     let stellar_client = StellarAssetClient::new(&test.env, &test.token_test.token_id);
     test.env.mock_all_auths();
@@ -1828,6 +2938,9 @@ fn test_create_event_collects_configured_fee_and_emits_event() {
             feed_id: String::from_str(&test.env, "XLM/USD"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
         &0,
@@ -1865,7 +2978,6 @@ fn test_create_event_collects_configured_fee_and_emits_event() {
 }
 
 #[test]
-#[should_panic]
 fn test_create_event_rejects_when_fee_insufficient() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -1887,26 +2999,31 @@ fn test_create_event_rejects_when_fee_insufficient() {
         String::from_str(&test.env, "yes"),
         String::from_str(&test.env, "no"),
     ];
-    client.create_event(
-        &test.admin,
-        &String::from_str(&test.env, "Insufficient fee should fail"),
-        &outcomes,
-        &(test.env.ledger().timestamp() + 3600),
-        &OracleConfig {
-            provider: OracleProvider::Reflector,
-            oracle_address: Address::generate(&test.env),
-            feed_id: String::from_str(&test.env, "BTC/USD"),
-            threshold: 50000,
-            comparison: String::from_str(&test.env, "gt"),
-        },
-        &None,
-        &0,
-        &EventVisibility::Public,
+    assert_eq!(
+        client.try_create_event(
+            &test.admin,
+            &String::from_str(&test.env, "Insufficient fee should fail"),
+            &outcomes,
+            &(test.env.ledger().timestamp() + 3600),
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "BTC/USD"),
+                threshold: 50000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &0,
+            &EventVisibility::Public,
+        ),
+        Err(Ok(Error::InsufficientBalance))
     );
 }
 
 #[test]
-#[should_panic(expected = "HostError: Error(Contract, #400)")]
 fn test_create_event_rejects_when_fee_asset_not_configured() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -1915,8 +3032,8 @@ fn test_create_event_rejects_when_fee_asset_not_configured() {
     test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
-            .persistent()
-            .remove(&Symbol::new(&test.env, "TokenID"));
+            .instance()
+            .remove(&DataKey::Token);
     });
 
     let outcomes = vec![
@@ -1924,21 +3041,27 @@ fn test_create_event_rejects_when_fee_asset_not_configured() {
         String::from_str(&test.env, "yes"),
         String::from_str(&test.env, "no"),
     ];
-    client.create_event(
-        &test.admin,
-        &String::from_str(&test.env, "Missing fee asset should fail"),
-        &outcomes,
-        &(test.env.ledger().timestamp() + 3600),
-        &OracleConfig {
-            provider: OracleProvider::Reflector,
-            oracle_address: Address::generate(&test.env),
-            feed_id: String::from_str(&test.env, "ETH/USD"),
-            threshold: 3000,
-            comparison: String::from_str(&test.env, "gt"),
-        },
-        &None,
-        &0,
-        &EventVisibility::Public,
+    assert_eq!(
+        client.try_create_event(
+            &test.admin,
+            &String::from_str(&test.env, "Missing fee asset should fail"),
+            &outcomes,
+            &(test.env.ledger().timestamp() + 3600),
+            &OracleConfig {
+                provider: OracleProvider::Reflector,
+                oracle_address: Address::generate(&test.env),
+                feed_id: String::from_str(&test.env, "ETH/USD"),
+                threshold: 3000,
+                comparison: String::from_str(&test.env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
+            },
+            &None,
+            &0,
+            &EventVisibility::Public,
+        ),
+        Err(Ok(Error::InvalidState))
     );
 }
 
@@ -1958,11 +3081,9 @@ fn test_create_event_uses_configured_fee_asset() {
     alt_token_client.mint(&test.admin, &1000_0000000);
 
     let fee = 3_000_000i128; // 0.3 XLM
+    test.switch_token(&alt_token);
+    assert_eq!(client.get_token(), alt_token);
     test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .set(&Symbol::new(&test.env, "TokenID"), &alt_token);
         let mut cfg = crate::config::ConfigManager::get_config(&test.env).unwrap();
         cfg.fees.creation_fee = fee;
         crate::config::ConfigManager::store_config(&test.env, &cfg).unwrap();
@@ -1987,6 +3108,9 @@ fn test_create_event_uses_configured_fee_asset() {
             feed_id: String::from_str(&test.env, "SOL/USD"),
             threshold: 150,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
         &0,
@@ -2003,6 +3127,344 @@ fn test_create_event_uses_configured_fee_asset() {
     );
 }
 
+// ===== STAKING TOKEN CONFIGURATION TESTS =====
+
+#[test]
+fn test_set_token_and_get_token() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    assert_eq!(client.get_token(), test.token_test.token_id);
+
+    let new_token = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_token(&test.admin, &new_token),
+        Err(Ok(Error::InvalidState))
+    );
+    test.switch_token(&new_token);
+    assert_eq!(client.get_token(), new_token);
+}
+
+#[test]
+fn test_set_token_rejects_non_admin() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let new_token = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+
+    test.env.mock_all_auths();
+    let result = client.try_schedule_action(&test.user, &TimelockedAction::SetToken(new_token));
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(client.get_token(), test.token_test.token_id);
+}
+
+#[test]
+fn test_set_token_rejects_address_without_token_interface() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    test.env.mock_all_auths();
+    let result = client.try_schedule_action(
+        &test.admin,
+        &TimelockedAction::SetToken(Address::generate(&test.env)),
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidInput)));
+}
+
+#[test]
+fn test_set_token_blocked_while_market_has_open_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let new_token = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+
+    // An empty market does not block switching
+    test.env.mock_all_auths();
+    test.switch_token(&new_token);
+    test.switch_token(&test.token_test.token_id);
+
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+        &None,
+    );
+
+    let switch = test.schedule_due(TimelockedAction::SetToken(new_token.clone()));
+    let result = client.try_execute_action(&switch);
+    assert_eq!(result, Err(Ok(Error::BetsAlreadyPlaced)));
+    assert_eq!(client.get_token(), test.token_test.token_id);
+
+    // Cancelling is not enough: the stake is still owed back in the old token
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+    assert_eq!(
+        client.try_execute_action(&switch),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+
+    // Once refunded the token can change again
+    client.process_refunds(&market_id, &0, &10);
+    client.execute_action(&switch);
+    assert_eq!(client.get_token(), new_token);
+}
+
+#[test]
+fn test_set_token_blocked_while_winnings_unclaimed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let new_token = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+    test.env.mock_all_auths();
+
+    let yes = String::from_str(&test.env, "yes");
+    client.place_bet(&test.user, &market_id, &yes, &10_000_000, &None);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+
+    let switch = test.schedule_due(TimelockedAction::SetToken(new_token.clone()));
+    assert_eq!(
+        client.try_execute_action(&switch),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+    assert_eq!(client.get_token(), test.token_test.token_id);
+}
+
+#[test]
+fn test_open_market_count_follows_market_lifecycle() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let has_open_market = || {
+        test.env.as_contract(&test.contract_id, || {
+            crate::markets::MarketUtils::has_open_market(&test.env)
+        })
+    };
+    assert!(!has_open_market());
+
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    assert!(has_open_market());
+
+    client.cancel_event(&test.admin, &first, &CancelReason::Administrative, &None);
+    assert!(has_open_market());
+
+    let end_time = client.get_market(&second).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &second, &String::from_str(&test.env, "yes"));
+    assert!(!has_open_market());
+}
+
+/// Minimal token with 9 decimals, for checking amounts scale with the token.
+#[soroban_sdk::contract]
+pub struct NineDecimalToken;
+
+#[soroban_sdk::contractimpl]
+impl NineDecimalToken {
+    pub fn decimals(_env: Env) -> u32 {
+        9
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "NINE")
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        assert!(from_balance >= amount, "insufficient balance");
+        env.storage().persistent().set(&from, &(from_balance - amount));
+        Self::mint(env, to, amount);
+    }
+}
+
+#[test]
+fn test_token_decimals_scale_amounts_and_block_switch() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (token, decimals, _) = client.get_token_info();
+    assert_eq!(token, test.token_test.token_id);
+    assert_eq!(decimals, 7);
+    assert_eq!(client.get_creation_fee(), 1_0000000);
+
+    let nine = test.env.register(NineDecimalToken, ());
+    let nine_client = NineDecimalTokenClient::new(&test.env, &nine);
+
+    // An open market blocks a switch to different decimals even with an empty pool
+    let market_id = test.create_test_market();
+    test.env.mock_all_auths();
+    let switch = test.schedule_due(TimelockedAction::SetToken(nine.clone()));
+    assert_eq!(
+        client.try_execute_action(&switch),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+    let same_decimals = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+    test.switch_token(&same_decimals);
+    test.switch_token(&test.token_test.token_id);
+
+    // Configured amounts are whole-token values: half a token and three tokens
+    client.set_min_bet(&test.admin, &5_000_000);
+    client.set_creation_bond(&test.admin, &3_0000000);
+    assert_eq!(client.get_min_bet(), 5_000_000);
+
+    client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None);
+    client.execute_action(&switch);
+    assert_eq!(
+        client.get_token_info(),
+        (nine.clone(), 9, String::from_str(&test.env, "NINE"))
+    );
+
+    // Defaults and configured amounts keep their value in 9-decimal base units
+    assert_eq!(client.get_creation_fee(), 1_000_000_000);
+    assert_eq!(client.get_creation_bond(), 3_000_000_000);
+    assert_eq!(client.get_min_bet(), 500_000_000);
+    let limits = client.get_effective_bet_limits(&market_id);
+    assert_eq!(limits.min_bet, 500_000_000);
+    assert_eq!(limits.max_bet, 10_000_000_000_000);
+
+    let market_id = test.create_test_market();
+    let bettor = Address::generate(&test.env);
+    nine_client.mint(&bettor, &10_000_000_000);
+    let yes = String::from_str(&test.env, "yes");
+    assert_eq!(
+        client.try_place_bet(&bettor, &market_id, &yes, &(500_000_000 - 1), &None),
+        Err(Ok(Error::BetBelowMinimum))
+    );
+    client.place_bet(&bettor, &market_id, &yes, &1_000_000_000, &None);
+    assert_eq!(nine_client.balance(&bettor), 9_000_000_000);
+}
+
+#[test]
+fn test_set_market_token_rejected_after_bets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let market_token = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_set_market_token(&test.user, &market_id, &market_token),
+        Err(Ok(Error::Unauthorized))
+    );
+    // Limits and fees are scaled to the global token's 7 decimals
+    let nine = test.env.register(NineDecimalToken, ());
+    assert_eq!(
+        client.try_set_market_token(&test.admin, &market_id, &nine),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+        &None,
+    );
+    assert_eq!(
+        client.try_set_market_token(&test.admin, &market_id, &market_token),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+    assert_eq!(client.get_market_token(&market_id), test.token_test.token_id);
+}
+
+#[test]
+fn test_per_market_token_payouts_and_refunds_stay_in_asset() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_a = test.token_test.token_id.clone();
+    let token_b = test
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address();
+    let token_a_client = soroban_sdk::token::Client::new(&test.env, &token_a);
+    let token_b_client = soroban_sdk::token::Client::new(&test.env, &token_b);
+
+    let market_a = test.create_test_market();
+    let market_b = test.create_test_market();
+    test.env.mock_all_auths();
+    client.set_market_token(&test.admin, &market_b, &token_b);
+    assert_eq!(client.get_market_token(&market_a), token_a);
+    assert_eq!(client.get_market_token(&market_b), token_b);
+
+    let user1 = test.create_funded_user();
+    let user2 = test.create_funded_user();
+    StellarAssetClient::new(&test.env, &token_b).mint(&user1, &1000_0000000);
+    StellarAssetClient::new(&test.env, &token_b).mint(&user2, &1000_0000000);
+
+    // Market B is staked entirely in token B
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    client.place_bet(&user1, &market_b, &yes, &100_0000000, &None);
+    client.place_bet(&user2, &market_b, &no, &100_0000000, &None);
+    assert_eq!(token_b_client.balance(&test.contract_id), 200_0000000);
+    assert_eq!(token_a_client.balance(&test.contract_id), 0);
+
+    // Market A is staked in the global token and then cancelled
+    client.place_bet(&user1, &market_a, &yes, &50_0000000, &None);
+    assert_eq!(token_a_client.balance(&user1), 950_0000000);
+    client.cancel_event(&test.admin, &market_a, &CancelReason::Administrative, &None);
+    client.process_refunds(&market_a, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(token_a_client.balance(&user1), 1000_0000000);
+    assert_eq!(token_a_client.balance(&test.contract_id), 0);
+    assert_eq!(token_b_client.balance(&test.contract_id), 200_0000000);
+
+    // Resolve market B and pay out in token B
+    resolve_market_without_distribution(&test, &market_b, "yes");
+    let market = client.get_market(&market_b).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let (distributed, _) = client.distribute_payouts(&market_b, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(distributed, 196_0000000);
+    assert_eq!(token_b_client.balance(&user1), 900_0000000 + 196_0000000);
+    assert_eq!(token_b_client.balance(&user2), 900_0000000);
+    assert_eq!(token_a_client.balance(&user1), 1000_0000000);
+
+    // Fees from market B accrue to the token B vault only
+    let fee = client.collect_fees(&test.admin, &market_b);
+    assert_eq!(client.get_fee_vault_balance(&Some(token_b.clone())), fee);
+    assert_eq!(client.get_fee_vault_balance(&None), 0);
+
+    let admin_before = token_b_client.balance(&test.admin);
+    assert_eq!(client.withdraw_token_fees(&test.admin, &token_b, &0), fee);
+    assert_eq!(token_b_client.balance(&test.admin), admin_before + fee);
+    assert_eq!(client.get_fee_vault_balance(&Some(token_b)), 0);
+}
+
 // ===== TESTS FOR EVENT CANCELLATION (#216, #217) =====
 
 #[test]
@@ -2040,6 +3502,7 @@ fn test_cancel_event_successful() {
     let total_refunded = client.cancel_event(
         &test.admin,
         &market_id,
+        &CancelReason::Administrative,
         &Some(String::from_str(&test.env, "Oracle unavailable")),
     );
 
@@ -2050,7 +3513,7 @@ fn test_cancel_event_successful() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert_eq!(market.state, MarketState::Cancelled);
@@ -2065,8 +3528,8 @@ fn test_cancel_event_unauthorized() {
     let stored_admin: Address = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&test.env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .unwrap()
     });
     assert_eq!(stored_admin, test.admin);
@@ -2077,7 +3540,7 @@ fn test_cancel_event_unauthorized() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert_eq!(market.state, MarketState::Active);
@@ -2097,7 +3560,7 @@ fn test_cancel_event_already_resolved() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2119,7 +3582,7 @@ fn test_cancel_event_already_resolved() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert_eq!(resolved_market.state, MarketState::Resolved);
@@ -2141,6 +3604,7 @@ fn test_cancel_event_no_bets() {
     let total_refunded = client.cancel_event(
         &test.admin,
         &market_id,
+        &CancelReason::Administrative,
         &Some(String::from_str(&test.env, "No participants")),
     );
 
@@ -2151,7 +3615,7 @@ fn test_cancel_event_no_bets() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert_eq!(market.state, MarketState::Cancelled);
@@ -2168,18 +3632,214 @@ fn test_cancel_event_already_cancelled() {
     let _ = client.cancel_event(
         &test.admin,
         &market_id,
+        &CancelReason::Administrative,
         &Some(String::from_str(&test.env, "First cancellation")),
     );
 
-    // Try to cancel again (should return 0, no error)
-    test.env.mock_all_auths();
-    let total_refunded = client.cancel_event(
-        &test.admin,
-        &market_id,
-        &Some(String::from_str(&test.env, "Second cancellation")),
+    // Try to cancel again (should return 0, no error)
+    test.env.mock_all_auths();
+    let total_refunded = client.cancel_event(
+        &test.admin,
+        &market_id,
+        &CancelReason::Administrative,
+        &Some(String::from_str(&test.env, "Second cancellation")),
+    );
+
+    assert_eq!(total_refunded, 0);
+}
+
+#[test]
+fn test_cancel_event_after_manual_resolution_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_0000000,
+        &None,
+    );
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    assert_eq!(
+        client.try_cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None),
+        Err(Ok(Error::MarketResolved))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Resolved);
+    assert_eq!(market.total_staked, 10_0000000);
+}
+
+#[test]
+fn test_resolve_market_manual_after_cancel_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    test.env.mock_all_auths();
+    client.cancel_event(
+        &test.admin,
+        &market_id,
+        &CancelReason::Administrative,
+        &Some(String::from_str(&test.env, "Oracle unavailable")),
+    );
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    assert_eq!(
+        client.try_resolve_market_manual(
+            &test.admin,
+            &market_id,
+            &String::from_str(&test.env, "yes")
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.state, MarketState::Cancelled);
+    assert!(market.winning_outcomes.is_none());
+}
+
+#[test]
+fn test_cancel_event_emits_market_cancelled_with_reason() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &user,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &10_0000000,
+        &None,
+    );
+    let reason = String::from_str(&test.env, "Feed deprecated");
+    client.cancel_event(
+        &test.admin,
+        &market_id,
+        &CancelReason::Administrative,
+        &Some(reason.clone()),
+    );
+
+    let event = test
+        .env
+        .as_contract(&test.contract_id, || {
+            test.env
+                .storage()
+                .persistent()
+                .get::<Symbol, crate::events::MarketCancelledEvent>(&symbol_short!("mkt_canc"))
+        })
+        .unwrap();
+    assert_eq!(event.market_id, market_id);
+    assert_eq!(event.admin, test.admin);
+    assert_eq!(event.reason, reason);
+    assert_eq!(event.total_refundable, 10_0000000);
+}
+
+#[test]
+fn test_cancel_event_paged_refunds() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = TokenClient::new(&test.env, &test.token_test.token_id);
+    let market_id = test.create_test_market();
+    let contract_before = token_client.balance(&test.contract_id);
+
+    let mut bettors = Vec::new(&test.env);
+    test.env.mock_all_auths();
+    for i in 0..30 {
+        let user = test.create_funded_user();
+        let outcome = if i % 2 == 0 { "yes" } else { "no" };
+        client.place_bet(
+            &user,
+            &market_id,
+            &String::from_str(&test.env, outcome),
+            &10_0000000,
+            &None,
+        );
+        bettors.push_back((user.clone(), token_client.balance(&user)));
+    }
+    assert_eq!(
+        token_client.balance(&test.contract_id),
+        contract_before + 300_0000000
+    );
+
+    // Not cancelled yet
+    assert_eq!(
+        client.try_process_refunds(&market_id, &0, &10),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Cancelling only flips the state; funds stay until refunds are processed
+    assert_eq!(
+        client.cancel_event(&test.admin, &market_id, &CancelReason::Administrative, &None),
+        300_0000000
+    );
+    assert_eq!(
+        token_client.balance(&test.contract_id),
+        contract_before + 300_0000000
+    );
+    let late = test.create_funded_user();
+    assert_eq!(
+        client.try_place_bet(
+            &late,
+            &market_id,
+            &String::from_str(&test.env, "yes"),
+            &10_0000000,
+            &None
+        ),
+        Err(Ok(Error::MarketClosed))
+    );
+    assert_eq!(
+        client.try_process_refunds(&market_id, &0, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    assert_eq!(client.process_refunds(&market_id, &0, &10), (100_0000000, 10));
+    // Re-submitting a processed page refunds nobody twice
+    assert_eq!(client.process_refunds(&market_id, &0, &10), (0, 10));
+    assert_eq!(client.process_refunds(&market_id, &10, &10), (100_0000000, 20));
+    assert_eq!(
+        client.process_refunds(&market_id, &20, &10),
+        (100_0000000, PAYOUT_CURSOR_DONE)
+    );
+    assert_eq!(
+        client.process_refunds(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE),
+        (0, PAYOUT_CURSOR_DONE)
     );
 
-    assert_eq!(total_refunded, 0);
+    assert_eq!(token_client.balance(&test.contract_id), contract_before);
+    for (user, balance_after_bet) in bettors.iter() {
+        assert_eq!(token_client.balance(&user), balance_after_bet + 10_0000000);
+        assert_eq!(
+            client.get_bet(&market_id, &user).unwrap().status,
+            BetStatus::Refunded
+        );
+    }
 }
 
 // ===== TESTS FOR REFUND ON ORACLE FAILURE (#257, #258) =====
@@ -2198,19 +3858,21 @@ fn test_refund_on_oracle_failure_admin_success() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &10_000_000,
+        &None,
     );
     client.place_bet(
         &user2,
         &market_id,
         &String::from_str(&test.env, "no"),
         &20_000_000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2232,10 +3894,10 @@ fn test_refund_on_oracle_failure_admin_success() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
-    assert_eq!(market_after.state, MarketState::Cancelled);
+    assert_eq!(market_after.state, MarketState::Voided);
 }
 
 #[test]
@@ -2253,19 +3915,21 @@ fn test_refund_on_oracle_failure_full_amount_per_user() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &amt1,
+        &None,
     );
     client.place_bet(
         &user2,
         &market_id,
         &String::from_str(&test.env, "no"),
         &amt2,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2296,13 +3960,14 @@ fn test_refund_on_oracle_failure_no_double_refund() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &10_000_000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2338,13 +4003,14 @@ fn test_refund_on_oracle_failure_after_timeout_any_caller() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &10_000_000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2400,7 +4066,7 @@ fn test_manual_dispute_resolution() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2423,7 +4089,7 @@ fn test_manual_dispute_resolution() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -2445,7 +4111,7 @@ fn test_manual_dispute_resolution_unauthorized() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -2463,8 +4129,8 @@ fn test_manual_dispute_resolution_unauthorized() {
     let stored_admin: Address = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
-            .persistent()
-            .get(&Symbol::new(&test.env, "Admin"))
+            .instance()
+            .get(&DataKey::Admin)
             .unwrap()
     });
     assert_eq!(stored_admin, test.admin);
@@ -2484,7 +4150,7 @@ fn test_manual_dispute_resolution_before_end_time() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert!(test.env.ledger().timestamp() < market.end_time);
@@ -2503,7 +4169,7 @@ fn test_manual_dispute_resolution_invalid_outcome() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -2557,10 +4223,10 @@ fn test_manual_dispute_resolution_triggers_payout() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
-    // Advance past end_time and dispute window so resolve_market_manual can distribute payouts
+    // Advance past end_time and dispute window
     let payout_time = market.end_time + market.dispute_window_seconds + 1;
     test.env.ledger().set(LedgerInfo {
         timestamp: payout_time,
@@ -2573,19 +4239,21 @@ fn test_manual_dispute_resolution_triggers_payout() {
         max_entry_ttl: 10000,
     });
 
-    // Manually resolve (distribute_payouts runs inside once dispute window has passed)
+    // Manually resolve, then pay out once the dispute window after resolution has passed
     test.env.mock_all_auths();
     client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+    client.finalize_after_window(&market_id);
 
     let market_after = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     assert_eq!(market_after.state, MarketState::Resolved);
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id, &user1));
 }
 
 // ===== PAYOUT DISTRIBUTION TESTS =====
@@ -2690,10 +4358,400 @@ fn test_claim_winnings_successful() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // 4. Resolve market manually (as admin)
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+
+    // 5. Claims are blocked until the dispute window after resolution closes
+    assert_eq!(
+        client.try_claim_winnings(&test.user, &market_id),
+        Err(Ok(Error::InvalidState))
+    );
+    test.advance_past_payout_unlock(&market_id);
+    client.claim_winnings(&test.user, &market_id);
+
+    // Verify claimed status
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+    assert_eq!(market.state, MarketState::Resolved);
+    assert!(test.is_claimed(&market_id, &test.user));
+}
+
+#[test]
+fn test_double_claim_prevention() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // User places bet
+    let user1 = test.create_funded_user();
+    // 1. User votes
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &100_0000000,
+    );
+
+    // 2. Advance time
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // 3. Resolve market
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+
+    // 4. First claim
+    test.env.mock_all_auths();
+    client.claim_winnings(&test.user, &market_id);
+
+    // 5. Try to claim again (should panic with AlreadyClaimed)
+    test.env.mock_all_auths();
+    assert_eq!(client.try_claim_winnings(&test.user, &market_id), Err(Ok(Error::AlreadyClaimed)));
+}
+
+#[test]
+fn test_claim_by_loser() {
+    let test = PredictifyTest::setup();
+    let market_id = test.create_test_market();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // 1. User votes for losing outcome
+    test.env.mock_all_auths();
+    client.vote(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "no"),
+        &100_0000000,
+    );
+
+    // 2. Advance time
+    let market = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
+    });
+
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    // 3. Resolve market with "yes" as winner (user voted "no", so they lose)
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    test.advance_past_payout_unlock(&market_id);
+
+    // 4. Loser claims - should complete without panic but receive 0 (or minimal) and be marked claimed
+    test.env.mock_all_auths();
+    client.claim_winnings(&test.user, &market_id);
+
+    // 5. Verify loser was marked as claimed (prevents re-entry) and did not get winnings
+    let market_after = test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
+    assert!(test.is_claimed(&market_id, &test.user));
+}
+
+#[test]
+fn test_claim_winnings_pull_based_each_winner_claims() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    let winner1 = test.create_funded_user();
+    let winner2 = test.create_funded_user();
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(&winner1, &market_id, &String::from_str(&test.env, "yes"), &10_000_000, &None);
+    client.place_bet(&winner2, &market_id, &String::from_str(&test.env, "yes"), &20_000_000, &None);
+    client.place_bet(&loser, &market_id, &String::from_str(&test.env, "no"), &10_000_000, &None);
+
+    resolve_market_without_distribution(&test, &market_id, "yes");
+
+    // Pool 4 XLM, winning stake 3 XLM, 2% fee:
+    // winner1 = 1 XLM * 0.98 * 4 / 3, winner2 = 2 XLM * 0.98 * 4 / 3 plus the
+    // 1 stroop rounding remainder, as the last winner to claim
+    test.env.mock_all_auths();
+    let paid1 = client.claim_winnings(&winner1, &market_id);
+    let paid2 = client.claim_winnings(&winner2, &market_id);
+    let paid_loser = client.claim_winnings(&loser, &market_id);
+
+    assert_eq!(paid1, 13_066_666);
+    assert_eq!(paid2, 26_133_334);
+    assert_eq!(paid_loser, 0);
+
+    let (balance1, balance2) = test.env.as_contract(&test.contract_id, || {
+        (
+            storage::BalanceStorage::get_balance(&test.env, &winner1, &ReflectorAsset::Stellar),
+            storage::BalanceStorage::get_balance(&test.env, &winner2, &ReflectorAsset::Stellar),
+        )
+    });
+    assert_eq!(balance1.amount, paid1);
+    assert_eq!(balance2.amount, paid2);
+
+    let bet = client.get_bet(&market_id, &winner1).unwrap();
+    assert_eq!(bet.status, BetStatus::Won);
+    let bet = client.get_bet(&market_id, &loser).unwrap();
+    assert_eq!(bet.status, BetStatus::Lost);
+}
+
+#[test]
+fn test_claim_winnings_second_pull_claim_rejected() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    test.env.mock_all_auths();
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &10_000_000,
+        &None,
+    );
+    resolve_market_without_distribution(&test, &market_id, "yes");
+
+    test.env.mock_all_auths();
+    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(client.try_claim_winnings(&test.user, &market_id), Err(Ok(Error::AlreadyClaimed)));
+}
+
+#[test]
+fn test_claim_winnings_reconciles_with_distribute_payouts() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let pushed_market = test.create_test_market();
+    let pulled_market = test.create_test_market();
+
+    let stakes: [(i128, &str); 4] = [
+        (10_000_000, "yes"),
+        (33_000_000, "yes"),
+        (17_000_000, "no"),
+        (25_000_000, "yes"),
+    ];
+    let mut winners = Vec::new(&test.env);
+    test.env.mock_all_auths();
+    for (amount, outcome) in stakes.iter() {
+        let user = test.create_funded_user();
+        let outcome = String::from_str(&test.env, outcome);
+        client.place_bet(&user, &pushed_market, &outcome, amount, &None);
+        client.place_bet(&user, &pulled_market, &outcome, amount, &None);
+        if outcome == String::from_str(&test.env, "yes") {
+            winners.push_back(user);
+        }
+    }
+
+    resolve_market_without_distribution(&test, &pushed_market, "yes");
+    resolve_market_without_distribution(&test, &pulled_market, "yes");
+
+    let market = client.get_market(&pushed_market).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let (pushed_total, _) = client.distribute_payouts(&pushed_market, &0, &MAX_PAYOUT_PAGE_SIZE);
+
+    let mut pulled_total = 0i128;
+    test.env.mock_all_auths();
+    for winner in winners.iter() {
+        pulled_total += client.claim_winnings(&winner, &pulled_market);
+    }
+
+    assert!(pushed_total > 0);
+    assert_eq!(pushed_total, pulled_total);
+}
+
+#[test]
+fn test_distribute_payouts_paginated_matches_single_shot() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    test.env.budget().reset_unlimited();
+    let single_market = test.create_test_market();
+    let paged_market = test.create_test_market();
+
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let mut bettors: Vec<(Address, String, i128)> = Vec::new(&test.env);
+    test.env.mock_all_auths();
+    for i in 0..50u32 {
+        let user = test.create_funded_user();
+        let outcome = if i % 2 == 0 { yes.clone() } else { no.clone() };
+        let stake = 10_000_000 * (1 + (i % 3) as i128);
+        client.place_bet(&user, &single_market, &outcome, &stake, &None);
+        client.place_bet(&user, &paged_market, &outcome, &stake, &None);
+        bettors.push_back((user, outcome, stake));
+    }
+
+    resolve_market_without_distribution(&test, &single_market, "yes");
+    resolve_market_without_distribution(&test, &paged_market, "yes");
+    let market = client.get_market(&paged_market).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+
+    let (single_total, single_cursor) =
+        client.distribute_payouts(&single_market, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(single_cursor, PAYOUT_CURSOR_DONE);
+
+    let mut paged_total = 0i128;
+    let mut cursor = 0u32;
+    let mut pages = 0u32;
+    while cursor != PAYOUT_CURSOR_DONE {
+        let (paid, next) = client.distribute_payouts(&paged_market, &cursor, &10);
+        paged_total += paid;
+        cursor = next;
+        pages += 1;
+    }
+    assert_eq!(pages, 5);
+    assert_eq!(paged_total, single_total);
+    assert_eq!(client.get_payout_cursor(&paged_market), PAYOUT_CURSOR_DONE);
+
+    // Re-submitting an already settled range pays nobody twice
+    let (replayed, _) = client.distribute_payouts(&paged_market, &0, &10);
+    assert_eq!(replayed, 0);
+
+    // Each winner holds the same payout from both markets: stake * 0.98 * pool / winning_total
+    let mut total_pool = 0i128;
+    let mut winning_total = 0i128;
+    for (_, outcome, stake) in bettors.iter() {
+        total_pool += stake;
+        if outcome == yes {
+            winning_total += stake;
+        }
+    }
+    for (user, outcome, stake) in bettors.iter() {
+        let balance = test.env.as_contract(&test.contract_id, || {
+            storage::BalanceStorage::get_balance(&test.env, &user, &ReflectorAsset::Stellar)
+        });
+        let expected = if outcome == yes {
+            (stake * 9_800 / 10_000) * total_pool / winning_total
+        } else {
+            0
+        };
+        assert_eq!(balance.amount, expected * 2);
+    }
+}
+
+fn resolve_market_without_distribution(
+    test: &PredictifyTest,
+    market_id: &Symbol,
+    winning_outcome: &str,
+) {
+    test.env.as_contract(&test.contract_id, || {
+        let mut market = test
+            .env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap();
+        let winners = vec![&test.env, String::from_str(&test.env, winning_outcome)];
+        market.winning_outcomes = Some(winners);
+        market.state = MarketState::Resolved;
+        // Resolved with the dispute window already closed
+        let now = test.env.ledger().timestamp();
+        market.resolved_at = Some(now);
+        market.payout_unlock_at = Some(now);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
+    });
+}
+
+#[test]
+fn test_creator_fee_split_on_twenty_million_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = TokenClient::new(&test.env, &test.token_test.token_id);
+    let creator = test.create_funded_user();
+    test.env.mock_all_auths();
+    client.add_market_creator(&test.admin, &creator);
+
+    // One market settled by distribute_payouts, one by claim_winnings
+    let pushed_market = try_create_market_as(&test, &creator).unwrap();
+    let pulled_market = try_create_market_as(&test, &creator).unwrap();
+    client.set_creator_fee_bps(&creator, &pushed_market, &100);
+    client.set_creator_fee_bps(&creator, &pulled_market, &100);
+
+    // 20M pool: 10M on the winning side, 10M on the losing side
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    for market_id in [&pushed_market, &pulled_market] {
+        client.place_bet(
+            &winner,
+            market_id,
+            &String::from_str(&test.env, "yes"),
+            &10_000_000,
+            &None,
+        );
+        client.place_bet(&loser, market_id, &String::from_str(&test.env, "no"), &10_000_000, &None);
+        resolve_market_without_distribution(&test, market_id, "yes");
+    }
 
+    let market = client.get_market(&pushed_market).unwrap();
     test.env.ledger().set(LedgerInfo {
         timestamp: market.end_time + market.dispute_window_seconds + 1,
         protocol_version: 22,
@@ -2705,98 +4763,255 @@ fn test_claim_winnings_successful() {
         max_entry_ttl: 10000,
     });
 
-    // 4. Resolve market manually (as admin); distribute_payouts runs inside and pays winners
-    test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    // Winner: 20M less 2% platform and 1% creator fee
+    let (pushed, _) = client.distribute_payouts(&pushed_market, &0, &MAX_PAYOUT_PAGE_SIZE);
+    assert_eq!(pushed, 19_400_000);
+    assert_eq!(client.claim_winnings(&winner, &pulled_market), 19_400_000);
 
-    // 5. Winner was already marked claimed and paid by distribute_payouts inside resolve
-    // Verify claimed status
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-    assert_eq!(market.state, MarketState::Resolved);
-    assert!(market.claimed.get(test.user.clone()).unwrap_or(false));
+    // Creator: 1% of the pool per market; platform: 2% of the pool per market
+    assert_eq!(client.get_creator_fees(&creator), 2 * 200_000);
+    for market_id in [&pushed_market, &pulled_market] {
+        let market = client.get_market(market_id).unwrap();
+        let platform_fee = market.total_staked * market.fee_bps as i128 / 10_000;
+        assert_eq!(platform_fee, 400_000);
+        assert_eq!(19_400_000 + 200_000 + platform_fee, market.total_staked);
+    }
+
+    let balance_before = token.balance(&creator);
+    assert_eq!(client.withdraw_creator_fees(&creator), 400_000);
+    assert_eq!(token.balance(&creator), balance_before + 400_000);
+    assert_eq!(client.get_creator_fees(&creator), 0);
+    assert_eq!(
+        client.try_withdraw_creator_fees(&creator),
+        Err(Ok(crate::errors::Error::NothingToClaim))
+    );
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #106)")] // AlreadyClaimed = 106
-fn test_double_claim_prevention() {
+fn test_creator_fee_validation() {
     let test = PredictifyTest::setup();
-    let market_id = test.create_test_market();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
 
-    // User places bet
-    let user1 = test.create_funded_user();
-    // 1. User votes
     test.env.mock_all_auths();
-    client.vote(
+    assert_eq!(
+        client.try_set_creator_fee_bps(&test.user, &market_id, &100),
+        Err(Ok(crate::errors::Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_creator_fee_bps(
+            &test.admin,
+            &market_id,
+            &(crate::config::MAX_CREATOR_FEE_BPS + 1)
+        ),
+        Err(Ok(crate::errors::Error::InvalidFeeConfig))
+    );
+    client.set_creator_fee_bps(&test.admin, &market_id, &crate::config::MAX_CREATOR_FEE_BPS);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().creator_fee_bps,
+        crate::config::MAX_CREATOR_FEE_BPS
+    );
+
+    // Fixed once the first stake is in
+    client.place_bet(
         &test.user,
         &market_id,
         &String::from_str(&test.env, "yes"),
-        &100_0000000,
+        &10_000_000,
+        &None,
     );
+    assert_eq!(
+        client.try_set_creator_fee_bps(&test.admin, &market_id, &0),
+        Err(Ok(crate::errors::Error::BetsAlreadyPlaced))
+    );
+}
 
-    // 2. Advance time
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
-
-    test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + 1,
-        protocol_version: 22,
-        sequence_number: test.env.ledger().sequence(),
-        network_id: Default::default(),
-        base_reserve: 10,
-        min_temp_entry_ttl: 1,
-        min_persistent_entry_ttl: 1,
-        max_entry_ttl: 10000,
-    });
+/// A market with an exempt and a normal winner of 100M each against a 200M loser,
+/// resolved and past its dispute window. Returns (market_id, exempt, normal).
+fn fee_exempt_market(test: &PredictifyTest) -> (Symbol, Address, Address) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let exempt = test.create_funded_user();
+    let normal = test.create_funded_user();
+    let loser = test.create_funded_user();
 
-    // 3. Resolve market
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.add_fee_exempt(&test.admin, &exempt);
+    let yes = String::from_str(&test.env, "yes");
+    client.place_bet(&exempt, &market_id, &yes, &100_000_000, &None);
+    client.place_bet(&normal, &market_id, &yes, &100_000_000, &None);
+    client.place_bet(&loser, &market_id, &String::from_str(&test.env, "no"), &200_000_000, &None);
 
-    // 4. First claim
-    test.env.mock_all_auths();
-    client.claim_winnings(&test.user, &market_id);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    test.advance_past_payout_unlock(&market_id);
+    (market_id, exempt, normal)
+}
+
+#[test]
+fn test_fee_exempt_winner_keeps_platform_fee_share() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, exempt, normal) = fee_exempt_market(&test);
+    assert!(client.is_fee_exempt(&exempt));
+    assert!(!client.is_fee_exempt(&normal));
+    assert_eq!(client.get_market_fees(&market_id), (8_000_000, 0));
+
+    // 400M pool split evenly; only the normal winner pays the 2% platform fee
+    assert_eq!(client.claim_winnings(&normal, &market_id), 196_000_000);
+    assert_eq!(client.claim_winnings(&exempt, &market_id), 200_000_000);
+    assert_eq!(
+        client.get_balance(&normal, &ReflectorAsset::Stellar).amount,
+        196_000_000
+    );
+    assert_eq!(
+        client.get_balance(&exempt, &ReflectorAsset::Stellar).amount,
+        200_000_000
+    );
+
+    // Only the normal winner's share of the fee is collected
+    assert_eq!(client.get_market_fees(&market_id), (4_000_000, 0));
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 4_000_000);
+    assert_eq!(client.get_fee_vault_balance(&None), 4_000_000);
+}
+
+#[test]
+fn test_fee_exempt_claim_after_fee_collection_debits_vault() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let (market_id, exempt, normal) = fee_exempt_market(&test);
+
+    assert_eq!(client.collect_fees(&test.admin, &market_id), 8_000_000);
+    assert_eq!(client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE).0, 396_000_000);
+    assert_eq!(
+        client.get_balance(&exempt, &ReflectorAsset::Stellar).amount,
+        200_000_000
+    );
+    assert_eq!(
+        client.get_balance(&normal, &ReflectorAsset::Stellar).amount,
+        196_000_000
+    );
+    assert_eq!(client.get_market_fees(&market_id), (4_000_000, 4_000_000));
+    assert_eq!(client.get_fee_vault_balance(&None), 4_000_000);
+}
+
+#[test]
+fn test_fee_exempt_list_admin_only() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let partner = Address::generate(&test.env);
 
-    // 5. Try to claim again (should panic with AlreadyClaimed)
     test.env.mock_all_auths();
-    client.claim_winnings(&test.user, &market_id);
+    assert_eq!(
+        client.try_add_fee_exempt(&test.user, &partner),
+        Err(Ok(crate::errors::Error::Unauthorized))
+    );
+    client.add_fee_exempt(&test.admin, &partner);
+    assert!(client.is_fee_exempt(&partner));
+    assert_eq!(
+        client.try_remove_fee_exempt(&test.user, &partner),
+        Err(Ok(crate::errors::Error::Unauthorized))
+    );
+    client.remove_fee_exempt(&test.admin, &partner);
+    assert!(!client.is_fee_exempt(&partner));
 }
 
 #[test]
-fn test_claim_by_loser() {
+fn test_preview_payout_matches_claimed_payout() {
     let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    let scenarios: [[(i128, &str); 4]; 3] = [
+        [
+            (10_000_000, "yes"),
+            (33_000_000, "yes"),
+            (17_000_000, "no"),
+            (25_000_000, "yes"),
+        ],
+        [
+            (10_000_000, "no"),
+            (10_000_000, "no"),
+            (70_000_000, "no"),
+            (13_000_000, "yes"),
+        ],
+        [
+            (99_000_000, "yes"),
+            (11_000_000, "no"),
+            (12_000_000, "no"),
+            (10_000_000, "no"),
+        ],
+    ];
+
+    for stakes in scenarios.iter() {
+        let market_id = test.create_test_market();
+        let mut users = Vec::new(&test.env);
+        test.env.mock_all_auths();
+        for (amount, outcome) in stakes.iter() {
+            let user = test.create_funded_user();
+            client.place_bet(
+                &user,
+                &market_id,
+                &String::from_str(&test.env, outcome),
+                amount,
+                &None,
+            );
+            users.push_back(user);
+        }
+
+        let mut previews = Vec::new(&test.env);
+        for user in users.iter() {
+            let if_yes = client.preview_payout(&user, &market_id, &yes);
+            let if_no = client.preview_payout(&user, &market_id, &no);
+            // Every position pays out under exactly one hypothetical
+            assert!((if_yes > 0) != (if_no > 0));
+            previews.push_back(if_yes);
+        }
+
+        resolve_market_without_distribution(&test, &market_id, "yes");
+        test.env.mock_all_auths();
+        for (user, preview) in users.iter().zip(previews.iter()) {
+            assert_eq!(client.claim_winnings(&user, &market_id), preview);
+        }
+    }
+
     let market_id = test.create_test_market();
+    assert_eq!(client.preview_payout(&test.user, &market_id, &yes), 0);
+    assert_eq!(
+        client.try_preview_payout(&test.user, &market_id, &String::from_str(&test.env, "maybe")),
+        Err(Ok(Error::InvalidOutcome))
+    );
+}
+
+// ===== USER POSITION TESTS =====
+
+#[test]
+fn test_get_user_position_through_resolution() {
+    let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let winner = test.create_funded_user();
+    let loser = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    assert_eq!(client.get_user_position(&winner, &market_id), None);
 
-    // 1. User votes for losing outcome
     test.env.mock_all_auths();
-    client.vote(
-        &test.user,
-        &market_id,
-        &String::from_str(&test.env, "no"),
-        &100_0000000,
-    );
+    client.place_bet(&winner, &market_id, &yes, &100_0000000, &None);
+    client.place_bet(&loser, &market_id, &no, &100_0000000, &None);
 
-    // 2. Advance time
-    let market = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
-    });
+    let position = client.get_user_position(&winner, &market_id).unwrap();
+    assert_eq!(position.market_id, market_id);
+    assert_eq!(position.stakes.len(), 1);
+    assert_eq!(position.stakes.get(yes.clone()), Some(100_0000000));
+    assert!(!position.resolved);
+    assert!(!position.claimed);
+    assert_eq!(position.claimable, 0);
 
+    let market = client.get_market(&market_id).unwrap();
     test.env.ledger().set(LedgerInfo {
         timestamp: market.end_time + 1,
         protocol_version: 22,
@@ -2807,42 +5022,35 @@ fn test_claim_by_loser() {
         min_persistent_entry_ttl: 1,
         max_entry_ttl: 10000,
     });
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
 
-    // 3. Resolve market with "yes" as winner (user voted "no", so they lose)
-    test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    // Pool 200 XLM, winning stake 100 XLM, 2% fee
+    let position = client.get_user_position(&winner, &market_id).unwrap();
+    assert!(position.resolved);
+    assert_eq!(position.claimable, 196_0000000);
 
-    // 4. Loser claims - should complete without panic but receive 0 (or minimal) and be marked claimed
-    test.env.mock_all_auths();
-    client.claim_winnings(&test.user, &market_id);
+    let position = client.get_user_position(&loser, &market_id).unwrap();
+    assert!(position.resolved);
+    assert_eq!(position.stakes.get(no), Some(100_0000000));
+    assert_eq!(position.claimable, 0);
 
-    // 5. Verify loser was marked as claimed (prevents re-entry) and did not get winnings
-    let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(&market_id)
-            .unwrap()
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + market.dispute_window_seconds + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
     });
-    assert!(market_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert_eq!(client.claim_winnings(&winner, &market_id), 196_0000000);
+
+    let position = client.get_user_position(&winner, &market_id).unwrap();
+    assert!(position.claimed);
+    assert_eq!(position.claimable, 0);
 }
 
-fn resolve_market_without_distribution(
-    test: &PredictifyTest,
-    market_id: &Symbol,
-    winning_outcome: &str,
-) {
-    test.env.as_contract(&test.contract_id, || {
-        let mut market = test
-            .env
-            .storage()
-            .persistent()
-            .get::<Symbol, Market>(market_id)
-            .unwrap();
-        let winners = vec![&test.env, String::from_str(&test.env, winning_outcome)];
-        market.winning_outcomes = Some(winners);
-        market.state = MarketState::Resolved;
-        test.env.storage().persistent().set(market_id, &market);
 // ===== BATCH CLAIM WINNINGS TESTS =====
 
 #[test]
@@ -2910,7 +5118,7 @@ fn test_claim_winnings_batch_successful() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
 
@@ -2946,36 +5154,48 @@ fn test_claim_winnings_batch_successful() {
         &String::from_str(&test.env, "yes"),
     );
 
+    // All three resolved together, so they unlock together
+    test.advance_past_payout_unlock(&market_id_1);
+    test.env.mock_all_auths();
+    client.claim_winnings_batch(
+        &test.user,
+        &vec![
+            &test.env,
+            market_id_1.clone(),
+            market_id_2.clone(),
+            market_id_3.clone(),
+        ],
+    );
+
     let market_1 = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
-    assert!(market_1.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id_1, &test.user));
 
     let market_2 = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_2)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_2.clone()))
             .unwrap()
     });
-    assert!(market_2.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id_2, &test.user));
 
     let market_3 = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_3)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_3.clone()))
             .unwrap()
     });
-    assert!(market_3.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id_3, &test.user));
 }
 
 #[test]
-#[should_panic]
 fn test_batch_claim_prevent_double_claim() {
     let test = PredictifyTest::setup();
     let market_id_1 = test.create_test_market();
@@ -3005,7 +5225,7 @@ fn test_batch_claim_prevent_double_claim() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
 
@@ -3026,15 +5246,19 @@ fn test_batch_claim_prevent_double_claim() {
         &market_id_1,
         &String::from_str(&test.env, "yes"),
     );
+    test.advance_past_payout_unlock(&market_id_1);
+    client.finalize_after_window(&market_id_1);
 
     let market_ids = vec![&test.env, market_id_1.clone()];
 
     test.env.mock_all_auths();
-    client.claim_winnings_batch(&test.user, &market_ids);
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::AlreadyClaimed))
+    );
 }
 
 #[test]
-#[should_panic]
 fn test_batch_claim_market_not_found() {
     let test = PredictifyTest::setup();
     let market_id_1 = test.create_test_market();
@@ -3053,7 +5277,7 @@ fn test_batch_claim_market_not_found() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
 
@@ -3071,11 +5295,13 @@ fn test_batch_claim_market_not_found() {
     let market_ids = vec![&test.env, market_id_1.clone(), nonexistent_market];
 
     test.env.mock_all_auths();
-    client.claim_winnings_batch(&test.user, &market_ids);
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::MarketNotResolved))
+    );
 }
 
 #[test]
-#[should_panic]
 fn test_batch_claim_market_not_resolved() {
     let test = PredictifyTest::setup();
     let market_id_1 = test.create_test_market();
@@ -3094,7 +5320,7 @@ fn test_batch_claim_market_not_resolved() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -3111,11 +5337,13 @@ fn test_batch_claim_market_not_resolved() {
     let market_ids = vec![&test.env, market_id_1.clone()];
 
     test.env.mock_all_auths();
-    client.claim_winnings_batch(&test.user, &market_ids);
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::MarketNotResolved))
+    );
 }
 
 #[test]
-#[should_panic]
 fn test_batch_claim_user_did_not_vote() {
     let test = PredictifyTest::setup();
     let market_id_1 = test.create_test_market();
@@ -3137,7 +5365,7 @@ fn test_batch_claim_user_did_not_vote() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
 
@@ -3162,11 +5390,13 @@ fn test_batch_claim_user_did_not_vote() {
     let market_ids = vec![&test.env, market_id_1.clone()];
 
     test.env.mock_all_auths();
-    client.claim_winnings_batch(&test.user, &market_ids);
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::NothingToClaim))
+    );
 }
 
 #[test]
-#[should_panic]
 fn test_batch_claim_empty_markets() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
@@ -3174,7 +5404,49 @@ fn test_batch_claim_empty_markets() {
     let market_ids: Vec<Symbol> = Vec::new(&test.env);
 
     test.env.mock_all_auths();
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn test_batch_claim_waits_for_payout_unlock_and_open_disputes() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+    let loser = test.create_funded_user();
+
+    test.env.mock_all_auths();
+    client.place_bet(&test.user, &market_id, &yes, &100_0000000, &None);
+    client.place_bet(&loser, &market_id, &no, &100_0000000, &None);
+    let end_time = client.get_market(&market_id).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &market_id, &yes);
+    let market_ids = vec![&test.env, market_id.clone()];
+
+    // Inside the dispute window payouts are still locked
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // An open result dispute keeps them locked past the window
+    client.dispute_result(&loser, &market_id, &no, &10_000_000);
+    test.advance_past_payout_unlock(&market_id);
+    assert_eq!(
+        client.try_claim_winnings_batch(&test.user, &market_ids),
+        Err(Ok(Error::InvalidState))
+    );
+    assert!(!client.has_claimed(&market_id, &test.user));
+
+    // Once the dispute is rejected and the appeal window closes, the batch pays out
+    client.resolve_dispute(&test.admin, &market_id, &yes, &false);
+    test.advance_past_payout_unlock(&market_id);
     client.claim_winnings_batch(&test.user, &market_ids);
+    assert!(client.has_claimed(&market_id, &test.user));
 }
 
 #[test]
@@ -3207,7 +5479,7 @@ fn test_batch_claim_partial_winners_losers() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
 
@@ -3228,15 +5500,17 @@ fn test_batch_claim_partial_winners_losers() {
         &market_id_1,
         &String::from_str(&test.env, "yes"),
     );
+    test.advance_past_payout_unlock(&market_id_1);
+    client.finalize_after_window(&market_id_1);
 
     let m1 = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
-    assert!(m1.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id_1, &test.user));
 }
 
 #[test]
@@ -3269,7 +5543,7 @@ fn test_batch_claim_atomicity_revert_on_second_market_failure() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
 
@@ -3290,15 +5564,17 @@ fn test_batch_claim_atomicity_revert_on_second_market_failure() {
         &market_id_1,
         &String::from_str(&test.env, "yes"),
     );
+    test.advance_past_payout_unlock(&market_id_1);
+    client.finalize_after_window(&market_id_1);
 
     let m1_after = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id_1)
+            .get::<DataKey, Market>(&DataKey::Market(market_id_1.clone()))
             .unwrap()
     });
-    assert!(m1_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id_1, &test.user));
 }
 
 // ===== MINIMUM POOL SIZE TESTS =====
@@ -3325,9 +5601,12 @@ fn test_create_market_without_min_pool_size() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 10000000,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -3337,7 +5616,7 @@ fn test_create_market_without_min_pool_size() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -3466,8 +5745,8 @@ fn test_batch_claim_winnings_all_succeed_in_one_batch() {
 
     let market_a_after = client.get_market(&market_a).unwrap();
     let market_b_after = client.get_market(&market_b).unwrap();
-    assert!(market_a_after.claimed.get(test.user.clone()).unwrap_or(false));
-    assert!(market_b_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_a, &test.user));
+    assert!(test.is_claimed(&market_b, &test.user));
 }
 
 #[test]
@@ -3504,7 +5783,7 @@ fn test_batch_claim_winnings_atomic_revert_when_one_claim_invalid() {
 
     // Ensure successful claim in the same batch was rolled back.
     let market_after = client.get_market(&valid_market).unwrap();
-    assert!(!market_after.claimed.get(test.user.clone()).unwrap_or(false));
+    assert!(!test.is_claimed(&valid_market, &test.user));
 }
 
 #[test]
@@ -3589,18 +5868,127 @@ fn test_batch_claim_winnings_rejects_empty_batch() {
 }
 
 #[test]
-fn test_batch_claim_winnings_rejects_batch_above_max_size() {
+fn test_batch_claim_winnings_rejects_batch_above_max_size() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let mut oversized = Vec::new(&test.env);
+    for _ in 0..51 {
+        oversized.push_back(Symbol::new(&test.env, "nonexistent"));
+    }
+
+    test.env.mock_all_auths();
+    let result = client.try_batch_claim_winnings(&test.user, &oversized);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_claim_all_skips_unclaimable_markets() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let other = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    let claimable = test.create_test_market();
+    let claimed = test.create_test_market();
+    let unresolved = test.create_test_market();
+    let not_joined = test.create_test_market();
+    test.env.mock_all_auths();
+    for market in [&claimable, &claimed, &unresolved] {
+        client.place_bet(&test.user, market, &yes, &10_000_000, &None);
+        client.place_bet(&other, market, &no, &10_000_000, &None);
+    }
+    client.place_bet(&other, &not_joined, &no, &10_000_000, &None);
+    for market in [&claimable, &claimed, &not_joined] {
+        resolve_market_without_distribution(&test, market, "yes");
+    }
+    let already_paid = client.claim_winnings(&test.user, &claimed);
+
+    let market_ids = vec![
+        &test.env,
+        claimable.clone(),
+        claimed.clone(),
+        unresolved.clone(),
+        not_joined.clone(),
+    ];
+    let total = client.claim_all(&test.user, &market_ids);
+    // Pool 2 XLM, winning stake 1 XLM, 2% fee
+    assert_eq!(total, 19_600_000);
+    assert_eq!(total, already_paid);
+
+    let mut results = Vec::new(&test.env);
+    for (_, topics, data) in test.env.events().all().iter() {
+        let topic: Symbol = topics.get(0).unwrap().try_into_val(&test.env).unwrap();
+        if topic == symbol_short!("claim_res") {
+            let event: ClaimResultEvent = data.try_into_val(&test.env).unwrap();
+            results.push_back((event.market_id, event.amount, event.error_code));
+        }
+    }
+    assert_eq!(
+        results,
+        vec![
+            &test.env,
+            (claimable.clone(), 19_600_000, 0),
+            (claimed, 0, Error::AlreadyClaimed as u32),
+            (unresolved, 0, Error::MarketNotResolved as u32),
+            (not_joined, 0, Error::NothingToClaim as u32),
+        ]
+    );
+    assert!(client.has_claimed(&claimable, &test.user));
+}
+
+#[test]
+fn test_claim_all_reverts_when_a_started_claim_fails() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let other = test.create_funded_user();
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    let first = test.create_test_market();
+    let second = test.create_test_market();
+    test.env.mock_all_auths();
+    for market in [&first, &second] {
+        client.place_bet(&test.user, market, &yes, &10_000_000, &None);
+        client.place_bet(&other, market, &no, &10_000_000, &None);
+        resolve_market_without_distribution(&test, market, "yes");
+    }
+
+    // The second market passes every check but its escrow cannot cover the payout
+    test.env.as_contract(&test.contract_id, || {
+        let held = crate::escrow::EscrowManager::get(&test.env, &second);
+        crate::escrow::EscrowManager::debit(&test.env, &second, held).unwrap();
+    });
+    let market_ids = vec![&test.env, first.clone(), second.clone()];
+    assert_eq!(
+        client.try_claim_all(&test.user, &market_ids),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // Nothing from the batch sticks, so the first market can still be claimed
+    for market in [&first, &second] {
+        assert!(!client.has_claimed(market, &test.user));
+    }
+    assert_eq!(client.claim_winnings(&test.user, &first), 19_600_000);
+}
+
+#[test]
+fn test_claim_all_rejects_batch_above_max_size() {
     let test = PredictifyTest::setup();
     let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
 
-    let mut oversized = Vec::new(&test.env);
-    for _ in 0..51 {
-        oversized.push_back(Symbol::new(&test.env, "nonexistent"));
+    let mut market_ids = Vec::new(&test.env);
+    for _ in 0..=MAX_CLAIM_ALL_MARKETS {
+        market_ids.push_back(market_id.clone());
     }
-
     test.env.mock_all_auths();
-    let result = client.try_batch_claim_winnings(&test.user, &oversized);
-    assert!(result.is_err());
+    assert_eq!(
+        client.try_claim_all(&test.user, &market_ids),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(client.claim_all(&test.user, &Vec::new(&test.env)), 0);
 }
 
 // ===== MINIMUM POOL SIZE TESTS =====
@@ -3629,9 +6017,12 @@ fn test_resolution_allowed_when_pool_exactly_at_min_pool_size() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(min_pool),
         &None,
         &None,
@@ -3645,7 +6036,11 @@ fn test_resolution_allowed_when_pool_exactly_at_min_pool_size() {
     client.vote(&user2, &market_id, &String::from_str(&test.env, "no"), &200_0000000);
 
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -3664,11 +6059,11 @@ fn test_resolution_allowed_when_pool_exactly_at_min_pool_size() {
             .env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     let result = test.env.as_contract(&test.contract_id, || {
@@ -3711,9 +6106,12 @@ fn test_resolution_blocked_and_emits_event_when_pool_below_min_pool_size() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(min_pool),
         &None,
         &None,
@@ -3725,7 +6123,11 @@ fn test_resolution_blocked_and_emits_event_when_pool_below_min_pool_size() {
     client.vote(&user1, &market_id, &String::from_str(&test.env, "yes"), &100_0000000);
 
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -3744,11 +6146,11 @@ fn test_resolution_blocked_and_emits_event_when_pool_below_min_pool_size() {
             .env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     let result = test.env.as_contract(&test.contract_id, || {
@@ -3780,7 +6182,11 @@ fn test_resolution_blocked_and_emits_event_when_pool_below_min_pool_size() {
     assert!(st_chng_event.is_none());
 
     let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     assert!(market_after.winning_outcomes.is_none());
 }
@@ -3808,16 +6214,23 @@ fn test_resolution_allowed_when_min_pool_size_is_zero() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(0),
         &None,
         &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -3836,11 +6249,11 @@ fn test_resolution_allowed_when_min_pool_size_is_zero() {
             .env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     let result = test.env.as_contract(&test.contract_id, || {
@@ -3865,13 +6278,14 @@ fn test_global_min_pool_blocks_resolution_when_market_min_not_set() {
         &market_id,
         &String::from_str(&test.env, "yes"),
         &100_0000000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -3886,10 +6300,14 @@ fn test_global_min_pool_blocks_resolution_when_market_min_not_set() {
     });
 
     test.env.as_contract(&test.contract_id, || {
-        let mut m: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut m: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     let result = test.env.as_contract(&test.contract_id, || {
@@ -3937,16 +6355,23 @@ fn test_per_market_min_pool_overrides_global_min_pool() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(0),
         &None,
         &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
         timestamp: market.end_time + market.dispute_window_seconds + 1,
@@ -3960,10 +6385,14 @@ fn test_per_market_min_pool_overrides_global_min_pool() {
     });
 
     test.env.as_contract(&test.contract_id, || {
-        let mut m: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut m: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     let result = test.env.as_contract(&test.contract_id, || {
@@ -3989,13 +6418,14 @@ fn test_cancel_underfunded_event_uses_global_min_pool_when_market_min_not_set()
         &market_id,
         &String::from_str(&test.env, "yes"),
         &100_0000000,
+        &None,
     );
 
     let market = test.env.as_contract(&test.contract_id, || {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
@@ -4010,10 +6440,14 @@ fn test_cancel_underfunded_event_uses_global_min_pool_when_market_min_not_set()
     });
 
     test.env.as_contract(&test.contract_id, || {
-        let mut m: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut m: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     test.env.mock_all_auths();
@@ -4034,6 +6468,124 @@ fn test_cancel_underfunded_event_uses_global_min_pool_when_market_min_not_set()
     assert_eq!(pool_lo_event.required_min, 500_0000000);
 }
 
+/// Yes/no market with a 500 XLM minimum pool and `yes_stake`/`no_stake` bets,
+/// advanced past its end time. Returns (market_id, [(bettor, balance before bet)]).
+fn underfunded_market_setup(
+    test: &PredictifyTest,
+    yes_stake: i128,
+    no_stake: i128,
+) -> (Symbol, [(Address, i128); 2]) {
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = soroban_sdk::token::Client::new(&test.env, &test.token_test.token_id);
+    let outcomes = vec![
+        &test.env,
+        String::from_str(&test.env, "yes"),
+        String::from_str(&test.env, "no"),
+    ];
+
+    test.env.mock_all_auths();
+    let market_id = client.create_market(
+        &test.admin,
+        &String::from_str(&test.env, "Will the pool reach its minimum?"),
+        &outcomes,
+        &30,
+        &OracleConfig {
+            provider: OracleProvider::Reflector,
+            oracle_address: Address::generate(&test.env),
+            feed_id: String::from_str(&test.env, "TEST"),
+            threshold: 100,
+            comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
+        },
+        &None,
+        &86400,
+        &Some(500_0000000),
+        &None,
+        &None,
+    );
+
+    let yes_user = test.create_funded_user();
+    let no_user = test.create_funded_user();
+    let bettors = [
+        (yes_user.clone(), token_client.balance(&yes_user)),
+        (no_user.clone(), token_client.balance(&no_user)),
+    ];
+    test.env.mock_all_auths();
+    client.place_bet(&yes_user, &market_id, &String::from_str(&test.env, "yes"), &yes_stake, &None);
+    client.place_bet(&no_user, &market_id, &String::from_str(&test.env, "no"), &no_stake, &None);
+
+    let market = client.get_market(&market_id).unwrap();
+    test.env.ledger().set(LedgerInfo {
+        timestamp: market.end_time + 1,
+        protocol_version: 22,
+        sequence_number: test.env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 1,
+        min_persistent_entry_ttl: 1,
+        max_entry_ttl: 10000,
+    });
+    (market_id, bettors)
+}
+
+#[test]
+fn test_void_market_below_min_pool_refunds_without_admin() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_client = soroban_sdk::token::Client::new(&test.env, &test.token_test.token_id);
+
+    // 100 + 150 XLM across both outcome pools, below the 500 XLM minimum
+    let (market_id, bettors) = underfunded_market_setup(&test, 100_0000000, 150_0000000);
+
+    test.env.mock_all_auths();
+    assert_eq!(
+        client.try_resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes")),
+        Err(Ok(Error::InvalidState))
+    );
+
+    // No authorization is required to void
+    test.env.set_auths(&[]);
+    assert_eq!(client.void_market(&market_id), 250_0000000);
+    assert_eq!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Voided
+    );
+
+    // Stakes come back a page at a time
+    let (first, cursor) = client.process_refunds(&market_id, &0, &1);
+    assert_eq!(cursor, 1);
+    let (second, cursor) = client.process_refunds(&market_id, &cursor, &1);
+    assert_eq!(cursor, crate::PAYOUT_CURSOR_DONE);
+    assert_eq!(first + second, 250_0000000);
+    for (user, before) in bettors.iter() {
+        assert_eq!(token_client.balance(user), *before);
+    }
+    assert_eq!(token_client.balance(&test.contract_id), 0);
+
+    // Idempotent once voided
+    assert_eq!(client.void_market(&market_id), 0);
+}
+
+#[test]
+fn test_void_market_rejects_market_meeting_min_pool() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    // 300 + 200 XLM across both outcome pools meets the 500 XLM minimum
+    let (market_id, _) = underfunded_market_setup(&test, 300_0000000, 200_0000000);
+
+    assert_eq!(client.try_void_market(&market_id), Err(Ok(Error::InvalidState)));
+    assert_ne!(
+        client.get_market(&market_id).unwrap().state,
+        MarketState::Cancelled
+    );
+
+    test.env.mock_all_auths();
+    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+}
+
 #[test]
 fn test_create_market_with_min_pool_size() {
     let test = PredictifyTest::setup();
@@ -4059,9 +6611,12 @@ fn test_create_market_with_min_pool_size() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(500_0000000), // 500 XLM min pool
         &None,
         &None,
@@ -4084,7 +6639,11 @@ fn test_create_market_with_min_pool_size() {
 
     // Advance time past market end AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -4100,33 +6659,41 @@ fn test_create_market_with_min_pool_size() {
 
     // Manually resolve with tied outcomes
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
             String::from_str(&test.env, "outcome_a"),
             String::from_str(&test.env, "outcome_b"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert!(total_distributed > 0);
 
     // Verify each user gets approximately their stake back (minus fees)
     // Total pool: 400 XLM, All are winners (200 XLM winning stakes)
     // Each should get back their stake proportionally
     let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     // All users should be marked as claimed
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user3.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user4.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id, &user1));
+    assert!(test.is_claimed(&market_id, &user2));
+    assert!(test.is_claimed(&market_id, &user3));
+    assert!(test.is_claimed(&market_id, &user4));
 
     // Check balances - each should get ~196 XLM (200 * 0.98 = 196 after 2% fee)
     let balance1 = test.env.as_contract(&test.contract_id, || {
@@ -4166,9 +6733,12 @@ fn test_resolution_blocked_when_pool_below_minimum() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(500_0000000), // 500 XLM minimum
         &None,
         &None,
@@ -4192,7 +6762,11 @@ fn test_resolution_blocked_when_pool_below_minimum() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -4208,19 +6782,23 @@ fn test_resolution_blocked_when_pool_below_minimum() {
 
     // Resolve with tie
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
             String::from_str(&test.env, "yes"),
             String::from_str(&test.env, "no"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert!(total_distributed > 0);
 
     // Verify proportional payouts
@@ -4269,9 +6847,12 @@ fn test_multi_outcome_tie_three_way() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -4300,7 +6881,11 @@ fn test_multi_outcome_tie_three_way() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -4316,7 +6901,11 @@ fn test_multi_outcome_tie_three_way() {
 
     // Resolve with 3-way tie
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
@@ -4324,25 +6913,29 @@ fn test_multi_outcome_tie_three_way() {
             String::from_str(&test.env, "outcome_b"),
             String::from_str(&test.env, "outcome_c"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert!(total_distributed > 0);
 
     // Verify all users are marked as claimed
     let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user3.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user4.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user5.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user6.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id, &user1));
+    assert!(test.is_claimed(&market_id, &user2));
+    assert!(test.is_claimed(&market_id, &user3));
+    assert!(test.is_claimed(&market_id, &user4));
+    assert!(test.is_claimed(&market_id, &user5));
+    assert!(test.is_claimed(&market_id, &user6));
 
     // Verify proportional payouts: total pool 600 XLM, all 600 are winning stakes
     // Each user: (100 / 600) * 600 * 0.98 = 98 XLM
@@ -4386,9 +6979,12 @@ fn test_proportional_share_different_stakes() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -4412,7 +7008,11 @@ fn test_proportional_share_different_stakes() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -4428,19 +7028,23 @@ fn test_proportional_share_different_stakes() {
 
     // Resolve with tie
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
             String::from_str(&test.env, "yes"),
             String::from_str(&test.env, "no"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert!(total_distributed > 0);
 
     // Verify proportional payouts
@@ -4491,9 +7095,12 @@ fn test_no_dust_left_after_tie_payout() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(10_0000000), // 10 XLM minimum
         &None,
         &None,
@@ -4511,7 +7118,11 @@ fn test_no_dust_left_after_tie_payout() {
     client.vote(&user3, &market_id, &String::from_str(&test.env, "cc"), &333_3333334); // Slightly different
 
     let market_before = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
     let total_pool = market_before.total_staked;
 
@@ -4529,7 +7140,11 @@ fn test_no_dust_left_after_tie_payout() {
 
     // Resolve with 3-way tie
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
@@ -4537,12 +7152,12 @@ fn test_no_dust_left_after_tie_payout() {
             String::from_str(&test.env, "bb"),
             String::from_str(&test.env, "cc"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
 
     // Calculate total received by users
     let balance1 = test.env.as_contract(&test.contract_id, || {
@@ -4603,9 +7218,12 @@ fn test_claim_flow_for_tie_winners() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -4620,7 +7238,11 @@ fn test_claim_flow_for_tie_winners() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -4636,34 +7258,46 @@ fn test_claim_flow_for_tie_winners() {
 
     // Verify users are not claimed before resolution
     let market_before_resolve = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
-    assert!(!market_before_resolve.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(!market_before_resolve.claimed.get(user2.clone()).unwrap_or(false));
+    assert!(!test.is_claimed(&market_id, &user1));
+    assert!(!test.is_claimed(&market_id, &user2));
 
     // Resolve with tie
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
             String::from_str(&test.env, "xx"),
             String::from_str(&test.env, "yy"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert!(total_distributed > 0);
 
     // Verify both users are marked as claimed
     let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
-    assert!(market_after.claimed.get(user1.clone()).unwrap_or(false));
-    assert!(market_after.claimed.get(user2.clone()).unwrap_or(false));
+    assert!(test.is_claimed(&market_id, &user1));
+    assert!(test.is_claimed(&market_id, &user2));
 
     // Verify both received payouts
     let balance1 = test.env.as_contract(&test.contract_id, || {
@@ -4704,9 +7338,12 @@ fn test_edge_case_single_winner_not_tie() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -4721,7 +7358,11 @@ fn test_edge_case_single_winner_not_tie() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -4741,11 +7382,11 @@ fn test_edge_case_single_winner_not_tie() {
             .env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "yes"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     // Resolution should succeed (no min pool constraint)
@@ -5243,9 +7884,12 @@ fn test_cancel_underfunded_event() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &Some(500_0000000), // 500 XLM minimum
         &None,
         &None,
@@ -5262,7 +7906,11 @@ fn test_cancel_underfunded_event() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -5278,10 +7926,14 @@ fn test_cancel_underfunded_event() {
 
     // Mark market as ended and set an oracle result so it is resolvable (but underfunded)
     test.env.as_contract(&test.contract_id, || {
-        let mut m: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut m: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         m.oracle_result = Some(String::from_str(&test.env, "unanimous"));
         m.state = MarketState::Ended;
-        test.env.storage().persistent().set(&market_id, &m);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &m);
     });
 
     // Non-admin cannot cancel until timeout
@@ -5319,14 +7971,18 @@ fn test_cancel_underfunded_event() {
     });
     assert_eq!(st_chng_event.market_id, market_id);
     assert_eq!(st_chng_event.old_state, MarketState::Ended);
-    assert_eq!(st_chng_event.new_state, MarketState::Cancelled);
+    assert_eq!(st_chng_event.new_state, MarketState::Voided);
 
     let market_after = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
-    assert_eq!(market_after.state, MarketState::Cancelled);
+    assert_eq!(market_after.state, MarketState::Voided);
 
-    // Idempotent once cancelled
+    // Idempotent once voided
     test.env.mock_all_auths();
     let second = client.cancel_underfunded_event(&test.admin, &market_id);
     assert_eq!(second, 0);
@@ -5357,9 +8013,12 @@ fn test_tie_with_zero_stakers_on_losing_outcome() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -5375,7 +8034,11 @@ fn test_tie_with_zero_stakers_on_losing_outcome() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -5391,19 +8054,23 @@ fn test_tie_with_zero_stakers_on_losing_outcome() {
 
     // Resolve with tie between a and b (c has no stakers)
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
             String::from_str(&test.env, "aa"),
             String::from_str(&test.env, "bb"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     assert!(total_distributed > 0);
 
     // Both users should be paid correctly
@@ -5443,9 +8110,12 @@ fn test_tie_with_very_small_stakes() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         },
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -5461,7 +8131,11 @@ fn test_tie_with_very_small_stakes() {
 
     // Advance time past end_time AND dispute window
     let market = test.env.as_contract(&test.contract_id, || {
-        test.env.storage().persistent().get::<Symbol, Market>(&market_id).unwrap()
+        test.env
+            .storage()
+            .persistent()
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
+            .unwrap()
     });
 
     test.env.ledger().set(LedgerInfo {
@@ -5477,19 +8151,23 @@ fn test_tie_with_very_small_stakes() {
 
     // Resolve with tie
     test.env.as_contract(&test.contract_id, || {
-        let mut market: Market = test.env.storage().persistent().get(&market_id).unwrap();
+        let mut market: Market = test.env
+            .storage()
+            .persistent()
+            .get(&DataKey::Market(market_id.clone()))
+            .unwrap();
         market.state = MarketState::Resolved;
         market.winning_outcomes = Some(vec![
             &test.env,
             String::from_str(&test.env, "opt1"),
             String::from_str(&test.env, "opt2"),
         ]);
-        test.env.storage().persistent().set(&market_id, &market);
+        test.env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
     });
 
     // Distribute payouts
     test.env.mock_all_auths();
-    let total_distributed = client.distribute_payouts(&market_id);
+    let (total_distributed, _) = client.distribute_payouts(&market_id, &0, &MAX_PAYOUT_PAGE_SIZE);
     
     // Even with very small stakes, payout should work
     assert!(total_distributed >= 0);
@@ -5524,6 +8202,9 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
         feed_id: String::from_str(&test.env, "BTC"),
         threshold: 1000,
         comparison: String::from_str(&test.env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     let duration_days = 30;
@@ -5537,7 +8218,7 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
         &duration_days,
         &oracle_config,
         &None,
-        &0,
+        &86400,
         &None,
         &None,
         &None,
@@ -5557,7 +8238,7 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
         test.env
             .storage()
             .persistent()
-            .get::<Symbol, Market>(&market_id)
+            .get::<DataKey, Market>(&DataKey::Market(market_id.clone()))
             .unwrap()
     });
 
@@ -5596,3 +8277,173 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
     // Verify the dummy implementation returns 100
     assert!(swept > 0, "Admin should have swept the remaining balance");
 }
+
+#[test]
+fn test_migrate_storage_reads_legacy_keys() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    client.place_bet(&user, &market_id, &String::from_str(&test.env, "yes"), &10_000_000, &None);
+
+    // Rewrite the entries under the keys used before `DataKey`.
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        let instance = test.env.storage().instance();
+        let admin: Address = instance.get(&DataKey::Admin).unwrap();
+        storage.set(&Symbol::new(&test.env, "Admin"), &admin);
+        instance.remove(&DataKey::Admin);
+
+        let token: Address = instance.get(&DataKey::Token).unwrap();
+        storage.set(&Symbol::new(&test.env, "TokenID"), &token);
+        instance.remove(&DataKey::Token);
+
+        let fee_key = Symbol::new(&test.env, "platform_fee");
+        let fee: i128 = instance.get(&fee_key).unwrap();
+        storage.set(&fee_key, &fee);
+        instance.remove(&fee_key);
+
+        let market_key = DataKey::Market(market_id.clone());
+        let market: Market = storage.get(&market_key).unwrap();
+        storage.set(&market_id, &market);
+        storage.remove(&market_key);
+
+        let bet_key = DataKey::Bet(market_id.clone(), user.clone());
+        let bet: crate::types::Bet = storage.get(&bet_key).unwrap();
+        let legacy_bet_key = crate::bets::BetKey {
+            market_id: market_id.clone(),
+            user: user.clone(),
+        };
+        storage.set(&legacy_bet_key, &bet);
+        storage.remove(&bet_key);
+        let legacy_registry_key = crate::bets::BetRegistryKey {
+            tag: Symbol::new(&test.env, "Registry"),
+            market_id: market_id.clone(),
+        };
+        storage.set(&legacy_registry_key, &vec![&test.env, user.clone()]);
+    });
+
+    assert!(client.get_market(&market_id).is_none());
+    assert!(client.get_bet(&market_id, &user).is_none());
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_migrate_storage(&outsider),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let report = client.migrate_storage(&test.admin);
+    assert!(report.moved >= 5);
+    assert!(report.unmigrated_markets.is_empty());
+    assert!(client.is_storage_migrated());
+
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.total_staked, 10_000_000);
+    let bet = client.get_bet(&market_id, &user).unwrap();
+    assert_eq!(bet.amount, 10_000_000);
+    let (token, _, _) = client.get_token_info();
+    assert_eq!(token, test.token_test.token_id);
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        assert!(!storage.has(&Symbol::new(&test.env, "Admin")));
+        assert!(!storage.has(&market_id));
+        assert!(test
+            .env
+            .storage()
+            .instance()
+            .has(&Symbol::new(&test.env, "platform_fee")));
+    });
+
+    // Admin-gated calls see the migrated admin.
+    client.set_platform_fee(&test.admin, &1);
+
+    assert_eq!(
+        client.try_migrate_storage(&test.admin),
+        Err(Ok(Error::InvalidState))
+    );
+}
+
+/// A market entry in the layout written before the `DataKey` switch
+pub(crate) fn baseline_market_fixture(
+    test: &PredictifyTest,
+    end_time: u64,
+) -> storage::LegacyMarketV0 {
+    let env = &test.env;
+    let oracle = storage::LegacyOracleConfigV0 {
+        provider: OracleProvider::Reflector,
+        oracle_address: Address::generate(env),
+        feed_id: String::from_str(env, "BTC"),
+        threshold: 2_500_000,
+        comparison: String::from_str(env, "gt"),
+    };
+    let yes = String::from_str(env, "yes");
+    let no = String::from_str(env, "no");
+    let (alice, bob) = (Address::generate(env), Address::generate(env));
+    storage::LegacyMarketV0 {
+        admin: test.admin.clone(),
+        question: String::from_str(env, "Will BTC go above $25,000?"),
+        outcomes: vec![env, yes.clone(), no.clone()],
+        end_time,
+        oracle_config: oracle.clone(),
+        has_fallback: false,
+        fallback_oracle_config: oracle,
+        resolution_timeout: 86400,
+        oracle_result: None,
+        votes: Map::from_array(env, [(alice.clone(), yes), (bob.clone(), no)]),
+        stakes: Map::from_array(env, [(alice, 10_000_000), (bob, 30_000_000)]),
+        claimed: Map::new(env),
+        total_staked: 40_000_000,
+        dispute_stakes: Map::new(env),
+        winning_outcomes: None,
+        fee_collected: false,
+        state: storage::LegacyMarketStateV0::Active,
+        total_extension_days: 0,
+        max_extension_days: 30,
+        extension_history: Vec::new(env),
+        category: None,
+        tags: Vec::new(env),
+        min_pool_size: None,
+        bet_deadline: 0,
+        dispute_window_seconds: 86400,
+    }
+}
+
+#[test]
+fn test_migrate_storage_converts_baseline_market_layout() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let legacy_id = test.create_test_market();
+    let broken_id = test.create_test_market();
+    let end_time = client.get_market(&legacy_id).unwrap().end_time;
+
+    // Replace the registered markets with a baseline-layout entry and with a
+    // value that is no market at all, both under the bare id
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        storage.remove(&DataKey::Market(legacy_id.clone()));
+        storage.set(&legacy_id, &baseline_market_fixture(&test, end_time));
+        storage.remove(&DataKey::Market(broken_id.clone()));
+        storage.set(&broken_id, &7u32);
+    });
+
+    let report = client.migrate_storage(&test.admin);
+    assert_eq!(report.unmigrated_markets, vec![&test.env, broken_id.clone()]);
+
+    let market = client.get_market(&legacy_id).unwrap();
+    assert_eq!(market.state, MarketState::Active);
+    assert_eq!(market.creator, test.admin);
+    assert_eq!(market.total_staked, 40_000_000);
+    assert_eq!(market.fee_bps, crate::config::DEFAULT_PLATFORM_FEE_BPS);
+    assert_eq!(market.oracle_config.twap_records, None);
+    assert_eq!(market.resolution_deadline, market.compute_resolution_deadline());
+    assert_eq!(market.outcome_pool(&String::from_str(&test.env, "yes")), 10_000_000);
+    assert_eq!(market.outcome_pool(&String::from_str(&test.env, "no")), 30_000_000);
+
+    // The undecodable value is left where it was
+    assert!(client.get_market(&broken_id).is_none());
+    test.env.as_contract(&test.contract_id, || {
+        let storage = test.env.storage().persistent();
+        assert!(!storage.has(&legacy_id));
+        assert_eq!(storage.get::<Symbol, u32>(&broken_id), Some(7));
+    });
+}
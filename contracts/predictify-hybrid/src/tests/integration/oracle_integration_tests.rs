@@ -36,6 +36,9 @@ fn test_successful_oracle_price_fetch_and_resolution() {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 2600000, // $26,000
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         ).unwrap();
 
@@ -84,6 +87,9 @@ fn test_oracle_timeout_market_resolution() {
                 feed_id: String::from_str(&env, "ETH/USD"),
                 threshold: 200000, // $2,000
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         ).unwrap();
 
@@ -146,6 +152,9 @@ fn test_oracle_fallback_mechanism() {
                 feed_id: String::from_str(&env, "XLM/USD"),
                 threshold: 12, // $0.12
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         ).unwrap();
 
@@ -216,6 +225,9 @@ fn test_end_to_end_market_lifecycle_with_oracle() {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 2500000,
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         ).unwrap();
 
@@ -353,6 +365,9 @@ fn test_oracle_failure_recovery_market_resolution() {
                 feed_id: String::from_str(&env, "ADA/USD"),
                 threshold: 50, // $0.50
                 comparison: String::from_str(&env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
         ).unwrap();
 
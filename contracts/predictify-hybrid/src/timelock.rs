@@ -0,0 +1,181 @@
+use soroban_sdk::{Address, Env, Map, Symbol, Vec};
+
+use crate::config::{
+    ADMIN_ACTION_DELAY_STORAGE_KEY, DEFAULT_ADMIN_ACTION_DELAY_SECONDS,
+    MAX_ADMIN_ACTION_DELAY_SECONDS, MAX_PENDING_ACTIONS, NEXT_ACTION_ID_STORAGE_KEY,
+    PENDING_ACTIONS_STORAGE_KEY,
+};
+use crate::errors::Error;
+use crate::events::EventEmitter;
+use crate::multisig::MultisigManager;
+use crate::storage::DataKey;
+use crate::types::{PendingAction, TimelockedAction};
+use crate::upgrade_manager::UpgradeManager;
+
+/// Two-phase timelock for sensitive admin operations.
+///
+/// Token switches, fee increases and upgrades are scheduled by the admin and
+/// only take effect once the admin action delay has passed, so users can see
+/// them coming and exit first. Applying a due action is left to the caller of
+/// `take_due`; emergency operations such as pausing stay immediate.
+pub struct TimelockManager;
+
+impl TimelockManager {
+    /// Seconds between scheduling an action and the earliest time it can be executed.
+    pub fn get_delay(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, ADMIN_ACTION_DELAY_STORAGE_KEY))
+            .unwrap_or(DEFAULT_ADMIN_ACTION_DELAY_SECONDS)
+    }
+
+    /// Raise the admin action delay (admin only), up to `MAX_ADMIN_ACTION_DELAY_SECONDS`.
+    ///
+    /// Lowering it would let a compromised admin key skip the delay, so a lower
+    /// value must itself be scheduled as `TimelockedAction::SetActionDelay`.
+    pub fn set_delay(env: &Env, admin: &Address, seconds: u64) -> Result<(), Error> {
+        Self::require_admin(env, admin)?;
+        if seconds > MAX_ADMIN_ACTION_DELAY_SECONDS || seconds < Self::get_delay(env) {
+            return Err(Error::InvalidInput);
+        }
+        Self::store_delay(env, seconds);
+        Ok(())
+    }
+
+    pub(crate) fn store_delay(env: &Env, seconds: u64) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, ADMIN_ACTION_DELAY_STORAGE_KEY), &seconds);
+    }
+
+    /// Actions waiting to be executed or cancelled, in scheduling order.
+    pub fn get_pending(env: &Env) -> Vec<PendingAction> {
+        Self::pending_map(env).values()
+    }
+
+    /// Schedule `action` (admin only); it can be executed once the admin action
+    /// delay, or for upgrades the longer upgrade timelock, has passed.
+    pub fn schedule(
+        env: &Env,
+        admin: &Address,
+        action: TimelockedAction,
+    ) -> Result<PendingAction, Error> {
+        Self::require_admin(env, admin)?;
+        // Upgrades need the signers' approval once more than one is required
+        if matches!(action, TimelockedAction::Upgrade(_)) {
+            MultisigManager::require_single_signer(env)?;
+        }
+        Self::enqueue(env, admin, action)
+    }
+
+    /// Schedule `action` on behalf of `admin`; callers are responsible for authorization.
+    pub(crate) fn enqueue(
+        env: &Env,
+        admin: &Address,
+        action: TimelockedAction,
+    ) -> Result<PendingAction, Error> {
+        let mut delay = Self::get_delay(env);
+        match &action {
+            TimelockedAction::SetToken(token) => {
+                let token_client = soroban_sdk::token::Client::new(env, token);
+                if !matches!(token_client.try_decimals(), Ok(Ok(_))) {
+                    return Err(Error::InvalidInput);
+                }
+            }
+            TimelockedAction::SetFeeBps(bps) | TimelockedAction::SetMarketFeeBps(_, bps) => {
+                if *bps > crate::config::MAX_PLATFORM_FEE_BPS {
+                    return Err(Error::InvalidFeeConfig);
+                }
+            }
+            TimelockedAction::Upgrade(_) => {
+                delay = delay.max(UpgradeManager::get_upgrade_timelock(env));
+            }
+            TimelockedAction::SetActionDelay(seconds) => {
+                if *seconds > MAX_ADMIN_ACTION_DELAY_SECONDS {
+                    return Err(Error::InvalidInput);
+                }
+            }
+        }
+
+        let mut pending = Self::pending_map(env);
+        if pending.len() >= MAX_PENDING_ACTIONS {
+            return Err(Error::InvalidState);
+        }
+
+        let id_key = Symbol::new(env, NEXT_ACTION_ID_STORAGE_KEY);
+        let id: u64 = env.storage().instance().get(&id_key).unwrap_or(0);
+        env.storage().instance().set(&id_key, &(id + 1));
+
+        let now = env.ledger().timestamp();
+        let scheduled = PendingAction {
+            id,
+            action,
+            scheduled_by: admin.clone(),
+            scheduled_at: now,
+            execute_after: now.saturating_add(delay),
+        };
+        pending.set(id, scheduled.clone());
+        Self::store_pending(env, &pending);
+
+        EventEmitter::emit_action_scheduled(env, admin, &scheduled);
+        Ok(scheduled)
+    }
+
+    /// Drop a pending action (admin only).
+    pub fn cancel(env: &Env, admin: &Address, action_id: u64) -> Result<(), Error> {
+        Self::require_admin(env, admin)?;
+        let mut pending = Self::pending_map(env);
+        let cancelled = pending.get(action_id).ok_or(Error::InvalidInput)?;
+        pending.remove(action_id);
+        Self::store_pending(env, &pending);
+
+        EventEmitter::emit_action_cancelled(env, admin, action_id, &cancelled.action);
+        Ok(())
+    }
+
+    /// Remove and return a pending action whose delay has passed.
+    ///
+    /// The caller applies the action; if that fails the transaction reverts and
+    /// the action stays pending.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::InvalidInput` - No pending action has this id
+    /// - `Error::MarketNotReady` - The delay has not passed yet
+    pub fn take_due(env: &Env, action_id: u64) -> Result<PendingAction, Error> {
+        let mut pending = Self::pending_map(env);
+        let due = pending.get(action_id).ok_or(Error::InvalidInput)?;
+        if env.ledger().timestamp() < due.execute_after {
+            return Err(Error::MarketNotReady);
+        }
+        pending.remove(action_id);
+        Self::store_pending(env, &pending);
+        Ok(due)
+    }
+
+    fn pending_map(env: &Env) -> Map<u64, PendingAction> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, PENDING_ACTIONS_STORAGE_KEY))
+            .unwrap_or(Map::new(env))
+    }
+
+    fn store_pending(env: &Env, pending: &Map<u64, PendingAction>) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, PENDING_ACTIONS_STORAGE_KEY), pending);
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if stored_admin != *admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+//! Admin action timelock tests.
+//!
+//! Token switches, fee increases and upgrades are scheduled with
+//! `schedule_action` and only take effect through `execute_action` once the
+//! admin action delay has passed; `cancel_action` drops them before that.
+
+use crate::config::{
+    DEFAULT_ADMIN_ACTION_DELAY_SECONDS, MAX_ADMIN_ACTION_DELAY_SECONDS, MAX_PENDING_ACTIONS,
+};
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::TimelockedAction;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address};
+
+fn new_token(test: &PredictifyTest) -> Address {
+    test.env
+        .register_stellar_asset_contract_v2(Address::generate(&test.env))
+        .address()
+}
+
+#[test]
+fn test_token_switch_and_fee_increase_are_not_immediate() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = new_token(&test);
+
+    assert_eq!(
+        client.try_set_token(&test.admin, &token),
+        Err(Ok(Error::InvalidState))
+    );
+    assert_eq!(
+        client.try_set_fee_bps(&test.admin, &201),
+        Err(Ok(Error::FeeIncreaseTimelocked))
+    );
+    assert_eq!(
+        client.try_set_platform_fee(&test.admin, &5),
+        Err(Ok(Error::FeeIncreaseTimelocked))
+    );
+    assert_eq!(client.get_token(), test.token_test.token_id);
+    assert_eq!(client.get_fee_bps(), 200);
+
+    // Fee cuts and emergency pauses still apply at once
+    client.set_fee_bps(&test.admin, &150);
+    assert_eq!(client.get_fee_bps(), 150);
+    client.pause(&test.admin);
+    assert!(client.is_contract_paused());
+}
+
+#[test]
+fn test_fee_increase_applies_once_delay_has_passed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let now = test.env.ledger().timestamp();
+
+    let scheduled = client.schedule_action(&test.admin, &TimelockedAction::SetFeeBps(350));
+    assert_eq!(scheduled.scheduled_by, test.admin);
+    assert_eq!(scheduled.execute_after, now + DEFAULT_ADMIN_ACTION_DELAY_SECONDS);
+    assert_eq!(client.get_pending_actions().get(0).unwrap(), scheduled);
+
+    // One second early is refused and leaves the action pending
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled.execute_after - 1);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    assert_eq!(client.get_fee_bps(), 200);
+    assert_eq!(client.get_pending_actions().len(), 1);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled.execute_after);
+    client.execute_action(&scheduled.id);
+    assert_eq!(client.get_fee_bps(), 350);
+    assert!(client.get_pending_actions().is_empty());
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    let count = client.get_admin_log_count() as u32;
+    let logged = client.get_admin_log(&(count - 1), &1).get(0).unwrap();
+    assert_eq!(logged.action, symbol_short!("fee_bps"));
+    assert_eq!(logged.who, test.admin);
+}
+
+#[test]
+fn test_token_switch_applies_once_delay_has_passed() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token = new_token(&test);
+
+    let scheduled =
+        client.schedule_action(&test.admin, &TimelockedAction::SetToken(token.clone()));
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled.execute_after - 1);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    assert_eq!(client.get_token(), test.token_test.token_id);
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled.execute_after);
+    client.execute_action(&scheduled.id);
+    assert_eq!(client.get_token(), token);
+}
+
+#[test]
+fn test_cancel_action() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outsider = Address::generate(&test.env);
+
+    let scheduled = client.schedule_action(&test.admin, &TimelockedAction::SetFeeBps(500));
+    assert_eq!(
+        client.try_cancel_action(&outsider, &scheduled.id),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.cancel_action(&test.admin, &scheduled.id);
+    assert!(client.get_pending_actions().is_empty());
+    assert_eq!(
+        client.try_cancel_action(&test.admin, &scheduled.id),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled.execute_after);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(client.get_fee_bps(), 200);
+}
+
+#[test]
+fn test_schedule_validates_action() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    assert_eq!(
+        client.try_schedule_action(&test.admin, &TimelockedAction::SetFeeBps(1_001)),
+        Err(Ok(Error::InvalidFeeConfig))
+    );
+    assert_eq!(
+        client.try_schedule_action(
+            &test.admin,
+            &TimelockedAction::SetToken(Address::generate(&test.env))
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_schedule_action(
+            &test.admin,
+            &TimelockedAction::SetActionDelay(MAX_ADMIN_ACTION_DELAY_SECONDS + 1)
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert!(client.get_pending_actions().is_empty());
+}
+
+#[test]
+fn test_pending_actions_are_listed_in_order_and_bounded() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    for bps in 0..MAX_PENDING_ACTIONS {
+        client.schedule_action(&test.admin, &TimelockedAction::SetFeeBps(bps));
+    }
+    assert_eq!(
+        client.try_schedule_action(&test.admin, &TimelockedAction::SetFeeBps(300)),
+        Err(Ok(Error::InvalidState))
+    );
+
+    let pending = client.get_pending_actions();
+    assert_eq!(pending.len(), MAX_PENDING_ACTIONS);
+    for (i, action) in pending.iter().enumerate() {
+        assert_eq!(action.id, i as u64);
+        assert_eq!(action.action, TimelockedAction::SetFeeBps(i as u32));
+    }
+
+    // Cancelling frees a slot; ids are never reused
+    client.cancel_action(&test.admin, &3);
+    let next = client.schedule_action(&test.admin, &TimelockedAction::SetFeeBps(300));
+    assert_eq!(next.id, MAX_PENDING_ACTIONS as u64);
+}
+
+#[test]
+fn test_action_delay_is_raised_directly_and_lowered_through_timelock() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let day = DEFAULT_ADMIN_ACTION_DELAY_SECONDS;
+    assert_eq!(client.get_action_delay(), day);
+
+    assert_eq!(
+        client.try_set_action_delay(&test.admin, &(day - 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_action_delay(&test.admin, &(MAX_ADMIN_ACTION_DELAY_SECONDS + 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+    client.set_action_delay(&test.admin, &(2 * day));
+    assert_eq!(client.get_action_delay(), 2 * day);
+
+    let lower = client.schedule_action(&test.admin, &TimelockedAction::SetActionDelay(3_600));
+    assert_eq!(lower.execute_after, lower.scheduled_at + 2 * day);
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = lower.execute_after);
+    client.execute_action(&lower.id);
+    assert_eq!(client.get_action_delay(), 3_600);
+
+    let fee = client.schedule_action(&test.admin, &TimelockedAction::SetFeeBps(300));
+    assert_eq!(fee.execute_after, fee.scheduled_at + 3_600);
+}
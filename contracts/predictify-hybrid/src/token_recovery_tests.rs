@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+//! Token recovery tests.
+//!
+//! `recover_token` returns assets sent to the contract by mistake, but never
+//! the global staking token, a market's token or a token with a fee vault
+//! balance, whose balance backs escrow and fees.
+
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+use soroban_sdk::{Address, String};
+
+const AMOUNT: i128 = 50_000_000;
+
+fn foreign_token(test: &PredictifyTest) -> Address {
+    let issuer = Address::generate(&test.env);
+    test.env
+        .register_stellar_asset_contract_v2(issuer)
+        .address()
+}
+
+#[test]
+fn test_recover_foreign_token() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let token_id = foreign_token(&test);
+    let token = TokenClient::new(&test.env, &token_id);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &token_id).mint(&test.contract_id, &AMOUNT);
+
+    let recipient = Address::generate(&test.env);
+    assert_eq!(
+        client.try_recover_token(&test.admin, &token_id, &(AMOUNT + 1), &recipient),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    client.recover_token(&test.admin, &token_id, &AMOUNT, &recipient);
+    assert_eq!(token.balance(&recipient), AMOUNT);
+    assert_eq!(token.balance(&test.contract_id), 0);
+
+    // Only the admin may recover
+    StellarAssetClient::new(&test.env, &token_id).mint(&test.contract_id, &AMOUNT);
+    assert_eq!(
+        client.try_recover_token(&test.user, &token_id, &AMOUNT, &test.user),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_recover_staking_tokens_is_refused() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    client.place_bet(
+        &test.user,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &AMOUNT,
+        &None,
+    );
+    let global = TokenClient::new(&test.env, &test.token_test.token_id);
+    let held = global.balance(&test.contract_id);
+
+    assert_eq!(
+        client.try_recover_token(&test.admin, &test.token_test.token_id, &AMOUNT, &test.admin),
+        Err(Ok(Error::CannotRecoverStakingToken))
+    );
+    assert_eq!(global.balance(&test.contract_id), held);
+
+    // A market's own token is protected the same way
+    let market_token = foreign_token(&test);
+    let other_market = test.create_test_market();
+    client.set_market_token(&test.admin, &other_market, &market_token);
+    StellarAssetClient::new(&test.env, &market_token).mint(&test.contract_id, &AMOUNT);
+    assert_eq!(
+        client.try_recover_token(&test.admin, &market_token, &AMOUNT, &test.admin),
+        Err(Ok(Error::CannotRecoverStakingToken))
+    );
+}
+
+#[test]
+fn test_recover_token_with_fee_vault_balance_is_refused() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let vault_token = foreign_token(&test);
+    test.env.mock_all_auths();
+    StellarAssetClient::new(&test.env, &vault_token).mint(&test.contract_id, &AMOUNT);
+
+    // Fees in a token no registered market uses any more
+    test.env.as_contract(&test.contract_id, || {
+        crate::fees::FeeTracker::credit_fee_vault(&test.env, Some(&vault_token), AMOUNT).unwrap();
+    });
+    assert_eq!(
+        client.try_recover_token(&test.admin, &vault_token, &AMOUNT, &test.admin),
+        Err(Ok(Error::CannotRecoverStakingToken))
+    );
+}
+
+#[test]
+fn test_token_switch_refused_while_fee_vault_holds_fees() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let new_token = foreign_token(&test);
+    test.env.mock_all_auths();
+
+    test.env.as_contract(&test.contract_id, || {
+        crate::fees::FeeTracker::credit_fee_vault(&test.env, None, AMOUNT).unwrap();
+    });
+    let switch = test.schedule_due(crate::types::TimelockedAction::SetToken(new_token));
+    assert_eq!(
+        client.try_execute_action(&switch),
+        Err(Ok(Error::BetsAlreadyPlaced))
+    );
+
+    // The old token keeps backing the vault, so it cannot be recovered either
+    assert_eq!(
+        client.try_recover_token(&test.admin, &test.token_test.token_id, &AMOUNT, &test.admin),
+        Err(Ok(Error::CannotRecoverStakingToken))
+    );
+}
@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+//! TTL management tests.
+//!
+//! Market and bet entries are extended on every read and write, and
+//! `extend_market_ttl` lets anyone keep an idle market alive, so entries
+//! outlive the network's minimum persistent TTL.
+
+use crate::config::{DEFAULT_TTL_EXTEND_TO_LEDGERS, DEFAULT_TTL_THRESHOLD_LEDGERS};
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::test::PredictifyTest;
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, String, Symbol};
+
+fn market_ttl(test: &PredictifyTest, market_id: &Symbol) -> u32 {
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get_ttl(&DataKey::Market(market_id.clone()))
+    })
+}
+
+fn bet_ttl(test: &PredictifyTest, market_id: &Symbol, user: &Address) -> u32 {
+    test.env.as_contract(&test.contract_id, || {
+        test.env
+            .storage()
+            .persistent()
+            .get_ttl(&DataKey::Bet(market_id.clone(), user.clone()))
+    })
+}
+
+fn instance_ttl(test: &PredictifyTest) -> u32 {
+    test.env
+        .as_contract(&test.contract_id, || test.env.storage().instance().get_ttl())
+}
+
+fn advance_ledgers(test: &PredictifyTest, ledgers: u32) {
+    test.env
+        .ledger()
+        .with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_market_and_bet_writes_extend_ttl() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+    let user = test.create_funded_user();
+    client.place_bet(&user, &market_id, &String::from_str(&test.env, "yes"), &10_000_000, &None);
+
+    assert_eq!(market_ttl(&test, &market_id), DEFAULT_TTL_EXTEND_TO_LEDGERS);
+    assert_eq!(bet_ttl(&test, &market_id, &user), DEFAULT_TTL_EXTEND_TO_LEDGERS);
+    assert_eq!(instance_ttl(&test), DEFAULT_TTL_EXTEND_TO_LEDGERS);
+
+    // Well past the network's minimum persistent TTL.
+    let min_ttl = test.env.ledger().get().min_persistent_entry_ttl;
+    advance_ledgers(&test, min_ttl * 10);
+
+    assert!(client.get_market(&market_id).is_some());
+    assert!(client.get_bet(&market_id, &user).is_some());
+}
+
+#[test]
+fn test_reads_extend_ttl_once_below_threshold() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    // Above the threshold a read leaves the TTL alone.
+    advance_ledgers(&test, 1_000);
+    client.get_market(&market_id);
+    assert_eq!(market_ttl(&test, &market_id), DEFAULT_TTL_EXTEND_TO_LEDGERS - 1_000);
+
+    advance_ledgers(&test, DEFAULT_TTL_EXTEND_TO_LEDGERS - DEFAULT_TTL_THRESHOLD_LEDGERS);
+    client.get_market(&market_id);
+    assert_eq!(market_ttl(&test, &market_id), DEFAULT_TTL_EXTEND_TO_LEDGERS);
+}
+
+#[test]
+fn test_extend_market_ttl_keeps_idle_market_alive() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let market_id = test.create_test_market();
+
+    advance_ledgers(&test, DEFAULT_TTL_EXTEND_TO_LEDGERS - 10);
+    assert_eq!(market_ttl(&test, &market_id), 10);
+
+    client.extend_market_ttl(&market_id);
+    assert_eq!(market_ttl(&test, &market_id), DEFAULT_TTL_EXTEND_TO_LEDGERS);
+    assert_eq!(instance_ttl(&test), DEFAULT_TTL_EXTEND_TO_LEDGERS);
+
+    // Past the market's original expiry.
+    advance_ledgers(&test, DEFAULT_TTL_THRESHOLD_LEDGERS);
+    assert!(client.get_market(&market_id).is_some());
+}
+
+#[test]
+fn test_extend_market_ttl_unknown_market() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    assert_eq!(
+        client.try_extend_market_ttl(&Symbol::new(&test.env, "missing")),
+        Err(Ok(Error::MarketNotFound))
+    );
+}
+
+#[test]
+fn test_set_ttl_config() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    let outsider = Address::generate(&test.env);
+    assert_eq!(
+        client.try_set_ttl_config(&outsider, &1_000, &50_000),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_ttl_config(&test.admin, &50_001, &50_000),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_ttl_config(&test.admin, &0, &0),
+        Err(Ok(Error::InvalidInput))
+    );
+
+    client.set_ttl_config(&test.admin, &10_000, &50_000);
+    let config = client.get_ttl_config();
+    assert_eq!(config.threshold, 10_000);
+    assert_eq!(config.extend_to, 50_000);
+
+    let market_id = test.create_test_market();
+    assert_eq!(market_ttl(&test, &market_id), 50_000);
+}
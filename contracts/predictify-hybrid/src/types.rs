@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Symbol, Vec};
 
 // ===== MARKET STATE =====
 
@@ -15,14 +15,19 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 ///
 /// The typical market progression follows this pattern:
 /// ```text
-/// Active → Ended → [Disputed] → Resolved → Closed
+/// Active → [BettingClosed] → Ended → [Disputed] → Resolved → Closed
 /// ```
 ///
 /// **Alternative flows:**
-/// - **Cancellation**: `Active → Cancelled` (emergency situations)
-/// - **Direct Resolution**: `Active → Resolved` (admin override)
+/// - **Cancellation**: `Active → Cancelled` (admin cancellation)
+/// - **Voiding**: `Ended → Voided` (underfunded, oracle failure, no resolution)
 /// - **Dispute Flow**: `Ended → Disputed → Resolved`
 ///
+/// The stored state only changes through `MarketStateLogic::transition`, which
+/// rejects any move not in its table (e.g. `Cancelled → Resolved`). The names
+/// are kept from the first release so stored markets keep decoding: `Active`
+/// is the open market, `Ended` is pending resolution and `Closed` is settled.
+///
 /// # State Descriptions
 ///
 /// **Active**: Market is live and accepting user participation
@@ -31,6 +36,10 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 /// - Oracle configuration is immutable
 /// - Voting period is ongoing
 ///
+/// **BettingClosed**: The betting deadline has passed before `end_time`
+/// - No new bets accepted
+/// - Market waits for its end time
+///
 /// **Ended**: Market voting period has concluded
 /// - No new votes or stakes accepted
 /// - Oracle resolution can be triggered
@@ -61,6 +70,11 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 /// - No winner determination
 /// - Administrative action required
 ///
+/// **Voided**: Market could not be settled
+/// - Below its minimum pool, oracle failed, or not resolved in time
+/// - Stakes returned to participants like a cancellation
+/// - No winner determination
+///
 /// # Example Usage
 ///
 /// ```rust
@@ -112,6 +126,7 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 /// - **Resolved**: Must have valid resolution with outcome and method
 /// - **Closed**: All payouts must be completed and verified
 /// - **Cancelled**: Must have valid cancellation reason and admin authorization
+/// - **Voided**: Must have failed to reach a resolution it can pay out
 ///
 /// # Integration Points
 ///
@@ -125,7 +140,7 @@ use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, Vec};
 #[contracttype]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MarketState {
-    /// Market is active and accepting votes
+    /// Market is open and accepting bets
     Active,
     /// Market has ended, waiting for resolution
     Ended,
@@ -133,10 +148,232 @@ pub enum MarketState {
     Disputed,
     /// Market has been resolved
     Resolved,
-    /// Market is closed
+    /// Market is settled and closed
     Closed,
     /// Market has been cancelled
     Cancelled,
+    /// Betting deadline passed, waiting for the end time
+    BettingClosed,
+    /// Market was voided and its stakes are refunded
+    Voided,
+}
+
+impl MarketState {
+    /// Returns true for the states whose stakes are returned by
+    /// `process_refunds` instead of being paid out to winners.
+    pub fn is_refunding(self) -> bool {
+        matches!(self, MarketState::Cancelled | MarketState::Voided)
+    }
+
+    /// Returns true for states no transition leaves.
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            MarketState::Closed | MarketState::Cancelled | MarketState::Voided
+        )
+    }
+}
+
+/// Lifecycle events fed to `MarketStateLogic::transition`, each moving a
+/// market to one state from the states it is allowed from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarketEvent {
+    /// Betting deadline passed: `Active → BettingClosed`
+    CloseBetting,
+    /// End time passed: `Active | BettingClosed → Ended`
+    End,
+    /// Outcome or result disputed: `Active | BettingClosed | Ended | Resolved → Disputed`
+    Dispute,
+    /// Winning outcome set, or a result dispute rejected: `Ended | Disputed → Resolved`
+    Resolve,
+    /// Result dispute upheld, winning outcome rewritten: `Disputed → Resolved`
+    Overturn,
+    /// Fees collected: `Resolved → Closed`
+    Settle,
+    /// Cancelled by the admin: any unresolved state `→ Cancelled`
+    Cancel,
+    /// Could not be settled: any unresolved state `→ Voided`
+    Void,
+}
+
+impl MarketEvent {
+    /// Every event, in lifecycle order.
+    pub const ALL: [MarketEvent; 8] = [
+        MarketEvent::CloseBetting,
+        MarketEvent::End,
+        MarketEvent::Dispute,
+        MarketEvent::Resolve,
+        MarketEvent::Overturn,
+        MarketEvent::Settle,
+        MarketEvent::Cancel,
+        MarketEvent::Void,
+    ];
+}
+
+/// Why the admin cancelled a market with `cancel_event`.
+///
+/// Decides what happens to the creator's creation bond: it is returned after an
+/// `Administrative` cancellation and slashed to the fee vault otherwise.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CancelReason {
+    /// Cancelled for reasons outside the creator's control
+    Administrative,
+    /// The market can never be resolved, e.g. its oracle config is nonsensical
+    Unresolvable,
+    /// The market was created in bad faith
+    Malicious,
+}
+
+/// Reward paid from the fee vault to keepers that settle overdue markets with
+/// `keeper_distribute_payouts`, `keeper_void_market` or `keeper_sweep_unclaimed`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeeperReward {
+    /// A flat amount in the market's token
+    Flat(i128),
+    /// Basis points of the market's platform fee
+    Bps(u32),
+}
+
+/// Sensitive admin operation that only takes effect after the admin action
+/// delay, through `schedule_action` and `execute_action`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockedAction {
+    /// Switch the staking token
+    SetToken(Address),
+    /// Change the global platform fee, in basis points
+    SetFeeBps(u32),
+    /// Replace the contract Wasm with the given hash
+    Upgrade(soroban_sdk::BytesN<32>),
+    /// Change the admin action delay, in seconds
+    SetActionDelay(u64),
+    /// Raise a market's platform fee above its snapshot, in basis points
+    SetMarketFeeBps(Symbol, u32),
+}
+
+/// A `TimelockedAction` waiting out its delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAction {
+    /// Identifier passed to `execute_action` and `cancel_action`
+    pub id: u64,
+    pub action: TimelockedAction,
+    /// Admin who scheduled the action
+    pub scheduled_by: Address,
+    pub scheduled_at: u64,
+    /// Earliest time `execute_action` applies the action
+    pub execute_after: u64,
+}
+
+/// Admin operation carried out once enough admin signers approve it, through
+/// `propose_admin_op` and `approve_admin_op`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MultisigOp {
+    /// Resolve a market manually; more than one outcome resolves it as a tie
+    ResolveMarket(Symbol, Vec<String>),
+    /// Cancel a market and open it for refunds
+    CancelMarket(Symbol, CancelReason),
+    /// Withdraw collected platform fees to the treasury (`0` for all)
+    WithdrawFees(i128),
+    /// Schedule an upgrade, which still waits out the admin action delay
+    Upgrade(soroban_sdk::BytesN<32>),
+    /// Replace the signer set and approval threshold
+    SetSigners(Vec<Address>, u32),
+}
+
+/// Signers allowed to approve admin operations and how many approvals
+/// execute one. Without a stored set the admin is the only signer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminSigners {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// A `MultisigOp` collecting approvals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigProposal {
+    /// Identifier passed to `approve_admin_op`
+    pub id: u64,
+    pub op: MultisigOp,
+    pub proposer: Address,
+    /// Signers that approved, the proposer included
+    pub approvals: Vec<Address>,
+    pub proposed_at: u64,
+}
+
+/// How a market's final outcome is reached.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ResolveMode {
+    /// The oracle result decides, weighed against betting consensus
+    Standard,
+    /// The oracle result and a stake-weighted community vote must agree;
+    /// otherwise the market is disputed and goes to the admin
+    Hybrid,
+    /// A bonded proposal decides unless it is challenged, in which case the
+    /// market's arbiter does
+    Optimistic,
+}
+
+/// Range a scalar market's oracle value is measured against.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScalarRange {
+    /// Value at or below which the SHORT side takes the whole pool
+    pub lower_bound: i128,
+    /// Value at or above which the LONG side takes the whole pool
+    pub upper_bound: i128,
+}
+
+/// What a market's bettors predict.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarketType {
+    /// Bettors pick one of the market's outcomes; winners split the pool
+    Categorical,
+    /// Bettors go `long` or `short` on a numeric oracle value. The pool is split
+    /// between the sides in proportion to where the value lands in the range
+    Scalar(ScalarRange),
+}
+
+/// Who may bet on a market.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarketAccess {
+    /// Anyone may bet
+    Public,
+    /// Only addresses on the market's allowlist may bet
+    Allowlist,
+}
+
+/// Anti-sniping settings of a market: a large bet close to the betting
+/// deadline pushes the deadline back.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AntiSnipeConfig {
+    /// How close to the betting deadline a bet must land to extend it, in seconds
+    pub window_seconds: u64,
+    /// Seconds each qualifying bet adds to the betting deadline
+    pub extension_seconds: u64,
+    /// Smallest bet that extends the deadline
+    pub threshold_amount: i128,
+    /// Cap on the total extension over the market's life, in seconds
+    pub max_extension_seconds: u64,
+}
+
+/// Promotional free-bet credit of a user, spent with `place_bet_with_credit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BetCredit {
+    /// Unspent credit, backed by tokens the admin deposited when granting it
+    pub amount: i128,
+    /// Ledger timestamp from which the credit can no longer be spent
+    pub expires_at: u64,
 }
 
 // ===== ORACLE TYPES =====
@@ -259,7 +496,7 @@ pub enum MarketState {
 /// # Network Compatibility
 ///
 /// Provider support varies by blockchain network:
-/// - **Stellar**: Only Reflector is currently supported
+/// - **Stellar**: Reflector and Pyth (via a Pyth price contract) are supported
 /// - **Ethereum**: Pyth, Band Protocol, and DIA are available
 /// - **Cosmos**: Band Protocol is native
 /// - **Multi-chain**: DIA supports multiple networks
@@ -276,12 +513,16 @@ pub enum MarketState {
 pub enum OracleProvider {
     /// Reflector oracle (primary oracle for Stellar Network)
     Reflector,
-    /// Pyth Network oracle (placeholder for Stellar)
+    /// Pyth Network oracle (read from a Pyth price contract)
     Pyth,
     /// Band Protocol oracle (not available on Stellar)
     BandProtocol,
     /// DIA oracle (not available on Stellar)
     DIA,
+    /// Results signed off-chain, for data without an on-chain feed (e.g.
+    /// sports scores). `oracle_address` is the signer's `G...` account, whose
+    /// ed25519 public key verifies results pushed with `submit_signed_result`.
+    Signed,
 }
 
 impl OracleProvider {
@@ -292,12 +533,16 @@ impl OracleProvider {
             OracleProvider::Pyth => "Pyth",
             OracleProvider::BandProtocol => "Band Protocol",
             OracleProvider::DIA => "DIA",
+            OracleProvider::Signed => "Signed",
         }
     }
 
     /// Check if provider is supported on Stellar
     pub fn is_supported(&self) -> bool {
-        matches!(self, OracleProvider::Reflector)
+        matches!(
+            self,
+            OracleProvider::Reflector | OracleProvider::Pyth | OracleProvider::Signed
+        )
     }
 }
 
@@ -469,10 +714,32 @@ pub struct OracleConfig {
     pub feed_id: String,
     /// Price threshold in cents (e.g., 10_000_00 = $10k)
     pub threshold: i128,
-    /// Comparison operator: "gt", "lt", "eq"
+    /// Comparison operator, see [`OracleComparison`]
     pub comparison: String,
+    /// Resolve against the time-weighted average of the last N rounds instead
+    /// of the latest price (Reflector only, at most [`MAX_TWAP_RECORDS`])
+    pub twap_records: Option<u32>,
+    /// Resolve on the ratio `feed_id / quote_feed_id` of two feeds from the same
+    /// provider, in the provider's price decimals (e.g. BTC/ETH)
+    pub quote_feed_id: Option<String>,
+    /// Key that signs the results of a `Signed` oracle; `OracleSigner::None`
+    /// for every other provider
+    pub signer: OracleSigner,
 }
 
+/// Key that signs an oracle's results, see [`OracleProvider::Signed`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleSigner {
+    /// Results are read from the oracle, not signed
+    None,
+    /// Ed25519 public key
+    Ed25519(BytesN<32>),
+}
+
+/// Largest `OracleConfig::twap_records` accepted.
+pub const MAX_TWAP_RECORDS: u32 = 20;
+
 impl OracleConfig {
     /// Create a new oracle configuration
     pub fn new(
@@ -488,6 +755,9 @@ impl OracleConfig {
             feed_id,
             threshold,
             comparison,
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         }
     }
 
@@ -502,8 +772,39 @@ impl OracleConfig {
             feed_id: String::from_str(env, ""),
             threshold: 0,
             comparison: String::from_str(env, ""),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         }
     }
+
+    /// Validate how the price is read, rejecting with `Error::InvalidOracleConfig`:
+    /// - a TWAP window on providers without one, or outside `1..=MAX_TWAP_RECORDS`
+    /// - an empty quote feed, or one equal to `feed_id`
+    /// - a `Signed` oracle without a signer key, or with a quote feed
+    /// - a signer key on any other provider
+    pub fn validate_price_source(&self) -> Result<(), crate::Error> {
+        if let Some(records) = self.twap_records {
+            if self.provider != OracleProvider::Reflector
+                || !(1..=MAX_TWAP_RECORDS).contains(&records)
+            {
+                return Err(crate::Error::InvalidOracleConfig);
+            }
+        }
+        if let Some(quote) = &self.quote_feed_id {
+            if quote.is_empty() || *quote == self.feed_id {
+                return Err(crate::Error::InvalidOracleConfig);
+            }
+        }
+        if self.provider == OracleProvider::Signed {
+            if self.quote_feed_id.is_some() || self.signer == OracleSigner::None {
+                return Err(crate::Error::InvalidOracleConfig);
+            }
+        } else if self.signer != OracleSigner::None {
+            return Err(crate::Error::InvalidOracleConfig);
+        }
+        Ok(())
+    }
 }
 
 impl OracleConfig {
@@ -515,19 +816,102 @@ impl OracleConfig {
         }
 
         // Validate comparison operator
-        if self.comparison != String::from_str(env, "gt")
-            && self.comparison != String::from_str(env, "lt")
-            && self.comparison != String::from_str(env, "eq")
-        {
-            return Err(crate::Error::InvalidComparison);
-        }
+        OracleComparison::parse(&self.comparison)?;
 
         // Validate provider is supported
         if !self.provider.is_supported() {
             return Err(crate::Error::InvalidOracleConfig);
         }
 
-        Ok(())
+        self.validate_price_source()
+    }
+}
+
+/// Comparison operator parsed from [`OracleConfig::comparison`].
+///
+/// Accepted strings are `"gt"`, `"gte"`, `"lt"`, `"lte"`, `"eq"` and
+/// `"range:<lo>:<hi>"`, where `lo` and `hi` are positive integers in the same
+/// units as the oracle price with `lo <= hi`. A range ignores `threshold` and
+/// is met when `lo <= price <= hi`; both bounds are inclusive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OracleComparison {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Eq,
+    Range(i128, i128),
+}
+
+impl OracleComparison {
+    /// Longest comparison string accepted (covers two 39-digit bounds).
+    const MAX_LEN: usize = 96;
+
+    /// Parse a comparison string, rejecting anything unknown with
+    /// `Error::InvalidComparison`.
+    pub fn parse(comparison: &String) -> Result<Self, crate::Error> {
+        let len = comparison.len() as usize;
+        if len == 0 || len > Self::MAX_LEN {
+            return Err(crate::Error::InvalidComparison);
+        }
+        let mut buf = [0u8; Self::MAX_LEN];
+        comparison.copy_into_slice(&mut buf[..len]);
+        let bytes = &buf[..len];
+
+        match bytes {
+            b"gt" => Ok(Self::Gt),
+            b"gte" => Ok(Self::Gte),
+            b"lt" => Ok(Self::Lt),
+            b"lte" => Ok(Self::Lte),
+            b"eq" => Ok(Self::Eq),
+            _ => {
+                let rest = bytes
+                    .strip_prefix(b"range:")
+                    .ok_or(crate::Error::InvalidComparison)?;
+                let sep = rest
+                    .iter()
+                    .position(|b| *b == b':')
+                    .ok_or(crate::Error::InvalidComparison)?;
+                let lo = Self::parse_bound(&rest[..sep])?;
+                let hi = Self::parse_bound(&rest[sep + 1..])?;
+                if lo > hi {
+                    return Err(crate::Error::InvalidComparison);
+                }
+                Ok(Self::Range(lo, hi))
+            }
+        }
+    }
+
+    /// Whether `price` satisfies this comparison against `threshold`.
+    pub fn is_met(&self, price: i128, threshold: i128) -> bool {
+        match *self {
+            Self::Gt => price > threshold,
+            Self::Gte => price >= threshold,
+            Self::Lt => price < threshold,
+            Self::Lte => price <= threshold,
+            Self::Eq => price == threshold,
+            Self::Range(lo, hi) => price >= lo && price <= hi,
+        }
+    }
+
+    fn parse_bound(digits: &[u8]) -> Result<i128, crate::Error> {
+        if digits.is_empty() {
+            return Err(crate::Error::InvalidComparison);
+        }
+        let mut value: i128 = 0;
+        for d in digits {
+            if !d.is_ascii_digit() {
+                return Err(crate::Error::InvalidComparison);
+            }
+            value = value
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((d - b'0') as i128))
+                .ok_or(crate::Error::InvalidComparison)?;
+        }
+        if value == 0 {
+            return Err(crate::Error::InvalidComparison);
+        }
+        Ok(value)
     }
 }
 
@@ -630,26 +1014,10 @@ impl OracleConfig {
 /// # let mut market = Market::default(); // Placeholder
 /// # let user = Address::generate(&soroban_sdk::Env::default());
 ///
-/// // Add user vote and stake (for testing)
-/// market.add_vote(
-///     user.clone(),
-///     String::from_str(&soroban_sdk::Env::default(), "yes"),
-///     1_000_000 // 1 XLM in stroops
-/// );
-///
-/// // Check user's vote
-/// if let Some(user_vote) = market.votes.get(user.clone()) {
-///     println!("User voted: {}", user_vote);
-/// }
-///
-/// // Check user's stake
-/// if let Some(user_stake) = market.stakes.get(user.clone()) {
-///     println!("User staked: {} stroops", user_stake);
-/// }
-///
-/// // Check if user has claimed payout
-/// let has_claimed = market.claimed.get(user.clone()).unwrap_or(false);
-/// println!("User claimed payout: {}", has_claimed);
+/// // Positions are stored as bets; the market keeps per-outcome totals
+/// let yes = String::from_str(&soroban_sdk::Env::default(), "yes");
+/// println!("Stake on yes: {} stroops", market.outcome_pool(&yes));
+/// println!("Positions on yes: {}", market.outcome_position_count(&yes));
 /// ```
 ///
 /// # Market Validation
@@ -722,6 +1090,8 @@ impl OracleConfig {
 pub struct Market {
     /// Market administrator address
     pub admin: Address,
+    /// Address that created the market (the admin or an allowlisted creator)
+    pub creator: Address,
     /// Market question/prediction
     pub question: String,
     /// Available outcomes for the market
@@ -738,12 +1108,6 @@ pub struct Market {
     pub resolution_timeout: u64,
     /// Oracle result (set after market ends)
     pub oracle_result: Option<String>,
-    /// User votes mapping (address -> outcome)
-    pub votes: Map<Address, String>,
-    /// User stakes mapping (address -> stake amount)
-    pub stakes: Map<Address, i128>,
-    /// Claimed status mapping (address -> claimed)
-    pub claimed: Map<Address, bool>,
     /// Total amount staked in the market
     pub total_staked: i128,
     /// Dispute stakes mapping (address -> dispute stake)
@@ -773,10 +1137,125 @@ pub struct Market {
     pub tags: Vec<String>,
     /// Minimum total pool size required for resolution (None = no minimum)
     pub min_pool_size: Option<i128>,
+    /// Maximum total pool size; bets that would exceed it are rejected (None = no cap)
+    pub max_pool_size: Option<i128>,
     /// Bet deadline (Unix timestamp). No bets accepted after this time. 0 = use end_time (no early cutoff).
     pub bet_deadline: u64,
-    /// Dispute window in seconds after end_time. Payouts allowed only after end_time + this period (or dispute resolved).
+    /// Dispute window in seconds after resolution. Payouts allowed only after resolved_at + this period (or dispute resolved).
     pub dispute_window_seconds: u64,
+    /// When the market was resolved (Unix timestamp); the dispute window starts here
+    pub resolved_at: Option<u64>,
+    /// When payouts unlock: `resolved_at + dispute_window_seconds`, or earlier once a dispute is settled
+    pub payout_unlock_at: Option<u64>,
+    /// Staking token for this market (None = use the global token set via `set_token`)
+    pub token: Option<Address>,
+    /// Platform fee in basis points, snapshotted from the global fee at creation
+    pub fee_bps: u32,
+    /// Creator fee in basis points, taken from winning payouts on top of `fee_bps`
+    pub creator_fee_bps: u32,
+    /// Resolution mode, see [`ResolveMode`]
+    pub resolve_mode: ResolveMode,
+    /// Outcome picked by the community vote, if one was held
+    pub community_result: Option<String>,
+    /// Frozen by the admin during an investigation: bets and payouts are blocked
+    pub frozen: bool,
+    /// Categorical or scalar, see [`MarketType`]
+    pub market_type: MarketType,
+    /// Oracle value a scalar market resolved at, before clamping to its range
+    pub scalar_value: Option<i128>,
+    /// When the admin closed the market ahead of its scheduled end, if they did
+    pub closed_early_at: Option<u64>,
+    /// Hard deadline for resolution, see [`Market::compute_resolution_deadline`].
+    /// Refreshed whenever the market is loaded, so it follows end time changes.
+    pub resolution_deadline: u64,
+    /// Total stake backing each outcome. Positions live in their own
+    /// `DataKey::Bet` entries, so payouts read these totals instead.
+    pub outcome_pools: Map<String, i128>,
+    /// Number of positions backing each outcome
+    pub outcome_positions: Map<String, u32>,
+}
+
+/// Reusable market settings for questions that repeat, e.g. a weekly price
+/// market. Mirrors the parameters of `create_market`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketTemplate {
+    /// Default question
+    pub question: String,
+    /// Outcomes every market from the template offers
+    pub outcomes: Vec<String>,
+    /// Default market duration in days
+    pub duration_days: u32,
+    /// Oracle config; its `threshold` is a default that markets may override
+    pub oracle_config: OracleConfig,
+    /// Whether markets from the template get a fallback oracle
+    pub has_fallback: bool,
+    /// Fallback oracle config (only used when `has_fallback` is true)
+    pub fallback_oracle_config: OracleConfig,
+    /// Seconds after `end_time` before the fallback oracle is used
+    pub resolution_timeout: u64,
+    /// Optional minimum pool size
+    pub min_pool_size: Option<i128>,
+    /// Optional betting cutoff in minutes before `end_time`
+    pub bet_deadline_mins_before_end: Option<u32>,
+    /// Optional dispute window, else the contract default
+    pub dispute_window_seconds: Option<u64>,
+    /// Creator fee in basis points applied to markets from the template
+    pub creator_fee_bps: u32,
+}
+
+/// Per-market values filled in when a market is created from a template.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateOverrides {
+    /// Replaces the template question, e.g. to name this week's strike
+    pub question: Option<String>,
+    /// Replaces the threshold of the primary and fallback oracle configs
+    pub threshold: Option<i128>,
+    /// Replaces the template duration, moving `end_time`
+    pub duration_days: Option<u32>,
+}
+
+/// A run of markets created one after another from the same template.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketSeries {
+    /// Admin who created the series; its markets are created on the admin's behalf
+    pub admin: Address,
+    /// Template each market is created from
+    pub template_id: u32,
+    /// Duration of each market in seconds (a whole number of days)
+    pub interval_seconds: u64,
+    /// Total number of markets in the series
+    pub count: u32,
+    /// Markets created so far, in order
+    pub markets: Vec<Symbol>,
+}
+
+/// Where a market sits in its series.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeriesPosition {
+    /// Series id
+    pub series_id: u32,
+    /// Zero-based index of the market within the series
+    pub index: u32,
+}
+
+/// Display metadata for a market, stored separately from `Market`.
+///
+/// Kept under its own storage key so markets created before metadata existed
+/// still deserialize. Markets are indexed by `category` for
+/// `get_markets_by_category`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketMetadata {
+    /// Category used for discovery (e.g. `crypto`, `sports`)
+    pub category: Symbol,
+    /// Long-form description (up to `MAX_METADATA_DESCRIPTION_LENGTH` characters)
+    pub description: String,
+    /// External reference URL (up to `MAX_REFERENCE_URL_LENGTH` characters)
+    pub reference_url: String,
 }
 
 // ===== BET LIMITS =====
@@ -845,6 +1324,34 @@ pub struct PlatformStatistics {
     pub active_events_count: u32,
 }
 
+/// Versions an off-chain client needs to decode this contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionInfo {
+    /// Release number of the running code, as returned by `get_version`
+    pub contract_version: u32,
+    /// Layout version of published events, the second topic of every event
+    pub event_schema_version: u32,
+}
+
+/// Protocol-wide counters, updated by the actions they count.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolStats {
+    /// Cumulative amount staked across all markets
+    pub total_volume: i128,
+    /// Stake currently escrowed in markets that have not resolved or been cancelled
+    pub open_interest: i128,
+    /// Number of markets created
+    pub markets_created: u64,
+    /// Number of markets resolved
+    pub markets_resolved: u64,
+    /// Number of markets cancelled
+    pub markets_cancelled: u64,
+    /// Total platform fees collected by `collect_fees`
+    pub total_fees_collected: i128,
+}
+
 /// User-specific betting statistics
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -863,6 +1370,22 @@ pub struct UserStatistics {
     pub last_activity_ts: u64,
 }
 
+/// Lifetime summary of a user's activity, with their current claimable total.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserStats {
+    /// Total amount the user has staked
+    pub lifetime_staked: i128,
+    /// Total winnings the user has claimed
+    pub lifetime_winnings: i128,
+    /// Number of markets the user has taken a position in
+    pub markets_participated: u64,
+    /// Number of winning claims
+    pub win_count: u64,
+    /// Winnings currently claimable across the user's indexed markets
+    pub total_claimable: i128,
+}
+
 impl Market {
     /// Create a new market
     pub fn new(
@@ -880,7 +1403,8 @@ impl Market {
             Some(c) => (true, c.clone()),
             None => (false, OracleConfig::none_sentinel(env)),
         };
-        Self {
+        let mut market = Self {
+            creator: admin.clone(),
             admin,
             question,
             outcomes,
@@ -890,9 +1414,6 @@ impl Market {
             fallback_oracle_config: fallback_cfg,
             resolution_timeout,
             oracle_result: None,
-            votes: Map::new(env),
-            stakes: Map::new(env),
-            claimed: Map::new(env),
             total_staked: 0,
             dispute_stakes: Map::new(env),
             winning_outcomes: None,
@@ -906,9 +1427,26 @@ impl Market {
             category: None,
             tags: Vec::new(env),
             min_pool_size: None,
+            max_pool_size: None,
             bet_deadline: 0,
-            dispute_window_seconds: 86400, // 24h default
-        }
+            dispute_window_seconds: crate::config::DEFAULT_DISPUTE_WINDOW_SECONDS,
+            resolved_at: None,
+            payout_unlock_at: None,
+            token: None,
+            fee_bps: crate::config::DEFAULT_PLATFORM_FEE_BPS,
+            creator_fee_bps: 0,
+            resolve_mode: ResolveMode::Standard,
+            community_result: None,
+            frozen: false,
+            market_type: MarketType::Categorical,
+            scalar_value: None,
+            closed_early_at: None,
+            resolution_deadline: 0,
+            outcome_pools: Map::new(env),
+            outcome_positions: Map::new(env),
+        };
+        market.resolution_deadline = market.compute_resolution_deadline();
+        market
     }
 
     /// Check if the market is active (not ended) using the current ledger timestamp
@@ -926,6 +1464,40 @@ impl Market {
         self.winning_outcomes.is_some()
     }
 
+    /// Record the resolution time and open the dispute window from it
+    pub fn mark_resolved(&mut self, now: u64) {
+        self.resolved_at = Some(now);
+        self.payout_unlock_at = Some(now.saturating_add(self.dispute_window_seconds));
+    }
+
+    /// Time from which bets are rejected: `bet_deadline`, or `end_time` when
+    /// no deadline is set.
+    pub fn betting_deadline(&self) -> u64 {
+        if self.bet_deadline > 0 {
+            self.bet_deadline
+        } else {
+            self.end_time
+        }
+    }
+
+    /// `end_time + resolution_timeout + dispute_window_seconds` plus the
+    /// resolution grace period. An unresolved market past this time can be
+    /// voided by anyone with `void_unresolved_market`.
+    pub fn compute_resolution_deadline(&self) -> u64 {
+        self.end_time
+            .saturating_add(self.resolution_timeout)
+            .saturating_add(self.dispute_window_seconds)
+            .saturating_add(crate::config::RESOLUTION_DEADLINE_GRACE_SECONDS)
+    }
+
+    /// Time from which payouts may be distributed and the result can no longer be disputed.
+    ///
+    /// Markets resolved without a recorded `resolved_at` fall back to `end_time`.
+    pub fn payout_unlock_time(&self) -> u64 {
+        self.payout_unlock_at
+            .unwrap_or_else(|| self.end_time.saturating_add(self.dispute_window_seconds))
+    }
+
     /// Get the primary winning outcome (first outcome if multiple, for backward compatibility)
     pub fn get_winning_outcome(&self) -> Option<String> {
         self.winning_outcomes.as_ref().and_then(|outcomes| {
@@ -954,11 +1526,52 @@ impl Market {
         total
     }
 
-    /// Add a vote to the market (for testing)
-    pub fn add_vote(&mut self, user: Address, outcome: String, stake: i128) {
-        self.votes.set(user.clone(), outcome);
-        self.stakes.set(user, stake);
-        self.total_staked += stake;
+    /// Count a new position of `stake` on `outcome` in the outcome totals.
+    ///
+    /// The position itself is stored as a `Bet`; callers update `total_staked`.
+    pub fn add_position(&mut self, outcome: &String, stake: i128) {
+        self.add_to_outcome_pool(outcome, stake);
+        let count = self.outcome_position_count(outcome).saturating_add(1);
+        self.outcome_positions.set(outcome.clone(), count);
+    }
+
+    /// Drop a position of `stake` on `outcome` from the outcome totals.
+    pub fn remove_position(&mut self, outcome: &String, stake: i128) {
+        self.add_to_outcome_pool(outcome, -stake);
+        let count = self.outcome_position_count(outcome).saturating_sub(1);
+        if count > 0 {
+            self.outcome_positions.set(outcome.clone(), count);
+        } else {
+            self.outcome_positions.remove(outcome.clone());
+        }
+    }
+
+    /// Number of positions backing `outcome`.
+    pub fn outcome_position_count(&self, outcome: &String) -> u32 {
+        self.outcome_positions.get(outcome.clone()).unwrap_or(0)
+    }
+
+    /// Number of open positions across all outcomes.
+    pub fn position_count(&self) -> u32 {
+        self.outcome_positions
+            .values()
+            .iter()
+            .fold(0u32, |total, count| total.saturating_add(count))
+    }
+
+    /// Total stake backing `outcome`.
+    pub fn outcome_pool(&self, outcome: &String) -> i128 {
+        self.outcome_pools.get(outcome.clone()).unwrap_or(0)
+    }
+
+    /// Move an outcome's pool by `delta` (negative when a position is removed).
+    pub fn add_to_outcome_pool(&mut self, outcome: &String, delta: i128) {
+        let total = self.outcome_pool(outcome).saturating_add(delta);
+        if total > 0 {
+            self.outcome_pools.set(outcome.clone(), total);
+        } else {
+            self.outcome_pools.remove(outcome.clone());
+        }
     }
 
     /// Validate market parameters
@@ -1246,6 +1859,55 @@ pub struct OracleResult {
     pub error_message: Option<String>,
 }
 
+/// Last-resort community vote on a market whose oracles all failed.
+///
+/// Bettors vote for an outcome weighted by their stake, which is frozen once
+/// the market ends. The plurality outcome resolves the market when enough
+/// stake voted; a tie or a missed quorum voids it and refunds every bettor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommunityVote {
+    /// Bettor who opened the vote
+    pub opened_by: Address,
+    /// Ledger time the vote opened
+    pub opened_at: u64,
+    /// Ledger time after which no more votes are accepted
+    pub ends_at: u64,
+    /// Stake-weighted votes per outcome
+    pub tallies: Map<String, i128>,
+    /// Outcome chosen by each voter
+    pub voters: Map<Address, String>,
+    /// Total stake that voted
+    pub total_weight: i128,
+    /// Whether the vote has been tallied
+    pub finalized: bool,
+}
+
+/// Bettors' stakes frozen when a market's community vote starts; vote weight
+/// is read from here rather than from the live market.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeSnapshot {
+    /// Ledger time the snapshot was taken
+    pub taken_at: u64,
+    /// Stake held by each bettor; withdrawn bettors are absent
+    pub stakes: Map<Address, i128>,
+    /// Sum of all snapshotted stakes
+    pub total: i128,
+}
+
+/// Timing and quorum settings for community votes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommunityVoteConfig {
+    /// Seconds after `end_time + resolution_timeout` before a vote may open
+    pub grace_period: u64,
+    /// Seconds a vote stays open
+    pub voting_period: u64,
+    /// Share of the market's total stake that must vote, in basis points
+    pub quorum_bps: u32,
+}
+
 impl OracleResult {
     /// Check if the oracle result is valid and verified
     pub fn is_valid(&self) -> bool {
@@ -1606,6 +2268,25 @@ pub struct ReflectorPriceData {
     pub source: String,
 }
 
+/// Price record returned by a Pyth price contract.
+///
+/// Mirrors Pyth's `Price` struct: the real price is `price * 10^expo`, and
+/// `publish_time` is the unix timestamp (seconds) of the price update. The
+/// contract normalizes this to 8 decimals before comparing with a market's
+/// threshold.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PythPriceData {
+    /// Raw price mantissa
+    pub price: i64,
+    /// Confidence interval around the price, in the same units
+    pub conf: u64,
+    /// Base-10 exponent applied to `price` and `conf`
+    pub expo: i32,
+    /// Unix timestamp of the price update
+    pub publish_time: u64,
+}
+
 // ===== MARKET EXTENSION TYPES =====
 
 /// Market extension data structure for time-based market lifecycle management.
@@ -2715,6 +3396,10 @@ pub enum MarketStatus {
     Closed,
     /// Market has been cancelled
     Cancelled,
+    /// Market betting has closed before its end time
+    BettingClosed,
+    /// Market has been voided
+    Voided,
 }
 
 impl MarketStatus {
@@ -2727,6 +3412,8 @@ impl MarketStatus {
             MarketState::Resolved => MarketStatus::Resolved,
             MarketState::Closed => MarketStatus::Closed,
             MarketState::Cancelled => MarketStatus::Cancelled,
+            MarketState::BettingClosed => MarketStatus::BettingClosed,
+            MarketState::Voided => MarketStatus::Voided,
         }
     }
 }
@@ -3100,6 +3787,98 @@ pub struct BetStats {
     pub outcome_totals: Map<String, i128>,
 }
 
+/// Live betting statistics for a market, as shown next to the current odds.
+///
+/// Built from the incrementally maintained `BetStats` plus the market's fee, so
+/// reading it does not iterate over bets.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketStats {
+    /// Total amount staked across all outcomes
+    pub total_staked: i128,
+    /// Amount staked per outcome (every market outcome, 0 when nobody backed it)
+    pub outcome_pools: Map<String, i128>,
+    /// Number of distinct users holding a position
+    pub unique_bettors: u32,
+    /// Number of open bets (a repeat bet on the same outcome tops up one bet)
+    pub total_bets: u32,
+    /// Payout per unit staked if the outcome wins, net of the market fee and
+    /// scaled by 100 (e.g. 196 = 1.96x); 0 for outcomes with no stake
+    pub payout_multipliers: Map<String, i128>,
+    /// Stake the market can still take before reaching `max_pool_size` (None = no cap)
+    pub remaining_capacity: Option<i128>,
+}
+
+/// A user's stake in one market and what they can currently claim from it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Position {
+    /// Market the position is in
+    pub market_id: Symbol,
+    /// Amount staked per outcome the user backed
+    pub stakes: Map<String, i128>,
+    /// Whether the market has winning outcomes set
+    pub resolved: bool,
+    /// Whether the user's winnings have already been paid
+    pub claimed: bool,
+    /// Net payout owed to the user (0 if unresolved, lost, or already claimed)
+    pub claimable: i128,
+}
+
+/// What resolving a market would do, as reported by `simulate_resolution`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionPreview {
+    /// Market the preview is for
+    pub market_id: Symbol,
+    /// Oracle price the resolution is based on (None if no oracle result was used)
+    pub oracle_value: Option<i128>,
+    /// Winning outcome(s) the market resolves to
+    pub winning_outcomes: Vec<String>,
+    /// Platform fee the market would owe
+    pub fee_amount: i128,
+    /// Number of positions that would be paid (winners, or every bettor in refund mode)
+    pub winner_count: u32,
+    /// Total that would be paid out, net of fees
+    pub total_payout: i128,
+}
+
+/// A market with the values derived from its fields and the ledger time, as
+/// reported by `get_market_view`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketView {
+    /// Market the view is for
+    pub market_id: Symbol,
+    /// Prediction question
+    pub question: String,
+    /// Available outcomes
+    pub outcomes: Vec<String>,
+    /// Effective state, see `MarketStateLogic::derive_market_state`
+    pub state: MarketState,
+    /// Scheduled end time
+    pub end_time: u64,
+    /// Time from which bets are rejected (`bet_deadline`, or `end_time` when unset)
+    pub betting_deadline: u64,
+    /// Time after which an unresolved market can be voided
+    pub resolution_deadline: u64,
+    /// When the dispute window closes, including any appeal window reopened by
+    /// a dispute ruling (None until resolved)
+    pub dispute_window_end: Option<u64>,
+    /// When payouts unlock (None until resolved, or while a result dispute is open)
+    pub payout_unlock_at: Option<u64>,
+    /// Total amount staked across all outcomes
+    pub total_pool: i128,
+    /// Amount staked on each outcome, including outcomes nobody backed
+    pub outcome_pools: Map<String, i128>,
+    /// Winning outcome(s) (None until resolved)
+    pub winning_outcomes: Option<Vec<String>>,
+    /// Whether the platform fee has been collected
+    pub fee_collected: bool,
+    /// Whether the market is frozen by the admin
+    pub frozen: bool,
+}
+
 // ===== EVENT TYPES =====
 
 /// Visibility setting for events (public vs private)
@@ -1,7 +1,12 @@
 #![cfg(test)]
 
+use crate::bets::BetStorage;
 use crate::config::ConfigManager;
-use crate::types::{Market, MarketState, OracleConfig, OracleProvider, ReflectorAsset};
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::types::{
+    Bet, Market, MarketState, OracleConfig, OracleProvider, OracleSigner, ReflectorAsset,
+};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
 use soroban_sdk::{vec, Address, Env, String, Symbol};
@@ -74,32 +79,38 @@ impl TimeoutSweepSetup {
                     feed_id: String::from_str(&env, "TEST/YES"),
                     threshold: 1,
                     comparison: String::from_str(&env, "gt"),
+                    twap_records: None,
+                    quote_feed_id: None,
+                    signer: OracleSigner::None,
                 },
                 None,
                 86_400,
                 MarketState::Resolved,
             );
 
-            market
-                .votes
-                .set(winner_1.clone(), String::from_str(&env, "yes"));
-            market
-                .votes
-                .set(winner_2.clone(), String::from_str(&env, "yes"));
-            market
-                .votes
-                .set(loser.clone(), String::from_str(&env, "no"));
-
-            market.stakes.set(winner_1.clone(), 1_000_000);
-            market.stakes.set(winner_2.clone(), 1_000_000);
-            market.stakes.set(loser.clone(), 1_000_000);
+            for (user, outcome) in [
+                (&winner_1, "yes"),
+                (&winner_2, "yes"),
+                (&loser, "no"),
+            ] {
+                let outcome = String::from_str(&env, outcome);
+                let bet =
+                    Bet::new(&env, user.clone(), market_id.clone(), outcome.clone(), 1_000_000);
+                BetStorage::store_bet(&env, &bet).unwrap();
+                market.add_position(&outcome, 1_000_000);
+            }
             market.total_staked = 3_000_000;
 
             let mut winning_outcomes = soroban_sdk::Vec::new(&env);
             winning_outcomes.push_back(String::from_str(&env, "yes"));
             market.winning_outcomes = Some(winning_outcomes);
+            // Resolved at end_time with no dispute window, so claims open at once
+            market.dispute_window_seconds = 0;
+            market.mark_resolved(end_time);
 
-            env.storage().persistent().set(&market_id, &market);
+            env.storage().persistent().set(&DataKey::Market(market_id.clone()), &market);
+            // The injected stakes are escrowed as if they had been bet
+            crate::escrow::EscrowManager::credit(&env, &market_id, market.total_staked).unwrap();
         });
 
         Self {
@@ -134,7 +145,6 @@ impl TimeoutSweepSetup {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #400)")]
 fn test_sweep_blocked_before_claim_period_end() {
     let setup = TimeoutSweepSetup::new();
 
@@ -144,9 +154,12 @@ fn test_sweep_blocked_before_claim_period_end() {
 
     setup.set_time(setup.end_time + 999);
 
-    setup
-        .client()
-        .sweep_unclaimed_winnings(&setup.admin, &setup.market_id, &false);
+    assert_eq!(
+        setup
+            .client()
+            .try_sweep_unclaimed_winnings(&setup.admin, &setup.market_id, &false),
+        Err(Ok(Error::InvalidState))
+    );
 }
 
 #[test]
@@ -176,32 +189,25 @@ fn test_sweep_after_timeout_only_unclaimed_to_treasury() {
         .amount;
     assert_eq!(treasury_balance, swept);
 
-    let market = setup.client().get_market(&setup.market_id).unwrap();
-    assert_eq!(
-        market.claimed.get(setup.winner_1.clone()).unwrap_or(false),
-        true
-    );
-    assert_eq!(
-        market.claimed.get(setup.winner_2.clone()).unwrap_or(false),
-        true
-    );
-    assert_eq!(
-        market.claimed.get(setup.loser.clone()).unwrap_or(false),
-        false
-    );
+    let client = setup.client();
+    assert!(client.has_claimed(&setup.market_id, &setup.winner_1));
+    assert!(client.has_claimed(&setup.market_id, &setup.winner_2));
+    assert!(!client.has_claimed(&setup.market_id, &setup.loser));
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #207)")]
 fn test_claim_blocked_after_claim_period_expired() {
     let setup = TimeoutSweepSetup::new();
 
     setup.client().set_global_claim_period(&setup.admin, &10u64);
     setup.set_time(setup.end_time + 10);
 
-    setup
-        .client()
-        .claim_winnings(&setup.winner_1, &setup.market_id);
+    assert_eq!(
+        setup
+            .client()
+            .try_claim_winnings(&setup.winner_1, &setup.market_id),
+        Err(Ok(Error::ResolutionTimeoutReached))
+    );
 }
 
 #[test]
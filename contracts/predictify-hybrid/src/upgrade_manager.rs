@@ -5,6 +5,7 @@ use soroban_sdk::{contracttype, Address, BytesN, Env, String, Symbol, Vec};
 
 use crate::errors::Error;
 use crate::events::EventEmitter;
+use crate::storage::DataKey;
 use crate::versioning::{Version, VersionManager};
 
 /// Comprehensive upgrade management system for Predictify Hybrid contract.
@@ -347,6 +348,13 @@ impl UpgradeManager {
         // Validate admin permissions
         Self::validate_admin_permissions(env, admin)?;
 
+        Self::apply_upgrade(env, admin, new_wasm_hash)
+    }
+
+    /// Swap in `new_wasm_hash` and record it in the upgrade history.
+    ///
+    /// Callers are responsible for authorization.
+    fn apply_upgrade(env: &Env, admin: &Address, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
         // Get current version and Wasm hash
         let current_version = Self::get_contract_version(env)?;
         let current_wasm_hash = Self::get_current_wasm_hash(env);
@@ -392,6 +400,55 @@ impl UpgradeManager {
         Ok(())
     }
 
+    /// Minimum seconds between scheduling and executing an upgrade. Upgrades
+    /// wait out the longer of this and the admin action delay; `0` (the
+    /// default) leaves the admin action delay alone.
+    pub fn get_upgrade_timelock(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, "upgrade_timelock"))
+            .unwrap_or(0)
+    }
+
+    /// Set the upgrade timelock (admin only).
+    ///
+    /// Like the fee withdrawal timelock it can only be tightened: a lower value
+    /// than the current one is rejected, so a compromised admin key cannot
+    /// drop the delay and upgrade at once. Capped at
+    /// `MAX_UPGRADE_TIMELOCK_SECONDS`.
+    pub fn set_upgrade_timelock(env: &Env, admin: &Address, seconds: u64) -> Result<(), Error> {
+        Self::require_contract_admin(env, admin)?;
+        if seconds > crate::config::MAX_UPGRADE_TIMELOCK_SECONDS {
+            return Err(Error::InvalidInput);
+        }
+        if seconds < Self::get_upgrade_timelock(env) {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, "upgrade_timelock"), &seconds);
+        Ok(())
+    }
+
+    /// Replace the contract Wasm with `new_wasm_hash` once its scheduled
+    /// `TimelockedAction::Upgrade` is due.
+    ///
+    /// Callers are responsible for the timelock; `admin` is the admin who
+    /// scheduled the upgrade.
+    pub(crate) fn execute_scheduled_upgrade(
+        env: &Env,
+        admin: &Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        EventEmitter::emit_upgrade_executed(
+            env,
+            admin,
+            &new_wasm_hash,
+            crate::config::CONTRACT_VERSION,
+        );
+        Self::apply_upgrade(env, admin, new_wasm_hash)
+    }
+
     /// Validate upgrade compatibility and safety
     ///
     /// Performs comprehensive pre-upgrade validation:
@@ -689,6 +746,20 @@ impl UpgradeManager {
         Ok(())
     }
 
+    /// Require auth from `admin` and check it against the contract admin.
+    fn require_contract_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if stored_admin != *admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
     /// Get current Wasm hash
     fn get_current_wasm_hash(env: &Env) -> BytesN<32> {
         let storage_key = Symbol::new(env, "current_wasm_hash");
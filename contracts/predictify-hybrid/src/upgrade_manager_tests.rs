@@ -5,8 +5,12 @@ use soroban_sdk::{
     Address, BytesN, Env, String,
 };
 
+use crate::errors::Error;
+use crate::test::PredictifyTest;
+use crate::types::TimelockedAction;
 use crate::upgrade_manager::{UpgradeManager, UpgradeProposal, ValidationResult};
 use crate::versioning::{Version, VersionManager};
+use crate::PredictifyHybridClient;
 
 /// Test helper to create a test environment with initialized contract
 fn setup_test_env() -> (Env, Address, Address) {
@@ -580,3 +584,126 @@ fn test_multiple_upgrade_proposals() {
     assert!(proposal1.proposal_id != proposal2.proposal_id);
     assert!(proposal2.proposal_id != proposal3.proposal_id);
 }
+
+// ===== TIMELOCKED UPGRADE TESTS =====
+
+const ONE_DAY: u64 = 24 * 60 * 60;
+
+/// Upload a real Wasm so `update_current_contract_wasm` has something to load.
+fn upload_upgrade_wasm(env: &Env) -> BytesN<32> {
+    env.deployer().upload_contract_wasm(crate::bandprotocol::WASM)
+}
+
+fn upgrade_count(test: &PredictifyTest) -> u32 {
+    test.env.as_contract(&test.contract_id, || {
+        UpgradeManager::get_upgrade_history(&test.env).unwrap().len()
+    })
+}
+
+#[test]
+fn test_non_admin_cannot_schedule_upgrade() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let outsider = Address::generate(&test.env);
+    let wasm_hash = BytesN::from_array(&test.env, &[7u8; 32]);
+
+    assert_eq!(
+        client.try_schedule_action(&outsider, &TimelockedAction::Upgrade(wasm_hash)),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert!(client.get_pending_actions().is_empty());
+}
+
+#[test]
+fn test_upgrade_timelock_can_only_increase() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+
+    assert_eq!(client.get_upgrade_timelock(), 0);
+    client.set_upgrade_timelock(&test.admin, &ONE_DAY);
+    assert_eq!(client.get_upgrade_timelock(), ONE_DAY);
+
+    assert_eq!(
+        client.try_set_upgrade_timelock(&test.admin, &(ONE_DAY - 1)),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(
+        client.try_set_upgrade_timelock(
+            &test.admin,
+            &(crate::config::MAX_UPGRADE_TIMELOCK_SECONDS + 1)
+        ),
+        Err(Ok(Error::InvalidInput))
+    );
+}
+
+#[test]
+fn test_scheduled_upgrade_runs_after_action_delay() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let wasm_hash = upload_upgrade_wasm(&test.env);
+
+    let scheduled =
+        client.schedule_action(&test.admin, &TimelockedAction::Upgrade(wasm_hash.clone()));
+    assert_eq!(scheduled.execute_after, test.env.ledger().timestamp() + ONE_DAY);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::MarketNotReady))
+    );
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled.execute_after);
+    client.execute_action(&scheduled.id);
+
+    let history = test.env.as_contract(&test.contract_id, || {
+        UpgradeManager::get_upgrade_history(&test.env).unwrap()
+    });
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().new_wasm_hash, wasm_hash);
+}
+
+#[test]
+fn test_upgrade_waits_for_longer_upgrade_timelock() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let wasm_hash = upload_upgrade_wasm(&test.env);
+    client.set_upgrade_timelock(&test.admin, &(2 * ONE_DAY));
+
+    let scheduled = client.schedule_action(&test.admin, &TimelockedAction::Upgrade(wasm_hash));
+    let scheduled_at = scheduled.scheduled_at;
+    assert_eq!(scheduled.execute_after, scheduled_at + 2 * ONE_DAY);
+
+    // The admin action delay alone is not enough
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled_at + 2 * ONE_DAY - 1);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::MarketNotReady))
+    );
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp = scheduled_at + 2 * ONE_DAY);
+    client.execute_action(&scheduled.id);
+    assert_eq!(upgrade_count(&test), 1);
+}
+
+#[test]
+fn test_cancelled_upgrade_cannot_execute() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let wasm_hash = upload_upgrade_wasm(&test.env);
+
+    let scheduled = client.schedule_action(&test.admin, &TimelockedAction::Upgrade(wasm_hash));
+    client.cancel_action(&test.admin, &scheduled.id);
+    assert!(client.get_pending_actions().is_empty());
+
+    test.env
+        .ledger()
+        .with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_execute_action(&scheduled.id),
+        Err(Ok(Error::InvalidInput))
+    );
+    assert_eq!(upgrade_count(&test), 0);
+}
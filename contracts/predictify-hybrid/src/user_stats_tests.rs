@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+//! Per-user lifetime statistics tests.
+//!
+//! Covers `get_user_stats` and the user market index after a win, a loss
+//! and a refund.
+
+extern crate std;
+
+use crate::statistics::{StatisticsManager, USER_MARKET_INDEX_LIMIT};
+use crate::test::PredictifyTest;
+use crate::types::{CancelReason, ReflectorAsset};
+use crate::PredictifyHybridClient;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{vec, String, Symbol};
+
+const STAKE: i128 = 100_000_000;
+
+#[test]
+fn test_user_stats_after_win_loss_and_refund() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let yes = String::from_str(&test.env, "yes");
+    let no = String::from_str(&test.env, "no");
+
+    let won = test.create_test_market();
+    let lost = test.create_test_market();
+    let refunded = test.create_test_market();
+    let user = test.create_funded_user();
+    let other = test.create_funded_user();
+
+    for market_id in [&won, &lost] {
+        client.place_bet(&user, market_id, &yes, &STAKE, &None);
+        client.place_bet(&other, market_id, &no, &STAKE, &None);
+    }
+    client.place_bet(&user, &refunded, &yes, &STAKE, &None);
+
+    assert_eq!(
+        client.get_user_markets(&user, &0, &10),
+        vec![&test.env, won.clone(), lost.clone(), refunded.clone()]
+    );
+    assert_eq!(
+        client.get_user_markets(&user, &1, &1),
+        vec![&test.env, lost.clone()]
+    );
+
+    client.cancel_event(&test.admin, &refunded, &CancelReason::Administrative, &None);
+    let end_time = client.get_market(&won).unwrap().end_time;
+    test.env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.resolve_market_manual(&test.admin, &won, &yes);
+    client.resolve_market_manual(&test.admin, &lost, &no);
+
+    // 2% platform fee on a 200M pool
+    let stats = client.get_user_stats(&user);
+    assert_eq!(stats.lifetime_staked, 3 * STAKE);
+    assert_eq!(stats.markets_participated, 3);
+    assert_eq!(stats.total_claimable, 196_000_000);
+    assert_eq!(stats.lifetime_winnings, 0);
+    assert_eq!(stats.win_count, 0);
+
+    test.advance_past_payout_unlock(&won);
+    assert_eq!(client.claim_winnings(&user, &won), 196_000_000);
+    assert_eq!(
+        client.get_balance(&user, &ReflectorAsset::Stellar).amount,
+        196_000_000
+    );
+
+    let stats = client.get_user_stats(&user);
+    assert_eq!(stats.lifetime_winnings, 196_000_000);
+    assert_eq!(stats.win_count, 1);
+    assert_eq!(stats.total_claimable, 0);
+    assert_eq!(stats.markets_participated, 3);
+}
+
+#[test]
+fn test_user_market_index_keeps_most_recent() {
+    let test = PredictifyTest::setup();
+    let client = PredictifyHybridClient::new(&test.env, &test.contract_id);
+    let user = test.create_funded_user();
+    let limit = USER_MARKET_INDEX_LIMIT;
+
+    test.env.as_contract(&test.contract_id, || {
+        for i in 0..=limit {
+            let market_id = Symbol::new(&test.env, &std::format!("mkt_{}", i));
+            StatisticsManager::record_market_joined(&test.env, &user, &market_id);
+            // Joining the same market again is not counted twice
+            StatisticsManager::record_market_joined(&test.env, &user, &market_id);
+        }
+    });
+
+    let markets = client.get_user_markets(&user, &0, &(limit + 10));
+    assert_eq!(markets.len(), limit);
+    assert_eq!(markets.get(0), Some(Symbol::new(&test.env, "mkt_1")));
+    assert_eq!(
+        markets.get(limit - 1),
+        Some(Symbol::new(&test.env, &std::format!("mkt_{}", limit)))
+    );
+    assert_eq!(client.get_user_markets(&user, &limit, &10).len(), 0);
+    assert_eq!(
+        client.get_user_stats(&user).markets_participated,
+        u64::from(limit) + 1
+    );
+}
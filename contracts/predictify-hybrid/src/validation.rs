@@ -5,7 +5,7 @@ extern crate alloc;
 use crate::{
     config,
     errors::Error,
-    types::{BetLimits, Market, OracleConfig, OracleProvider},
+    types::{BetLimits, Market, OracleConfig, OracleProvider, OracleSigner},
 };
 // use alloc::string::ToString; // Removed to fix Display/ToString trait errors
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
@@ -1462,10 +1462,43 @@ impl InputValidator {
         )
     }
 
+    /// Validate market metadata description with length limits
+    ///
+    /// The metadata description is optional (may be empty) and must not exceed
+    /// MAX_METADATA_DESCRIPTION_LENGTH characters.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the description length is valid
+    /// * `Err(ValidationError::StringTooLong)` if above maximum length
+    pub fn validate_metadata_description_length(
+        description: &String,
+    ) -> Result<(), ValidationError> {
+        if description.len() > config::MAX_METADATA_DESCRIPTION_LENGTH {
+            return Err(ValidationError::StringTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate market reference URL with length limits
+    ///
+    /// The reference URL is optional (may be empty) and must not exceed
+    /// MAX_REFERENCE_URL_LENGTH characters.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the URL length is valid
+    /// * `Err(ValidationError::StringTooLong)` if above maximum length
+    pub fn validate_reference_url_length(url: &String) -> Result<(), ValidationError> {
+        if url.len() > config::MAX_REFERENCE_URL_LENGTH {
+            return Err(ValidationError::StringTooLong);
+        }
+        Ok(())
+    }
+
     /// Validate all outcomes in a vector
     ///
-    /// Validates that all outcomes in a vector meet length requirements and
-    /// that the number of outcomes is within acceptable limits.
+    /// Validates that all outcomes in a vector meet length requirements, that
+    /// the number of outcomes is within acceptable limits, and that no outcome
+    /// appears twice.
     ///
     /// # Parameters
     /// * `outcomes` - Vector of outcome strings to validate
@@ -1499,7 +1532,26 @@ impl InputValidator {
         for outcome in outcomes.iter() {
             Self::validate_outcome_length(&outcome)?;
         }
-        
+
+        Self::validate_unique_outcomes(outcomes)
+    }
+
+    /// Validate that no outcome appears more than once
+    ///
+    /// Duplicate outcomes would split one answer's backers across two pools.
+    ///
+    /// # Returns
+    /// * `Ok(())` if all outcomes are distinct
+    /// * `Err(ValidationError::InvalidOutcome)` on the first duplicate
+    pub fn validate_unique_outcomes(outcomes: &Vec<String>) -> Result<(), ValidationError> {
+        for i in 0..outcomes.len() {
+            let outcome = outcomes.get(i).unwrap();
+            for j in (i + 1)..outcomes.len() {
+                if outcomes.get(j).unwrap() == outcome {
+                    return Err(ValidationError::InvalidOutcome);
+                }
+            }
+        }
         Ok(())
     }
 
@@ -2181,6 +2233,7 @@ impl EventValidator {
                 return Err(e);
             }
         }
+        InputValidator::validate_unique_outcomes(outcomes)?;
 
         // Validate end time (must be in the future)
         let current_time = env.ledger().timestamp();
@@ -2436,6 +2489,7 @@ impl OracleValidator {
             OracleProvider::DIA => Ok(()),
             OracleProvider::Reflector => Ok(()),
             OracleProvider::Pyth => Ok(()),
+            OracleProvider::Signed => Ok(()),
         }
     }
 
@@ -2820,7 +2874,7 @@ impl VoteValidator {
         }
 
         // Check if user has already voted
-        if market.votes.contains_key(user.clone()) {
+        if crate::bets::BetStorage::get_position(env, market_id, user).is_some() {
             return Err(ValidationError::InvalidVote);
         }
 
@@ -2861,10 +2915,14 @@ impl VoteValidator {
 // ===== BET LIMITS VALIDATION =====
 
 /// Validates bet amount against min/max limits. Used by place_bet.
-/// Returns InsufficientStake if below min, InvalidInput if above max.
+/// Returns InvalidAmount for zero or negative amounts, BetBelowMinimum if below min,
+/// InvalidInput if above max.
 pub fn validate_bet_amount_against_limits(amount: i128, limits: &BetLimits) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
     if amount < limits.min_bet {
-        return Err(Error::InsufficientStake);
+        return Err(Error::BetBelowMinimum);
     }
     if amount > limits.max_bet {
         return Err(Error::InvalidInput);
@@ -3463,6 +3521,9 @@ impl ValidationTestingUtils {
                 feed_id: String::from_str(env, "BTC/USD"),
                 threshold: 2500000,
                 comparison: String::from_str(env, "gt"),
+                twap_records: None,
+                quote_feed_id: None,
+                signer: OracleSigner::None,
             },
             None,
             86400,
@@ -3481,6 +3542,9 @@ impl ValidationTestingUtils {
             feed_id: String::from_str(env, "BTC/USD"),
             threshold: 2500000,
             comparison: String::from_str(env, "gt"),
+            twap_records: None,
+            quote_feed_id: None,
+            signer: OracleSigner::None,
         }
     }
 }
@@ -4576,7 +4640,7 @@ impl MarketParams {
 /// - Threshold range: $0.01 to $10,000,000
 /// - Supported operators: "gt", "lt", "eq"
 ///
-/// **Pyth Network (Future):**
+/// **Pyth Network:**
 /// - Feed ID format: 64-character hex string
 /// - Threshold range: $0.01 to $1,000,000
 /// - Supported operators: "gt", "gte", "lt", "lte", "eq"
@@ -4675,6 +4739,10 @@ impl OracleConfigValidator {
 
                 Ok(())
             }
+            OracleProvider::Signed => {
+                // Any non-empty label: the signer defines what it attests to
+                Ok(())
+            }
             OracleProvider::BandProtocol | OracleProvider::DIA => {
                 // Not supported on Stellar
                 Err(ValidationError::InvalidOracle)
@@ -4739,6 +4807,10 @@ impl OracleConfigValidator {
 
                 Ok(())
             }
+            OracleProvider::Signed => {
+                // Any non-empty label: the signer defines what it attests to
+                Ok(())
+            }
             OracleProvider::BandProtocol | OracleProvider::DIA => {
                 // Not supported on Stellar
                 Err(ValidationError::InvalidOracle)
@@ -4812,9 +4884,8 @@ impl OracleConfigValidator {
     /// - ✅ Full integration available
     ///
     /// **Pyth Network:**
-    /// - ⚠️ Placeholder for future Stellar support
-    /// - ❌ Not currently available on Stellar
-    /// - 🔮 Future implementation planned
+    /// - ✅ Supported on Stellar via a Pyth price contract
+    /// - ✅ Staleness checked against `max_price_age`
     ///
     /// **Band Protocol:**
     /// - ❌ Not supported on Stellar
@@ -4832,9 +4903,12 @@ impl OracleConfigValidator {
                 Ok(())
             }
             OracleProvider::Pyth => {
-                // Pyth is placeholder for future Stellar support
-                // Currently returns error but could be changed when Pyth supports Stellar
-                Err(ValidationError::InvalidOracle)
+                // Pyth is supported through a Pyth price contract on Stellar
+                Ok(())
+            }
+            OracleProvider::Signed => {
+                // Signed results are verified against the signer's ed25519 key
+                Ok(())
             }
             OracleProvider::BandProtocol | OracleProvider::DIA => {
                 // Not supported on Stellar network
@@ -4877,7 +4951,8 @@ impl OracleConfigValidator {
         Self::validate_threshold_range(&config.threshold, &config.provider)?;
 
         // Get supported operators for the provider
-        let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
+        let supported_operators =
+            Self::get_supported_operators_for_provider(config.feed_id.env(), &config.provider);
 
         // Validate comparison operator
         Self::validate_comparison_operator(&config.comparison, &supported_operators)?;
@@ -4898,6 +4973,9 @@ impl OracleConfigValidator {
                     return Err(ValidationError::InvalidOracle);
                 }
             }
+            OracleProvider::Signed => {
+                // The signed payload carries the outcome; nothing to cross-check
+            }
             OracleProvider::BandProtocol | OracleProvider::DIA => {
                 // Not supported providers
                 return Err(ValidationError::InvalidOracle);
@@ -4977,11 +5055,11 @@ impl OracleConfigValidator {
                 );
                 rules.set(
                     String::from_str(env, "network_support"),
-                    String::from_str(env, "Future Stellar support"),
+                    String::from_str(env, "Pyth price contract on Stellar"),
                 );
                 rules.set(
                     String::from_str(env, "integration_status"),
-                    String::from_str(env, "Placeholder implementation"),
+                    String::from_str(env, "Production ready"),
                 );
             }
             OracleProvider::BandProtocol => {
@@ -5036,6 +5114,24 @@ impl OracleConfigValidator {
                     String::from_str(env, "Not available"),
                 );
             }
+            OracleProvider::Signed => {
+                rules.set(
+                    String::from_str(env, "feed_id_format"),
+                    String::from_str(env, "Any label for the signed data (e.g., a game ID)"),
+                );
+                rules.set(
+                    String::from_str(env, "oracle_address"),
+                    String::from_str(env, "Signer's G... account (ed25519 public key)"),
+                );
+                rules.set(
+                    String::from_str(env, "network_support"),
+                    String::from_str(env, "Verified on-chain with ed25519"),
+                );
+                rules.set(
+                    String::from_str(env, "integration_status"),
+                    String::from_str(env, "Results pushed with submit_signed_result"),
+                );
+            }
         }
 
         rules
@@ -5080,7 +5176,8 @@ impl OracleConfigValidator {
         Self::validate_threshold_range(&config.threshold, &config.provider)?;
 
         // Step 4: Get supported operators and validate comparison
-        let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
+        let supported_operators =
+            Self::get_supported_operators_for_provider(config.feed_id.env(), &config.provider);
         Self::validate_comparison_operator(&config.comparison, &supported_operators)?;
 
         // Step 5: Validate configuration consistency
@@ -5092,6 +5189,7 @@ impl OracleConfigValidator {
     /// Get supported comparison operators for a specific provider
     ///
     /// # Arguments
+    /// * `env` - The Soroban environment the operator strings are allocated in
     /// * `provider` - The oracle provider to get operators for
     ///
     /// # Returns
@@ -5113,28 +5211,28 @@ impl OracleConfigValidator {
     ///
     /// **Band Protocol & DIA:**
     /// - Empty vector (not supported)
-    fn get_supported_operators_for_provider(provider: &OracleProvider) -> Vec<String> {
+    fn get_supported_operators_for_provider(env: &Env, provider: &OracleProvider) -> Vec<String> {
         match provider {
             OracleProvider::Reflector => {
                 vec![
-                    &soroban_sdk::Env::default(),
-                    String::from_str(&soroban_sdk::Env::default(), "gt"),
-                    String::from_str(&soroban_sdk::Env::default(), "lt"),
-                    String::from_str(&soroban_sdk::Env::default(), "eq"),
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "eq"),
                 ]
             }
-            OracleProvider::Pyth => {
+            OracleProvider::Pyth | OracleProvider::Signed => {
                 vec![
-                    &soroban_sdk::Env::default(),
-                    String::from_str(&soroban_sdk::Env::default(), "gt"),
-                    String::from_str(&soroban_sdk::Env::default(), "gte"),
-                    String::from_str(&soroban_sdk::Env::default(), "lt"),
-                    String::from_str(&soroban_sdk::Env::default(), "lte"),
-                    String::from_str(&soroban_sdk::Env::default(), "eq"),
+                    env,
+                    String::from_str(env, "gt"),
+                    String::from_str(env, "gte"),
+                    String::from_str(env, "lt"),
+                    String::from_str(env, "lte"),
+                    String::from_str(env, "eq"),
                 ]
             }
             OracleProvider::BandProtocol | OracleProvider::DIA => {
-                vec![&soroban_sdk::Env::default()]
+                vec![env]
             }
         }
     }
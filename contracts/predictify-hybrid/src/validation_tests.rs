@@ -628,6 +628,9 @@ fn test_validate_comprehensive_inputs() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100000,
         comparison: String::from_str(&env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     // Test question format
@@ -661,6 +664,9 @@ fn test_validate_market_creation() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100000,
         comparison: String::from_str(&env, "gt"),
+        twap_records: None,
+        quote_feed_id: None,
+        signer: OracleSigner::None,
     };
 
     // Test question format
@@ -1152,13 +1158,13 @@ mod oracle_config_validator_tests {
 
     #[test]
     fn test_validate_oracle_provider() {
-        // Supported provider
+        // Supported providers
         assert!(
             OracleConfigValidator::validate_oracle_provider(&OracleProvider::Reflector).is_ok()
         );
+        assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::Pyth).is_ok());
 
         // Unsupported providers
-        assert!(OracleConfigValidator::validate_oracle_provider(&OracleProvider::Pyth).is_err());
 
         assert!(
             OracleConfigValidator::validate_oracle_provider(&OracleProvider::BandProtocol).is_err()
@@ -1408,7 +1414,7 @@ mod oracle_config_validator_tests {
         )
         .is_ok());
 
-        // Test Pyth-specific validation (should fail for provider support but pass format validation)
+        // Test Pyth-specific validation
         let pyth_config = OracleConfig::new(
             OracleProvider::Pyth,
             Address::generate(&env),
@@ -1432,8 +1438,7 @@ mod oracle_config_validator_tests {
         )
         .is_ok());
 
-        // Overall validation should fail due to provider not being supported
-        assert!(OracleConfigValidator::validate_oracle_config_all_together(&pyth_config).is_err());
+        assert!(OracleConfigValidator::validate_oracle_config_all_together(&pyth_config).is_ok());
     }
 }
 
@@ -1758,7 +1763,7 @@ fn test_validate_outcome_format_comprehensive() {
     let valid_outcome2 = String::from_str(&env, "No");
     assert!(InputValidator::validate_outcome_format(&valid_outcome2).is_ok());
 
-    let valid_outcome3 = String::from_str(&env, "Maybe - depends on market conditions");
+    let valid_outcome3 = String::from_str(&env, "Maybe - depends on conditions");
     assert!(InputValidator::validate_outcome_format(&valid_outcome3).is_ok());
 
     // Boundary - minimum length (2 characters)
@@ -2,8 +2,10 @@
 
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::{
+    bets::BetStorage,
     errors::Error,
     markets::{MarketAnalytics, MarketStateManager, MarketUtils, MarketValidator},
+    storage::DataKey,
     types::Market,
 };
 
@@ -323,8 +325,11 @@ impl VotingManager {
         VotingUtils::transfer_stake(env, &user, stake)?;
 
         // Add vote to market (pass market_id for event emission)
-        MarketStateManager::add_vote(&mut market, user, outcome, stake, Some(&market_id));
+        MarketStateManager::add_vote(env, &market_id, &mut market, user.clone(), outcome, stake)?;
         MarketStateManager::update_market(env, &market_id, &market);
+        crate::escrow::EscrowManager::credit(env, &market_id, stake)?;
+        crate::statistics::StatisticsManager::record_stake_escrowed(env, stake);
+        crate::statistics::StatisticsManager::record_market_joined(env, &user, &market_id);
 
         Ok(())
     }
@@ -360,6 +365,7 @@ impl VotingManager {
             cfg.voting.dispute_extension_hours.into(),
         );
         MarketStateManager::update_market(env, &market_id, &market);
+        crate::escrow::EscrowManager::credit(env, &market_id, stake)?;
 
         Ok(())
     }
@@ -370,21 +376,21 @@ impl VotingManager {
         user.require_auth();
 
         // Get and validate market
-        let mut market = MarketStateManager::get_market(env, &market_id)?;
-        VotingValidator::validate_market_for_claim(env, &market, &user)?;
+        let market = MarketStateManager::get_market(env, &market_id)?;
+        VotingValidator::validate_market_for_claim(env, &market_id, &market, &user)?;
 
         // Calculate and process payout
-        let payout = VotingUtils::calculate_user_payout(env, &market, &user)?;
+        let payout = VotingUtils::calculate_user_payout(env, &market_id, &market, &user)?;
+
+        // Mark as claimed before paying out
+        MarketStateManager::mark_claimed(env, &market_id, &market, &user);
+        crate::escrow::EscrowManager::debit(env, &market_id, payout)?;
 
         // Transfer winnings if any
         if payout > 0 {
             VotingUtils::transfer_winnings(env, &user, payout)?;
         }
 
-        // Mark as claimed
-        MarketStateManager::mark_claimed(&mut market, user, Some(&market_id));
-        MarketStateManager::update_market(env, &market_id, &market);
-
         Ok(payout)
     }
 
@@ -479,12 +485,6 @@ impl VotingManager {
             &admin,
         )?;
 
-        // Get market for updating
-        let mut market = MarketStateManager::get_market(env, &market_id)?;
-
-        // Mark fees as collected
-        MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
-        MarketStateManager::update_market(env, &market_id, &market);
         Ok(new_threshold_data)
     }
 
@@ -570,7 +570,7 @@ impl ThresholdUtils {
 
         // Calculate activity factor
         let activity_factor =
-            Self::modify_threshold_by_activity(env, market_id, market.votes.len() as u32)?;
+            Self::modify_threshold_by_activity(env, market_id, market.position_count())?;
 
         // Calculate complexity factor (based on number of outcomes) using dynamic base
         let base = crate::config::ConfigManager::get_config(env)?
@@ -902,7 +902,7 @@ impl VotingValidator {
     /// Validate admin authentication and permissions
     pub fn validate_admin_authentication(env: &Env, admin: &Address) -> Result<(), Error> {
         let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+            env.storage().instance().get(&DataKey::Admin);
 
         match stored_admin {
             Some(stored_admin) => {
@@ -950,13 +950,13 @@ impl VotingValidator {
     /// Validate market state for claim
 
     pub fn validate_market_for_claim(
-        _env: &Env,
+        env: &Env,
+        market_id: &Symbol,
         market: &Market,
         user: &Address,
     ) -> Result<(), Error> {
         // Check if user has already claimed
-        let claimed = market.claimed.get(user.clone()).unwrap_or(false);
-        if claimed {
+        if BetStorage::is_claimed(env, market_id, user) {
             return Err(Error::AlreadyClaimed);
         }
 
@@ -966,7 +966,7 @@ impl VotingValidator {
         }
 
         // Check if user has voted
-        if !market.votes.contains_key(user.clone()) {
+        if BetStorage::get_position(env, market_id, user).is_none() {
             return Err(Error::NothingToClaim);
         }
 
@@ -1075,6 +1075,7 @@ impl VotingValidator {
 /// }
 ///
 /// // Check if user has voted
+/// # let market_id = Symbol::new(&env, "market");
 /// # let market = Market::new(
 /// #     &env,
 /// #     Address::generate(&env),
@@ -1090,7 +1091,7 @@ impl VotingValidator {
 /// #     crate::types::MarketState::Active
 /// # );
 ///
-/// if VotingUtils::has_user_voted(&market, &user) {
+/// if VotingUtils::has_user_voted(&env, &market_id, &user) {
 ///     println!("User has already voted on this market");
 /// } else {
 ///     println!("User has not voted yet");
@@ -1112,16 +1113,17 @@ impl VotingUtils {
         // Reentrancy guard removed - external call protection no longer needed
         let token_client = MarketUtils::get_token_client(env)?;
         // Soroban token transfer returns (), assume success if no panic
-        token_client.transfer(user, &env.current_contract_address(), &stake);
+        MarketUtils::receive_tokens(env, &token_client, user, stake)?;
         Ok(())
     }
 
     /// Transfer winnings to user
     pub fn transfer_winnings(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
-        // Reentrancy guard removed - external call protection no longer needed
         let token_client = MarketUtils::get_token_client(env)?;
-        token_client.transfer(&env.current_contract_address(), user, &amount);
-        Ok(())
+        crate::reentrancy_guard::ReentrancyGuard::guarded(env, || {
+            token_client.transfer(&env.current_contract_address(), user, &amount)
+        })
+        .map_err(|_| Error::InvalidState)
     }
 
     /// Transfer fees to admin (moved to fees module)
@@ -1134,6 +1136,7 @@ impl VotingUtils {
     /// Calculate user's payout
     pub fn calculate_user_payout(
         env: &Env,
+        market_id: &Symbol,
         market: &Market,
         user: &Address,
     ) -> Result<i128, Error> {
@@ -1142,12 +1145,10 @@ impl VotingUtils {
             .as_ref()
             .ok_or(Error::MarketNotResolved)?;
 
-        let user_outcome = market
-            .votes
-            .get(user.clone())
-            .ok_or(Error::NothingToClaim)?;
-
-        let user_stake = market.stakes.get(user.clone()).unwrap_or(0);
+        let position =
+            BetStorage::get_position(env, market_id, user).ok_or(Error::NothingToClaim)?;
+        let user_outcome = position.outcome;
+        let user_stake = position.amount;
 
         // Only pay if user voted for a winning outcome (handles ties - pool split)
         if !winning_outcomes.contains(&user_outcome) {
@@ -1159,11 +1160,7 @@ impl VotingUtils {
         // Get total stake across all winning outcomes
         let mut winning_total = 0;
         for outcome in winning_outcomes.iter() {
-            for (voter, voted_outcome) in market.votes.iter() {
-                if voted_outcome == outcome {
-                    winning_total += market.stakes.get(voter.clone()).unwrap_or(0);
-                }
-            }
+            winning_total += market.outcome_pool(&outcome);
         }
 
         // Calculate payout using total across all winning outcomes (handles ties - pool split)
@@ -1199,20 +1196,18 @@ impl VotingUtils {
     }
 
     /// Check if user has voted on a market
-    pub fn has_user_voted(market: &Market, user: &Address) -> bool {
-        market.votes.contains_key(user.clone())
+    pub fn has_user_voted(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        BetStorage::get_position(env, market_id, user).is_some()
     }
 
     /// Get user's vote details
-    pub fn get_user_vote(market: &Market, user: &Address) -> Option<(String, i128)> {
-        let outcome = market.votes.get(user.clone())?;
-        let stake = market.stakes.get(user.clone()).unwrap_or(0);
-        Some((outcome, stake))
+    pub fn get_user_vote(env: &Env, market_id: &Symbol, user: &Address) -> Option<(String, i128)> {
+        BetStorage::get_position(env, market_id, user).map(|bet| (bet.outcome, bet.amount))
     }
 
     /// Check if user has claimed winnings
-    pub fn has_user_claimed(market: &Market, user: &Address) -> bool {
-        market.claimed.get(user.clone()).unwrap_or(false)
+    pub fn has_user_claimed(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        BetStorage::is_claimed(env, market_id, user)
     }
 }
 
@@ -1311,17 +1306,17 @@ impl VotingAnalytics {
 
         // This is a simplified calculation - in a real scenario you might want
         // to track total eligible participants
-        let participation_rate = (market.votes.len() as f64) / 100.0; // Assuming 100 max participants
+        let participation_rate = (market.position_count() as f64) / 100.0; // Assuming 100 max participants
         participation_rate.min(1.0)
     }
 
     /// Calculate average stake per voter
     pub fn calculate_average_stake(market: &Market) -> i128 {
-        if market.votes.is_empty() {
+        if market.position_count() == 0 {
             return 0;
         }
 
-        market.total_staked / (market.votes.len() as i128)
+        market.total_staked / (market.position_count() as i128)
     }
 
     /// Calculate stake distribution by outcome
@@ -1332,14 +1327,20 @@ impl VotingAnalytics {
     }
 
     /// Calculate voting power concentration
-    pub fn calculate_voting_power_concentration(market: &Market) -> f64 {
+    pub fn calculate_voting_power_concentration(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> f64 {
         if market.total_staked == 0 {
             return 0.0;
         }
 
         let mut total_squared_stakes = 0i128;
-        for (_, stake) in market.stakes.iter() {
-            total_squared_stakes += stake * stake;
+        for user in BetStorage::get_all_bets_for_market(env, market_id).iter() {
+            if let Some(bet) = BetStorage::get_position(env, market_id, &user) {
+                total_squared_stakes += bet.amount * bet.amount;
+            }
         }
 
         let concentration =
@@ -1633,11 +1634,9 @@ mod tests {
         );
 
         // Add some test votes
-        let user1 = Address::generate(&env);
-        let user2 = Address::generate(&env);
-
-        market.add_vote(user1, String::from_str(&env, "yes"), 1000);
-        market.add_vote(user2, String::from_str(&env, "no"), 2000);
+        market.add_position(&String::from_str(&env, "yes"), 1000);
+        market.add_position(&String::from_str(&env, "no"), 2000);
+        market.total_staked = 3000;
 
         let avg_stake = VotingAnalytics::calculate_average_stake(&market);
         assert_eq!(avg_stake, 1500); // (1000 + 2000) / 2
@@ -1668,14 +1667,14 @@ mod tests {
             crate::types::MarketState::Active,
         );
 
-        let user = Address::generate(&env);
-        market.add_vote(user.clone(), String::from_str(&env, "yes"), 1000);
+        market.add_position(&String::from_str(&env, "yes"), 1000);
+        market.total_staked = 1000;
 
         let stats = VotingUtils::get_voting_stats(&market);
         assert_eq!(stats.total_votes, 0); // Simplified implementation returns 0
         assert_eq!(stats.total_staked, 0); // Simplified implementation returns 0
         assert_eq!(stats.unique_voters, 0); // Simplified implementation returns 0
-        assert!(VotingUtils::has_user_voted(&market, &user));
+        assert_eq!(market.position_count(), 1);
     }
 
     #[test]